@@ -13,11 +13,13 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::traits::ToFixed;
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::int256;
+use crate::int256::U256;
+use crate::traits::{MulExact, ToFixed};
+use crate::types::extra::{self, LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, NonZeroI256Bits, NonZeroU256Bits, U256Bits,
 };
 use core::hint;
 use core::iter::{Product, Sum};
@@ -573,9 +575,527 @@ fixed_arith! { FixedI32(i32, LeEqU32, 32), Signed }
 fixed_arith! { FixedI64(i64, LeEqU64, 64), Signed }
 fixed_arith! { FixedI128(i128, LeEqU128, 128), Signed }
 
+// There is no primitive 256-bit integer, so the 256-bit fixed-point numbers
+// cannot use fixed_arith!.
+macro_rules! shift256 {
+    (impl {$Imp:ident, $ImpAssign:ident}<$Rhs:ty> for $Fixed:ident { $method:ident, $method_assign:ident }) => {
+        impl<Frac> $Imp<$Rhs> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $method(self, rhs: $Rhs) -> $Fixed<Frac> {
+                // like primitives, panic in debug mode and mask in release mode
+                #[allow(unused_comparisons)]
+                let in_range = rhs >= 0 && (rhs as u128) < 256;
+                debug_assert!(in_range, "shift overflow");
+                self.$method(rhs as u32 & 255)
+            }
+        }
+
+        impl<Frac> $Imp<$Rhs> for &$Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $method(self, rhs: $Rhs) -> $Fixed<Frac> {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl<Frac> $Imp<&$Rhs> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $method(self, rhs: &$Rhs) -> $Fixed<Frac> {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<Frac> $Imp<&$Rhs> for &$Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn $method(self, rhs: &$Rhs) -> $Fixed<Frac> {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl<Frac> $ImpAssign<$Rhs> for $Fixed<Frac> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $Rhs) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl<Frac> $ImpAssign<&$Rhs> for $Fixed<Frac> {
+            #[inline]
+            fn $method_assign(&mut self, rhs: &$Rhs) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    };
+}
+
+macro_rules! fixed256_arith {
+    ($Fixed:ident($Bits:ident, $NonZeroBits:ident), $Signedness:ident) => {
+        if_signed! {
+            $Signedness;
+
+            impl<Frac> Neg for $Fixed<Frac> {
+                type Output = $Fixed<Frac>;
+                #[inline]
+                fn neg(self) -> $Fixed<Frac> {
+                    let (ans, overflow) = self.overflowing_neg();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            impl<Frac> Neg for &$Fixed<Frac> {
+                type Output = $Fixed<Frac>;
+                #[inline]
+                fn neg(self) -> $Fixed<Frac> {
+                    (*self).neg()
+                }
+            }
+        }
+
+        impl<Frac> Add<$Fixed<Frac>> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn add(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_add(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        refs! { impl Add for $Fixed { add } }
+
+        impl<Frac> AddAssign<$Fixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn add_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = (*self).add(rhs);
+            }
+        }
+
+        refs_assign! { impl AddAssign for $Fixed { add_assign } }
+
+        impl<Frac> Sub<$Fixed<Frac>> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn sub(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_sub(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        refs! { impl Sub for $Fixed { sub } }
+
+        impl<Frac> SubAssign<$Fixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = (*self).sub(rhs);
+            }
+        }
+
+        refs_assign! { impl SubAssign for $Fixed { sub_assign } }
+
+        impl<Frac: LeEqU256> Mul<$Fixed<Frac>> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_mul(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        refs! { impl Mul for $Fixed(LeEqU256) { mul } }
+
+        impl<Frac: LeEqU256> MulAssign<$Fixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = (*self).mul(rhs);
+            }
+        }
+
+        refs_assign! { impl MulAssign for $Fixed(LeEqU256) { mul_assign } }
+
+        impl<Frac: LeEqU256> Div<$Fixed<Frac>> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn div(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                let (ans, overflow) = self.overflowing_div(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        refs! { impl Div for $Fixed(LeEqU256) { div } }
+
+        impl<Frac: LeEqU256> DivAssign<$Fixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn div_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = (*self).div(rhs);
+            }
+        }
+
+        refs_assign! { impl DivAssign for $Fixed(LeEqU256) { div_assign } }
+
+        impl<Frac> Rem<$Fixed<Frac>> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn rem(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                self.checked_rem(rhs).expect("division by zero")
+            }
+        }
+
+        refs! { impl Rem for $Fixed { rem } }
+
+        impl<Frac> RemAssign<$Fixed<Frac>> for $Fixed<Frac> {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $Fixed<Frac>) {
+                *self = (*self).rem(rhs);
+            }
+        }
+
+        refs_assign! { impl RemAssign for $Fixed { rem_assign } }
+
+        pass_one! { impl Not for $Fixed { not } }
+        pass! { impl BitAnd for $Fixed { bitand } }
+        pass_assign! { impl BitAndAssign for $Fixed { bitand_assign } }
+        pass! { impl BitOr for $Fixed { bitor } }
+        pass_assign! { impl BitOrAssign for $Fixed { bitor_assign } }
+        pass! { impl BitXor for $Fixed { bitxor } }
+        pass_assign! { impl BitXorAssign for $Fixed { bitxor_assign } }
+
+        impl<Frac> Shl<u32> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn shl(self, rhs: u32) -> $Fixed<Frac> {
+                debug_assert!(rhs < 256, "shift overflow");
+                Self::from_u256(int256::wrapping_shl_u256(self.to_u256(), rhs & 255))
+            }
+        }
+
+        impl<Frac> Shr<u32> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn shr(self, rhs: u32) -> $Fixed<Frac> {
+                debug_assert!(rhs < 256, "shift overflow");
+                let rhs = rhs & 255;
+                let bits = self.to_u256();
+                if Self::IS_SIGNED && bits.hi >> 127 != 0 {
+                    // arithmetic shift: !(!bits >> rhs)
+                    Self::from_u256(!int256::wrapping_shr_u256(!bits, rhs))
+                } else {
+                    Self::from_u256(int256::wrapping_shr_u256(bits, rhs))
+                }
+            }
+        }
+
+        shift256! { impl {Shl, ShlAssign}<i8> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<i16> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<i32> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<i64> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<i128> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<isize> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<u8> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<u16> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<u64> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<u128> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shl, ShlAssign}<usize> for $Fixed { shl, shl_assign } }
+        shift256! { impl {Shr, ShrAssign}<i8> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<i16> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<i32> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<i64> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<i128> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<isize> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<u8> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<u16> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<u64> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<u128> for $Fixed { shr, shr_assign } }
+        shift256! { impl {Shr, ShrAssign}<usize> for $Fixed { shr, shr_assign } }
+
+        impl<Frac> ShlAssign<u32> for $Fixed<Frac> {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = (*self).shl(rhs);
+            }
+        }
+
+        impl<Frac> ShrAssign<u32> for $Fixed<Frac> {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = (*self).shr(rhs);
+            }
+        }
+
+        refs! { impl Shl<u32> for $Fixed { shl } }
+        refs_assign! { impl ShlAssign<u32> for $Fixed { shl_assign } }
+        refs! { impl Shr<u32> for $Fixed { shr } }
+        refs_assign! { impl ShrAssign<u32> for $Fixed { shr_assign } }
+
+        impl<Frac> Mul<$Bits> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Bits) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits().mul(rhs))
+            }
+        }
+
+        refs! { impl Mul<$Bits> for $Fixed { mul } }
+
+        impl<Frac> MulAssign<$Bits> for $Fixed<Frac> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $Bits) {
+                *self = (*self).mul(rhs);
+            }
+        }
+
+        refs_assign! { impl MulAssign<$Bits> for $Fixed { mul_assign } }
+
+        impl<Frac> Mul<$Fixed<Frac>> for $Bits {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                rhs.mul(self)
+            }
+        }
+
+        impl<Frac> Mul<&$Fixed<Frac>> for $Bits {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: &$Fixed<Frac>) -> $Fixed<Frac> {
+                (*rhs).mul(self)
+            }
+        }
+
+        impl<Frac> Mul<$Fixed<Frac>> for &$Bits {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: $Fixed<Frac>) -> $Fixed<Frac> {
+                rhs.mul(*self)
+            }
+        }
+
+        impl<Frac> Mul<&$Fixed<Frac>> for &$Bits {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn mul(self, rhs: &$Fixed<Frac>) -> $Fixed<Frac> {
+                (*rhs).mul(*self)
+            }
+        }
+
+        impl<Frac> Div<$Bits> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn div(self, rhs: $Bits) -> $Fixed<Frac> {
+                Self::from_bits(self.to_bits().div(rhs))
+            }
+        }
+
+        refs! { impl Div<$Bits> for $Fixed { div } }
+
+        impl<Frac> DivAssign<$Bits> for $Fixed<Frac> {
+            #[inline]
+            fn div_assign(&mut self, rhs: $Bits) {
+                *self = (*self).div(rhs);
+            }
+        }
+
+        refs_assign! { impl DivAssign<$Bits> for $Fixed { div_assign } }
+
+        impl<Frac: LeEqU256> Rem<$Bits> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn rem(self, rhs: $Bits) -> $Fixed<Frac> {
+                self.checked_rem_int(rhs).expect("division by zero")
+            }
+        }
+
+        refs! { impl Rem<$Bits> for $Fixed(LeEqU256) { rem } }
+
+        impl<Frac: LeEqU256> RemAssign<$Bits> for $Fixed<Frac> {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $Bits) {
+                *self = (*self).rem(rhs);
+            }
+        }
+
+        refs_assign! { impl RemAssign<$Bits> for $Fixed(LeEqU256) { rem_assign } }
+
+        if_unsigned! {
+            $Signedness;
+
+            impl<Frac> Div<$NonZeroBits> for $Fixed<Frac> {
+                type Output = $Fixed<Frac>;
+                #[inline]
+                fn div(self, rhs: $NonZeroBits) -> $Fixed<Frac> {
+                    Self::from_bits(self.to_bits() / rhs.get())
+                }
+            }
+
+            refs! { impl Div<$NonZeroBits> for $Fixed { div } }
+
+            impl<Frac> DivAssign<$NonZeroBits> for $Fixed<Frac> {
+                #[inline]
+                fn div_assign(&mut self, rhs: $NonZeroBits) {
+                    *self = (*self).div(rhs)
+                }
+            }
+
+            refs_assign! { impl DivAssign<$NonZeroBits> for $Fixed { div_assign } }
+        }
+
+        impl<Frac: LeEqU256> Rem<$NonZeroBits> for $Fixed<Frac> {
+            type Output = $Fixed<Frac>;
+            #[inline]
+            fn rem(self, rhs: $NonZeroBits) -> $Fixed<Frac> {
+                // the remainder is computed on magnitudes, so unlike division
+                // it cannot overflow even for MIN and −1
+                self.unwrapped_rem_int(rhs.get())
+            }
+        }
+
+        refs! { impl Rem<$NonZeroBits> for $Fixed(LeEqU256) { rem } }
+
+        impl<Frac: LeEqU256> RemAssign<$NonZeroBits> for $Fixed<Frac> {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $NonZeroBits) {
+                *self = (*self).rem(rhs)
+            }
+        }
+
+        refs_assign! { impl RemAssign<$NonZeroBits> for $Fixed(LeEqU256) { rem_assign } }
+
+        impl<Frac> Sum<$Fixed<Frac>> for $Fixed<Frac> {
+            fn sum<I>(iter: I) -> $Fixed<Frac>
+            where
+                I: Iterator<Item = $Fixed<Frac>>,
+            {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl<'a, Frac: 'a> Sum<&'a $Fixed<Frac>> for $Fixed<Frac> {
+            fn sum<I>(iter: I) -> $Fixed<Frac>
+            where
+                I: Iterator<Item = &'a $Fixed<Frac>>,
+            {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl<Frac: LeEqU256> Product<$Fixed<Frac>> for $Fixed<Frac> {
+            fn product<I>(mut iter: I) -> $Fixed<Frac>
+            where
+                I: Iterator<Item = $Fixed<Frac>>,
+            {
+                match iter.next() {
+                    None => Self::from_num(1),
+                    Some(first) => iter.fold(first, Mul::mul),
+                }
+            }
+        }
+
+        impl<'a, Frac: 'a + LeEqU256> Product<&'a $Fixed<Frac>> for $Fixed<Frac> {
+            fn product<I>(mut iter: I) -> $Fixed<Frac>
+            where
+                I: Iterator<Item = &'a $Fixed<Frac>>,
+            {
+                match iter.next() {
+                    None => Self::from_num(1),
+                    Some(first) => iter.fold(*first, Mul::mul),
+                }
+            }
+        }
+    };
+}
+
+fixed256_arith! { FixedU256(U256Bits, NonZeroU256Bits), Unsigned }
+fixed256_arith! { FixedI256(I256Bits, NonZeroI256Bits), Signed }
+
+macro_rules! mul_exact {
+    (@impl $Lhs:ident, $Rhs:ident => $Out:ident($OutInner:ident)) => {
+        impl<FracLhs: Add<FracRhs>, FracRhs> MulExact<$Rhs<FracRhs>> for $Lhs<FracLhs> {
+            type Output = $Out<extra::Sum<FracLhs, FracRhs>>;
+            #[inline]
+            fn mul_exact(self, rhs: $Rhs<FracRhs>) -> $Out<extra::Sum<FracLhs, FracRhs>> {
+                let lhs = $OutInner::from(self.to_bits());
+                let rhs = $OutInner::from(rhs.to_bits());
+                $Out::from_bits(lhs * rhs)
+            }
+        }
+    };
+    (@impl $Lhs:ident, $Rhs:ident => FixedU256) => {
+        impl<FracLhs: Add<FracRhs>, FracRhs> MulExact<$Rhs<FracRhs>> for $Lhs<FracLhs> {
+            type Output = FixedU256<extra::Sum<FracLhs, FracRhs>>;
+            #[inline]
+            fn mul_exact(self, rhs: $Rhs<FracRhs>) -> FixedU256<extra::Sum<FracLhs, FracRhs>> {
+                let lhs = u128::from(self.to_bits());
+                let rhs = u128::from(rhs.to_bits());
+                FixedU256::from_u256(int256::wide_mul_u128(lhs, rhs))
+            }
+        }
+    };
+    (@impl $Lhs:ident, $Rhs:ident => FixedI256) => {
+        impl<FracLhs: Add<FracRhs>, FracRhs> MulExact<$Rhs<FracRhs>> for $Lhs<FracLhs> {
+            type Output = FixedI256<extra::Sum<FracLhs, FracRhs>>;
+            #[inline]
+            fn mul_exact(self, rhs: $Rhs<FracRhs>) -> FixedI256<extra::Sum<FracLhs, FracRhs>> {
+                let lhs = i128::from(self.to_bits());
+                let rhs = i128::from(rhs.to_bits());
+                let prod = int256::wide_mul_i128(lhs, rhs);
+                FixedI256::from_u256(U256 {
+                    lo: prod.lo,
+                    hi: prod.hi as u128,
+                })
+            }
+        }
+    };
+    ($Fixed:ident => $($Out:tt)*) => {
+        mul_exact! { @impl $Fixed, $Fixed => $($Out)* }
+    };
+    ($Lhs:ident, $Rhs:ident => $($Out:tt)*) => {
+        mul_exact! { @impl $Lhs, $Rhs => $($Out)* }
+        mul_exact! { @impl $Rhs, $Lhs => $($Out)* }
+    };
+}
+
+mul_exact! { FixedU8 => FixedU16(u16) }
+mul_exact! { FixedU8, FixedU16 => FixedU32(u32) }
+mul_exact! { FixedU8, FixedU32 => FixedU64(u64) }
+mul_exact! { FixedU8, FixedU64 => FixedU128(u128) }
+mul_exact! { FixedU8, FixedU128 => FixedU256 }
+mul_exact! { FixedU16 => FixedU32(u32) }
+mul_exact! { FixedU16, FixedU32 => FixedU64(u64) }
+mul_exact! { FixedU16, FixedU64 => FixedU128(u128) }
+mul_exact! { FixedU16, FixedU128 => FixedU256 }
+mul_exact! { FixedU32 => FixedU64(u64) }
+mul_exact! { FixedU32, FixedU64 => FixedU128(u128) }
+mul_exact! { FixedU32, FixedU128 => FixedU256 }
+mul_exact! { FixedU64 => FixedU128(u128) }
+mul_exact! { FixedU64, FixedU128 => FixedU256 }
+mul_exact! { FixedU128 => FixedU256 }
+mul_exact! { FixedI8 => FixedI16(i16) }
+mul_exact! { FixedI8, FixedI16 => FixedI32(i32) }
+mul_exact! { FixedI8, FixedI32 => FixedI64(i64) }
+mul_exact! { FixedI8, FixedI64 => FixedI128(i128) }
+mul_exact! { FixedI8, FixedI128 => FixedI256 }
+mul_exact! { FixedI16 => FixedI32(i32) }
+mul_exact! { FixedI16, FixedI32 => FixedI64(i64) }
+mul_exact! { FixedI16, FixedI64 => FixedI128(i128) }
+mul_exact! { FixedI16, FixedI128 => FixedI256 }
+mul_exact! { FixedI32 => FixedI64(i64) }
+mul_exact! { FixedI32, FixedI64 => FixedI128(i128) }
+mul_exact! { FixedI32, FixedI128 => FixedI256 }
+mul_exact! { FixedI64 => FixedI128(i128) }
+mul_exact! { FixedI64, FixedI128 => FixedI256 }
+mul_exact! { FixedI128 => FixedI256 }
+
 macro_rules! mul_div_widen {
-    ($Single:ident, $Double:ty, $Signedness:ident, $Unsigned:ty) => {
+    ($Single:ident, $Double:ty, $Signedness:ident, $Unsigned:ty, $UDouble:ty) => {
         pub mod $Single {
+            use crate::rounding::{RoundAbs, RoundingMode};
+
             // 0 <= frac_nbits <= NBITS
             #[inline]
             pub const fn overflowing_mul(
@@ -659,22 +1179,115 @@ macro_rules! mul_div_widen {
                 );
                 (quot, overflow)
             }
+
+            // 0 <= frac_nbits <= NBITS
+            #[inline]
+            pub const fn overflowing_mul_round(
+                lhs: $Single,
+                rhs: $Single,
+                frac_nbits: u32,
+                round: RoundingMode,
+            ) -> ($Single, bool) {
+                // the double-width product is exact
+                let prod2 = lhs as $Double * rhs as $Double;
+                let (neg, abs) = neg_abs(prod2);
+                let abs = shr_round(abs, frac_nbits, round.round_abs(neg));
+                from_neg_abs(neg, abs)
+            }
+
+            // 0 <= frac_nbits <= NBITS
+            #[inline]
+            pub const fn overflowing_div_round(
+                lhs: $Single,
+                rhs: $Single,
+                frac_nbits: u32,
+                round: RoundingMode,
+            ) -> ($Single, bool) {
+                if rhs == 0 {
+                    panic!("division by zero");
+                }
+                let (lhs_neg, lhs_abs) = neg_abs(lhs as $Double);
+                let (rhs_neg, rhs_abs) = neg_abs(rhs as $Double);
+                // |lhs| <= 2^NBITS, so this cannot overflow
+                let lhs_abs = lhs_abs << frac_nbits;
+                let quot = lhs_abs / rhs_abs;
+                let rem = lhs_abs % rhs_abs;
+                // compare rem with rhs_abs / 2 without overflowing
+                let half = rem >= rhs_abs - rem;
+                let lower = if half { rem > rhs_abs - rem } else { rem != 0 };
+                let neg = lhs_neg != rhs_neg;
+                // if rhs_abs > 1, quot < 2^(2 * NBITS - 1); otherwise rem == 0
+                let quot = if round.round_abs(neg).is_up(half, lower, quot & 1 != 0) {
+                    quot + 1
+                } else {
+                    quot
+                };
+                from_neg_abs(neg, quot)
+            }
+
+            #[inline]
+            const fn neg_abs(a: $Double) -> (bool, $UDouble) {
+                if_signed_unsigned!($Signedness, (a < 0, a.unsigned_abs()), (false, a),)
+            }
+
+            // Returns ±abs wrapped, and whether it overflowed.
+            #[inline]
+            const fn from_neg_abs(neg: bool, abs: $UDouble) -> ($Single, bool) {
+                const NBITS: u32 = <$Single>::BITS;
+                if_signed_unsigned!(
+                    $Signedness,
+                    {
+                        let val = if neg {
+                            (abs as $Double).wrapping_neg()
+                        } else {
+                            abs as $Double
+                        };
+                        let max_abs = (1 as $UDouble) << (NBITS - 1);
+                        let overflow = if neg { abs > max_abs } else { abs >= max_abs };
+                        (val as $Single, overflow)
+                    },
+                    {
+                        let _ = neg;
+                        (abs as $Single, abs >> NBITS != 0)
+                    },
+                )
+            }
+
+            // Shifts abs right by shift bits, rounding the magnitude.
+            // 0 <= shift <= NBITS
+            #[inline]
+            const fn shr_round(abs: $UDouble, shift: u32, round: RoundAbs) -> $UDouble {
+                if shift == 0 {
+                    return abs;
+                }
+                let q = abs >> shift;
+                let half = (abs >> (shift - 1)) & 1 != 0;
+                let lower = abs & (((1 as $UDouble) << (shift - 1)) - 1) != 0;
+                // q < 2^(2 * NBITS - 1), so this cannot overflow
+                if round.is_up(half, lower, q & 1 != 0) {
+                    q + 1
+                } else {
+                    q
+                }
+            }
         }
     };
 }
 
-mul_div_widen! { u8, u16, Unsigned, u8 }
-mul_div_widen! { u16, u32, Unsigned, u16 }
-mul_div_widen! { u32, u64, Unsigned, u32 }
-mul_div_widen! { u64, u128, Unsigned, u64 }
-mul_div_widen! { i8, i16, Signed, u8 }
-mul_div_widen! { i16, i32, Signed, u16 }
-mul_div_widen! { i32, i64, Signed, u32 }
-mul_div_widen! { i64, i128, Signed, u64 }
+mul_div_widen! { u8, u16, Unsigned, u8, u16 }
+mul_div_widen! { u16, u32, Unsigned, u16, u32 }
+mul_div_widen! { u32, u64, Unsigned, u32, u64 }
+mul_div_widen! { u64, u128, Unsigned, u64, u128 }
+mul_div_widen! { i8, i16, Signed, u8, u16 }
+mul_div_widen! { i16, i32, Signed, u16, u32 }
+mul_div_widen! { i32, i64, Signed, u32, u64 }
+mul_div_widen! { i64, i128, Signed, u64, u128 }
 
 pub mod u128 {
+    use crate::fixed256;
     use crate::int256;
     use crate::int256::U256;
+    use crate::rounding::{RoundAbs, RoundingMode};
     use core::num::NonZeroU128;
 
     // 0 <= frac_nbits <= NBITS
@@ -745,12 +1358,73 @@ pub mod u128 {
         let overflow = quot2.hi != 0;
         (quot, overflow)
     }
+
+    // 0 <= frac_nbits <= NBITS
+    #[inline]
+    pub const fn overflowing_mul_round(
+        lhs: u128,
+        rhs: u128,
+        frac_nbits: u32,
+        round: RoundingMode,
+    ) -> (u128, bool) {
+        let prod = int256::wide_mul_u128(lhs, rhs);
+        let quot = fixed256::shr_round(prod, frac_nbits, round.round_abs(false));
+        (quot.lo, quot.hi != 0)
+    }
+
+    // 0 <= frac_nbits <= NBITS
+    #[inline]
+    pub const fn overflowing_div_round(
+        lhs: u128,
+        rhs: u128,
+        frac_nbits: u32,
+        round: RoundingMode,
+    ) -> (u128, bool) {
+        let Some(rhs) = NonZeroU128::new(rhs) else {
+            panic!("division by zero");
+        };
+        let quot = div_round_abs(lhs, rhs, frac_nbits, round.round_abs(false));
+        (quot.lo, quot.hi != 0)
+    }
+
+    // Returns (lhs_abs << frac_nbits) / rhs_abs, rounding the magnitude.
+    // 0 <= frac_nbits <= NBITS
+    pub(super) const fn div_round_abs(
+        lhs_abs: u128,
+        rhs_abs: NonZeroU128,
+        frac_nbits: u32,
+        round: RoundAbs,
+    ) -> U256 {
+        let lhs2 = if frac_nbits == 0 {
+            U256 { lo: lhs_abs, hi: 0 }
+        } else if frac_nbits == 128 {
+            U256 { lo: 0, hi: lhs_abs }
+        } else {
+            U256 {
+                lo: lhs_abs << frac_nbits,
+                hi: lhs_abs >> (128 - frac_nbits),
+            }
+        };
+        let (quot, rem) = int256::div_rem_u256_u128(lhs2, rhs_abs);
+        // compare rem with rhs_abs / 2 without overflowing
+        let rhs_abs = rhs_abs.get();
+        let half = rem >= rhs_abs - rem;
+        let lower = if half { rem > rhs_abs - rem } else { rem != 0 };
+        // if rhs_abs > 1, quot < 2^255; otherwise rem == 0
+        if round.is_up(half, lower, quot.lo & 1 != 0) {
+            int256::wrapping_add_u256_u128(quot, 1)
+        } else {
+            quot
+        }
+    }
 }
 
 pub mod i128 {
+    use crate::fixed256;
     use crate::int256;
-    use crate::int256::I256;
-    use core::num::NonZeroI128;
+    use crate::int256::{I256, U256};
+    use crate::rounding::RoundingMode;
+    use core::num::{NonZeroI128, NonZeroU128};
 
     // 0 <= frac_nbits <= NBITS
     #[inline]
@@ -833,6 +1507,297 @@ pub mod i128 {
         let overflow = quot2.hi != quot >> 127;
         (quot, overflow)
     }
+
+    // 0 <= frac_nbits <= NBITS
+    #[inline]
+    pub const fn overflowing_mul_round(
+        lhs: i128,
+        rhs: i128,
+        frac_nbits: u32,
+        round: RoundingMode,
+    ) -> (i128, bool) {
+        let neg = (lhs < 0) != (rhs < 0);
+        let prod = int256::wide_mul_u128(lhs.unsigned_abs(), rhs.unsigned_abs());
+        let quot = fixed256::shr_round(prod, frac_nbits, round.round_abs(neg));
+        from_neg_abs(neg, quot)
+    }
+
+    // 0 <= frac_nbits <= NBITS
+    #[inline]
+    pub const fn overflowing_div_round(
+        lhs: i128,
+        rhs: i128,
+        frac_nbits: u32,
+        round: RoundingMode,
+    ) -> (i128, bool) {
+        let Some(rhs_abs) = NonZeroU128::new(rhs.unsigned_abs()) else {
+            panic!("division by zero");
+        };
+        let neg = (lhs < 0) != (rhs < 0);
+        let quot = super::u128::div_round_abs(
+            lhs.unsigned_abs(),
+            rhs_abs,
+            frac_nbits,
+            round.round_abs(neg),
+        );
+        from_neg_abs(neg, quot)
+    }
+
+    // Returns ±abs wrapped, and whether it overflowed.
+    #[inline]
+    const fn from_neg_abs(neg: bool, abs: U256) -> (i128, bool) {
+        let max_abs = 1u128 << 127;
+        if neg {
+            let overflow = abs.hi != 0 || abs.lo > max_abs;
+            (abs.lo.wrapping_neg() as i128, overflow)
+        } else {
+            let overflow = abs.hi != 0 || abs.lo >= max_abs;
+            (abs.lo as i128, overflow)
+        }
+    }
+}
+
+pub mod u256 {
+    use crate::int256;
+    use crate::int256::{U256, U512};
+    use crate::rounding::{RoundAbs, RoundingMode};
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_mul(lhs: U256, rhs: U256, frac_nbits: u32) -> (U256, bool) {
+        let prod = int256::wide_mul_u256(lhs, rhs);
+        let quot = int256::wrapping_shr_u512(prod, frac_nbits);
+        (quot.lo, !int256::is_zero_u256(quot.hi))
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_div(lhs: U256, rhs: U256, frac_nbits: u32) -> (U256, bool) {
+        if int256::is_zero_u256(rhs) {
+            panic!("division by zero");
+        }
+        let lhs2 = U512 {
+            lo: lhs,
+            hi: U256::ZERO,
+        };
+        let lhs2 = int256::wrapping_shl_u512(lhs2, frac_nbits);
+        let (quot, _) = int256::div_rem_u512_u256(lhs2, rhs);
+        (quot.lo, !int256::is_zero_u256(quot.hi))
+    }
+
+    // Returns abs >> sh rounded using round; 0 <= sh <= 256
+    #[inline]
+    pub(super) const fn shr_round(abs: U512, sh: u32, round: RoundAbs) -> U512 {
+        if sh == 0 {
+            return abs;
+        }
+        let quot = int256::wrapping_shr_u512(abs, sh);
+        // 0 < sh <= 256, so the discarded bits are all in abs.lo
+        let rem = int256::wrapping_sub_u256(abs.lo, int256::wrapping_shl_u512(quot, sh).lo);
+        let half = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, sh - 1);
+        let is_half = rem.hi == half.hi && rem.lo == half.lo;
+        let above_half = rem.hi > half.hi || (rem.hi == half.hi && rem.lo >= half.lo);
+        let lower = !int256::is_zero_u256(rem) && !is_half;
+        if round.is_up(above_half, lower, quot.lo.lo & 1 != 0) {
+            int256::inc_u512(quot)
+        } else {
+            quot
+        }
+    }
+
+    // Returns (lhs << frac_nbits) / rhs rounded using round; 0 <= frac_nbits <= 256
+    #[inline]
+    pub(super) const fn div_round(lhs: U256, rhs: U256, frac_nbits: u32, round: RoundAbs) -> U512 {
+        if int256::is_zero_u256(rhs) {
+            panic!("division by zero");
+        }
+        let lhs2 = U512 {
+            lo: lhs,
+            hi: U256::ZERO,
+        };
+        let lhs2 = int256::wrapping_shl_u512(lhs2, frac_nbits);
+        let (quot, rem) = int256::div_rem_u512_u256(lhs2, rhs);
+        if int256::is_zero_u256(rem) {
+            return quot;
+        }
+        // the discarded fraction is rem / rhs, which is compared to one half
+        let diff = int256::wrapping_sub_u256(rhs, rem);
+        let half = rem.hi > diff.hi || (rem.hi == diff.hi && rem.lo >= diff.lo);
+        let lower = rem.hi != diff.hi || rem.lo != diff.lo;
+        if round.is_up(half, lower, quot.lo.lo & 1 != 0) {
+            int256::inc_u512(quot)
+        } else {
+            quot
+        }
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_mul_round(
+        lhs: U256,
+        rhs: U256,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (U256, bool) {
+        let prod = int256::wide_mul_u256(lhs, rhs);
+        let quot = shr_round(prod, frac_nbits, mode.round_abs(false));
+        (quot.lo, !int256::is_zero_u256(quot.hi))
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_div_round(
+        lhs: U256,
+        rhs: U256,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (U256, bool) {
+        let quot = div_round(lhs, rhs, frac_nbits, mode.round_abs(false));
+        (quot.lo, !int256::is_zero_u256(quot.hi))
+    }
+
+    // -256 <= frac_nbits <= 512
+    #[inline]
+    pub const fn overflowing_mul_add(
+        m1: U256,
+        m2: U256,
+        add: U256,
+        frac_nbits: i32,
+    ) -> (U256, bool) {
+        let prod = int256::wide_mul_u256(m1, m2);
+        let (shifted, overflow1) = int256::shift_abs_u512(prod, frac_nbits, false);
+        let (ans, overflow2) = int256::overflowing_add_neg_abs_u256(false, shifted, add);
+        (ans, overflow1 | overflow2)
+    }
+}
+
+pub mod i256 {
+    use super::u256;
+    use crate::int256;
+    use crate::int256::{U256, U512};
+    use crate::rounding::RoundingMode;
+
+    #[inline]
+    const fn neg_abs(a: U256) -> (bool, U256) {
+        if a.hi >> 127 != 0 {
+            (true, int256::wrapping_neg_u256(a))
+        } else {
+            (false, a)
+        }
+    }
+
+    // Returns ±abs wrapped, and whether it overflowed.
+    #[inline]
+    const fn from_neg_abs(neg: bool, abs: U512) -> (U256, bool) {
+        let lo = abs.lo;
+        let fits = if neg {
+            // −2^255 fits
+            lo.hi >> 127 == 0 || (lo.hi == 1 << 127 && lo.lo == 0)
+        } else {
+            lo.hi >> 127 == 0
+        };
+        let ans = if neg {
+            int256::wrapping_neg_u256(lo)
+        } else {
+            lo
+        };
+        (ans, !int256::is_zero_u256(abs.hi) || !fits)
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_mul(lhs: U256, rhs: U256, frac_nbits: u32) -> (U256, bool) {
+        let (lhs_neg, lhs_abs) = neg_abs(lhs);
+        let (rhs_neg, rhs_abs) = neg_abs(rhs);
+        let neg = lhs_neg != rhs_neg;
+        let prod = int256::wide_mul_u256(lhs_abs, rhs_abs);
+        let mut quot = int256::wrapping_shr_u512(prod, frac_nbits);
+        if neg {
+            // the product is rounded towards −∞, so increase the magnitude
+            // if any nonzero bits were discarded
+            let back = int256::wrapping_shl_u512(quot, frac_nbits);
+            let exact = back.lo.lo == prod.lo.lo
+                && back.lo.hi == prod.lo.hi
+                && back.hi.lo == prod.hi.lo
+                && back.hi.hi == prod.hi.hi;
+            if !exact {
+                let (lo, carry) = int256::overflowing_add_u256(quot.lo, U256 { lo: 1, hi: 0 });
+                quot.lo = lo;
+                if carry {
+                    quot.hi = int256::wrapping_add_u256_u128(quot.hi, 1);
+                }
+            }
+        }
+        from_neg_abs(neg, quot)
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_div(lhs: U256, rhs: U256, frac_nbits: u32) -> (U256, bool) {
+        if int256::is_zero_u256(rhs) {
+            panic!("division by zero");
+        }
+        let (lhs_neg, lhs_abs) = neg_abs(lhs);
+        let (rhs_neg, rhs_abs) = neg_abs(rhs);
+        let lhs2 = U512 {
+            lo: lhs_abs,
+            hi: U256::ZERO,
+        };
+        let lhs2 = int256::wrapping_shl_u512(lhs2, frac_nbits);
+        // the quotient is truncated, like for the other signed numbers
+        let (quot, _) = int256::div_rem_u512_u256(lhs2, rhs_abs);
+        from_neg_abs(lhs_neg != rhs_neg, quot)
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_mul_round(
+        lhs: U256,
+        rhs: U256,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (U256, bool) {
+        let (lhs_neg, lhs_abs) = neg_abs(lhs);
+        let (rhs_neg, rhs_abs) = neg_abs(rhs);
+        let neg = lhs_neg != rhs_neg;
+        let prod = int256::wide_mul_u256(lhs_abs, rhs_abs);
+        let quot = u256::shr_round(prod, frac_nbits, mode.round_abs(neg));
+        from_neg_abs(neg, quot)
+    }
+
+    // 0 <= frac_nbits <= 256
+    #[inline]
+    pub const fn overflowing_div_round(
+        lhs: U256,
+        rhs: U256,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> (U256, bool) {
+        let (lhs_neg, lhs_abs) = neg_abs(lhs);
+        let (rhs_neg, rhs_abs) = neg_abs(rhs);
+        let neg = lhs_neg != rhs_neg;
+        let quot = u256::div_round(lhs_abs, rhs_abs, frac_nbits, mode.round_abs(neg));
+        from_neg_abs(neg, quot)
+    }
+
+    // -256 <= frac_nbits <= 512
+    #[inline]
+    pub const fn overflowing_mul_add(
+        m1: U256,
+        m2: U256,
+        add: U256,
+        frac_nbits: i32,
+    ) -> (U256, bool) {
+        let (m1_neg, m1_abs) = neg_abs(m1);
+        let (m2_neg, m2_abs) = neg_abs(m2);
+        let neg = m1_neg != m2_neg;
+        let prod = int256::wide_mul_u256(m1_abs, m2_abs);
+        // the product is rounded towards −∞, so the magnitude of a negative
+        // product is rounded up
+        let (shifted, overflow1) = int256::shift_abs_u512(prod, frac_nbits, neg);
+        let (ans, overflow2) = int256::overflowing_add_neg_abs_i256(neg, shifted, add);
+        (ans, overflow1 | overflow2)
+    }
 }
 
 #[cfg(test)]
@@ -1622,4 +2587,34 @@ mod tests {
             (I0F128::from_bits(-2), true)
         );
     }
+
+    #[test]
+    fn mul_exact() {
+        use crate::types::extra::{U16, U24, U32, U48, U64, U128, U192};
+        use crate::types::{I0F128, I8F8, I16F16, I64F64, U0F128, U32F32};
+
+        let prod: FixedI64<U24> = I16F16::MIN.mul_exact(I8F8::MIN);
+        assert_eq!(prod, 1i64 << 22);
+        let prod: FixedI64<U24> = I8F8::MAX.mul_exact(I16F16::MIN);
+        assert_eq!(prod.to_bits(), i64::from(i16::MAX) * i64::from(i32::MIN));
+        let prod: FixedI32<U16> = I8F8::MIN.mul_exact(I8F8::MIN);
+        assert_eq!(prod, 16384);
+        let prod: FixedU128<U64> = U32F32::MAX.mul_exact(U32F32::MAX);
+        assert_eq!(prod.to_bits(), u128::from(u64::MAX) * u128::from(u64::MAX));
+        let prod: FixedU128<U48> = FixedU64::<U32>::DELTA.mul_exact(FixedU64::<U16>::MAX);
+        assert_eq!(prod.to_bits(), u128::from(u64::MAX));
+
+        // 256-bit products
+        let prod: FixedI256<U128> = I64F64::MIN.mul_exact(I64F64::MIN);
+        assert_eq!(prod, FixedI256::<U128>::from_num(1u128 << 126));
+        let prod: FixedI256<U192> = I0F128::MIN.mul_exact(I64F64::MAX);
+        let max = FixedI256::<U192>::from_num(I64F64::MAX);
+        assert_eq!(prod, -max / FixedI256::from_num(2));
+        let prod: FixedU256<U192> = U0F128::MAX.mul_exact(FixedU64::<U64>::MAX);
+        let lhs = FixedU256::<U192>::from_num(U0F128::MAX);
+        let rhs = FixedU256::<U192>::from_num(FixedU64::<U64>::MAX);
+        assert_eq!(prod, lhs * rhs);
+        let prod: FixedU256<types::extra::U256> = U0F128::MAX.mul_exact(U0F128::MAX);
+        assert!(prod < 1 && prod > U0F128::MAX - U0F128::DELTA * 2);
+    }
 }
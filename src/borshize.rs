@@ -13,9 +13,10 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::int256::U256;
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
 };
 use borsh::io::{Read, Result, Write};
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -48,3 +49,30 @@ borsh_fixed! { FixedU16 is u16 }
 borsh_fixed! { FixedU32 is u32 }
 borsh_fixed! { FixedU64 is u64 }
 borsh_fixed! { FixedU128 is u128 }
+borsh_fixed! { FixedI256 is I256Bits }
+borsh_fixed! { FixedU256 is U256Bits }
+
+// Like the primitive integers, the bits are serialized in little-endian order.
+macro_rules! borsh_bits_256 {
+    ($Bits:ident) => {
+        impl BorshSerialize for $Bits {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                <u128 as BorshSerialize>::serialize(&self.0.lo, writer)?;
+                <u128 as BorshSerialize>::serialize(&self.0.hi, writer)
+            }
+        }
+
+        impl BorshDeserialize for $Bits {
+            #[inline]
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let lo = <u128 as BorshDeserialize>::deserialize_reader(reader)?;
+                let hi = <u128 as BorshDeserialize>::deserialize_reader(reader)?;
+                Ok($Bits(U256 { lo, hi }))
+            }
+        }
+    };
+}
+
+borsh_bits_256! { I256Bits }
+borsh_bits_256! { U256Bits }
@@ -0,0 +1,583 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::traits::{Fixed, FromFixed, ToFixed};
+use az::CastFrom;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A fixed-point number restricted to the range
+/// <code>[MIN](Bounded::min_bound)..=[MAX](Bounded::max_bound)</code>.
+///
+/// The bounds `MIN` and `MAX` are given as the underlying bits of the
+/// fixed-point number, so for example the range 0.0..=1.0 in [`U1F15`] is
+/// <code>Bounded&lt;U1F15, 0, 0x8000></code>. It is a compile-time error for
+/// `MIN` to be greater than `MAX`, or for a bound not to be representable by
+/// `F`. The bounds are checked when the type is used, that is when a bound is
+/// queried or a bounded number is constructed, which includes every arithmetic
+/// operation; merely naming the type, for example in a type alias that is never
+/// used, is not an error.
+///
+/// Since the bounds are [`i128`] values, not every range can be expressed.
+/// For types with 128 bits or more, such as [`U0F128`], the bounds must be
+/// within <code>[i128::MIN]..=[i128::MAX]</code> when taken as bits, so for
+/// example an unsigned 128-bit type cannot have an upper bound with the most
+/// significant bit set. Such bounds are a compile-time error like any other
+/// bound that is out of range.
+///
+/// The underlying value can be retrieved using [`get`][Bounded::get]. It can
+/// only be constructed through methods that check or clamp against the bounds,
+/// so it is always within range. The arithmetic operators clamp the result to
+/// the bounds, while methods such as [`checked_add`][Bounded::checked_add]
+/// return [`None`] when the result is out of range.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::U1F15;
+/// use fixed::Bounded;
+/// type Level = Bounded<U1F15, 0, 0x8000>;
+/// assert_eq!(Level::max_bound(), 1);
+///
+/// let a = Level::checked_from_num(0.75).unwrap();
+/// let b = Level::checked_from_num(0.5).unwrap();
+/// assert!(Level::checked_from_num(1.5).is_none());
+///
+/// // U1F15 can represent 1.25, but the sum is clamped to the declared bound.
+/// assert_eq!((a + b).get(), 1);
+/// assert_eq!(a.checked_add(b), None);
+/// ```
+///
+/// Bounds that do not fit in the fixed-point type are rejected.
+///
+/// ```rust,compile_fail
+/// use fixed::types::U1F15;
+/// use fixed::Bounded;
+/// // 0x1_0000 does not fit in 16 bits.
+/// let _ = Bounded::<U1F15, 0, 0x1_0000>::new_clamped(U1F15::ZERO);
+/// ```
+///
+/// [`U0F128`]: crate::types::U0F128
+/// [`U1F15`]: crate::types::U1F15
+#[repr(transparent)]
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Bounded<F, const MIN: i128, const MAX: i128>(F);
+
+impl<F: Fixed, const MIN: i128, const MAX: i128> Bounded<F, MIN, MAX> {
+    const CHECK_BOUNDS: () = {
+        let nbits = F::INT_NBITS + F::FRAC_NBITS;
+        let (type_min, type_max) = if F::IS_SIGNED {
+            if nbits >= 128 {
+                (i128::MIN, i128::MAX)
+            } else {
+                (-(1 << (nbits - 1)), (1 << (nbits - 1)) - 1)
+            }
+        } else if nbits >= 128 {
+            (0, i128::MAX)
+        } else {
+            (0, (1 << nbits) - 1)
+        };
+        assert!(MIN <= MAX, "MIN must not be greater than MAX");
+        assert!(type_min <= MIN && MAX <= type_max, "bounds out of range");
+    };
+
+    /// Returns the lower bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// assert_eq!(Bounded::<I8F8, -0x0180, 0x0200>::min_bound(), -1.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn min_bound() -> F {
+        let () = Self::CHECK_BOUNDS;
+        F::from_bits(F::Bits::cast_from(MIN))
+    }
+
+    /// Returns the upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// assert_eq!(Bounded::<I8F8, -0x0180, 0x0200>::max_bound(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn max_bound() -> F {
+        let () = Self::CHECK_BOUNDS;
+        F::from_bits(F::Bits::cast_from(MAX))
+    }
+
+    /// Creates a bounded number if `val` is within the bounds, otherwise
+    /// returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::new(I8F8::from_num(1.5)).unwrap().get(), 1.5);
+    /// assert!(B::new(I8F8::from_num(-2)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(val: F) -> Option<Self> {
+        let () = Self::CHECK_BOUNDS;
+        if Self::min_bound() <= val && val <= Self::max_bound() {
+            Some(Bounded(val))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a bounded number, clamping `val` to the bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::new_clamped(I8F8::from_num(1.5)).get(), 1.5);
+    /// assert_eq!(B::new_clamped(I8F8::from_num(-2)).get(), -1.5);
+    /// assert_eq!(B::new_clamped(I8F8::MAX).get(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_clamped(val: F) -> Self {
+        let () = Self::CHECK_BOUNDS;
+        Bounded(val.clamp(Self::min_bound(), Self::max_bound()))
+    }
+
+    /// Returns the underlying fixed-point number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::new_clamped(I8F8::from_num(0.5)).get(), I8F8::from_num(0.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get(self) -> F {
+        self.0
+    }
+
+    /// Creates a bounded number from another number if it is within the
+    /// bounds, otherwise returns [`None`].
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`].
+    ///   * A floating-point number of type
+    ///     <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] is implemented.
+    ///
+    /// See also <code>FixedI32::[checked\_from\_num][FixedI32::checked_from_num]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::checked_from_num(-1.5).unwrap().get(), -1.5);
+    /// assert!(B::checked_from_num(2.5).is_none());
+    /// assert!(B::checked_from_num(300).is_none());
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    /// [FixedI32::checked_from_num]: crate::FixedI32::checked_from_num
+    #[inline]
+    #[must_use]
+    pub fn checked_from_num<Src: ToFixed>(src: Src) -> Option<Self> {
+        Self::new(F::checked_from_num(src)?)
+    }
+
+    /// Creates a bounded number from another number, clamping the value to
+    /// the bounds.
+    ///
+    /// The other number can be any number for which [`ToFixed`] is
+    /// implemented, as in [`checked_from_num`][Bounded::checked_from_num].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::saturating_from_num(2.5).get(), 2);
+    /// assert_eq!(B::saturating_from_num(-300).get(), -1.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_num<Src: ToFixed>(src: Src) -> Self {
+        Self::new_clamped(F::saturating_from_num(src))
+    }
+
+    /// Converts a bounded number to another number.
+    ///
+    /// The other number can be any number for which [`FromFixed`] is
+    /// implemented.
+    ///
+    /// See also <code>FixedI32::[to\_num][FixedI32::to_num]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future it
+    /// panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::saturating_from_num(-1.5).to_num::<f32>(), -1.5);
+    /// ```
+    ///
+    /// [FixedI32::to_num]: crate::FixedI32::to_num
+    #[inline]
+    #[must_use]
+    pub fn to_num<Dst: FromFixed>(self) -> Dst {
+        self.0.to_num()
+    }
+
+    /// Checked addition. Returns the sum, or [`None`] if the sum is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let one = B::saturating_from_num(1);
+    /// assert_eq!(one.checked_add(one).unwrap().get(), 2);
+    /// assert!(one.checked_add(B::saturating_from_num(1.5)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0.checked_add(rhs.0)?)
+    }
+
+    /// Checked subtraction. Returns the difference, or [`None`] if the
+    /// difference is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let one = B::saturating_from_num(1);
+    /// assert_eq!(one.checked_sub(one).unwrap().get(), 0);
+    /// assert!(B::saturating_from_num(-1).checked_sub(one).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0.checked_sub(rhs.0)?)
+    }
+
+    /// Checked multiplication. Returns the product, or [`None`] if the
+    /// product is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let a = B::saturating_from_num(1.25);
+    /// assert_eq!(a.checked_mul(a).unwrap().get(), 1.5625);
+    /// assert!(B::saturating_from_num(-1.5).checked_mul(a).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0.checked_mul(rhs.0)?)
+    }
+
+    /// Checked division. Returns the quotient, or [`None`] if the divisor is
+    /// zero or if the quotient is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let one = B::saturating_from_num(1);
+    /// assert_eq!(one.checked_div(B::saturating_from_num(2)).unwrap().get(), 0.5);
+    /// assert!(one.checked_div(B::saturating_from_num(0.25)).is_none());
+    /// assert!(one.checked_div(B::saturating_from_num(0)).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0.checked_div(rhs.0)?)
+    }
+
+    /// Checked negation. Returns the negated value, or [`None`] if it is out
+    /// of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::saturating_from_num(1.5).checked_neg().unwrap().get(), -1.5);
+    /// assert!(B::saturating_from_num(2).checked_neg().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::new(self.0.checked_neg()?)
+    }
+
+    /// Saturating addition. Returns the sum, clamped to the bounds.
+    ///
+    /// This is the same as the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let a = B::saturating_from_num(1.5);
+    /// assert_eq!(a.saturating_add(a).get(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction. Returns the difference, clamped to the bounds.
+    ///
+    /// This is the same as the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let a = B::saturating_from_num(1.5);
+    /// assert_eq!(a.saturating_sub(a).get(), 0);
+    /// assert_eq!(B::saturating_from_num(-1).saturating_sub(a).get(), -1.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Saturating multiplication. Returns the product, clamped to the bounds.
+    ///
+    /// This is the same as the `*` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let a = B::saturating_from_num(1.5);
+    /// assert_eq!(a.saturating_mul(a).get(), 2);
+    /// assert_eq!(a.saturating_mul(-a).get(), -1.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0.saturating_mul(rhs.0))
+    }
+
+    /// Saturating division. Returns the quotient, clamped to the bounds.
+    ///
+    /// This is the same as the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// let one = B::saturating_from_num(1);
+    /// assert_eq!(one.saturating_div(B::saturating_from_num(0.25)).get(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0.saturating_div(rhs.0))
+    }
+
+    /// Saturating negation. Returns the negated value, clamped to the bounds.
+    ///
+    /// This is the same as the unary `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Bounded;
+    /// type B = Bounded<I8F8, -0x0180, 0x0200>;
+    /// assert_eq!(B::saturating_from_num(2).saturating_neg().get(), -1.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_neg(self) -> Self {
+        Self::new_clamped(self.0.saturating_neg())
+    }
+}
+
+impl<F: Fixed, const MIN: i128, const MAX: i128> Display for Bounded<F, MIN, MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<F: Fixed, const MIN: i128, const MAX: i128> Debug for Bounded<F, MIN, MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+macro_rules! op {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $saturating:ident) => {
+        impl<F: Fixed, const MIN: i128, const MAX: i128> $Op for Bounded<F, MIN, MAX> {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                self.$saturating(rhs)
+            }
+        }
+
+        impl<F: Fixed, const MIN: i128, const MAX: i128> $Op<&Self> for Bounded<F, MIN, MAX> {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: &Self) -> Self {
+                self.$saturating(*rhs)
+            }
+        }
+
+        impl<F: Fixed, const MIN: i128, const MAX: i128> $OpAssign for Bounded<F, MIN, MAX> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$saturating(rhs);
+            }
+        }
+
+        impl<F: Fixed, const MIN: i128, const MAX: i128> $OpAssign<&Self> for Bounded<F, MIN, MAX> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &Self) {
+                *self = self.$saturating(*rhs);
+            }
+        }
+    };
+}
+
+op! { Add add, AddAssign add_assign, saturating_add }
+op! { Sub sub, SubAssign sub_assign, saturating_sub }
+op! { Mul mul, MulAssign mul_assign, saturating_mul }
+op! { Div div, DivAssign div_assign, saturating_div }
+
+impl<F: Fixed, const MIN: i128, const MAX: i128> Neg for Bounded<F, MIN, MAX> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self.saturating_neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Bounded;
+    use crate::types::{I8F8, I128F0, U1F15, U128F0};
+
+    #[test]
+    fn bounds() {
+        type Unit = Bounded<U1F15, 0, 0x8000>;
+        assert_eq!(Unit::min_bound(), 0);
+        assert_eq!(Unit::max_bound(), 1);
+        assert_eq!(Unit::saturating_from_num(U1F15::MAX).get(), 1);
+        assert!(Unit::new(U1F15::from_bits(0x8001)).is_none());
+        assert_eq!(Unit::new(U1F15::ONE).unwrap().get(), 1);
+
+        type Full = Bounded<I128F0, { i128::MIN }, { i128::MAX }>;
+        assert_eq!(Full::min_bound(), I128F0::MIN);
+        assert_eq!(Full::max_bound(), I128F0::MAX);
+
+        type Half = Bounded<U128F0, 0, { i128::MAX }>;
+        assert!(Half::new(U128F0::MAX).is_none());
+        assert_eq!(Half::new_clamped(U128F0::MAX).get(), i128::MAX);
+    }
+
+    #[test]
+    fn arith() {
+        type B = Bounded<I8F8, -0x0180, 0x0200>;
+        for a in -0x0180..=0x0200 {
+            let fa = I8F8::from_bits(a);
+            let ba = B::new(fa).unwrap();
+            assert_eq!(
+                (-ba).get(),
+                fa.saturating_neg().clamp(B::min_bound(), B::max_bound())
+            );
+            for b in (-0x0180..=0x0200).step_by(19) {
+                let fb = I8F8::from_bits(b);
+                let bb = B::new(fb).unwrap();
+                let clamp = |f: I8F8| f.clamp(B::min_bound(), B::max_bound());
+                let within = |f: Option<I8F8>| f.filter(|&f| clamp(f) == f);
+                assert_eq!((ba + bb).get(), clamp(fa.saturating_add(fb)));
+                assert_eq!((ba - bb).get(), clamp(fa.saturating_sub(fb)));
+                assert_eq!((ba * bb).get(), clamp(fa.saturating_mul(fb)));
+                assert_eq!(ba.checked_add(bb).map(B::get), within(fa.checked_add(fb)));
+                assert_eq!(ba.checked_sub(bb).map(B::get), within(fa.checked_sub(fb)));
+                assert_eq!(ba.checked_mul(bb).map(B::get), within(fa.checked_mul(fb)));
+                assert_eq!(ba.checked_div(bb).map(B::get), within(fa.checked_div(fb)));
+                if b != 0 {
+                    assert_eq!((ba / bb).get(), clamp(fa.saturating_div(fb)));
+                }
+            }
+        }
+    }
+}
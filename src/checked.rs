@@ -0,0 +1,2041 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::from_str::ParseFixedError;
+use crate::traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
+use crate::{
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
+};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Provides checked arithmetic operations.
+///
+/// The underlying value is an [`Option`] that can be retrieved through the
+/// `.0` index. When an operation overflows, the result is [`None`], and any
+/// further operation involving a [`None`] value also results in [`None`].
+/// This way a long computation only needs to be checked once at the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed::Checked;
+/// let a = Checked(Some(I16F16::from_num(3)));
+/// let b = Checked(Some(I16F16::from_num(4.5)));
+/// let c = Checked::<I16F16>::from_num(-1);
+/// assert_eq!((a * b + c).0, Some(I16F16::from_num(12.5)));
+///
+/// // The overflow in max + a is not lost when subtracting a afterwards.
+/// let max = Checked(Some(I16F16::MAX));
+/// assert_eq!((max + a - a).0, None);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Checked<F>(pub Option<F>);
+
+impl<F: Fixed> Checked<F> {
+    /// Zero.
+    ///
+    /// See also <code>FixedI32::[ZERO][FixedI32::ZERO]</code> and
+    /// <code>FixedU32::[ZERO][FixedU32::ZERO]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO, Checked(Some(I16F16::ZERO)));
+    /// ```
+    pub const ZERO: Checked<F> = Checked(Some(F::ZERO));
+
+    /// The difference between any two successive representable numbers, <i>Δ</i>.
+    ///
+    /// See also <code>FixedI32::[DELTA][FixedI32::DELTA]</code> and
+    /// <code>FixedU32::[DELTA][FixedU32::DELTA]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::DELTA, Checked(Some(I16F16::DELTA)));
+    /// ```
+    pub const DELTA: Checked<F> = Checked(Some(F::DELTA));
+
+    /// The smallest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MIN][FixedI32::MIN]</code> and
+    /// <code>FixedU32::[MIN][FixedU32::MIN]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::MIN, Checked(Some(I16F16::MIN)));
+    /// ```
+    pub const MIN: Checked<F> = Checked(Some(F::MIN));
+
+    /// The largest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MAX][FixedI32::MAX]</code> and
+    /// <code>FixedU32::[MAX][FixedU32::MAX]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::MAX, Checked(Some(I16F16::MAX)));
+    /// ```
+    pub const MAX: Checked<F> = Checked(Some(F::MAX));
+
+    /// [`true`] if the type is signed.
+    ///
+    /// See also <code>FixedI32::[IS\_SIGNED][FixedI32::IS_SIGNED]</code> and
+    /// <code>FixedU32::[IS\_SIGNED][FixedU32::IS_SIGNED]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Checked;
+    /// assert!(Checked::<I16F16>::IS_SIGNED);
+    /// assert!(!Checked::<U16F16>::IS_SIGNED);
+    /// ```
+    pub const IS_SIGNED: bool = F::IS_SIGNED;
+
+    /// The number of integer bits.
+    ///
+    /// See also <code>FixedI32::[INT\_NBITS][FixedI32::INT_NBITS]</code> and
+    /// <code>FixedU32::[INT\_NBITS][FixedU32::INT_NBITS]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::INT_NBITS, I16F16::INT_NBITS);
+    /// ```
+    pub const INT_NBITS: u32 = F::INT_NBITS;
+
+    /// The number of fractional bits.
+    ///
+    /// See also <code>FixedI32::[FRAC\_NBITS][FixedI32::FRAC_NBITS]</code> and
+    /// <code>FixedU32::[FRAC\_NBITS][FixedU32::FRAC_NBITS]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::FRAC_NBITS, I16F16::FRAC_NBITS);
+    /// ```
+    pub const FRAC_NBITS: u32 = F::FRAC_NBITS;
+
+    /// Returns [`true`] if an operation has overflowed, that is if the
+    /// value is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let one = Checked::<I16F16>::from_num(1);
+    /// assert!(!one.is_overflowed());
+    /// assert!((Checked::<I16F16>::MAX + one).is_overflowed());
+    /// ```
+    #[inline]
+    pub fn is_overflowed(self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Creates a fixed-point number that has a bitwise representation
+    /// identical to the given integer.
+    ///
+    /// See also <code>FixedI32::[from\_bits][FixedI32::from_bits]</code> and
+    /// <code>FixedU32::[from\_bits][FixedU32::from_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::from_bits(0x1C), Checked(Some(I16F16::from_bits(0x1C))));
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: F::Bits) -> Checked<F> {
+        Checked(Some(F::from_bits(bits)))
+    }
+
+    /// Creates an integer that has a bitwise representation identical
+    /// to the given fixed-point number.
+    ///
+    /// Returns [`None`] if the value is [`None`].
+    ///
+    /// See also <code>FixedI32::[to\_bits][FixedI32::to_bits]</code> and
+    /// <code>FixedU32::[to\_bits][FixedU32::to_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let c = Checked(Some(I16F16::from_bits(0x1C)));
+    /// assert_eq!(c.to_bits(), Some(0x1C));
+    /// ```
+    #[inline]
+    pub fn to_bits(self) -> Option<F::Bits> {
+        self.0.map(F::to_bits)
+    }
+
+    /// Creates a fixed-point number from another number.
+    ///
+    /// Returns the same value as
+    /// <code>[Checked]\(src.[checked\_to\_fixed][ToFixed::checked_to_fixed]\())</code>.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`].
+    ///   * A floating-point number of type
+    ///     <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] is implemented.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num][FixedI32::checked_from_num]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num][FixedU32::checked_from_num]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I4F4, I16F16};
+    /// use fixed::Checked;
+    /// let src = I16F16::from_num(1.75);
+    /// let dst = Checked::<I4F4>::from_num(src);
+    /// assert_eq!(dst, Checked(Some(I4F4::from_num(1.75))));
+    ///
+    /// // 0x1234.5678 does not fit in I4F4
+    /// let too_large = I16F16::from_bits(0x1234_5678);
+    /// assert_eq!(Checked::<I4F4>::from_num(too_large).0, None);
+    ///
+    /// // NaN cannot be converted
+    /// assert_eq!(Checked::<I4F4>::from_num(f32::NAN).0, None);
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    #[inline]
+    pub fn from_num<Src: ToFixed>(src: Src) -> Checked<F> {
+        Checked(src.checked_to_fixed())
+    }
+
+    /// Converts a fixed-point number to another number.
+    ///
+    /// Returns [`None`] if the value is [`None`] or if it does not fit in
+    /// the destination, that is the same value as
+    /// <code>self.0.[and\_then][Option::and_then]\(Dst::[checked\_from\_fixed][FromFixed::checked_from_fixed])</code>.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`]. Any fractional bits are discarded, which rounds
+    ///     towards &minus;∞.
+    ///   * A floating-point number of type
+    ///     <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other type `Dst` for which [`FromFixed`] is implemented.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_to\_num][FixedI32::checked_to_num]</code>
+    /// and
+    /// <code>FixedU32::[checked\_to\_num][FixedU32::checked_to_num]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, I2F6, I4F4};
+    /// use fixed::Checked;
+    /// let src = Checked(Some(I4F4::from_num(1.75)));
+    /// assert_eq!(src.to_num::<I16F16>(), Some(I16F16::from_num(1.75)));
+    ///
+    /// let max = Checked(Some(I4F4::MAX));
+    /// assert_eq!(max.to_num::<I2F6>(), None);
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    #[inline]
+    pub fn to_num<Dst: FromFixed>(self) -> Option<Dst> {
+        self.0.and_then(Dst::checked_from_fixed)
+    }
+
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// A value that does not fit results in <code>[Ok]\([Checked]\([None]))</code>,
+    /// while a parsing error results in [`Err`].
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str][FixedI32::overflowing_from_str]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str][FixedU32::overflowing_from_str]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Checked;
+    /// // 16 + 3/4 = 16.75
+    /// let check = Checked(Some(I8F8::from_bits((16 << 8) + (3 << 8) / 4)));
+    /// assert_eq!(Checked::<I8F8>::from_str_dec("16.75"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_dec("1000"), Ok(Checked(None)));
+    /// assert!(Checked::<I8F8>::from_str_dec("1.2.").is_err());
+    /// ```
+    #[inline]
+    pub fn from_str_dec(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str(src).map(overflowing_to_checked)
+    }
+
+    /// Parses a string slice containing binary digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// A value that does not fit results in <code>[Ok]\([Checked]\([None]))</code>,
+    /// while a parsing error results in [`Err`].
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_binary][FixedI32::overflowing_from_str_binary]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_binary][FixedU32::overflowing_from_str_binary]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Checked;
+    /// let check = Checked(Some(I8F8::from_bits(0b1110001 << (8 - 1))));
+    /// assert_eq!(Checked::<I8F8>::from_str_binary("111000.1"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_binary("100000000"), Ok(Checked(None)));
+    /// ```
+    #[inline]
+    pub fn from_str_binary(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_binary(src).map(overflowing_to_checked)
+    }
+
+    /// Parses a string slice containing octal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// A value that does not fit results in <code>[Ok]\([Checked]\([None]))</code>,
+    /// while a parsing error results in [`Err`].
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_octal][FixedI32::overflowing_from_str_octal]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_octal][FixedU32::overflowing_from_str_octal]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Checked;
+    /// let check = Checked(Some(I8F8::from_bits(0o1654 << (8 - 3))));
+    /// assert_eq!(Checked::<I8F8>::from_str_octal("165.4"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_octal("400"), Ok(Checked(None)));
+    /// ```
+    #[inline]
+    pub fn from_str_octal(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_octal(src).map(overflowing_to_checked)
+    }
+
+    /// Parses a string slice containing hexadecimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// A value that does not fit results in <code>[Ok]\([Checked]\([None]))</code>,
+    /// while a parsing error results in [`Err`].
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_hex][FixedI32::overflowing_from_str_hex]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_hex][FixedU32::overflowing_from_str_hex]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Checked;
+    /// let check = Checked(Some(I8F8::from_bits(0xFFE)));
+    /// assert_eq!(Checked::<I8F8>::from_str_hex("F.FE"), Ok(check));
+    /// assert_eq!(Checked::<I8F8>::from_str_hex("100"), Ok(Checked(None)));
+    /// ```
+    #[inline]
+    pub fn from_str_hex(src: &str) -> Result<Checked<F>, ParseFixedError> {
+        F::overflowing_from_str_hex(src).map(overflowing_to_checked)
+    }
+
+    /// Returns the integer part.
+    ///
+    /// This never overflows, so it only returns a [`None`] value if an
+    /// operand is already [`None`].
+    ///
+    /// See also <code>FixedI32::[int][FixedI32::int]</code> and
+    /// <code>FixedU32::[int][FixedU32::int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(12.25)));
+    /// assert_eq!(x.int().0, Some(I16F16::from_num(12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn int(self) -> Checked<F> {
+        Checked(self.0.map(F::int))
+    }
+
+    /// Returns the fractional part.
+    ///
+    /// This never overflows, so it only returns a [`None`] value if an
+    /// operand is already [`None`].
+    ///
+    /// See also <code>FixedI32::[frac][FixedI32::frac]</code> and
+    /// <code>FixedU32::[frac][FixedU32::frac]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(12.25)));
+    /// assert_eq!(x.frac().0, Some(I16F16::from_num(0.25)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn frac(self) -> Checked<F> {
+        Checked(self.0.map(F::frac))
+    }
+
+    /// Rounds to the next integer towards 0.
+    ///
+    /// This never overflows, so it only returns a [`None`] value if an
+    /// operand is already [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_to\_zero][FixedI32::round_to_zero]</code> and
+    /// <code>FixedU32::[round\_to\_zero][FixedU32::round_to_zero]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(-12.75)));
+    /// assert_eq!(x.round_to_zero().0, Some(I16F16::from_num(-12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_to_zero(self) -> Checked<F> {
+        Checked(self.0.map(F::round_to_zero))
+    }
+
+    /// Rounds to the next integer towards +∞.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_ceil][FixedI32::checked_ceil]</code> and
+    /// <code>FixedU32::[checked\_ceil][FixedU32::checked_ceil]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(12.25)));
+    /// assert_eq!(x.ceil().0, Some(I16F16::from_num(13)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).ceil().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ceil(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_ceil))
+    }
+
+    /// Rounds to the next integer towards −∞.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_floor][FixedI32::checked_floor]</code> and
+    /// <code>FixedU32::[checked\_floor][FixedU32::checked_floor]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(12.75)));
+    /// assert_eq!(x.floor().0, Some(I16F16::from_num(12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn floor(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_floor))
+    }
+
+    /// Rounds to the nearest integer, with ties rounded away from zero.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_round][FixedI32::checked_round]</code> and
+    /// <code>FixedU32::[checked\_round][FixedU32::checked_round]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(12.5)));
+    /// assert_eq!(x.round().0, Some(I16F16::from_num(13)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).round().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_round))
+    }
+
+    /// Rounds to the nearest integer, with ties rounded to even.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_round\_ties\_even][FixedI32::checked_round_ties_even]</code> and
+    /// <code>FixedU32::[checked\_round\_ties\_even][FixedU32::checked_round_ties_even]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(12.5))).round_ties_even().0, Some(I16F16::from_num(12)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(13.5))).round_ties_even().0, Some(I16F16::from_num(14)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_ties_even(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_round_ties_even))
+    }
+
+    /// Returns the square root.
+    ///
+    /// Returns a [`None`] value if the number is negative, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sqrt][FixedI32::checked_sqrt]</code> and
+    /// <code>FixedU32::[checked\_sqrt][FixedU32::checked_sqrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(6.25))).sqrt().0, Some(I16F16::from_num(2.5)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(-1))).sqrt().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_sqrt))
+    }
+
+    /// Returns the reciprocal of the square root.
+    ///
+    /// Returns a [`None`] value if the number is not positive, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rsqrt][FixedI32::checked_rsqrt]</code> and
+    /// <code>FixedU32::[checked\_rsqrt][FixedU32::checked_rsqrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(4))).rsqrt().0, Some(I16F16::from_num(0.5)));
+    /// assert_eq!(Checked::<I16F16>::ZERO.rsqrt().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rsqrt(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_rsqrt))
+    }
+
+    /// Returns 2 raised to the power of the number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp2][FixedI32::checked_exp2]</code> and
+    /// <code>FixedU32::[checked\_exp2][FixedU32::checked_exp2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(3))).exp2().0, Some(I16F16::from_num(8)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(20))).exp2().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_exp2))
+    }
+
+    /// Returns <i>e</i> raised to the power of the number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp][FixedI32::checked_exp]</code> and
+    /// <code>FixedU32::[checked\_exp][FixedU32::checked_exp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(0))).exp().0, Some(I16F16::ONE));
+    /// assert_eq!(Checked(Some(I16F16::from_num(20))).exp().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_exp))
+    }
+
+    /// Returns <i>e</i> raised to the power of the number, minus one.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp\_m1][FixedI32::checked_exp_m1]</code> and
+    /// <code>FixedU32::[checked\_exp\_m1][FixedU32::checked_exp_m1]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(0))).exp_m1().0, Some(I16F16::ZERO));
+    /// assert_eq!(Checked(Some(I16F16::from_num(20))).exp_m1().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp_m1(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_exp_m1))
+    }
+
+    /// Returns the base-2 logarithm of the number.
+    ///
+    /// Returns a [`None`] value if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log2][FixedI32::checked_log2]</code> and
+    /// <code>FixedU32::[checked\_log2][FixedU32::checked_log2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(8))).log2().0, Some(I16F16::from_num(3)));
+    /// assert_eq!(Checked::<I16F16>::ZERO.log2().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn log2(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_log2))
+    }
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// Returns a [`None`] value if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_ln][FixedI32::checked_ln]</code> and
+    /// <code>FixedU32::[checked\_ln][FixedU32::checked_ln]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::ONE)).ln().0, Some(I16F16::ZERO));
+    /// assert_eq!(Checked::<I16F16>::ZERO.ln().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ln(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_ln))
+    }
+
+    /// Returns the base-10 logarithm of the number.
+    ///
+    /// Returns a [`None`] value if the number is ≤&nbsp;0 or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log10][FixedI32::checked_log10]</code> and
+    /// <code>FixedU32::[checked\_log10][FixedU32::checked_log10]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(100))).log10().0, Some(I16F16::from_num(2)));
+    /// assert_eq!(Checked::<I16F16>::ZERO.log10().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn log10(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_log10))
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// Returns a [`None`] value on overflow, or if raising zero to a negative power.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powi][FixedI32::checked_powi]</code> and
+    /// <code>FixedU32::[checked\_powi][FixedU32::checked_powi]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(1.5))).powi(3).0, Some(I16F16::from_num(3.375)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).powi(2).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn powi(self, n: i32) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_powi(n)))
+    }
+
+    /// Raises the number to a fixed-point power.
+    ///
+    /// Returns a [`None`] value on overflow, or if the power is not defined.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powf][FixedI32::checked_powf]</code> and
+    /// <code>FixedU32::[checked\_powf][FixedU32::checked_powf]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let half = Checked(Some(I16F16::from_num(0.5)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(4))).powf(half).0, Some(I16F16::from_num(2)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).powf(Checked(Some(I16F16::from_num(2)))).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn powf(self, y: Checked<F>) -> Checked<F> {
+        Checked(self.0.zip(y.0).and_then(|(x, y)| x.checked_powf(y)))
+    }
+
+    /// Returns the cube root.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cbrt][FixedI32::checked_cbrt]</code> and
+    /// <code>FixedU32::[checked\_cbrt][FixedU32::checked_cbrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(-3.375))).cbrt().0, Some(I16F16::from_num(-1.5)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cbrt(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_cbrt))
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// Returns a [`None`] value if <i>n</i> is zero, if the root of a negative number is not real, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_nth\_root][FixedI32::checked_nth_root]</code> and
+    /// <code>FixedU32::[checked\_nth\_root][FixedU32::checked_nth_root]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(5.0625))).nth_root(4).0, Some(I16F16::from_num(1.5)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(4))).nth_root(0).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn nth_root(self, n: u32) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_nth_root(n)))
+    }
+
+    /// Returns the reciprocal (inverse) of the fixed-point number, 1/`self`.
+    ///
+    /// Returns a [`None`] value on division by zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_recip][FixedI32::checked_recip]</code> and
+    /// <code>FixedU32::[checked\_recip][FixedU32::checked_recip]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(4))).recip().0, Some(I16F16::from_num(0.25)));
+    /// assert_eq!(Checked::<I16F16>::ZERO.recip().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_recip))
+    }
+
+    /// Returns the distance from `self` to `other`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_dist][FixedI32::checked_dist]</code> and
+    /// <code>FixedU32::[checked\_dist][FixedU32::checked_dist]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(3))).dist(Checked(Some(I16F16::from_num(-2)))).0, Some(I16F16::from_num(5)));
+    /// assert_eq!(Checked(Some(I16F16::MIN)).dist(Checked(Some(I16F16::MAX))).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dist(self, other: Checked<F>) -> Checked<F> {
+        Checked(
+            self.0
+                .zip(other.0)
+                .and_then(|(x, other)| x.checked_dist(other)),
+        )
+    }
+
+    /// Returns the mean of `self` and `other`.
+    ///
+    /// This never overflows, so it only returns a [`None`] value if an
+    /// operand is already [`None`].
+    ///
+    /// See also <code>FixedI32::[mean][FixedI32::mean]</code> and
+    /// <code>FixedU32::[mean][FixedU32::mean]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(3))).mean(Checked(Some(I16F16::from_num(4)))).0, Some(I16F16::from_num(3.5)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mean(self, other: Checked<F>) -> Checked<F> {
+        Checked(self.0.zip(other.0).map(|(x, other)| x.mean(other)))
+    }
+
+    /// Compute the hypotenuse of a right triangle.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_hypot][FixedI32::checked_hypot]</code> and
+    /// <code>FixedU32::[checked\_hypot][FixedU32::checked_hypot]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(3))).hypot(Checked(Some(I16F16::from_num(4)))).0, Some(I16F16::from_num(5)));
+    /// let max = Checked(Some(I16F16::MAX));
+    /// assert_eq!(max.hypot(max).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hypot(self, other: Checked<F>) -> Checked<F> {
+        Checked(
+            self.0
+                .zip(other.0)
+                .and_then(|(x, other)| x.checked_hypot(other)),
+        )
+    }
+
+    /// Returns the next multiple of `other`.
+    ///
+    /// Returns a [`None`] value if `other` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_next\_multiple\_of][FixedI32::checked_next_multiple_of]</code> and
+    /// <code>FixedU32::[checked\_next\_multiple\_of][FixedU32::checked_next_multiple_of]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(5)));
+    /// assert_eq!(x.next_multiple_of(Checked(Some(I16F16::from_num(1.5)))).0, Some(I16F16::from_num(6)));
+    /// assert_eq!(x.next_multiple_of(Checked::ZERO).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_multiple_of(self, other: Checked<F>) -> Checked<F> {
+        Checked(
+            self.0
+                .zip(other.0)
+                .and_then(|(x, other)| x.checked_next_multiple_of(other)),
+        )
+    }
+
+    /// Multiply and add. Returns `self` × `mul` + `add`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_add][FixedI32::checked_mul_add]</code> and
+    /// <code>FixedU32::[checked\_mul\_add][FixedU32::checked_mul_add]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(3)));
+    /// assert_eq!(x.mul_add(Checked(Some(I16F16::from_num(2))), Checked(Some(I16F16::from_num(0.5)))).0, Some(I16F16::from_num(6.5)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).mul_add(Checked(Some(I16F16::from_num(2))), Checked::ZERO).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, mul: Checked<F>, add: Checked<F>) -> Checked<F> {
+        match (self.0, mul.0, add.0) {
+            (Some(x), Some(mul), Some(add)) => Checked(x.checked_mul_add(mul, add)),
+            _ => Checked(None),
+        }
+    }
+
+    /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_add\_prod][FixedI32::checked_add_prod]</code> and
+    /// <code>FixedU32::[checked\_add\_prod][FixedU32::checked_add_prod]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(0.5)));
+    /// assert_eq!(x.add_prod(Checked(Some(I16F16::from_num(3))), Checked(Some(I16F16::from_num(2)))).0, Some(I16F16::from_num(6.5)));
+    /// let max = Checked(Some(I16F16::MAX));
+    /// assert_eq!(max.add_prod(Checked(Some(I16F16::from_num(1))), Checked::DELTA).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_prod(self, a: Checked<F>, b: Checked<F>) -> Checked<F> {
+        match (self.0, a.0, b.0) {
+            (Some(x), Some(a), Some(b)) => Checked(x.checked_add_prod(a, b)),
+            _ => Checked(None),
+        }
+    }
+
+    /// Euclidean division.
+    ///
+    /// Returns a [`None`] value if the divisor is zero, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_euclid][FixedI32::checked_div_euclid]</code> and
+    /// <code>FixedU32::[checked\_div\_euclid][FixedU32::checked_div_euclid]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(7.5)));
+    /// assert_eq!(x.div_euclid(Checked(Some(I16F16::from_num(2)))).0, Some(I16F16::from_num(3)));
+    /// assert_eq!(x.div_euclid(Checked::ZERO).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn div_euclid(self, rhs: Checked<F>) -> Checked<F> {
+        Checked(
+            self.0
+                .zip(rhs.0)
+                .and_then(|(x, rhs)| x.checked_div_euclid(rhs)),
+        )
+    }
+
+    /// Remainder for Euclidean division.
+    ///
+    /// Returns a [`None`] value if the divisor is zero.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rem\_euclid][FixedI32::checked_rem_euclid]</code> and
+    /// <code>FixedU32::[checked\_rem\_euclid][FixedU32::checked_rem_euclid]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(7.5)));
+    /// assert_eq!(x.rem_euclid(Checked(Some(I16F16::from_num(2)))).0, Some(I16F16::from_num(1.5)));
+    /// assert_eq!(x.rem_euclid(Checked::ZERO).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Checked<F>) -> Checked<F> {
+        Checked(
+            self.0
+                .zip(rhs.0)
+                .and_then(|(x, rhs)| x.checked_rem_euclid(rhs)),
+        )
+    }
+
+    /// Euclidean division by an integer.
+    ///
+    /// Returns a [`None`] value if the divisor is zero, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_euclid\_int][FixedI32::checked_div_euclid_int]</code> and
+    /// <code>FixedU32::[checked\_div\_euclid\_int][FixedU32::checked_div_euclid_int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(7.5)));
+    /// assert_eq!(x.div_euclid_int(2).0, Some(I16F16::from_num(3)));
+    /// assert_eq!(x.div_euclid_int(0).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn div_euclid_int(self, rhs: F::Bits) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_div_euclid_int(rhs)))
+    }
+
+    /// Remainder for Euclidean division by an integer.
+    ///
+    /// Returns a [`None`] value if the divisor is zero, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rem\_euclid\_int][FixedI32::checked_rem_euclid_int]</code> and
+    /// <code>FixedU32::[checked\_rem\_euclid\_int][FixedU32::checked_rem_euclid_int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(7.5)));
+    /// assert_eq!(x.rem_euclid_int(2).0, Some(I16F16::from_num(1.5)));
+    /// assert_eq!(x.rem_euclid_int(0).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rem_euclid_int(self, rhs: F::Bits) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_rem_euclid_int(rhs)))
+    }
+
+    /// Linear interpolation between `start` and `end`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_lerp][FixedI32::checked_lerp]</code> and
+    /// <code>FixedU32::[checked\_lerp][FixedU32::checked_lerp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(0.5)));
+    /// assert_eq!(x.lerp(Checked(Some(I16F16::from_num(2))), Checked(Some(I16F16::from_num(4)))).0, Some(I16F16::from_num(3)));
+    /// assert_eq!(Checked(Some(I16F16::from_num(2))).lerp(Checked::ZERO, Checked(Some(I16F16::MAX))).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, start: Checked<F>, end: Checked<F>) -> Checked<F> {
+        match (self.0, start.0, end.0) {
+            (Some(x), Some(start), Some(end)) => Checked(x.checked_lerp(start, end)),
+            _ => Checked(None),
+        }
+    }
+
+    /// Inverse linear interpolation between `start` and `end`.
+    ///
+    /// Returns a [`None`] value when `start`&nbsp;=&nbsp;`end` or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_inv\_lerp][FixedI32::checked_inv_lerp]</code> and
+    /// <code>FixedU32::[checked\_inv\_lerp][FixedU32::checked_inv_lerp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(3)));
+    /// assert_eq!(x.inv_lerp(Checked(Some(I16F16::from_num(2))), Checked(Some(I16F16::from_num(4)))).0, Some(I16F16::from_num(0.5)));
+    /// assert_eq!(x.inv_lerp(Checked(Some(I16F16::from_num(2))), Checked(Some(I16F16::from_num(2)))).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn inv_lerp(self, start: Checked<F>, end: Checked<F>) -> Checked<F> {
+        match (self.0, start.0, end.0) {
+            (Some(x), Some(start), Some(end)) => Checked(x.checked_inv_lerp(start, end)),
+            _ => Checked(None),
+        }
+    }
+
+    /// Multiply and accumulate. Adds (`a` × `b`) to `self`.
+    ///
+    /// `self` is set to [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_acc][FixedI32::checked_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_acc][FixedU32::checked_mul_acc]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let mut acc = Checked(Some(I16F16::from_num(0.5)));
+    /// acc.mul_acc(Checked(Some(I16F16::from_num(3))), Checked(Some(I16F16::from_num(2))));
+    /// assert_eq!(acc.0, Some(I16F16::from_num(6.5)));
+    ///
+    /// acc = Checked::MAX;
+    /// acc.mul_acc(Checked::MAX, Checked(Some(I16F16::from_num(3))));
+    /// assert_eq!(acc.0, None);
+    /// ```
+    #[inline]
+    pub fn mul_acc(&mut self, a: Checked<F>, b: Checked<F>) {
+        self.0 = match (self.0, a.0, b.0) {
+            (Some(mut acc), Some(a), Some(b)) => acc.checked_mul_acc(a, b).map(|()| acc),
+            _ => None,
+        };
+    }
+}
+
+impl<F: FixedSigned> Checked<F> {
+    /// Returns the absolute value.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_abs][FixedI32::checked_abs]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(-5))).abs().0, Some(I16F16::from_num(5)));
+    /// assert_eq!(Checked(Some(I16F16::MIN)).abs().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_abs))
+    }
+
+    /// Returns a number representing the sign of `self`.
+    ///
+    /// Returns a [`None`] value on overflow, which can only happen if the value 1 or −1 cannot be represented.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_signum][FixedI32::checked_signum]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I0F16, I16F16};
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(-5))).signum().0, Some(I16F16::NEG_ONE));
+    /// assert_eq!(Checked::<I16F16>::ZERO.signum().0, Some(I16F16::ZERO));
+    /// let half = Checked(Some(I0F16::from_num(0.25)));
+    /// assert_eq!(half.signum().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn signum(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_signum))
+    }
+
+    /// Addition with an unsigned fixed-point number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_add\_unsigned][FixedI32::checked_add_unsigned]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(-3)));
+    /// assert_eq!(x.add_unsigned(U16F16::from_num(5)).0, Some(I16F16::from_num(2)));
+    /// assert_eq!(Checked(Some(I16F16::MAX)).add_unsigned(U16F16::DELTA).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_unsigned(self, rhs: F::Unsigned) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_add_unsigned(rhs)))
+    }
+
+    /// Subtraction with an unsigned fixed-point number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sub\_unsigned][FixedI32::checked_sub_unsigned]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Checked;
+    /// let x = Checked(Some(I16F16::from_num(3)));
+    /// assert_eq!(x.sub_unsigned(U16F16::from_num(5)).0, Some(I16F16::from_num(-2)));
+    /// assert_eq!(Checked(Some(I16F16::MIN)).sub_unsigned(U16F16::DELTA).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sub_unsigned(self, rhs: F::Unsigned) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_sub_unsigned(rhs)))
+    }
+
+    /// Computes the sine of `self`, where `self` is in radians.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_sin][FixedI32::checked_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO.sin().0, Some(I16F16::ZERO));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_sin))
+    }
+
+    /// Computes the cosine of `self`, where `self` is in radians.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_cos][FixedI32::checked_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO.cos().0, Some(I16F16::ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_cos))
+    }
+
+    /// Computes the inverse sine of `self`, with the result in radians.
+    ///
+    /// Returns a [`None`] value if the number is outside the range −1&nbsp;≤&nbsp;`self`&nbsp;≤&nbsp;1, or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_asin][FixedI32::checked_asin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO.asin().0, Some(I16F16::ZERO));
+    /// assert_eq!(Checked(Some(I16F16::from_num(2))).asin().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_asin))
+    }
+
+    /// Computes the inverse cosine of `self`, with the result in radians.
+    ///
+    /// Returns a [`None`] value if the number is outside the range −1&nbsp;≤&nbsp;`self`&nbsp;≤&nbsp;1, or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_acos][FixedI32::checked_acos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(I16F16::from_num(1))).acos().0, Some(I16F16::ZERO));
+    /// assert_eq!(Checked(Some(I16F16::from_num(2))).acos().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn acos(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_acos))
+    }
+
+    /// Computes the arctangent of `self`, with the result in radians.
+    ///
+    /// The result is an angle of type [`Angle`][FixedSigned::Angle]. This
+    /// never overflows, so it only returns a [`None`] value if `self` is
+    /// already [`None`].
+    ///
+    /// See also <code>FixedI32::[atan][FixedI32::atan]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I3F29, I16F16};
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO.atan().0, Some(I3F29::ZERO));
+    /// assert_eq!(Checked::<I16F16>(None).atan().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn atan(self) -> Checked<F::Angle> {
+        Checked(self.0.map(F::atan))
+    }
+
+    /// Computes the four-quadrant arctangent of `self` and `other`, where
+    /// `self` is the <i>y</i> coordinate and `other` is the <i>x</i>
+    /// coordinate, with the result in radians.
+    ///
+    /// The result is an angle of type [`Angle`][FixedSigned::Angle]. This
+    /// never overflows, so it only returns a [`None`] value if an operand is
+    /// already [`None`].
+    ///
+    /// See also <code>FixedI32::[atan2][FixedI32::atan2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I3F29, I16F16};
+    /// use fixed::Checked;
+    /// let one = Checked(Some(I16F16::ONE));
+    /// assert_eq!(Checked::<I16F16>::ZERO.atan2(one).0, Some(I3F29::ZERO));
+    /// assert_eq!(one.atan2(Checked(None)).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn atan2(self, other: Checked<F>) -> Checked<F::Angle> {
+        Checked(self.0.zip(other.0).map(|(y, x)| y.atan2(x)))
+    }
+
+    /// Computes the hyperbolic sine of `self`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_sinh][FixedI32::checked_sinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I4F12>::ZERO.sinh().0, Some(I4F12::ZERO));
+    /// assert_eq!(Checked(Some(I4F12::from_num(3))).sinh().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sinh(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_sinh))
+    }
+
+    /// Computes the hyperbolic cosine of `self`.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_cosh][FixedI32::checked_cosh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I4F12>::ZERO.cosh().0, Some(I4F12::ONE));
+    /// assert_eq!(Checked(Some(I4F12::from_num(3))).cosh().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cosh(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_cosh))
+    }
+
+    /// Computes the hyperbolic tangent of `self`.
+    ///
+    /// This never overflows, so it only returns a [`None`] value if an
+    /// operand is already [`None`].
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked::<I16F16>::ZERO.tanh().0, Some(I16F16::ZERO));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tanh(self) -> Checked<F> {
+        Checked(self.0.map(F::tanh))
+    }
+
+    /// Computes the sine and cosine of `self`, where `self` is in radians.
+    ///
+    /// Both values are [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin\_cos][FixedI32::checked_sin_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Checked;
+    /// let (sin, cos) = Checked::<I16F16>::ZERO.sin_cos();
+    /// assert_eq!(sin.0, Some(I16F16::ZERO));
+    /// assert_eq!(cos.0, Some(I16F16::ONE));
+    /// ```
+    #[inline]
+    pub fn sin_cos(self) -> (Checked<F>, Checked<F>) {
+        match self.0.and_then(F::checked_sin_cos) {
+            Some((sin, cos)) => (Checked(Some(sin)), Checked(Some(cos))),
+            None => (Checked(None), Checked(None)),
+        }
+    }
+}
+
+impl<F: FixedUnsigned> Checked<F> {
+    /// Returns the smallest power of two that is ≥&nbsp;`self`.
+    ///
+    /// Returns a [`None`] value if the next power of two is too large to fit.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_next\_power\_of\_two][FixedU32::checked_next_power_of_two]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::U16F16;
+    /// use fixed::Checked;
+    /// assert_eq!(Checked(Some(U16F16::from_num(5))).next_power_of_two().0, Some(U16F16::from_num(8)));
+    /// assert_eq!(Checked(Some(U16F16::MAX)).next_power_of_two().0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_power_of_two(self) -> Checked<F> {
+        Checked(self.0.and_then(F::checked_next_power_of_two))
+    }
+
+    /// Addition with a signed fixed-point number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_add\_signed][FixedU32::checked_add_signed]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Checked;
+    /// let x = Checked(Some(U16F16::from_num(5)));
+    /// assert_eq!(x.add_signed(I16F16::from_num(-3)).0, Some(U16F16::from_num(2)));
+    /// assert_eq!(x.add_signed(I16F16::from_num(-6)).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_signed(self, rhs: F::Signed) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_add_signed(rhs)))
+    }
+
+    /// Subtraction with a signed fixed-point number.
+    ///
+    /// Returns a [`None`] value on overflow.
+    ///
+    /// See also
+    /// <code>FixedU32::[checked\_sub\_signed][FixedU32::checked_sub_signed]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Checked;
+    /// let x = Checked(Some(U16F16::from_num(5)));
+    /// assert_eq!(x.sub_signed(I16F16::from_num(3)).0, Some(U16F16::from_num(2)));
+    /// assert_eq!(x.sub_signed(I16F16::from_num(6)).0, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sub_signed(self, rhs: F::Signed) -> Checked<F> {
+        Checked(self.0.and_then(|x| x.checked_sub_signed(rhs)))
+    }
+}
+
+#[inline]
+fn overflowing_to_checked<F>((val, overflow): (F, bool)) -> Checked<F> {
+    Checked(if overflow { None } else { Some(val) })
+}
+
+impl<F: Fixed> Default for Checked<F> {
+    /// Returns zero.
+    #[inline]
+    fn default() -> Checked<F> {
+        Checked::ZERO
+    }
+}
+
+impl<F: Fixed> Debug for Checked<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<F: Fixed> From<F> for Checked<F> {
+    /// Wraps a fixed-point number.
+    #[inline]
+    fn from(src: F) -> Checked<F> {
+        Checked(Some(src))
+    }
+}
+
+impl<F: Fixed> FromStr for Checked<F> {
+    type Err = ParseFixedError;
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// A value that does not fit results in <code>[Ok]\([Checked]\([None]))</code>,
+    /// while a parsing error results in [`Err`].
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Checked::from_str_dec(s)
+    }
+}
+
+macro_rules! op {
+    ($checked:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(self.0.zip(other.0).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<F: Fixed> $Op<Checked<F>> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&Checked<F>> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: Checked<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &Checked<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<F> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: F) {
+                self.0 = self.0.and_then(|a| a.$checked(other));
+            }
+        }
+        impl<F: Fixed> $OpAssign<&F> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &F) {
+                self.0 = self.0.and_then(|a| a.$checked(*other));
+            }
+        }
+    };
+}
+
+macro_rules! op_bitwise {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                Checked(self.0.zip(other.0).map(|(a, b)| a.$op(b)))
+            }
+        }
+        impl<F: Fixed> $Op<Checked<F>> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: Checked<F>) -> Checked<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&Checked<F>> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&Checked<F>> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &Checked<F>) -> Checked<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: Checked<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&Checked<F>> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &Checked<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<F> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: F) {
+                self.0 = self.0.map(|a| a.$op(other));
+            }
+        }
+        impl<F: Fixed> $OpAssign<&F> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &F) {
+                self.0 = self.0.map(|a| a.$op(*other));
+            }
+        }
+    };
+}
+
+macro_rules! op_shift {
+    (
+        $checked:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident;
+        $($Rhs:ident),*
+    ) => { $(
+        impl<F: Fixed> $Op<$Rhs> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Checked<F> {
+                let other = u32::try_from(other).ok();
+                Checked(self.0.zip(other).and_then(|(a, b)| a.$checked(b)))
+            }
+        }
+        impl<F: Fixed> $Op<$Rhs> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Checked<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&$Rhs> for Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Checked<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&$Rhs> for &Checked<F> {
+            type Output = Checked<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Checked<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<$Rhs> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: $Rhs) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&$Rhs> for Checked<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &$Rhs) {
+                *self = (*self).$op(*other);
+            }
+        }
+    )* };
+}
+
+impl<F: Fixed> Neg for Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn neg(self) -> Checked<F> {
+        Checked(self.0.and_then(<F as Fixed>::checked_neg))
+    }
+}
+
+impl<F: Fixed> Neg for &Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn neg(self) -> Checked<F> {
+        -*self
+    }
+}
+op! { checked_add, Add add, AddAssign add_assign }
+op! { checked_sub, Sub sub, SubAssign sub_assign }
+op! { checked_mul, Mul mul, MulAssign mul_assign }
+op! { checked_div, Div div, DivAssign div_assign }
+op! { checked_rem, Rem rem, RemAssign rem_assign }
+
+impl<F: Fixed> Not for Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn not(self) -> Checked<F> {
+        Checked(self.0.map(F::not))
+    }
+}
+impl<F: Fixed> Not for &Checked<F> {
+    type Output = Checked<F>;
+    #[inline]
+    fn not(self) -> Checked<F> {
+        !*self
+    }
+}
+op_bitwise! { BitAnd bitand, BitAndAssign bitand_assign }
+op_bitwise! { BitOr bitor, BitOrAssign bitor_assign }
+op_bitwise! { BitXor bitxor, BitXorAssign bitxor_assign }
+
+op_shift! {
+    checked_shl, Shl shl, ShlAssign shl_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+op_shift! {
+    checked_shr, Shr shr, ShrAssign shr_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+
+impl<F: Fixed> Sum<Checked<F>> for Checked<F> {
+    fn sum<I>(iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = Checked<F>>,
+    {
+        iter.fold(Checked::ZERO, Add::add)
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a Checked<F>> for Checked<F> {
+    fn sum<I>(iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = &'a Checked<F>>,
+    {
+        iter.fold(Checked::ZERO, Add::add)
+    }
+}
+
+impl<F: Fixed> Product<Checked<F>> for Checked<F> {
+    fn product<I>(mut iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = Checked<F>>,
+    {
+        match iter.next() {
+            None => Checked(1.checked_to_fixed()),
+            Some(first) => iter.fold(first, Mul::mul),
+        }
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a Checked<F>> for Checked<F> {
+    fn product<I>(mut iter: I) -> Checked<F>
+    where
+        I: Iterator<Item = &'a Checked<F>>,
+    {
+        match iter.next() {
+            None => Checked(1.checked_to_fixed()),
+            Some(first) => iter.fold(*first, Mul::mul),
+        }
+    }
+}
+
+// The following cannot be implemented for Checked<F> where F: Fixed,
+// otherwise there will be a conflicting implementation error. For
+// example we cannot implement both these without triggering E0119:
+//
+//     impl<F: Fixed> Op<F::Bits> for Checked<F> { /* ... */ }
+//     impl<F: Fixed> Op<&F::Bits> for Checked<F> { /* ... */ }
+//
+// To work around this, we provide implementations like this:
+//
+//     impl<Frac> Op<i8> for Checked<FixedI8<Frac>> { /* ... */ }
+//     impl<Frac> Op<&i8> for Checked<FixedI8<Frac>> { /* ... */ }
+//     impl<Frac> Op<i16> for Checked<FixedI16<Frac>> { /* ... */ }
+//     impl<Frac> Op<&i16> for Checked<FixedI16<Frac>> { /* ... */ }
+//     ...
+
+macro_rules! op_bits {
+    (
+        $Fixed:ident($Bits:ident $(, $LeEqU:ident)*)::$checked:ident,
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<Frac $(: $LeEqU)*> $Op<$Bits> for Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: $Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(other)))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<$Bits> for &Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: $Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(other)))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<&$Bits> for Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: &$Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(*other)))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<&$Bits> for &Checked<$Fixed<Frac>> {
+            type Output = Checked<$Fixed<Frac>>;
+            #[inline]
+            fn $op(self, other: &$Bits) -> Checked<$Fixed<Frac>> {
+                Checked(self.0.and_then(|a| a.$checked(*other)))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $OpAssign<$Bits> for Checked<$Fixed<Frac>> {
+            #[inline]
+            fn $op_assign(&mut self, other: $Bits) {
+                self.0 = self.0.and_then(|a| a.$checked(other));
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $OpAssign<&$Bits> for Checked<$Fixed<Frac>> {
+            #[inline]
+            fn $op_assign(&mut self, other: &$Bits) {
+                self.0 = self.0.and_then(|a| a.$checked(*other));
+            }
+        }
+    };
+}
+
+macro_rules! ops {
+    ($Fixed:ident($Bits:ident, $LeEqU:ident)) => {
+        op_bits! { $Fixed($Bits)::checked_mul_int, Mul mul, MulAssign mul_assign }
+        op_bits! { $Fixed($Bits)::checked_div_int, Div div, DivAssign div_assign }
+        op_bits! { $Fixed($Bits, $LeEqU)::checked_rem_int, Rem rem, RemAssign rem_assign }
+    };
+}
+ops! { FixedI8(i8, LeEqU8) }
+ops! { FixedI16(i16, LeEqU16) }
+ops! { FixedI32(i32, LeEqU32) }
+ops! { FixedI64(i64, LeEqU64) }
+ops! { FixedI128(i128, LeEqU128) }
+ops! { FixedU8(u8, LeEqU8) }
+ops! { FixedU16(u16, LeEqU16) }
+ops! { FixedU32(u32, LeEqU32) }
+ops! { FixedU64(u64, LeEqU64) }
+ops! { FixedU128(u128, LeEqU128) }
+ops! { FixedI256(I256Bits, LeEqU256) }
+ops! { FixedU256(U256Bits, LeEqU256) }
+
+#[cfg(test)]
+mod tests {
+    use crate::Checked;
+    use crate::types::{I4F4, I8F8, U8F8};
+
+    #[test]
+    fn arith() {
+        for a in i8::MIN..=i8::MAX {
+            let fa = I4F4::from_bits(a);
+            let ca = Checked(Some(fa));
+            assert_eq!(-ca, Checked(fa.checked_neg()));
+            for b in i8::MIN..=i8::MAX {
+                let fb = I4F4::from_bits(b);
+                let cb = Checked(Some(fb));
+                assert_eq!(ca + cb, Checked(fa.checked_add(fb)));
+                assert_eq!(ca - cb, Checked(fa.checked_sub(fb)));
+                assert_eq!(ca * cb, Checked(fa.checked_mul(fb)));
+                assert_eq!(ca / cb, Checked(fa.checked_div(fb)));
+                assert_eq!(ca % cb, Checked(fa.checked_rem(fb)));
+                assert_eq!(ca * b, Checked(fa.checked_mul_int(b)));
+                assert_eq!(ca / b, Checked(fa.checked_div_int(b)));
+                assert_eq!(ca % b, Checked(fa.checked_rem_int(b)));
+            }
+        }
+    }
+
+    #[test]
+    fn poison() {
+        let none = Checked::<I8F8>(None);
+        let one = Checked(Some(I8F8::ONE));
+        let zero = Checked::<I8F8>::ZERO;
+
+        // division and remainder by zero
+        assert_eq!((one / zero).0, None);
+        assert_eq!((one % zero).0, None);
+        assert_eq!((one / 0).0, None);
+        assert_eq!((one % 0).0, None);
+        assert_eq!((one.div_euclid(zero)).0, None);
+        assert_eq!((one.rem_euclid_int(0)).0, None);
+
+        // once None, always None
+        let poisoned = one / zero + one;
+        assert_eq!(poisoned.0, None);
+        assert_eq!((poisoned * zero).0, None);
+        assert_eq!((poisoned & one).0, None);
+        assert_eq!((!poisoned).0, None);
+        assert_eq!((-poisoned).0, None);
+        assert_eq!(poisoned.abs().0, None);
+        assert_eq!(poisoned.mean(one).0, None);
+        assert_eq!(one.mean(poisoned).0, None);
+        assert_eq!(poisoned.atan2(one).0, None);
+        let (sin, cos) = poisoned.sin_cos();
+        assert_eq!((sin.0, cos.0), (None, None));
+
+        let mut acc = one;
+        acc /= zero;
+        acc += one;
+        acc -= one;
+        assert_eq!(acc.0, None);
+
+        // sums and products containing None
+        assert_eq!([one, none, one].iter().sum::<Checked<I8F8>>().0, None);
+        assert_eq!([none, one].into_iter().sum::<Checked<I8F8>>().0, None);
+        assert_eq!([one, one, none].iter().product::<Checked<I8F8>>().0, None);
+        assert_eq!([none, zero].into_iter().product::<Checked<I8F8>>().0, None);
+        assert_eq!(
+            [one, one].iter().sum::<Checked<I8F8>>().0,
+            Some(I8F8::from_num(2))
+        );
+        assert_eq!(
+            core::iter::empty::<Checked<I8F8>>().product::<Checked<I8F8>>(),
+            one
+        );
+
+        // logarithms of non-positive numbers
+        assert_eq!(zero.log2().0, None);
+        assert_eq!((-one).ln().0, None);
+        assert_eq!(none.log10().0, None);
+        assert_eq!(one.log2().0, Some(I8F8::ZERO));
+        assert_eq!(none.atan().0, None);
+    }
+
+    #[test]
+    fn shifts() {
+        let x = Checked(Some(U8F8::from_bits(0x0181)));
+        assert_eq!(x << 1u32, Checked(Some(U8F8::from_bits(0x0302))));
+        assert_eq!(x >> 1i8, Checked(Some(U8F8::from_bits(0x00C0))));
+        assert_eq!(x << 15usize, Checked(Some(U8F8::from_bits(0x8000))));
+
+        // out-of-range and negative amounts give None
+        for amount in [16u32, 17, 33, u32::MAX] {
+            assert_eq!((x << amount).0, None);
+            assert_eq!((x >> amount).0, None);
+            assert_eq!((x << u64::from(amount)).0, None);
+        }
+        for amount in [-1i32, -16, i32::MIN] {
+            assert_eq!((x << amount).0, None);
+            assert_eq!((x >> amount).0, None);
+            assert_eq!((x >> i128::from(amount)).0, None);
+        }
+        assert_eq!((x << u128::MAX).0, None);
+
+        let mut y = x;
+        y <<= -1i8;
+        y >>= 0u8;
+        assert_eq!(y.0, None);
+        assert_eq!((Checked::<U8F8>(None) << 0u8).0, None);
+    }
+}
@@ -15,13 +15,16 @@
 
 #![allow(deprecated)]
 
+use crate::fixed256;
 use crate::float_helper;
+use crate::helpers::Private;
 use crate::int_helper;
 use crate::int_helper::IntFixed;
-use crate::types::extra::Unsigned;
+use crate::traits::ToFixed;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256, Unsigned};
 use crate::{
-    F128, F128Bits, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32,
-    FixedU64, FixedU128,
+    F128, F128Bits, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16,
+    FixedU32, FixedU64, FixedU128, FixedU256,
 };
 use core::cmp::Ordering;
 use core::ops::{Shl, Shr};
@@ -386,6 +389,101 @@ fixed_cmp_all! { FixedU32(LeEqU32, u32) }
 fixed_cmp_all! { FixedU64(LeEqU64, u64) }
 fixed_cmp_all! { FixedU128(LeEqU128, u128) }
 
+// The 256-bit numbers can be compared to anything that can be converted to
+// them exactly, so they use the fixed256::Wide representation.
+macro_rules! fixed256_cmp {
+    ($Fixed:ident) => {
+        impl<Frac: LeEqU256, Rhs: ToFixed + Copy> PartialEq<Rhs> for $Fixed<Frac> {
+            #[inline]
+            fn eq(&self, rhs: &Rhs) -> bool {
+                let ord = fixed256::partial_cmp_wide(self.to_wide(Private), rhs.to_wide(Private));
+                ord == Some(Ordering::Equal)
+            }
+        }
+
+        impl<Frac: LeEqU256> Eq for $Fixed<Frac> {}
+
+        impl<Frac: LeEqU256, Rhs: ToFixed + Copy> PartialOrd<Rhs> for $Fixed<Frac> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &Rhs) -> Option<Ordering> {
+                fixed256::partial_cmp_wide(self.to_wide(Private), rhs.to_wide(Private))
+            }
+        }
+
+        impl<Frac: LeEqU256> Ord for $Fixed<Frac> {
+            #[inline]
+            fn cmp(&self, rhs: &$Fixed<Frac>) -> Ordering {
+                self.to_bits().cmp(&rhs.to_bits())
+            }
+        }
+
+        fixed256_cmp! { $Fixed, i8 }
+        fixed256_cmp! { $Fixed, i16 }
+        fixed256_cmp! { $Fixed, i32 }
+        fixed256_cmp! { $Fixed, i64 }
+        fixed256_cmp! { $Fixed, i128 }
+        fixed256_cmp! { $Fixed, isize }
+        fixed256_cmp! { $Fixed, u8 }
+        fixed256_cmp! { $Fixed, u16 }
+        fixed256_cmp! { $Fixed, u32 }
+        fixed256_cmp! { $Fixed, u64 }
+        fixed256_cmp! { $Fixed, u128 }
+        fixed256_cmp! { $Fixed, usize }
+        #[cfg(feature = "nightly-float")]
+        fixed256_cmp! { $Fixed, f16 }
+        fixed256_cmp! { $Fixed, half_f16 }
+        fixed256_cmp! { $Fixed, half_bf16 }
+        fixed256_cmp! { $Fixed, f32 }
+        fixed256_cmp! { $Fixed, f64 }
+        #[cfg(feature = "nightly-float")]
+        fixed256_cmp! { $Fixed, f128 }
+        fixed256_cmp! { $Fixed, F128 }
+        fixed256_cmp! { $Fixed, FixedI8(LeEqU8) }
+        fixed256_cmp! { $Fixed, FixedI16(LeEqU16) }
+        fixed256_cmp! { $Fixed, FixedI32(LeEqU32) }
+        fixed256_cmp! { $Fixed, FixedI64(LeEqU64) }
+        fixed256_cmp! { $Fixed, FixedI128(LeEqU128) }
+        fixed256_cmp! { $Fixed, FixedU8(LeEqU8) }
+        fixed256_cmp! { $Fixed, FixedU16(LeEqU16) }
+        fixed256_cmp! { $Fixed, FixedU32(LeEqU32) }
+        fixed256_cmp! { $Fixed, FixedU64(LeEqU64) }
+        fixed256_cmp! { $Fixed, FixedU128(LeEqU128) }
+    };
+    ($Fixed:ident, $Lhs:ident($LeEqU:ident)) => {
+        impl<Frac: LeEqU256, LhsFrac: $LeEqU> PartialEq<$Fixed<Frac>> for $Lhs<LhsFrac> {
+            #[inline]
+            fn eq(&self, rhs: &$Fixed<Frac>) -> bool {
+                rhs.eq(self)
+            }
+        }
+
+        impl<Frac: LeEqU256, LhsFrac: $LeEqU> PartialOrd<$Fixed<Frac>> for $Lhs<LhsFrac> {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Fixed<Frac>) -> Option<Ordering> {
+                rhs.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+    ($Fixed:ident, $Lhs:ident) => {
+        impl<Frac: LeEqU256> PartialEq<$Fixed<Frac>> for $Lhs {
+            #[inline]
+            fn eq(&self, rhs: &$Fixed<Frac>) -> bool {
+                rhs.eq(self)
+            }
+        }
+
+        impl<Frac: LeEqU256> PartialOrd<$Fixed<Frac>> for $Lhs {
+            #[inline]
+            fn partial_cmp(&self, rhs: &$Fixed<Frac>) -> Option<Ordering> {
+                rhs.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+fixed256_cmp! { FixedI256 }
+fixed256_cmp! { FixedU256 }
+
 #[cfg(test)]
 mod tests {
     use crate::*;
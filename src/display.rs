@@ -16,10 +16,12 @@
 use crate::debug_hex;
 use crate::debug_hex::IsDebugHex;
 use crate::int_helper;
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, Unsigned};
+use crate::int256;
+use crate::int256::U256;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256, Unsigned};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    DynFixed, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16,
+    FixedU32, FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
 };
 use az::{WrappingAs, WrappingCast};
 use core::cmp;
@@ -28,10 +30,11 @@ use core::fmt::{
     Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult,
     UpperExp, UpperHex,
 };
+use core::num::NonZero;
 use core::ops::{Add, Shl, Shr};
 use core::str;
 
-// We need 129 digit bytes: 128 digits, one leading zero.
+// We need 257 digit bytes: 256 digits, one leading zero.
 //
 // The leading zero has two purposes:
 //
@@ -43,11 +46,11 @@ use core::str;
 //   * data[0..1 + int_digits]: integer digits with 0, 1, or 2 extra zeros
 //   * data[1 + int_digits..1 + int_digits + frac_digits]: fractional digits
 //
-// exp is only used for decimal, so its range is from -39 to 38 inclusive.
+// exp is only used for decimal, so its range is from -78 to 77 inclusive.
 struct Buffer {
     int_digits: usize,
     frac_digits: usize,
-    digits: [u8; 129],
+    digits: [u8; 257],
     exp: i32,
     exp_len: usize,
     exp_bytes: [u8; 4],
@@ -55,11 +58,11 @@ struct Buffer {
 
 impl Buffer {
     fn new(int_digits: u32, frac_digits: u32) -> Buffer {
-        assert!(int_digits + frac_digits <= 128, "out of bounds");
+        assert!(int_digits + frac_digits <= 256, "out of bounds");
         Buffer {
             int_digits: int_digits as usize,
             frac_digits: frac_digits as usize,
-            digits: [0; 129],
+            digits: [0; 257],
             exp: 0,
             exp_len: 0,
             exp_bytes: [0; 4],
@@ -204,7 +207,7 @@ impl Buffer {
     }
 
     fn encode_exp(&mut self, upper: bool) {
-        debug_assert!(-39 <= self.exp && self.exp <= 38);
+        debug_assert!(-78 <= self.exp && self.exp <= 77);
 
         self.exp_len = 1;
         self.exp_bytes[0] = if upper { b'E' } else { b'e' };
@@ -612,6 +615,38 @@ impl_format_helper! { u32, u16 }
 impl_format_helper! { u64, u32 }
 impl_format_helper! { u128, u64 }
 
+impl FmtHelper for U256 {
+    const ZERO: U256 = U256::ZERO;
+    const MSB: U256 = U256 {
+        lo: 0,
+        hi: 1 << 127,
+    };
+    const BITS: u32 = 256;
+
+    type Half = u128;
+
+    fn int_used_nbits(int: U256) -> u32 {
+        256 - int256::leading_zeros_u256(int)
+    }
+
+    fn frac_used_nbits(frac: U256) -> u32 {
+        256 - int256::trailing_zeros_u256(frac)
+    }
+
+    fn as_half(val: U256) -> u128 {
+        val.lo
+    }
+
+    fn div_rem_10(val: U256) -> (U256, u8) {
+        let (q, r) = int256::div_rem_u256_u128(val, NonZero::<u128>::new(10).unwrap());
+        (q, r.wrapping_cast())
+    }
+
+    fn wrapping_neg(val: U256) -> U256 {
+        int256::wrapping_neg_u256(val)
+    }
+}
+
 fn fmt<U: FmtHelper>(
     (neg, abs): (bool, U),
     frac_nbits: u32,
@@ -785,6 +820,198 @@ impl_fmt! { FixedI32(LeEqU32, i32) }
 impl_fmt! { FixedI64(LeEqU64, i64) }
 impl_fmt! { FixedI128(LeEqU128, i128) }
 
+macro_rules! impl_fmt_256 {
+    ($Fixed:ident) => {
+        impl<Frac: LeEqU256> Display for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::Dec, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> Debug for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                match debug_hex::is_debug_hex(f) {
+                    IsDebugHex::Lower => fmt(self.neg_abs(), Self::FRAC_NBITS, Format::LowHex, f),
+                    IsDebugHex::Upper => fmt(self.neg_abs(), Self::FRAC_NBITS, Format::UpHex, f),
+                    IsDebugHex::No => fmt(self.neg_abs(), Self::FRAC_NBITS, Format::Dec, f),
+                }
+            }
+        }
+
+        impl<Frac: LeEqU256> Binary for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::Bin, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> Octal for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::Oct, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> LowerHex for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::LowHex, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> UpperHex for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::UpHex, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> LowerExp for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::LowExp, f)
+            }
+        }
+
+        impl<Frac: LeEqU256> UpperExp for $Fixed<Frac> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), Self::FRAC_NBITS, Format::UpExp, f)
+            }
+        }
+    };
+}
+
+impl_fmt_256! { FixedU256 }
+impl_fmt_256! { FixedI256 }
+
+// Writes the digits of abs like the primitive integers do. For radix 2, 8 and
+// 16, abs holds the two's complement bits and nonneg is true.
+fn fmt_int_256(
+    nonneg: bool,
+    mut abs: U256,
+    radix: u8,
+    prefix: &str,
+    upper: bool,
+    f: &mut Formatter,
+) -> FmtResult {
+    // 256 binary digits is the maximum for any radix
+    let mut buf = [0u8; 256];
+    let mut start = buf.len();
+    loop {
+        let digit = if radix == 10 {
+            let (q, r) = U256::div_rem_10(abs);
+            abs = q;
+            r
+        } else {
+            let digit = abs.lo.wrapping_as::<u8>() & (radix - 1);
+            abs = abs >> radix.trailing_zeros();
+            digit
+        };
+        start -= 1;
+        buf[start] = match digit {
+            0..=9 => b'0' + digit,
+            _ if upper => b'A' + digit - 10,
+            _ => b'a' + digit - 10,
+        };
+        if int256::is_zero_u256(abs) {
+            break;
+        }
+    }
+    let digits = str::from_utf8(&buf[start..]).expect("ascii digits");
+    f.pad_integral(nonneg, prefix, digits)
+}
+
+macro_rules! impl_fmt_bits_256 {
+    ($Bits:ident) => {
+        impl Display for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                let (neg, abs) = self.neg_abs();
+                fmt_int_256(!neg, abs, 10, "", false, f)
+            }
+        }
+
+        impl Debug for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                match debug_hex::is_debug_hex(f) {
+                    IsDebugHex::Lower => LowerHex::fmt(self, f),
+                    IsDebugHex::Upper => UpperHex::fmt(self, f),
+                    IsDebugHex::No => Display::fmt(self, f),
+                }
+            }
+        }
+
+        impl Binary for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_int_256(true, self.0, 2, "0b", false, f)
+            }
+        }
+
+        impl Octal for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_int_256(true, self.0, 8, "0o", false, f)
+            }
+        }
+
+        impl LowerHex for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_int_256(true, self.0, 16, "0x", false, f)
+            }
+        }
+
+        impl UpperHex for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_int_256(true, self.0, 16, "0x", true, f)
+            }
+        }
+
+        impl LowerExp for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), 0, Format::LowExp, f)
+            }
+        }
+
+        impl UpperExp for $Bits {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), 0, Format::UpExp, f)
+            }
+        }
+    };
+}
+
+impl_fmt_bits_256! { U256Bits }
+impl_fmt_bits_256! { I256Bits }
+
+impl Display for DynFixed {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt(self.neg_abs(), self.format().frac_nbits(), Format::Dec, f)
+    }
+}
+
+impl Debug for DynFixed {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let frac_nbits = self.format().frac_nbits();
+        match debug_hex::is_debug_hex(f) {
+            IsDebugHex::Lower => fmt(self.neg_abs(), frac_nbits, Format::LowHex, f),
+            IsDebugHex::Upper => fmt(self.neg_abs(), frac_nbits, Format::UpHex, f),
+            IsDebugHex::No => fmt(self.neg_abs(), frac_nbits, Format::Dec, f),
+        }
+    }
+}
+
+macro_rules! impl_fmt_dyn {
+    ($($Trait:ident($format:ident)),*) => { $(
+        impl $Trait for DynFixed {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt(self.neg_abs(), self.format().frac_nbits(), Format::$format, f)
+            }
+        }
+    )* };
+}
+
+impl_fmt_dyn! {
+    Binary(Bin),
+    Octal(Oct),
+    LowerHex(LowHex),
+    UpperHex(UpHex),
+    LowerExp(LowExp),
+    UpperExp(UpExp)
+}
+
 // ceil(i × log_10 2), works for input < 112_816
 fn ceil_log10_2_times(int_bits: u32) -> u32 {
     debug_assert!(int_bits < 112_816);
@@ -826,6 +1053,16 @@ impl Mul10 for u128 {
         hi_hi as u8 + u8::from(overflow)
     }
 }
+impl Mul10 for U256 {
+    #[inline]
+    fn mul10_assign(x: &mut U256) -> u8 {
+        let lo = int256::wide_mul_u128(x.lo, 10);
+        let hi = int256::wide_mul_u128(x.hi, 10);
+        let (mid, carry) = lo.hi.overflowing_add(hi.lo);
+        *x = U256 { lo: lo.lo, hi: mid };
+        hi.hi as u8 + u8::from(carry)
+    }
+}
 
 #[cfg(test)]
 mod tests {
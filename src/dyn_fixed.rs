@@ -0,0 +1,2004 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::FixedI256;
+use crate::fixed256;
+use crate::fixed256::{Finite, Wide};
+use crate::from_str;
+use crate::from_str::ParseFixedError;
+use crate::helpers::{self, FloatKind, FromFloatHelper, Private};
+use crate::int_helper;
+use crate::int256;
+use crate::int256::{U256, U512};
+use crate::rounding::RoundingMode;
+use crate::traits::{Fixed, FromFixed, ToFixed};
+use crate::types::extra::{U127, U128};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::num::NonZero;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// The format of a [`DynFixed`] number, chosen at run time.
+///
+/// The format consists of the signedness, the total number of bits <i>n</i>
+/// and the number of fractional bits <i>f</i>, where
+/// 0&nbsp;≤&nbsp;<i>f</i>&nbsp;≤&nbsp;<i>n</i>&nbsp;≤&nbsp;128. Signed formats
+/// need at least two bits, and unsigned formats need at least one bit.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed::DynFormat;
+/// // Q15 format: signed, 16 bits, 15 of which are fractional
+/// let q15 = DynFormat::signed(16, 15).unwrap();
+/// assert_eq!(q15.int_nbits(), 1);
+/// assert_eq!(DynFormat::of::<I16F16>(), DynFormat::signed(32, 16).unwrap());
+/// assert_eq!(DynFormat::unsigned(8, 9), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynFormat {
+    signed: bool,
+    nbits: u32,
+    frac_nbits: u32,
+}
+
+impl DynFormat {
+    /// Creates a signed format with `nbits` total bits of which `frac_nbits`
+    /// are fractional bits.
+    ///
+    /// Returns [`None`] unless 2&nbsp;≤&nbsp;`nbits`&nbsp;≤&nbsp;128 and
+    /// `frac_nbits`&nbsp;≤&nbsp;`nbits`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert!(q15.is_signed());
+    /// assert_eq!(DynFormat::signed(1, 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn signed(nbits: u32, frac_nbits: u32) -> Option<DynFormat> {
+        if nbits < 2 || nbits > 128 || frac_nbits > nbits {
+            return None;
+        }
+        Some(DynFormat {
+            signed: true,
+            nbits,
+            frac_nbits,
+        })
+    }
+
+    /// Creates an unsigned format with `nbits` total bits of which
+    /// `frac_nbits` are fractional bits.
+    ///
+    /// Returns [`None`] unless 1&nbsp;≤&nbsp;`nbits`&nbsp;≤&nbsp;128 and
+    /// `frac_nbits`&nbsp;≤&nbsp;`nbits`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// assert!(!u8f8.is_signed());
+    /// assert_eq!(DynFormat::unsigned(129, 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unsigned(nbits: u32, frac_nbits: u32) -> Option<DynFormat> {
+        if nbits < 1 || nbits > 128 || frac_nbits > nbits {
+            return None;
+        }
+        Some(DynFormat {
+            signed: false,
+            nbits,
+            frac_nbits,
+        })
+    }
+
+    /// Returns the format of the fixed-point number type `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` has more than 128 bits, such as [`FixedI256`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::U8F8;
+    /// use fixed::DynFormat;
+    /// assert_eq!(DynFormat::of::<U8F8>(), DynFormat::unsigned(16, 8).unwrap());
+    /// ```
+    ///
+    /// [`FixedI256`]: crate::FixedI256
+    #[inline]
+    #[must_use]
+    pub const fn of<F: Fixed>() -> DynFormat {
+        assert!(
+            F::INT_NBITS + F::FRAC_NBITS <= 128,
+            "DynFormat cannot have more than 128 bits"
+        );
+        DynFormat {
+            signed: F::IS_SIGNED,
+            nbits: F::INT_NBITS + F::FRAC_NBITS,
+            frac_nbits: F::FRAC_NBITS,
+        }
+    }
+
+    // Returns the format used when converting from F: the format of F if it
+    // has at most 128 bits, otherwise a 128-bit format with the same
+    // signedness and at most 128 fractional bits.
+    #[inline]
+    const fn of_fixed<F: Fixed>() -> DynFormat {
+        let nbits = F::INT_NBITS + F::FRAC_NBITS;
+        let frac_nbits = F::FRAC_NBITS;
+        DynFormat {
+            signed: F::IS_SIGNED,
+            nbits: if nbits > 128 { 128 } else { nbits },
+            frac_nbits: if frac_nbits > 128 { 128 } else { frac_nbits },
+        }
+    }
+
+    /// Returns [`true`] if the format is signed.
+    #[inline]
+    #[must_use]
+    pub const fn is_signed(self) -> bool {
+        self.signed
+    }
+
+    /// Returns the total number of bits.
+    #[inline]
+    #[must_use]
+    pub const fn nbits(self) -> u32 {
+        self.nbits
+    }
+
+    /// Returns the number of integer bits.
+    #[inline]
+    #[must_use]
+    pub const fn int_nbits(self) -> u32 {
+        self.nbits - self.frac_nbits
+    }
+
+    /// Returns the number of fractional bits.
+    #[inline]
+    #[must_use]
+    pub const fn frac_nbits(self) -> u32 {
+        self.frac_nbits
+    }
+
+    /// Returns zero in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert!(q15.zero().is_zero());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn zero(self) -> DynFixed {
+        DynFixed {
+            bits: 0,
+            format: self,
+        }
+    }
+
+    /// Returns the difference between any two successive representable
+    /// numbers in this format, <i>Δ</i>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(u4f4.delta().to_num::<f64>(), 0.0625);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn delta(self) -> DynFixed {
+        DynFixed::from_bits(1, self)
+    }
+
+    /// Returns the smallest value that can be represented in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(q15.min().to_num::<f64>(), -1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min(self) -> DynFixed {
+        if self.signed {
+            DynFixed::from_bits(1 << (self.nbits - 1), self)
+        } else {
+            self.zero()
+        }
+    }
+
+    /// Returns the largest value that can be represented in this format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(u4f4.max().to_num::<f64>(), 15.9375);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max(self) -> DynFixed {
+        let uns_max = u128::MAX >> (128 - self.nbits);
+        if self.signed {
+            DynFixed::from_bits(uns_max >> 1, self)
+        } else {
+            DynFixed::from_bits(uns_max, self)
+        }
+    }
+
+    /// Returns one if it can be represented in this format, otherwise
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::DynFormat;
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(q15.try_one(), None);
+    /// let q14 = DynFormat::signed(16, 14).unwrap();
+    /// assert_eq!(q14.try_one().unwrap().to_num::<i32>(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_one(self) -> Option<DynFixed> {
+        if self.int_nbits() > self.signed as u32 {
+            Some(DynFixed::from_bits(1 << self.frac_nbits, self))
+        } else {
+            None
+        }
+    }
+
+    // Sign-extends or zero-extends the low nbits bits.
+    #[inline]
+    const fn canonical(self, bits: u128) -> u128 {
+        let unused = 128 - self.nbits;
+        if self.signed {
+            (((bits << unused) as i128) >> unused) as u128
+        } else {
+            (bits << unused) >> unused
+        }
+    }
+
+    #[inline]
+    fn overflowing_from_finite(self, src: Finite) -> (DynFixed, bool) {
+        let (bits, overflow) = fixed256::convert(src, self.frac_nbits, self.nbits, self.signed);
+        (DynFixed::from_bits(bits.lo, self), overflow)
+    }
+
+    #[inline]
+    const fn saturated(self, neg: bool) -> DynFixed {
+        if neg { self.min() } else { self.max() }
+    }
+}
+
+/// A fixed-point number with a format chosen at run time.
+///
+/// The static fixed-point numbers such as [`FixedI32`] have their number of
+/// fractional bits fixed at compile time. A `DynFixed` number stores its
+/// [`DynFormat`] together with its bits, which is useful for example when the
+/// format is only known after reading a file header.
+///
+/// Arithmetic between two `DynFixed` numbers is exact before the result is
+/// stored in the format of the left-hand operand, so the operands can have
+/// different formats. Like for the static numbers, multiplication rounds
+/// towards &minus;∞, and division rounds towards zero.
+///
+/// `DynFixed` implements the [`FromFixed`] and [`ToFixed`] traits, so it can
+/// be converted from and to the static fixed-point numbers. Conversion from a
+/// static number is lossless and keeps its format.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::traits::FromFixed;
+/// use fixed::types::I16F16;
+/// use fixed::{DynFixed, DynFormat};
+///
+/// // the format is read from a header
+/// let q15 = DynFormat::signed(16, 15).unwrap();
+/// // a raw sample is 0x6000, which is 0.75 in Q15
+/// let sample = DynFixed::from_bits(0x6000, q15);
+/// assert_eq!(sample.to_string(), "0.75");
+/// let half = DynFixed::from_str("0.5", q15).unwrap();
+/// assert_eq!((sample * half).to_string(), "0.375");
+///
+/// // conversions to and from static numbers
+/// assert_eq!(I16F16::from_num(sample), 0.75);
+/// let d = DynFixed::from_fixed(I16F16::from_num(-2.5));
+/// assert_eq!(d.format(), DynFormat::of::<I16F16>());
+/// assert_eq!(d.to_num::<f64>(), -2.5);
+/// ```
+///
+/// [`FixedI32`]: crate::FixedI32
+#[derive(Clone, Copy)]
+pub struct DynFixed {
+    // sign-extended or zero-extended to 128 bits
+    bits: u128,
+    format: DynFormat,
+}
+
+// Generates the checked, saturating, wrapping, unwrapped and overflowing
+// methods from a method that returns the exact result and whether it
+// overflowed 256 bits, or None on division by zero. The result is stored in
+// the format of self.
+macro_rules! dyn_arith {
+    (
+        $desc:literal, $none:literal, $zero:literal, $ex:literal;
+        $exact:ident($($arg:ident),*);
+        $checked:ident, $saturating:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident
+    ) => {
+        comment! {
+            "Checked ", $desc, ". Returns the result, or [`None`] on ", $none, ".
+
+The result has the same format as `self`.
+
+# Examples
+
+```rust
+use fixed::{DynFixed, DynFormat};
+let fmt = DynFormat::signed(16, 8).unwrap();
+", $ex, "```
+";
+            #[inline]
+            #[must_use]
+            pub fn $checked(self $(, $arg: DynFixed)*) -> Option<DynFixed> {
+                match self.$exact($($arg),*) {
+                    Some((exact, false)) => match self.format.overflowing_from_finite(exact) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    },
+                    Some((_, true)) | None => None,
+                }
+            }
+        }
+
+        comment! {
+            "Saturating ", $desc, ". Returns the result, saturating on overflow.
+
+The result has the same format as `self`.
+", $zero;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $saturating(self $(, $arg: DynFixed)*) -> DynFixed {
+                let (exact, overflow) = self.$exact($($arg),*).expect("division by zero");
+                match self.format.overflowing_from_finite(exact) {
+                    (ans, false) if !overflow => ans,
+                    _ => self.format.saturated(exact.neg),
+                }
+            }
+        }
+
+        comment! {
+            "Wrapping ", $desc, ". Returns the result, wrapping on overflow.
+
+The result has the same format as `self`.
+", $zero;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $wrapping(self $(, $arg: DynFixed)*) -> DynFixed {
+                self.$overflowing($($arg),*).0
+            }
+        }
+
+        comment! {
+            "Unwrapped ", $desc, ". Returns the result, panicking on overflow.
+
+The result has the same format as `self`.
+
+# Panics
+
+Panics if the result does not fit.
+", $zero;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $unwrapped(self $(, $arg: DynFixed)*) -> DynFixed {
+                match self.$overflowing($($arg),*) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Overflowing ", $desc, ".
+
+Returns a [tuple] of the result and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The result has the same format as `self`.
+", $zero;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn $overflowing(self $(, $arg: DynFixed)*) -> (DynFixed, bool) {
+                let (exact, overflow) = self.$exact($($arg),*).expect("division by zero");
+                let (ans, convert_overflow) = self.format.overflowing_from_finite(exact);
+                (ans, overflow || convert_overflow)
+            }
+        }
+    };
+}
+
+impl DynFixed {
+    /// Creates a fixed-point number from its bits and format.
+    ///
+    /// Only the lowest [`nbits`] bits of `bits` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_bits(0xC000, q15).to_num::<f64>(), -0.5);
+    /// // only the lowest 16 bits are used
+    /// assert_eq!(DynFixed::from_bits(0x1_4000, q15).to_num::<f64>(), 0.5);
+    /// ```
+    ///
+    /// [`nbits`]: DynFormat::nbits
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u128, format: DynFormat) -> DynFixed {
+        DynFixed {
+            bits: format.canonical(bits),
+            format,
+        }
+    }
+
+    /// Returns the bits of the fixed-point number.
+    ///
+    /// Only the lowest [`nbits`] bits of the result can be non-zero, that is
+    /// the bits of negative numbers are not sign-extended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_num(-0.5, q15).to_bits(), 0xC000);
+    /// ```
+    ///
+    /// [`nbits`]: DynFormat::nbits
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u128 {
+        let unused = 128 - self.format.nbits;
+        (self.bits << unused) >> unused
+    }
+
+    /// Returns the format of the fixed-point number.
+    #[inline]
+    #[must_use]
+    pub const fn format(self) -> DynFormat {
+        self.format
+    }
+
+    /// Returns [`true`] if the number is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns [`true`] if the number is &gt;&nbsp;0.
+    #[inline]
+    #[must_use]
+    pub const fn is_positive(self) -> bool {
+        self.bits != 0 && !self.is_negative()
+    }
+
+    /// Returns [`true`] if the number is &lt;&nbsp;0.
+    #[inline]
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.format.signed && (self.bits as i128) < 0
+    }
+
+    #[inline]
+    pub(crate) const fn neg_abs(self) -> (bool, u128) {
+        if self.is_negative() {
+            (true, (self.bits as i128).unsigned_abs())
+        } else {
+            (false, self.bits)
+        }
+    }
+
+    #[inline]
+    fn finite(self) -> Finite {
+        let (neg, abs) = self.neg_abs();
+        Finite {
+            neg,
+            abs: U256 { lo: abs, hi: 0 },
+            frac_nbits: self.format.frac_nbits as i32,
+            round: RoundingMode::Floor,
+        }
+    }
+
+    // Every DynFixed number can be converted exactly to FixedI256<U127>, or to
+    // FixedI256<U128> if all its 128 bits are fractional, so conversions to
+    // other numbers can go through the FromFixed implementations.
+    #[inline]
+    #[track_caller]
+    fn via_fixed256<R>(
+        self,
+        frac127: impl FnOnce(FixedI256<U127>) -> R,
+        frac128: impl FnOnce(FixedI256<U128>) -> R,
+    ) -> R {
+        if self.format.frac_nbits < 128 {
+            frac127(self.to_fixed())
+        } else {
+            frac128(self.to_fixed())
+        }
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number.
+    ///
+    /// The other number can be:
+    ///
+    ///   * A fixed-point number, including a [`DynFixed`] number. Any extra
+    ///     fractional bits are discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`]
+    ///     or [`bool`].
+    ///   * A floating-point number of type <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] is implemented.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is not [finite].
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_from_num`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(DynFixed::from_num(3.25, u4f4).to_bits(), 0x34);
+    /// assert_eq!(DynFixed::from_num(3u8, u4f4).to_bits(), 0x30);
+    /// // changing the format rounds towards −∞
+    /// let u6f2 = DynFormat::unsigned(8, 2).unwrap();
+    /// let f = DynFixed::from_bits(0x35, u4f4);
+    /// assert_eq!(DynFixed::from_num(f, u6f2).to_bits(), 0x0D);
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    /// [`wrapping_from_num`]: Self::wrapping_from_num
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn from_num<Src: ToFixed>(src: Src, format: DynFormat) -> DynFixed {
+        let (ans, overflow) = DynFixed::overflowing_from_num(src, format);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number if it fits, otherwise returns [`None`].
+    ///
+    /// See [`from_num`] for details on rounding. For floating-point numbers
+    /// that are not [finite], [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(DynFixed::checked_from_num(3.25, u4f4).unwrap().to_bits(), 0x34);
+    /// assert!(DynFixed::checked_from_num(16, u4f4).is_none());
+    /// assert!(DynFixed::checked_from_num(-1, u4f4).is_none());
+    /// assert!(DynFixed::checked_from_num(f64::NAN, u4f4).is_none());
+    /// ```
+    ///
+    /// [`from_num`]: Self::from_num
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[must_use]
+    pub fn checked_from_num<Src: ToFixed>(src: Src, format: DynFormat) -> Option<DynFixed> {
+        match src.to_wide(Private) {
+            Wide::Finite(src) => match format.overflowing_from_finite(src) {
+                (ans, false) => Some(ans),
+                (_, true) => None,
+            },
+            Wide::NaN | Wide::Infinite { .. } => None,
+        }
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number, saturating the value if it does not fit.
+    ///
+    /// See [`from_num`] for details on rounding.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is [NaN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(DynFixed::saturating_from_num(16, u4f4), u4f4.max());
+    /// assert_eq!(DynFixed::saturating_from_num(-1, u4f4), u4f4.min());
+    /// ```
+    ///
+    /// [`from_num`]: Self::from_num
+    /// [NaN]: f64::is_nan
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn saturating_from_num<Src: ToFixed>(src: Src, format: DynFormat) -> DynFixed {
+        match src.to_wide(Private) {
+            Wide::NaN => panic!("NaN"),
+            Wide::Infinite { neg } => format.saturated(neg),
+            Wide::Finite(src) => match format.overflowing_from_finite(src) {
+                (ans, false) => ans,
+                (_, true) => format.saturated(src.neg),
+            },
+        }
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number, wrapping the value on overflow.
+    ///
+    /// See [`from_num`] for details on rounding.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is not [finite].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// // 17.5 is 0x11.8, which wraps to 0x1.8
+    /// assert_eq!(DynFixed::wrapping_from_num(17.5, u4f4).to_bits(), 0x18);
+    /// ```
+    ///
+    /// [`from_num`]: Self::from_num
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn wrapping_from_num<Src: ToFixed>(src: Src, format: DynFormat) -> DynFixed {
+        DynFixed::overflowing_from_num(src, format).0
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number, panicking if the value does not fit.
+    ///
+    /// See [`from_num`] for details on rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions are not
+    /// enabled. For floating-point numbers, also panics if the value is not
+    /// [finite].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// assert_eq!(DynFixed::unwrapped_from_num(3.25, u4f4).to_bits(), 0x34);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// let _overflow = DynFixed::unwrapped_from_num(16, u4f4);
+    /// ```
+    ///
+    /// [`from_num`]: Self::from_num
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn unwrapped_from_num<Src: ToFixed>(src: Src, format: DynFormat) -> DynFixed {
+        match DynFixed::overflowing_from_num(src, format) {
+            (ans, false) => ans,
+            (_, true) => panic!("overflow"),
+        }
+    }
+
+    /// Creates a fixed-point number with the given format from another
+    /// number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See [`from_num`] for details on rounding.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is not [finite].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// let (f, overflow) = DynFixed::overflowing_from_num(17.5, u4f4);
+    /// assert_eq!((f.to_bits(), overflow), (0x18, true));
+    /// ```
+    ///
+    /// [`from_num`]: Self::from_num
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn overflowing_from_num<Src: ToFixed>(src: Src, format: DynFormat) -> (DynFixed, bool) {
+        match src.to_wide(Private) {
+            Wide::NaN => panic!("NaN"),
+            Wide::Infinite { .. } => panic!("infinite"),
+            Wide::Finite(src) => format.overflowing_from_finite(src),
+        }
+    }
+
+    /// Converts the fixed-point number to another number.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`] or
+    ///     [`usize`]. Any fractional bits are discarded, which rounds towards
+    ///     &minus;∞.
+    ///   * A floating-point number of type <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other type `Dst` for which [`FromFixed`] is implemented.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_to_num`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// let f = DynFixed::from_bits(0xA000, q15);
+    /// assert_eq!(f.to_num::<f32>(), -0.75);
+    /// assert_eq!(f.to_num::<I8F8>(), -0.75);
+    /// // −0.75 is rounded towards −∞
+    /// assert_eq!(f.to_num::<i32>(), -1);
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    /// [`wrapping_to_num`]: Self::wrapping_to_num
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn to_num<Dst: FromFixed>(self) -> Dst {
+        self.via_fixed256(Dst::from_fixed, Dst::from_fixed)
+    }
+
+    /// Converts the fixed-point number to another number if it fits,
+    /// otherwise returns [`None`].
+    ///
+    /// See [`to_num`] for details on rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// assert_eq!(f.checked_to_num::<u8>(), Some(200));
+    /// assert_eq!(f.checked_to_num::<i8>(), None);
+    /// ```
+    ///
+    /// [`to_num`]: Self::to_num
+    #[inline]
+    #[must_use]
+    pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+        self.via_fixed256(Dst::checked_from_fixed, Dst::checked_from_fixed)
+    }
+
+    /// Converts the fixed-point number to another number, saturating the
+    /// value if it does not fit.
+    ///
+    /// See [`to_num`] for details on rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// assert_eq!(f.saturating_to_num::<i8>(), i8::MAX);
+    /// ```
+    ///
+    /// [`to_num`]: Self::to_num
+    #[inline]
+    #[must_use]
+    pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+        self.via_fixed256(Dst::saturating_from_fixed, Dst::saturating_from_fixed)
+    }
+
+    /// Converts the fixed-point number to another number, wrapping the value
+    /// on overflow.
+    ///
+    /// See [`to_num`] for details on rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// assert_eq!(f.wrapping_to_num::<i8>(), 200u8 as i8);
+    /// ```
+    ///
+    /// [`to_num`]: Self::to_num
+    #[inline]
+    #[must_use]
+    pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+        self.via_fixed256(Dst::wrapping_from_fixed, Dst::wrapping_from_fixed)
+    }
+
+    /// Converts the fixed-point number to another number, panicking if the
+    /// value does not fit.
+    ///
+    /// See [`to_num`] for details on rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions are not
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// assert_eq!(f.unwrapped_to_num::<u8>(), 200);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```rust,should_panic
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// let _overflow = f.unwrapped_to_num::<i8>();
+    /// ```
+    ///
+    /// [`to_num`]: Self::to_num
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst {
+        self.via_fixed256(Dst::unwrapped_from_fixed, Dst::unwrapped_from_fixed)
+    }
+
+    /// Converts the fixed-point number to another number.
+    ///
+    /// Returns a [tuple] of the number and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See [`to_num`] for details on rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u8f8 = DynFormat::unsigned(16, 8).unwrap();
+    /// let f = DynFixed::from_num(200.5, u8f8);
+    /// assert_eq!(f.overflowing_to_num::<i8>(), (200u8 as i8, true));
+    /// ```
+    ///
+    /// [`to_num`]: Self::to_num
+    #[inline]
+    #[must_use]
+    pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+        self.via_fixed256(Dst::overflowing_from_fixed, Dst::overflowing_from_fixed)
+    }
+
+    #[inline]
+    fn overflowing_from_ascii_radix(
+        src: &[u8],
+        radix: u32,
+        format: DynFormat,
+    ) -> Result<(DynFixed, bool), ParseFixedError> {
+        let parsed = from_str::u256::overflowing_from_str_radix_nbits(
+            src,
+            radix,
+            format.frac_nbits,
+            format.nbits,
+            format.signed,
+        );
+        match parsed {
+            Ok((bits, overflow)) => Ok((DynFixed::from_bits(bits, format), overflow)),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    fn from_ascii_radix(
+        src: &[u8],
+        radix: u32,
+        format: DynFormat,
+    ) -> Result<DynFixed, ParseFixedError> {
+        let parsed = from_str::u256::from_str_radix_nbits(
+            src,
+            radix,
+            format.frac_nbits,
+            format.nbits,
+            format.signed,
+        );
+        match parsed {
+            Ok(bits) => Ok(DynFixed::from_bits(bits, format)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number with the given format.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_str("-0.75", q15).unwrap().to_bits(), 0xA000);
+    /// assert!(DynFixed::from_str("1", q15).is_err());
+    /// ```
+    #[inline]
+    pub fn from_str(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        DynFixed::from_ascii_radix(src.as_bytes(), 10, format)
+    }
+
+    /// Parses a string slice containing binary digits to return a
+    /// fixed-point number with the given format.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_str_binary("-0.11", q15).unwrap().to_bits(), 0xA000);
+    /// ```
+    #[inline]
+    pub fn from_str_binary(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        DynFixed::from_ascii_radix(src.as_bytes(), 2, format)
+    }
+
+    /// Parses a string slice containing octal digits to return a
+    /// fixed-point number with the given format.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_str_octal("-0.6", q15).unwrap().to_bits(), 0xA000);
+    /// ```
+    #[inline]
+    pub fn from_str_octal(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        DynFixed::from_ascii_radix(src.as_bytes(), 8, format)
+    }
+
+    /// Parses a string slice containing hexadecimal digits to return a
+    /// fixed-point number with the given format.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::from_str_hex("-0.C", q15).unwrap().to_bits(), 0xA000);
+    /// ```
+    #[inline]
+    pub fn from_str_hex(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        DynFixed::from_ascii_radix(src.as_bytes(), 16, format)
+    }
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number with the given format, saturating on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let q15 = DynFormat::signed(16, 15).unwrap();
+    /// assert_eq!(DynFixed::saturating_from_str("9", q15), Ok(q15.max()));
+    /// assert_eq!(DynFixed::saturating_from_str("-9", q15), Ok(q15.min()));
+    /// ```
+    #[inline]
+    pub fn saturating_from_str(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        match DynFixed::overflowing_from_ascii_radix(src.as_bytes(), 10, format) {
+            Ok((ans, false)) => Ok(ans),
+            Ok((_, true)) => Ok(format.saturated(src.starts_with('-'))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number with the given format, wrapping on overflow.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// // 17.5 is 0x11.8, which wraps to 0x1.8
+    /// let f = DynFixed::wrapping_from_str("17.5", u4f4).unwrap();
+    /// assert_eq!(f.to_bits(), 0x18);
+    /// ```
+    #[inline]
+    pub fn wrapping_from_str(src: &str, format: DynFormat) -> Result<DynFixed, ParseFixedError> {
+        match DynFixed::overflowing_from_ascii_radix(src.as_bytes(), 10, format) {
+            Ok((ans, _)) => Ok(ans),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses a string slice containing decimal digits to return a
+    /// fixed-point number with the given format.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let u4f4 = DynFormat::unsigned(8, 4).unwrap();
+    /// let (f, overflow) = DynFixed::overflowing_from_str("17.5", u4f4).unwrap();
+    /// assert_eq!((f.to_bits(), overflow), (0x18, true));
+    /// ```
+    #[inline]
+    pub fn overflowing_from_str(
+        src: &str,
+        format: DynFormat,
+    ) -> Result<(DynFixed, bool), ParseFixedError> {
+        DynFixed::overflowing_from_ascii_radix(src.as_bytes(), 10, format)
+    }
+
+    #[inline]
+    fn exact_add(self, rhs: DynFixed) -> Option<(Finite, bool)> {
+        Some(add_finite(self.finite(), rhs.finite()))
+    }
+
+    #[inline]
+    fn exact_sub(self, rhs: DynFixed) -> Option<(Finite, bool)> {
+        Some(add_finite(self.finite(), negate(rhs.finite())))
+    }
+
+    #[inline]
+    fn exact_mul(self, rhs: DynFixed) -> Option<(Finite, bool)> {
+        let (lhs_neg, lhs_abs) = self.neg_abs();
+        let (rhs_neg, rhs_abs) = rhs.neg_abs();
+        let abs = int256::wide_mul_u128(lhs_abs, rhs_abs);
+        let exact = Finite {
+            neg: lhs_neg != rhs_neg && !int256::is_zero_u256(abs),
+            abs,
+            frac_nbits: (self.format.frac_nbits + rhs.format.frac_nbits) as i32,
+            round: RoundingMode::Floor,
+        };
+        Some((exact, false))
+    }
+
+    #[inline]
+    fn exact_div(self, rhs: DynFixed) -> Option<(Finite, bool)> {
+        let (rhs_neg, rhs_abs) = rhs.neg_abs();
+        let rhs_abs = NonZero::new(rhs_abs)?;
+        let (lhs_neg, lhs_abs) = self.neg_abs();
+        // The quotient has the same number of fractional bits as self, and is
+        // truncated, so it is (|lhs| << rhs_frac_nbits) / |rhs| which fits.
+        let shifted = int256::wrapping_shl_u256(U256 { lo: lhs_abs, hi: 0 }, rhs.format.frac_nbits);
+        let (abs, _) = int256::div_rem_u256_u128(shifted, rhs_abs);
+        let exact = Finite {
+            neg: lhs_neg != rhs_neg && !int256::is_zero_u256(abs),
+            abs,
+            frac_nbits: self.format.frac_nbits as i32,
+            round: RoundingMode::Floor,
+        };
+        Some((exact, false))
+    }
+
+    #[inline]
+    fn exact_neg(self) -> Option<(Finite, bool)> {
+        Some((negate(self.finite()), false))
+    }
+
+    dyn_arith! {
+        "addition", "overflow", "", "let a = DynFixed::from_num(1.5, fmt);
+// the operands can have different formats
+let b = DynFixed::from_num(2.25, DynFormat::unsigned(8, 2).unwrap());
+assert_eq!(a.checked_add(b), Some(DynFixed::from_num(3.75, fmt)));
+assert_eq!(fmt.max().checked_add(fmt.delta()), None);
+";
+        exact_add(rhs);
+        checked_add, saturating_add, wrapping_add, unwrapped_add, overflowing_add
+    }
+
+    dyn_arith! {
+        "subtraction", "overflow", "", "let a = DynFixed::from_num(1.5, fmt);
+// the operands can have different formats
+let b = DynFixed::from_num(2.25, DynFormat::unsigned(8, 2).unwrap());
+assert_eq!(a.checked_sub(b), Some(DynFixed::from_num(-0.75, fmt)));
+assert_eq!(fmt.min().checked_sub(fmt.delta()), None);
+";
+        exact_sub(rhs);
+        checked_sub, saturating_sub, wrapping_sub, unwrapped_sub, overflowing_sub
+    }
+
+    dyn_arith! {
+        "multiplication", "overflow", "", "let a = DynFixed::from_num(1.5, fmt);
+// the operands can have different formats
+let b = DynFixed::from_num(2.25, DynFormat::unsigned(8, 2).unwrap());
+assert_eq!(a.checked_mul(b), Some(DynFixed::from_num(3.375, fmt)));
+let hundred = DynFixed::from_num(100, fmt);
+assert_eq!(hundred.checked_mul(DynFixed::from_num(2, fmt)), None);
+";
+        exact_mul(rhs);
+        checked_mul, saturating_mul, wrapping_mul, unwrapped_mul, overflowing_mul
+    }
+
+    dyn_arith! {
+        "division", "overflow or division by zero", "
+# Panics
+
+Panics if the divisor is zero.
+", "let a = DynFixed::from_num(3.375, fmt);
+// the operands can have different formats
+let b = DynFixed::from_num(1.5, DynFormat::unsigned(8, 2).unwrap());
+assert_eq!(a.checked_div(b), Some(DynFixed::from_num(2.25, fmt)));
+assert_eq!(a.checked_div(fmt.zero()), None);
+let hundred = DynFixed::from_num(100, fmt);
+assert_eq!(hundred.checked_div(DynFixed::from_num(0.5, fmt)), None);
+";
+        exact_div(rhs);
+        checked_div, saturating_div, wrapping_div, unwrapped_div, overflowing_div
+    }
+
+    dyn_arith! {
+        "negation", "overflow", "", "let a = DynFixed::from_num(1.5, fmt);
+assert_eq!(a.checked_neg(), Some(DynFixed::from_num(-1.5, fmt)));
+assert_eq!(fmt.min().checked_neg(), None);
+";
+        exact_neg();
+        checked_neg, saturating_neg, wrapping_neg, unwrapped_neg, overflowing_neg
+    }
+
+    /// Checked remainder. Returns the remainder, or [`None`] if the divisor
+    /// is zero.
+    ///
+    /// The result has the same format as `self`, and the remainder never
+    /// overflows. If the remainder needs more fractional bits than the
+    /// format of `self` has, it is rounded towards &minus;∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::{DynFixed, DynFormat};
+    /// let fmt = DynFormat::signed(16, 8).unwrap();
+    /// let a = DynFixed::from_num(-3.75, fmt);
+    /// let b = DynFixed::from_num(1.5, DynFormat::unsigned(8, 2).unwrap());
+    /// assert_eq!(a.checked_rem(b), Some(DynFixed::from_num(-0.75, fmt)));
+    /// assert_eq!(a.checked_rem(fmt.zero()), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, rhs: DynFixed) -> Option<DynFixed> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let lhs = self.finite();
+        let rhs = rhs.finite();
+        let frac_nbits = lhs.frac_nbits.max(rhs.frac_nbits);
+        let lhs_abs = align(lhs, frac_nbits);
+        let rhs_abs = align(rhs, frac_nbits);
+        let (_, abs) = int256::div_rem_u512_u256(
+            U512 {
+                lo: lhs_abs,
+                hi: U256::ZERO,
+            },
+            rhs_abs,
+        );
+        let exact = Finite {
+            neg: lhs.neg && !int256::is_zero_u256(abs),
+            abs,
+            frac_nbits,
+            round: RoundingMode::Floor,
+        };
+        // |remainder| ≤ |self|, and rounding towards −∞ cannot go below
+        // self, so this never overflows
+        Some(self.format.overflowing_from_finite(exact).0)
+    }
+
+    #[inline]
+    fn to_float_helper<F: Fixed>(self) -> FromFloatHelper {
+        let conv = if self.format.signed {
+            int_helper::i128::to_fixed_helper(
+                self.bits as i128,
+                self.format.frac_nbits as i32,
+                F::FRAC_NBITS,
+                F::INT_NBITS,
+            )
+        } else {
+            int_helper::u128::to_fixed_helper(
+                self.bits,
+                self.format.frac_nbits as i32,
+                F::FRAC_NBITS,
+                F::INT_NBITS,
+            )
+        };
+        FromFloatHelper {
+            kind: FloatKind::Finite {
+                neg: self.is_negative(),
+                conv,
+            },
+        }
+    }
+}
+
+// The magnitude with frac_nbits fractional bits; src has at most frac_nbits
+// fractional bits, and the shift is at most 128, so this never overflows.
+#[inline]
+fn align(src: Finite, frac_nbits: i32) -> U256 {
+    int256::wrapping_shl_u256(src.abs, (frac_nbits - src.frac_nbits).unsigned_abs())
+}
+
+#[inline]
+fn negate(src: Finite) -> Finite {
+    Finite {
+        neg: !src.neg && !int256::is_zero_u256(src.abs),
+        ..src
+    }
+}
+
+// Returns the exact sum and whether its magnitude overflowed 256 bits. Both
+// magnitudes are < 2^128 before alignment, so on overflow the sum is ≥ 2^128
+// and does not fit, but the lowest 128 bits of the wrapped result are still
+// correct after conversion to any format.
+fn add_finite(lhs: Finite, rhs: Finite) -> (Finite, bool) {
+    let frac_nbits = lhs.frac_nbits.max(rhs.frac_nbits);
+    let lhs_abs = align(lhs, frac_nbits);
+    let rhs_abs = align(rhs, frac_nbits);
+    let (neg, abs, overflow) = if lhs.neg == rhs.neg {
+        let (abs, overflow) = int256::overflowing_add_u256(lhs_abs, rhs_abs);
+        (lhs.neg, abs, overflow)
+    } else {
+        match lhs_abs.cmp(&rhs_abs) {
+            Ordering::Less => (rhs.neg, int256::wrapping_sub_u256(rhs_abs, lhs_abs), false),
+            Ordering::Equal => (false, U256::ZERO, false),
+            Ordering::Greater => (lhs.neg, int256::wrapping_sub_u256(lhs_abs, rhs_abs), false),
+        }
+    };
+    let exact = Finite {
+        neg,
+        abs,
+        frac_nbits,
+        round: RoundingMode::Floor,
+    };
+    (exact, overflow)
+}
+
+impl FromFixed for DynFixed {
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format.
+    ///
+    /// For fixed-point numbers with at most 128 bits, the conversion is
+    /// lossless and never overflows. Wider numbers such as [`FixedI256`] are
+    /// converted to a format with 128 bits, the same signedness and at most
+    /// 128 fractional bits; any extra fractional bits are discarded, which
+    /// rounds towards &minus;∞.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_from_fixed`] instead.
+    ///
+    /// [`FixedI256`]: crate::FixedI256
+    /// [`wrapping_from_fixed`]: FromFixed::wrapping_from_fixed
+    #[inline]
+    #[track_caller]
+    fn from_fixed<F: Fixed>(src: F) -> Self {
+        let (ans, overflow) = DynFixed::overflowing_from_fixed(src);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format if it fits, otherwise returns [`None`].
+    ///
+    /// See [`from_fixed`] for the format of the result. For fixed-point numbers
+    /// with at most 128 bits, the conversion never overflows, so [`Some`] is
+    /// always returned.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    #[inline]
+    fn checked_from_fixed<F: Fixed>(src: F) -> Option<Self> {
+        match DynFixed::overflowing_from_fixed(src) {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, saturating if it does not fit.
+    ///
+    /// See [`from_fixed`] for the format of the result. For fixed-point numbers
+    /// with at most 128 bits, the conversion never overflows.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    #[inline]
+    fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
+        let format = DynFormat::of_fixed::<F>();
+        match DynFixed::overflowing_from_fixed(src) {
+            (ans, false) => ans,
+            (_, true) => format.saturated(src < 0),
+        }
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, wrapping if it does not fit.
+    ///
+    /// See [`from_fixed`] for the format of the result. For fixed-point numbers
+    /// with at most 128 bits, the conversion never overflows.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    #[inline]
+    fn wrapping_from_fixed<F: Fixed>(src: F) -> Self {
+        DynFixed::overflowing_from_fixed(src).0
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format.
+    ///
+    /// Returns a [tuple] of the value and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See [`from_fixed`] for the format of the result. For fixed-point numbers
+    /// with at most 128 bits, the conversion never overflows, so the returned
+    /// [`bool`] is always [`false`].
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    #[inline]
+    fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
+        DynFixed::overflowing_from_fixed_round(src, RoundingMode::Floor)
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, panicking if it does not fit.
+    ///
+    /// See [`from_fixed`] for the format of the result. For fixed-point numbers
+    /// with at most 128 bits, the conversion never overflows, so this never
+    /// panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit, even when debug assertions are not
+    /// enabled.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    #[inline]
+    #[track_caller]
+    fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
+        match DynFixed::overflowing_from_fixed(src) {
+            (ans, false) => ans,
+            (_, true) => panic!("overflow"),
+        }
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, rounding as specified by `mode`.
+    ///
+    /// See [`from_fixed`] for the format of the result. Only numbers with
+    /// more than 128 fractional bits can need rounding.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_from_fixed_round`]
+    /// instead.
+    ///
+    /// [`from_fixed`]: FromFixed::from_fixed
+    /// [`wrapping_from_fixed_round`]: FromFixed::wrapping_from_fixed_round
+    #[inline]
+    #[track_caller]
+    fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+        let (ans, overflow) = DynFixed::overflowing_from_fixed_round(src, mode);
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, rounding as specified by `mode`, if it fits, otherwise returns
+    /// [`None`].
+    ///
+    /// See [`from_fixed_round`] for details on the conversion.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self> {
+        match DynFixed::overflowing_from_fixed_round(src, mode) {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, rounding as specified by `mode`, and saturating if it does not
+    /// fit.
+    ///
+    /// See [`from_fixed_round`] for details on the conversion.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+        let format = DynFormat::of_fixed::<F>();
+        match DynFixed::overflowing_from_fixed_round(src, mode) {
+            (ans, false) => ans,
+            (_, true) => format.saturated(src < 0),
+        }
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, rounding as specified by `mode`, and wrapping if it does not
+    /// fit.
+    ///
+    /// See [`from_fixed_round`] for details on the conversion.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn wrapping_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+        DynFixed::overflowing_from_fixed_round(src, mode).0
+    }
+
+    /// Converts a fixed-point number to a [`DynFixed`] number with the same
+    /// format, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the value and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See [`from_fixed_round`] for details on the conversion.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
+        let format = DynFormat::of_fixed::<F>();
+        if helpers::is_wide::<F>() {
+            let Wide::Finite(src) = src.to_wide(Private) else {
+                unreachable!();
+            };
+            return format.overflowing_from_finite(Finite { round: mode, ..src });
+        }
+        let helper = src.to_float_helper(Private);
+        let bits = if helper.neg {
+            helper.abs.wrapping_neg()
+        } else {
+            helper.abs
+        };
+        (DynFixed::from_bits(bits, format), false)
+    }
+}
+
+impl ToFixed for DynFixed {
+    /// Converts a [`DynFixed`] number to a fixed-point number.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be
+    /// returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_to_fixed`] instead.
+    ///
+    /// [`wrapping_to_fixed`]: ToFixed::wrapping_to_fixed
+    #[inline]
+    #[track_caller]
+    fn to_fixed<F: Fixed>(self) -> F {
+        let (ans, overflow) = self.overflowing_to_fixed();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+
+    /// Converts a [`DynFixed`] number to a fixed-point number if it fits,
+    /// otherwise returns [`None`].
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    #[inline]
+    fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+        match self.overflowing_to_fixed() {
+            (ans, false) => Some(ans),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts a [`DynFixed`] number to a fixed-point number, saturating if
+    /// it does not fit.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    #[inline]
+    fn saturating_to_fixed<F: Fixed>(self) -> F {
+        if helpers::is_wide::<F>() {
+            return fixed256::saturating_convert_round(self, RoundingMode::Floor);
+        }
+        F::saturating_from_float_helper(Private, self.to_float_helper::<F>())
+    }
+
+    /// Converts a [`DynFixed`] number to a fixed-point number, wrapping if it
+    /// does not fit.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    #[inline]
+    fn wrapping_to_fixed<F: Fixed>(self) -> F {
+        self.overflowing_to_fixed().0
+    }
+
+    /// Converts a [`DynFixed`] number to a fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
+    #[inline]
+    fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+        if helpers::is_wide::<F>() {
+            return fixed256::overflowing_convert_round(self, RoundingMode::Floor);
+        }
+        F::overflowing_from_float_helper(Private, self.to_float_helper::<F>())
+    }
+
+    #[inline]
+    fn to_wide(self, _: Private) -> Wide {
+        Wide::Finite(self.finite())
+    }
+}
+
+// Numbers are compared by value, so numbers with different formats can be
+// equal.
+impl PartialEq for DynFixed {
+    #[inline]
+    fn eq(&self, other: &DynFixed) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DynFixed {}
+
+impl PartialOrd for DynFixed {
+    #[inline]
+    fn partial_cmp(&self, other: &DynFixed) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynFixed {
+    #[inline]
+    fn cmp(&self, other: &DynFixed) -> Ordering {
+        let lhs = Wide::Finite(self.finite());
+        let rhs = Wide::Finite(other.finite());
+        fixed256::partial_cmp_wide(lhs, rhs).expect("finite numbers are ordered")
+    }
+}
+
+impl Hash for DynFixed {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal numbers with different formats have to give the same hash, so
+        // trailing zeros are stripped from the magnitude first.
+        let (neg, abs) = self.neg_abs();
+        let (abs, frac_nbits) = if abs == 0 {
+            (0, 0)
+        } else {
+            let zeros = abs.trailing_zeros();
+            (abs >> zeros, self.format.frac_nbits as i32 - zeros as i32)
+        };
+        neg.hash(state);
+        abs.hash(state);
+        frac_nbits.hash(state);
+    }
+}
+
+macro_rules! refs {
+    (impl $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident) => {
+        impl $Imp<&DynFixed> for DynFixed {
+            type Output = DynFixed;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &DynFixed) -> DynFixed {
+                $Imp::$method(self, *rhs)
+            }
+        }
+
+        impl $Imp<DynFixed> for &DynFixed {
+            type Output = DynFixed;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: DynFixed) -> DynFixed {
+                $Imp::$method(*self, rhs)
+            }
+        }
+
+        impl $Imp<&DynFixed> for &DynFixed {
+            type Output = DynFixed;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &DynFixed) -> DynFixed {
+                $Imp::$method(*self, *rhs)
+            }
+        }
+
+        impl $ImpAssign<DynFixed> for DynFixed {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: DynFixed) {
+                *self = $Imp::$method(*self, rhs);
+            }
+        }
+
+        impl $ImpAssign<&DynFixed> for DynFixed {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: &DynFixed) {
+                *self = $Imp::$method(*self, *rhs);
+            }
+        }
+    };
+}
+
+macro_rules! op {
+    (impl $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident, $overflowing:ident) => {
+        impl $Imp<DynFixed> for DynFixed {
+            type Output = DynFixed;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: DynFixed) -> DynFixed {
+                let (ans, overflow) = self.$overflowing(rhs);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        refs! { impl $Imp $method, $ImpAssign $method_assign }
+    };
+}
+
+op! { impl Add add, AddAssign add_assign, overflowing_add }
+op! { impl Sub sub, SubAssign sub_assign, overflowing_sub }
+op! { impl Mul mul, MulAssign mul_assign, overflowing_mul }
+op! { impl Div div, DivAssign div_assign, overflowing_div }
+
+impl Rem<DynFixed> for DynFixed {
+    type Output = DynFixed;
+    #[inline]
+    #[track_caller]
+    fn rem(self, rhs: DynFixed) -> DynFixed {
+        self.checked_rem(rhs).expect("division by zero")
+    }
+}
+
+refs! { impl Rem rem, RemAssign rem_assign }
+
+impl Neg for DynFixed {
+    type Output = DynFixed;
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> DynFixed {
+        let (ans, overflow) = self.overflowing_neg();
+        debug_assert!(!overflow, "overflow");
+        ans
+    }
+}
+
+impl Neg for &DynFixed {
+    type Output = DynFixed;
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> DynFixed {
+        -*self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I8F8, I16F16, U0F16, U8F8};
+    use crate::{DynFixed, DynFormat, FixedI256};
+    use std::format;
+
+    #[test]
+    fn arith_matches_static() {
+        let fmt = DynFormat::of::<I8F8>();
+        let vals = [
+            I8F8::MIN,
+            I8F8::from_num(-3.75),
+            I8F8::from_num(-1),
+            -I8F8::DELTA,
+            I8F8::ZERO,
+            I8F8::DELTA,
+            I8F8::from_num(0.5),
+            I8F8::from_num(10.125),
+            I8F8::MAX,
+        ];
+        for &a in &vals {
+            let da = DynFixed::from_bits(a.to_bits() as u128, fmt);
+            assert_eq!(
+                da.wrapping_neg().to_bits(),
+                a.wrapping_neg().to_bits() as u16 as u128
+            );
+            for &b in &vals {
+                let db = DynFixed::from_bits(b.to_bits() as u128, fmt);
+                let check = |d: (DynFixed, bool), s: (I8F8, bool)| {
+                    assert_eq!(d.0.to_bits(), s.0.to_bits() as u16 as u128, "{a} {b}");
+                    assert_eq!(d.1, s.1, "{a} {b}");
+                };
+                check(da.overflowing_add(db), a.overflowing_add(b));
+                check(da.overflowing_sub(db), a.overflowing_sub(b));
+                check(da.overflowing_mul(db), a.overflowing_mul(b));
+                if b != 0 {
+                    check(da.overflowing_div(db), a.overflowing_div(b));
+                    assert_eq!(da.checked_rem(db).unwrap().to_num::<I8F8>(), a % b);
+                    assert_eq!(da.saturating_div(db).to_num::<I8F8>(), a.saturating_div(b));
+                }
+                assert_eq!(da.saturating_add(db).to_num::<I8F8>(), a.saturating_add(b));
+                assert_eq!(da.saturating_sub(db).to_num::<I8F8>(), a.saturating_sub(b));
+                assert_eq!(da.saturating_mul(db).to_num::<I8F8>(), a.saturating_mul(b));
+                assert_eq!(da.checked_mul(db).is_none(), a.checked_mul(b).is_none());
+                assert_eq!(da.cmp(&db), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_formats() {
+        let q15 = DynFormat::signed(16, 15).unwrap();
+        let u0f16 = DynFormat::of::<U0F16>();
+        let a = DynFixed::from_num(-0.5, q15);
+        let b = DynFixed::from_num(0.75, u0f16);
+        assert_eq!(a + b, DynFixed::from_num(0.25, q15));
+        assert_eq!(b.checked_add(a), Some(DynFixed::from_num(0.25, u0f16)));
+        assert_eq!(
+            b.checked_sub(DynFixed::from_num(0.5, q15)),
+            Some(DynFixed::from_num(0.25, u0f16))
+        );
+        assert_eq!(b.checked_mul(a), None);
+        assert_eq!(b.saturating_mul(a), u0f16.zero());
+        assert_eq!(a / b, DynFixed::from_bits(0xAAAB, q15));
+        // the exact results are rounded towards −∞
+        let tiny = DynFixed::from_bits(1, u0f16);
+        assert_eq!(q15.zero() + tiny, q15.zero());
+        assert_eq!((q15.zero() - tiny).to_bits(), 0xFFFF);
+        // wide formats
+        let i128f0 = DynFormat::signed(128, 0).unwrap();
+        let u128f128 = DynFormat::unsigned(128, 128).unwrap();
+        let max = i128f0.max();
+        let frac = u128f128.max();
+        assert_eq!(max + frac, max);
+        assert_eq!((max - frac).to_bits(), i128::MAX as u128 - 1);
+        assert_eq!(
+            max.overflowing_add(max),
+            (DynFixed::from_bits(u128::MAX - 1, i128f0), true)
+        );
+        assert_eq!(i128f0.min().overflowing_neg(), (i128f0.min(), true));
+        assert_eq!(max.saturating_mul(max), max);
+        assert_eq!(i128f0.min().saturating_mul(max), i128f0.min());
+    }
+
+    #[test]
+    fn conversions() {
+        let f = I16F16::from_num(-2.625);
+        let d = DynFixed::from_num(f, DynFormat::of::<I16F16>());
+        assert_eq!(d.to_num::<I16F16>(), f);
+        assert_eq!(d.to_num::<I8F8>(), f);
+        assert_eq!(I8F8::from_num(d), f);
+        assert_eq!(d.checked_to_num::<U8F8>(), None);
+        assert_eq!(d.saturating_to_num::<U8F8>(), U8F8::ZERO);
+        assert_eq!(FixedI256::<crate::types::extra::U100>::from_num(d), -2.625);
+        let u8f8 = DynFormat::of::<U8F8>();
+        let d = DynFixed::from_num(200.5, u8f8);
+        assert_eq!(I8F8::checked_from_num(d), None);
+        assert_eq!(I8F8::saturating_from_num(d), I8F8::MAX);
+        assert_eq!(
+            I8F8::overflowing_from_num(d),
+            (I8F8::from_bits(0xC880u16 as i16), true)
+        );
+        let i = DynFixed::from_num(-1.25, DynFormat::signed(8, 2).unwrap());
+        assert_eq!(U8F8::saturating_from_num(i), U8F8::ZERO);
+        assert_eq!(I8F8::from_num(i), -1.25);
+    }
+
+    #[test]
+    fn from_wide_fixed() {
+        use crate::FixedU256;
+        use crate::traits::{Fixed, FromFixed};
+        use crate::types::extra::{U8, U200};
+
+        let one = FixedI256::<U8>::from_num(1);
+        let i120f8 = DynFormat::signed(128, 8).unwrap();
+        let d = DynFixed::checked_from_fixed(one).unwrap();
+        assert_eq!(d.format(), i120f8);
+        assert_eq!(d, DynFixed::from_num(1, i120f8));
+        assert_eq!(
+            <FixedI256<U8> as Fixed>::checked_to_num::<DynFixed>(one),
+            Some(d)
+        );
+        assert_eq!(one.checked_to_num::<DynFixed>(), Some(d));
+        assert_eq!(one.to_num::<DynFixed>(), d);
+        assert_eq!(DynFixed::overflowing_from_fixed(one), (d, false));
+
+        let max = FixedI256::<U8>::MAX;
+        assert_eq!(DynFixed::checked_from_fixed(max), None);
+        assert_eq!(DynFixed::saturating_from_fixed(max), i120f8.max());
+        assert_eq!(
+            DynFixed::saturating_from_fixed(FixedI256::<U8>::MIN),
+            i120f8.min()
+        );
+        assert_eq!(
+            DynFixed::overflowing_from_fixed(max),
+            (DynFixed::from_bits(u128::MAX, i120f8), true)
+        );
+        assert_eq!(DynFixed::wrapping_from_fixed(-one), -d);
+
+        // extra fractional bits are discarded
+        let u56f200 = FixedU256::<U200>::from_num(0.75) + FixedU256::<U200>::DELTA;
+        let u0f128 = DynFormat::unsigned(128, 128).unwrap();
+        let d = DynFixed::checked_from_fixed(u56f200).unwrap();
+        assert_eq!(d.format(), u0f128);
+        assert_eq!(d, DynFixed::from_num(0.75, u0f128));
+        assert_eq!(DynFixed::checked_from_fixed(FixedU256::<U200>::MAX), None);
+    }
+
+    #[test]
+    fn from_wide_fixed_round() {
+        use crate::FixedU256;
+        use crate::RoundingMode;
+        use crate::traits::{Fixed, FromFixed};
+        use crate::types::extra::U200;
+
+        let u0f128 = DynFormat::unsigned(128, 128).unwrap();
+        let three_quarters = DynFixed::from_num(0.75, u0f128);
+        let delta = DynFixed::from_bits(1, u0f128);
+        let src = FixedU256::<U200>::from_num(0.75) + FixedU256::<U200>::DELTA;
+        assert_eq!(
+            src.to_num_round::<DynFixed>(RoundingMode::Floor),
+            three_quarters
+        );
+        assert_eq!(
+            src.to_num_round::<DynFixed>(RoundingMode::Ceil),
+            three_quarters + delta
+        );
+        assert_eq!(
+            DynFixed::checked_from_fixed_round(src, RoundingMode::NearestEven),
+            Some(three_quarters)
+        );
+        assert_eq!(
+            <FixedU256<U200> as Fixed>::overflowing_to_num_round::<DynFixed>(
+                FixedU256::<U200>::MAX,
+                RoundingMode::Ceil
+            ),
+            (u0f128.zero(), true)
+        );
+        assert_eq!(
+            DynFixed::saturating_from_fixed_round(FixedU256::<U200>::MAX, RoundingMode::Ceil),
+            u0f128.max()
+        );
+    }
+
+    #[test]
+    fn hash_and_eq() {
+        use core::hash::BuildHasher;
+        use std::collections::hash_map::RandomState;
+        let state = RandomState::new();
+        let hash = |d: DynFixed| state.hash_one(d);
+        let a = DynFixed::from_num(-1.5, DynFormat::signed(8, 4).unwrap());
+        let b = DynFixed::from_num(-1.5, DynFormat::signed(64, 40).unwrap());
+        assert_eq!(a, b);
+        assert_eq!(hash(a), hash(b));
+        let z1 = DynFormat::unsigned(8, 8).unwrap().zero();
+        let z2 = DynFormat::signed(100, 3).unwrap().zero();
+        assert_eq!(z1, z2);
+        assert_eq!(hash(z1), hash(z2));
+        assert!(a < z1);
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let q15 = DynFormat::signed(16, 15).unwrap();
+        let d = DynFixed::from_bits(0xA000, q15);
+        assert_eq!(format!("{d}"), "-0.75");
+        assert_eq!(format!("{d:?}"), "-0.75");
+        assert_eq!(format!("{d:b}"), "-0.11");
+        assert_eq!(format!("{d:x}"), "-0.c");
+        assert_eq!(format!("{d:X}"), "-0.C");
+        assert_eq!(format!("{d:o}"), "-0.6");
+        assert_eq!(format!("{d:e}"), "-7.5e-1");
+        assert_eq!(format!("{d:.2}"), "-0.75");
+        for s in ["0", "-1", "0.99997", "-0.00003"] {
+            assert_eq!(format!("{}", DynFixed::from_str(s, q15).unwrap()), s);
+        }
+        assert!(DynFixed::from_str("1", q15).is_err());
+        assert!(DynFixed::from_str("x", q15).is_err());
+        assert_eq!(DynFixed::saturating_from_str("1", q15), Ok(q15.max()));
+        assert_eq!(DynFixed::wrapping_from_str("1", q15), Ok(q15.min()));
+        let u128f0 = DynFormat::unsigned(128, 0).unwrap();
+        let max = format!("{}", u128::MAX);
+        assert_eq!(DynFixed::from_str(&max, u128f0), Ok(u128f0.max()));
+        assert_eq!(
+            DynFixed::saturating_from_str("-1", u128f0),
+            Ok(u128f0.zero())
+        );
+        let u0f128 = DynFormat::unsigned(128, 128).unwrap();
+        assert_eq!(
+            DynFixed::from_str("0.5", u0f128).unwrap().to_bits(),
+            1 << 127
+        );
+    }
+}
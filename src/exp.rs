@@ -0,0 +1,1119 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::int256;
+use crate::int256::U256;
+
+// The exponential 2^x is computed by splitting x into an integer part n and a
+// fractional part 0 ≤ f < 1, so that 2^x = 2^n × 2^f. Then 2^f is the product
+// of 2^(2^−(i+1)) for all the bits i that are set in f, where the factors are
+// taken from a table. To keep more bits, the factors are stored with 1
+// subtracted, and (1 + a) × (1 + b) is computed as 1 + (a + b + a × b).
+//
+// The exponential e^x is computed as 2^(x × log₂ e).
+//
+// The magnitude of x is stored with ten integer bits and the remaining bits are
+// fractional bits. The wide functions, which are used for 128-bit fixed-point
+// numbers, have 246 fractional bits inside a U256. The narrow functions, which
+// are used for all other fixed-point numbers, have 118 fractional bits inside a
+// u128. If |x| ≥ 512 before conversion to base 2, the result overflows all
+// fixed-point numbers or rounds to zero, so the computation is skipped.
+//
+// The result 2^f is finally stored as a U256 with 255 fractional bits, and the
+// final shift rounds to the nearest.
+//
+// The hyperbolic functions are computed from e^|x| and e^−|x|, which are both
+// found with a 256-bit mantissa. The difference and sum of the two are then
+// rounded to the nearest only once, so there is no loss of accuracy even when
+// there is cancellation in sinh for small x. For tanh, the ratio of the
+// difference to the sum is found using long division. Since |tanh x| < |x| for
+// x ≠ 0, the rounded result of tanh never overflows.
+
+const WIDE_FRAC_NBITS: u32 = 246;
+const NARROW_FRAC_NBITS: u32 = 118;
+const MAX_INT_NBITS: u32 = 9;
+
+/*
+```rust
+use rug::{Assign, Float, Integer};
+
+fn u256(val: Float) -> String {
+    let int = (val << 256u32).to_integer().unwrap();
+    let lo = Integer::from(&int & ((Integer::from(1) << 128u32) - 1u32));
+    let hi = Integer::from(&int >> 128u32);
+    let hex = |i: Integer| {
+        let mut s = format!("0x{i:032X}");
+        for i in 0..7 {
+            s.insert(6 + 5 * i, '_');
+        }
+        s
+    };
+    format!("U256 {{\n    lo: {},\n    hi: {},\n}}", hex(lo), hex(hi))
+}
+
+fn float<T>(t: T) -> Float
+where
+    Float: Assign<T>,
+{
+    Float::with_val(1000, t)
+}
+
+fn main() {
+    for i in 0..128 {
+        println!("{},", u256((float(1) >> (i + 1)).exp2() - 1u32));
+    }
+    println!("{}", u256(float(1).exp().log2() - 1u32));
+}
+```
+*/
+
+// EXP2_TABLE[i] = 2^(2^−(i+1)) − 1 with 256 fractional bits, rounded to the
+// nearest.
+const EXP2_TABLE: [U256; 128] = [
+    U256 {
+        lo: 0x3ADE_C175_1277_5099_DA2F_590B_0667_322B,
+        hi: 0x6A09_E667_F3BC_C908_B2FB_1366_EA95_7D3E,
+    },
+    U256 {
+        lo: 0xBC27_3436_29F5_02F1_AF16_CB97_DF4F_76DF,
+        hi: 0x306F_E0A3_1B71_52DE_8D5A_4630_5C85_EDEC,
+    },
+    U256 {
+        lo: 0x3550_9FF7_D758_693F_23C2_6BDD_0947_EE67,
+        hi: 0x172B_83C7_D517_ADCD_F7C8_C50E_B14A_7920,
+    },
+    U256 {
+        lo: 0x2914_08B3_CEB0_A2A2_BA85_66C5_5E3D_D0B3,
+        hi: 0x0B55_86CF_9890_F629_8B92_B718_42A9_8364,
+    },
+    U256 {
+        lo: 0x53E3_495F_7DF4_BAF8_4A05_E2A0_CE6F_142E,
+        hi: 0x059B_0D31_5857_43AE_7C54_8EB6_8CA4_17FE,
+    },
+    U256 {
+        lo: 0x3D70_A2CA_BC5C_B89B_A100_EB58_3E40_1C98,
+        hi: 0x02C9_A3E7_7806_0EE6_F7CA_CA4F_7A29_BDE9,
+    },
+    U256 {
+        lo: 0x03EC_04C3_60BE_2404_0786_AB59_749B_E9F4,
+        hi: 0x0163_DA9F_B333_56D8_4A66_AE33_6DCD_FA40,
+    },
+    U256 {
+        lo: 0x55B8_187F_0C97_41EB_3B8E_0232_2A97_1F34,
+        hi: 0x00B1_AFA5_ABCB_ED61_29AB_13EC_11DC_9544,
+    },
+    U256 {
+        lo: 0x68BF_05EB_63F5_C41C_7A16_3180_D2EB_82C4,
+        hi: 0x0058_C86D_A1C0_9EA1_FF19_D294_CF2F_679C,
+    },
+    U256 {
+        lo: 0x806C_0A42_D5DA_E7E0_9371_EE3B_9549_2845,
+        hi: 0x002C_605E_2E8C_EC50_6D21_BFC8_9A23_A010,
+    },
+    U256 {
+        lo: 0x6DDD_3115_5FE0_6C40_ED98_6FFF_2B09_9C2A,
+        hi: 0x0016_2F39_0405_1FA1_28BC_A9C5_5C31_E5E0,
+    },
+    U256 {
+        lo: 0x6946_30E2_F50B_A331_28B6_794C_2416_FAA9,
+        hi: 0x000B_175E_FFDC_76BA_38E3_1671_CA93_9726,
+    },
+    U256 {
+        lo: 0x43A8_6F9E_D9B4_CED9_0537_E130_5A8E_069E,
+        hi: 0x0005_8BA0_1FB9_F96D_6CAC_D4B1_8091_7C3E,
+    },
+    U256 {
+        lo: 0xD505_D939_D869_B9E7_BCE6_20A8_4552_51E1,
+        hi: 0x0002_C5CC_37DA_9491_D098_5C34_8C68_E7B3,
+    },
+    U256 {
+        lo: 0xD9D4_7870_C82F_4523_9318_734C_AAC6_0563,
+        hi: 0x0001_62E5_25EE_0547_5445_7D59_9529_2026,
+    },
+    U256 {
+        lo: 0xA8F8_CFDF_C7EB_1DBF_36E5_12DF_BB33_1F94,
+        hi: 0x0000_B172_5577_5C04_0618_BF4A_4ADE_83FC,
+    },
+    U256 {
+        lo: 0x1B1B_76FB_F41B_08A8_8EFE_4705_DA0D_4669,
+        hi: 0x0000_58B9_1B5B_C9AE_2EED_81E9_B7D4_CFAC,
+    },
+    U256 {
+        lo: 0xD9CD_F6F6_72D9_A286_EEA1_4A31_BA5B_1E48,
+        hi: 0x0000_2C5C_89D5_EC6C_A4D7_C8AC_C017_B7C9,
+    },
+    U256 {
+        lo: 0x6FB3_32D9_BABC_52DE_4661_5331_8747_CDBD,
+        hi: 0x0000_162E_43F4_F831_060E_02D8_39A9_D16D,
+    },
+    U256 {
+        lo: 0x7D19_0CB3_E159_DFE8_7B96_7E31_563D_5F3E,
+        hi: 0x0000_0B17_21BC_FC99_D9F8_90EA_0691_1763,
+    },
+    U256 {
+        lo: 0x9FA7_5FFD_0B14_CF25_2E9F_A6BD_175D_44F2,
+        hi: 0x0000_058B_90CF_1E6D_97F9_CA14_DBCC_1628,
+    },
+    U256 {
+        lo: 0x11F6_D72B_C102_0152_8AE0_9002_D1A3_6A3A,
+        hi: 0x0000_02C5_C863_B73F_0164_68F6_BAC5_CA2C,
+    },
+    U256 {
+        lo: 0xF70F_2C46_B0DD_191F_C26D_5880_C5F0_E895,
+        hi: 0x0000_0162_E430_E5A1_8F61_19E3_C022_82A5,
+    },
+    U256 {
+        lo: 0x6819_E466_8BEE_A56A_31DA_E4BA_6A36_FEF7,
+        hi: 0x0000_00B1_7218_3551_4B86_E6D9_6EFD_1BFF,
+    },
+    U256 {
+        lo: 0x2C95_A3BB_C281_9121_EBDB_E8C1_2204_2D5B,
+        hi: 0x0000_0058_B90C_0B48_C6BE_5DF8_46C5_B2F0,
+    },
+    U256 {
+        lo: 0xBFEF_0E9C_3BA6_06C1_F7FD_7FE3_F525_62DD,
+        hi: 0x0000_002C_5C86_01CC_6B9E_9421_3C72_737A,
+    },
+    U256 {
+        lo: 0xBAA5_3C7E_7FA7_F95E_2222_30D7_8F2C_60C4,
+        hi: 0x0000_0016_2E42_FFF0_37DF_38AA_2B21_9F06,
+    },
+    U256 {
+        lo: 0xC7FA_9EE4_1FE8_C78D_E8D7_A7CF_58BE_0DE1,
+        hi: 0x0000_000B_1721_7FBA_9C73_9AA5_819F_44F9,
+    },
+    U256 {
+        lo: 0xB106_57B2_B69A_DC8E_CA74_18B1_D058_2A80,
+        hi: 0x0000_0005_8B90_BFCD_EE5A_CD3C_1CED_C823,
+    },
+    U256 {
+        lo: 0xFD0F_47F5_4E7C_4107_75CA_EB3B_8498_5F55,
+        hi: 0x0000_0002_C5C8_5FE3_1F35_A6A3_0DA1_BE50,
+    },
+    U256 {
+        lo: 0xBA83_C5E1_1BA8_3DD6_2E6B_390F_BD5D_DA4D,
+        hi: 0x0000_0001_62E4_2FF0_999C_E354_1B9F_FFCF,
+    },
+    U256 {
+        lo: 0x66E7_0CC0_654E_E4FE_2B07_6880_BC6D_EC3B,
+        hi: 0x0000_0000_B172_17F8_0F4E_F5AA_DDA4_5554,
+    },
+    U256 {
+        lo: 0xA9F6_44DE_97E5_1FA2_8ED4_558C_7644_5E87,
+        hi: 0x0000_0000_58B9_0BFB_F847_9BD5_A81B_51AD,
+    },
+    U256 {
+        lo: 0xBCEB_4B8E_2DC9_EB2F_CCA1_190F_921C_4F41,
+        hi: 0x0000_0000_2C5C_85FD_F84B_D62A_E30A_74CC,
+    },
+    U256 {
+        lo: 0x79B8_63EE_9816_6287_848F_AF87_8F74_EBB0,
+        hi: 0x0000_0000_162E_42FE_FB2F_ED25_7559_BDAA,
+    },
+    U256 {
+        lo: 0x17D5_8448_2369_AF7C_574F_99B0_A072_989F,
+        hi: 0x0000_0000_0B17_217F_7D5A_7716_BBA4_A9AF,
+    },
+    U256 {
+        lo: 0x1D2E_27D5_D64E_66E5_4441_1D11_B50F_9C69,
+        hi: 0x0000_0000_058B_90BF_BE9D_DBAC_5E10_9CCF,
+    },
+    U256 {
+        lo: 0x9638_8F47_5923_CFE4_CAA2_AAB7_0F8F_4D3F,
+        hi: 0x0000_0000_02C5_C85F_DF4B_15DE_6F17_EB0D,
+    },
+    U256 {
+        lo: 0x516E_BAB5_8C5B_B5CF_5D6D_15DE_85EA_52A6,
+        hi: 0x0000_0000_0162_E42F_EFA4_94F1_478F_DE05,
+    },
+    U256 {
+        lo: 0xEAD9_3CA6_62F4_A131_B103_63DB_AF8F_0BC1,
+        hi: 0x0000_0000_00B1_7217_F7D2_0CF9_27C8_E94C,
+    },
+    U256 {
+        lo: 0x5A06_BE76_FA0B_3C65_5D09_6988_E0BC_66EB,
+        hi: 0x0000_0000_0058_B90B_FBE8_F71C_B4E4_B33E,
+    },
+    U256 {
+        lo: 0xD0AC_1C4E_8B22_0B6B_0ADC_09FC_FD39_E443,
+        hi: 0x0000_0000_002C_5C85_FDF4_77B6_62B2_6945,
+    },
+    U256 {
+        lo: 0xA690_840D_4C93_EBFC_2395_F580_4321_0759,
+        hi: 0x0000_0000_0016_2E42_FEFA_3AE5_3369_388C,
+    },
+    U256 {
+        lo: 0xC580_E854_5079_F539_C831_F132_55B4_1C34,
+        hi: 0x0000_0000_000B_1721_7F7D_1D35_1A38_9D40,
+    },
+    U256 {
+        lo: 0xFFA3_DED8_17D6_DFE5_C36E_00EE_DAF3_A048,
+        hi: 0x0000_0000_0005_8B90_BFBE_8E8B_2D3D_4EDE,
+    },
+    U256 {
+        lo: 0x2715_723A_D873_A5C0_A2C1_A69A_EE3B_6622,
+        hi: 0x0000_0000_0002_C5C8_5FDF_4741_BEA6_E77F,
+    },
+    U256 {
+        lo: 0x7D5C_EED5_8970_17DF_F77A_0903_88F8_8130,
+        hi: 0x0000_0000_0001_62E4_2FEF_A39F_E955_83C3,
+    },
+    U256 {
+        lo: 0xB923_2F79_5948_2421_3849_216F_A619_DCE1,
+        hi: 0x0000_0000_0000_B172_17F7_D1CF_B72B_45E2,
+    },
+    U256 {
+        lo: 0x9B2E_CB14_6370_68FB_126D_01C3_0288_4FB2,
+        hi: 0x0000_0000_0000_58B9_0BFB_E8E7_CC35_C3F1,
+    },
+    U256 {
+        lo: 0xDD3E_B30A_A1A0_5453_EB79_955C_3EBC_715B,
+        hi: 0x0000_0000_0000_2C5C_85FD_F473_E242_EA38,
+    },
+    U256 {
+        lo: 0x7289_2CFA_BD10_D363_06E0_7383_AC9A_E854,
+        hi: 0x0000_0000_0000_162E_42FE_FA39_F02B_772C,
+    },
+    U256 {
+        lo: 0x3A3F_0B5D_63A1_6828_36CB_4CB9_1353_2AAE,
+        hi: 0x0000_0000_0000_0B17_217F_7D1C_F7D8_3C1A,
+    },
+    U256 {
+        lo: 0x1D5E_22E7_0858_0E36_D61E_ACF7_4401_7FBB,
+        hi: 0x0000_0000_0000_058B_90BF_BE8E_7BDC_BE2E,
+    },
+    U256 {
+        lo: 0x4EBE_B8C1_A476_00F4_BB79_E02A_38F7_84C6,
+        hi: 0x0000_0000_0000_02C5_C85F_DF47_3DEA_871F,
+    },
+    U256 {
+        lo: 0xB763_4634_5BA2_835A_C60F_1B7E_83F5_6C92,
+        hi: 0x0000_0000_0000_0162_E42F_EFA3_9EF4_4D91,
+    },
+    U256 {
+        lo: 0x5FB2_9D8F_1055_C2F2_BFA3_09B9_205B_9DEE,
+        hi: 0x0000_0000_0000_00B1_7217_F7D1_CF79_E949,
+    },
+    U256 {
+        lo: 0xD0D9_8D64_C0D1_55DC_5F49_41FE_F51B_0863,
+        hi: 0x0000_0000_0000_0058_B90B_FBE8_E7BC_E544,
+    },
+    U256 {
+        lo: 0x70AC_D659_AE92_F289_248C_AC0C_913F_BD7B,
+        hi: 0x0000_0000_0000_002C_5C85_FDF4_73DE_6ECA,
+    },
+    U256 {
+        lo: 0x3A66_6F16_AAD4_207B_9621_9C42_1DF8_0F1E,
+        hi: 0x0000_0000_0000_0016_2E42_FEFA_39EF_366F,
+    },
+    U256 {
+        lo: 0x1DB7_3885_CA4C_BCB5_94DB_C81F_1647_5433,
+        hi: 0x0000_0000_0000_000B_1721_7F7D_1CF7_9AFA,
+    },
+    U256 {
+        lo: 0xAEFC_9C81_825F_09CD_FDFB_279C_EF35_2219,
+        hi: 0x0000_0000_0000_0005_8B90_BFBE_8E7B_CD6D,
+    },
+    U256 {
+        lo: 0xFF86_8E50_687D_AFCE_7404_3553_8CE6_C374,
+        hi: 0x0000_0000_0000_0002_C5C8_5FDF_473D_E6B2,
+    },
+    U256 {
+        lo: 0x89C5_572C_1E12_62A2_6C48_2D1F_434F_68DD,
+        hi: 0x0000_0000_0000_0001_62E4_2FEF_A39E_F358,
+    },
+    U256 {
+        lo: 0x0763_2F97_097E_1400_2D56_286F_87CF_D588,
+        hi: 0x0000_0000_0000_0000_B172_17F7_D1CF_79AC,
+    },
+    U256 {
+        lo: 0xF451_B8CB_C35C_42AB_D9CB_AA58_0E50_16F5,
+        hi: 0x0000_0000_0000_0000_58B9_0BFB_E8E7_BCD5,
+    },
+    U256 {
+        lo: 0xF650_E4A5_F155_6F80_DE58_7CE9_23DD_DB04,
+        hi: 0x0000_0000_0000_0000_2C5C_85FD_F473_DE6A,
+    },
+    U256 {
+        lo: 0x7A32_7462_FC94_8B4B_2B9E_38AA_7A5F_D9F4,
+        hi: 0x0000_0000_0000_0000_162E_42FE_FA39_EF35,
+    },
+    U256 {
+        lo: 0xBCDB_BAB5_7F44_BA88_44EE_44EB_8B74_9728,
+        hi: 0x0000_0000_0000_0000_0B17_217F_7D1C_F79A,
+    },
+    U256 {
+        lo: 0x5E5E_7D7B_BFE0_FA7C_CE3F_41DC_73D0_8402,
+        hi: 0x0000_0000_0000_0000_058B_90BF_BE8E_7BCD,
+    },
+    U256 {
+        lo: 0xAF2B_66C6_2000_248C_9211_B370_08BE_71D9,
+        hi: 0x0000_0000_0000_0000_02C5_C85F_DF47_3DE6,
+    },
+    U256 {
+        lo: 0x5794_BD65_2003_FC19_D3C5_5FAD_7C7E_D91A,
+        hi: 0x0000_0000_0000_0000_0162_E42F_EFA3_9EF3,
+    },
+    U256 {
+        lo: 0xABCA_2133_1402_F881_CC91_D17E_BCD4_971F,
+        hi: 0x0000_0000_0000_0000_00B1_7217_F7D1_CF79,
+    },
+    U256 {
+        lo: 0xD5E5_0139_AB01_BADE_1EF4_B12E_B221_3E85,
+        hi: 0x0000_0000_0000_0000_0058_B90B_FBE8_E7BC,
+    },
+    U256 {
+        lo: 0x6AF2_7CC4_DDC0_ED16_5DA5_4AB3_D880_910A,
+        hi: 0x0000_0000_0000_0000_002C_5C85_FDF4_73DE,
+    },
+    U256 {
+        lo: 0x3579_3D6C_70F0_7A75_025D_61E1_216C_8B98,
+        hi: 0x0000_0000_0000_0000_0016_2E42_FEFA_39EF,
+    },
+    U256 {
+        lo: 0x9ABC_9E78_B8FC_3E34_F611_6012_60AB_5F65,
+        hi: 0x0000_0000_0000_0000_000B_1721_7F7D_1CF7,
+    },
+    U256 {
+        lo: 0xCD5E_4F2C_FC9F_1F59_1841_5BD1_A4A8_3733,
+        hi: 0x0000_0000_0000_0000_0005_8B90_BFBE_8E7B,
+    },
+    U256 {
+        lo: 0xE6AF_2792_A657_CFBC_336E_D8DA_EF73_659D,
+        hi: 0x0000_0000_0000_0000_0002_C5C8_5FDF_473D,
+    },
+    U256 {
+        lo: 0xF357_93C8_5D2D_F7E2_038A_F729_FF02_DA54,
+        hi: 0x0000_0000_0000_0000_0001_62E4_2FEF_A39E,
+    },
+    U256 {
+        lo: 0x79AB_C9E3_F117_7FF1_FC3A_5E44_2153_E1AC,
+        hi: 0x0000_0000_0000_0000_0000_B172_17F7_D1CF,
+    },
+    U256 {
+        lo: 0xBCD5_E4F1_E92B_E0F9_3CBA_67CD_D91E_934A,
+        hi: 0x0000_0000_0000_0000_0000_58B9_0BFB_E8E7,
+    },
+    U256 {
+        lo: 0xDE6A_F278_F0BD_F8BC_AE04_8211_DEAC_72ED,
+        hi: 0x0000_0000_0000_0000_0000_2C5C_85FD_F473,
+    },
+    U256 {
+        lo: 0xEF35_793C_7768_FE6E_5AEC_1493_ABDD_83DE,
+        hi: 0x0000_0000_0000_0000_0000_162E_42FE_FA39,
+    },
+    U256 {
+        lo: 0xF79A_BC9E_3B76_FFBB_2E70_7F2C_8510_948B,
+        hi: 0x0000_0000_0000_0000_0000_0B17_217F_7D1C,
+    },
+    U256 {
+        lo: 0x7BCD_5E4F_1DAC_1FFE_9776_DCCE_EE50_BEED,
+        hi: 0x0000_0000_0000_0000_0000_058B_90BF_BE8E,
+    },
+    U256 {
+        lo: 0x3DE6_AF27_8ED2_3807_8BCB_15B5_A21A_7CA0,
+        hi: 0x0000_0000_0000_0000_0000_02C5_C85F_DF47,
+    },
+    U256 {
+        lo: 0x9EF3_5793_C768_2605_D5E9_74AE_5BC9_C59B,
+        hi: 0x0000_0000_0000_0000_0000_0162_E42F_EFA3,
+    },
+    U256 {
+        lo: 0xCF79_ABC9_E3B3_D583_6EF5_B4CC_1094_04A0,
+        hi: 0x0000_0000_0000_0000_0000_00B1_7217_F7D1,
+    },
+    U256 {
+        lo: 0xE7BC_D5E4_F1D9_DB61_D87B_1903_40F5_CAC5,
+        hi: 0x0000_0000_0000_0000_0000_0058_B90B_FBE8,
+    },
+    U256 {
+        lo: 0x73DE_6AF2_78EC_E9D8_F47D_9C28_EEA5_D77F,
+        hi: 0x0000_0000_0000_0000_0000_002C_5C85_FDF4,
+    },
+    U256 {
+        lo: 0x39EF_3579_3C76_73F6_7C4E_D1FE_4ADD_A847,
+        hi: 0x0000_0000_0000_0000_0000_0016_2E42_FEFA,
+    },
+    U256 {
+        lo: 0x1CF7_9ABC_9E3B_39BD_BEAB_69F9_9A51_8345,
+        hi: 0x0000_0000_0000_0000_0000_000B_1721_7F7D,
+    },
+    U256 {
+        lo: 0x8E7B_CD5E_4F1D_9CCF_7F76_B53B_6A61_6D6B,
+        hi: 0x0000_0000_0000_0000_0000_0005_8B90_BFBE,
+    },
+    U256 {
+        lo: 0x473D_E6AF_278E_CE63_E7C3_9AAD_5C7E_E1A8,
+        hi: 0x0000_0000_0000_0000_0000_0002_C5C8_5FDF,
+    },
+    U256 {
+        lo: 0xA39E_F357_93C7_6730_FDE3_DD5A_9812_FB90,
+        hi: 0x0000_0000_0000_0000_0000_0001_62E4_2FEF,
+    },
+    U256 {
+        lo: 0xD1CF_79AB_C9E3_B398_4172_72AE_467E_6077,
+        hi: 0x0000_0000_0000_0000_0000_0000_B172_17F7,
+    },
+    U256 {
+        lo: 0xE8E7_BCD5_E4F1_D9CC_1159_5A57_61DC_68E7,
+        hi: 0x0000_0000_0000_0000_0000_0000_58B9_0BFB,
+    },
+    U256 {
+        lo: 0xF473_DE6A_F278_ECE6_04D4_B56B_C095_829F,
+        hi: 0x0000_0000_0000_0000_0000_0000_2C5C_85FD,
+    },
+    U256 {
+        lo: 0xFA39_EF35_793C_7673_0174_5CC5_E434_94DA,
+        hi: 0x0000_0000_0000_0000_0000_0000_162E_42FE,
+    },
+    U256 {
+        lo: 0x7D1C_F79A_BC9E_3B39_807C_AEE6_F314_BF50,
+        hi: 0x0000_0000_0000_0000_0000_0000_0B17_217F,
+    },
+    U256 {
+        lo: 0xBE8E_7BCD_5E4F_1D9C_C02E_F794_79C8_FCE1,
+        hi: 0x0000_0000_0000_0000_0000_0000_058B_90BF,
+    },
+    U256 {
+        lo: 0xDF47_3DE6_AF27_8ECE_6013_A3D2_7CF4_25BE,
+        hi: 0x0000_0000_0000_0000_0000_0000_02C5_C85F,
+    },
+    U256 {
+        lo: 0xEFA3_9EF3_5793_C767_3008_DBEB_4E7D_FCB3,
+        hi: 0x0000_0000_0000_0000_0000_0000_0162_E42F,
+    },
+    U256 {
+        lo: 0xF7D1_CF79_ABC9_E3B3_9804_3076_2B3F_F8CE,
+        hi: 0x0000_0000_0000_0000_0000_0000_00B1_7217,
+    },
+    U256 {
+        lo: 0xFBE8_E7BC_D5E4_F1D9_CC02_08DB_36A0_3B04,
+        hi: 0x0000_0000_0000_0000_0000_0000_0058_B90B,
+    },
+    U256 {
+        lo: 0xFDF4_73DE_6AF2_78EC_E601_0095_A390_2D29,
+        hi: 0x0000_0000_0000_0000_0000_0000_002C_5C85,
+    },
+    U256 {
+        lo: 0xFEFA_39EF_3579_3C76_7300_7F54_D3D8_1A7F,
+        hi: 0x0000_0000_0000_0000_0000_0000_0016_2E42,
+    },
+    U256 {
+        lo: 0x7F7D_1CF7_9ABC_9E3B_3980_3F6C_EA70_0E3A,
+        hi: 0x0000_0000_0000_0000_0000_0000_000B_1721,
+    },
+    U256 {
+        lo: 0xBFBE_8E7B_CD5E_4F1D_9CC0_1FA7_1559_075B,
+        hi: 0x0000_0000_0000_0000_0000_0000_0005_8B90,
+    },
+    U256 {
+        lo: 0x5FDF_473D_E6AF_278E_CE60_0FCF_B2B4_C3BD,
+        hi: 0x0000_0000_0000_0000_0000_0000_0002_C5C8,
+    },
+    U256 {
+        lo: 0x2FEF_A39E_F357_93C7_6730_07E6_E35C_71E3,
+        hi: 0x0000_0000_0000_0000_0000_0000_0001_62E4,
+    },
+    U256 {
+        lo: 0x17F7_D1CF_79AB_C9E3_B398_03F3_342E_BCF2,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_B172,
+    },
+    U256 {
+        lo: 0x0BFB_E8E7_BCD5_E4F1_D9CC_01F9_8AB7_7F79,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_58B9,
+    },
+    U256 {
+        lo: 0x85FD_F473_DE6A_F278_ECE6_00FC_C183_C7FD,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_2C5C,
+    },
+    U256 {
+        lo: 0x42FE_FA39_EF35_793C_7673_007E_5FCB_E60E,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_162E,
+    },
+    U256 {
+        lo: 0x217F_7D1C_F79A_BC9E_3B39_803F_2FA8_738B,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0B17,
+    },
+    U256 {
+        lo: 0x90BF_BE8E_7BCD_5E4F_1D9C_C01F_97C4_D9E7,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_058B,
+    },
+    U256 {
+        lo: 0xC85F_DF47_3DE6_AF27_8ECE_600F_CBDE_94FC,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_02C5,
+    },
+    U256 {
+        lo: 0xE42F_EFA3_9EF3_5793_C767_3007_E5EE_5480,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0162,
+    },
+    U256 {
+        lo: 0x7217_F7D1_CF79_ABC9_E3B3_9803_F2F6_ECC0,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_00B1,
+    },
+    U256 {
+        lo: 0xB90B_FBE8_E7BC_D5E4_F1D9_CC01_F97B_6700,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0058,
+    },
+    U256 {
+        lo: 0x5C85_FDF4_73DE_6AF2_78EC_E600_FCBD_AFA8,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_002C,
+    },
+    U256 {
+        lo: 0x2E42_FEFA_39EF_3579_3C76_7300_7E5E_D6DE,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0016,
+    },
+    U256 {
+        lo: 0x1721_7F7D_1CF7_9ABC_9E3B_3980_3F2F_6B32,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_000B,
+    },
+    U256 {
+        lo: 0x8B90_BFBE_8E7B_CD5E_4F1D_9CC0_1F97_B589,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0005,
+    },
+    U256 {
+        lo: 0xC5C8_5FDF_473D_E6AF_278E_CE60_0FCB_DAC1,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0002,
+    },
+    U256 {
+        lo: 0x62E4_2FEF_A39E_F357_93C7_6730_07E5_ED5F,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0001,
+    },
+    U256 {
+        lo: 0xB172_17F7_D1CF_79AB_C9E3_B398_03F2_F6AF,
+        hi: 0x0000_0000_0000_0000_0000_0000_0000_0000,
+    },
+];
+
+// log₂ e − 1 with 256 fractional bits, rounded to the nearest.
+const LOG2_E_M1: U256 = U256 {
+    lo: 0xD6AE_F551_BAD2_B4B1_164A_2CD9_A342_6490,
+    hi: 0x7154_7652_B82F_E177_7D0F_FDA0_D23A_7D11,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub enum ExpBase {
+    Two,
+    E,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Hyperbolic {
+    Sinh,
+    Cosh,
+    Tanh,
+}
+
+// Returns 2^f − 1, where both f and the result have 128 fractional bits.
+const fn narrow_exp2_frac(f: u128) -> u128 {
+    let mut ans = 0u128;
+    let mut rem = f;
+    while rem != 0 {
+        let i = rem.leading_zeros();
+        rem &= !(1 << (127 - i));
+        let factor = EXP2_TABLE[i as usize].hi;
+        ans += factor + int256::wide_mul_u128(ans, factor).hi;
+    }
+    ans
+}
+
+// Returns (1 + a) × (1 + b) − 1.
+const fn wide_mul_add(a: U256, b: U256) -> U256 {
+    let (sum, _) = int256::overflowing_add_u256(a, b);
+    let (sum, _) = int256::overflowing_add_u256(sum, int256::mul_hi_u256(a, b));
+    sum
+}
+
+// Returns 2^f − 1, where both f and the result have 256 fractional bits.
+pub const fn wide_exp2_frac(f: U256) -> U256 {
+    let mut ans = U256 { lo: 0, hi: 0 };
+    let mut rem = f.hi;
+    while rem != 0 {
+        let i = rem.leading_zeros();
+        rem &= !(1 << (127 - i));
+        ans = wide_mul_add(ans, EXP2_TABLE[i as usize]);
+    }
+    // For the remaining r < 2^−128, 2^r − 1 = r ln 2 with an error < 2^−256.
+    if f.lo != 0 {
+        let r_ln_2 = U256 {
+            lo: int256::wide_mul_u128(f.lo, consts::LN_2.to_bits()).hi,
+            hi: 0,
+        };
+        ans = wide_mul_add(ans, r_ln_2);
+    }
+    ans
+}
+
+// Returns round(a × 2^−sh), where sh ≥ 1.
+const fn shr_round(a: U256, sh: u32) -> U256 {
+    let (shifted, round) = if sh > 256 {
+        (U256 { lo: 0, hi: 0 }, 0)
+    } else if sh == 256 {
+        (U256 { lo: 0, hi: 0 }, a.hi >> 127)
+    } else if sh > 128 {
+        let shifted = U256 {
+            lo: a.hi >> (sh - 128),
+            hi: 0,
+        };
+        (shifted, (a.hi >> (sh - 129)) & 1)
+    } else if sh == 128 {
+        (U256 { lo: a.hi, hi: 0 }, a.lo >> 127)
+    } else {
+        (int256::shl_u256_max_128(a, sh), (a.lo >> (sh - 1)) & 1)
+    };
+    int256::wrapping_add_u256_u128(shifted, round)
+}
+
+// Returns mant × 2^(exp − 255) rounded to the nearest, where 1 ≤ mant < 2 has
+// 255 fractional bits. The overflow flag is set if the result is ≥ 2^129, in
+// which case only the lower 128 bits of the result are returned.
+pub const fn scale(mant: U256, exp: i32) -> (U256, bool) {
+    if exp <= 128 {
+        return (shr_round(mant, (255 - exp) as u32), false);
+    }
+    let lo = if exp >= 255 + 128 {
+        0
+    } else if exp >= 255 {
+        mant.lo << (exp - 255)
+    } else {
+        shr_round(mant, (255 - exp) as u32).lo
+    };
+    (U256 { lo, hi: 0 }, true)
+}
+
+// Returns (n, f) such that x = n + f, where 0 ≤ f < 1 has 128 fractional bits.
+const fn narrow_split(neg: bool, abs: u128, frac_nbits: u32, base: ExpBase) -> (i32, u128) {
+    let mut x = abs << (NARROW_FRAC_NBITS - frac_nbits);
+    if let ExpBase::E = base {
+        x += int256::wide_mul_u128(x, LOG2_E_M1.hi).hi;
+    }
+    let int = (x >> NARROW_FRAC_NBITS) as i32;
+    let frac = x << (128 - NARROW_FRAC_NBITS);
+    if !neg {
+        (int, frac)
+    } else if frac == 0 {
+        (-int, 0)
+    } else {
+        (-int - 1, frac.wrapping_neg())
+    }
+}
+
+// Returns (n, f) such that x = n + f, where 0 ≤ f < 1 has 256 fractional bits.
+const fn wide_split(neg: bool, abs: u128, frac_nbits: u32, base: ExpBase) -> (i32, U256) {
+    let x = U256 { lo: abs, hi: 0 };
+    let mut x = int256::wrapping_shl_u256(x, WIDE_FRAC_NBITS - frac_nbits);
+    if let ExpBase::E = base {
+        (x, _) = int256::overflowing_add_u256(x, int256::mul_hi_u256(x, LOG2_E_M1));
+    }
+    let int = (x.hi >> (WIDE_FRAC_NBITS - 128)) as i32;
+    let frac = int256::wrapping_shl_u256(x, 256 - WIDE_FRAC_NBITS);
+    if !neg {
+        (int, frac)
+    } else if frac.lo == 0 && frac.hi == 0 {
+        (-int, frac)
+    } else {
+        (-int - 1, int256::wrapping_neg_u256(frac))
+    }
+}
+
+// Returns base^(±abs × 2^−frac_nbits) × 2^frac_nbits, rounded to the nearest,
+// and with 2^frac_nbits subtracted if minus_one is true. The overflow flag is
+// set if the result before subtraction is ≥ 2^129, in which case only the lower
+// 128 bits of the result are returned.
+const fn real_exp(
+    neg: bool,
+    abs: u128,
+    frac_nbits: u32,
+    base: ExpBase,
+    minus_one: bool,
+    wide: bool,
+) -> (U256, bool) {
+    let (ans, overflow) = if frac_nbits < 128 && abs >> frac_nbits >> MAX_INT_NBITS != 0 {
+        (U256 { lo: 0, hi: 0 }, !neg)
+    } else if wide {
+        let (int, frac) = wide_split(neg, abs, frac_nbits, base);
+        let m = wide_exp2_frac(frac);
+        let mant = U256 {
+            lo: (m.lo >> 1) | (m.hi << 127),
+            hi: (1 << 127) | (m.hi >> 1),
+        };
+        scale(mant, int + frac_nbits as i32)
+    } else {
+        let (int, frac) = narrow_split(neg, abs, frac_nbits, base);
+        let m = narrow_exp2_frac(frac);
+        let mant = U256 {
+            lo: m << 127,
+            hi: (1 << 127) | (m >> 1),
+        };
+        scale(mant, int + frac_nbits as i32)
+    };
+    if !minus_one {
+        return (ans, overflow);
+    }
+    let one = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, frac_nbits);
+    (int256::wrapping_sub_u256(ans, one), overflow)
+}
+
+// Returns a × 2^−sh rounded down, where sh can be ≥ 256.
+const fn shr_u256(a: U256, sh: u32) -> U256 {
+    if sh >= 256 {
+        U256 { lo: 0, hi: 0 }
+    } else if sh > 128 {
+        U256 {
+            lo: a.hi >> (sh - 128),
+            hi: 0,
+        }
+    } else {
+        int256::shl_u256_max_128(a, sh)
+    }
+}
+
+const fn ge_u256(a: U256, b: U256) -> bool {
+    a.hi > b.hi || (a.hi == b.hi && a.lo >= b.lo)
+}
+
+// Returns (mant, exp) such that e^(±abs × 2^−frac_nbits) = mant × 2^(exp − 255),
+// where 1 ≤ mant < 2 has 255 fractional bits.
+const fn wide_exp(neg: bool, abs: u128, frac_nbits: u32) -> (U256, i32) {
+    let (int, frac) = wide_split(neg, abs, frac_nbits, ExpBase::E);
+    let m = wide_exp2_frac(frac);
+    let mant = U256 {
+        lo: (m.lo >> 1) | (m.hi << 127),
+        hi: (1 << 127) | (m.hi >> 1),
+    };
+    (mant, int)
+}
+
+// Returns a × 2^(exp − 255) rounded to the nearest, where a ≠ 0 does not need to
+// be normalized. The overflow flag is set as in scale.
+const fn scale_unnormalized(a: U256, exp: i32) -> (U256, bool) {
+    let lz = if a.hi == 0 {
+        128 + a.lo.leading_zeros()
+    } else {
+        a.hi.leading_zeros()
+    };
+    scale(int256::wrapping_shl_u256(a, lz), exp - lz as i32)
+}
+
+// Returns |f(x)| × 2^frac_nbits rounded to the nearest, where f is the
+// hyperbolic function and |x| = abs × 2^−frac_nbits. The overflow flag is set
+// if the result is ≥ 2^129, in which case only the lower 128 bits of the result
+// are returned.
+const fn real_hyperbolic(abs: u128, frac_nbits: u32, f: Hyperbolic) -> (U256, bool) {
+    let one = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, frac_nbits);
+    if abs == 0 {
+        return match f {
+            Hyperbolic::Cosh => (one, false),
+            Hyperbolic::Sinh | Hyperbolic::Tanh => (U256 { lo: 0, hi: 0 }, false),
+        };
+    }
+    if frac_nbits < 128 && abs >> frac_nbits >> MAX_INT_NBITS != 0 {
+        return match f {
+            Hyperbolic::Sinh | Hyperbolic::Cosh => (U256 { lo: 0, hi: 0 }, true),
+            Hyperbolic::Tanh => (one, false),
+        };
+    }
+    // e^|x| = pos × 2^(exp − 255) and e^−|x| = neg × 2^(exp − 255), where
+    // exp ≥ 0 and neg ≤ pos/2 since neg_exp < 0
+    let (pos, exp) = wide_exp(false, abs, frac_nbits);
+    let (neg, neg_exp) = wide_exp(true, abs, frac_nbits);
+    let neg = shr_u256(neg, (exp - neg_exp) as u32);
+    let exp = exp + frac_nbits as i32;
+    match f {
+        Hyperbolic::Sinh => scale_unnormalized(int256::wrapping_sub_u256(pos, neg), exp - 1),
+        Hyperbolic::Cosh => {
+            let sum = int256::overflowing_add_u256(shr_u256(pos, 1), shr_u256(neg, 1)).0;
+            scale_unnormalized(sum, exp)
+        }
+        Hyperbolic::Tanh => {
+            // keep two spare bits so that rem << 1 cannot overflow
+            let pos = shr_u256(pos, 2);
+            let neg = shr_u256(neg, 2);
+            let num = int256::wrapping_sub_u256(pos, neg);
+            let den = int256::overflowing_add_u256(pos, neg).0;
+            // long division for frac_nbits bits and one rounding bit
+            let mut q = 0u128;
+            let mut rem = num;
+            let mut i = 0;
+            while i <= frac_nbits {
+                rem = int256::wrapping_shl_u256(rem, 1);
+                let bit = ge_u256(rem, den);
+                if bit {
+                    rem = int256::wrapping_sub_u256(rem, den);
+                }
+                if i < frac_nbits {
+                    q = (q << 1) | bit as u128;
+                } else {
+                    return (
+                        int256::wrapping_add_u256_u128(U256 { lo: q, hi: 0 }, bit as u128),
+                        false,
+                    );
+                }
+                i += 1;
+            }
+            unreachable!();
+        }
+    }
+}
+
+macro_rules! impl_exp {
+    ($u:ident) => {
+        // Returns (ans, overflow) for unsigned values.
+        pub const fn $u(val: $u, frac_nbits: u32, base: ExpBase, minus_one: bool) -> ($u, bool) {
+            let wide = $u::BITS == 128;
+            let (ans, overflow) =
+                exp::real_exp(false, val as u128, frac_nbits, base, minus_one, wide);
+            let wrapped = ans.lo as $u;
+            (
+                wrapped,
+                overflow || ans.hi != 0 || wrapped as u128 != ans.lo,
+            )
+        }
+    };
+    ($i:ident, $u:ident) => {
+        impl_exp! { $u }
+
+        // Returns (ans, overflow) for signed values.
+        pub const fn $i(val: $i, frac_nbits: u32, base: ExpBase, minus_one: bool) -> ($i, bool) {
+            let wide = $i::BITS == 128;
+            let neg = val < 0;
+            let abs = val.unsigned_abs() as u128;
+            let (ans, overflow) = exp::real_exp(neg, abs, frac_nbits, base, minus_one, wide);
+            let wrapped = ans.lo as $i;
+            let sign_ext = (wrapped as i128 >> 127) as u128;
+            (
+                wrapped,
+                overflow || ans.hi != sign_ext || wrapped as i128 as u128 != ans.lo,
+            )
+        }
+    };
+}
+
+pub mod real {
+    use crate::exp;
+    use crate::exp::ExpBase;
+
+    impl_exp! { i8, u8 }
+    impl_exp! { i16, u16 }
+    impl_exp! { i32, u32 }
+    impl_exp! { i64, u64 }
+    impl_exp! { i128, u128 }
+}
+
+macro_rules! impl_hyperbolic {
+    ($i:ident) => {
+        // Returns (ans, overflow).
+        pub const fn $i(val: $i, frac_nbits: u32, f: Hyperbolic) -> ($i, bool) {
+            let (abs, overflow) = exp::real_hyperbolic(val.unsigned_abs() as u128, frac_nbits, f);
+            let ans = match f {
+                Hyperbolic::Sinh | Hyperbolic::Tanh if val < 0 => int256::wrapping_neg_u256(abs),
+                _ => abs,
+            };
+            let wrapped = ans.lo as $i;
+            let sign_ext = (wrapped as i128 >> 127) as u128;
+            (
+                wrapped,
+                overflow || ans.hi != sign_ext || wrapped as i128 as u128 != ans.lo,
+            )
+        }
+    };
+}
+
+pub mod hyperbolic {
+    use crate::exp;
+    use crate::exp::Hyperbolic;
+    use crate::int256;
+
+    impl_hyperbolic! { i8 }
+    impl_hyperbolic! { i16 }
+    impl_hyperbolic! { i32 }
+    impl_hyperbolic! { i64 }
+    impl_hyperbolic! { i128 }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::extra::{
+        U0, U4, U7, U8, U12, U16, U24, U28, U32, U60, U64, U124, U127, U128,
+    };
+    use crate::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128};
+    use crate::{FixedU8, FixedU16, FixedU32, FixedU64, FixedU128};
+
+    macro_rules! check_f64 {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            let f = val.to_num::<f64>();
+            let delta = Fix::DELTA.to_num::<f64>();
+            let max = Fix::MAX.to_num::<f64>();
+            for (exact, (ans, overflow)) in [
+                (f.exp2(), val.overflowing_exp2()),
+                (f.exp(), val.overflowing_exp()),
+                (f.exp_m1(), val.overflowing_exp_m1()),
+            ] {
+                if overflow {
+                    assert!(exact > max, "{val}");
+                } else {
+                    let err = (ans.to_num::<f64>() - exact).abs();
+                    assert!(
+                        err <= delta / 2.0 + exact.abs() * 1e-15,
+                        "{val} {exact} {ans}"
+                    );
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn exhaustive_8_16() {
+        for bits in i8::MIN..=i8::MAX {
+            check_f64!(FixedI8<U0>, bits);
+            check_f64!(FixedI8<U4>, bits);
+            check_f64!(FixedI8<U7>, bits);
+            check_f64!(FixedI8<U8>, bits);
+            check_f64!(FixedU8<U0>, bits as u8);
+            check_f64!(FixedU8<U4>, bits as u8);
+            check_f64!(FixedU8<U8>, bits as u8);
+        }
+        for bits in i16::MIN..=i16::MAX {
+            check_f64!(FixedI16<U0>, bits);
+            check_f64!(FixedI16<U8>, bits);
+            check_f64!(FixedI16<U12>, bits);
+            check_f64!(FixedU16<U12>, bits as u16);
+            check_f64!(FixedU16<U16>, bits as u16);
+        }
+    }
+
+    #[test]
+    fn sample_32_64_128() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let bits = bits >> (i & 15);
+            check_f64!(FixedI32<U24>, bits);
+            check_f64!(FixedI32<U28>, bits);
+            check_f64!(FixedU32<U32>, bits as u32);
+            check_f64!(FixedI64<U32>, i64::from(bits) << 8);
+            check_f64!(FixedU64<U60>, (i64::from(bits) << 28) as u64);
+            check_f64!(FixedI128<U64>, i128::from(bits) << 32);
+            check_f64!(FixedU128<U124>, (i128::from(bits) << 92) as u128);
+        }
+    }
+
+    macro_rules! check_hyperbolic {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            let f = val.to_num::<f64>();
+            let delta = Fix::DELTA.to_num::<f64>();
+            let min = Fix::MIN.to_num::<f64>();
+            let max = Fix::MAX.to_num::<f64>();
+            for (exact, (ans, overflow)) in [
+                (f.sinh(), val.overflowing_sinh()),
+                (f.cosh(), val.overflowing_cosh()),
+                (f.tanh(), (val.tanh(), false)),
+            ] {
+                if overflow {
+                    assert!(exact > max || exact < min, "{val}");
+                } else {
+                    let err = (ans.to_num::<f64>() - exact).abs();
+                    assert!(
+                        err <= delta / 2.0 + exact.abs() * 1e-15,
+                        "{val} {exact} {ans}"
+                    );
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn hyperbolic_exhaustive_8_16() {
+        for bits in i8::MIN..=i8::MAX {
+            check_hyperbolic!(FixedI8<U0>, bits);
+            check_hyperbolic!(FixedI8<U4>, bits);
+            check_hyperbolic!(FixedI8<U7>, bits);
+            check_hyperbolic!(FixedI8<U8>, bits);
+        }
+        for bits in i16::MIN..=i16::MAX {
+            check_hyperbolic!(FixedI16<U0>, bits);
+            check_hyperbolic!(FixedI16<U8>, bits);
+            check_hyperbolic!(FixedI16<U12>, bits);
+        }
+    }
+
+    #[test]
+    fn hyperbolic_sample_32_64_128() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let bits = bits >> (i & 15);
+            check_hyperbolic!(FixedI32<U24>, bits);
+            check_hyperbolic!(FixedI32<U28>, bits);
+            check_hyperbolic!(FixedI64<U32>, i64::from(bits) << 8);
+            check_hyperbolic!(FixedI128<U64>, i128::from(bits) << 32);
+            check_hyperbolic!(FixedI128<U124>, i128::from(bits) << 92);
+        }
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(FixedI8::<U4>::from_num(-3).exp2(), 0.125);
+        assert_eq!(FixedI8::<U7>::from_num(-0.5).exp_m1(), -0.390_625);
+        assert_eq!(FixedU8::<U8>::ZERO.overflowing_exp(), (FixedU8::ZERO, true));
+        assert_eq!(FixedI32::<U0>::from_num(30).exp2(), 1 << 30);
+        assert_eq!(
+            FixedI32::<U0>::from_num(31).overflowing_exp2(),
+            (i32::MIN.into(), true)
+        );
+        assert_eq!(FixedU64::<U0>::from_num(63).exp2(), 1u64 << 63);
+        assert_eq!(FixedI128::<U0>::from_num(126).exp2(), 1i128 << 126);
+        assert_eq!(FixedU128::<U0>::from_num(127).exp2(), 1u128 << 127);
+        assert_eq!(
+            FixedU128::<U0>::from_num(128).overflowing_exp2(),
+            (FixedU128::ZERO, true)
+        );
+        assert_eq!(FixedI128::<U127>::from_num(-1).exp2(), 0.5);
+        assert_eq!(FixedU128::<U128>::ZERO.checked_exp2(), None);
+        assert_eq!(FixedU128::<U128>::ZERO.exp_m1(), 0);
+        type I1F127 = FixedI128<U127>;
+        assert_eq!(I1F127::MIN.exp_m1(), I1F127::MIN.exp() + I1F127::NEG_ONE);
+
+        // results that underflow round to zero
+        assert_eq!(FixedI128::<U64>::MIN.exp(), 0);
+        assert_eq!(FixedI128::<U64>::MIN.exp_m1(), -1);
+        assert_eq!(FixedI8::<U0>::MIN.exp2(), 0);
+        assert_eq!(FixedI64::<U0>::MIN.exp(), 0);
+        assert_eq!(FixedI64::<U0>::MAX.checked_exp(), None);
+
+        // hyperbolic functions
+        assert_eq!(FixedI8::<U4>::ZERO.cosh(), 1);
+        assert_eq!(FixedI8::<U8>::ZERO.checked_cosh(), None);
+        assert_eq!(FixedI8::<U8>::MIN.checked_sinh(), None);
+        assert_eq!(
+            FixedI128::<U0>::from_num(88).sinh(),
+            FixedI128::<U0>::from_num(88).cosh()
+        );
+        assert_eq!(FixedI128::<U0>::from_num(89).checked_sinh(), None);
+        assert!(FixedI128::<U0>::MIN.overflowing_sinh().1);
+        assert_eq!(FixedI128::<U0>::MIN.tanh(), -1);
+        assert_eq!(FixedI128::<U64>::MAX.tanh(), 1);
+        assert_eq!(
+            FixedI128::<U128>::from_bits(1).sinh(),
+            FixedI128::<U128>::DELTA
+        );
+        assert_eq!(
+            FixedI128::<U128>::from_bits(-1).tanh(),
+            -FixedI128::<U128>::DELTA
+        );
+    }
+}
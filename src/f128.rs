@@ -34,6 +34,9 @@ use core::num::FpCategory;
 use core::ops::Neg;
 use half::{bf16 as half_bf16, f16 as half_f16};
 
+mod arith;
+mod decimal;
+
 const PREC: u32 = 113;
 const EXP_BITS: u32 = u128::BITS - PREC;
 const EXP_BIAS: u32 = (1 << (EXP_BITS - 1)) - 1;
@@ -50,8 +53,14 @@ pub(crate) mod private {
     ///     128-bit floating-point numbers.
     ///   * compare fixed-point numbers and the bit representation of 128-bit
     ///     floating-point numbers.
+    ///   * perform correctly rounded basic arithmetic, that is addition,
+    ///     subtraction, multiplication, division, [square root][F128::sqrt]
+    ///     and [fused multiply-add][F128::mul_add], on stable Rust.
+    ///   * parse and display decimal numbers, where displaying without a
+    ///     precision gives the shortest string that parses back to the same
+    ///     value.
     ///
-    /// This type does *not* support arithmetic or general analytic functions.
+    /// This type does *not* support general analytic functions.
     ///
     /// Please see [<i>Quadruple-precision floating-point format</i> on
     /// Wikipedia][quad] for more information on *binary128*.
@@ -74,6 +83,10 @@ pub(crate) mod private {
     /// // fixed-point numbers can be compared directly to F128 values
     /// assert!(I16F16::from_num(1.5) > F128::ONE);
     /// assert!(I16F16::from_num(0.5) < F128::ONE);
+    ///
+    /// // arithmetic is correctly rounded
+    /// let third = F128::ONE / "3".parse::<F128>().unwrap();
+    /// assert_eq!(third.to_string(), "0.3333333333333333333333333333333333");
     /// ```
     ///
     /// [`f128` feature]: https://github.com/rust-lang/rust/issues/116909
@@ -994,6 +1007,7 @@ pub mod consts {
 #[cfg(test)]
 mod tests {
     use crate::F128;
+    use crate::f128::{EXP_BIAS, PREC};
     use crate::traits::FromFixed;
     use half::{bf16 as half_bf16, f16 as half_f16};
 
@@ -1262,4 +1276,170 @@ mod tests {
             F128::from_bits((1 << 127) | (exp << exp_shift) | mantissa)
         );
     }
+
+    #[test]
+    fn arith() {
+        let two = F128::from(2f64);
+        let three = F128::from(3f64);
+        assert_eq!(two + three, F128::from(5f64));
+        assert_eq!(two - three, F128::NEG_ONE);
+        assert_eq!(two * three, F128::from(6f64));
+        assert_eq!(three / two, F128::from(1.5f64));
+        assert_eq!(
+            (F128::ONE / three).to_bits(),
+            0x3FFD_5555_5555_5555_5555_5555_5555_5555
+        );
+
+        // 1 + 2^-113 is a tie and rounds to even, 1 + 3 × 2^-113 rounds up
+        let half_ulp = F128::from_bits(u128::from(EXP_BIAS - PREC) << (PREC - 1));
+        assert_eq!(F128::ONE + half_ulp, F128::ONE);
+        assert_eq!(
+            F128::ONE + half_ulp * three,
+            F128::from_bits(F128::ONE.to_bits() + 2)
+        );
+
+        // signed zeros
+        assert!((F128::ONE - F128::ONE).is_sign_positive());
+        assert!((F128::NEG_ZERO + F128::NEG_ZERO).is_sign_negative());
+        assert!((F128::NEG_ONE * F128::ZERO).is_sign_negative());
+
+        // overflow, underflow and subnormals
+        assert_eq!(F128::MAX + F128::MAX, F128::INFINITY);
+        assert_eq!(F128::MIN * two, F128::NEG_INFINITY);
+        assert_eq!(F128::MIN_POSITIVE / two * two, F128::MIN_POSITIVE);
+        assert_eq!((F128::MIN_POSITIVE_SUB / two).to_bits(), 0);
+        assert_eq!(
+            (F128::MIN_POSITIVE_SUB * three / two).to_bits(),
+            F128::from_bits(2).to_bits()
+        );
+
+        // special values
+        assert!((F128::INFINITY - F128::INFINITY).is_nan());
+        assert!((F128::ZERO / F128::ZERO).is_nan());
+        assert!((F128::INFINITY * F128::ZERO).is_nan());
+        assert!((F128::NAN + F128::ONE).is_nan());
+        assert_eq!(F128::ONE / F128::NEG_ZERO, F128::NEG_INFINITY);
+        assert_eq!(F128::INFINITY + F128::MAX, F128::INFINITY);
+    }
+
+    #[test]
+    fn sqrt_mul_add() {
+        use crate::f128::consts;
+
+        let two = F128::from(2f64);
+        assert_eq!(two.sqrt(), consts::SQRT_2);
+        assert_eq!((F128::ONE / two).sqrt(), consts::FRAC_1_SQRT_2);
+        assert_eq!(F128::from(9f64).sqrt(), F128::from(3f64));
+        // the square roots of the subnormals 2^-16494 and 2^-16492 are exact
+        assert_eq!(
+            F128::from_bits(4).sqrt(),
+            F128::MIN_POSITIVE_SUB.sqrt() * two
+        );
+        assert!(F128::NEG_ZERO.sqrt().is_sign_negative());
+        assert!(F128::NEG_ONE.sqrt().is_nan());
+        assert_eq!(F128::INFINITY.sqrt(), F128::INFINITY);
+
+        // (1 + 2^-112)(1 - 2^-112) - 1 = -2^-224 exactly, but the unfused
+        // product rounds to 1
+        let ulp = F128::from_bits(u128::from(EXP_BIAS - PREC + 1) << (PREC - 1));
+        let a = F128::ONE + ulp;
+        let b = F128::ONE - ulp;
+        assert_eq!(a.mul_add(b, F128::NEG_ONE), -(ulp * ulp));
+        assert_eq!(a * b - F128::ONE, F128::ZERO);
+        assert!(
+            F128::ONE
+                .mul_add(F128::ONE, F128::NEG_ONE)
+                .is_sign_positive()
+        );
+        assert!(
+            F128::NEG_ZERO
+                .mul_add(F128::ONE, F128::NEG_ZERO)
+                .is_sign_negative()
+        );
+        assert!(
+            F128::INFINITY
+                .mul_add(F128::ONE, F128::NEG_INFINITY)
+                .is_nan()
+        );
+        assert_eq!(F128::MAX.mul_add(two, F128::MIN), F128::MAX);
+    }
+
+    #[test]
+    fn parse() {
+        use crate::f128::consts;
+
+        let p = |s: &str| s.parse::<F128>().unwrap();
+        assert_eq!(p("1"), F128::ONE);
+        assert_eq!(p("-1.0e0"), F128::NEG_ONE);
+        assert_eq!(
+            p("0.1").to_bits(),
+            0x3FFB_9999_9999_9999_9999_9999_9999_999A
+        );
+        assert_eq!(p("3.141592653589793238462643383279502884197"), consts::PI);
+        assert!(p("-0").is_sign_negative());
+        assert_eq!(p("1e5000"), F128::INFINITY);
+        assert_eq!(p("-1e-5000").to_bits(), F128::NEG_ZERO.to_bits());
+        assert_eq!(p("6e-4966"), F128::MIN_POSITIVE_SUB);
+        assert_eq!(p("-InFinity"), F128::NEG_INFINITY);
+        assert_eq!(p("+inf"), F128::INFINITY);
+        assert!(p("NaN").is_nan());
+        assert!("".parse::<F128>().is_err());
+        assert!("1.2.3".parse::<F128>().is_err());
+        assert!("infinite".parse::<F128>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        use crate::f128::consts;
+        use std::format;
+        use std::string::ToString;
+
+        let p = |s: &str| s.parse::<F128>().unwrap();
+        assert_eq!(F128::ONE.to_string(), "1");
+        assert_eq!(F128::NEG_ZERO.to_string(), "-0");
+        assert_eq!(p("0.1").to_string(), "0.1");
+        assert_eq!(
+            p("1e40").to_string(),
+            "10000000000000000000000000000000000000000"
+        );
+        assert_eq!(p("1.5e-5").to_string(), "0.000015");
+        assert_eq!(
+            consts::PI.to_string(),
+            "3.1415926535897932384626433832795028"
+        );
+        assert_eq!(format!("{:e}", p("1234.5")), "1.2345e3");
+        assert_eq!(format!("{:E}", p("-0.00012")), "-1.2E-4");
+        assert_eq!(format!("{:e}", F128::MIN_POSITIVE_SUB), "6e-4966");
+        assert_eq!(format!("{:e}", F128::ZERO), "0e0");
+
+        // precision, with ties rounded to even
+        assert_eq!(format!("{:.3}", consts::PI), "3.142");
+        assert_eq!(format!("{:.0}", p("0.5")), "0");
+        assert_eq!(format!("{:.0}", p("1.5")), "2");
+        assert_eq!(format!("{:.0}", p("2.5")), "2");
+        assert_eq!(format!("{:.2}", p("0.125")), "0.12");
+        assert_eq!(format!("{:.2}", p("0.999")), "1.00");
+        assert_eq!(format!("{:.1}", p("0.04")), "0.0");
+        assert_eq!(format!("{:.1}", p("0.06")), "0.1");
+        assert_eq!(format!("{:.2e}", p("9.999")), "1.00e1");
+        assert_eq!(format!("{:.3}", F128::ZERO), "0.000");
+
+        // padding
+        assert_eq!(format!("{:+08.2}", p("1.5")), "+0001.50");
+        assert_eq!(format!("{:*^9}", p("-1.5")), "**-1.5***");
+        assert_eq!(format!("{:<6}", p("2")), "2     ");
+        assert_eq!(format!("{}", F128::NAN), "NaN");
+        assert_eq!(format!("{:>5}", F128::NEG_INFINITY), " -inf");
+
+        // round trip
+        for x in [
+            F128::MAX,
+            F128::MIN_POSITIVE,
+            F128::MIN_POSITIVE_SUB,
+            consts::E,
+        ] {
+            assert_eq!(p(&x.to_string()), x);
+            assert_eq!(p(&format!("{x:e}")), x);
+        }
+    }
 }
@@ -0,0 +1,495 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Software implementation of correctly rounded binary128 arithmetic. All
+// operations round to nearest, with ties rounded to even.
+
+use crate::F128;
+use crate::f128::{EXP_BIAS, EXP_MASK, MANT_MASK, PREC, SIGN_MASK};
+use crate::int256::{self, U256};
+use core::num::NonZero;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+// The exponent of the least significant bit of subnormal numbers.
+pub(super) const SUB_EXP: i32 = 2 - EXP_BIAS as i32 - PREC as i32;
+
+pub(super) enum Class {
+    Nan,
+    Inf { neg: bool },
+    Zero { neg: bool },
+    // value is sig × 2^exp, sig is not zero
+    Finite { neg: bool, sig: u128, exp: i32 },
+}
+
+pub(super) const fn classify(a: F128) -> Class {
+    let bits = a.to_bits();
+    let neg = bits & SIGN_MASK != 0;
+    let biased = ((bits & EXP_MASK) >> (PREC - 1)) as i32;
+    let mant = bits & MANT_MASK;
+    if biased == (EXP_MASK >> (PREC - 1)) as i32 {
+        if mant == 0 {
+            Class::Inf { neg }
+        } else {
+            Class::Nan
+        }
+    } else if biased == 0 {
+        if mant == 0 {
+            Class::Zero { neg }
+        } else {
+            Class::Finite {
+                neg,
+                sig: mant,
+                exp: SUB_EXP,
+            }
+        }
+    } else {
+        Class::Finite {
+            neg,
+            sig: mant | (MANT_MASK + 1),
+            exp: SUB_EXP + biased - 1,
+        }
+    }
+}
+
+const fn zero(neg: bool) -> F128 {
+    if neg { F128::NEG_ZERO } else { F128::ZERO }
+}
+
+const fn inf(neg: bool) -> F128 {
+    if neg {
+        F128::NEG_INFINITY
+    } else {
+        F128::INFINITY
+    }
+}
+
+// Rounds (sig + sticky) × 2^exp, where sticky stands for some nonzero value
+// smaller than the least significant bit of sig.
+//
+// If sticky is true, sig must have at least PREC + 3 significant bits, so
+// that the sticky part lies below the rounding bit.
+pub(super) const fn pack(neg: bool, sig: u128, exp: i32, sticky: bool) -> F128 {
+    if sig == 0 {
+        return zero(neg);
+    }
+    let sig_bits = (u128::BITS - sig.leading_zeros()) as i32;
+    let mut shift = sig_bits - PREC as i32;
+    if shift < SUB_EXP - exp {
+        shift = SUB_EXP - exp;
+    }
+    let (mut kept, mut exp) = if shift <= 0 {
+        debug_assert!(!sticky);
+        (sig << -shift, exp + shift)
+    } else {
+        let kept = if shift >= u128::BITS as i32 {
+            0
+        } else {
+            sig >> shift
+        };
+        let round_up = if shift > u128::BITS as i32 {
+            false
+        } else {
+            let half = 1u128 << (shift - 1);
+            let rem = if shift == u128::BITS as i32 {
+                sig
+            } else {
+                sig & ((half << 1) - 1)
+            };
+            rem > half || (rem == half && (sticky || kept & 1 != 0))
+        };
+        (kept + round_up as u128, exp + shift)
+    };
+    if kept >> PREC != 0 {
+        kept >>= 1;
+        exp += 1;
+    }
+    let sign_bit = if neg { SIGN_MASK } else { 0 };
+    if kept >> (PREC - 1) == 0 {
+        // subnormal or zero
+        return F128::from_bits(sign_bit | kept);
+    }
+    let biased = exp - SUB_EXP + 1;
+    if biased >= (EXP_MASK >> (PREC - 1)) as i32 {
+        return inf(neg);
+    }
+    F128::from_bits(sign_bit | ((biased as u128) << (PREC - 1)) | (kept & MANT_MASK))
+}
+
+// Shifts sig left so that its most significant bit is bit msb.
+const fn normalize(sig: u128, exp: i32, msb: u32) -> (u128, i32) {
+    let shift = sig.leading_zeros() - (u128::BITS - 1 - msb);
+    (sig << shift, exp - shift as i32)
+}
+
+const fn normalize_u256(sig: U256, exp: i32, msb: u32) -> (U256, i32) {
+    let shift = int256::leading_zeros_u256(sig) - (U256::BITS - 1 - msb);
+    (int256::wrapping_shl_u256(sig, shift), exp - shift as i32)
+}
+
+// Shifts sig right, returning whether any nonzero bits were shifted out.
+const fn shr_sticky_u256(sig: U256, shift: u32) -> (U256, bool) {
+    if shift >= U256::BITS {
+        (U256::ZERO, !int256::is_zero_u256(sig))
+    } else {
+        let sticky = int256::trailing_zeros_u256(sig) < shift;
+        (int256::wrapping_shr_u256(sig, shift), sticky)
+    }
+}
+
+// Narrows a 256-bit significand to 127 bits, keeping the dropped bits as a
+// sticky flag.
+const fn narrow(sig: U256, exp: i32) -> (u128, i32, bool) {
+    let sig_bits = U256::BITS - int256::leading_zeros_u256(sig);
+    if sig_bits < u128::BITS {
+        return (sig.lo, exp, false);
+    }
+    let shift = sig_bits - (u128::BITS - 1);
+    let (narrow, sticky) = shr_sticky_u256(sig, shift);
+    (narrow.lo, exp + shift as i32, sticky)
+}
+
+const fn add(a: F128, b: F128) -> F128 {
+    let (a_neg, a_sig, a_exp, b_neg, b_sig, b_exp) = match (classify(a), classify(b)) {
+        (Class::Nan, _) | (_, Class::Nan) => return F128::NAN,
+        (Class::Inf { neg: a_neg }, Class::Inf { neg: b_neg }) => {
+            return if a_neg == b_neg { a } else { F128::NAN };
+        }
+        (Class::Inf { .. }, _) => return a,
+        (_, Class::Inf { .. }) => return b,
+        (Class::Zero { neg: a_neg }, Class::Zero { neg: b_neg }) => return zero(a_neg && b_neg),
+        (Class::Zero { .. }, _) => return b,
+        (_, Class::Zero { .. }) => return a,
+        (
+            Class::Finite {
+                neg: a_neg,
+                sig: a_sig,
+                exp: a_exp,
+            },
+            Class::Finite {
+                neg: b_neg,
+                sig: b_sig,
+                exp: b_exp,
+            },
+        ) => (a_neg, a_sig, a_exp, b_neg, b_sig, b_exp),
+    };
+
+    // With the most significant bit at bit 125, both significands have at
+    // least 13 trailing zeros, so a difference that needs the sticky flag
+    // still has at least 125 significant bits.
+    let (a_sig, a_exp) = normalize(a_sig, a_exp, 125);
+    let (b_sig, b_exp) = normalize(b_sig, b_exp, 125);
+    let (hi_neg, hi_sig, hi_exp, lo_neg, lo_sig, lo_exp) =
+        if a_exp > b_exp || (a_exp == b_exp && a_sig >= b_sig) {
+            (a_neg, a_sig, a_exp, b_neg, b_sig, b_exp)
+        } else {
+            (b_neg, b_sig, b_exp, a_neg, a_sig, a_exp)
+        };
+    let shift = (hi_exp - lo_exp) as u32;
+    let (lo_sig, sticky) = if shift >= u128::BITS {
+        (0, true)
+    } else {
+        (lo_sig >> shift, lo_sig.trailing_zeros() < shift)
+    };
+    if hi_neg == lo_neg {
+        pack(hi_neg, hi_sig + lo_sig, hi_exp, sticky)
+    } else {
+        let diff = hi_sig - lo_sig - sticky as u128;
+        if diff == 0 && !sticky {
+            return F128::ZERO;
+        }
+        pack(hi_neg, diff, hi_exp, sticky)
+    }
+}
+
+const fn mul(a: F128, b: F128) -> F128 {
+    let (neg, a_sig, a_exp, b_sig, b_exp) = match (classify(a), classify(b)) {
+        (Class::Nan, _) | (_, Class::Nan) => return F128::NAN,
+        (Class::Inf { .. }, Class::Zero { .. }) | (Class::Zero { .. }, Class::Inf { .. }) => {
+            return F128::NAN;
+        }
+        (Class::Inf { neg: a_neg }, Class::Inf { neg: b_neg })
+        | (Class::Inf { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Inf { neg: b_neg }) => {
+            return inf(a_neg != b_neg);
+        }
+        (Class::Zero { neg: a_neg }, Class::Zero { neg: b_neg })
+        | (Class::Zero { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Zero { neg: b_neg }) => {
+            return zero(a_neg != b_neg);
+        }
+        (
+            Class::Finite {
+                neg: a_neg,
+                sig: a_sig,
+                exp: a_exp,
+            },
+            Class::Finite {
+                neg: b_neg,
+                sig: b_sig,
+                exp: b_exp,
+            },
+        ) => (a_neg != b_neg, a_sig, a_exp, b_sig, b_exp),
+    };
+
+    let (sig, exp, sticky) = narrow(int256::wide_mul_u128(a_sig, b_sig), a_exp + b_exp);
+    pack(neg, sig, exp, sticky)
+}
+
+const fn div(a: F128, b: F128) -> F128 {
+    let (neg, a_sig, a_exp, b_sig, b_exp) = match (classify(a), classify(b)) {
+        (Class::Nan, _) | (_, Class::Nan) => return F128::NAN,
+        (Class::Inf { .. }, Class::Inf { .. }) | (Class::Zero { .. }, Class::Zero { .. }) => {
+            return F128::NAN;
+        }
+        (Class::Inf { neg: a_neg }, Class::Zero { neg: b_neg })
+        | (Class::Inf { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Zero { neg: b_neg }) => {
+            return inf(a_neg != b_neg);
+        }
+        (Class::Zero { neg: a_neg }, Class::Inf { neg: b_neg })
+        | (Class::Zero { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Inf { neg: b_neg }) => {
+            return zero(a_neg != b_neg);
+        }
+        (
+            Class::Finite {
+                neg: a_neg,
+                sig: a_sig,
+                exp: a_exp,
+            },
+            Class::Finite {
+                neg: b_neg,
+                sig: b_sig,
+                exp: b_exp,
+            },
+        ) => (a_neg != b_neg, a_sig, a_exp, b_sig, b_exp),
+    };
+
+    // Both significands have PREC bits, so (a_sig << 126) / b_sig lies in
+    // the range [2^125, 2^127) and has enough bits for rounding.
+    let (a_sig, a_exp) = normalize(a_sig, a_exp, PREC - 1);
+    let (b_sig, b_exp) = normalize(b_sig, b_exp, PREC - 1);
+    let dividend = int256::wrapping_shl_u256(U256 { lo: a_sig, hi: 0 }, 126);
+    let Some(divisor) = NonZero::new(b_sig) else {
+        unreachable!();
+    };
+    let (quot, rem) = int256::div_rem_u256_u128(dividend, divisor);
+    debug_assert!(quot.hi == 0);
+    pack(neg, quot.lo, a_exp - b_exp - 126, rem != 0)
+}
+
+const fn sqrt(a: F128) -> F128 {
+    let (sig, exp) = match classify(a) {
+        Class::Nan | Class::Inf { neg: true } | Class::Finite { neg: true, .. } => {
+            return F128::NAN;
+        }
+        Class::Inf { neg: false } | Class::Zero { .. } => return a,
+        Class::Finite { sig, exp, .. } => (sig, exp),
+    };
+
+    // Shift the PREC-bit significand by 120 or 121 to make the exponent even;
+    // the square root then has 117 significant bits.
+    let (sig, exp) = normalize(sig, exp, PREC - 1);
+    let shift = if exp % 2 == 0 { 120 } else { 121 };
+    let radicand = int256::wrapping_shl_u256(U256 { lo: sig, hi: 0 }, shift);
+    let mut root = 0u128;
+    let mut bit = 1u128 << 116;
+    while bit != 0 {
+        let try_root = root | bit;
+        if cmp_u256(int256::wide_mul_u128(try_root, try_root), radicand) <= 0 {
+            root = try_root;
+        }
+        bit >>= 1;
+    }
+    let exact = cmp_u256(int256::wide_mul_u128(root, root), radicand) == 0;
+    pack(false, root, (exp - shift as i32) / 2, !exact)
+}
+
+const fn mul_add(a: F128, b: F128, c: F128) -> F128 {
+    let (prod_neg, a_sig, a_exp, b_sig, b_exp) = match (classify(a), classify(b)) {
+        (Class::Nan, _) | (_, Class::Nan) => return F128::NAN,
+        (Class::Inf { .. }, Class::Zero { .. }) | (Class::Zero { .. }, Class::Inf { .. }) => {
+            return F128::NAN;
+        }
+        (Class::Inf { neg: a_neg }, Class::Inf { neg: b_neg })
+        | (Class::Inf { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Inf { neg: b_neg }) => {
+            // the product is infinite, so the result is either an infinity or NaN
+            return add(inf(a_neg != b_neg), c);
+        }
+        (Class::Zero { neg: a_neg }, Class::Zero { neg: b_neg })
+        | (Class::Zero { neg: a_neg }, Class::Finite { neg: b_neg, .. })
+        | (Class::Finite { neg: a_neg, .. }, Class::Zero { neg: b_neg }) => {
+            // the product is exactly zero
+            return add(zero(a_neg != b_neg), c);
+        }
+        (
+            Class::Finite {
+                neg: a_neg,
+                sig: a_sig,
+                exp: a_exp,
+            },
+            Class::Finite {
+                neg: b_neg,
+                sig: b_sig,
+                exp: b_exp,
+            },
+        ) => (a_neg != b_neg, a_sig, a_exp, b_sig, b_exp),
+    };
+    let (c_neg, c_sig, c_exp) = match classify(c) {
+        Class::Nan => return F128::NAN,
+        Class::Inf { .. } => return c,
+        Class::Zero { .. } => return mul(a, b),
+        Class::Finite { neg, sig, exp } => (neg, sig, exp),
+    };
+
+    // The product has at most 2 × PREC bits, so with the most significant
+    // bit at bit 253, both operands have at least 28 trailing zeros, and a
+    // difference that needs the sticky flag still has at least 253
+    // significant bits.
+    let prod = int256::wide_mul_u128(a_sig, b_sig);
+    let (prod, prod_exp) = normalize_u256(prod, a_exp + b_exp, 253);
+    let (c_sig, c_exp) = normalize_u256(U256 { lo: c_sig, hi: 0 }, c_exp, 253);
+    let prod_is_hi = prod_exp > c_exp || (prod_exp == c_exp && cmp_u256(prod, c_sig) >= 0);
+    let (hi_neg, hi_sig, hi_exp, lo_neg, lo_sig, lo_exp) = if prod_is_hi {
+        (prod_neg, prod, prod_exp, c_neg, c_sig, c_exp)
+    } else {
+        (c_neg, c_sig, c_exp, prod_neg, prod, prod_exp)
+    };
+    let (lo_sig, sticky) = shr_sticky_u256(lo_sig, (hi_exp - lo_exp) as u32);
+    let sum = if hi_neg == lo_neg {
+        int256::overflowing_add_u256(hi_sig, lo_sig).0
+    } else {
+        let diff = int256::wrapping_sub_u256(hi_sig, lo_sig);
+        if int256::is_zero_u256(diff) && !sticky {
+            return F128::ZERO;
+        }
+        int256::wrapping_sub_u256(
+            diff,
+            U256 {
+                lo: sticky as u128,
+                hi: 0,
+            },
+        )
+    };
+    let (sig, exp, narrow_sticky) = narrow(sum, hi_exp);
+    pack(hi_neg, sig, exp, sticky || narrow_sticky)
+}
+
+const fn cmp_u256(a: U256, b: U256) -> i32 {
+    if a.hi != b.hi {
+        if a.hi < b.hi { -1 } else { 1 }
+    } else if a.lo != b.lo {
+        if a.lo < b.lo { -1 } else { 1 }
+    } else {
+        0
+    }
+}
+
+impl F128 {
+    /// Returns the square root of `self`.
+    ///
+    /// The result is correctly rounded to the nearest representable value,
+    /// with ties rounded to even. The square root of a negative number other
+    /// than &minus;0 is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::f128::consts;
+    /// use fixed::F128;
+    /// let two = F128::ONE + F128::ONE;
+    /// assert_eq!(two.sqrt(), consts::SQRT_2);
+    /// assert!(F128::NEG_ONE.sqrt().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn sqrt(self) -> F128 {
+        sqrt(self)
+    }
+
+    /// Fused multiply-add. Computes (`self` × `a`) + `b` with only one
+    /// rounding error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::F128;
+    /// let two = F128::ONE + F128::ONE;
+    /// let three = two + F128::ONE;
+    /// // 2 × 3 + 1 = 7
+    /// assert_eq!(two.mul_add(three, F128::ONE), three + three + F128::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn mul_add(self, a: F128, b: F128) -> F128 {
+        mul_add(self, a, b)
+    }
+}
+
+macro_rules! op {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident, $func:expr) => {
+        impl $Op<F128> for F128 {
+            type Output = F128;
+            #[inline]
+            fn $op(self, other: F128) -> F128 {
+                $func(self, other)
+            }
+        }
+
+        impl $Op<F128> for &F128 {
+            type Output = F128;
+            #[inline]
+            fn $op(self, other: F128) -> F128 {
+                $func(*self, other)
+            }
+        }
+
+        impl $Op<&F128> for F128 {
+            type Output = F128;
+            #[inline]
+            fn $op(self, other: &F128) -> F128 {
+                $func(self, *other)
+            }
+        }
+
+        impl $Op<&F128> for &F128 {
+            type Output = F128;
+            #[inline]
+            fn $op(self, other: &F128) -> F128 {
+                $func(*self, *other)
+            }
+        }
+
+        impl $OpAssign<F128> for F128 {
+            #[inline]
+            fn $op_assign(&mut self, other: F128) {
+                *self = $func(*self, other);
+            }
+        }
+
+        impl $OpAssign<&F128> for F128 {
+            #[inline]
+            fn $op_assign(&mut self, other: &F128) {
+                *self = $func(*self, *other);
+            }
+        }
+    };
+}
+
+op! { Add add, AddAssign add_assign, add }
+op! { Sub sub, SubAssign sub_assign, |a: F128, b: F128| add(a, -b) }
+op! { Mul mul, MulAssign mul_assign, mul }
+op! { Div div, DivAssign div_assign, div }
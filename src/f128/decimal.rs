@@ -0,0 +1,875 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Conversion between F128 and decimal strings.
+//
+// Parsing uses the simple decimal algorithm also used by the standard library
+// as the slow path for f64: the digits are stored in a decimal buffer which
+// is shifted by powers of two until the value lies in [1/2, 1), after which
+// the significand can be read off and rounded. This gives correctly rounded
+// results for any number of input digits.
+//
+// Formatting uses exact big-integer arithmetic. Without a precision, the
+// Burger–Dybvig free-format algorithm generates the shortest digits that
+// parse back to the same value. With a precision, exact digits are generated
+// and rounded to nearest, with ties rounded to even.
+
+use crate::F128;
+use crate::bytes::DigitsExp;
+use crate::f128::arith::{self, Class};
+use crate::f128::{EXP_BIAS, PREC};
+use crate::from_str::{self, ParseFixedError};
+use core::cmp::Ordering;
+use core::fmt::{Alignment, Display, Formatter, LowerExp, Result as FmtResult, UpperExp, Write};
+use core::str::FromStr;
+
+// Parsing
+
+// The maximum number of significant digits that can affect rounding. The
+// longest exact decimal expansion of a halfway point between two values has
+// 11_565 significant digits; one more digit records whether the rest is
+// nonzero.
+const MAX_DIGITS: usize = 11_566;
+const DECIMAL_POINT_RANGE: i32 = 0x7FFF;
+// 5^55 is the largest power of five that fits in u128.
+const MAX_SHIFT: u32 = 55;
+const MIN_EXPONENT: i32 = -(EXP_BIAS as i32);
+const INFINITE_POWER: i32 = 0x7FFF;
+// Any value smaller than 10^-4966 rounds to zero, and any value not smaller
+// than 10^4933 rounds to infinity.
+const ZERO_DECIMAL_POINT: i32 = -4966;
+const INF_DECIMAL_POINT: i32 = 4934;
+
+// The value is 0.digits × 10^decimal_point.
+struct Decimal {
+    num_digits: usize,
+    decimal_point: i32,
+    truncated: bool,
+    digits: [u8; MAX_DIGITS],
+}
+
+impl Decimal {
+    fn new() -> Decimal {
+        Decimal {
+            num_digits: 0,
+            decimal_point: 0,
+            truncated: false,
+            digits: [0; MAX_DIGITS],
+        }
+    }
+
+    fn push_digit(&mut self, digit: u8) {
+        if self.num_digits < MAX_DIGITS {
+            self.digits[self.num_digits] = digit;
+            self.num_digits += 1;
+        } else if digit != 0 {
+            self.truncated = true;
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.num_digits != 0 && self.digits[self.num_digits - 1] == 0 {
+            self.num_digits -= 1;
+        }
+    }
+
+    // Rounds to the nearest integer, with ties rounded to even.
+    fn round(&self) -> u128 {
+        if self.num_digits == 0 || self.decimal_point < 0 {
+            return 0;
+        }
+        if self.decimal_point > 38 {
+            return u128::MAX;
+        }
+        let point = self.decimal_point as usize;
+        let mut n = 0u128;
+        for i in 0..point {
+            n *= 10;
+            if i < self.num_digits {
+                n += u128::from(self.digits[i]);
+            }
+        }
+        let mut round_up = false;
+        if point < self.num_digits {
+            round_up = self.digits[point] >= 5;
+            if self.digits[point] == 5 && point + 1 == self.num_digits {
+                round_up = self.truncated || (point != 0 && self.digits[point - 1] & 1 != 0);
+            }
+        }
+        n + u128::from(round_up)
+    }
+
+    // Multiplying by 2^shift adds as many digits as there are in 2^shift,
+    // less one if the digits compare less than the digits of 5^shift, since
+    // 2^shift = 10^shift / 5^shift.
+    fn num_new_digits(&self, shift: u32) -> usize {
+        let pow2_digits = ((u64::from(shift) * 78_913) >> 18) as usize + 1;
+        let mut pow5 = 5u128.pow(shift);
+        let mut pow5_digits = [0u8; 39];
+        let mut len = 0;
+        while pow5 != 0 {
+            pow5_digits[len] = (pow5 % 10) as u8;
+            pow5 /= 10;
+            len += 1;
+        }
+        for i in 0..len {
+            let p = pow5_digits[len - 1 - i];
+            if i >= self.num_digits {
+                return pow2_digits - 1;
+            }
+            if self.digits[i] != p {
+                return if self.digits[i] < p {
+                    pow2_digits - 1
+                } else {
+                    pow2_digits
+                };
+            }
+        }
+        pow2_digits
+    }
+
+    // shift must be ≤ MAX_SHIFT
+    fn left_shift(&mut self, shift: u32) {
+        if self.num_digits == 0 {
+            return;
+        }
+        let num_new_digits = self.num_new_digits(shift);
+        let mut read_index = self.num_digits;
+        let mut write_index = self.num_digits + num_new_digits;
+        let mut n = 0u64;
+        while read_index != 0 || n != 0 {
+            if read_index != 0 {
+                read_index -= 1;
+                n += u64::from(self.digits[read_index]) << shift;
+            }
+            write_index -= 1;
+            let quotient = n / 10;
+            let remainder = (n - 10 * quotient) as u8;
+            if write_index < MAX_DIGITS {
+                self.digits[write_index] = remainder;
+            } else if remainder != 0 {
+                self.truncated = true;
+            }
+            n = quotient;
+        }
+        debug_assert!(write_index == 0);
+        self.num_digits = (self.num_digits + num_new_digits).min(MAX_DIGITS);
+        self.decimal_point += num_new_digits as i32;
+        self.trim();
+    }
+
+    // shift must be ≤ MAX_SHIFT
+    fn right_shift(&mut self, shift: u32) {
+        let mut read_index = 0;
+        let mut write_index = 0;
+        let mut n = 0u64;
+        while (n >> shift) == 0 {
+            if read_index < self.num_digits {
+                n = 10 * n + u64::from(self.digits[read_index]);
+                read_index += 1;
+            } else if n == 0 {
+                return;
+            } else {
+                while (n >> shift) == 0 {
+                    n *= 10;
+                    read_index += 1;
+                }
+                break;
+            }
+        }
+        self.decimal_point -= read_index as i32 - 1;
+        if self.decimal_point < -DECIMAL_POINT_RANGE {
+            self.num_digits = 0;
+            self.decimal_point = 0;
+            self.truncated = false;
+            return;
+        }
+        let mask = (1u64 << shift) - 1;
+        while read_index < self.num_digits {
+            let new_digit = (n >> shift) as u8;
+            n = 10 * (n & mask) + u64::from(self.digits[read_index]);
+            read_index += 1;
+            self.digits[write_index] = new_digit;
+            write_index += 1;
+        }
+        while n > 0 {
+            let new_digit = (n >> shift) as u8;
+            n = 10 * (n & mask);
+            if write_index < MAX_DIGITS {
+                self.digits[write_index] = new_digit;
+                write_index += 1;
+            } else if new_digit > 0 {
+                self.truncated = true;
+            }
+        }
+        self.num_digits = write_index;
+        self.trim();
+    }
+
+    // Returns the bits of the absolute value, rounded to nearest.
+    fn round_to_bits(&mut self) -> u128 {
+        const INF_BITS: u128 = F128::INFINITY.to_bits();
+        const POWERS: [u8; 17] = [
+            0, 3, 6, 9, 13, 16, 19, 23, 26, 29, 33, 36, 39, 43, 46, 49, 53,
+        ];
+        const fn get_shift(n: usize) -> u32 {
+            if n < POWERS.len() {
+                POWERS[n] as u32
+            } else {
+                MAX_SHIFT
+            }
+        }
+
+        if self.num_digits == 0 || self.decimal_point <= ZERO_DECIMAL_POINT {
+            return 0;
+        }
+        if self.decimal_point >= INF_DECIMAL_POINT {
+            return INF_BITS;
+        }
+        let mut exp2 = 0i32;
+        while self.decimal_point > 0 {
+            let shift = get_shift(self.decimal_point as usize);
+            self.right_shift(shift);
+            if self.decimal_point < -DECIMAL_POINT_RANGE {
+                return 0;
+            }
+            exp2 += shift as i32;
+        }
+        while self.decimal_point <= 0 {
+            let shift = if self.decimal_point == 0 {
+                match self.digits[0] {
+                    5..=9 => break,
+                    0 | 1 => 2,
+                    _ => 1,
+                }
+            } else {
+                get_shift((-self.decimal_point) as usize)
+            };
+            self.left_shift(shift);
+            if self.decimal_point > DECIMAL_POINT_RANGE {
+                return INF_BITS;
+            }
+            exp2 -= shift as i32;
+        }
+        // the value is now in [1/2, 1), but the significand is in [1, 2)
+        exp2 -= 1;
+        while MIN_EXPONENT + 1 > exp2 {
+            let shift = ((MIN_EXPONENT + 1 - exp2) as u32).min(MAX_SHIFT);
+            self.right_shift(shift);
+            exp2 += shift as i32;
+        }
+        if exp2 - MIN_EXPONENT >= INFINITE_POWER {
+            return INF_BITS;
+        }
+        // shift so that the integer part has the PREC significant bits
+        let mut rem_shift = PREC;
+        while rem_shift > 0 {
+            let shift = rem_shift.min(MAX_SHIFT);
+            self.left_shift(shift);
+            rem_shift -= shift;
+        }
+        let mut mantissa = self.round();
+        if mantissa >> PREC != 0 {
+            // rounding overflowed
+            self.right_shift(1);
+            exp2 += 1;
+            mantissa = self.round();
+            if exp2 - MIN_EXPONENT >= INFINITE_POWER {
+                return INF_BITS;
+            }
+        }
+        let mut power2 = exp2 - MIN_EXPONENT;
+        if mantissa >> (PREC - 1) == 0 {
+            power2 -= 1;
+        }
+        mantissa &= (1 << (PREC - 1)) - 1;
+        mantissa | ((power2 as u128) << (PREC - 1))
+    }
+}
+
+fn parse_special(bytes: &[u8]) -> Option<F128> {
+    let (neg, rest) = match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, bytes),
+    };
+    if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity") {
+        Some(if neg {
+            F128::NEG_INFINITY
+        } else {
+            F128::INFINITY
+        })
+    } else if rest.eq_ignore_ascii_case(b"nan") {
+        Some(F128::NAN)
+    } else {
+        None
+    }
+}
+
+fn parse_abs(int: DigitsExp, frac: DigitsExp) -> u128 {
+    let mut decimal = Decimal::new();
+    let mut frac = frac;
+    if int.is_empty() {
+        // skip leading zeros of the fractional part
+        let mut zeros = 0i32;
+        while let Some((b'0', rest)) = frac.split_first() {
+            zeros += 1;
+            if zeros > -ZERO_DECIMAL_POINT {
+                return 0;
+            }
+            frac = rest;
+        }
+        decimal.decimal_point = -zeros;
+    } else {
+        if int.len() >= INF_DECIMAL_POINT as usize {
+            return F128::INFINITY.to_bits();
+        }
+        decimal.decimal_point = int.len() as i32;
+        let mut int = int;
+        while let Some((digit, rest)) = int.split_first() {
+            decimal.push_digit(digit - b'0');
+            int = rest;
+        }
+    }
+    while let Some((digit, rest)) = frac.split_first() {
+        decimal.push_digit(digit - b'0');
+        frac = rest;
+    }
+    decimal.trim();
+    decimal.round_to_bits()
+}
+
+impl FromStr for F128 {
+    type Err = ParseFixedError;
+
+    /// Parses a decimal string slice into a correctly rounded number.
+    ///
+    /// Apart from decimal numbers with an optional exponent, the strings
+    /// “inf”, “infinity” and “NaN” are accepted, ignoring case, with an
+    /// optional sign.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if let Some(special) = parse_special(bytes) {
+            return Ok(special);
+        }
+        let (neg, int, frac) = from_str::parse_dec_digits(bytes)?;
+        let abs = F128::from_bits(parse_abs(int, frac));
+        Ok(if neg { -abs } else { abs })
+    }
+}
+
+// Formatting
+
+// The largest intermediate value is 10 × 2^16_496, just under 16_500 bits.
+const LIMBS: usize = 520;
+// The shortest representation never needs more than 36 digits.
+const MAX_SHORTEST: usize = 40;
+
+#[derive(Clone)]
+struct Big {
+    len: usize,
+    // limbs at len and above are always zero
+    limbs: [u32; LIMBS],
+}
+
+impl Big {
+    fn from_u128(mut val: u128) -> Big {
+        let mut big = Big {
+            len: 0,
+            limbs: [0; LIMBS],
+        };
+        while val != 0 {
+            big.limbs[big.len] = val as u32;
+            big.len += 1;
+            val >>= 32;
+        }
+        big
+    }
+
+    fn trim(&mut self) {
+        while self.len > 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    fn mul_small(&mut self, m: u32) {
+        let mut carry = 0u64;
+        for limb in &mut self.limbs[..self.len] {
+            let prod = u64::from(*limb) * u64::from(m) + carry;
+            *limb = prod as u32;
+            carry = prod >> 32;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    fn mul_pow10(&mut self, mut n: u32) {
+        while n >= 9 {
+            self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        if n > 0 {
+            self.mul_small(10u32.pow(n));
+        }
+    }
+
+    fn shl(&mut self, shift: u32) {
+        if self.len == 0 {
+            return;
+        }
+        let limbs = (shift / 32) as usize;
+        let bits = shift % 32;
+        let old_len = self.len;
+        if bits == 0 {
+            self.limbs.copy_within(0..old_len, limbs);
+            self.len += limbs;
+        } else {
+            self.limbs[old_len + limbs] = self.limbs[old_len - 1] >> (32 - bits);
+            for i in (1..old_len).rev() {
+                self.limbs[i + limbs] =
+                    (self.limbs[i] << bits) | (self.limbs[i - 1] >> (32 - bits));
+            }
+            self.limbs[limbs] = self.limbs[0] << bits;
+            self.len += limbs + 1;
+        }
+        self.limbs[..limbs].fill(0);
+        self.trim();
+    }
+
+    fn add(&mut self, other: &Big) {
+        let len = self.len.max(other.len);
+        let mut carry = false;
+        for i in 0..len {
+            let (sum, carry1) = self.limbs[i].overflowing_add(other.limbs[i]);
+            let (sum, carry2) = sum.overflowing_add(u32::from(carry));
+            self.limbs[i] = sum;
+            carry = carry1 || carry2;
+        }
+        self.len = len;
+        if carry {
+            self.limbs[len] = 1;
+            self.len += 1;
+        }
+    }
+
+    // other must not be greater than self
+    fn sub(&mut self, other: &Big) {
+        let mut borrow = false;
+        for i in 0..self.len {
+            let (diff, borrow1) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (diff, borrow2) = diff.overflowing_sub(u32::from(borrow));
+            self.limbs[i] = diff;
+            borrow = borrow1 || borrow2;
+        }
+        debug_assert!(!borrow);
+        self.trim();
+    }
+
+    fn cmp(&self, other: &Big) -> Ordering {
+        self.len.cmp(&other.len).then_with(|| {
+            let a = self.limbs[..self.len].iter().rev();
+            a.cmp(other.limbs[..other.len].iter().rev())
+        })
+    }
+
+    fn cmp_sum(&self, add: &Big, other: &Big) -> Ordering {
+        let mut sum = self.clone();
+        sum.add(add);
+        sum.cmp(other)
+    }
+}
+
+// Multiplies r by 10 and returns the integer part of r / s, leaving the
+// remainder in r.
+fn next_digit(r: &mut Big, s: &Big) -> u8 {
+    r.mul_small(10);
+    let mut digit = 0;
+    while r.cmp(s) != Ordering::Less {
+        r.sub(s);
+        digit += 1;
+    }
+    digit
+}
+
+// Returns whether the digits should be rounded up when the remainder is
+// r / s and the last digit is last.
+fn round_up(r: &Big, s: &Big, last: u8) -> bool {
+    match r.cmp_sum(r, s) {
+        Ordering::Less => false,
+        Ordering::Equal => last & 1 != 0,
+        Ordering::Greater => true,
+    }
+}
+
+// Returns floor(log10(2^exp2)) + 1.
+fn estimate_k(exp2: i32) -> i32 {
+    // 0x4D10_4D42_7DE7_FBCC / 2^64 is just under log10(2)
+    ((i128::from(exp2) * 0x4D10_4D42_7DE7_FBCC) >> 64) as i32 + 1
+}
+
+// Sets up r / s = sig × 2^exp / 10^k with sig × 2^exp < 10^k ≤ 10 × sig × 2^exp.
+fn fixed_setup(sig: u128, exp: i32) -> (Big, Big, i32) {
+    let mut r = Big::from_u128(sig);
+    let mut s = Big::from_u128(1);
+    if exp >= 0 {
+        r.shl(exp as u32);
+    } else {
+        s.shl(exp.unsigned_abs());
+    }
+    let mut k = estimate_k(exp + (u128::BITS - sig.leading_zeros()) as i32 - 1);
+    if k >= 0 {
+        s.mul_pow10(k as u32);
+    } else {
+        r.mul_pow10(k.unsigned_abs());
+    }
+    if r.cmp(&s) != Ordering::Less {
+        s.mul_small(10);
+        k += 1;
+    }
+    (r, s, k)
+}
+
+// Generates the shortest digits that round back to sig × 2^exp. Returns the
+// number of digits and k, such that the value is 0.digits × 10^k.
+fn shortest(sig: u128, exp: i32, buf: &mut [u8; MAX_SHORTEST]) -> (usize, i32) {
+    // When the significand is even, the halfway points round back to it.
+    let even = sig & 1 == 0;
+    // At a power of two the next smaller value is closer than the next
+    // larger value, except at the smallest normal exponent.
+    let closer = sig == 1 << (PREC - 1) && exp > arith::SUB_EXP;
+    let (mut r, mut s, mut plus, mut minus) = if closer {
+        (
+            Big::from_u128(sig << 2),
+            Big::from_u128(4),
+            Big::from_u128(2),
+            Big::from_u128(1),
+        )
+    } else {
+        (
+            Big::from_u128(sig << 1),
+            Big::from_u128(2),
+            Big::from_u128(1),
+            Big::from_u128(1),
+        )
+    };
+    if exp >= 0 {
+        r.shl(exp as u32);
+        plus.shl(exp as u32);
+        minus.shl(exp as u32);
+    } else {
+        s.shl(exp.unsigned_abs());
+    }
+    let mut k = estimate_k(exp + (u128::BITS - sig.leading_zeros()) as i32 - 1);
+    if k >= 0 {
+        s.mul_pow10(k as u32);
+    } else {
+        r.mul_pow10(k.unsigned_abs());
+        plus.mul_pow10(k.unsigned_abs());
+        minus.mul_pow10(k.unsigned_abs());
+    }
+    let high = |r: &Big, plus: &Big, s: &Big| match r.cmp_sum(plus, s) {
+        Ordering::Less => false,
+        Ordering::Equal => even,
+        Ordering::Greater => true,
+    };
+    if high(&r, &plus, &s) {
+        s.mul_small(10);
+        k += 1;
+    }
+
+    let mut len = 0;
+    loop {
+        let digit = next_digit(&mut r, &s);
+        plus.mul_small(10);
+        minus.mul_small(10);
+        let low_done = match r.cmp(&minus) {
+            Ordering::Less => true,
+            Ordering::Equal => even,
+            Ordering::Greater => false,
+        };
+        let high_done = high(&r, &plus, &s);
+        let last = match (low_done, high_done) {
+            (false, false) => {
+                buf[len] = digit;
+                len += 1;
+                continue;
+            }
+            (true, false) => digit,
+            (false, true) => digit + 1,
+            (true, true) => digit + u8::from(round_up(&r, &s, digit)),
+        };
+        buf[len] = last;
+        return (len + 1, k);
+    }
+}
+
+// Writes digits, inserting the point and padding with zeros as required.
+struct DigitWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    // the number of integer digits; if not positive, the number is written
+    // as 0.000ddd with -point zeros before the first digit
+    point: i32,
+    frac_len: usize,
+    pos: i32,
+}
+
+impl<'a, 'b> DigitWriter<'a, 'b> {
+    fn width(point: i32, frac_len: usize) -> usize {
+        let int_len = if point > 0 { point as usize } else { 1 };
+        if frac_len > 0 {
+            int_len + 1 + frac_len
+        } else {
+            int_len
+        }
+    }
+
+    fn new(f: &'a mut Formatter<'b>, point: i32, frac_len: usize) -> DigitWriter<'a, 'b> {
+        DigitWriter {
+            f,
+            point,
+            frac_len,
+            pos: 0,
+        }
+    }
+
+    fn start(&mut self) -> FmtResult {
+        if self.point <= 0 {
+            self.f.write_char('0')?;
+            if self.frac_len > 0 {
+                self.f.write_char('.')?;
+                for _ in 0..self.point.unsigned_abs().min(self.frac_len as u32) {
+                    self.f.write_char('0')?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, digit: u8) -> FmtResult {
+        if self.point > 0 && self.pos == self.point {
+            self.f.write_char('.')?;
+        }
+        self.pos += 1;
+        self.f.write_char(char::from(b'0' + digit))
+    }
+
+    fn finish(&mut self) -> FmtResult {
+        let frac_written = if self.point > 0 {
+            while self.pos < self.point {
+                self.f.write_char('0')?;
+                self.pos += 1;
+            }
+            if self.frac_len > 0 && self.pos == self.point {
+                self.f.write_char('.')?;
+            }
+            (self.pos - self.point) as usize
+        } else {
+            (self.point.unsigned_abs() as usize).min(self.frac_len) + self.pos as usize
+        };
+        for _ in frac_written..self.frac_len {
+            self.f.write_char('0')?;
+        }
+        Ok(())
+    }
+}
+
+// Only one value lives on the stack at a time while formatting.
+#[allow(clippy::large_enum_variant)]
+enum Digits {
+    None,
+    One,
+    Shortest([u8; MAX_SHORTEST], usize),
+    Exact(Big, Big, usize),
+}
+
+// Returns whether rounding n exact digits carries all the way through,
+// that is whether all digits are nine and they are rounded up.
+fn carries_through(mut r: Big, s: &Big, n: usize) -> bool {
+    for _ in 0..n {
+        if next_digit(&mut r, s) != 9 {
+            return false;
+        }
+    }
+    round_up(&r, s, 9)
+}
+
+// Writes n exact digits, rounding the last one. Rounding must not carry
+// through all the digits.
+fn write_exact(w: &mut DigitWriter, r: &mut Big, s: &Big, n: usize) -> FmtResult {
+    // A digit followed by nines is held back until we know whether a carry
+    // will reach it.
+    let mut pending = None;
+    let mut nines = 0;
+    let mut last = 0;
+    for _ in 0..n {
+        last = next_digit(r, s);
+        if last == 9 {
+            if pending.is_some() {
+                nines += 1;
+            } else {
+                w.push(9)?;
+            }
+        } else {
+            if let Some(digit) = pending {
+                w.push(digit)?;
+                for _ in 0..nines {
+                    w.push(9)?;
+                }
+            }
+            pending = Some(last);
+            nines = 0;
+        }
+    }
+    if let Some(digit) = pending {
+        let up = round_up(r, s, last);
+        w.push(digit + u8::from(up))?;
+        for _ in 0..nines {
+            w.push(if up { 0 } else { 9 })?;
+        }
+    }
+    Ok(())
+}
+
+fn fmt_dec(x: F128, exp_char: Option<char>, f: &mut Formatter) -> FmtResult {
+    let (neg, sig, exp) = match arith::classify(x) {
+        Class::Nan => return f.pad("NaN"),
+        Class::Inf { neg: true } => return f.pad("-inf"),
+        Class::Inf { neg: false } => return f.pad(if f.sign_plus() { "+inf" } else { "inf" }),
+        Class::Zero { neg } => (neg, 0, 0),
+        Class::Finite { neg, sig, exp } => (neg, sig, exp),
+    };
+
+    // The value is 0.digits × 10^dec_exp.
+    let (digits, dec_exp, frac_len) = match f.precision() {
+        None => {
+            let mut buf = [0; MAX_SHORTEST];
+            let (len, k) = if sig == 0 {
+                (1, 1)
+            } else {
+                shortest(sig, exp, &mut buf)
+            };
+            let frac_len = if exp_char.is_some() {
+                len - 1
+            } else {
+                (len as i32 - k).max(0) as usize
+            };
+            (Digits::Shortest(buf, len), k, frac_len)
+        }
+        Some(prec) if sig == 0 => (Digits::None, 1, prec),
+        Some(prec) => {
+            let (r, s, k) = fixed_setup(sig, exp);
+            let prec_i64 = i64::try_from(prec).unwrap_or(i64::MAX);
+            let n = if exp_char.is_some() {
+                prec_i64.saturating_add(1)
+            } else {
+                prec_i64.saturating_add(i64::from(k))
+            };
+            let digits = if n < 0 {
+                (Digits::None, 1)
+            } else if n == 0 {
+                if round_up(&r, &s, 0) {
+                    (Digits::One, k + 1)
+                } else {
+                    (Digits::None, 1)
+                }
+            } else if carries_through(r.clone(), &s, n as usize) {
+                (Digits::One, k + 1)
+            } else {
+                (Digits::Exact(r, s, n as usize), k)
+            };
+            (digits.0, digits.1, prec)
+        }
+    };
+
+    let (point, exp_len) = match exp_char {
+        Some(_) => {
+            let abs = (dec_exp - 1).unsigned_abs();
+            let abs_len = abs.checked_ilog10().unwrap_or(0) as usize + 1;
+            (1, 1 + usize::from(dec_exp - 1 < 0) + abs_len)
+        }
+        None => (dec_exp, 0),
+    };
+    let sign = if neg {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let req_width = sign.len() + DigitWriter::width(point, frac_len) + exp_len;
+    let pad = f
+        .width()
+        .and_then(|w| w.checked_sub(req_width))
+        .unwrap_or(0);
+    let (pad_left, pad_zeros, pad_right) = if f.sign_aware_zero_pad() {
+        (0, pad, 0)
+    } else {
+        match f.align() {
+            Some(Alignment::Left) => (0, 0, pad),
+            Some(Alignment::Center) => (pad / 2, 0, pad - pad / 2),
+            None | Some(Alignment::Right) => (pad, 0, 0),
+        }
+    };
+    let fill = f.fill();
+
+    for _ in 0..pad_left {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    for _ in 0..pad_zeros {
+        f.write_char('0')?;
+    }
+    let mut w = DigitWriter::new(f, point, frac_len);
+    w.start()?;
+    match digits {
+        Digits::None => {}
+        Digits::One => w.push(1)?,
+        Digits::Shortest(buf, len) => {
+            for &digit in &buf[..len] {
+                w.push(digit)?;
+            }
+        }
+        Digits::Exact(mut r, s, n) => write_exact(&mut w, &mut r, &s, n)?,
+    }
+    w.finish()?;
+    if let Some(exp_char) = exp_char {
+        write!(f, "{exp_char}{}", dec_exp - 1)?;
+    }
+    for _ in 0..pad_right {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl Display for F128 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt_dec(*self, None, f)
+    }
+}
+
+impl LowerExp for F128 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt_dec(*self, Some('e'), f)
+    }
+}
+
+impl UpperExp for F128 {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        fmt_dec(*self, Some('E'), f)
+    }
+}
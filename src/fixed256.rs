@@ -0,0 +1,4897 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+#[allow(deprecated)]
+use crate::F128Bits;
+use crate::arith;
+use crate::from_str;
+use crate::from_str::{ParseFixedError, ParseOptions};
+use crate::helpers::{Private, ToFixedHelper, Widest};
+use crate::int256;
+use crate::int256::{U256, U512};
+use crate::inv_lerp;
+use crate::lerp;
+use crate::ratio;
+use crate::rounding::{RoundAbs, RoundingMode};
+use crate::traits::{FixedEquiv, FromFixed, RngLike, ToFixed};
+use crate::types::extra::{
+    IsLessOrEqual, LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256, True, U0, U253,
+};
+use crate::{
+    F128, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
+    FixedU128,
+};
+use bytemuck::TransparentWrapper;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use half::{bf16 as half_bf16, f16 as half_f16};
+
+#[macro_use]
+mod macros;
+#[macro_use]
+mod frac;
+#[macro_use]
+mod math;
+#[macro_use]
+mod no_frac;
+#[macro_use]
+mod trig;
+
+mod bits;
+pub(crate) mod float;
+
+pub use crate::fixed256::bits::{I256Bits, NonZeroI256Bits, NonZeroU256Bits, U256Bits};
+
+// There is no primitive 256-bit integer, so the 256-bit fixed-point numbers
+// store their bits in a U256, using two's complement for signed numbers, and
+// cannot use the fixed! macro. Conversions go through the Wide enum, which can
+// represent any value from any source exactly.
+
+pub enum Wide {
+    NaN,
+    Infinite { neg: bool },
+    Finite(Finite),
+}
+
+// The value is ±abs × 2^-frac_nbits. Zero is never negative. If the value has
+// to lose precision in a conversion, it is rounded using round.
+#[derive(Clone, Copy)]
+pub struct Finite {
+    pub(crate) neg: bool,
+    pub(crate) abs: U256,
+    pub(crate) frac_nbits: i32,
+    pub(crate) round: RoundingMode,
+}
+
+pub trait FromWide: Copy {
+    // Returns the value wrapped and whether it overflowed.
+    fn overflowing_from_finite(_: Private, src: Finite) -> (Self, bool);
+    // Like overflowing_from_finite, but floating-point numbers also round
+    // using src.round instead of always rounding to the nearest.
+    #[inline]
+    fn overflowing_from_finite_round(_: Private, src: Finite) -> (Self, bool) {
+        Self::overflowing_from_finite(Private, src)
+    }
+    // Returns the value that an overflowing value saturates to.
+    fn saturated(_: Private, neg: bool) -> Self;
+}
+
+const MSB: U256 = U256 {
+    lo: 0,
+    hi: 1 << 127,
+};
+
+#[inline]
+const fn is_neg(a: U256) -> bool {
+    a.hi >> 127 != 0
+}
+
+#[inline]
+const fn and(a: U256, b: U256) -> U256 {
+    U256 {
+        lo: a.lo & b.lo,
+        hi: a.hi & b.hi,
+    }
+}
+
+#[inline]
+const fn or(a: U256, b: U256) -> U256 {
+    U256 {
+        lo: a.lo | b.lo,
+        hi: a.hi | b.hi,
+    }
+}
+
+#[inline]
+const fn xor(a: U256, b: U256) -> U256 {
+    U256 {
+        lo: a.lo ^ b.lo,
+        hi: a.hi ^ b.hi,
+    }
+}
+
+#[inline]
+const fn not(a: U256) -> U256 {
+    U256 {
+        lo: !a.lo,
+        hi: !a.hi,
+    }
+}
+
+#[inline]
+const fn neg_if(neg: bool, abs: U256) -> U256 {
+    if neg {
+        int256::wrapping_neg_u256(abs)
+    } else {
+        abs
+    }
+}
+
+// Returns a mask with the low n bits set, n ≤ 256.
+#[inline]
+const fn low_mask(n: u32) -> U256 {
+    if n == 0 {
+        U256::ZERO
+    } else {
+        int256::wrapping_shr_u256(U256::MAX, 256 - n)
+    }
+}
+
+#[inline]
+const fn overflowing_shl(a: U256, sh: u32) -> (U256, bool) {
+    if int256::is_zero_u256(a) {
+        (U256::ZERO, false)
+    } else if sh >= 256 {
+        (U256::ZERO, true)
+    } else {
+        (
+            int256::wrapping_shl_u256(a, sh),
+            int256::leading_zeros_u256(a) < sh,
+        )
+    }
+}
+
+// Shifts abs right by shift bits, rounding the magnitude.
+pub(crate) const fn shr_round(abs: U256, shift: u32, round: RoundAbs) -> U256 {
+    if shift == 0 {
+        return abs;
+    }
+    let (q, half, lower) = if shift > 256 {
+        (U256::ZERO, false, !int256::is_zero_u256(abs))
+    } else {
+        let q = if shift == 256 {
+            U256::ZERO
+        } else {
+            int256::wrapping_shr_u256(abs, shift)
+        };
+        let half = int256::wrapping_shr_u256(abs, shift - 1).lo & 1 != 0;
+        let lower = !int256::is_zero_u256(and(abs, low_mask(shift - 1)));
+        (q, half, lower)
+    };
+    let up = round.is_up(half, lower, q.lo & 1 != 0);
+    // q < 2^255 unless shift is 0, so this cannot overflow
+    if up {
+        int256::wrapping_add_u256_u128(q, 1)
+    } else {
+        q
+    }
+}
+
+// Returns the logarithm of abs / 2^frac_nbits to the given base, rounded down.
+// abs must be nonzero, and base must be ≥ 2.
+const fn int_log(abs: U256, frac_nbits: u32, base: u32) -> i32 {
+    let base = U256 {
+        lo: base as u128,
+        hi: 0,
+    };
+    let mut int = if frac_nbits == 256 {
+        U256::ZERO
+    } else {
+        int256::wrapping_shr_u256(abs, frac_nbits)
+    };
+    let mut log = 0;
+    if !int256::is_zero_u256(int) {
+        loop {
+            int = int256::div_rem_u256(int, base).0;
+            if int256::is_zero_u256(int) {
+                return log;
+            }
+            log += 1;
+        }
+    }
+    // The value is < 1, so multiply it by base until it is ≥ 1. While it is
+    // < 1, it is < 2^frac_nbits in the fixed-point representation, and fits in
+    // the low half.
+    let mut val = U512 {
+        lo: abs,
+        hi: U256::ZERO,
+    };
+    loop {
+        val = int256::wide_mul_u256(val.lo, base);
+        log -= 1;
+        if !int256::is_zero_u512(int256::wrapping_shr_u512(val, frac_nbits)) {
+            return log;
+        }
+    }
+}
+
+// Checks whether ±abs fits in an nbits-bit integer.
+const fn fits(neg: bool, abs: U256, nbits: u32, signed: bool) -> bool {
+    let used = 256 - int256::leading_zeros_u256(abs);
+    if signed {
+        used < nbits || (neg && used == nbits && int256::trailing_zeros_u256(abs) == nbits - 1)
+    } else {
+        used <= nbits && (!neg || used == 0)
+    }
+}
+
+// Converts src to an nbits-bit number with dst_frac_nbits fractional bits.
+// Returns the wrapped value, which has its lower nbits bits correct, and
+// whether it overflowed.
+pub(crate) fn convert(src: Finite, dst_frac_nbits: u32, nbits: u32, signed: bool) -> (U256, bool) {
+    let shift = src.frac_nbits - dst_frac_nbits as i32;
+    let (abs, shl_overflow) = if shift >= 0 {
+        let round = src.round.round_abs(src.neg);
+        (shr_round(src.abs, shift.unsigned_abs(), round), false)
+    } else {
+        overflowing_shl(src.abs, shift.unsigned_abs())
+    };
+    let overflow = shl_overflow || !fits(src.neg, abs, nbits, signed);
+    (neg_if(src.neg, abs), overflow)
+}
+
+// Converts src to the helper used for conversions to fixed-point numbers with
+// at most 128 bits. Like the integer helpers, the value is rounded towards −∞
+// and wrapped, and overflows if it needs more than the destination bits, where
+// the sign bit is only counted for negative values.
+pub(crate) fn to_fixed_helper(
+    src: Finite,
+    dst_frac_nbits: u32,
+    dst_int_nbits: u32,
+) -> ToFixedHelper {
+    let (bits, overflow) = convert(src, dst_frac_nbits, dst_frac_nbits + dst_int_nbits, src.neg);
+    let shift = src.frac_nbits - dst_frac_nbits as i32;
+    let lost_bits =
+        shift > 0 && !int256::is_zero_u256(and(src.abs, low_mask(shift.min(256) as u32)));
+    ToFixedHelper {
+        bits: if src.neg {
+            Widest::Negative(bits.lo as i128)
+        } else {
+            Widest::Unsigned(bits.lo)
+        },
+        dir: if lost_bits {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        },
+        overflow,
+    }
+}
+
+// Converts src to Dst, rounding using round, and returns the wrapped value and
+// whether it overflowed.
+#[inline]
+#[track_caller]
+pub(crate) fn overflowing_convert_round<Src: ToFixed, Dst: FromWide>(
+    src: Src,
+    round: RoundingMode,
+) -> (Dst, bool) {
+    match src.to_wide(Private) {
+        Wide::NaN => panic!("NaN"),
+        Wide::Infinite { .. } => panic!("infinite"),
+        Wide::Finite(src) => Dst::overflowing_from_finite_round(Private, Finite { round, ..src }),
+    }
+}
+
+// Converts src to Dst, rounding using round, and returns None if the value is
+// not finite or does not fit.
+#[inline]
+pub(crate) fn checked_convert_round<Src: ToFixed, Dst: FromWide>(
+    src: Src,
+    round: RoundingMode,
+) -> Option<Dst> {
+    match src.to_wide(Private) {
+        Wide::Finite(src) => {
+            match Dst::overflowing_from_finite_round(Private, Finite { round, ..src }) {
+                (ans, false) => Some(ans),
+                (_, true) => None,
+            }
+        }
+        Wide::NaN | Wide::Infinite { .. } => None,
+    }
+}
+
+// Converts src to Dst, rounding using round, and saturating if it does not fit.
+#[inline]
+#[track_caller]
+pub(crate) fn saturating_convert_round<Src: ToFixed, Dst: FromWide>(
+    src: Src,
+    round: RoundingMode,
+) -> Dst {
+    match src.to_wide(Private) {
+        Wide::NaN => panic!("NaN"),
+        Wide::Infinite { neg } => Dst::saturated(Private, neg),
+        Wide::Finite(src) => {
+            match Dst::overflowing_from_finite_round(Private, Finite { round, ..src }) {
+                (ans, false) => ans,
+                (_, true) => Dst::saturated(Private, src.neg),
+            }
+        }
+    }
+}
+
+// Converts src to Dst, saturating if it does not fit, and returns whether it
+// overflowed.
+#[inline]
+#[track_caller]
+pub(crate) fn overflowing_saturating_convert<Src: ToFixed, Dst: FromWide>(src: Src) -> (Dst, bool) {
+    match src.to_wide(Private) {
+        Wide::NaN => panic!("NaN"),
+        Wide::Infinite { neg } => (Dst::saturated(Private, neg), true),
+        Wide::Finite(src) => match Dst::overflowing_from_finite(Private, src) {
+            (ans, false) => (ans, false),
+            (_, true) => (Dst::saturated(Private, src.neg), true),
+        },
+    }
+}
+
+// Returns the 64 bits of d below bit shift − 64 × k, where d < 2^shift and
+// 64 × k < shift.
+fn frac_chunk(d: U256, shift: u32, k: u32) -> u64 {
+    let end = 64 * (k + 1);
+    if end <= shift {
+        let pos = shift - end;
+        if pos >= 256 {
+            0
+        } else {
+            int256::wrapping_shr_u256(d, pos).lo as u64
+        }
+    } else {
+        // the wanted bits are all in d.lo, and truncation discards the others
+        (d.lo << (end - shift)) as u64
+    }
+}
+
+// Returns whether a random number uniformly distributed in [0, 1) is less than
+// the fraction whose first 64 bits are first and whose other bits are the bits
+// of d below bit shift − 64. Random bits are only drawn until the result is
+// known, so the comparison is exact.
+fn random_lt<R: RngLike + ?Sized>(first: u64, d: U256, shift: u32, rng: &mut R) -> bool {
+    // a zero fraction needs no random bits
+    if first == 0 && int256::is_zero_u256(d) {
+        return false;
+    }
+    let r = rng.next_u64();
+    if r != first {
+        return r < first;
+    }
+    for k in 1..shift.div_ceil(64) {
+        let r = rng.next_u64();
+        let chunk = frac_chunk(d, shift, k);
+        if r != chunk {
+            return r < chunk;
+        }
+    }
+    false
+}
+
+// Adds random noise to src and rounds it down to dst_frac_nbits fractional
+// bits. The noise is uniform in [0, 1) units of the result's lsb for
+// stochastic rounding, and is the sum of two such noises minus one half for
+// TPDF dither; either way the result is unbiased. The noise is added to the
+// magnitude, which keeps it symmetric for negative numbers. Returns the result
+// and whether its magnitude overflowed.
+fn add_noise_floor<R: RngLike + ?Sized>(
+    src: Finite,
+    dst_frac_nbits: u32,
+    dither: bool,
+    rng: &mut R,
+) -> (Finite, bool) {
+    let shift = src.frac_nbits - dst_frac_nbits as i32;
+    let (q, d, d_shift, shl_overflow) = if shift <= 0 {
+        let (q, overflow) = overflowing_shl(src.abs, shift.unsigned_abs());
+        (q, U256::ZERO, 0, overflow)
+    } else {
+        let shift = shift.unsigned_abs();
+        let q = if shift >= 256 {
+            U256::ZERO
+        } else {
+            int256::wrapping_shr_u256(src.abs, shift)
+        };
+        (q, and(src.abs, low_mask(shift.min(256))), shift, false)
+    };
+    let first = if d_shift == 0 {
+        0
+    } else {
+        frac_chunk(d, d_shift, 0)
+    };
+    // carry is the integer part of first × 2^-64 + noise − 1/2, in −1..=1
+    let (first, carry) = if dither {
+        let sum = i128::from(first) + i128::from(rng.next_u64()) - (1 << 63);
+        (sum as u64, (sum >> 64) as i32)
+    } else {
+        (first, 0)
+    };
+    let offset = carry + i32::from(random_lt(first, d, d_shift, rng));
+    let (neg, abs, add_overflow) = if offset >= 0 {
+        let (abs, overflow) = int256::overflowing_add_u256(
+            q,
+            U256 {
+                lo: offset as u128,
+                hi: 0,
+            },
+        );
+        (src.neg, abs, overflow)
+    } else if int256::is_zero_u256(q) {
+        // the noise moved the value across zero
+        (!src.neg, U256 { lo: 1, hi: 0 }, false)
+    } else {
+        let abs = int256::wrapping_sub_u256(q, U256 { lo: 1, hi: 0 });
+        (src.neg, abs, false)
+    };
+    let dst = Finite {
+        neg: neg && !int256::is_zero_u256(abs),
+        abs,
+        frac_nbits: dst_frac_nbits as i32,
+        round: RoundingMode::Floor,
+    };
+    (dst, shl_overflow || add_overflow)
+}
+
+// Converts src to Dst, which has dst_frac_nbits fractional bits, using
+// stochastic rounding or TPDF dither, and returns the wrapped value and
+// whether it overflowed.
+#[inline]
+#[track_caller]
+pub(crate) fn overflowing_convert_random<Src: ToFixed, Dst: FromWide, R: RngLike + ?Sized>(
+    src: Src,
+    dst_frac_nbits: u32,
+    dither: bool,
+    rng: &mut R,
+) -> (Dst, bool) {
+    match src.to_wide(Private) {
+        Wide::NaN => panic!("NaN"),
+        Wide::Infinite { .. } => panic!("infinite"),
+        Wide::Finite(src) => {
+            let (src, overflow1) = add_noise_floor(src, dst_frac_nbits, dither, rng);
+            let (ans, overflow2) = Dst::overflowing_from_finite(Private, src);
+            (ans, overflow1 || overflow2)
+        }
+    }
+}
+
+// Converts src to Dst, which has dst_frac_nbits fractional bits, using
+// stochastic rounding or TPDF dither, and returns None if the value is not
+// finite or does not fit.
+#[inline]
+pub(crate) fn checked_convert_random<Src: ToFixed, Dst: FromWide, R: RngLike + ?Sized>(
+    src: Src,
+    dst_frac_nbits: u32,
+    dither: bool,
+    rng: &mut R,
+) -> Option<Dst> {
+    match src.to_wide(Private) {
+        Wide::Finite(src) => match add_noise_floor(src, dst_frac_nbits, dither, rng) {
+            (src, false) => match Dst::overflowing_from_finite(Private, src) {
+                (ans, false) => Some(ans),
+                (_, true) => None,
+            },
+            (_, true) => None,
+        },
+        Wide::NaN | Wide::Infinite { .. } => None,
+    }
+}
+
+// Converts src to Dst, which has dst_frac_nbits fractional bits, using
+// stochastic rounding or TPDF dither, and saturating if it does not fit.
+#[inline]
+#[track_caller]
+pub(crate) fn saturating_convert_random<Src: ToFixed, Dst: FromWide, R: RngLike + ?Sized>(
+    src: Src,
+    dst_frac_nbits: u32,
+    dither: bool,
+    rng: &mut R,
+) -> Dst {
+    match src.to_wide(Private) {
+        Wide::NaN => panic!("NaN"),
+        Wide::Infinite { neg } => Dst::saturated(Private, neg),
+        Wide::Finite(src) => {
+            let (src, overflow1) = add_noise_floor(src, dst_frac_nbits, dither, rng);
+            match Dst::overflowing_from_finite(Private, src) {
+                (ans, false) if !overflow1 => ans,
+                _ => Dst::saturated(Private, src.neg),
+            }
+        }
+    }
+}
+
+// Rounds the fixed-point number ±abs with frac_nbits fractional bits to an
+// integer, and returns the wrapped result and whether it overflowed.
+const fn round_to_int(
+    neg: bool,
+    abs: U256,
+    frac_nbits: u32,
+    round: RoundAbs,
+    signed: bool,
+) -> (U256, bool) {
+    let int = shr_round(abs, frac_nbits, round);
+    let (abs, shl_overflow) = overflowing_shl(int, frac_nbits);
+    (
+        neg_if(neg, abs),
+        shl_overflow || !fits(neg, abs, 256, signed),
+    )
+}
+
+// Returns the bits of a floating-point number with nbits bits of which prec
+// are significand bits including the implicit bit, rounded using src.round.
+fn to_float_bits(src: Finite, nbits: u32, prec: u32) -> u128 {
+    let sign = if src.neg { 1u128 << (nbits - 1) } else { 0 };
+    if int256::is_zero_u256(src.abs) {
+        return sign;
+    }
+    let round = src.round.round_abs(src.neg);
+    let exp_bias = (1i32 << (nbits - prec - 1)) - 1;
+    let exp_min = 1 - exp_bias;
+    let inf_bits = ((1u128 << (nbits - prec)) - 1) << (prec - 1);
+    let exp = 255 - int256::leading_zeros_u256(src.abs) as i32 - src.frac_nbits;
+    if exp > exp_bias {
+        // rounding the magnitude down gives the largest finite magnitude
+        return match round {
+            RoundAbs::Down => sign | (inf_bits - 1),
+            _ => sign | inf_bits,
+        };
+    }
+    // subnormals have the same lsb as the smallest normal
+    let normal_exp = exp.max(exp_min);
+    let shift = normal_exp - (prec as i32 - 1) + src.frac_nbits;
+    let mant = if shift >= 0 {
+        shr_round(src.abs, shift.unsigned_abs(), round).lo
+    } else {
+        // the msb of abs is at most prec - 1 + shift, so abs fits in lo
+        src.abs.lo << shift.unsigned_abs()
+    };
+    // Adding the mantissa including its implicit bit to the biased exponent
+    // minus one handles both subnormals and rounding up to the next power of
+    // two.
+    let bits = ((normal_exp + exp_bias - 1) as u128) << (prec - 1);
+    sign | (bits + mant).min(inf_bits)
+}
+
+// Compares two magnitudes.
+fn cmp_abs(lhs: &Finite, rhs: &Finite) -> Ordering {
+    match (int256::is_zero_u256(lhs.abs), int256::is_zero_u256(rhs.abs)) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
+    let lhs_lz = int256::leading_zeros_u256(lhs.abs);
+    let rhs_lz = int256::leading_zeros_u256(rhs.abs);
+    let lhs_exp = 255 - lhs_lz as i32 - lhs.frac_nbits;
+    let rhs_exp = 255 - rhs_lz as i32 - rhs.frac_nbits;
+    if lhs_exp != rhs_exp {
+        return lhs_exp.cmp(&rhs_exp);
+    }
+    // Both have the same msb weight, so we can compare the normalized
+    // significands exactly.
+    let lhs_norm = int256::wrapping_shl_u256(lhs.abs, lhs_lz);
+    let rhs_norm = int256::wrapping_shl_u256(rhs.abs, rhs_lz);
+    lhs_norm.cmp(&rhs_norm)
+}
+
+pub(crate) fn partial_cmp_wide(lhs: Wide, rhs: Wide) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Wide::NaN, _) | (_, Wide::NaN) => None,
+        (Wide::Infinite { neg: lhs_neg }, Wide::Infinite { neg: rhs_neg }) => {
+            Some(rhs_neg.cmp(&lhs_neg))
+        }
+        (Wide::Infinite { neg }, Wide::Finite(_)) => Some(if neg {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }),
+        (Wide::Finite(_), Wide::Infinite { neg }) => Some(if neg {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }),
+        (Wide::Finite(lhs), Wide::Finite(rhs)) => Some(match (lhs.neg, rhs.neg) {
+            (false, false) => cmp_abs(&lhs, &rhs),
+            (true, true) => cmp_abs(&rhs, &lhs),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }),
+    }
+}
+
+const fn u256_from_be_bytes(bytes: [u8; 32]) -> U256 {
+    let mut hi = [0; 16];
+    let mut lo = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        hi[i] = bytes[i];
+        lo[i] = bytes[i + 16];
+        i += 1;
+    }
+    U256 {
+        lo: u128::from_be_bytes(lo),
+        hi: u128::from_be_bytes(hi),
+    }
+}
+
+const fn u256_to_be_bytes(a: U256) -> [u8; 32] {
+    let hi = a.hi.to_be_bytes();
+    let lo = a.lo.to_be_bytes();
+    let mut bytes = [0; 32];
+    let mut i = 0;
+    while i < 16 {
+        bytes[i] = hi[i];
+        bytes[i + 16] = lo[i];
+        i += 1;
+    }
+    bytes
+}
+
+const fn reverse_bytes(mut bytes: [u8; 32]) -> [u8; 32] {
+    let mut i = 0;
+    while i < 16 {
+        let tmp = bytes[i];
+        bytes[i] = bytes[31 - i];
+        bytes[31 - i] = tmp;
+        i += 1;
+    }
+    bytes
+}
+
+macro_rules! fixed256 {
+    (
+        description = $description:literal,
+        {Self, ISelf, USelf} = {$Self:ident, $ISelf:ident, $USelf:ident},
+        Signedness = $Signedness:ident,
+        {Bits, from_prim} = {$Bits:ident, $from_prim:ident},
+    ) => {
+        comment! {
+            $description, "-bit ",
+            if_signed_unsigned!($Signedness, "signed", "unsigned"),
+            " number with `Frac` fractional bits.
+
+The number has 256 bits, of which <i>f</i>&nbsp;=&nbsp;`Frac` are fractional
+bits and 256&nbsp;&minus;&nbsp;<i>f</i> are integer bits. The value <i>x</i>
+can lie in the range ",
+            if_signed_unsigned!(
+                $Signedness,
+                "&minus;2<sup>255</sup>/2<sup><i>f</i></sup>",
+                "0",
+            ),
+            "&nbsp;≤&nbsp;<i>x</i>&nbsp;<&nbsp;2<sup>",
+            if_signed_unsigned!($Signedness, "255", "256"),
+            "</sup>/2<sup><i>f</i></sup>. The difference between successive
+numbers is constant throughout the range: <i>Δ</i>&nbsp;=&nbsp;1/2<sup><i>f</i></sup>.
+
+`Frac` is an [`Unsigned`] as provided by the [*typenum* crate], and can be at
+most [`U256`].
+
+Since there is no primitive 256-bit integer, the bits are represented by
+[`", stringify!($Bits), "`], which is used by methods such as
+[`from_bits`][Self::from_bits] and [`to_bits`][Self::to_bits].
+Conversions from and to other numbers are available using
+[`from_num`][Self::from_num] and [`to_num`][Self::to_num], and conversions from
+and to strings are available using [`FromStr`] and [`Display`].
+
+# Examples
+
+```rust
+use fixed::types::extra::U3;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U3>;
+let eleven = Fix::from_num(11);
+assert_eq!(eleven, 11);
+assert_eq!(eleven.to_string(), \"11\");
+let two_point_75 = eleven / Fix::from_num(4);
+assert_eq!(two_point_75, 2.75);
+assert_eq!(two_point_75.to_string(), \"2.8\");
+```
+
+[*typenum* crate]: https://crates.io/crates/typenum
+[`Display`]: core::fmt::Display
+[`FromStr`]: core::str::FromStr
+[`U256`]: crate::types::extra::U256
+[`Unsigned`]: crate::types::extra::Unsigned
+";
+            #[repr(transparent)]
+            pub struct $Self<Frac> {
+                pub(crate) bits: $Bits,
+                phantom: PhantomData<Frac>,
+            }
+        }
+
+        impl<Frac> Clone for $Self<Frac> {
+            #[inline]
+            fn clone(&self) -> $Self<Frac> {
+                *self
+            }
+        }
+
+        impl<Frac> Copy for $Self<Frac> {}
+
+        impl<Frac> Default for $Self<Frac> {
+            #[inline]
+            fn default() -> Self {
+                $Self::ZERO
+            }
+        }
+
+        impl<Frac> Hash for $Self<Frac> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.bits.0.hash(state);
+            }
+        }
+
+        /// The implementation of items in this block is independent
+        /// of the number of fractional bits `Frac`.
+        impl<Frac> $Self<Frac> {
+            comment! {
+                "Zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO, Fix::from_num(0));
+```
+";
+                pub const ZERO: $Self<Frac> = Self::from_u256(U256::ZERO);
+            }
+
+            comment! {
+                "The difference between any two successive representable numbers, <i>Δ</i>.
+
+If the number has <i>f</i>&nbsp;=&nbsp;`Frac` fractional bits, then
+<i>Δ</i>&nbsp;=&nbsp;1/2<sup><i>f</i></sup>.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// binary 0.0001 is decimal 0.0625
+assert_eq!(Fix::DELTA, 0.0625);
+```
+";
+                pub const DELTA: $Self<Frac> = Self::from_u256(U256 { lo: 1, hi: 0 });
+            }
+
+            comment! {
+                "The smallest value that can be represented.
+
+",
+                if_signed_unsigned! {
+                    $Signedness,
+                    "If the number has <i>f</i>&nbsp;=&nbsp;`Frac` fractional bits,
+then the minimum is &minus;2<sup>255</sup>/2<sup><i>f</i></sup>.",
+                    "The minimum of unsigned numbers is 0."
+                },
+                "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN, -Fix::MAX - Fix::DELTA);",
+                    "assert_eq!(Fix::MIN, 0);",
+                ),
+                "
+```
+";
+                pub const MIN: $Self<Frac> = Self::from_u256(if Self::IS_SIGNED {
+                    MSB
+                } else {
+                    U256::ZERO
+                });
+            }
+
+            comment! {
+                "The largest value that can be represented.
+
+If the number has <i>f</i>&nbsp;=&nbsp;`Frac` fractional bits, then the maximum is
+(2<sup>",
+                if_signed_unsigned!($Signedness, "255", "256"),
+                "</sup>&nbsp;&minus;&nbsp;1)/2<sup><i>f</i></sup>.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::MAX.to_be_bytes()[0], ",
+                if_signed_unsigned!($Signedness, "0x7F", "0xFF"),
+                ");
+assert_eq!(Fix::MAX.count_zeros(), ",
+                if_signed_unsigned!($Signedness, "1", "0"),
+                ");
+```
+";
+                pub const MAX: $Self<Frac> = Self::from_u256(if Self::IS_SIGNED {
+                    not(MSB)
+                } else {
+                    U256::MAX
+                });
+            }
+
+            comment! {
+                if_signed_unsigned!($Signedness, "[`true`]", "[`false`]"),
+                "[`bool`] because the [`", stringify!($Self), "`] type is ",
+                if_signed_unsigned!($Signedness, "signed", "unsigned"),
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert!(", if_signed_unsigned!($Signedness, "", "!"), "Fix::IS_SIGNED);
+```
+";
+                pub const IS_SIGNED: bool = if_signed_unsigned!($Signedness, true, false);
+            }
+
+            comment! {
+                "Creates a fixed-point number that has a bitwise
+representation identical to the given integer.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 0010.0000 = 2
+let bits = ", stringify!($Bits), "::",
+                if_signed_unsigned!($Signedness, "from_i128", "from_u128"),
+                "(0b10_0000);
+assert_eq!(Fix::from_bits(bits), 2);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_bits(bits: $Bits) -> $Self<Frac> {
+                    $Self {
+                        bits,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            comment! {
+                "Creates an integer that has a bitwise representation
+identical to the given fixed-point number.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 2 is 0010.0000
+let bits = ", stringify!($Bits), "::",
+                if_signed_unsigned!($Signedness, "from_i128", "from_u128"),
+                "(0b10_0000);
+assert_eq!(Fix::from_num(2).to_bits(), bits);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_bits(self) -> $Bits {
+                    self.bits
+                }
+            }
+
+            #[inline]
+            pub(crate) const fn from_u256(bits: U256) -> $Self<Frac> {
+                $Self {
+                    bits: $Bits(bits),
+                    phantom: PhantomData,
+                }
+            }
+
+            #[inline]
+            pub(crate) const fn to_u256(self) -> U256 {
+                self.bits.0
+            }
+
+            #[inline]
+            pub(crate) const fn neg_abs(self) -> (bool, U256) {
+                if Self::IS_SIGNED && is_neg(self.bits.0) {
+                    (true, int256::wrapping_neg_u256(self.bits.0))
+                } else {
+                    (false, self.bits.0)
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in big endian.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut bytes = [0; 32];
+bytes[31] = 0x28;
+assert_eq!(Fix::from_be_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_be_bytes(bytes: [u8; 32]) -> $Self<Frac> {
+                    $Self::from_u256(u256_from_be_bytes(bytes))
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in little endian.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut bytes = [0; 32];
+bytes[0] = 0x28;
+assert_eq!(Fix::from_le_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_le_bytes(bytes: [u8; 32]) -> $Self<Frac> {
+                    $Self::from_u256(u256_from_be_bytes(reverse_bytes(bytes)))
+                }
+            }
+
+            comment! {
+                "Creates a fixed-point number from its representation
+as a byte array in native endian.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut bytes = [0; 32];
+if cfg!(target_endian = \"big\") {
+    bytes[31] = 0x28;
+} else {
+    bytes[0] = 0x28;
+}
+assert_eq!(Fix::from_ne_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_ne_bytes(bytes: [u8; 32]) -> $Self<Frac> {
+                    if cfg!(target_endian = "big") {
+                        $Self::from_be_bytes(bytes)
+                    } else {
+                        $Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in big-endian byte order.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let bytes = Fix::from_num(2.5).to_be_bytes();
+assert_eq!(bytes[31], 0x28);
+assert_eq!(Fix::from_be_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_be_bytes(self) -> [u8; 32] {
+                    u256_to_be_bytes(self.to_u256())
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in little-endian byte order.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let bytes = Fix::from_num(2.5).to_le_bytes();
+assert_eq!(bytes[0], 0x28);
+assert_eq!(Fix::from_le_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_le_bytes(self) -> [u8; 32] {
+                    reverse_bytes(u256_to_be_bytes(self.to_u256()))
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this fixed-point
+number as a byte array in native byte order.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let bytes = Fix::from_num(2.5).to_ne_bytes();
+assert_eq!(Fix::from_ne_bytes(bytes), 2.5);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_ne_bytes(self) -> [u8; 32] {
+                    if cfg!(target_endian = "big") {
+                        self.to_be_bytes()
+                    } else {
+                        self.to_le_bytes()
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the number of ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// binary 11.1
+let f = Fix::from_num(3.5);
+assert_eq!(f.count_ones(), 3);
+```
+";
+                #[inline]
+                #[doc(alias("popcount", "popcnt"))]
+                #[must_use]
+                pub const fn count_ones(self) -> u32 {
+                    self.bits.0.lo.count_ones() + self.bits.0.hi.count_ones()
+                }
+            }
+
+            comment! {
+                "Returns the number of zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// binary 11.1
+let f = Fix::from_num(3.5);
+assert_eq!(f.count_zeros(), 256 - 3);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn count_zeros(self) -> u32 {
+                    self.bits.0.lo.count_zeros() + self.bits.0.hi.count_zeros()
+                }
+            }
+
+            comment! {
+                "Returns the number of leading zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// binary 11.1, with 4 fractional bits
+let f = Fix::from_num(3.5);
+assert_eq!(f.leading_zeros(), 256 - 6);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn leading_zeros(self) -> u32 {
+                    int256::leading_zeros_u256(self.bits.0)
+                }
+            }
+
+            comment! {
+                "Returns the number of trailing zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// binary 11.1, with 4 fractional bits
+let f = Fix::from_num(3.5);
+assert_eq!(f.trailing_zeros(), 3);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn trailing_zeros(self) -> u32 {
+                    int256::trailing_zeros_u256(self.bits.0)
+                }
+            }
+
+            comment! {
+                "Returns [`true`] if the number is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert!(Fix::ZERO.is_zero());
+assert!(!Fix::DELTA.is_zero());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn is_zero(self) -> bool {
+                    int256::is_zero_u256(self.bits.0)
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+
+                comment! {
+                    "Returns [`true`] if the number is >&nbsp;0.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert!(Fix::from_num(4.3).is_positive());
+assert!(!Fix::ZERO.is_positive());
+assert!(!Fix::from_num(-4.3).is_positive());
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_positive(self) -> bool {
+                        !is_neg(self.bits.0) && !self.is_zero()
+                    }
+                }
+
+                comment! {
+                    "Returns [`true`] if the number is <&nbsp;0.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert!(!Fix::from_num(4.3).is_negative());
+assert!(!Fix::ZERO.is_negative());
+assert!(Fix::from_num(-4.3).is_negative());
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_negative(self) -> bool {
+                        is_neg(self.bits.0)
+                    }
+                }
+
+                comment! {
+                    "Returns the absolute value.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can
+be returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_abs`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let five = Fix::from_num(5);
+let minus_five = Fix::from_num(-5);
+assert_eq!(five.abs(), five);
+assert_eq!(minus_five.abs(), five);
+```
+
+[`wrapping_abs`]: Self::wrapping_abs
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn abs(self) -> $Self<Frac> {
+                        let (ans, overflow) = self.overflowing_abs();
+                        debug_assert!(!overflow, "overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Returns the absolute value using an unsigned type
+without any wrapping or panicking.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+type Fix = ", stringify!($Self), "<U4>;
+type UFix = ", stringify!($USelf), "<U4>;
+assert_eq!(Fix::from_num(-5).unsigned_abs(), UFix::from_num(5));
+// min_as_unsigned has only highest bit set
+let min_as_unsigned = UFix::ONE << (UFix::INT_NBITS - 1);
+assert_eq!(Fix::MIN.unsigned_abs(), min_as_unsigned);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn unsigned_abs(self) -> $USelf<Frac> {
+                        $USelf::from_u256(self.neg_abs().1)
+                    }
+                }
+
+                comment! {
+                    "Checked absolute value. Returns the absolute value, or [`None`] on overflow.
+
+Overflow can only occur when trying to find the absolute value of the minimum value.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-5).checked_abs(), Some(Fix::from_num(5)));
+assert_eq!(Fix::MIN.checked_abs(), None);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_abs(self) -> Option<$Self<Frac>> {
+                        match self.overflowing_abs() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Saturating absolute value. Returns the absolute value, saturating on overflow.
+
+Overflow can only occur when trying to find the absolute value of the minimum value.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-5).saturating_abs(), Fix::from_num(5));
+assert_eq!(Fix::MIN.saturating_abs(), Fix::MAX);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn saturating_abs(self) -> $Self<Frac> {
+                        match self.overflowing_abs() {
+                            (ans, false) => ans,
+                            (_, true) => Self::MAX,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Wrapping absolute value. Returns the absolute value, wrapping on overflow.
+
+Overflow can only occur when trying to find the absolute value of the minimum value.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-5).wrapping_abs(), Fix::from_num(5));
+assert_eq!(Fix::MIN.wrapping_abs(), Fix::MIN);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn wrapping_abs(self) -> $Self<Frac> {
+                        self.overflowing_abs().0
+                    }
+                }
+
+                comment! {
+                    "Unwrapped absolute value. Returns the absolute value, panicking on overflow.
+
+Overflow can only occur when trying to find the absolute value of the minimum value.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-5).unwrapped_abs(), Fix::from_num(5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MIN.unwrapped_abs();
+```
+";
+                    #[inline]
+                    #[track_caller]
+                    #[must_use]
+                    pub const fn unwrapped_abs(self) -> $Self<Frac> {
+                        match self.overflowing_abs() {
+                            (ans, false) => ans,
+                            (_, true) => panic!("overflow"),
+                        }
+                    }
+                }
+
+                comment! {
+                    "Overflowing absolute value.
+
+Returns a [tuple] of the fixed-point number and a [`bool`], indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-5).overflowing_abs(), (Fix::from_num(5), false));
+assert_eq!(Fix::MIN.overflowing_abs(), (Fix::MIN, true));
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn overflowing_abs(self) -> ($Self<Frac>, bool) {
+                        let abs = self.neg_abs().1;
+                        ($Self::from_u256(abs), is_neg(abs))
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+
+                comment! {
+                    "Returns [`true`] if the fixed-point number is
+2<sup><i>k</i></sup> for some integer <i>k</i>.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_num(0.375);
+// 1/2 is 0.1000
+let half = Fix::from_num(0.5);
+assert!(!three_eights.is_power_of_two());
+assert!(half.is_power_of_two());
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_power_of_two(self) -> bool {
+                        self.count_ones() == 1
+                    }
+                }
+            }
+
+            comment! {
+                "Checked negation. Returns the negated value, or [`None`] on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).checked_neg(), Some(Fix::from_num(-5)));
+assert_eq!(Fix::MIN.checked_neg(), None);",
+                    "assert_eq!(Fix::ZERO.checked_neg(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(5).checked_neg(), None);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg(self) -> Option<$Self<Frac>> {
+                    match self.overflowing_neg() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating negation. Returns the negated value, saturating on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "This method always returns zero.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).saturating_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.saturating_neg(), Fix::MAX);",
+                    "assert_eq!(Fix::ZERO.saturating_neg(), Fix::ZERO);
+assert_eq!(Fix::from_num(5).saturating_neg(), Fix::ZERO);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_neg(self) -> $Self<Frac> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if Self::IS_SIGNED {
+                                Self::MAX
+                            } else {
+                                Self::ZERO
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping negation. Returns the negated value, wrapping on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).wrapping_neg(), Fix::from_num(-5));
+assert_eq!(Fix::MIN.wrapping_neg(), Fix::MIN);",
+                    "assert_eq!(Fix::ZERO.wrapping_neg(), Fix::ZERO);
+assert_eq!(Fix::DELTA.wrapping_neg(), Fix::MAX);",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_neg(self) -> $Self<Frac> {
+                    self.overflowing_neg().0
+                }
+            }
+
+            comment! {
+                "Unwrapped negation. Returns the negated value, panicking on overflow.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).unwrapped_neg(), Fix::from_num(-5));",
+                    "assert_eq!(Fix::ZERO.unwrapped_neg(), Fix::ZERO);",
+                ),
+                "
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "let _overflow = Fix::MIN.unwrapped_neg();",
+                    "let _overflow = Fix::from_num(5).unwrapped_neg();",
+                ),
+                "
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_neg(self) -> $Self<Frac> {
+                    match self.overflowing_neg() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Overflow can only occur when negating the minimum value.",
+                    "Only zero can be negated without overflow.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(5).overflowing_neg(), (Fix::from_num(-5), false));
+assert_eq!(Fix::MIN.overflowing_neg(), (Fix::MIN, true));",
+                    "assert_eq!(Fix::ZERO.overflowing_neg(), (Fix::ZERO, false));
+assert_eq!(Fix::DELTA.overflowing_neg(), (Fix::MAX, true));",
+                ),
+                "
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_neg(self) -> ($Self<Frac>, bool) {
+                    let ans = int256::wrapping_neg_u256(self.bits.0);
+                    let overflow = if Self::IS_SIGNED {
+                        is_neg(self.bits.0) && is_neg(ans)
+                    } else {
+                        !self.is_zero()
+                    };
+                    ($Self::from_u256(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked addition. Returns the sum, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one = Fix::from_num(1);
+assert_eq!((Fix::MAX - one).checked_add(one), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_add(one), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating addition. Returns the sum, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).saturating_add(Fix::from_num(2)), Fix::from_num(5));
+assert_eq!(Fix::MAX.saturating_add(Fix::DELTA), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_add(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if Self::IS_SIGNED && is_neg(rhs.bits.0) {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping addition. Returns the sum, wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).wrapping_add(Fix::from_num(2)), Fix::from_num(5));
+assert_eq!(Fix::MAX.wrapping_add(Fix::DELTA), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_add(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_add(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped addition. Returns the sum, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).unwrapped_add(Fix::from_num(2)), Fix::from_num(5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MAX.unwrapped_add(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_add(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing addition.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let (three, two) = (Fix::from_num(3), Fix::from_num(2));
+assert_eq!(three.overflowing_add(two), (Fix::from_num(5), false));
+assert_eq!(Fix::MAX.overflowing_add(Fix::DELTA), (Fix::MIN, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_add(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    let (ans, carry) = int256::overflowing_add_u256(self.bits.0, rhs.bits.0);
+                    let overflow = if Self::IS_SIGNED {
+                        is_neg(self.bits.0) == is_neg(rhs.bits.0) && is_neg(ans) != is_neg(self.bits.0)
+                    } else {
+                        carry
+                    };
+                    ($Self::from_u256(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked subtraction. Returns the difference, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one = Fix::from_num(1);
+assert_eq!((Fix::MIN + one).checked_sub(one), Some(Fix::MIN));
+assert_eq!(Fix::MIN.checked_sub(one), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating subtraction. Returns the difference, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5).saturating_sub(Fix::from_num(3)), Fix::from_num(2));
+assert_eq!(Fix::MIN.saturating_sub(Fix::DELTA), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sub(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if Self::IS_SIGNED && is_neg(rhs.bits.0) {
+                                Self::MAX
+                            } else {
+                                Self::MIN
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping subtraction. Returns the difference, wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5).wrapping_sub(Fix::from_num(3)), Fix::from_num(2));
+assert_eq!(Fix::MIN.wrapping_sub(Fix::DELTA), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sub(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_sub(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped subtraction. Returns the difference, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5).unwrapped_sub(Fix::from_num(3)), Fix::from_num(2));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MIN.unwrapped_sub(Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sub(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing subtraction.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let (five, three) = (Fix::from_num(5), Fix::from_num(3));
+assert_eq!(five.overflowing_sub(three), (Fix::from_num(2), false));
+assert_eq!(Fix::MIN.overflowing_sub(Fix::DELTA), (Fix::MAX, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sub(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    let (ans, borrow) = int256::overflowing_sub_u256(self.bits.0, rhs.bits.0);
+                    let overflow = if Self::IS_SIGNED {
+                        is_neg(self.bits.0) != is_neg(rhs.bits.0) && is_neg(ans) != is_neg(self.bits.0)
+                    } else {
+                        borrow
+                    };
+                    ($Self::from_u256(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked remainder. Returns the remainder, or [`None`] if
+the divisor is zero.
+
+The remainder has the same sign as the dividend.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3.75).checked_rem(Fix::ONE), Some(Fix::from_num(0.75)));
+assert_eq!(Fix::from_num(3.75).checked_rem(Fix::ZERO), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_rem(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    let (neg, lhs_abs) = self.neg_abs();
+                    let n = U512 {
+                        lo: lhs_abs,
+                        hi: U256::ZERO,
+                    };
+                    let (_, rem) = int256::div_rem_u512_u256(n, rhs.neg_abs().1);
+                    Some($Self::from_u256(neg_if(neg, rem)))
+                }
+            }
+
+            #[inline]
+            const fn saturated(neg: bool) -> $Self<Frac> {
+                if neg { Self::MIN } else { Self::MAX }
+            }
+
+            fixed256_no_frac! {
+                {Self, ISelf, USelf} = {$Self, $ISelf, $USelf},
+                Signedness = $Signedness,
+                {Bits, from_prim} = {$Bits, $from_prim},
+            }
+        }
+
+        /// The implementation of items in this block depends on the
+        /// number of fractional bits `Frac`.
+        impl<Frac: LeEqU256> $Self<Frac> {
+            comment! {
+                "The number of integer bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U6;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U6>;
+assert_eq!(Fix::INT_NBITS, 256 - 6);
+```
+";
+                pub const INT_NBITS: u32 = 256 - Self::FRAC_NBITS;
+            }
+
+            comment! {
+                "The number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U6;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U6>;
+assert_eq!(Fix::FRAC_NBITS, 6);
+```
+";
+                pub const FRAC_NBITS: u32 = Frac::U32;
+            }
+
+            comment! {
+                "One.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE, Fix::from_num(1));
+```
+
+This constant does not exist if there are not enough integer bits to
+represent one; [`TRY_ONE`][Self::TRY_ONE] is available in all cases.
+";
+                pub const ONE: $Self<Frac> = match Self::TRY_ONE {
+                    Some(one) => one,
+                    None => panic!("one does not fit"),
+                };
+            }
+
+            comment! {
+                "One if the fixed-point number can represent it, otherwise [`None`].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", if_signed_unsigned!($Signedness, "255", "256"), "};
+use fixed::", stringify!($Self), ";
+type One = ", stringify!($Self), "<U4>;
+type Fraction = ", stringify!($Self), "<U", if_signed_unsigned!($Signedness, "255", "256"), ">;
+assert_eq!(One::TRY_ONE, Some(One::from_num(1)));
+assert_eq!(Fraction::TRY_ONE, None);
+```
+";
+                pub const TRY_ONE: Option<$Self<Frac>> =
+                    if Self::INT_NBITS > if_signed_unsigned!($Signedness, 1, 0) {
+                        Some(Self::from_u256(int256::wrapping_shl_u256(
+                            U256 { lo: 1, hi: 0 },
+                            Self::FRAC_NBITS,
+                        )))
+                    } else {
+                        None
+                    };
+            }
+
+            if_signed! {
+                $Signedness;
+
+                comment! {
+                    "Negative one.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::NEG_ONE, Fix::from_num(-1));
+```
+
+This constant does not exist if there are no integer bits to represent negative
+one; [`TRY_NEG_ONE`][Self::TRY_NEG_ONE] is available in all cases.
+";
+                    pub const NEG_ONE: $Self<Frac> = match Self::TRY_NEG_ONE {
+                        Some(neg_one) => neg_one,
+                        None => panic!("negative one does not fit"),
+                    };
+                }
+
+                comment! {
+                    "Negative one if the fixed-point number can represent it, otherwise
+[`None`].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U256};
+use fixed::", stringify!($Self), ";
+type NegOne = ", stringify!($Self), "<U4>;
+type Fraction = ", stringify!($Self), "<U256>;
+assert_eq!(NegOne::TRY_NEG_ONE, Some(NegOne::from_num(-1)));
+assert_eq!(Fraction::TRY_NEG_ONE, None);
+```
+";
+                    pub const TRY_NEG_ONE: Option<$Self<Frac>> = if Self::INT_NBITS > 0 {
+                        Some(Self::from_u256(int256::wrapping_shl_u256(
+                            U256::MAX,
+                            Self::FRAC_NBITS,
+                        )))
+                    } else {
+                        None
+                    };
+                }
+            }
+
+            #[inline]
+            pub(crate) fn finite(self) -> Finite {
+                let (neg, abs) = self.neg_abs();
+                Finite {
+                    neg,
+                    abs,
+                    frac_nbits: Self::FRAC_NBITS as i32,
+                    round: RoundingMode::Floor,
+                }
+            }
+
+            #[inline]
+            fn overflowing_from_finite(src: Finite) -> ($Self<Frac>, bool) {
+                let (bits, overflow) = convert(src, Self::FRAC_NBITS, 256, Self::IS_SIGNED);
+                ($Self::from_u256(bits), overflow)
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number.
+
+The other number can be:
+
+  * A fixed-point number. Any extra fractional bits are discarded, which rounds
+    towards &minus;∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`],
+    [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`] or [`bool`].
+  * A floating-point number of type <code>[half]::[f16][half::f16]</code>,
+    <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or [`F128`]. For
+    this conversion, the method rounds to the nearest, with ties rounding to
+    even.
+  * Any other number `src` for which [`ToFixed`] is implemented, in which case
+    this method returns <code>src.[to\_fixed][ToFixed::to_fixed]\()</code>.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_from_num`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+
+// 1.75 is 1.11 in binary
+let src = I8F8::from_bits(0b111 << (8 - 2));
+assert_eq!(Fix::from_num(src), Fix::from_num(1.75));
+assert_eq!(Fix::from_num(3u8), 3);
+assert_eq!(Fix::from_num(1.75f32), src);
+// 1.09375 lies exactly between 1.0625 and 1.125, and rounds to even
+assert_eq!(Fix::from_num(1.09375f64), 1.125);
+```
+
+[`wrapping_from_num`]: Self::wrapping_from_num
+[finite]: f64::is_finite
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn from_num<Src: ToFixed>(src: Src) -> $Self<Frac> {
+                    src.to_fixed()
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number.
+
+The other number can be:
+
+  * Another fixed-point number. Any extra fractional bits are discarded, which
+    rounds towards &minus;∞.
+  * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`],
+    [`u8`], [`u16`], [`u32`], [`u64`], [`u128`] or [`usize`]. Any fractional
+    bits are discarded, which rounds towards &minus;∞.
+  * A floating-point number of type <code>[half]::[f16][half::f16]</code>,
+    <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or [`F128`]. For
+    this conversion, the method rounds to the nearest, with ties rounding to
+    even.
+  * Any other type `Dst` for which [`FromFixed`] is implemented, in which case
+    this method returns <code>Dst::[from\_fixed][FromFixed::from_fixed]\(self)</code>.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit. When debug
+assertions are not enabled, the wrapped value can be returned, but it is not
+considered a breaking change if in the future it panics; if wrapping is required
+use [`wrapping_to_num`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+
+let f = Fix::from_num(2.5);
+assert_eq!(f.to_num::<I8F8>(), I8F8::from_num(2.5));
+assert_eq!(f.to_num::<i32>(), 2);
+assert_eq!(f.to_num::<f32>(), 2.5);
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -2.5 is rounded towards −∞
+assert_eq!(Fix::from_num(-2.5).to_num::<i32>(), -3);
+"
+                },
+                r#"```
+
+[`wrapping_to_num`]: Self::wrapping_to_num
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::from_fixed(self)
+                }
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number if it
+fits, otherwise returns [`None`].
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`from_num`] for details on rounding. For floating-point numbers
+that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::checked_from_num(1.75f32), Some(Fix::from_num(1.75)));
+assert_eq!(Fix::checked_from_num(1e80), None);
+assert_eq!(Fix::checked_from_num(f64::NAN), None);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+"#;
+                #[inline]
+                pub fn checked_from_num<Src: ToFixed>(src: Src) -> Option<$Self<Frac>> {
+                    src.checked_to_fixed()
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number if it
+fits, otherwise returns [`None`].
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`to_num`] for details on rounding.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_num(2.5).checked_to_num::<I8F8>(), Some(I8F8::from_num(2.5)));
+assert_eq!(Fix::from_num(300).checked_to_num::<I8F8>(), None);
+assert_eq!(Fix::from_num(300).checked_to_num::<u8>(), None);
+```
+
+[`to_num`]: Self::to_num
+"#;
+                #[inline]
+                pub fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+                    Dst::checked_from_fixed(self)
+                }
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number,
+saturating if it does not fit.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`from_num`] for details on rounding.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::saturating_from_num(1.75f32), Fix::from_num(1.75));
+assert_eq!(Fix::saturating_from_num(1e80), Fix::MAX);
+assert_eq!(Fix::saturating_from_num(f64::NEG_INFINITY), Fix::MIN);
+```
+
+[`from_num`]: Self::from_num
+[NaN]: f64::is_nan
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn saturating_from_num<Src: ToFixed>(src: Src) -> $Self<Frac> {
+                    src.saturating_to_fixed()
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number,
+saturating the value if it does not fit.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`to_num`] for details on rounding.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_num(2.5).saturating_to_num::<I8F8>(), I8F8::from_num(2.5));
+assert_eq!(Fix::from_num(300).saturating_to_num::<I8F8>(), I8F8::MAX);
+assert_eq!(Fix::from_num(300).saturating_to_num::<u8>(), u8::MAX);
+```
+
+[`to_num`]: Self::to_num
+"#;
+                #[inline]
+                pub fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::saturating_from_fixed(self)
+                }
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number,
+wrapping the value on overflow.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`from_num`] for details on rounding.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::wrapping_from_num(1.75f32), Fix::from_num(1.75));
+// 2^252 is 1 followed by 256 zero bits when Frac is U4
+assert_eq!(Fix::wrapping_from_num(2f64.powi(252)), 0);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn wrapping_from_num<Src: ToFixed>(src: Src) -> $Self<Frac> {
+                    src.wrapping_to_fixed()
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number,
+wrapping the value on overflow.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`to_num`] for details on rounding.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_num(2.5).wrapping_to_num::<I8F8>(), I8F8::from_num(2.5));
+// 300.25 is 0x12C.4 and wraps to 0x2C.4
+assert_eq!(Fix::from_num(300.25).wrapping_to_num::<I8F8>(), I8F8::from_num(44.25));
+assert_eq!(Fix::from_num(300).wrapping_to_num::<u8>(), 44);
+```
+
+[`to_num`]: Self::to_num
+"#;
+                #[inline]
+                pub fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+                    Dst::wrapping_from_fixed(self)
+                }
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number,
+panicking on overflow.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`from_num`] for details on rounding.
+
+# Panics
+
+Panics if the value does not fit.
+
+For floating-point numbers, also panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::unwrapped_from_num(1.75f32), Fix::from_num(1.75));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let _overflow = Fix::unwrapped_from_num(1e80);
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_from_num<Src: ToFixed>(src: Src) -> $Self<Frac> {
+                    match src.overflowing_to_fixed() {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number,
+panicking on overflow.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`to_num`] for details on rounding.
+
+# Panics
+
+Panics if the value does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_num(2.5).unwrapped_to_num::<I8F8>(), I8F8::from_num(2.5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let _overflow = Fix::from_num(300).unwrapped_to_num::<I8F8>();
+```
+
+[`to_num`]: Self::to_num
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst {
+                    match Dst::overflowing_from_fixed(self) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
+                }
+            }
+
+            comment! {
+                r#"Creates a fixed-point number from another number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`from_num`] for details on rounding.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::overflowing_from_num(1.75f32), (Fix::from_num(1.75), false));
+// 2^252 is 1 followed by 256 zero bits when Frac is U4
+assert_eq!(Fix::overflowing_from_num(2f64.powi(252)), (Fix::ZERO, true));
+```
+
+[`from_num`]: Self::from_num
+[finite]: f64::is_finite
+"#;
+                #[inline]
+                #[track_caller]
+                pub fn overflowing_from_num<Src: ToFixed>(src: Src) -> ($Self<Frac>, bool) {
+                    src.overflowing_to_fixed()
+                }
+            }
+
+            comment! {
+                r#"Converts a fixed-point number to another number.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+The other number can be a fixed-point number, an integer or a floating-point
+number; see [`to_num`] for details on rounding.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I8F8;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let check = I8F8::from_num(2.5);
+assert_eq!(Fix::from_num(2.5).overflowing_to_num::<I8F8>(), (check, false));
+// 300.25 is 0x12C.4 and wraps to 0x2C.4
+let wrapped = I8F8::from_num(44.25);
+assert_eq!(Fix::from_num(300.25).overflowing_to_num::<I8F8>(), (wrapped, true));
+```
+
+[`to_num`]: Self::to_num
+"#;
+                #[inline]
+                pub fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+                    Dst::overflowing_from_fixed(self)
+                }
+            }
+
+            #[inline]
+            const fn overflowing_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                let parsed = if Self::IS_SIGNED {
+                    from_str::i256::overflowing_from_str_radix(src, radix, Self::FRAC_NBITS)
+                } else {
+                    from_str::u256::overflowing_from_str_radix(src, radix, Self::FRAC_NBITS)
+                };
+                match parsed {
+                    Ok((bits, overflow)) => Ok(($Self::from_u256(bits), overflow)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            #[inline]
+            const fn from_ascii_radix(src: &[u8], radix: u32) -> Result<$Self<Frac>, ParseFixedError> {
+                let parsed = if Self::IS_SIGNED {
+                    from_str::i256::from_str_radix(src, radix, Self::FRAC_NBITS)
+                } else {
+                    from_str::u256::from_str_radix(src, radix, Self::FRAC_NBITS)
+                };
+                match parsed {
+                    Ok(bits) => Ok($Self::from_u256(bits)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            #[inline]
+            const fn saturating_from_ascii_radix(
+                src: &[u8],
+                radix: u32,
+            ) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_ascii_radix(src, radix) {
+                    Ok((ans, false)) => Ok(ans),
+                    Ok((_, true)) => Ok(Self::saturated(matches!(src.first(), Some(b'-')))),
+                    Err(e) => Err(e),
+                }
+            }
+
+            #[inline]
+            #[track_caller]
+            const fn unwrapped_from_ascii_radix(src: &[u8], radix: u32) -> $Self<Frac> {
+                match Self::from_ascii_radix(src, radix) {
+                    Ok(o) => o,
+                    Err(e) => panic!("{}", e.message()),
+                }
+            }
+
+            comment! {
+                "Parses a fixed-point literal.
+
+Rounding is to the nearest, with ties rounded to even.
+
+This is similar to [`from_str`][Self::from_str] but accepts a prefix for setting
+the radix, and ignores underscores, such that the parsing is more similar to
+numeric literals in Rust code. Strings starting with “`0b`” are parsed as
+binary, strings starting with “`0o`” are parsed as octal, and strings starting
+with “`0x`” are parsed as hexadecimal. The syntax is the same as for the
+narrower fixed-point numbers.
+
+The `lit` method can be evaluated in constant context. Normal calls are *not*
+evaluated at compile time; `lit` must be used in an inline constant expression
+or to initialize a constant for that.
+
+# Panics
+
+Panics if the number is not valid or overflows.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+
+assert_eq!(Fix::lit("1_.7_5_"), 1.75);
+assert_eq!(Fix::lit("17.5e-1"), 1.75);
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    r#"assert_eq!(Fix::lit("-01.75"), -1.75);
+"#,
+                },
+                r#"assert_eq!(Fix::lit("0b_111e-2"), 1.75);
+assert_eq!(Fix::lit("0o1.6"), 1.75);
+assert_eq!(Fix::lit("0x1.C"), 1.75);
+
+const ONE_AND_HALF: Fix = Fix::lit("1.5");
+assert_eq!(ONE_AND_HALF, 1.5);
+```
+"#;
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn lit(src: &str) -> $Self<Frac> {
+                    let parsed = if Self::IS_SIGNED {
+                        from_str::i256::lit(src.as_bytes(), Self::FRAC_NBITS)
+                    } else {
+                        from_str::u256::lit(src.as_bytes(), Self::FRAC_NBITS)
+                    };
+                    match parsed {
+                        Ok(bits) => $Self::from_u256(bits),
+                        Err(e) => panic!("{}", e.lit_message()),
+                    }
+                }
+            }
+
+            fixed256_from_str! {
+                $Self, $Signedness;
+                "decimal", 10,
+                {
+                    from_str,
+                    saturating_from_str,
+                    wrapping_from_str,
+                    unwrapped_from_str,
+                    overflowing_from_str,
+                },
+                {
+                    from_ascii,
+                    saturating_from_ascii,
+                    wrapping_from_ascii,
+                    unwrapped_from_ascii,
+                    overflowing_from_ascii,
+                },
+                "1.75", "1.75", "1e-1", "0.125", "99",
+            }
+            fixed256_from_str! {
+                $Self, $Signedness;
+                "binary", 2,
+                {
+                    from_str_binary,
+                    saturating_from_str_binary,
+                    wrapping_from_str_binary,
+                    unwrapped_from_str_binary,
+                    overflowing_from_str_binary,
+                },
+                {
+                    from_ascii_binary,
+                    saturating_from_ascii_binary,
+                    wrapping_from_ascii_binary,
+                    unwrapped_from_ascii_binary,
+                    overflowing_from_ascii_binary,
+                },
+                "1.11", "1.75", "1e-100", "0", "11",
+            }
+            fixed256_from_str! {
+                $Self, $Signedness;
+                "octal", 8,
+                {
+                    from_str_octal,
+                    saturating_from_str_octal,
+                    wrapping_from_str_octal,
+                    unwrapped_from_str_octal,
+                    overflowing_from_str_octal,
+                },
+                {
+                    from_ascii_octal,
+                    saturating_from_ascii_octal,
+                    wrapping_from_ascii_octal,
+                    unwrapped_from_ascii_octal,
+                    overflowing_from_ascii_octal,
+                },
+                "1.6", "1.75", "1e-1", "0.125", "77",
+            }
+            fixed256_from_str! {
+                $Self, $Signedness;
+                "hexadecimal", 16,
+                {
+                    from_str_hex,
+                    saturating_from_str_hex,
+                    wrapping_from_str_hex,
+                    unwrapped_from_str_hex,
+                    overflowing_from_str_hex,
+                },
+                {
+                    from_ascii_hex,
+                    saturating_from_ascii_hex,
+                    wrapping_from_ascii_hex,
+                    unwrapped_from_ascii_hex,
+                    overflowing_from_ascii_hex,
+                },
+                "1.C", "1.75", "1@-1", "0.0625", "FF",
+            }
+
+            comment! {
+                "Parses a string slice to return a fixed-point number, using
+the given [`ParseOptions`].
+
+The options are handled as for the narrower fixed-point numbers.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ParseOptions, RoundingMode};
+type Fix = ", stringify!($Self), r#"<U4>;
+let ceil = ParseOptions::new().rounding(RoundingMode::Ceil);
+assert_eq!(Fix::from_str_with("1.3", &ceil), Ok(Fix::from_num(1.3125)));
+let exact = ParseOptions::new().exact(true);
+assert!(Fix::from_str_with("1.3", &exact).is_err());
+```
+"#;
+                #[inline]
+                pub fn from_str_with(
+                    src: &str,
+                    options: &ParseOptions,
+                ) -> Result<$Self<Frac>, ParseFixedError> {
+                    match from_str::from_str_with(
+                        src,
+                        options,
+                        Self::FRAC_NBITS,
+                        256,
+                        Self::IS_SIGNED,
+                    ) {
+                        Ok(bits) => Ok($Self::from_u256(bits)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a hexadecimal floating-point
+number to return a fixed-point number.
+
+The syntax is the same as for the narrower fixed-point numbers, for example
+“`0x1.8p-3`”. Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", HexFloat};
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_str_hexfloat("0x1.8p-3"), Ok(Fix::from_num(0.1875)));
+let f = Fix::from_num(5.75);
+assert_eq!(Fix::from_str_hexfloat(&HexFloat(f).to_string()), Ok(f));
+```
+"#;
+                #[inline]
+                pub fn from_str_hexfloat(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_hexfloat(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Err(ParseFixedError::overflow(16)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a hexadecimal floating-point
+number to return a fixed-point number, saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+assert_eq!(Fix::saturating_from_str_hexfloat("0x1p10"), Ok(Fix::MAX));
+"#,
+                if_signed_unsigned!(
+                    $Signedness,
+                    r#"assert_eq!(Fix::saturating_from_str_hexfloat("-0x1p10"), Ok(Fix::MIN));
+"#,
+                    r#"assert_eq!(Fix::saturating_from_str_hexfloat("-0x1p0"), Ok(Fix::ZERO));
+"#,
+                ),
+                "```
+";
+                #[inline]
+                pub fn saturating_from_str_hexfloat(
+                    src: &str,
+                ) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_hexfloat(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Ok(Self::saturated(src.starts_with('-'))),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a hexadecimal floating-point
+number to return a fixed-point number, wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+// the value wraps modulo 2
+assert_eq!(Fix::wrapping_from_str_hexfloat("0x1.8p1"), Ok(Fix::from_num("#,
+                if_signed_unsigned!($Signedness, "-1", "1"),
+                r#")));
+```
+"#;
+                #[inline]
+                pub fn wrapping_from_str_hexfloat(
+                    src: &str,
+                ) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_hexfloat(src) {
+                        Ok((val, _)) => Ok(val),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a hexadecimal floating-point
+number to return a fixed-point number, panicking on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit or if there is a parsing error.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::unwrapped_from_str_hexfloat("0x1.cp0"), Fix::from_num(1.75));
+```
+
+The following panics because of a parsing error, as the prefix is missing.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let _error = Fix::unwrapped_from_str_hexfloat("1.cp0");
+```
+"#;
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub fn unwrapped_from_str_hexfloat(src: &str) -> $Self<Frac> {
+                    match Self::from_str_hexfloat(src) {
+                        Ok(o) => o,
+                        Err(e) => panic!("{}", e.message()),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a hexadecimal floating-point
+number to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+assert_eq!(Fix::overflowing_from_str_hexfloat("0x1p-1"), Ok((Fix::from_num(0.5), false)));
+assert_eq!(
+    Fix::overflowing_from_str_hexfloat("0x1.8p1"),
+    Ok((Fix::from_num("#,
+                if_signed_unsigned!($Signedness, "-1", "1"),
+                r#"), true))
+);
+```
+"#;
+                #[inline]
+                pub fn overflowing_from_str_hexfloat(
+                    src: &str,
+                ) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                    match from_str::overflowing_from_str_hexfloat(
+                        src,
+                        Self::FRAC_NBITS,
+                        256,
+                        Self::IS_SIGNED,
+                    ) {
+                        Ok((bits, overflow)) => Ok(($Self::from_u256(bits), overflow)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a ratio of two decimal
+integers, such as “`-3/7`”, to return a fixed-point number.
+
+The syntax is the same as for the narrower fixed-point numbers. The quotient
+is rounded to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_str_ratio("3/8"), Ok(Fix::from_num(0.375)));
+assert_eq!(Fix::from_str_ratio("1/3"), Ok(Fix::from_num(0.3125)));
+assert!(Fix::from_str_ratio("1/0").is_err());
+```
+"#;
+                #[inline]
+                pub fn from_str_ratio(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_ratio(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Err(ParseFixedError::overflow(10)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a ratio of two decimal
+integers, such as “`-3/7`”, to return a fixed-point number, saturating on
+overflow.
+
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+assert_eq!(Fix::saturating_from_str_ratio("3/1"), Ok(Fix::MAX));
+"#,
+                if_signed_unsigned!(
+                    $Signedness,
+                    r#"assert_eq!(Fix::saturating_from_str_ratio("-3/1"), Ok(Fix::MIN));
+"#,
+                    r#"assert_eq!(Fix::saturating_from_str_ratio("-1/3"), Ok(Fix::ZERO));
+"#,
+                ),
+                "```
+";
+                #[inline]
+                pub fn saturating_from_str_ratio(
+                    src: &str,
+                ) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_ratio(src) {
+                        Ok((val, false)) => Ok(val),
+                        Ok((_, true)) => Ok(Self::saturated(src.starts_with('-'))),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a ratio of two decimal
+integers, such as “`-3/7`”, to return a fixed-point number, wrapping on
+overflow.
+
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+// the value wraps modulo 2
+assert_eq!(Fix::wrapping_from_str_ratio("3/1"), Ok(Fix::from_num("#,
+                if_signed_unsigned!($Signedness, "-1", "1"),
+                r#")));
+```
+"#;
+                #[inline]
+                pub fn wrapping_from_str_ratio(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                    match Self::overflowing_from_str_ratio(src) {
+                        Ok((val, _)) => Ok(val),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Parses a string slice containing a ratio of two decimal
+integers, such as “`-3/7`”, to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+assert_eq!(Fix::overflowing_from_str_ratio("1/4"), Ok((Fix::from_num(0.25), false)));
+assert_eq!(
+    Fix::overflowing_from_str_ratio("3/1"),
+    Ok((Fix::from_num("#,
+                if_signed_unsigned!($Signedness, "-1", "1"),
+                r#"), true))
+);
+```
+"#;
+                #[inline]
+                pub fn overflowing_from_str_ratio(
+                    src: &str,
+                ) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                    match from_str::overflowing_from_str_ratio(
+                        src,
+                        Self::FRAC_NBITS,
+                        256,
+                        Self::IS_SIGNED,
+                    ) {
+                        Ok((bits, overflow)) => Ok(($Self::from_u256(bits), overflow)),
+                        Err(e) => Err(e),
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the ratio of two integers that is closest to the
+value, with a denominator no larger than `max_den`.
+
+The ratio is returned as a [tuple] `(num, den)` in lowest terms, with
+1&nbsp;≤&nbsp;`den`&nbsp;≤&nbsp;`max_den`. It is found using continued
+fractions. If two ratios are equally close, the one with the smaller
+denominator is returned, and if the denominators are equal, the one with the
+smaller numerator magnitude is returned.
+
+# Panics
+
+Panics if `max_den` is ",
+                if_signed_unsigned!($Signedness, "not positive", "zero"),
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let int = |i| ", stringify!($Bits), "::", stringify!($from_prim), "(i);
+// 1.3125 is exactly 21/16
+assert_eq!(Fix::from_num(1.3125).to_ratio(int(16)), (int(21), int(16)));
+// 4/3 = 1.333… is the closest ratio with a denominator up to 4
+assert_eq!(Fix::from_num(1.3125).to_ratio(int(4)), (int(4), int(3)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.3125).to_ratio(int(4)), (int(-4), int(3)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn to_ratio(self, max_den: $Bits) -> ($Bits, $Bits) {
+                    let (max_den_neg, max_den) = max_den.neg_abs();
+                    if max_den_neg || int256::is_zero_u256(max_den) {
+                        panic!("non-positive maximum denominator");
+                    }
+                    let (neg, abs) = self.neg_abs();
+                    // the magnitude of a negative numerator can be one more than MAX
+                    let max_num = $Bits::MAX.neg_abs().1;
+                    let max_num = if neg {
+                        int256::wrapping_add_u256_u128(max_num, 1)
+                    } else {
+                        max_num
+                    };
+                    let (num, den) = ratio::best_ratio(abs, Self::FRAC_NBITS, max_num, max_den);
+                    let num = if neg { int256::wrapping_neg_u256(num) } else { num };
+                    ($Bits(num), $Bits(den))
+                }
+            }
+
+            comment! {
+                "Returns the integer part.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Note that since the numbers are stored in two’s
+complement, negative numbers with non-zero fractional parts will be
+rounded towards &minus;∞, except in the case where there are no
+integer bits, that is `", stringify!($Self), "<U256>`, where the return value is always zero.
+
+"
+                },
+                "# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// 0010.0000
+let two = Fix::from_num(2);
+// 0010.0100
+let two_and_quarter = Fix::from_num(2.25);
+assert_eq!(two_and_quarter.int(), two);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// 1101.0000
+let three = Fix::from_num(3);
+// 1101.1100
+assert_eq!((-two_and_quarter).int(), -three);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn int(self) -> $Self<Frac> {
+                    $Self::from_u256(and(self.bits.0, not(low_mask(Self::FRAC_NBITS))))
+                }
+            }
+
+            comment! {
+                "Returns the fractional part.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Note that since the numbers are stored in two’s
+complement, the returned fraction will be non-negative for negative
+numbers, except in the case where there are no integer bits, that is
+`", stringify!($Self), "<U256>`, where the return value is always equal to
+`self`.
+
+"
+                },
+                "# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// 0000.0100
+let quarter = Fix::from_num(0.25);
+// 0010.0100
+let two_and_quarter = Fix::from_num(2.25);
+assert_eq!(two_and_quarter.frac(), quarter);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// 0000.1100
+let three_quarters = Fix::from_num(0.75);
+// 1101.1100
+assert_eq!((-two_and_quarter).frac(), three_quarters);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn frac(self) -> $Self<Frac> {
+                    $Self::from_u256(and(self.bits.0, low_mask(Self::FRAC_NBITS)))
+                }
+            }
+
+            comment! {
+                "Rounds to the next integer towards 0.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2.1).round_to_zero(), Fix::from_num(2));
+assert_eq!(Fix::from_num(2.9).round_to_zero(), Fix::from_num(2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.1).round_to_zero(), Fix::from_num(-2));
+assert_eq!(Fix::from_num(-2.9).round_to_zero(), Fix::from_num(-2));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn round_to_zero(self) -> $Self<Frac> {
+                    let (neg, abs) = self.neg_abs();
+                    let round = RoundAbs::Down;
+                    let (ans, _) = round_to_int(neg, abs, Self::FRAC_NBITS, round, Self::IS_SIGNED);
+                    $Self::from_u256(ans)
+                }
+            }
+
+            fixed256_round! {
+                $Self, $Signedness;
+                "Rounds to the next integer towards +∞",
+                {ceil, checked_ceil, saturating_ceil, wrapping_ceil, unwrapped_ceil, overflowing_ceil},
+                |neg| if neg { RoundAbs::Down } else { RoundAbs::Up },
+                "2.5", "3", "-2.5", "-2",
+            }
+            fixed256_round! {
+                $Self, $Signedness;
+                "Rounds to the next integer towards &minus;∞",
+                {floor, checked_floor, saturating_floor, wrapping_floor, unwrapped_floor, overflowing_floor},
+                |neg| if neg { RoundAbs::Up } else { RoundAbs::Down },
+                "2.5", "2", "-2.5", "-3",
+            }
+            fixed256_round! {
+                $Self, $Signedness;
+                "Rounds to the nearest integer, with ties rounded away from zero",
+                {round, checked_round, saturating_round, wrapping_round, unwrapped_round, overflowing_round},
+                |_neg| RoundAbs::HalfUp,
+                "2.5", "3", "-2.5", "-3",
+            }
+            fixed256_round! {
+                $Self, $Signedness;
+                "Rounds to the nearest integer, with ties rounded to even",
+                {
+                    round_ties_even,
+                    checked_round_ties_even,
+                    saturating_round_ties_even,
+                    wrapping_round_ties_even,
+                    unwrapped_round_ties_even,
+                    overflowing_round_ties_even,
+                },
+                |_neg| RoundAbs::HalfEven,
+                "2.5", "2", "-3.5", "-4",
+            }
+
+            comment! {
+                "Checked multiplication. Returns the product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::MAX.checked_mul(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_mul(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication. Returns the product, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
+assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => Self::saturated(Self::IS_SIGNED && is_neg(self.bits.0) != is_neg(rhs.bits.0)),
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping multiplication. Returns the product, wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).wrapping_mul(Fix::from_num(2)), Fix::from_num(6));
+let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4));
+assert_eq!(wrapped, ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Fix::from_num(-4) * Fix::DELTA",
+                    "Fix::MAX - Fix::from_num(3) * Fix::DELTA",
+                ),
+                ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_mul(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped multiplication. Returns the product, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).unwrapped_mul(Fix::from_num(2)), Fix::from_num(6));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let (three, two) = (Fix::from_num(3), Fix::from_num(2));
+assert_eq!(three.overflowing_mul(two), (Fix::from_num(6), false));
+let wrapped = ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Fix::from_num(-4) * Fix::DELTA",
+                    "Fix::MAX - Fix::from_num(3) * Fix::DELTA",
+                ),
+                ";
+assert_eq!(Fix::MAX.overflowing_mul(Fix::from_num(4)), (wrapped, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_mul(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) = if Self::IS_SIGNED {
+                        arith::i256::overflowing_mul(self.bits.0, rhs.bits.0, Self::FRAC_NBITS)
+                    } else {
+                        arith::u256::overflowing_mul(self.bits.0, rhs.bits.0, Self::FRAC_NBITS)
+                    };
+                    ($Self::from_u256(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked division. Returns the quotient, or [`None`] if
+the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::ONE.checked_div(Fix::ZERO), None);
+let half = Fix::from_num(0.5);
+assert_eq!(Fix::MAX.checked_div(half), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_div(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division. Returns the quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one_half = Fix::from_num(0.5);
+assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
+assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => Self::saturated(Self::IS_SIGNED && is_neg(self.bits.0) != is_neg(rhs.bits.0)),
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping division. Returns the quotient, wrapping on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one_point_5 = Fix::from_num(1.5);
+assert_eq!(Fix::from_num(3).wrapping_div(Fix::from_num(2)), one_point_5);
+let quarter = Fix::from_num(0.25);
+let wrapped = Fix::MAX.wrapping_div(quarter);
+assert_eq!(wrapped, ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Fix::from_num(-4) * Fix::DELTA",
+                    "Fix::MAX - Fix::from_num(3) * Fix::DELTA",
+                ),
+                ");
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_div(rhs).0
+                }
+            }
+
+            comment! {
+                "Unwrapped division. Returns the quotient, panicking on overflow.
+
+# Panics
+
+Panics if the divisor is zero or if the division results in overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).unwrapped_div(Fix::from_num(2)), 1.5);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let quarter = Fix::from_num(0.25);
+let _overflow = Fix::MAX.unwrapped_div(quarter);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing division.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one_point_5 = Fix::from_num(1.5);
+assert_eq!(Fix::from_num(3).overflowing_div(Fix::from_num(2)), (one_point_5, false));
+let quarter = Fix::from_num(0.25);
+let wrapped = ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Fix::from_num(-4) * Fix::DELTA",
+                    "Fix::MAX - Fix::from_num(3) * Fix::DELTA",
+                ),
+                ";
+assert_eq!(Fix::MAX.overflowing_div(quarter), (wrapped, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_div(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) = if Self::IS_SIGNED {
+                        arith::i256::overflowing_div(self.bits.0, rhs.bits.0, Self::FRAC_NBITS)
+                    } else {
+                        arith::u256::overflowing_div(self.bits.0, rhs.bits.0, Self::FRAC_NBITS)
+                    };
+                    ($Self::from_u256(ans), overflow)
+                }
+            }
+
+            fixed256_frac! {
+                {Self, ISelf, USelf} = {$Self, $ISelf, $USelf},
+                Signedness = $Signedness,
+                {Bits, from_prim} = {$Bits, $from_prim},
+            }
+
+            fixed256_math! {
+                {Self, ISelf, USelf} = {$Self, $ISelf, $USelf},
+                Signedness = $Signedness,
+                {Bits, from_prim} = {$Bits, $from_prim},
+            }
+
+            if_signed! {
+                $Signedness;
+                fixed256_trig! {
+                    Self = $Self,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! fixed256_from_str {
+    (
+        $Self:ident, $Signedness:ident;
+        $desc:literal, $radix:literal,
+        {$from:ident, $saturating:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident $(,)?},
+        {
+            $from_a:ident,
+            $saturating_a:ident,
+            $wrapping_a:ident,
+            $unwrapped_a:ident,
+            $overflowing_a:ident $(,)?
+        },
+        $str:literal, $val:literal, $tiny_str:literal, $tiny_val:literal, $big:literal $(,)?
+    ) => {
+        fixed256_from_str! {
+            @fns $Self, $Signedness;
+            "a string slice", "", src: &str => src.as_bytes();
+            $desc, $radix,
+            {$from, $saturating, $wrapping, $unwrapped, $overflowing},
+            $str, $val, $tiny_str, $tiny_val, $big,
+        }
+        fixed256_from_str! {
+            @fns $Self, $Signedness;
+            "an ASCII-byte slice", "b", src: &[u8] => src;
+            $desc, $radix,
+            {$from_a, $saturating_a, $wrapping_a, $unwrapped_a, $overflowing_a},
+            $str, $val, $tiny_str, $tiny_val, $big,
+        }
+    };
+    (
+        @fns $Self:ident, $Signedness:ident;
+        $kind:literal, $q:literal, $src:ident: $Src:ty => $bytes:expr;
+        $desc:literal, $radix:literal,
+        {$from:ident, $saturating:ident, $wrapping:ident, $unwrapped:ident, $overflowing:ident $(,)?},
+        $str:literal, $val:literal, $tiny_str:literal, $tiny_val:literal, $big:literal $(,)?
+    ) => {
+        comment! {
+            "Parses ", $kind, " containing ", $desc, " digits to return a
+fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even. The syntax, including
+exponents, is the same as for the narrower fixed-point numbers.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::"#, stringify!($from), "(", $q, "\"", $str, r#""), Ok(Fix::from_num("#, $val, r#")));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::"#, stringify!($from), "(", $q, "\"-", $str, r#""), Ok(Fix::from_num(-"#, $val, r#")));
+"#,
+            },
+            r#"assert_eq!(Fix::"#, stringify!($from), "(", $q, "\"", $tiny_str, r#""), Ok(Fix::from_num("#, $tiny_val, r#")));
+```
+"#;
+            #[inline]
+            #[allow(clippy::should_implement_trait)]
+            pub const fn $from($src: $Src) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::from_ascii_radix($bytes, $radix)
+            }
+        }
+
+        comment! {
+            "Parses ", $kind, " containing ", $desc, " digits to return a
+fixed-point number, saturating on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+assert_eq!(Fix::"#, stringify!($saturating), "(", $q, "\"", $big, r#""), Ok(Fix::MAX));
+"#,
+            if_signed_unsigned!(
+                $Signedness,
+                concat!(
+                    r#"assert_eq!(Fix::"#, stringify!($saturating), "(", $q, "\"-", $big, r#""), Ok(Fix::MIN));
+"#,
+                ),
+                concat!(
+                    r#"assert_eq!(Fix::"#, stringify!($saturating), "(", $q, r#""-1"), Ok(Fix::ZERO));
+"#,
+                ),
+            ),
+            "```
+";
+            #[inline]
+            pub const fn $saturating($src: $Src) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::saturating_from_ascii_radix($bytes, $radix)
+            }
+        }
+
+        comment! {
+            "Parses ", $kind, " containing ", $desc, " digits to return a
+fixed-point number, wrapping on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U255;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U255>;
+// the value wraps modulo 2
+assert_eq!(Fix::"#, stringify!($wrapping), "(", $q, "\"", $big, r#""), Ok(Fix::from_num("#,
+            if_signed_unsigned!($Signedness, "-1", "1"),
+            r#")));
+```
+"#;
+            #[inline]
+            pub const fn $wrapping($src: $Src) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_ascii_radix($bytes, $radix) {
+                    Ok((ans, _)) => Ok(ans),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+
+        comment! {
+            "Parses ", $kind, " containing ", $desc, " digits to return a
+fixed-point number, panicking on overflow.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit or if there is a parsing error.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::"#, stringify!($unwrapped), "(", $q, "\"", $str, r#""), Fix::from_num("#, $val, r#"));
+```
+
+The following panics because of a parsing error.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let _error = Fix::"#, stringify!($unwrapped), "(", $q, r#""1.1.");
+```
+"#;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn $unwrapped($src: $Src) -> $Self<Frac> {
+                Self::unwrapped_from_ascii_radix($bytes, $radix)
+            }
+        }
+
+        comment! {
+            "Parses ", $kind, " containing ", $desc, " digits to return a
+fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+Rounding is to the nearest, with ties rounded to even.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let check = Fix::from_num("#, $val, r#");
+assert_eq!(Fix::"#, stringify!($overflowing), "(", $q, "\"", $str, r#""), Ok((check, false)));
+```
+"#;
+            #[inline]
+            pub const fn $overflowing($src: $Src) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                Self::overflowing_from_ascii_radix($bytes, $radix)
+            }
+        }
+    };
+}
+
+macro_rules! fixed256_round {
+    (
+        $Self:ident, $Signedness:ident;
+        $desc:literal,
+        {
+            $plain:ident,
+            $checked:ident,
+            $saturating:ident,
+            $wrapping:ident,
+            $unwrapped:ident,
+            $overflowing:ident $(,)?
+        },
+        |$neg:ident| $round:expr,
+        $pos:literal, $pos_ans:literal, $neg_val:literal, $neg_ans:literal $(,)?
+    ) => {
+        comment! {
+            $desc, ".
+
+# Panics
+
+When debug assertions are enabled, panics if the result does not fit.
+When debug assertions are not enabled, the wrapped result can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`", stringify!($wrapping), "`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($plain), "(), Fix::from_num(", $pos_ans, "));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(", $neg_val, ").", stringify!($plain),
+                "(), Fix::from_num(", $neg_ans, "));
+",
+            },
+            "```
+
+[`", stringify!($wrapping), "`]: Self::", stringify!($wrapping), "
+";
+            #[inline]
+            #[must_use]
+            pub const fn $plain(self) -> $Self<Frac> {
+                let (ans, overflow) = self.$overflowing();
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        comment! {
+            "Checked rounding. ", $desc, ", returning [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($checked), "(), Some(Fix::from_num(", $pos_ans, ")));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn $checked(self) -> Option<$Self<Frac>> {
+                match self.$overflowing() {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Saturating rounding. ", $desc, ", saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($saturating), "(), Fix::from_num(", $pos_ans, "));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn $saturating(self) -> $Self<Frac> {
+                match self.$overflowing() {
+                    (ans, false) => ans,
+                    (_, true) => Self::saturated(self.neg_abs().0),
+                }
+            }
+        }
+
+        comment! {
+            "Wrapping rounding. ", $desc, ", wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($wrapping), "(), Fix::from_num(", $pos_ans, "));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn $wrapping(self) -> $Self<Frac> {
+                self.$overflowing().0
+            }
+        }
+
+        comment! {
+            "Unwrapped rounding. ", $desc, ", panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($unwrapped), "(), Fix::from_num(", $pos_ans, "));
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn $unwrapped(self) -> $Self<Frac> {
+                match self.$overflowing() {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Overflowing rounding. ", $desc, ".
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let check = Fix::from_num(", $pos_ans, ");
+assert_eq!(Fix::from_num(", $pos, ").", stringify!($overflowing), "(), (check, false));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn $overflowing(self) -> ($Self<Frac>, bool) {
+                let (neg, abs) = self.neg_abs();
+                let $neg = neg;
+                let round = $round;
+                let (ans, overflow) =
+                    round_to_int(neg, abs, Self::FRAC_NBITS, round, Self::IS_SIGNED);
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+    };
+}
+
+fixed256! {
+    description = "A 256",
+    {Self, ISelf, USelf} = {FixedU256, FixedI256, FixedU256},
+    Signedness = Unsigned,
+    {Bits, from_prim} = {U256Bits, from_u128},
+}
+fixed256! {
+    description = "A 256",
+    {Self, ISelf, USelf} = {FixedI256, FixedI256, FixedU256},
+    Signedness = Signed,
+    {Bits, from_prim} = {I256Bits, from_i128},
+}
+
+macro_rules! fixed_equiv {
+    ($Fixed:ident($Bits:ident)) => {
+        impl FixedEquiv for $Bits {
+            type Equiv = $Fixed<U0>;
+
+            #[inline]
+            fn to_fixed_equiv(self) -> $Fixed<U0> {
+                $Fixed::from_bits(self)
+            }
+
+            #[inline]
+            fn as_fixed_equiv(&self) -> &$Fixed<U0> {
+                $Fixed::wrap_ref(self)
+            }
+
+            #[inline]
+            fn as_fixed_equiv_mut(&mut self) -> &mut $Fixed<U0> {
+                $Fixed::wrap_mut(self)
+            }
+
+            #[inline]
+            fn from_fixed_equiv(f: $Fixed<U0>) -> $Bits {
+                f.to_bits()
+            }
+
+            #[inline]
+            fn ref_from_fixed_equiv(f: &$Fixed<U0>) -> &$Bits {
+                &f.bits
+            }
+
+            #[inline]
+            fn mut_from_fixed_equiv(f: &mut $Fixed<U0>) -> &mut $Bits {
+                &mut f.bits
+            }
+        }
+    };
+}
+
+fixed_equiv! { FixedI256(I256Bits) }
+fixed_equiv! { FixedU256(U256Bits) }
+
+macro_rules! wide_int {
+    ($Int:ident) => {
+        impl FromWide for $Int {
+            #[inline]
+            fn overflowing_from_finite(_: Private, src: Finite) -> ($Int, bool) {
+                let (bits, overflow) = convert(src, 0, <$Int>::BITS, <$Int>::MIN != 0);
+                (bits.lo as $Int, overflow)
+            }
+
+            #[inline]
+            fn saturated(_: Private, neg: bool) -> $Int {
+                if neg { <$Int>::MIN } else { <$Int>::MAX }
+            }
+        }
+    };
+}
+
+wide_int! { i8 }
+wide_int! { i16 }
+wide_int! { i32 }
+wide_int! { i64 }
+wide_int! { i128 }
+wide_int! { isize }
+wide_int! { u8 }
+wide_int! { u16 }
+wide_int! { u32 }
+wide_int! { u64 }
+wide_int! { u128 }
+wide_int! { usize }
+
+macro_rules! wide_float {
+    ($(#[$attr:meta])* $Float:ident($Bits:ty, $nbits:literal, $prec:literal)) => {
+        $(#[$attr])*
+        impl FromWide for $Float {
+            #[inline]
+            fn overflowing_from_finite(_: Private, src: Finite) -> ($Float, bool) {
+                let src = Finite {
+                    round: RoundingMode::NearestEven,
+                    ..src
+                };
+                let bits = to_float_bits(src, $nbits, $prec);
+                (<$Float>::from_bits(bits as $Bits), false)
+            }
+
+            #[inline]
+            fn overflowing_from_finite_round(_: Private, src: Finite) -> ($Float, bool) {
+                let bits = to_float_bits(src, $nbits, $prec);
+                (<$Float>::from_bits(bits as $Bits), false)
+            }
+
+            #[inline]
+            fn saturated(_: Private, neg: bool) -> $Float {
+                if neg {
+                    <$Float>::NEG_INFINITY
+                } else {
+                    <$Float>::INFINITY
+                }
+            }
+        }
+    };
+}
+
+wide_float! { #[cfg(feature = "nightly-float")] f16(u16, 16, 11) }
+wide_float! { half_f16(u16, 16, 11) }
+wide_float! { half_bf16(u16, 16, 8) }
+wide_float! { f32(u32, 32, 24) }
+wide_float! { f64(u64, 64, 53) }
+wide_float! { #[cfg(feature = "nightly-float")] f128(u128, 128, 113) }
+wide_float! { F128(u128, 128, 113) }
+
+#[allow(deprecated)]
+impl FromWide for F128Bits {
+    #[inline]
+    fn overflowing_from_finite(_: Private, src: Finite) -> (F128Bits, bool) {
+        let (f, overflow) = F128::overflowing_from_finite(Private, src);
+        (F128Bits(f.to_bits()), overflow)
+    }
+
+    #[inline]
+    fn saturated(_: Private, neg: bool) -> F128Bits {
+        F128Bits(F128::saturated(Private, neg).to_bits())
+    }
+}
+
+macro_rules! wide_fixed {
+    ($Fixed:ident($LeEqU:ident, $Inner:ident)) => {
+        impl<Frac: $LeEqU> FromWide for $Fixed<Frac> {
+            #[inline]
+            fn overflowing_from_finite(_: Private, src: Finite) -> ($Fixed<Frac>, bool) {
+                let (bits, overflow) =
+                    convert(src, Self::FRAC_NBITS, <$Inner>::BITS, Self::IS_SIGNED);
+                (Self::from_bits(bits.lo as $Inner), overflow)
+            }
+
+            #[inline]
+            fn saturated(_: Private, neg: bool) -> $Fixed<Frac> {
+                if neg { Self::MIN } else { Self::MAX }
+            }
+        }
+    };
+}
+
+wide_fixed! { FixedI8(LeEqU8, i8) }
+wide_fixed! { FixedI16(LeEqU16, i16) }
+wide_fixed! { FixedI32(LeEqU32, i32) }
+wide_fixed! { FixedI64(LeEqU64, i64) }
+wide_fixed! { FixedI128(LeEqU128, i128) }
+wide_fixed! { FixedU8(LeEqU8, u8) }
+wide_fixed! { FixedU16(LeEqU16, u16) }
+wide_fixed! { FixedU32(LeEqU32, u32) }
+wide_fixed! { FixedU64(LeEqU64, u64) }
+wide_fixed! { FixedU128(LeEqU128, u128) }
+
+macro_rules! wide_fixed256 {
+    ($Fixed:ident) => {
+        impl<Frac: LeEqU256> FromWide for $Fixed<Frac> {
+            #[inline]
+            fn overflowing_from_finite(_: Private, src: Finite) -> ($Fixed<Frac>, bool) {
+                $Fixed::overflowing_from_finite(src)
+            }
+
+            #[inline]
+            fn saturated(_: Private, neg: bool) -> $Fixed<Frac> {
+                $Fixed::saturated(neg)
+            }
+        }
+    };
+}
+
+wide_fixed256! { FixedI256 }
+wide_fixed256! { FixedU256 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::extra::{U0, U32, U64, U125, U128, U192, U253, U255, U256};
+    use crate::*;
+    use std::format;
+
+    // xorshift, enough to get varied bit patterns
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn next_i128(state: &mut u64) -> i128 {
+        let hi = next(state);
+        let lo = next(state);
+        let bits = (i128::from(hi as i64) << 64) | i128::from(lo);
+        // vary the magnitude so that products do not always overflow
+        bits >> (next(state) % 128)
+    }
+
+    #[test]
+    fn arith_matches_128() {
+        type F128F = FixedI128<U64>;
+        type F256F = FixedI256<U64>;
+        let mut state = 0x1234_5678_9abc_def0;
+        for _ in 0..2000 {
+            let a = F128F::from_bits(next_i128(&mut state));
+            let b = F128F::from_bits(next_i128(&mut state));
+            let (wa, wb) = (F256F::from_num(a), F256F::from_num(b));
+            assert_eq!(wa, a);
+            assert_eq!(wa.to_num::<F128F>(), a);
+
+            if let Some(sum) = a.checked_add(b) {
+                assert_eq!(wa + wb, sum);
+            }
+            if let Some(diff) = a.checked_sub(b) {
+                assert_eq!(wa - wb, diff);
+            }
+            match a.checked_mul(b) {
+                Some(prod) => assert_eq!(wa * wb, prod),
+                None => assert_eq!((wa * wb).checked_to_num::<F128F>(), None),
+            }
+            if let Some(quot) = a.checked_div(b) {
+                assert_eq!(wa / wb, quot);
+            }
+            if let Some(rem) = a.checked_rem(b) {
+                assert_eq!(wa % wb, rem);
+            }
+            assert_eq!(wa.floor(), a.floor());
+            assert_eq!(wa.ceil(), a.ceil());
+            assert_eq!(wa.round(), a.round());
+            assert_eq!(wa.round_ties_even(), a.round_ties_even());
+            assert_eq!(wa.round_to_zero(), a.round_to_zero());
+            assert_eq!(wa.int(), a.int());
+            assert_eq!(wa.frac(), a.frac());
+            assert_eq!(wa.cmp(&wb), a.cmp(&b));
+            assert_eq!(wa.to_num::<f64>(), a.to_num::<f64>());
+            assert_eq!(wa.to_num::<f32>(), a.to_num::<f32>());
+            assert_eq!(wa.saturating_to_num::<i64>(), a.saturating_to_num::<i64>());
+            assert_eq!(wa >> 3u8, a >> 3u8);
+            assert_eq!(wa & wb, a & b);
+        }
+    }
+
+    #[test]
+    fn no_frac_methods_match_128() {
+        type F128F = FixedI128<U64>;
+        type F256F = FixedI256<U64>;
+        type U256F = FixedU256<U64>;
+        let mut state = 0x0fed_cba9_8765_4321;
+        for _ in 0..1000 {
+            let a = F128F::from_bits(next_i128(&mut state));
+            let b = F128F::from_bits(next_i128(&mut state));
+            let i = next_i128(&mut state) >> 64;
+            let (wa, wb) = (F256F::from_num(a), F256F::from_num(b));
+            let wi = I256Bits::from_i128(i);
+
+            if let Some(prod) = a.checked_mul_int(i) {
+                assert_eq!(wa.checked_mul_int(wi), Some(F256F::from_num(prod)));
+            }
+            if i != 0 {
+                assert_eq!(
+                    wa.checked_div_int(wi),
+                    a.checked_div_int(i).map(F256F::from_num)
+                );
+            }
+            assert_eq!(wa.dist(wb), a.unsigned_dist(b));
+            assert_eq!(wa.unsigned_dist(wb), a.unsigned_dist(b));
+            assert_eq!(wa.mean(wb), a.mean(b));
+            if let Some(m) = a.checked_next_multiple_of(b) {
+                assert_eq!(wa.next_multiple_of(wb), m);
+            }
+            let sh = (i as u32) % 64;
+            assert_eq!(wa.checked_shl(sh).map(|x| x >> sh), Some(wa));
+            assert_eq!(wa.wrapping_shr(sh), a.wrapping_shr(sh));
+            assert_eq!(
+                wa.unbounded_shr(256 + sh),
+                if a < 0 { -F256F::DELTA } else { F256F::ZERO }
+            );
+            assert_eq!(wa.signed_bits(), a.signed_bits());
+            assert_eq!(
+                wa.leading_ones(),
+                a.leading_ones() + if a < 0 { 128 } else { 0 }
+            );
+            let all_ones = a.count_zeros() == 0;
+            assert_eq!(
+                wa.trailing_ones(),
+                a.trailing_ones() + if all_ones { 128 } else { 0 }
+            );
+            assert_eq!(wa.rotate_left(sh).rotate_right(sh), wa);
+            assert_eq!(wa.reverse_bits().reverse_bits(), wa);
+            assert_eq!(wa.swap_bytes().swap_bytes(), wa);
+            assert_eq!(wa.const_bitxor(wb).const_bitxor(wb), wa);
+
+            let (ua, ub) = (a.cast_unsigned(), b.cast_unsigned());
+            let (wua, wub) = (U256F::from_num(ua), U256F::from_num(ub));
+            assert_eq!(wua.dist(wub), ua.dist(ub));
+            assert_eq!(wua.abs_diff(wub), ua.abs_diff(ub));
+            assert_eq!(wua.mean(wub), ua.mean(ub));
+            assert_eq!(wua.significant_bits(), ua.significant_bits());
+            assert_eq!(wua.highest_one(), ua.highest_one());
+            if let Some(sum) = ua.checked_add_signed(b) {
+                assert_eq!(wua.add_signed(wb), sum);
+            }
+            if let Some(diff) = ua.checked_sub_signed(b) {
+                assert_eq!(wua.sub_signed(wb), diff);
+            }
+            if let Some(p) = ua.checked_next_power_of_two() {
+                assert_eq!(wua.next_power_of_two(), p);
+            }
+            if let Some(sum) = a.checked_add_unsigned(ub) {
+                assert_eq!(wa.add_unsigned(wub), sum);
+            }
+            if let Some(diff) = a.checked_sub_unsigned(ub) {
+                assert_eq!(wa.sub_unsigned(wub), diff);
+            }
+            assert_eq!(wa.cast_unsigned().cast_signed(), wa);
+        }
+        assert_eq!(F256F::MIN.checked_div_int(I256Bits::from_i128(-1)), None);
+        assert_eq!(
+            F256F::MIN.overflowing_dist(F256F::MAX),
+            (-F256F::DELTA, true)
+        );
+        assert_eq!(F256F::MIN.unsigned_dist(F256F::MAX), U256F::MAX);
+        assert_eq!(U256F::MAX.wrapping_next_power_of_two(), 0);
+    }
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestEven,
+        RoundingMode::NearestAway,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::TowardZero,
+    ];
+
+    #[test]
+    fn frac_methods_match_128() {
+        type F128F = FixedI128<U64>;
+        type F256F = FixedI256<U64>;
+        type U128F = FixedU128<U64>;
+        type U256F = FixedU256<U64>;
+        let mut state = 0x5a5a_1234_a5a5_4321;
+        for _ in 0..1000 {
+            let a = F128F::from_bits(next_i128(&mut state));
+            let b = F128F::from_bits(next_i128(&mut state));
+            let c = F128F::from_bits(next_i128(&mut state));
+            let i = next_i128(&mut state) >> 64;
+            let (wa, wb, wc) = (F256F::from_num(a), F256F::from_num(b), F256F::from_num(c));
+            let wi = I256Bits::from_i128(i);
+            let mode = MODES[(i as usize) % MODES.len()];
+
+            if let Some(q) = a.checked_div_euclid(b) {
+                assert_eq!(wa.checked_div_euclid(wb), Some(F256F::from_num(q)));
+            }
+            if let Some(r) = a.checked_rem_euclid(b) {
+                assert_eq!(wa.checked_rem_euclid(wb), Some(F256F::from_num(r)));
+            }
+            if let Some(q) = a.checked_div_euclid_int(i) {
+                assert_eq!(wa.checked_div_euclid_int(wi), Some(F256F::from_num(q)));
+            }
+            if let Some(r) = a.checked_rem_euclid_int(i) {
+                assert_eq!(wa.checked_rem_euclid_int(wi), Some(F256F::from_num(r)));
+            }
+            if let Some(r) = a.checked_rem_int(i) {
+                assert_eq!(wa.checked_rem_int(wi), Some(F256F::from_num(r)));
+            }
+            if let Some(p) = a.checked_mul_round(b, mode) {
+                assert_eq!(wa.mul_round(wb, mode), p);
+            }
+            if let Some(q) = a.checked_div_round(b, mode) {
+                assert_eq!(wa.div_round(wb, mode), q);
+            }
+            if let Some(r) = a.checked_recip() {
+                assert_eq!(wa.checked_recip(), Some(F256F::from_num(r)));
+            }
+            assert_eq!(wa.checked_int_log2(), a.checked_int_log2());
+            assert_eq!(wa.checked_int_log10(), a.checked_int_log10());
+            assert_eq!(wa.checked_int_log(7), a.checked_int_log(7));
+            assert_eq!(wa.signum(), a.signum());
+            if let Some(m) = a.checked_mul_add(b, c) {
+                assert_eq!(wa.mul_add(wb, wc), m);
+            }
+            if let Some(m) = c.checked_add_prod(a, b) {
+                assert_eq!(wc.add_prod(wa, wb), m);
+            }
+            if let Some(l) = a.checked_lerp(b, c) {
+                assert_eq!(wa.lerp(wb, wc), l);
+            }
+            if let Some(l) = a.checked_inv_lerp::<U64>(b, c) {
+                assert_eq!(wa.checked_inv_lerp::<U64>(wb, wc), Some(F256F::from_num(l)));
+            }
+            let num = next_i128(&mut state);
+            if let Some(r) = F128F::checked_from_ratio(num, i) {
+                let wr = F256F::checked_from_ratio(I256Bits::from_i128(num), wi);
+                assert_eq!(wr, Some(F256F::from_num(r)));
+            }
+            assert_eq!(wa.requantize::<U32>(mode), a.requantize::<U32>(mode));
+            assert_eq!(
+                F256F::from_num_round(a.to_num::<f64>(), mode),
+                a.to_num::<f64>()
+            );
+
+            let (ua, ub) = (a.cast_unsigned(), b.cast_unsigned());
+            let (wua, wub) = (U256F::from_num(ua), U256F::from_num(ub));
+            if let Some(q) = ua.checked_div_euclid(ub) {
+                assert_eq!(wua.checked_div_euclid(wub), Some(U256F::from_num(q)));
+            }
+            if let Some(p) = ua.checked_mul_round(ub, mode) {
+                assert_eq!(wua.mul_round(wub, mode), p);
+            }
+            if let Some(r) = ua.checked_recip() {
+                assert_eq!(wua.checked_recip(), Some(U256F::from_num(r)));
+            }
+            assert_eq!(wua.checked_int_log2(), ua.checked_int_log2());
+            assert_eq!(wua.checked_int_log10(), ua.checked_int_log10());
+            if let Some(m) = ua.checked_mul_add(ub, U128F::DELTA) {
+                assert_eq!(wua.mul_add(wub, U256F::DELTA), m);
+            }
+            if let Some(l) = ua.checked_lerp(ub, U128F::MAX) {
+                assert_eq!(wua.lerp(wub, U256F::from_num(U128F::MAX)), l);
+            }
+        }
+        assert_eq!(F256F::MIN.checked_div_euclid(-F256F::ONE), None);
+        assert_eq!(F256F::ZERO.checked_recip(), None);
+        assert_eq!(
+            F256F::ONE.checked_inv_lerp::<U64>(F256F::ONE, F256F::ONE),
+            None
+        );
+
+        let mut acc = F256F::MAX;
+        assert_eq!(acc.checked_mul_acc(F256F::MAX, F256F::ONE), None);
+        assert_eq!(acc, F256F::MAX);
+        acc = -F256F::MAX;
+        acc.mul_acc(F256F::MAX, F256F::from_num(1.5));
+        assert_eq!(acc, F256F::MAX / F256F::from_num(2));
+
+        let mut zero = || 0u64;
+        let src = FixedI128::<U128>::from_bits(3 << 62);
+        assert_eq!(F256F::from_num_stochastic(src, &mut zero), F256F::DELTA);
+        assert_eq!(F256F::from_num_dither(F256F::ONE, &mut zero), F256F::ONE);
+    }
+
+    #[test]
+    fn math_methods_match_128() {
+        type F128F = FixedI128<U64>;
+        type F256F = FixedI256<U64>;
+        type U128F = FixedU128<U64>;
+        type U256F = FixedU256<U64>;
+        let close = |w: F256F, n: F128F| {
+            let d = w.dist(F256F::from_num(n));
+            assert!(d <= F256F::from_num(F128F::DELTA * 2), "{w} {n}");
+        };
+        let trig = |w: F256F, n: F128F| {
+            let d = w.dist(F256F::from_num(n));
+            assert!(d <= F256F::from_num(F128F::DELTA * 4), "{w} {n}");
+        };
+        let mut state = 0x1234_5678_9abc_def0;
+        for _ in 0..200 {
+            let a = F128F::from_bits(next_i128(&mut state));
+            let b = F128F::from_bits(next_i128(&mut state) >> 60);
+            let n = (next(&mut state) % 7) as i32 - 3;
+            let (wa, wb) = (F256F::from_num(a), F256F::from_num(b));
+
+            if let Some(s) = a.checked_sqrt() {
+                assert_eq!(wa.checked_sqrt(), Some(F256F::from_num(s)));
+            }
+            if let Some(e) = b.checked_exp() {
+                close(wb.exp(), e);
+            }
+            if let Some(e) = b.checked_exp2() {
+                close(wb.exp2(), e);
+            }
+            if let Some(e) = b.checked_exp_m1() {
+                close(wb.exp_m1(), e);
+            }
+            if let Some(l) = a.checked_ln() {
+                close(wa.ln(), l);
+                close(wa.log2(), a.log2());
+                close(wa.log10(), a.log10());
+            }
+            if let Some(p) = a.checked_powi(n) {
+                close(wa.powi(n), p);
+            }
+            if let Some(p) = a.checked_powf(b) {
+                close(wa.powf(wb), p);
+            }
+            if let Some(r) = a.checked_cbrt() {
+                close(wa.cbrt(), r);
+            }
+            if let Some(r) = a.checked_rsqrt() {
+                close(wa.rsqrt(), r);
+            }
+            assert_eq!(wa.checked_hypot(wb).is_some(), a.checked_hypot(b).is_some());
+            if let Some(h) = a.checked_hypot(b) {
+                assert_eq!(wa.hypot(wb), h);
+            }
+
+            // the narrow trigonometric functions have an error < 2 DELTA
+            trig(wa.sin(), a.sin());
+            trig(wa.cos(), a.cos());
+            trig(wb.sinh(), b.sinh());
+            trig(wb.cosh(), b.cosh());
+            trig(wb.tanh(), b.tanh());
+            let c = F128F::from_bits(next_i128(&mut state) >> 64);
+            let wc = F256F::from_num(c);
+            trig(wc.asin(), c.asin());
+            trig(wc.acos(), c.acos());
+            let atan: FixedI128<U125> = wb.atan().to_num();
+            assert!(atan.dist(b.atan()) <= FixedI128::<U125>::DELTA * 4);
+            let atan2: FixedI128<U125> = wa.atan2(wb).to_num();
+            assert!(atan2.dist(a.atan2(b)) <= FixedI128::<U125>::DELTA * 4);
+
+            let ua = a.unsigned_abs();
+            let wua = U256F::from_num(ua);
+            assert_eq!(wua.sqrt(), U256F::from_num(ua.sqrt()));
+            if let Some(r) = ua.checked_nth_root(5) {
+                let d = wua.nth_root(5).dist(U256F::from_num(r));
+                assert!(d <= U256F::from_num(U128F::DELTA * 2));
+            }
+        }
+
+        // check the full precision against values computed with mpmath
+        type Wide = FixedI256<U192>;
+        let e = "2.718281828459045235360287471352662497757247093699959574966967627724";
+        let ln_2 = "0.693147180559945309417232121458176568075500134360255254120680009493";
+        let dist_e = Wide::ONE.exp().dist(Wide::from_str(e).unwrap());
+        assert!(dist_e <= Wide::DELTA);
+        let dist_ln_2 = Wide::from_num(2).ln().dist(Wide::from_str(ln_2).unwrap());
+        assert!(dist_ln_2 <= Wide::DELTA);
+        let root_2 = "1.414213562373095048801688724209698078569671875376948073176679737990";
+        let dist_root_2 = Wide::from_num(2)
+            .sqrt()
+            .dist(Wide::from_str(root_2).unwrap());
+        assert!(dist_root_2 <= Wide::DELTA);
+        let sin_1 = "0.841470984807896506652502321630298999622563060798371065672751709991";
+        let cos_1 = "0.540302305868139717400936607442976603732310420617922227670097255381";
+        let (sin, cos) = Wide::ONE.sin_cos();
+        assert!(sin.dist(Wide::from_str(sin_1).unwrap()) <= Wide::DELTA);
+        assert!(cos.dist(Wide::from_str(cos_1).unwrap()) <= Wide::DELTA);
+        type Angle = FixedI256<U253>;
+        let pi =
+            "3.14159265358979323846264338327950288419716939937510582097494459230781640628620899863";
+        let dist_pi = Wide::ZERO
+            .atan2(Wide::NEG_ONE)
+            .dist(Angle::from_str(pi).unwrap());
+        assert!(dist_pi <= Angle::DELTA);
+        assert_eq!(Wide::from_num(1024).log2(), 10);
+        assert_eq!(Wide::from_num(3).powi(30), Wide::from_num(3u64.pow(30)));
+        assert!(FixedI256::<U0>::from_num(3).overflowing_powi(161).1);
+
+        assert_eq!(F256F::ZERO.checked_ln(), None);
+        assert_eq!(F256F::from_num(-1).checked_sqrt(), None);
+        assert_eq!(F256F::ZERO.checked_rsqrt(), None);
+        assert_eq!(F256F::ZERO.checked_powi(-1), None);
+        assert_eq!(F256F::ZERO.powf(F256F::ZERO), 1);
+        assert_eq!(F256F::from_num(-8).cbrt(), -2);
+        assert_eq!(F256F::MAX.checked_exp(), None);
+        assert_eq!(F256F::MIN.exp(), 0);
+        assert!(FixedI256::<U256>::from_num(0.25).overflowing_sqrt().1);
+        assert_eq!(F256F::from_num(2).checked_asin(), None);
+        assert!(FixedI256::<U255>::ZERO.overflowing_cos().1);
+        assert_eq!(F256F::ZERO.atan2(F256F::ZERO), 0);
+        assert_eq!(F256F::MAX.tanh(), 1);
+        assert_eq!(F256F::MIN.tanh(), -1);
+        assert_eq!(F256F::MIN.checked_sinh(), None);
+        assert_eq!(F256F::MIN.saturating_sinh(), F256F::MIN);
+        assert_eq!(F256F::MIN.saturating_cosh(), F256F::MAX);
+        assert_eq!(F256F::MAX.checked_hypot(F256F::MAX), None);
+    }
+
+    #[test]
+    fn fmt_and_parse_match_128() {
+        type F128F = FixedI128<U64>;
+        type F256F = FixedI256<U64>;
+        let mut state = 0x0fed_cba9_8765_4321;
+        for _ in 0..500 {
+            let a = F128F::from_bits(next_i128(&mut state));
+            let wa = F256F::from_num(a);
+            let s = format!("{a}");
+            assert_eq!(format!("{wa}"), s);
+            assert_eq!(format!("{wa:?}"), format!("{a:?}"));
+            assert_eq!(format!("{wa:x}"), format!("{a:x}"));
+            assert_eq!(format!("{wa:b}"), format!("{a:b}"));
+            assert_eq!(format!("{wa:.5}"), format!("{a:.5}"));
+            assert_eq!(format!("{wa:e}"), format!("{a:e}"));
+            assert_eq!(F256F::from_str(&s).unwrap(), a);
+            let hex = format!("{a:X}");
+            assert_eq!(F256F::from_str_hex(&hex).unwrap(), a);
+        }
+    }
+
+    #[test]
+    fn bits_match_128() {
+        let mut state = 0x5555_aaaa_3333_cccc;
+        for _ in 0..2000 {
+            let (a, b) = (next_i128(&mut state), next_i128(&mut state));
+            let (wa, wb) = (I256Bits::from_i128(a), I256Bits::from_i128(b));
+            let narrow = |w: Option<I256Bits>| w.and_then(|w| i128::try_from(w).ok());
+            assert_eq!(narrow(wa.checked_add(wb)), a.checked_add(b));
+            assert_eq!(narrow(wa.checked_sub(wb)), a.checked_sub(b));
+            assert_eq!(narrow(wa.checked_mul(wb)), a.checked_mul(b));
+            assert_eq!(narrow(wa.checked_div(wb)), a.checked_div(b));
+            assert_eq!(narrow(wa.checked_rem(wb)), a.checked_rem(b));
+            assert_eq!(narrow(wa.checked_div_euclid(wb)), a.checked_div_euclid(b));
+            assert_eq!(narrow(wa.checked_rem_euclid(wb)), a.checked_rem_euclid(b));
+            assert_eq!(wa.wrapping_shr(7), I256Bits::from_i128(a >> 7));
+            assert_eq!(wa.cmp(&wb), a.cmp(&b));
+            assert_eq!(wa.signum(), I256Bits::from_i128(a.signum()));
+            assert_eq!(format!("{wa}"), format!("{a}"));
+            assert_eq!(format!("{wa:+08}"), format!("{a:+08}"));
+            assert_eq!(format!("{wa:e}"), format!("{a:e}"));
+            assert_eq!(format!("{wa:?}"), format!("{a:?}"));
+            assert_eq!(format!("{a}").parse::<I256Bits>(), Ok(wa));
+
+            let (ua, ub) = (a.unsigned_abs(), b.unsigned_abs());
+            let (wua, wub) = (U256Bits::from_u128(ua), U256Bits::from_u128(ub));
+            let narrow = |w: Option<U256Bits>| w.and_then(|w| u128::try_from(w).ok());
+            assert_eq!(narrow(wua.checked_add(wub)), ua.checked_add(ub));
+            assert_eq!(narrow(wua.checked_sub(wub)), ua.checked_sub(ub));
+            assert_eq!(narrow(wua.checked_mul(wub)), ua.checked_mul(ub));
+            assert_eq!(narrow(wua.checked_div(wub)), ua.checked_div(ub));
+            assert_eq!(narrow(wua.checked_rem(wub)), ua.checked_rem(ub));
+            assert_eq!(wua.cmp(&wub), ua.cmp(&ub));
+            assert_eq!(format!("{wua:#x}"), format!("{ua:#x}"));
+            assert_eq!(format!("{wua:o}"), format!("{ua:o}"));
+            assert_eq!(U256Bits::from_str_radix(&format!("{ua:b}"), 2), Ok(wua));
+        }
+    }
+
+    #[test]
+    fn bits_extremes() {
+        assert_eq!(I256Bits::MAX.checked_add(I256Bits::from_i128(1)), None);
+        assert_eq!(I256Bits::MIN.checked_neg(), None);
+        assert_eq!(I256Bits::MIN.checked_div(I256Bits::from_i128(-1)), None);
+        assert_eq!(
+            I256Bits::MIN.overflowing_rem(I256Bits::from_i128(-1)),
+            (I256Bits::default(), true)
+        );
+        assert_eq!(I256Bits::MIN.overflowing_abs(), (I256Bits::MIN, true));
+        assert_eq!(
+            I256Bits::from_i128(-1).wrapping_shr(255),
+            I256Bits::from_i128(-1)
+        );
+        assert_eq!(
+            I256Bits::from_i128(-7).rem_euclid(I256Bits::from_i128(2)),
+            I256Bits::from_i128(1)
+        );
+        assert_eq!(U256Bits::MAX.checked_mul(U256Bits::from_u128(2)), None);
+        assert_eq!(
+            U256Bits::MAX.saturating_add(U256Bits::from_u128(1)),
+            U256Bits::MAX
+        );
+        assert_eq!(U256Bits::from_u128(1).checked_shl(256), None);
+        assert_eq!(
+            U256Bits::from_u128(3)
+                .pow(161)
+                .checked_mul(U256Bits::from_u128(3)),
+            None
+        );
+        assert_eq!(
+            U256Bits::from_u128(5).checked_next_power_of_two(),
+            Some(U256Bits::from_u128(8))
+        );
+        assert_eq!(U256Bits::MAX.rotate_left(17), U256Bits::MAX);
+        assert_eq!(I256Bits::MIN.unsigned_abs(), U256Bits::from_u128(1) << 255);
+        assert_eq!(U256Bits::try_from(I256Bits::from_i128(-1)).ok(), None);
+        assert_eq!(I256Bits::try_from(U256Bits::MAX).ok(), None);
+        assert_eq!(i8::try_from(I256Bits::from_i128(-128)), Ok(-128));
+        assert!(i8::try_from(I256Bits::from_i128(128)).is_err());
+        assert!(u128::try_from(U256Bits::MAX).is_err());
+        assert_eq!(U256Bits::try_from(-1i32).ok(), None);
+        assert_eq!(I256Bits::from(u128::MAX).count_ones(), 128);
+
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(format!("{}", U256Bits::MAX), max);
+        assert_eq!(max.parse::<U256Bits>(), Ok(U256Bits::MAX));
+        assert!(format!("{max}0").parse::<U256Bits>().is_err());
+        assert!("-1".parse::<U256Bits>().is_err());
+        assert!("".parse::<I256Bits>().is_err());
+        assert_eq!(format!("{:x}", I256Bits::from_i128(-1)), "f".repeat(64));
+        assert_eq!(
+            I256Bits::from_str_radix(&format!("-{}", "f".repeat(63)), 16).map(|x| x.count_ones()),
+            Ok(5)
+        );
+
+        let bytes = core::array::from_fn(|i| i as u8);
+        assert_eq!(I256Bits::from_be_bytes(bytes).to_be_bytes(), bytes);
+        assert_eq!(I256Bits::from_le_bytes(bytes).to_le_bytes(), bytes);
+        assert_eq!(
+            I256Bits::from_be_bytes(bytes).swap_bytes(),
+            I256Bits::from_le_bytes(bytes)
+        );
+        assert_eq!(
+            I256Bits::from_be_bytes(bytes).reverse_bits().reverse_bits(),
+            I256Bits::from_be_bytes(bytes)
+        );
+    }
+
+    #[test]
+    fn from_to_bits() {
+        type I = FixedI256<U4>;
+        type U = FixedU256<U255>;
+        assert_eq!(I::from_bits(I256Bits::from_i128(-0x28)), -2.5);
+        assert_eq!(I::from_num(-2.5).to_bits(), I256Bits::from_i128(-0x28));
+        assert_eq!(I::MIN.to_bits(), I256Bits::MIN);
+        assert_eq!(I::MAX.to_bits(), I256Bits::MAX);
+        assert_eq!(U::from_bits(U256Bits::from_u128(1) << 254), 0.5);
+        assert_eq!(U::MAX.to_bits(), U256Bits::MAX);
+        assert_eq!(
+            U::from_bits(U256Bits::MAX).to_be_bytes(),
+            U256Bits::MAX.to_be_bytes()
+        );
+        let x = I::from_num(1234.5625);
+        assert_eq!(I::from_bits(x.to_bits()), x);
+        assert_eq!(I::from_be_bytes(x.to_bits().to_be_bytes()), x);
+    }
+
+    #[test]
+    fn extremes() {
+        type I0 = FixedI256<U0>;
+        type U0F = FixedU256<U0>;
+        type IAll = FixedI256<U255>;
+        type UAll = FixedU256<U256>;
+
+        let max = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+        assert_eq!(format!("{}", I0::MAX), max);
+        assert_eq!(
+            format!("{}", I0::MIN),
+            format!("-{}8", &max[..max.len() - 1])
+        );
+        assert_eq!(I0::from_str(max), Ok(I0::MAX));
+        assert!(I0::from_str(&format!("{max}0")).is_err());
+        assert_eq!(I0::saturating_from_str(&format!("{max}0")), Ok(I0::MAX));
+        assert_eq!(I0::MIN.overflowing_neg(), (I0::MIN, true));
+        assert_eq!(I0::MIN.checked_div(-I0::ONE), None);
+        assert_eq!(I0::MIN % -I0::ONE, 0);
+        assert_eq!(U0F::MAX.count_ones(), 256);
+        assert_eq!(U0F::MAX.to_num::<f64>(), 2f64.powi(256));
+        assert_eq!(U0F::MAX.to_num::<f32>(), f32::INFINITY);
+        assert_eq!(U0F::from_num(u128::MAX) * U0F::from_num(u128::MAX), {
+            let sq: U0F = U0F::MAX - U0F::from_num(u128::MAX) * U0F::from_num(2);
+            sq
+        });
+
+        assert_eq!(IAll::TRY_ONE, None);
+        assert_eq!(IAll::MIN, -0.5f64 * 2.0);
+        assert_eq!(IAll::from_num(0.5) * IAll::from_num(0.5), 0.25);
+        assert_eq!(IAll::from_num(-0.5) * IAll::DELTA, -IAll::DELTA);
+        assert_eq!(IAll::from_num(0.25) / IAll::from_num(0.5), 0.5);
+        assert_eq!(format!("{}", UAll::from_num(0.75)), "0.75");
+        assert_eq!(UAll::from_str("0.75"), Ok(UAll::from_num(0.75)));
+        assert_eq!(UAll::DELTA.to_num::<f64>(), 2f64.powi(-256));
+
+        let big = FixedU256::<U128>::from_num(u128::MAX);
+        assert_eq!(big.to_num::<u128>(), u128::MAX);
+        assert_eq!(big.checked_to_num::<i128>(), None);
+        assert_eq!(big.saturating_to_num::<i128>(), i128::MAX);
+        assert_eq!(big.wrapping_to_num::<i128>(), -1);
+        let x = FixedI256::<U192>::from_num(-1.5);
+        assert_eq!(x.to_num::<i8>(), -2);
+        assert_eq!(x.to_num::<FixedI8<U0>>(), -2);
+        assert_eq!(x.checked_to_num::<u8>(), None);
+        assert_eq!(x.saturating_to_num::<u8>(), 0);
+        assert!(x < -1i32 && x > -2.0f32 && x > I0::from_num(-2));
+        assert_eq!(FixedI256::<U192>::checked_from_num(f64::NAN), None);
+        assert_eq!(FixedI256::<U192>::checked_from_num(1e20f64), None);
+    }
+
+    #[test]
+    fn fixed_trait() {
+        use crate::traits::{Fixed, FixedEquiv};
+        use crate::types::extra::U4;
+
+        fn sum<F: Fixed>(a: F, b: F) -> F {
+            a + b
+        }
+        fn from_to_bits<F: Fixed>(bits: F::Bits) -> F::Bits {
+            F::from_bits(bits).to_bits()
+        }
+        fn is_signed<F: Fixed>() -> bool {
+            F::IS_SIGNED
+        }
+
+        type I = FixedI256<U4>;
+        type U = FixedU256<U4>;
+        assert_eq!(sum(I::from_num(-1.5), I::from_num(0.25)), -1.25);
+        assert_eq!(<I as Fixed>::FRAC_NBITS, 4);
+        assert_eq!(<I as Fixed>::INT_NBITS, 252);
+        assert!(is_signed::<I>() && !is_signed::<U>());
+        assert_eq!(<U as Fixed>::MAX.to_bits(), U256Bits::MAX);
+        assert_eq!(from_to_bits::<I>(I256Bits::MIN), I256Bits::MIN);
+        assert_eq!(from_to_bits::<U>(U256Bits::MAX), U256Bits::MAX);
+        assert_eq!(I256Bits::MAX.to_fixed_equiv(), FixedI256::<U0>::MAX);
+        let mut bits = U256Bits::from_u128(3);
+        *bits.as_fixed_equiv_mut() += FixedU256::<U0>::ONE;
+        assert_eq!(bits.to_fixed_equiv(), FixedU256::<U0>::from_num(4));
+
+        // conversions through the traits
+        assert_eq!(I::from_num(FixedI8::<U4>::from_num(-1.5)), -1.5);
+        assert_eq!(I::from_num(-1.5).to_num::<FixedI16<U4>>(), -1.5);
+        assert_eq!(I::from_num(-1.5).to_num::<f32>(), -1.5);
+        assert_eq!(I::from_num(-1.5).checked_to_num::<u16>(), None);
+        assert_eq!(
+            U::MAX.saturating_to_num::<FixedU128<U4>>(),
+            FixedU128::<U4>::MAX
+        );
+        assert_eq!(
+            U::MAX.overflowing_to_num::<FixedU8<U4>>(),
+            (FixedU8::<U4>::MAX, true)
+        );
+        let d = DynFixed::from_num(-1.5, DynFormat::signed(16, 4).unwrap());
+        assert_eq!(I::from_num(d), -1.5);
+        assert_eq!(U::saturating_from_num(d), 0);
+
+        // wrappers
+        let c = Checked(Some(I::MAX)) + Checked(Some(I::DELTA));
+        assert_eq!(c.0, None);
+        assert_eq!(
+            Checked(Some(I::ONE)) + Checked(Some(I::DELTA)),
+            Checked(Some(I::ONE + I::DELTA))
+        );
+        assert_eq!((Wrapping(I::MAX) + Wrapping(I::DELTA)).0, I::MIN);
+        let o = Overflowing::new(U::ZERO) - Overflowing::new(U::DELTA);
+        assert_eq!((o.value, o.overflow), (U::MAX, true));
+        assert_eq!(
+            (Unwrapped(U::from_num(3)) * Unwrapped(U::from_num(0.5))).0,
+            1.5
+        );
+        type B = Bounded<I, -0x20, 0x20>;
+        assert_eq!(B::new_clamped(I::from_num(3)).get(), 2);
+        assert_eq!(B::new(I::from_num(-3)), None);
+        assert_eq!(B::saturating_from_num(-5).get(), -2);
+    }
+
+    #[test]
+    fn wrapper_int_ops() {
+        use crate::types::extra::U4;
+
+        type I = FixedI256<U4>;
+        type U = FixedU256<U4>;
+        let i = |x| I256Bits::from_i128(x);
+        let u = |x| U256Bits::from_u128(x);
+
+        let c = Checked(Some(I::from_num(-1.5))) * i(3);
+        assert_eq!(c, Checked(Some(I::from_num(-4.5))));
+        assert_eq!(c / i(2), Checked(Some(I::from_num(-2.25))));
+        assert_eq!(c % i(2), Checked(Some(I::from_num(-0.5))));
+        assert_eq!((c / i(0)).0, None);
+        assert_eq!((c % i(0)).0, None);
+        assert_eq!((Checked(Some(I::MAX)) * i(2)).0, None);
+        let mut c = Checked(Some(U::from_num(1.5)));
+        c *= &u(4);
+        c /= u(3);
+        c %= u(1);
+        assert_eq!(c, Checked(Some(U::ZERO)));
+
+        let s = Saturating(I::MAX) * i(2);
+        assert_eq!(s.0, I::MAX);
+        assert_eq!((Saturating(I::MAX) * i(-2)).0, I::MIN);
+        assert_eq!((Saturating(U::from_num(7.5)) / u(2)).0, 3.75);
+        assert_eq!((Saturating(U::from_num(7.5)) % u(2)).0, 1.5);
+
+        assert_eq!((Wrapping(I::MIN) * i(-1)).0, I::MIN);
+        assert_eq!((Wrapping(I::MIN) / i(-1)).0, I::MIN);
+        let mut w = Wrapping(U::MAX);
+        w *= u(2);
+        assert_eq!(w.0, U::MAX - U::DELTA);
+        w %= &u(3);
+        assert_eq!(w.0, 0.875);
+
+        assert_eq!((Unwrapped(I::from_num(-1.5)) * i(3)).0, -4.5);
+        assert_eq!((Unwrapped(U::from_num(4.5)) / u(3)).0, 1.5);
+        assert_eq!((Unwrapped(U::from_num(4.5)) % u(3)).0, 1.5);
+
+        let o = Overflowing::new(I::MAX) * i(2);
+        assert_eq!((o.value, o.overflow), (I::from_bits(i(-2)), true));
+        let mut o = Overflowing::new(U::from_num(4.5)) / u(3);
+        o %= &u(1);
+        assert_eq!((o.value, o.overflow), (U::from_num(0.5), false));
+    }
+
+    #[test]
+    #[should_panic(expected = "DynFormat cannot have more than 128 bits")]
+    fn dyn_format_of_256() {
+        let _ = DynFormat::of::<FixedI256<U0>>();
+    }
+}
@@ -0,0 +1,2256 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::fixed256::{
+    MSB, is_neg, neg_if, not, reverse_bytes, u256_from_be_bytes, u256_to_be_bytes,
+};
+use crate::int256;
+use crate::int256::U256;
+use az::{
+    Cast, CheckedAs, CheckedCast, OverflowingCast, SaturatingCast, UnwrappedCast, WrappingAs,
+    WrappingCast,
+};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::num::{ParseIntError, TryFromIntError};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+// There is no way to construct the error types of the standard library
+// directly, so we get them from failing primitive operations.
+
+#[derive(Clone, Copy)]
+pub(crate) enum ParseIntErrorKind {
+    Empty,
+    InvalidDigit,
+    PosOverflow,
+    NegOverflow,
+}
+
+pub(crate) fn parse_int_error(kind: ParseIntErrorKind) -> ParseIntError {
+    let res = match kind {
+        ParseIntErrorKind::Empty => "".parse::<i8>(),
+        ParseIntErrorKind::InvalidDigit => "x".parse::<i8>(),
+        ParseIntErrorKind::PosOverflow => "128".parse::<i8>(),
+        ParseIntErrorKind::NegOverflow => "-129".parse::<i8>(),
+    };
+    match res {
+        Ok(_) => unreachable!(),
+        Err(e) => e,
+    }
+}
+
+pub(crate) fn try_from_int_error() -> TryFromIntError {
+    match u8::try_from(-1i8) {
+        Ok(_) => unreachable!(),
+        Err(e) => e,
+    }
+}
+
+const ONE: U256 = U256 { lo: 1, hi: 0 };
+
+#[inline]
+const fn eq(a: U256, b: U256) -> bool {
+    a.lo == b.lo && a.hi == b.hi
+}
+
+// Parses the digits of an integer in the given radix, returning the magnitude
+// and whether it overflowed.
+fn parse_abs(digits: &[u8], radix: u32) -> Result<(U256, bool), ParseIntErrorKind> {
+    if digits.is_empty() {
+        return Err(ParseIntErrorKind::InvalidDigit);
+    }
+    let mut acc = U256::ZERO;
+    let mut overflow = false;
+    for &b in digits {
+        let Some(digit) = (b as char).to_digit(radix) else {
+            return Err(ParseIntErrorKind::InvalidDigit);
+        };
+        if !overflow {
+            let (next, o) = int256::overflowing_mul_add_u256_u128(acc, radix.into(), digit.into());
+            acc = next;
+            overflow = o;
+        }
+    }
+    Ok((acc, overflow))
+}
+
+macro_rules! bits256 {
+    (
+        description = $description:literal,
+        {Self, NonZero, Fixed} = {$Self:ident, $NonZero:ident, $Fixed:ident},
+        Signedness = $Signedness:ident,
+        {Prim, from_prim} = {$Prim:ident, $from_prim:ident},
+    ) => {
+        comment! {
+            $description, "-bit ",
+            if_signed_unsigned!($Signedness, "signed", "unsigned"),
+            " integer, used as the bits of [`", stringify!($Fixed), "`](crate::", stringify!($Fixed), ").
+
+Since there is no primitive 256-bit integer, this type provides the subset of
+the primitive integer API that is useful when working with the bits of
+fixed-point numbers: constants, byte conversions, bit counting, and arithmetic
+with the usual checked, wrapping, saturating and overflowing variants. It
+implements the arithmetic, bitwise and formatting traits, and can be parsed
+from a string.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Fixed), ", ", stringify!($Self), "};
+type Fix = ", stringify!($Fixed), "<U4>;
+let bits = ", stringify!($Self), "::", stringify!($from_prim), "(0x28);
+assert_eq!(Fix::from_bits(bits), 2.5);
+assert_eq!(Fix::from_num(2.5).to_bits(), bits);
+assert_eq!(bits.to_string(), \"40\");
+assert_eq!(bits * ", stringify!($Self), "::", stringify!($from_prim), "(2), \"80\".parse().unwrap());
+```
+";
+            #[derive(Clone, Copy, PartialEq, Eq)]
+            #[repr(transparent)]
+            pub struct $Self(pub(crate) U256);
+        }
+
+        comment! {
+            "A ", stringify!($Self), " that is known not to equal zero.
+
+This is the equivalent of [`NonZero`][core::num::NonZero] for
+[`", stringify!($Self), "`].
+
+# Examples
+
+```rust
+use fixed::{", stringify!($NonZero), ", ", stringify!($Self), "};
+let three = ", stringify!($Self), "::", stringify!($from_prim), "(3);
+assert_eq!(", stringify!($NonZero), "::new(three).unwrap().get(), three);
+assert!(", stringify!($NonZero), "::new(", stringify!($Self), "::", stringify!($from_prim), "(0)).is_none());
+```
+";
+            #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+            #[repr(transparent)]
+            pub struct $NonZero($Self);
+        }
+
+        impl $Self {
+            comment! {
+                "The smallest value that can be represented by this integer type",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", &minus;2<sup>255</sup>.",
+                    ", 0.",
+                ),
+                "
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::MIN.to_string(), \"",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+                    "0",
+                ),
+                "\");
+```
+";
+                pub const MIN: $Self = $Self(if_signed_unsigned!($Signedness, MSB, U256::ZERO));
+            }
+
+            comment! {
+                "The largest value that can be represented by this integer type, 2<sup>",
+                if_signed_unsigned!($Signedness, "255", "256"),
+                "</sup>&nbsp;&minus;&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::MAX.count_ones(), ",
+                if_signed_unsigned!($Signedness, "255", "256"),
+                ");
+```
+";
+                pub const MAX: $Self = $Self(if_signed_unsigned!($Signedness, not(MSB), U256::MAX));
+            }
+
+            comment! {
+                "The size of this integer type in bits.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::BITS, 256);
+```
+";
+                pub const BITS: u32 = 256;
+            }
+
+            comment! {
+                "Creates an integer from a [`", stringify!($Prim), "`].
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let five = ", stringify!($Self), "::", stringify!($from_prim), "(5);
+assert_eq!(five.to_string(), \"5\");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn $from_prim(val: $Prim) -> $Self {
+                    $Self(U256 {
+                        lo: val as u128,
+                        hi: if_signed_unsigned!($Signedness, (val >> 127) as u128, 0),
+                    })
+                }
+            }
+
+            #[inline]
+            pub(crate) const fn neg_abs(self) -> (bool, U256) {
+                if if_signed_unsigned!($Signedness, is_neg(self.0), false) {
+                    (true, int256::wrapping_neg_u256(self.0))
+                } else {
+                    (false, self.0)
+                }
+            }
+
+            #[inline]
+            pub(crate) const fn is_zero(self) -> bool {
+                int256::is_zero_u256(self.0)
+            }
+
+            #[inline]
+            pub(crate) const fn cmp_bits(self, rhs: $Self) -> Ordering {
+                let (lhs_hi, rhs_hi) = (self.0.hi as $Prim, rhs.0.hi as $Prim);
+                if lhs_hi < rhs_hi {
+                    Ordering::Less
+                } else if lhs_hi > rhs_hi {
+                    Ordering::Greater
+                } else if self.0.lo < rhs.0.lo {
+                    Ordering::Less
+                } else if self.0.lo > rhs.0.lo {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
+
+            comment! {
+                "Creates an integer from its representation as a byte array in big endian.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let mut bytes = [0; 32];
+bytes[31] = 0x28;
+assert_eq!(", stringify!($Self), "::from_be_bytes(bytes), ", stringify!($Self), "::", stringify!($from_prim), "(0x28));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_be_bytes(bytes: [u8; 32]) -> $Self {
+                    $Self(u256_from_be_bytes(bytes))
+                }
+            }
+
+            comment! {
+                "Creates an integer from its representation as a byte array in little endian.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let mut bytes = [0; 32];
+bytes[0] = 0x28;
+assert_eq!(", stringify!($Self), "::from_le_bytes(bytes), ", stringify!($Self), "::", stringify!($from_prim), "(0x28));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_le_bytes(bytes: [u8; 32]) -> $Self {
+                    $Self(u256_from_be_bytes(reverse_bytes(bytes)))
+                }
+            }
+
+            comment! {
+                "Creates an integer from its representation as a byte array in native endian.
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_ne_bytes(bytes: [u8; 32]) -> $Self {
+                    if cfg!(target_endian = "big") {
+                        $Self::from_be_bytes(bytes)
+                    } else {
+                        $Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this integer as a byte
+array in big-endian byte order.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let bytes = ", stringify!($Self), "::", stringify!($from_prim), "(0x28).to_be_bytes();
+assert_eq!(bytes[31], 0x28);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_be_bytes(self) -> [u8; 32] {
+                    u256_to_be_bytes(self.0)
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this integer as a byte
+array in little-endian byte order.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let bytes = ", stringify!($Self), "::", stringify!($from_prim), "(0x28).to_le_bytes();
+assert_eq!(bytes[0], 0x28);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_le_bytes(self) -> [u8; 32] {
+                    reverse_bytes(u256_to_be_bytes(self.0))
+                }
+            }
+
+            comment! {
+                "Returns the memory representation of this integer as a byte
+array in native byte order.
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_ne_bytes(self) -> [u8; 32] {
+                    if cfg!(target_endian = "big") {
+                        self.to_be_bytes()
+                    } else {
+                        self.to_le_bytes()
+                    }
+                }
+            }
+
+            comment! {
+                "Converts an integer from big endian to the target’s endianness.
+
+On big endian this is a no-op. On little endian the bytes are swapped.
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_be(x: $Self) -> $Self {
+                    if cfg!(target_endian = "big") {
+                        x
+                    } else {
+                        x.swap_bytes()
+                    }
+                }
+            }
+
+            comment! {
+                "Converts an integer from little endian to the target’s endianness.
+
+On little endian this is a no-op. On big endian the bytes are swapped.
+";
+                #[inline]
+                #[must_use]
+                pub const fn from_le(x: $Self) -> $Self {
+                    if cfg!(target_endian = "big") {
+                        x.swap_bytes()
+                    } else {
+                        x
+                    }
+                }
+            }
+
+            comment! {
+                "Converts `self` to big endian from the target’s endianness.
+
+On big endian this is a no-op. On little endian the bytes are swapped.
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_be(self) -> $Self {
+                    $Self::from_be(self)
+                }
+            }
+
+            comment! {
+                "Converts `self` to little endian from the target’s endianness.
+
+On little endian this is a no-op. On big endian the bytes are swapped.
+";
+                #[inline]
+                #[must_use]
+                pub const fn to_le(self) -> $Self {
+                    $Self::from_le(self)
+                }
+            }
+
+            comment! {
+                "Reverses the byte order of the integer.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let a = ", stringify!($Self), "::", stringify!($from_prim), "(0x12);
+assert_eq!(a.swap_bytes().to_be_bytes()[0], 0x12);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn swap_bytes(self) -> $Self {
+                    $Self(U256 {
+                        lo: self.0.hi.swap_bytes(),
+                        hi: self.0.lo.swap_bytes(),
+                    })
+                }
+            }
+
+            comment! {
+                "Reverses the order of bits in the integer.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let a = ", stringify!($Self), "::", stringify!($from_prim), "(1);
+assert_eq!(a.reverse_bits().leading_zeros(), 0);
+assert_eq!(a.reverse_bits().reverse_bits(), a);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn reverse_bits(self) -> $Self {
+                    $Self(U256 {
+                        lo: self.0.hi.reverse_bits(),
+                        hi: self.0.lo.reverse_bits(),
+                    })
+                }
+            }
+
+            comment! {
+                "Returns the number of ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::", stringify!($from_prim), "(0b1011).count_ones(), 3);
+```
+";
+                #[inline]
+                #[doc(alias("popcount", "popcnt"))]
+                #[must_use]
+                pub const fn count_ones(self) -> u32 {
+                    self.0.lo.count_ones() + self.0.hi.count_ones()
+                }
+            }
+
+            comment! {
+                "Returns the number of zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::", stringify!($from_prim), "(0b1011).count_zeros(), 253);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn count_zeros(self) -> u32 {
+                    self.0.lo.count_zeros() + self.0.hi.count_zeros()
+                }
+            }
+
+            comment! {
+                "Returns the number of leading zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::", stringify!($from_prim), "(1).leading_zeros(), 255);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn leading_zeros(self) -> u32 {
+                    int256::leading_zeros_u256(self.0)
+                }
+            }
+
+            comment! {
+                "Returns the number of leading ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::MAX.leading_ones(), ",
+                if_signed_unsigned!($Signedness, "0", "256"),
+                ");
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn leading_ones(self) -> u32 {
+                    int256::leading_zeros_u256(not(self.0))
+                }
+            }
+
+            comment! {
+                "Returns the number of trailing zeros in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::", stringify!($from_prim), "(0b1000).trailing_zeros(), 3);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn trailing_zeros(self) -> u32 {
+                    int256::trailing_zeros_u256(self.0)
+                }
+            }
+
+            comment! {
+                "Returns the number of trailing ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::", stringify!($from_prim), "(0b0111).trailing_ones(), 3);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn trailing_ones(self) -> u32 {
+                    int256::trailing_zeros_u256(not(self.0))
+                }
+            }
+
+            comment! {
+                "Shifts the bits to the left by a specified amount, `n`,
+wrapping the truncated bits to the end of the resulting integer.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let a = ", stringify!($Self), "::", stringify!($from_prim), "(0b101);
+assert_eq!(a.rotate_left(255), ", stringify!($Self), "::from_be_bytes({
+    let mut bytes = [0; 32];
+    bytes[0] = 0x80;
+    bytes[31] = 0b10;
+    bytes
+}));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn rotate_left(self, n: u32) -> $Self {
+                    let n = n % 256;
+                    if n == 0 {
+                        return self;
+                    }
+                    let left = int256::wrapping_shl_u256(self.0, n);
+                    let right = int256::wrapping_shr_u256(self.0, 256 - n);
+                    $Self(U256 {
+                        lo: left.lo | right.lo,
+                        hi: left.hi | right.hi,
+                    })
+                }
+            }
+
+            comment! {
+                "Shifts the bits to the right by a specified amount, `n`,
+wrapping the truncated bits to the beginning of the resulting integer.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let a = ", stringify!($Self), "::", stringify!($from_prim), "(0b101);
+assert_eq!(a.rotate_right(1).rotate_left(1), a);
+assert_eq!(a.rotate_right(1).leading_zeros(), 0);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn rotate_right(self, n: u32) -> $Self {
+                    self.rotate_left(256 - n % 256)
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+
+                comment! {
+                    "Returns [`true`] if the number is >&nbsp;0.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_positive(self) -> bool {
+                        !is_neg(self.0) && !self.is_zero()
+                    }
+                }
+
+                comment! {
+                    "Returns [`true`] if the number is <&nbsp;0.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_negative(self) -> bool {
+                        is_neg(self.0)
+                    }
+                }
+
+                comment! {
+                    "Returns a number representing the sign of `self`: 0 if
+the number is zero, 1 if it is positive and &minus;1 if it is negative.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let neg_five = ", stringify!($Self), "::from_i128(-5);
+assert_eq!(neg_five.signum(), ", stringify!($Self), "::from_i128(-1));
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn signum(self) -> $Self {
+                        if is_neg(self.0) {
+                            $Self(U256::MAX)
+                        } else if self.is_zero() {
+                            self
+                        } else {
+                            $Self(ONE)
+                        }
+                    }
+                }
+
+                comment! {
+                    "Computes the absolute value.
+
+# Panics
+
+When debug assertions are enabled, panics if the result overflows, which
+only happens for [`MIN`][Self::MIN]. Otherwise the wrapped value is returned.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn abs(self) -> $Self {
+                        let (ans, overflow) = self.overflowing_abs();
+                        debug_assert!(!overflow, "attempt to negate with overflow");
+                        ans
+                    }
+                }
+
+                comment! {
+                    "Checked absolute value. Returns [`None`] on overflow.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_abs(self) -> Option<$Self> {
+                        match self.overflowing_abs() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Wrapping absolute value, wrapping on overflow.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn wrapping_abs(self) -> $Self {
+                        self.overflowing_abs().0
+                    }
+                }
+
+                comment! {
+                    "Saturating absolute value, saturating on overflow.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn saturating_abs(self) -> $Self {
+                        match self.overflowing_abs() {
+                            (ans, false) => ans,
+                            (_, true) => $Self::MAX,
+                        }
+                    }
+                }
+
+                comment! {
+                    "Overflowing absolute value.
+
+Returns a [tuple] of the absolute value and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn overflowing_abs(self) -> ($Self, bool) {
+                        let abs = self.neg_abs().1;
+                        ($Self(abs), is_neg(abs))
+                    }
+                }
+
+                comment! {
+                    "Computes the absolute value of `self` without any
+wrapping or panicking.
+
+# Examples
+
+```rust
+use fixed::{I256Bits, U256Bits};
+assert_eq!(I256Bits::MIN.unsigned_abs(), U256Bits::from_u128(1) << 255);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn unsigned_abs(self) -> U256Bits {
+                        U256Bits(self.neg_abs().1)
+                    }
+                }
+            }
+
+            if_unsigned! {
+                $Signedness;
+
+                comment! {
+                    "Returns [`true`] if and only if `self`&nbsp;=&nbsp;2<sup><i>k</i></sup>
+for some <i>k</i>.
+
+# Examples
+
+```rust
+use fixed::U256Bits;
+assert!(U256Bits::from_u128(16).is_power_of_two());
+assert!(!U256Bits::from_u128(10).is_power_of_two());
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn is_power_of_two(self) -> bool {
+                        self.count_ones() == 1
+                    }
+                }
+
+                comment! {
+                    "Returns the smallest power of two greater than or equal to `self`,
+or [`None`] if the next power of two is greater than the maximum value.
+
+# Examples
+
+```rust
+use fixed::U256Bits;
+let ten = U256Bits::from_u128(10);
+assert_eq!(ten.checked_next_power_of_two(), Some(U256Bits::from_u128(16)));
+assert_eq!(U256Bits::MAX.checked_next_power_of_two(), None);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_next_power_of_two(self) -> Option<$Self> {
+                        if int256::leading_zeros_u256(self.0) == 256 || self.is_power_of_two() {
+                            return Some(if self.is_zero() { $Self(ONE) } else { self });
+                        }
+                        let lz = int256::leading_zeros_u256(self.0);
+                        if lz == 0 {
+                            None
+                        } else {
+                            Some($Self(int256::wrapping_shl_u256(ONE, 256 - lz)))
+                        }
+                    }
+                }
+
+                comment! {
+                    "Returns the smallest power of two greater than or equal to `self`.
+
+# Panics
+
+When debug assertions are enabled, panics if the result overflows.
+Otherwise zero is returned on overflow.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn next_power_of_two(self) -> $Self {
+                        match self.checked_next_power_of_two() {
+                            Some(ans) => ans,
+                            None => {
+                                debug_assert!(false, "attempt to add with overflow");
+                                $Self(U256::ZERO)
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked negation. Returns [`None`] on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_neg(self) -> Option<$Self> {
+                    match self.overflowing_neg() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping negation, wrapping on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_neg(self) -> $Self {
+                    $Self(int256::wrapping_neg_u256(self.0))
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Saturating negation, saturating on overflow.
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn saturating_neg(self) -> $Self {
+                        match self.overflowing_neg() {
+                            (ans, false) => ans,
+                            (_, true) => $Self::MAX,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing negation.
+
+Returns a [tuple] of the negated value and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_neg(self) -> ($Self, bool) {
+                    let ans = int256::wrapping_neg_u256(self.0);
+                    let overflow = if_signed_unsigned!(
+                        $Signedness,
+                        is_neg(self.0) && is_neg(ans),
+                        !self.is_zero(),
+                    );
+                    ($Self(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked addition. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let one = ", stringify!($Self), "::", stringify!($from_prim), "(1);
+assert_eq!(one.checked_add(one), Some(", stringify!($Self), "::", stringify!($from_prim), "(2)));
+assert_eq!(", stringify!($Self), "::MAX.checked_add(one), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add(self, rhs: $Self) -> Option<$Self> {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping addition, wrapping on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_add(self, rhs: $Self) -> $Self {
+                    self.overflowing_add(rhs).0
+                }
+            }
+
+            comment! {
+                "Saturating addition, saturating on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_add(self, rhs: $Self) -> $Self {
+                    match self.overflowing_add(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, is_neg(rhs.0), false) {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing addition.
+
+Returns a [tuple] of the sum and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_add(self, rhs: $Self) -> ($Self, bool) {
+                    let (ans, carry) = int256::overflowing_add_u256(self.0, rhs.0);
+                    let overflow = if if_signed_unsigned!($Signedness, true, false) {
+                        is_neg(self.0) == is_neg(rhs.0) && is_neg(ans) != is_neg(self.0)
+                    } else {
+                        carry
+                    };
+                    ($Self(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked subtraction. Returns [`None`] on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sub(self, rhs: $Self) -> Option<$Self> {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping subtraction, wrapping on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sub(self, rhs: $Self) -> $Self {
+                    self.overflowing_sub(rhs).0
+                }
+            }
+
+            comment! {
+                "Saturating subtraction, saturating on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sub(self, rhs: $Self) -> $Self {
+                    match self.overflowing_sub(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if if_signed_unsigned!($Signedness, is_neg(rhs.0), false) {
+                                $Self::MAX
+                            } else {
+                                $Self::MIN
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing subtraction.
+
+Returns a [tuple] of the difference and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sub(self, rhs: $Self) -> ($Self, bool) {
+                    let (ans, borrow) = int256::overflowing_sub_u256(self.0, rhs.0);
+                    let overflow = if if_signed_unsigned!($Signedness, true, false) {
+                        is_neg(self.0) != is_neg(rhs.0) && is_neg(ans) != is_neg(self.0)
+                    } else {
+                        borrow
+                    };
+                    ($Self(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Checked multiplication. Returns [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let two = ", stringify!($Self), "::", stringify!($from_prim), "(2);
+assert_eq!(two.checked_mul(two), Some(", stringify!($Self), "::", stringify!($from_prim), "(4)));
+assert_eq!(", stringify!($Self), "::MAX.checked_mul(two), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_mul(self, rhs: $Self) -> Option<$Self> {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping multiplication, wrapping on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_mul(self, rhs: $Self) -> $Self {
+                    self.overflowing_mul(rhs).0
+                }
+            }
+
+            comment! {
+                "Saturating multiplication, saturating on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_mul(self, rhs: $Self) -> $Self {
+                    match self.overflowing_mul(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if self.neg_abs().0 != rhs.neg_abs().0 {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_mul(self, rhs: $Self) -> ($Self, bool) {
+                    let (lhs_neg, lhs_abs) = self.neg_abs();
+                    let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                    let prod = int256::wide_mul_u256(lhs_abs, rhs_abs);
+                    let neg = lhs_neg != rhs_neg;
+                    let overflow = !int256::is_zero_u256(prod.hi)
+                        || if_signed_unsigned!(
+                            $Signedness,
+                            is_neg(prod.lo) && !(neg && eq(prod.lo, MSB)),
+                            false,
+                        );
+                    ($Self(neg_if(neg, prod.lo)), overflow)
+                }
+            }
+
+            comment! {
+                "Checked division. Returns [`None`] if `rhs` is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let seven = ", stringify!($Self), "::", stringify!($from_prim), "(7);
+let two = ", stringify!($Self), "::", stringify!($from_prim), "(2);
+assert_eq!(seven.checked_div(two), Some(", stringify!($Self), "::", stringify!($from_prim), "(3)));
+assert_eq!(seven.checked_div(", stringify!($Self), "::", stringify!($from_prim), "(0)), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_div(self, rhs: $Self) -> Option<$Self> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping division, wrapping on overflow.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_div(self, rhs: $Self) -> $Self {
+                    self.overflowing_div(rhs).0
+                }
+            }
+
+            comment! {
+                "Saturating division, saturating on overflow.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_div(self, rhs: $Self) -> $Self {
+                    match self.overflowing_div(rhs) {
+                        (ans, false) => ans,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing division.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_div(self, rhs: $Self) -> ($Self, bool) {
+                    if rhs.is_zero() {
+                        panic!("attempt to divide by zero");
+                    }
+                    let (lhs_neg, lhs_abs) = self.neg_abs();
+                    let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                    let (q, _) = int256::div_rem_u256(lhs_abs, rhs_abs);
+                    let neg = lhs_neg != rhs_neg;
+                    let overflow = if_signed_unsigned!($Signedness, !neg && is_neg(q), false);
+                    ($Self(neg_if(neg, q)), overflow)
+                }
+            }
+
+            comment! {
+                "Checked remainder. Returns [`None`] if `rhs` is zero or on overflow.
+
+The remainder has the same sign as `self`.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_rem(self, rhs: $Self) -> Option<$Self> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_rem(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping remainder, wrapping on overflow.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_rem(self, rhs: $Self) -> $Self {
+                    self.overflowing_rem(rhs).0
+                }
+            }
+
+            comment! {
+                "Overflowing remainder.
+
+Returns a [tuple] of the remainder and a [`bool`] indicating whether an
+overflow would occur in the division. On overflow, zero is returned.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_rem(self, rhs: $Self) -> ($Self, bool) {
+                    if rhs.is_zero() {
+                        panic!("attempt to calculate the remainder with a divisor of zero");
+                    }
+                    let (lhs_neg, lhs_abs) = self.neg_abs();
+                    let (rhs_neg, rhs_abs) = rhs.neg_abs();
+                    let (q, r) = int256::div_rem_u256(lhs_abs, rhs_abs);
+                    let overflow = if if_signed_unsigned!($Signedness, true, false) {
+                        lhs_neg == rhs_neg && is_neg(q)
+                    } else {
+                        false
+                    };
+                    ($Self(neg_if(lhs_neg, r)), overflow)
+                }
+            }
+
+            comment! {
+                "Calculates the quotient of Euclidean division.
+
+# Panics
+
+Panics if `rhs` is zero. When debug assertions are enabled, also panics on
+overflow.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn div_euclid(self, rhs: $Self) -> $Self {
+                    let (ans, overflow) = self.overflowing_div_euclid(rhs);
+                    debug_assert!(!overflow, "attempt to divide with overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked Euclidean division. Returns [`None`] if `rhs` is zero or on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_div_euclid(self, rhs: $Self) -> Option<$Self> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_div_euclid(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping Euclidean division, wrapping on overflow.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_div_euclid(self, rhs: $Self) -> $Self {
+                    self.overflowing_div_euclid(rhs).0
+                }
+            }
+
+            comment! {
+                "Overflowing Euclidean division.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_div_euclid(self, rhs: $Self) -> ($Self, bool) {
+                    let (q, overflow) = self.overflowing_div(rhs);
+                    if if_signed_unsigned!($Signedness, is_neg(self.overflowing_rem(rhs).0.0), false) {
+                        let q = if is_neg(rhs.0) {
+                            q.wrapping_add($Self(ONE))
+                        } else {
+                            q.wrapping_sub($Self(ONE))
+                        };
+                        (q, overflow)
+                    } else {
+                        (q, overflow)
+                    }
+                }
+            }
+
+            comment! {
+                "Calculates the least nonnegative remainder of Euclidean division.
+
+# Panics
+
+Panics if `rhs` is zero. When debug assertions are enabled, also panics on
+overflow.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn rem_euclid(self, rhs: $Self) -> $Self {
+                    let (ans, overflow) = self.overflowing_rem_euclid(rhs);
+                    debug_assert!(
+                        !overflow,
+                        "attempt to calculate the remainder with overflow"
+                    );
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked Euclidean remainder. Returns [`None`] if `rhs` is zero or on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_rem_euclid(self, rhs: $Self) -> Option<$Self> {
+                    if rhs.is_zero() {
+                        return None;
+                    }
+                    match self.overflowing_rem_euclid(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping Euclidean remainder, wrapping on overflow.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_rem_euclid(self, rhs: $Self) -> $Self {
+                    self.overflowing_rem_euclid(rhs).0
+                }
+            }
+
+            comment! {
+                "Overflowing Euclidean remainder.
+
+Returns a [tuple] of the remainder and a [`bool`] indicating whether an
+overflow would occur in the division. On overflow, zero is returned.
+
+# Panics
+
+Panics if `rhs` is zero.
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_rem_euclid(self, rhs: $Self) -> ($Self, bool) {
+                    let (r, overflow) = self.overflowing_rem(rhs);
+                    if if_signed_unsigned!($Signedness, is_neg(r.0), false) {
+                        // |r| < |rhs|, so r + |rhs| is in range after wrapping
+                        let rhs_abs = $Self(rhs.neg_abs().1);
+                        (r.wrapping_add(rhs_abs), overflow)
+                    } else {
+                        (r, overflow)
+                    }
+                }
+            }
+
+            comment! {
+                "Checked shift left. Returns [`None`] if `rhs`&nbsp;≥&nbsp;256.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_shl(self, rhs: u32) -> Option<$Self> {
+                    match self.overflowing_shl(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping shift left. Shifts by `rhs`&nbsp;%&nbsp;256.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_shl(self, rhs: u32) -> $Self {
+                    $Self(int256::wrapping_shl_u256(self.0, rhs % 256))
+                }
+            }
+
+            comment! {
+                "Overflowing shift left.
+
+Returns a [tuple] of the shifted value and a [`bool`] indicating whether
+`rhs`&nbsp;≥&nbsp;256. The value is shifted by `rhs`&nbsp;%&nbsp;256.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_shl(self, rhs: u32) -> ($Self, bool) {
+                    (self.wrapping_shl(rhs), rhs >= 256)
+                }
+            }
+
+            comment! {
+                "Checked shift right. Returns [`None`] if `rhs`&nbsp;≥&nbsp;256.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_shr(self, rhs: u32) -> Option<$Self> {
+                    match self.overflowing_shr(rhs) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping shift right. Shifts by `rhs`&nbsp;%&nbsp;256.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The shift is arithmetic, that is the sign bit is extended.
+"
+                };
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_shr(self, rhs: u32) -> $Self {
+                    let rhs = rhs % 256;
+                    if if_signed_unsigned!($Signedness, is_neg(self.0), false) {
+                        $Self(not(int256::wrapping_shr_u256(not(self.0), rhs)))
+                    } else {
+                        $Self(int256::wrapping_shr_u256(self.0, rhs))
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing shift right.
+
+Returns a [tuple] of the shifted value and a [`bool`] indicating whether
+`rhs`&nbsp;≥&nbsp;256. The value is shifted by `rhs`&nbsp;%&nbsp;256.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_shr(self, rhs: u32) -> ($Self, bool) {
+                    (self.wrapping_shr(rhs), rhs >= 256)
+                }
+            }
+
+            comment! {
+                "Raises `self` to the power of `exp`.
+
+# Panics
+
+When debug assertions are enabled, panics on overflow. Otherwise the wrapped
+value is returned.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+let three = ", stringify!($Self), "::", stringify!($from_prim), "(3);
+assert_eq!(three.pow(4), ", stringify!($Self), "::", stringify!($from_prim), "(81));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn pow(self, exp: u32) -> $Self {
+                    let (ans, overflow) = self.overflowing_pow(exp);
+                    debug_assert!(!overflow, "attempt to multiply with overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked exponentiation. Returns [`None`] on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_pow(self, exp: u32) -> Option<$Self> {
+                    match self.overflowing_pow(exp) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping exponentiation, wrapping on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_pow(self, exp: u32) -> $Self {
+                    self.overflowing_pow(exp).0
+                }
+            }
+
+            comment! {
+                "Saturating exponentiation, saturating on overflow.
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_pow(self, exp: u32) -> $Self {
+                    match self.overflowing_pow(exp) {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if self.neg_abs().0 && exp % 2 == 1 {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing exponentiation.
+
+Returns a [tuple] of the power and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_pow(self, mut exp: u32) -> ($Self, bool) {
+                    let mut acc = $Self(ONE);
+                    if exp == 0 {
+                        return (acc, false);
+                    }
+                    let mut base = self;
+                    let mut overflow = false;
+                    loop {
+                        if exp & 1 == 1 {
+                            let (prod, o) = acc.overflowing_mul(base);
+                            acc = prod;
+                            overflow |= o;
+                            if exp == 1 {
+                                return (acc, overflow);
+                            }
+                        }
+                        exp /= 2;
+                        let (sq, o) = base.overflowing_mul(base);
+                        base = sq;
+                        overflow |= o;
+                    }
+                }
+            }
+
+            comment! {
+                "Converts a string slice in a given base to an integer.
+
+The string is expected to be an optional ",
+                if_signed_unsigned!($Signedness, "`+` or `-`", "`+`"),
+                " sign followed by digits. Leading and trailing whitespace
+represent an error.
+
+# Panics
+
+Panics if `radix` is not in the range from 2 to 36.
+
+# Examples
+
+```rust
+use fixed::", stringify!($Self), ";
+assert_eq!(
+    ", stringify!($Self), "::from_str_radix(\"A\", 16),
+    Ok(", stringify!($Self), "::", stringify!($from_prim), "(10))
+);
+```
+";
+                #[inline]
+                pub fn from_str_radix(src: &str, radix: u32) -> Result<$Self, ParseIntError> {
+                    assert!(
+                        (2..=36).contains(&radix),
+                        "from_str_radix: radix must lie in the range `[2, 36]`"
+                    );
+                    let bytes = src.as_bytes();
+                    let (neg, digits) = match bytes {
+                        [] => return Err(parse_int_error(ParseIntErrorKind::Empty)),
+                        [b'+', rest @ ..] => (false, rest),
+                        [b'-', rest @ ..] if if_signed_unsigned!($Signedness, true, false) => (true, rest),
+                        _ => (false, bytes),
+                    };
+                    let (abs, overflow) = match parse_abs(digits, radix) {
+                        Ok(ok) => ok,
+                        Err(kind) => return Err(parse_int_error(kind)),
+                    };
+                    let overflow = overflow
+                        || if_signed_unsigned!(
+                            $Signedness,
+                            is_neg(abs) && !(neg && eq(abs, MSB)),
+                            false,
+                        );
+                    if overflow {
+                        let kind = if neg {
+                            ParseIntErrorKind::NegOverflow
+                        } else {
+                            ParseIntErrorKind::PosOverflow
+                        };
+                        return Err(parse_int_error(kind));
+                    }
+                    Ok($Self(neg_if(neg, abs)))
+                }
+            }
+        }
+
+        impl $NonZero {
+            comment! {
+                "Creates a non-zero if the given value is not zero.
+";
+                #[inline]
+                #[must_use]
+                pub const fn new(n: $Self) -> Option<$NonZero> {
+                    if n.is_zero() { None } else { Some($NonZero(n)) }
+                }
+            }
+
+            comment! {
+                "Creates a non-zero without checking whether the value is non-zero.
+
+# Safety
+
+The value must not be zero.
+";
+                #[inline]
+                #[must_use]
+                pub const unsafe fn new_unchecked(n: $Self) -> $NonZero {
+                    debug_assert!(!n.is_zero(), "zero");
+                    $NonZero(n)
+                }
+            }
+
+            comment! {
+                "Returns the contained value as a [`", stringify!($Self), "`].
+";
+                #[inline]
+                #[must_use]
+                pub const fn get(self) -> $Self {
+                    self.0
+                }
+            }
+        }
+
+        impl Default for $Self {
+            #[inline]
+            fn default() -> $Self {
+                $Self(U256::ZERO)
+            }
+        }
+
+        impl Hash for $Self {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        impl PartialOrd for $Self {
+            #[inline]
+            fn partial_cmp(&self, other: &$Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $Self {
+            #[inline]
+            fn cmp(&self, other: &$Self) -> Ordering {
+                self.cmp_bits(*other)
+            }
+        }
+
+        impl PartialOrd for $NonZero {
+            #[inline]
+            fn partial_cmp(&self, other: &$NonZero) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $NonZero {
+            #[inline]
+            fn cmp(&self, other: &$NonZero) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl Debug for $NonZero {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl Display for $NonZero {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$NonZero> for $Self {
+            #[inline]
+            fn from(src: $NonZero) -> $Self {
+                src.0
+            }
+        }
+
+        impl TryFrom<$Self> for $NonZero {
+            type Error = TryFromIntError;
+            #[inline]
+            fn try_from(src: $Self) -> Result<$NonZero, TryFromIntError> {
+                $NonZero::new(src).ok_or_else(try_from_int_error)
+            }
+        }
+
+        impl FromStr for $Self {
+            type Err = ParseIntError;
+            #[inline]
+            fn from_str(src: &str) -> Result<$Self, ParseIntError> {
+                $Self::from_str_radix(src, 10)
+            }
+        }
+
+        bits256_op! { $Self, Add add, AddAssign add_assign, overflowing_add, "attempt to add with overflow" }
+        bits256_op! { $Self, Sub sub, SubAssign sub_assign, overflowing_sub, "attempt to subtract with overflow" }
+        bits256_op! { $Self, Mul mul, MulAssign mul_assign, overflowing_mul, "attempt to multiply with overflow" }
+        bits256_op! { $Self, Div div, DivAssign div_assign, overflowing_div, "attempt to divide with overflow", always }
+        bits256_op! {
+            $Self, Rem rem, RemAssign rem_assign, overflowing_rem,
+            "attempt to calculate the remainder with overflow", always
+        }
+        bits256_bitwise! { $Self, BitAnd bitand, BitAndAssign bitand_assign }
+        bits256_bitwise! { $Self, BitOr bitor, BitOrAssign bitor_assign }
+        bits256_bitwise! { $Self, BitXor bitxor, BitXorAssign bitxor_assign }
+        bits256_shift! { $Self, Shl shl, ShlAssign shl_assign, wrapping_shl, "attempt to shift left with overflow" }
+        bits256_shift! { $Self, Shr shr, ShrAssign shr_assign, wrapping_shr, "attempt to shift right with overflow" }
+
+        if_signed! {
+            $Signedness;
+            impl Neg for $Self {
+                type Output = $Self;
+                #[inline]
+                #[track_caller]
+                fn neg(self) -> $Self {
+                    let (ans, overflow) = self.overflowing_neg();
+                    debug_assert!(!overflow, "attempt to negate with overflow");
+                    ans
+                }
+            }
+
+            impl Neg for &$Self {
+                type Output = $Self;
+                #[inline]
+                #[track_caller]
+                fn neg(self) -> $Self {
+                    -*self
+                }
+            }
+        }
+
+        impl Not for $Self {
+            type Output = $Self;
+            #[inline]
+            fn not(self) -> $Self {
+                $Self(not(self.0))
+            }
+        }
+
+        impl Not for &$Self {
+            type Output = $Self;
+            #[inline]
+            fn not(self) -> $Self {
+                !*self
+            }
+        }
+
+        impl Sum<$Self> for $Self {
+            #[track_caller]
+            fn sum<I: Iterator<Item = $Self>>(iter: I) -> $Self {
+                iter.fold($Self(U256::ZERO), Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $Self> for $Self {
+            #[track_caller]
+            fn sum<I: Iterator<Item = &'a $Self>>(iter: I) -> $Self {
+                iter.fold($Self(U256::ZERO), Add::add)
+            }
+        }
+
+        impl Product<$Self> for $Self {
+            #[track_caller]
+            fn product<I: Iterator<Item = $Self>>(iter: I) -> $Self {
+                iter.fold($Self(ONE), Mul::mul)
+            }
+        }
+
+        impl<'a> Product<&'a $Self> for $Self {
+            #[track_caller]
+            fn product<I: Iterator<Item = &'a $Self>>(iter: I) -> $Self {
+                iter.fold($Self(ONE), Mul::mul)
+            }
+        }
+    };
+}
+
+macro_rules! bits256_op {
+    (
+        $Bits:ident, $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident,
+        $overflowing:ident, $msg:literal $(, $always:ident)?
+    ) => {
+        impl $Imp<$Bits> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: $Bits) -> $Bits {
+                let (ans, overflow) = self.$overflowing(rhs);
+                bits256_op!(@check overflow, $msg $(, $always)?);
+                ans
+            }
+        }
+
+        impl $Imp<$Bits> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: $Bits) -> $Bits {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl $Imp<&$Bits> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &$Bits) -> $Bits {
+                self.$method(*rhs)
+            }
+        }
+
+        impl $Imp<&$Bits> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &$Bits) -> $Bits {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl $ImpAssign<$Bits> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: $Bits) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl $ImpAssign<&$Bits> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: &$Bits) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    };
+    (@check $overflow:ident, $msg:literal) => {
+        debug_assert!(!$overflow, $msg);
+    };
+    (@check $overflow:ident, $msg:literal, always) => {
+        assert!(!$overflow, $msg);
+    };
+}
+
+macro_rules! bits256_bitwise {
+    ($Bits:ident, $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident) => {
+        impl $Imp<$Bits> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            fn $method(self, rhs: $Bits) -> $Bits {
+                $Bits(self.0.$method(rhs.0))
+            }
+        }
+
+        impl $Imp<$Bits> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            fn $method(self, rhs: $Bits) -> $Bits {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl $Imp<&$Bits> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            fn $method(self, rhs: &$Bits) -> $Bits {
+                self.$method(*rhs)
+            }
+        }
+
+        impl $Imp<&$Bits> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            fn $method(self, rhs: &$Bits) -> $Bits {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl $ImpAssign<$Bits> for $Bits {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $Bits) {
+                self.0.$method_assign(rhs.0);
+            }
+        }
+
+        impl $ImpAssign<&$Bits> for $Bits {
+            #[inline]
+            fn $method_assign(&mut self, rhs: &$Bits) {
+                self.0.$method_assign(rhs.0);
+            }
+        }
+    };
+}
+
+macro_rules! bits256_shift {
+    (
+        $Bits:ident, $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident,
+        $wrapping:ident, $msg:literal
+    ) => {
+        bits256_shift! {
+            $Bits, $Imp $method, $ImpAssign $method_assign, $wrapping, $msg;
+            i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
+        }
+    };
+    (
+        $Bits:ident, $Imp:ident $method:ident, $ImpAssign:ident $method_assign:ident,
+        $wrapping:ident, $msg:literal; $($Rhs:ident)*
+    ) => { $(
+        impl $Imp<$Rhs> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: $Rhs) -> $Bits {
+                debug_assert!(matches!(rhs.checked_as::<u32>(), Some(0..256)), $msg);
+                // like the primitives, only the low bits of rhs are used when wrapping
+                self.$wrapping(rhs.wrapping_as::<u32>())
+            }
+        }
+
+        impl $Imp<$Rhs> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: $Rhs) -> $Bits {
+                (*self).$method(rhs)
+            }
+        }
+
+        impl $Imp<&$Rhs> for $Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &$Rhs) -> $Bits {
+                self.$method(*rhs)
+            }
+        }
+
+        impl $Imp<&$Rhs> for &$Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn $method(self, rhs: &$Rhs) -> $Bits {
+                (*self).$method(*rhs)
+            }
+        }
+
+        impl $ImpAssign<$Rhs> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: $Rhs) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl $ImpAssign<&$Rhs> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: &$Rhs) {
+                *self = (*self).$method(*rhs);
+            }
+        }
+    )* };
+}
+
+bits256! {
+    description = "A 256",
+    {Self, NonZero, Fixed} = {I256Bits, NonZeroI256Bits, FixedI256},
+    Signedness = Signed,
+    {Prim, from_prim} = {i128, from_i128},
+}
+bits256! {
+    description = "A 256",
+    {Self, NonZero, Fixed} = {U256Bits, NonZeroU256Bits, FixedU256},
+    Signedness = Unsigned,
+    {Prim, from_prim} = {u128, from_u128},
+}
+
+macro_rules! from_prim {
+    ($Bits:ident, $Wide:ident, $from_wide:ident; $($Prim:ident)*) => { $(
+        impl From<$Prim> for $Bits {
+            #[inline]
+            fn from(src: $Prim) -> $Bits {
+                $Bits::$from_wide($Wide::from(src))
+            }
+        }
+    )* };
+}
+
+from_prim! { I256Bits, i128, from_i128; i8 i16 i32 i64 i128 }
+from_prim! { U256Bits, u128, from_u128; u8 u16 u32 u64 u128 }
+
+macro_rules! from_unsigned_for_signed {
+    ($($Prim:ident)*) => { $(
+        impl From<$Prim> for I256Bits {
+            #[inline]
+            fn from(src: $Prim) -> I256Bits {
+                I256Bits(U256 {
+                    lo: u128::from(src),
+                    hi: 0,
+                })
+            }
+        }
+    )* };
+}
+
+from_unsigned_for_signed! { u8 u16 u32 u64 u128 }
+
+macro_rules! try_from_prim {
+    ($Bits:ident, $Wide:ident, $from_wide:ident; $($Prim:ident)*) => { $(
+        impl TryFrom<$Prim> for $Bits {
+            type Error = TryFromIntError;
+            #[inline]
+            fn try_from(src: $Prim) -> Result<$Bits, TryFromIntError> {
+                $Wide::try_from(src).map($Bits::$from_wide)
+            }
+        }
+    )* };
+}
+
+try_from_prim! { I256Bits, i128, from_i128; isize }
+try_from_prim! { U256Bits, u128, from_u128; i8 i16 i32 i64 i128 isize usize }
+
+impl TryFrom<usize> for I256Bits {
+    type Error = TryFromIntError;
+    #[inline]
+    fn try_from(src: usize) -> Result<I256Bits, TryFromIntError> {
+        u128::try_from(src).map(I256Bits::from)
+    }
+}
+
+macro_rules! to_prim {
+    ($($Prim:ident)*) => { $(
+        impl TryFrom<I256Bits> for $Prim {
+            type Error = TryFromIntError;
+            #[inline]
+            fn try_from(src: I256Bits) -> Result<$Prim, TryFromIntError> {
+                // the value fits in i128 if hi is the sign extension of lo
+                if src.0.hi != ((src.0.lo as i128) >> 127) as u128 {
+                    return Err(try_from_int_error());
+                }
+                $Prim::try_from(src.0.lo as i128).map_err(|_| try_from_int_error())
+            }
+        }
+
+        impl TryFrom<U256Bits> for $Prim {
+            type Error = TryFromIntError;
+            #[inline]
+            fn try_from(src: U256Bits) -> Result<$Prim, TryFromIntError> {
+                if src.0.hi != 0 {
+                    return Err(try_from_int_error());
+                }
+                $Prim::try_from(src.0.lo).map_err(|_| try_from_int_error())
+            }
+        }
+    )* };
+}
+
+to_prim! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+impl TryFrom<I256Bits> for U256Bits {
+    type Error = TryFromIntError;
+    #[inline]
+    fn try_from(src: I256Bits) -> Result<U256Bits, TryFromIntError> {
+        if is_neg(src.0) {
+            Err(try_from_int_error())
+        } else {
+            Ok(U256Bits(src.0))
+        }
+    }
+}
+
+impl TryFrom<U256Bits> for I256Bits {
+    type Error = TryFromIntError;
+    #[inline]
+    fn try_from(src: U256Bits) -> Result<I256Bits, TryFromIntError> {
+        if is_neg(src.0) {
+            Err(try_from_int_error())
+        } else {
+            Ok(I256Bits(src.0))
+        }
+    }
+}
+
+macro_rules! cast_prim {
+    ($Bits:ident; $($Prim:ident as $Wide:ident),*) => { $(
+        impl Cast<$Prim> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn cast(self) -> $Prim {
+                let (wrapped, overflow) = self.overflowing_cast();
+                debug_assert!(!overflow, "{} overflows", self);
+                wrapped
+            }
+        }
+
+        impl CheckedCast<$Prim> for $Bits {
+            #[inline]
+            fn checked_cast(self) -> Option<$Prim> {
+                $Prim::try_from(self).ok()
+            }
+        }
+
+        impl SaturatingCast<$Prim> for $Bits {
+            #[inline]
+            fn saturating_cast(self) -> $Prim {
+                match $Prim::try_from(self) {
+                    Ok(val) => val,
+                    Err(_) if self < $Bits::default() => $Prim::MIN,
+                    Err(_) => $Prim::MAX,
+                }
+            }
+        }
+
+        impl WrappingCast<$Prim> for $Bits {
+            #[inline]
+            fn wrapping_cast(self) -> $Prim {
+                self.overflowing_cast().0
+            }
+        }
+
+        impl OverflowingCast<$Prim> for $Bits {
+            #[inline]
+            fn overflowing_cast(self) -> ($Prim, bool) {
+                (self.0.lo as $Prim, $Prim::try_from(self).is_err())
+            }
+        }
+
+        impl UnwrappedCast<$Prim> for $Bits {
+            #[inline]
+            #[track_caller]
+            fn unwrapped_cast(self) -> $Prim {
+                match $Prim::try_from(self) {
+                    Ok(val) => val,
+                    Err(_) => panic!("overflow"),
+                }
+            }
+        }
+
+        impl Cast<$Bits> for $Prim {
+            #[inline]
+            #[track_caller]
+            fn cast(self) -> $Bits {
+                let (wrapped, overflow) = self.overflowing_cast();
+                debug_assert!(!overflow, "{} overflows", self);
+                wrapped
+            }
+        }
+
+        impl CheckedCast<$Bits> for $Prim {
+            #[inline]
+            fn checked_cast(self) -> Option<$Bits> {
+                $Bits::try_from(self).ok()
+            }
+        }
+
+        impl SaturatingCast<$Bits> for $Prim {
+            #[inline]
+            fn saturating_cast(self) -> $Bits {
+                match $Bits::try_from(self) {
+                    Ok(val) => val,
+                    // only negative values can fail to fit
+                    Err(_) => $Bits::MIN,
+                }
+            }
+        }
+
+        impl WrappingCast<$Bits> for $Prim {
+            #[inline]
+            fn wrapping_cast(self) -> $Bits {
+                self.overflowing_cast().0
+            }
+        }
+
+        impl OverflowingCast<$Bits> for $Prim {
+            #[inline]
+            fn overflowing_cast(self) -> ($Bits, bool) {
+                let wrapped = $Bits(I256Bits::from(self as $Wide).0);
+                (wrapped, $Bits::try_from(self).is_err())
+            }
+        }
+
+        impl UnwrappedCast<$Bits> for $Prim {
+            #[inline]
+            #[track_caller]
+            fn unwrapped_cast(self) -> $Bits {
+                match $Bits::try_from(self) {
+                    Ok(val) => val,
+                    Err(_) => panic!("overflow"),
+                }
+            }
+        }
+    )* };
+}
+
+cast_prim! {
+    I256Bits;
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128,
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128
+}
+cast_prim! {
+    U256Bits;
+    i8 as i128, i16 as i128, i32 as i128, i64 as i128, i128 as i128, isize as i128,
+    u8 as u128, u16 as u128, u32 as u128, u64 as u128, u128 as u128, usize as u128
+}
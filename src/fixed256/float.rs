@@ -0,0 +1,792 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int256;
+use crate::int256::{U256, U512};
+
+// The mathematical functions of the 256-bit fixed-point numbers are computed on
+// a floating-point representation with a 512-bit mantissa.
+//
+// A nonzero value is ±mant × 2^(exp − 511), where 2^511 ≤ mant < 2^512, and
+// zero has mant = 0. Each operation truncates its result, so that it has a
+// relative error < 2^−510. The functions use at most a few hundred operations,
+// and they keep their absolute error well below 2^−300 for the arguments that
+// can give a representable result, so only the final rounding to 256 bits is
+// significant.
+//
+// The exponent is clamped so that it cannot overflow; a clamped exponent always
+// leads to a result that overflows or rounds to zero.
+//
+// Series are summed until the terms are < 2^−530.
+
+const MAX_EXP: i32 = 1 << 24;
+const MIN_TERM_EXP: i32 = -530;
+
+const U512_ZERO: U512 = U512 {
+    lo: U256::ZERO,
+    hi: U256::ZERO,
+};
+
+const MANT_ONE: U512 = U512 {
+    lo: U256::ZERO,
+    hi: U256 {
+        lo: 0,
+        hi: 1 << 127,
+    },
+};
+
+/*
+```rust
+use rug::float::Constant;
+use rug::{Float, Integer};
+
+fn mant(val: Float, exp: i32) -> String {
+    let int = (val << (511 - exp)).to_integer().unwrap();
+    let mask = (Integer::from(1) << 128u32) - 1u32;
+    let hex = |i: Integer| {
+        let mut s = format!("0x{i:032X}");
+        for i in 0..7 {
+            s.insert(6 + 5 * i, '_');
+        }
+        s
+    };
+    let limb = |i: u32| hex(Integer::from(&int >> (128 * i)) & &mask);
+    format!(
+        "U512 {{\n    lo: U256 {{\n        lo: {},\n        hi: {},\n    }},\n    \
+         hi: U256 {{\n        lo: {},\n        hi: {},\n    }},\n}}",
+        limb(0),
+        limb(1),
+        limb(2),
+        limb(3),
+    )
+}
+
+fn main() {
+    let ln_2 = Float::with_val(1000, Constant::Log2);
+    let ln_10 = Float::with_val(1000, 10).ln();
+    let frac_pi_2 = Float::with_val(1000, Constant::Pi) >> 1;
+    println!("{}", mant(ln_2, -1));
+    println!("{}", mant(ln_10, 1));
+    println!("{}", mant(frac_pi_2, 0));
+}
+```
+*/
+
+// ln 2 rounded to the nearest.
+const LN_2: Float = Float {
+    neg: false,
+    exp: -1,
+    mant: U512 {
+        lo: U256 {
+            lo: 0xED2E_AE35_C138_2144_2757_3B29_1169_B825,
+            hi: 0xE7B8_7620_6DEB_AC98_5595_52FB_4AFA_1B10,
+        },
+        hi: U256 {
+            lo: 0x40F3_4326_7298_B62D_8A0D_175B_8BAA_FA2B,
+            hi: 0xB172_17F7_D1CF_79AB_C9E3_B398_03F2_F6AF,
+        },
+    },
+};
+
+// ln 10 rounded to the nearest.
+const LN_10: Float = Float {
+    neg: false,
+    exp: 1,
+    mant: U512 {
+        lo: U256 {
+            lo: 0x2C62_2418_410B_E2DA_FB8F_7884_02E5_16D6,
+            hi: 0x962F_02D7_B1A8_105C_CC70_CBC0_2C5F_0D68,
+        },
+        hi: U256 {
+            lo: 0xE28F_ECF9_DA5D_F90E_83C6_1E82_01F0_2D72,
+            hi: 0x935D_8DDD_AAA8_AC16_EA56_D62B_82D3_0A28,
+        },
+    },
+};
+
+// π/2 rounded to the nearest.
+const FRAC_PI_2: Float = Float {
+    neg: false,
+    exp: 0,
+    mant: U512 {
+        lo: U256 {
+            lo: 0x302B_0A6D_F25F_1437_4FE1_356D_6D51_C246,
+            hi: 0x514A_0879_8E34_04DD_EF95_19B3_CD3A_431B,
+        },
+        hi: U256 {
+            lo: 0x2902_4E08_8A67_CC74_020B_BEA6_3B13_9B22,
+            hi: 0xC90F_DAA2_2168_C234_C4C6_628B_80DC_1CD1,
+        },
+    },
+};
+
+const fn clamp_exp(exp: i32) -> i32 {
+    if exp > MAX_EXP {
+        MAX_EXP
+    } else if exp < -MAX_EXP {
+        -MAX_EXP
+    } else {
+        exp
+    }
+}
+
+const fn leading_zeros_u512(a: U512) -> u32 {
+    if int256::is_zero_u256(a.hi) {
+        256 + int256::leading_zeros_u256(a.lo)
+    } else {
+        int256::leading_zeros_u256(a.hi)
+    }
+}
+
+const fn ge_u256(a: U256, b: U256) -> bool {
+    a.hi > b.hi || (a.hi == b.hi && a.lo >= b.lo)
+}
+
+const fn ge_u512(a: U512, b: U512) -> bool {
+    if int256::is_zero_u256(int256::wrapping_sub_u256(a.hi, b.hi)) {
+        ge_u256(a.lo, b.lo)
+    } else {
+        ge_u256(a.hi, b.hi)
+    }
+}
+
+const fn wrapping_sub_u512(a: U512, b: U512) -> U512 {
+    int256::overflowing_add_u512(a, int256::wrapping_neg_u512(b)).0
+}
+
+// Returns the upper 512 bits of the 1024-bit product.
+const fn mul_hi_u512(a: U512, b: U512) -> U512 {
+    let ll = int256::wide_mul_u256(a.lo, b.lo);
+    let lh = int256::wide_mul_u256(a.lo, b.hi);
+    let hl = int256::wide_mul_u256(a.hi, b.lo);
+    let hh = int256::wide_mul_u256(a.hi, b.hi);
+    // The middle column lh + hl + ll.hi has up to 514 bits.
+    let (mid, carry1) = int256::overflowing_add_u512(lh, hl);
+    let (mid, carry2) = int256::overflowing_add_u512(
+        mid,
+        U512 {
+            lo: ll.hi,
+            hi: U256::ZERO,
+        },
+    );
+    let mid_hi = U512 {
+        lo: mid.hi,
+        hi: U256 {
+            lo: carry1 as u128 + carry2 as u128,
+            hi: 0,
+        },
+    };
+    int256::overflowing_add_u512(hh, mid_hi).0
+}
+
+const fn u512_to_limbs(a: U512) -> [u64; 8] {
+    [
+        a.lo.lo as u64,
+        (a.lo.lo >> 64) as u64,
+        a.lo.hi as u64,
+        (a.lo.hi >> 64) as u64,
+        a.hi.lo as u64,
+        (a.hi.lo >> 64) as u64,
+        a.hi.hi as u64,
+        (a.hi.hi >> 64) as u64,
+    ]
+}
+
+const fn limbs_to_u512(l: [u64; 8]) -> U512 {
+    U512 {
+        lo: U256 {
+            lo: l[0] as u128 | ((l[1] as u128) << 64),
+            hi: l[2] as u128 | ((l[3] as u128) << 64),
+        },
+        hi: U256 {
+            lo: l[4] as u128 | ((l[5] as u128) << 64),
+            hi: l[6] as u128 | ((l[7] as u128) << 64),
+        },
+    }
+}
+
+// Returns floor(√(carry × 2^512 + n)), which is < 2^257.
+//
+// This uses the abacus method like the hypot module. When carry is set, the
+// first two iterations are performed directly, as in that module.
+pub const fn isqrt_u512(carry: bool, n: U512) -> U512 {
+    let mut x = n;
+    let mut y;
+    let mut bit;
+    if carry {
+        y = U512 {
+            lo: U256::ZERO,
+            hi: U256 {
+                lo: 0,
+                hi: 1 << 127,
+            },
+        };
+        bit = U512 {
+            lo: U256::ZERO,
+            hi: U256 {
+                lo: 0,
+                hi: 1 << 124,
+            },
+        };
+    } else {
+        let lz = leading_zeros_u512(n);
+        if lz == 512 {
+            return U512_ZERO;
+        }
+        y = U512_ZERO;
+        bit = int256::wrapping_shl_u512(
+            U512 {
+                lo: U256 { lo: 1, hi: 0 },
+                hi: U256::ZERO,
+            },
+            510 - lz / 2 * 2,
+        );
+    }
+    while !int256::is_zero_u512(bit) {
+        let y_plus_bit = int256::overflowing_add_u512(y, bit).0;
+        y = int256::wrapping_shr_u512(y, 1);
+        if ge_u512(x, y_plus_bit) {
+            x = wrapping_sub_u512(x, y_plus_bit);
+            y = int256::overflowing_add_u512(y, bit).0;
+        }
+        bit = int256::wrapping_shr_u512(bit, 2);
+    }
+    y
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Float {
+    neg: bool,
+    exp: i32,
+    mant: U512,
+}
+
+impl Float {
+    pub const ZERO: Float = Float {
+        neg: false,
+        exp: 0,
+        mant: U512_ZERO,
+    };
+
+    pub const ONE: Float = Float {
+        neg: false,
+        exp: 0,
+        mant: MANT_ONE,
+    };
+
+    // Returns ±mant × 2^(exp − 511), where mant does not need to be normalized.
+    const fn normalize(neg: bool, exp: i32, mant: U512) -> Float {
+        let lz = leading_zeros_u512(mant);
+        if lz == 512 {
+            return Float::ZERO;
+        }
+        Float {
+            neg,
+            exp: clamp_exp(exp - lz as i32),
+            mant: int256::wrapping_shl_u512(mant, lz),
+        }
+    }
+
+    // Returns ±abs × 2^−frac_nbits.
+    pub const fn from_neg_abs(neg: bool, abs: U256, frac_nbits: u32) -> Float {
+        let abs = U512 {
+            lo: abs,
+            hi: U256::ZERO,
+        };
+        Float::normalize(neg, 511 - frac_nbits as i32, abs)
+    }
+
+    // Returns bits × 2^−frac_nbits, where bits is two's complement if signed.
+    pub const fn from_bits(bits: U256, frac_nbits: u32, signed: bool) -> Float {
+        if signed && bits.hi >> 127 != 0 {
+            Float::from_neg_abs(true, int256::wrapping_neg_u256(bits), frac_nbits)
+        } else {
+            Float::from_neg_abs(false, bits, frac_nbits)
+        }
+    }
+
+    const fn from_i32(i: i32) -> Float {
+        let abs = U256 {
+            lo: i.unsigned_abs() as u128,
+            hi: 0,
+        };
+        Float::from_neg_abs(i < 0, abs, 0)
+    }
+
+    // Returns self × 2^frac_nbits rounded to the nearest if nearest is true,
+    // otherwise rounded toward zero. The result is two's complement if signed.
+    // Returns the wrapped value and whether it overflowed.
+    pub const fn to_bits(self, frac_nbits: u32, nearest: bool, signed: bool) -> (U256, bool) {
+        if self.is_zero() {
+            return (U256::ZERO, false);
+        }
+        // right shift of mant
+        let sh = 511 - frac_nbits as i32 - self.exp;
+        if sh < -511 {
+            return (U256::ZERO, true);
+        }
+        let (abs, overflow) = if nearest && sh >= 1 {
+            let (twice, _) = int256::shift_abs_u512(self.mant, sh - 1, false);
+            let half = twice.lo.lo & 1 != 0;
+            let abs = int256::wrapping_shr_u512(twice, 1);
+            (if half { int256::inc_u512(abs) } else { abs }, false)
+        } else {
+            int256::shift_abs_u512(self.mant, sh, false)
+        };
+        let (ans, add_overflow) = if signed {
+            int256::overflowing_add_neg_abs_i256(self.neg, abs, U256::ZERO)
+        } else {
+            int256::overflowing_add_neg_abs_u256(self.neg, abs, U256::ZERO)
+        };
+        (ans, overflow || add_overflow)
+    }
+
+    pub const fn is_zero(self) -> bool {
+        int256::is_zero_u512(self.mant)
+    }
+
+    pub const fn neg(self) -> Float {
+        if self.is_zero() {
+            return self;
+        }
+        Float {
+            neg: !self.neg,
+            exp: self.exp,
+            mant: self.mant,
+        }
+    }
+
+    pub const fn abs(self) -> Float {
+        Float {
+            neg: false,
+            exp: self.exp,
+            mant: self.mant,
+        }
+    }
+
+    // Returns whether |self| > 1.
+    pub const fn abs_gt_one(self) -> bool {
+        !self.is_zero() && (self.exp > 0 || (self.exp == 0 && !ge_u512(MANT_ONE, self.mant)))
+    }
+
+    // Returns self × 2^n.
+    const fn mul_pow2(self, n: i32) -> Float {
+        if self.is_zero() {
+            return self;
+        }
+        Float {
+            neg: self.neg,
+            exp: clamp_exp(self.exp + n),
+            mant: self.mant,
+        }
+    }
+
+    // Returns whether |self| ≥ |other|.
+    const fn abs_ge(self, other: Float) -> bool {
+        if other.is_zero() {
+            true
+        } else if self.is_zero() {
+            false
+        } else if self.exp != other.exp {
+            self.exp > other.exp
+        } else {
+            ge_u512(self.mant, other.mant)
+        }
+    }
+
+    pub const fn add(self, other: Float) -> Float {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let (big, small) = if self.abs_ge(other) {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let diff = (big.exp - small.exp) as u32;
+        if diff >= 512 {
+            return big;
+        }
+        let small_mant = int256::wrapping_shr_u512(small.mant, diff);
+        if big.neg == small.neg {
+            let (sum, carry) = int256::overflowing_add_u512(big.mant, small_mant);
+            if carry {
+                let mut mant = int256::wrapping_shr_u512(sum, 1);
+                mant.hi.hi |= 1 << 127;
+                Float {
+                    neg: big.neg,
+                    exp: clamp_exp(big.exp + 1),
+                    mant,
+                }
+            } else {
+                Float {
+                    neg: big.neg,
+                    exp: big.exp,
+                    mant: sum,
+                }
+            }
+        } else {
+            Float::normalize(big.neg, big.exp, wrapping_sub_u512(big.mant, small_mant))
+        }
+    }
+
+    pub const fn sub(self, other: Float) -> Float {
+        self.add(other.neg())
+    }
+
+    pub const fn mul(self, other: Float) -> Float {
+        if self.is_zero() || other.is_zero() {
+            return Float::ZERO;
+        }
+        // 2^510 ≤ prod < 2^512
+        let prod = mul_hi_u512(self.mant, other.mant);
+        let neg = self.neg != other.neg;
+        let exp = self.exp + other.exp;
+        if prod.hi.hi >> 127 != 0 {
+            Float {
+                neg,
+                exp: clamp_exp(exp + 1),
+                mant: prod,
+            }
+        } else {
+            Float {
+                neg,
+                exp: clamp_exp(exp),
+                mant: int256::wrapping_shl_u512(prod, 1),
+            }
+        }
+    }
+
+    // other must not be zero.
+    pub const fn div(self, other: Float) -> Float {
+        if self.is_zero() {
+            return Float::ZERO;
+        }
+        // long division for q = floor(self.mant × 2^511 / other.mant), where
+        // 2^510 < q < 2^512
+        let d = other.mant;
+        let mut rem = self.mant;
+        let mut rem_carry = false;
+        let mut q = U512_ZERO;
+        let mut i = 0;
+        while i < 512 {
+            q = int256::wrapping_shl_u512(q, 1);
+            if rem_carry || ge_u512(rem, d) {
+                rem = wrapping_sub_u512(rem, d);
+                q.lo.lo |= 1;
+            }
+            rem_carry = rem.hi.hi >> 127 != 0;
+            rem = int256::wrapping_shl_u512(rem, 1);
+            i += 1;
+        }
+        Float::normalize(self.neg != other.neg, self.exp - other.exp, q)
+    }
+
+    // d must not be zero.
+    const fn div_u32(self, d: u32) -> Float {
+        if self.is_zero() {
+            return self;
+        }
+        let d = d as u64;
+        let mut limbs = u512_to_limbs(self.mant);
+        let mut rem = 0u64;
+        let mut i = 8;
+        while i > 0 {
+            i -= 1;
+            let cur = ((rem as u128) << 64) | limbs[i] as u128;
+            limbs[i] = (cur / d as u128) as u64;
+            rem = (cur % d as u128) as u64;
+        }
+        // q ≥ 2^511 / d > 2^479, so lz ≤ 32, and the lowest lz bits are filled
+        // in from the remainder
+        let q = limbs_to_u512(limbs);
+        let lz = leading_zeros_u512(q);
+        let mut mant = int256::wrapping_shl_u512(q, lz);
+        if lz > 0 {
+            mant.lo.lo |= ((rem << lz) / d) as u128;
+        }
+        Float {
+            neg: self.neg,
+            exp: clamp_exp(self.exp - lz as i32),
+            mant,
+        }
+    }
+
+    // self must not be negative.
+    pub const fn sqrt(self) -> Float {
+        if self.is_zero() {
+            return self;
+        }
+        // self = x × 2^(2k), where 1 ≤ x < 4
+        let k = self.exp >> 1;
+        let x = Float {
+            neg: false,
+            exp: self.exp - 2 * k,
+            mant: self.mant,
+        };
+        // Newton iterations starting from 1.25 have a relative error
+        // ≤ 0.375, which is squared and halved by each iteration.
+        let mut y = Float {
+            neg: false,
+            exp: 0,
+            mant: U512 {
+                lo: U256::ZERO,
+                hi: U256 {
+                    lo: 0,
+                    hi: 0b101 << 125,
+                },
+            },
+        };
+        let mut i = 0;
+        while i < 9 {
+            y = y.add(x.div(y)).mul_pow2(-1);
+            i += 1;
+        }
+        y.mul_pow2(k)
+    }
+
+    pub const fn recip(self) -> Float {
+        Float::ONE.div(self)
+    }
+}
+
+// Returns e^x.
+pub const fn exp(x: Float) -> Float {
+    if x.is_zero() {
+        return Float::ONE;
+    }
+    // For |x| ≥ 2^25, the result overflows or rounds to zero.
+    if x.exp >= 25 {
+        if x.neg {
+            return Float::ZERO;
+        }
+        return Float {
+            neg: false,
+            exp: MAX_EXP,
+            mant: MANT_ONE,
+        };
+    }
+    // x = k ln 2 + r, where |r| ≤ ln 2 / 2
+    let (k, _) = x.div(LN_2).to_bits(0, true, true);
+    let k = k.lo as i32;
+    let r = x.sub(Float::from_i32(k).mul(LN_2));
+    // e^r = (e^(r / 2^8))^(2^8)
+    let r = r.mul_pow2(-8);
+    let mut sum = Float::ONE;
+    let mut term = Float::ONE;
+    let mut i = 1;
+    loop {
+        term = term.mul(r).div_u32(i);
+        if term.is_zero() || term.exp < MIN_TERM_EXP {
+            break;
+        }
+        sum = sum.add(term);
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 8 {
+        sum = sum.mul(sum);
+        i += 1;
+    }
+    sum.mul_pow2(k)
+}
+
+// Returns ln x, where x > 0.
+pub const fn ln(x: Float) -> Float {
+    // x = m × 2^e, where 3/4 ≤ m < 3/2
+    let (m, e) = if x.mant.hi.hi >> 126 == 0b11 {
+        (-1, x.exp + 1)
+    } else {
+        (0, x.exp)
+    };
+    let m = Float {
+        neg: false,
+        exp: m,
+        mant: x.mant,
+    };
+    // ln m = 2 atanh t, where t = (m − 1) / (m + 1) and −1/7 < t ≤ 1/5
+    let t = m.sub(Float::ONE).div(m.add(Float::ONE));
+    let t2 = t.mul(t);
+    let mut sum = t;
+    let mut power = t;
+    let mut i = 3;
+    loop {
+        power = power.mul(t2);
+        if power.is_zero() || power.exp < MIN_TERM_EXP {
+            break;
+        }
+        sum = sum.add(power.div_u32(i));
+        i += 2;
+    }
+    sum.mul_pow2(1).add(Float::from_i32(e).mul(LN_2))
+}
+
+// Returns log₂ x, where x > 0.
+pub const fn log2(x: Float) -> Float {
+    ln(x).div(LN_2)
+}
+
+// Returns log₁₀ x, where x > 0.
+pub const fn log10(x: Float) -> Float {
+    ln(x).div(LN_10)
+}
+
+// Returns 2^x.
+pub const fn exp2(x: Float) -> Float {
+    exp(x.mul(LN_2))
+}
+
+// Returns x^n, where x ≠ 0.
+pub const fn powi(x: Float, n: i32) -> Float {
+    let mut base = if n < 0 { x.recip() } else { x };
+    let mut rem = n.unsigned_abs();
+    let mut acc = Float::ONE;
+    loop {
+        if rem & 1 != 0 {
+            acc = acc.mul(base);
+        }
+        rem >>= 1;
+        if rem == 0 {
+            return acc;
+        }
+        base = base.mul(base);
+    }
+}
+
+// Returns x^y, where x > 0, or x = 0 and y = 0.
+pub const fn powf(x: Float, y: Float) -> Float {
+    if y.is_zero() {
+        return Float::ONE;
+    }
+    exp(y.mul(ln(x)))
+}
+
+// Returns the nth root of x, where x ≠ 0, n > 0, and x > 0 if n is even.
+pub const fn root(x: Float, n: u32) -> Float {
+    let abs = exp(ln(x.abs()).div_u32(n));
+    if x.neg { abs.neg() } else { abs }
+}
+
+// Returns (sin x, cos x), where |x| < 2^256.
+pub const fn sin_cos(x: Float) -> (Float, Float) {
+    // x = k π/2 + r, where |r| ≤ π/4; since |k| < 2^255, k fits
+    let (k, _) = x.div(FRAC_PI_2).to_bits(0, true, true);
+    let k_float = Float::from_bits(k, 0, true);
+    let r = x.sub(k_float.mul(FRAC_PI_2));
+    let r2 = r.mul(r);
+    let mut sin = r;
+    let mut term = r;
+    let mut i = 2;
+    loop {
+        term = term.mul(r2).div_u32(i * (i + 1)).neg();
+        if term.is_zero() || term.exp < MIN_TERM_EXP {
+            break;
+        }
+        sin = sin.add(term);
+        i += 2;
+    }
+    let mut cos = Float::ONE;
+    let mut term = Float::ONE;
+    let mut i = 1;
+    loop {
+        term = term.mul(r2).div_u32(i * (i + 1)).neg();
+        if term.is_zero() || term.exp < MIN_TERM_EXP {
+            break;
+        }
+        cos = cos.add(term);
+        i += 2;
+    }
+    match k.lo & 3 {
+        0 => (sin, cos),
+        1 => (cos, sin.neg()),
+        2 => (sin.neg(), cos.neg()),
+        _ => (cos.neg(), sin),
+    }
+}
+
+// Returns atan x.
+pub const fn atan(x: Float) -> Float {
+    if x.is_zero() {
+        return x;
+    }
+    // atan |x| = π/2 − atan (1/|x|)
+    let recip = x.exp >= 0;
+    let mut a = if recip { x.abs().recip() } else { x.abs() };
+    // halve the angle three times, so that a ≤ tan(π/32) < 0.1, using
+    // atan a = 2 atan (a / (1 + √(1 + a²)))
+    let mut i = 0;
+    while i < 3 {
+        let hyp = Float::ONE.add(a.mul(a)).sqrt();
+        a = a.div(Float::ONE.add(hyp));
+        i += 1;
+    }
+    let a2 = a.mul(a);
+    let mut sum = a;
+    let mut power = a;
+    let mut i = 3;
+    loop {
+        power = power.mul(a2).neg();
+        if power.is_zero() || power.exp < MIN_TERM_EXP {
+            break;
+        }
+        sum = sum.add(power.div_u32(i));
+        i += 2;
+    }
+    let mut ans = sum.mul_pow2(3);
+    if recip {
+        ans = FRAC_PI_2.sub(ans);
+    }
+    if x.neg { ans.neg() } else { ans }
+}
+
+// Returns the angle of the point (x, y), in the range −π < θ ≤ π.
+pub const fn atan2(y: Float, x: Float) -> Float {
+    if x.is_zero() {
+        if y.is_zero() {
+            return Float::ZERO;
+        }
+        return if y.neg { FRAC_PI_2.neg() } else { FRAC_PI_2 };
+    }
+    let angle = atan(y.abs().div(x.abs()));
+    let angle = if x.neg {
+        FRAC_PI_2.mul_pow2(1).sub(angle)
+    } else {
+        angle
+    };
+    if y.neg { angle.neg() } else { angle }
+}
+
+// Returns (asin x, acos x), where |x| ≤ 1.
+pub const fn asin_acos(x: Float) -> (Float, Float) {
+    let cos = Float::ONE.sub(x).mul(Float::ONE.add(x)).sqrt();
+    (atan2(x, cos), atan2(cos, x))
+}
+
+// Returns (sinh x, cosh x, tanh x).
+pub const fn hyperbolic(x: Float) -> (Float, Float, Float) {
+    // use |x|, since e^x can underflow to zero for large negative x
+    let pos = exp(x.abs());
+    let neg = pos.recip();
+    let (diff, sum) = (pos.sub(neg), pos.add(neg));
+    let (sinh, cosh, tanh) = (diff.mul_pow2(-1), sum.mul_pow2(-1), diff.div(sum));
+    if x.neg {
+        (sinh.neg(), cosh, tanh.neg())
+    } else {
+        (sinh, cosh, tanh)
+    }
+}
@@ -0,0 +1,2624 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Arithmetic and conversion methods of the 256-bit fixed-point numbers that
+// depend on the number of fractional bits. This macro is expanded inside the
+// impl<Frac: LeEqU256> block of the fixed256! macro.
+macro_rules! fixed256_frac {
+    (
+        {Self, ISelf, USelf} = {$Self:ident, $ISelf:ident, $USelf:ident},
+        Signedness = $Signedness:ident,
+        {Bits, from_prim} = {$Bits:ident, $from_prim:ident},
+    ) => {
+        /// Creates a fixed-point number from a fixed-point number with the same
+        /// underlying integer type. Usable in constant context.
+        ///
+        /// This is equivalent to the [`unwrapped_from_num`] method with
+        #[doc = concat!("<code>[", stringify!($Self), "]&lt;SrcFrac></code>")]
+        /// as its generic parameter, but can also be used in constant context.
+        /// Unless required in constant context, use [`unwrapped_from_num`] or
+        /// [`from_num`] instead.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value does not fit.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use fixed::types::extra::{U2, U4};
+        #[doc = concat!("use fixed::", stringify!($Self), ";")]
+        #[doc = concat!("type FixA = ", stringify!($Self), "<U2>;")]
+        #[doc = concat!("type FixB = ", stringify!($Self), "<U4>;")]
+        /// const A: FixA = FixA::unwrapped_from_str("3.5");
+        /// const B: FixB = FixB::const_from_fixed(A);
+        /// assert_eq!(B, 3.5);
+        /// ```
+        ///
+        /// The following would fail to compile because of overflow.
+        ///
+        /// ```rust,compile_fail
+        /// use fixed::types::extra::{U2, U4};
+        #[doc = concat!("use fixed::", stringify!($Self), ";")]
+        #[doc = concat!(
+            "const _OVERFLOW: ", stringify!($Self), "<U4> = ", stringify!($Self), "::const_from_fixed(", stringify!($Self), "::<U2>::MAX);"
+        )]
+        /// ```
+        ///
+        /// [`from_num`]: Self::from_num
+        /// [`unwrapped_from_num`]: Self::unwrapped_from_num
+        #[inline]
+        #[track_caller]
+        #[must_use]
+        pub const fn const_from_fixed<SrcFrac: LeEqU256>(src: $Self<SrcFrac>) -> $Self<Frac> {
+            let shift_left = Frac::I32 - SrcFrac::I32;
+            if shift_left <= 0 {
+                // at most 256 fractional bits are dropped
+                let shift_right = shift_left.unsigned_abs();
+                if shift_right == 256 {
+                    let neg = Self::IS_SIGNED && is_neg(src.to_u256());
+                    return $Self::from_u256(if neg { U256::MAX } else { U256::ZERO });
+                }
+                return $Self::from_bits(src.to_bits().wrapping_shr(shift_right));
+            }
+            let src_bits = src.to_u256();
+            let (bits, overflow) = overflowing_shl(src_bits, shift_left.unsigned_abs());
+            // for signed numbers, the shift must not change the sign either
+            if overflow || (Self::IS_SIGNED && is_neg(bits) != is_neg(src_bits)) {
+                panic!("overflow");
+            }
+            $Self::from_u256(bits)
+        }
+
+        /// Creates a fixed-point number from the underlying integer type
+        #[doc = concat!("[`", stringify!($Bits), "`].")]
+        /// Usable in constant context.
+        ///
+        /// This is equivalent to the [`unwrapped_from_num`] method with an
+        /// integer, but can also be used in constant context. Unless required
+        /// in constant context, use [`unwrapped_from_num`] or [`from_num`]
+        /// instead.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value does not fit.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use fixed::types::extra::U4;
+        #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};")]
+        #[doc = concat!("type Fix = ", stringify!($Self), "<U4>;")]
+        #[doc = concat!("const FIVE: Fix = Fix::const_from_int(", fixed256_int!($Bits, $from_prim, "5"), ");")]
+        /// assert_eq!(FIVE, 5);
+        /// ```
+        ///
+        /// The following would fail to compile because of overflow.
+        ///
+        /// ```rust,compile_fail
+        /// use fixed::types::extra::U4;
+        #[doc = concat!("use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};")]
+        #[doc = concat!(
+            "const _OVERFLOW: ", stringify!($Self), "<U4> = ",
+            stringify!($Self), "::const_from_int(",
+            stringify!($Bits), "::MAX);"
+        )]
+        /// ```
+        ///
+        /// [`from_num`]: Self::from_num
+        /// [`unwrapped_from_num`]: Self::unwrapped_from_num
+        #[inline]
+        #[track_caller]
+        #[must_use]
+        pub const fn const_from_int(src: $Bits) -> $Self<Frac> {
+            Self::const_from_fixed($Self::<U0>::from_bits(src))
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Creates a fixed-point number from the ratio of two integers",
+            "creation from the ratio of two integers", "fixed-point number",
+            "The quotient `num`&nbsp;/&nbsp;`den` is rounded to the nearest, with ties
+rounded to even. The division uses a wide intermediate, so the result is
+correctly rounded for all `num` and `den`.
+
+";
+            fn [const] [] [] (num: $Bits, den: $Bits) -> $Self<Frac>;
+            panics = ["Panics if `den` is zero."];
+            imports = [$Bits];
+            plain = from_ratio (wrapping_from_ratio) [
+                "// 3/8 is 0.011 in binary\n",
+                "let (three, eight) = (", fixed256_int!($Bits, $from_prim, "3"), ", ",
+                fixed256_int!($Bits, $from_prim, "8"), ");\n",
+                "assert_eq!(Fix::from_ratio(three, eight), Fix::from_num(0.375));\n",
+                "// 1/3 × 16 = 5.33…, which rounds to 5\n",
+                "let (one, three) = (", fixed256_int!($Bits, $from_prim, "1"), ", ",
+                fixed256_int!($Bits, $from_prim, "3"), ");\n",
+                "assert_eq!(Fix::from_ratio(one, three), Fix::from_bits(", fixed256_int!($Bits, $from_prim, "5"), "));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_ratio(one, three.wrapping_neg()), Fix::from_bits(", fixed256_int!($Bits, $from_prim, "-5"), "));\n",
+                ),
+            ];
+            checked = checked_from_ratio if den.is_zero(), "if the value does not fit or if `den` is zero" [
+                "let (one, three) = (", fixed256_int!($Bits, $from_prim, "1"), ", ",
+                fixed256_int!($Bits, $from_prim, "3"), ");\n",
+                "assert_eq!(Fix::checked_from_ratio(one, three), Some(Fix::from_bits(", fixed256_int!($Bits, $from_prim, "5"), ")));\n",
+                "assert_eq!(Fix::checked_from_ratio(one, ", fixed256_int!($Bits, $from_prim, "0"),
+                "), None);\n",
+                "assert_eq!(Fix::checked_from_ratio(Fix::MAX.to_bits(), one), None);\n",
+            ];
+            saturating = saturating_from_ratio => $Self::saturated(num.neg_abs().0 != den.neg_abs().0); [
+                "let (one, three) = (", fixed256_int!($Bits, $from_prim, "1"), ", ",
+                fixed256_int!($Bits, $from_prim, "3"), ");\n",
+                "assert_eq!(Fix::saturating_from_ratio(one, three), Fix::from_bits(", fixed256_int!($Bits, $from_prim, "5"), "));\n",
+                "assert_eq!(Fix::saturating_from_ratio(Fix::MAX.to_bits(), one), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::saturating_from_ratio(Fix::MAX.to_bits(), one.wrapping_neg()), Fix::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_from_ratio [
+                "let (one, three) = (", fixed256_int!($Bits, $from_prim, "1"), ", ",
+                fixed256_int!($Bits, $from_prim, "3"), ");\n",
+                "assert_eq!(Fix::wrapping_from_ratio(one, three), Fix::from_bits(", fixed256_int!($Bits, $from_prim, "5"), "));\n",
+                "let wrapped = Fix::from_bits(Fix::MAX.to_bits() << 4);\n",
+                "assert_eq!(Fix::wrapping_from_ratio(Fix::MAX.to_bits(), one), wrapped);\n",
+            ];
+            overflowing = overflowing_from_ratio [
+                "let (one, three) = (", fixed256_int!($Bits, $from_prim, "1"), ", ",
+                fixed256_int!($Bits, $from_prim, "3"), ");\n",
+                "assert_eq!(Fix::overflowing_from_ratio(one, three), (Fix::from_bits(", fixed256_int!($Bits, $from_prim, "5"), "), false));\n",
+                "let wrapped = Fix::from_bits(Fix::MAX.to_bits() << 4);\n",
+                "assert_eq!(Fix::overflowing_from_ratio(Fix::MAX.to_bits(), one), (wrapped, true));\n",
+            ] {
+                let (num_neg, num_abs) = num.neg_abs();
+                let (den_neg, den_abs) = den.neg_abs();
+                if int256::is_zero_u256(den_abs) {
+                    panic!("division by zero");
+                }
+                let (bits, overflow) = ratio::overflowing_div_bits(
+                    num_neg != den_neg,
+                    num_abs,
+                    den_abs,
+                    Self::FRAC_NBITS,
+                    256,
+                    Self::IS_SIGNED,
+                );
+                ($Self::from_u256(bits), overflow)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. Unlike
+[`from_num`], which discards the extra fractional bits of fixed-point numbers
+and rounds floating-point numbers to the nearest, this method rounds any value
+that cannot be represented exactly using `mode`.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_from_num_round`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+
+// 1.03125 is 1.00001 in binary, exactly between 1 and 1.0625
+let src = I16F16::from_bits(0b100001 << (16 - 5));
+assert_eq!(Fix::from_num_round(src, RoundingMode::NearestEven), 1);
+assert_eq!(Fix::from_num_round(src, RoundingMode::NearestAway), 1.0625);
+assert_eq!(Fix::from_num_round(src, RoundingMode::Floor), 1);
+
+// 0.1 × 16 = 1.6
+assert_eq!(Fix::from_num_round(0.1f32, RoundingMode::TowardZero), Fix::DELTA);
+assert_eq!(Fix::from_num_round(0.1f32, RoundingMode::Ceil), Fix::from_num(0.125));
+```
+
+[`from_num`]: Self::from_num
+[`wrapping_from_num_round`]: Self::wrapping_from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> $Self<Frac> {
+                let (wrapped, overflow) = overflowing_convert_round(src, mode);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, if it fits, otherwise returns [`None`].
+
+See [`from_num_round`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::checked_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Some(Fix::from_num(0.125)));
+assert!(Fix::checked_from_num_round(1e80, RoundingMode::Floor).is_none());
+assert!(Fix::checked_from_num_round(f64::NAN, RoundingMode::Floor).is_none());
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> Option<$Self<Frac>> {
+                checked_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, and saturating if it does not fit.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::saturating_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Fix::from_num(0.125));
+assert_eq!(Fix::saturating_from_num_round(1e80, RoundingMode::Floor), Fix::MAX);
+assert_eq!(Fix::saturating_from_num_round(f64::NEG_INFINITY, RoundingMode::Ceil), Fix::MIN);
+```
+
+[`from_num_round`]: Self::from_num_round
+[NaN]: f64::is_nan
+";
+            #[inline]
+            pub fn saturating_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> $Self<Frac> {
+                saturating_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, and wrapping the value on overflow.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U4};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::wrapping_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Fix::from_num(0.125));
+// MAX with two fractional bits has two integer bits too many
+let src = ", stringify!($Self), r#"::<U2>::MAX;
+let wrapped = Fix::from_bits(src.to_bits() << 2);
+assert_eq!(Fix::wrapping_from_num_round(src, RoundingMode::Floor), wrapped);
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+"#;
+            #[inline]
+            pub fn wrapping_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> $Self<Frac> {
+                overflowing_convert_round(src, mode).0
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U4};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::overflowing_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, (Fix::from_num(0.125), false));
+// MAX with two fractional bits has two integer bits too many
+let src = ", stringify!($Self), r#"::<U2>::MAX;
+let wrapped = Fix::from_bits(src.to_bits() << 2);
+let floor = Fix::overflowing_from_num_round(src, RoundingMode::Floor);
+assert_eq!(floor, (wrapped, true));
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+"#;
+            #[inline]
+            pub fn overflowing_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`FromFixed`] is implemented. Unlike
+[`to_num`], which discards the extra fractional bits when converting to
+fixed-point numbers and integers and rounds to the nearest when converting to
+floating-point numbers, this method rounds any value that cannot be represented
+exactly using `mode`.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_to_num_round`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I30F2;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_num(2.5);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestEven), 2);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestAway), 3);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Ceil), 3);
+
+// 0.0625 is 0.0001 in binary
+let small = Fix::DELTA;
+assert_eq!(small.to_num_round::<I30F2>(RoundingMode::Floor), 0);
+assert_eq!(small.to_num_round::<I30F2>(RoundingMode::Ceil), 0.25);
+```
+
+[`to_num`]: Self::to_num
+[`wrapping_to_num_round`]: Self::wrapping_to_num_round
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, if it fits, otherwise returns [`None`].
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let two_point_5 = Fix::from_num(2.5);
+assert_eq!(two_point_5.checked_to_num_round::<u8>(RoundingMode::Ceil), Some(3));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+assert!(Fix::MAX.checked_to_num_round::<TooFewIntBits>(RoundingMode::Floor).is_none());
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst> {
+                Dst::checked_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, and saturating if it does not fit.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let two_point_5 = Fix::from_num(2.5);
+assert_eq!(two_point_5.saturating_to_num_round::<u8>(RoundingMode::Ceil), 3);
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let saturated = Fix::MAX.saturating_to_num_round::<TooFewIntBits>(RoundingMode::Floor);
+assert_eq!(saturated, TooFewIntBits::MAX);
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::saturating_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, and wrapping the value on overflow.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let two_point_5 = Fix::from_num(2.5);
+assert_eq!(two_point_5.wrapping_to_num_round::<u8>(RoundingMode::Ceil), 3);
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+assert_eq!(Fix::MAX.wrapping_to_num_round::<TooFewIntBits>(RoundingMode::Floor), wrapped);
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::wrapping_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let two_point_5 = Fix::from_num(2.5);
+let ceil = two_point_5.overflowing_to_num_round::<u8>(RoundingMode::Ceil);
+assert_eq!(ceil, (3, false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let floor = Fix::MAX.overflowing_to_num_round::<TooFewIntBits>(RoundingMode::Floor);
+assert_eq!(floor, (wrapped, true));
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            pub fn overflowing_to_num_round<Dst: FromFixed>(
+                self,
+                mode: RoundingMode,
+            ) -> (Dst, bool) {
+                Dst::overflowing_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            "Unwrapped remainder. Returns the remainder, panicking if the divisor is zero.
+
+The remainder has the same sign as the dividend.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3.75).unwrapped_rem(Fix::ONE), Fix::from_num(0.75));
+```
+
+The following panics because the divisor is zero.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _divisor_is_zero = Fix::from_num(3.75).unwrapped_rem(Fix::ZERO);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_rem(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                match self.checked_rem(rhs) {
+                    Some(ans) => ans,
+                    None => panic!("division by zero"),
+                }
+            }
+        }
+
+        comment! {
+            "Remainder for Euclidean division.
+
+The remainder is never negative.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(1.5));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-7.5).rem_euclid(Fix::from_num(2)), Fix::from_num(0.5));
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn rem_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                match self.checked_rem_euclid(rhs) {
+                    Some(ans) => ans,
+                    None => panic!("division by zero"),
+                }
+            }
+        }
+
+        comment! {
+            "Checked remainder for Euclidean division. Returns the
+remainder, or [`None`] if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let num = Fix::from_num(7.5);
+assert_eq!(num.checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(1.5)));
+assert_eq!(num.checked_rem_euclid(Fix::ZERO), None);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!((-num).checked_rem_euclid(Fix::from_num(2)), Some(Fix::from_num(0.5)));
+",
+            },
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_rem_euclid(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                let Some(rem) = self.checked_rem(rhs) else {
+                    return None;
+                };
+                if Self::IS_SIGNED && is_neg(rem.to_u256()) {
+                    // |rem| < |rhs|, so rem + |rhs| is in range
+                    let (ans, _) = int256::overflowing_add_u256(rem.to_u256(), rhs.neg_abs().1);
+                    return Some($Self::from_u256(ans));
+                }
+                Some(rem)
+            }
+        }
+
+        comment! {
+            "Unwrapped remainder for Euclidean division. Returns the
+remainder, panicking if the divisor is zero.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let num = Fix::from_num(7.5);
+assert_eq!(num.unwrapped_rem_euclid(Fix::from_num(2)), Fix::from_num(1.5));
+```
+
+The following panics because the divisor is zero.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _divisor_is_zero = Fix::from_num(3).unwrapped_rem_euclid(Fix::ZERO);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_rem_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                match self.checked_rem_euclid(rhs) {
+                    Some(ans) => ans,
+                    None => panic!("division by zero"),
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Euclidean division", "Euclidean division", "quotient", "";
+            fn [const] [] [self] (rhs: $Self<Frac>) -> $Self<Frac>;
+            panics = ["Panics if the divisor is zero."];
+            imports = [];
+            plain = div_euclid (wrapping_div_euclid) [
+                "assert_eq!(Fix::from_num(7.5).div_euclid(Fix::from_num(2)), Fix::from_num(3));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).div_euclid(Fix::from_num(2)), Fix::from_num(-4));\n",
+                ),
+            ];
+            checked = checked_div_euclid if rhs.is_zero(), "if the divisor is zero or on overflow" [
+                "let num = Fix::from_num(7.5);\n",
+                "assert_eq!(num.checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(3)));\n",
+                "assert_eq!(num.checked_div_euclid(Fix::ZERO), None);\n",
+                "assert_eq!(Fix::MAX.checked_div_euclid(Fix::from_num(0.25)), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-num).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(-4)));\n",
+                ),
+            ];
+            saturating = saturating_div_euclid => $Self::saturated(
+                Self::IS_SIGNED && is_neg(self.to_u256()) != is_neg(rhs.to_u256())
+            ); [
+                "let num = Fix::from_num(7.5);\n",
+                "assert_eq!(num.saturating_div_euclid(Fix::from_num(2)), Fix::from_num(3));\n",
+                "let quarter = Fix::from_num(0.25);\n",
+                "assert_eq!(Fix::MAX.saturating_div_euclid(quarter), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-num).saturating_div_euclid(Fix::from_num(2)), Fix::from_num(-4));\n",
+                    "assert_eq!(Fix::MIN.saturating_div_euclid(quarter), Fix::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_div_euclid [
+                "let num = Fix::from_num(7.5);\n",
+                "assert_eq!(num.wrapping_div_euclid(Fix::from_num(2)), Fix::from_num(3));\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4)).round_to_zero();\n",
+                "assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);\n",
+            ];
+            unwrapped = unwrapped_div_euclid [
+                "let num = Fix::from_num(7.5);\n",
+                "assert_eq!(num.unwrapped_div_euclid(Fix::from_num(2)), Fix::from_num(3));\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_div_euclid(Fix::from_num(0.25));\n",
+            ];
+            overflowing = overflowing_div_euclid [
+                "let check = Fix::from_num(3);\n",
+                "assert_eq!(Fix::from_num(7.5).overflowing_div_euclid(Fix::from_num(2)), (check, false));\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4)).round_to_zero();\n",
+                "assert_eq!(Fix::MAX.overflowing_div_euclid(Fix::from_num(0.25)), (wrapped, true));\n",
+            ] {
+                let (mut q, overflow) = self.overflowing_div(rhs);
+                q = q.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if self.unwrapped_rem(rhs).is_negative() {
+                        let Some(neg_one) = Self::TRY_NEG_ONE else {
+                            return (q, true);
+                        };
+                        let (q, overflow2) = if rhs.is_positive() {
+                            q.overflowing_add(neg_one)
+                        } else {
+                            q.overflowing_sub(neg_one)
+                        };
+                        return (q, overflow | overflow2);
+                    }
+                }
+                (q, overflow)
+            }
+        }
+
+        comment! {
+            "Checked fixed-point remainder for division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let two = ", fixed256_int!($Bits, $from_prim, "2"), ";
+assert_eq!(Fix::from_num(3.75).checked_rem_int(two), Some(Fix::from_num(1.75)));
+assert_eq!(Fix::from_num(3.75).checked_rem_int(", fixed256_int!($Bits, $from_prim, "0"), "), None);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-3.75).checked_rem_int(two), Some(Fix::from_num(-1.75)));
+",
+            },
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_rem_int(self, rhs: $Bits) -> Option<$Self<Frac>> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                let (neg, abs) = self.neg_abs();
+                // the divisor as a fixed-point number can need up to 512 bits
+                let d = U512 {
+                    lo: rhs.neg_abs().1,
+                    hi: U256::ZERO,
+                };
+                let d = int256::wrapping_shl_u512(d, Self::FRAC_NBITS);
+                let rem = if int256::is_zero_u256(d.hi) {
+                    int256::div_rem_u256(abs, d.lo).1
+                } else {
+                    abs
+                };
+                Some($Self::from_u256(neg_if(neg, rem)))
+            }
+        }
+
+        comment! {
+            "Unwrapped fixed-point remainder for division by an integer.
+Returns the remainder, panicking if the divisor is zero.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let two = ", fixed256_int!($Bits, $from_prim, "2"), ";
+assert_eq!(Fix::from_num(3.75).unwrapped_rem_int(two), Fix::from_num(1.75));
+```
+
+The following panics because the divisor is zero.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let _divisor_is_zero = Fix::from_num(3.75).unwrapped_rem_int(", fixed256_int!($Bits, $from_prim, "0"), ");
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_rem_int(self, rhs: $Bits) -> $Self<Frac> {
+                match self.checked_rem_int(rhs) {
+                    Some(ans) => ans,
+                    None => panic!("division by zero"),
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Euclidean division by an integer", "Euclidean division by an integer", "quotient",
+            if_signed_unsigned!(
+                $Signedness,
+                "Overflow can only occur when dividing the minimum value by &minus;1.
+
+",
+                "Overflow cannot occur for unsigned values.
+
+",
+            );
+            fn [const] [] [self] (rhs: $Bits) -> $Self<Frac>;
+            panics = ["Panics if the divisor is zero."];
+            imports = [$Bits];
+            plain = div_euclid_int (wrapping_div_euclid_int) [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).div_euclid_int(two), Fix::from_num(3));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).div_euclid_int(two), Fix::from_num(-4));\n",
+                ),
+            ];
+            checked = checked_div_euclid_int if rhs.is_zero(), "if the divisor is zero or on overflow" [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(two), Some(Fix::from_num(3)));\n",
+                "let zero = ", fixed256_int!($Bits, $from_prim, "0"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(zero), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "let neg_one = ", fixed256_int!($Bits, $from_prim, "-1"), ";\n",
+                    "assert_eq!(Fix::MIN.checked_div_euclid_int(neg_one), None);\n",
+                ),
+            ];
+            saturating = saturating_div_euclid_int => $Self::MAX; [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).saturating_div_euclid_int(two), Fix::from_num(3));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).saturating_div_euclid_int(two), Fix::from_num(-4));\n",
+                    "let neg_one = ", fixed256_int!($Bits, $from_prim, "-1"), ";\n",
+                    "assert_eq!(Fix::MIN.saturating_div_euclid_int(neg_one), Fix::MAX);\n",
+                ),
+            ];
+            wrapping = wrapping_div_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).wrapping_div_euclid_int(two), Fix::from_num(3));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_div_euclid_int(two), Fix::from_num(-4));\n",
+                    "let wrapped = Fix::MIN.round_to_zero();\n",
+                    "let neg_one = ", fixed256_int!($Bits, $from_prim, "-1"), ";\n",
+                    "assert_eq!(Fix::MIN.wrapping_div_euclid_int(neg_one), wrapped);\n",
+                ),
+            ];
+            unwrapped = unwrapped_div_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).unwrapped_div_euclid_int(two), Fix::from_num(3));\n",
+            ] [
+                "let zero = ", fixed256_int!($Bits, $from_prim, "0"), ";\n",
+                "let _divisor_is_zero = Fix::from_num(7.5).unwrapped_div_euclid_int(zero);\n",
+            ];
+            overflowing = overflowing_div_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).overflowing_div_euclid_int(two), (Fix::from_num(3), false));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "let wrapped = Fix::MIN.round_to_zero();\n",
+                    "let neg_one = ", fixed256_int!($Bits, $from_prim, "-1"), ";\n",
+                    "assert_eq!(Fix::MIN.overflowing_div_euclid_int(neg_one), (wrapped, true));\n",
+                ),
+            ] {
+                let (mut q, overflow) = self.overflowing_div_int(rhs);
+                q = q.round_to_zero();
+                if_signed! {
+                    $Signedness;
+                    if self.unwrapped_rem_int(rhs).is_negative() {
+                        let Some(neg_one) = Self::TRY_NEG_ONE else {
+                            return (q, true);
+                        };
+                        let (q, overflow2) = if rhs.is_positive() {
+                            q.overflowing_add(neg_one)
+                        } else {
+                            q.overflowing_sub(neg_one)
+                        };
+                        return (q, overflow | overflow2);
+                    }
+                }
+                (q, overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Remainder for Euclidean division by an integer",
+            "remainder for Euclidean division by an integer", "remainder",
+            if_signed_unsigned!(
+                $Signedness,
+                "Note that while remainder for Euclidean division cannot be negative, the
+wrapped value can be negative.
+
+",
+                "Overflow cannot occur for unsigned values.
+
+",
+            );
+            fn [const] [] [self] (rhs: $Bits) -> $Self<Frac>;
+            panics = ["Panics if the divisor is zero."];
+            imports = [$Bits];
+            plain = rem_euclid_int (wrapping_rem_euclid_int) [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).rem_euclid_int(two), Fix::from_num(1.5));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).rem_euclid_int(two), Fix::from_num(0.5));\n",
+                ),
+            ];
+            checked = checked_rem_euclid_int if rhs.is_zero(), "if the divisor is zero or on overflow" [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(two), Some(Fix::from_num(1.5)));\n",
+                "let zero = ", fixed256_int!($Bits, $from_prim, "0"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(zero), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(two), Some(Fix::from_num(0.5)));\n",
+                    "// big = MAX.int() + 2, so big - 0.5 does not fit\n",
+                    "let big = Fix::MAX.to_bits().wrapping_shr(4).wrapping_add(two);\n",
+                    "assert_eq!(Fix::from_num(-0.5).checked_rem_euclid_int(big), None);\n",
+                ),
+            ];
+            saturating = saturating_rem_euclid_int => $Self::MAX; [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).saturating_rem_euclid_int(two), Fix::from_num(1.5));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(two), Fix::from_num(0.5));\n",
+                    "// big = MAX.int() + 2, so big - 0.5 does not fit\n",
+                    "let big = Fix::MAX.to_bits().wrapping_shr(4).wrapping_add(two);\n",
+                    "assert_eq!(Fix::from_num(-0.5).saturating_rem_euclid_int(big), Fix::MAX);\n",
+                ),
+            ];
+            wrapping = wrapping_rem_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).wrapping_rem_euclid_int(two), Fix::from_num(1.5));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(two), Fix::from_num(0.5));\n",
+                    "// big = MAX.int() + 2, so big - 0.5 wraps to MIN + 0.5\n",
+                    "let big = Fix::MAX.to_bits().wrapping_shr(4).wrapping_add(two);\n",
+                    "let wrapped = Fix::MIN + Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(-0.5).wrapping_rem_euclid_int(big), wrapped);\n",
+                ),
+            ];
+            unwrapped = unwrapped_rem_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).unwrapped_rem_euclid_int(two), Fix::from_num(1.5));\n",
+            ] [
+                "let zero = ", fixed256_int!($Bits, $from_prim, "0"), ";\n",
+                "let _divisor_is_zero = Fix::from_num(7.5).unwrapped_rem_euclid_int(zero);\n",
+            ];
+            overflowing = overflowing_rem_euclid_int [
+                "let two = ", fixed256_int!($Bits, $from_prim, "2"), ";\n",
+                "assert_eq!(Fix::from_num(7.5).overflowing_rem_euclid_int(two), (Fix::from_num(1.5), false));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "let check = (Fix::from_num(0.5), false);\n",
+                    "assert_eq!(Fix::from_num(-7.5).overflowing_rem_euclid_int(two), check);\n",
+                ),
+            ] {
+                let rem = self.unwrapped_rem_int(rhs);
+                if !Self::IS_SIGNED || !is_neg(rem.to_u256()) {
+                    return (rem, false);
+                }
+                // the answer is |rhs| − |rem|, where |rhs| as a fixed-point
+                // number can need up to 512 bits
+                let d = U512 {
+                    lo: rhs.neg_abs().1,
+                    hi: U256::ZERO,
+                };
+                let d = int256::wrapping_shl_u512(d, Self::FRAC_NBITS);
+                let rem_abs = U512 {
+                    lo: rem.neg_abs().1,
+                    hi: U256::ZERO,
+                };
+                let (ans, _) = int256::overflowing_add_u512(d, int256::wrapping_neg_u512(rem_abs));
+                let overflow = !int256::is_zero_u256(ans.hi) || is_neg(ans.lo);
+                ($Self::from_u256(ans.lo), overflow)
+            }
+        }
+
+        comment! {
+            "Integer base-2 logarithm, rounded down.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).int_log2(), 2);
+assert_eq!(Fix::from_num(3.9375).int_log2(), 1);
+assert_eq!(Fix::from_num(0.25).int_log2(), -2);
+assert_eq!(Fix::from_num(0.1875).int_log2(), -3);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[doc(alias("ilog2"))]
+            #[must_use]
+            pub const fn int_log2(self) -> i32 {
+                match self.checked_int_log2() {
+                    Some(s) => s,
+                    None => panic!("log of non-positive number"),
+                }
+            }
+        }
+
+        comment! {
+            "Integer base-10 logarithm, rounded down.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U6};
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::<U2>::from_num(10).int_log10(), 1);
+assert_eq!(", stringify!($Self), "::<U2>::from_num(9.75).int_log10(), 0);
+assert_eq!(", stringify!($Self), "::<U6>::from_num(0.109375).int_log10(), -1);
+assert_eq!(", stringify!($Self), "::<U6>::from_num(0.09375).int_log10(), -2);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[doc(alias("ilog10"))]
+            #[must_use]
+            pub const fn int_log10(self) -> i32 {
+                match self.checked_int_log10() {
+                    Some(s) => s,
+                    None => panic!("log of non-positive number"),
+                }
+            }
+        }
+
+        comment! {
+            "Integer logarithm to the specified base, rounded down.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), "
+or if the base is <&nbsp;2.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).int_log(2), 2);
+assert_eq!(Fix::from_num(5.75).int_log(5), 1);
+assert_eq!(Fix::from_num(0.25).int_log(5), -1);
+assert_eq!(Fix::from_num(0.1875).int_log(5), -2);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[doc(alias("ilog"))]
+            #[must_use]
+            pub const fn int_log(self, base: u32) -> i32 {
+                match self.checked_int_log(base) {
+                    Some(s) => s,
+                    None => {
+                        if base < 2 {
+                            panic!("log with base < 2");
+                        } else {
+                            panic!("log of non-positive number");
+                        }
+                    }
+                }
+            }
+        }
+
+        comment! {
+            "Checked integer base-2 logarithm, rounded down.
+Returns the logarithm or [`None`] if the fixed-point number is
+", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_int_log2(), None);
+assert_eq!(Fix::from_num(4).checked_int_log2(), Some(2));
+assert_eq!(Fix::from_num(3.9375).checked_int_log2(), Some(1));
+assert_eq!(Fix::from_num(0.25).checked_int_log2(), Some(-2));
+assert_eq!(Fix::from_num(0.1875).checked_int_log2(), Some(-3));
+```
+";
+            #[inline]
+            #[doc(alias("checked_ilog2"))]
+            #[must_use]
+            pub const fn checked_int_log2(self) -> Option<i32> {
+                let bits = self.to_u256();
+                if int256::is_zero_u256(bits) || (Self::IS_SIGNED && is_neg(bits)) {
+                    return None;
+                }
+                let log = 255 - int256::leading_zeros_u256(bits) as i32;
+                Some(log - Self::FRAC_NBITS as i32)
+            }
+        }
+
+        comment! {
+            "Checked integer base-10 logarithm, rounded down.
+Returns the logarithm or [`None`] if the fixed-point number is
+", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ".
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U6};
+use fixed::", stringify!($Self), ";
+assert_eq!(", stringify!($Self), "::<U2>::ZERO.checked_int_log10(), None);
+assert_eq!(", stringify!($Self), "::<U2>::from_num(10).checked_int_log10(), Some(1));
+assert_eq!(", stringify!($Self), "::<U2>::from_num(9.75).checked_int_log10(), Some(0));
+assert_eq!(", stringify!($Self), "::<U6>::from_num(0.109375).checked_int_log10(), Some(-1));
+assert_eq!(", stringify!($Self), "::<U6>::from_num(0.09375).checked_int_log10(), Some(-2));
+```
+";
+            #[inline]
+            #[doc(alias("checked_ilog10"))]
+            #[must_use]
+            pub const fn checked_int_log10(self) -> Option<i32> {
+                self.checked_int_log(10)
+            }
+        }
+
+        comment! {
+            "Checked integer logarithm to the specified base, rounded down.
+Returns the logarithm, or [`None`] if the fixed-point number is
+", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), "
+or if the base is <&nbsp;2.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_int_log(5), None);
+assert_eq!(Fix::from_num(4).checked_int_log(2), Some(2));
+assert_eq!(Fix::from_num(5.75).checked_int_log(5), Some(1));
+assert_eq!(Fix::from_num(0.25).checked_int_log(5), Some(-1));
+assert_eq!(Fix::from_num(0.1875).checked_int_log(5), Some(-2));
+```
+";
+            #[inline]
+            #[doc(alias("checked_ilog"))]
+            #[must_use]
+            pub const fn checked_int_log(self, base: u32) -> Option<i32> {
+                let bits = self.to_u256();
+                if int256::is_zero_u256(bits) || (Self::IS_SIGNED && is_neg(bits)) || base < 2 {
+                    return None;
+                }
+                Some(int_log(bits, Self::FRAC_NBITS, base))
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            fixed256_variants! {
+                $Self;
+                "Returns a number representing the sign of `self`", "signum", "signum",
+                "Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value &minus;1.
+
+";
+                fn [const] [] [self] () -> $Self<Frac>;
+                panics = [];
+                imports = [];
+                plain = signum (wrapping_signum) [
+                    "assert_eq!(Fix::from_num(5).signum(), 1);\n",
+                    "assert_eq!(Fix::ZERO.signum(), 0);\n",
+                    "assert_eq!(Fix::from_num(-5).signum(), -1);\n",
+                ];
+                checked = checked_signum, "on overflow" [
+                    "assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::ONE));\n",
+                    "assert_eq!(Fix::ZERO.checked_signum(), Some(Fix::ZERO));\n",
+                    "assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::NEG_ONE));\n",
+                    "\n",
+                    "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                    "type ZeroIntBits = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(OneIntBit::from_num(0.5).checked_signum(), None);\n",
+                    "assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);\n",
+                    "assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);\n",
+                ];
+                saturating = saturating_signum => if self.is_negative() {
+                    $Self::MIN
+                } else {
+                    $Self::MAX
+                }; [
+                    "assert_eq!(Fix::from_num(5).saturating_signum(), 1);\n",
+                    "assert_eq!(Fix::ZERO.saturating_signum(), 0);\n",
+                    "assert_eq!(Fix::from_num(-5).saturating_signum(), -1);\n",
+                    "\n",
+                    "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                    "type ZeroIntBits = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(OneIntBit::from_num(0.5).saturating_signum(), OneIntBit::MAX);\n",
+                    "assert_eq!(ZeroIntBits::from_num(0.25).saturating_signum(), ZeroIntBits::MAX);\n",
+                    "assert_eq!(ZeroIntBits::from_num(-0.5).saturating_signum(), ZeroIntBits::MIN);\n",
+                ];
+                wrapping = wrapping_signum [
+                    "assert_eq!(Fix::from_num(5).wrapping_signum(), 1);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_signum(), 0);\n",
+                    "assert_eq!(Fix::from_num(-5).wrapping_signum(), -1);\n",
+                    "\n",
+                    "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                    "type ZeroIntBits = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(OneIntBit::from_num(0.5).wrapping_signum(), -1);\n",
+                    "assert_eq!(ZeroIntBits::from_num(0.25).wrapping_signum(), 0);\n",
+                    "assert_eq!(ZeroIntBits::from_num(-0.5).wrapping_signum(), 0);\n",
+                ];
+                unwrapped = unwrapped_signum [
+                    "assert_eq!(Fix::from_num(5).unwrapped_signum(), 1);\n",
+                    "assert_eq!(Fix::ZERO.unwrapped_signum(), 0);\n",
+                    "assert_eq!(Fix::from_num(-5).unwrapped_signum(), -1);\n",
+                ] [
+                    "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                    "let _overflow = Fix::from_num(0.5).to_num::<OneIntBit>().unwrapped_signum();\n",
+                ];
+                overflowing = overflowing_signum [
+                    "assert_eq!(Fix::from_num(5).overflowing_signum(), (Fix::ONE, false));\n",
+                    "assert_eq!(Fix::ZERO.overflowing_signum(), (Fix::ZERO, false));\n",
+                    "assert_eq!(Fix::from_num(-5).overflowing_signum(), (Fix::NEG_ONE, false));\n",
+                    "\n",
+                    "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                    "type ZeroIntBits = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "let check = (OneIntBit::NEG_ONE, true);\n",
+                    "assert_eq!(OneIntBit::from_num(0.5).overflowing_signum(), check);\n",
+                    "let check = (ZeroIntBits::ZERO, true);\n",
+                    "assert_eq!(ZeroIntBits::from_num(0.25).overflowing_signum(), check);\n",
+                    "assert_eq!(ZeroIntBits::from_num(-0.5).overflowing_signum(), check);\n",
+                ] {
+                    if self.is_zero() {
+                        ($Self::ZERO, false)
+                    } else if Self::FRAC_NBITS == 256 {
+                        ($Self::ZERO, true)
+                    } else if self.is_negative() {
+                        let bits = int256::wrapping_shl_u256(U256::MAX, Self::FRAC_NBITS);
+                        ($Self::from_u256(bits), false)
+                    } else {
+                        let one = U256 { lo: 1, hi: 0 };
+                        let bits = int256::wrapping_shl_u256(one, Self::FRAC_NBITS);
+                        ($Self::from_u256(bits), Self::FRAC_NBITS == 255)
+                    }
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`",
+            "reciprocal", "reciprocal", "";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = ["Panics if the fixed-point number is zero."];
+            imports = [];
+            plain = recip (wrapping_recip) [
+                "assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));\n",
+            ];
+            checked = checked_recip if self.is_zero(), "if `self` is zero or on overflow" [
+                "assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));\n",
+                "assert_eq!(Fix::ZERO.checked_recip(), None);\n",
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).checked_recip(), None);\n",
+            ];
+            saturating = saturating_recip => $Self::saturated(Self::IS_SIGNED && is_neg(self.to_u256())); [
+                "assert_eq!(Fix::from_num(2).saturating_recip(), Fix::from_num(0.5));\n",
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).saturating_recip(), Small::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Small::from_num(-0.25).saturating_recip(), Small::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_recip [
+                "assert_eq!(Fix::from_num(2).wrapping_recip(), Fix::from_num(0.5));\n",
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).wrapping_recip(), Small::ZERO);\n",
+            ];
+            unwrapped = unwrapped_recip [
+                "assert_eq!(Fix::from_num(2).unwrapped_recip(), Fix::from_num(0.5));\n",
+            ] [
+                "let _zero = Fix::ZERO.unwrapped_recip();\n",
+            ];
+            overflowing = overflowing_recip [
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Fix::from_num(0.25).overflowing_recip(), (Fix::from_num(4), false));\n",
+                "assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));\n",
+            ] {
+                if let Some(one) = Self::TRY_ONE {
+                    return one.overflowing_div(self);
+                }
+                if_signed! {
+                    $Signedness;
+                    let (neg, abs) = self.neg_abs();
+                    let uns_abs = $USelf::<Frac>::from_u256(abs);
+                    let (uns_wrapped, overflow1) = uns_abs.overflowing_recip();
+                    let wrapped = $Self::<Frac>::from_u256(uns_wrapped.to_u256());
+                    let overflow2 = wrapped.is_negative();
+                    if neg && int256::is_zero_u256(xor(wrapped.to_u256(), MSB)) {
+                        return (wrapped, overflow1);
+                    }
+                    if neg {
+                        // if we do not have overflow yet, we will not overflow now
+                        (wrapped.wrapping_neg(), overflow1 | overflow2)
+                    } else {
+                        (wrapped, overflow1 | overflow2)
+                    }
+                }
+                if_unsigned! {
+                    $Signedness;
+                    // 0 < x < 1: 1/x = 1 + (1 - x) / x, wrapped to (1 - x) / x
+                    // x.wrapping_neg() = 1 - x
+
+                    // x = 0: we still get division by zero
+
+                    (self.wrapping_neg().wrapping_div(self), true)
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Multiplication, rounding the product using the given rounding mode",
+            "multiplication, rounding the product using the given rounding mode", "product", "";
+            fn [const] [] [self] (rhs: $Self<Frac>, mode: RoundingMode) -> $Self<Frac>;
+            panics = [];
+            imports = [RoundingMode];
+            plain = mul_round (overflowing_mul_round) [
+                "let a = Fix::from_num(1.25);\n",
+                "let b = Fix::from_num(0.125);\n",
+                "// the exact product is 2.5 × DELTA\n",
+                "let two = Fix::from_num(0.125);\n",
+                "let three = Fix::from_num(0.1875);\n",
+                "assert_eq!(a.mul_round(b, RoundingMode::NearestEven), two);\n",
+                "assert_eq!(a.mul_round(b, RoundingMode::NearestAway), three);\n",
+                "assert_eq!(a.mul_round(b, RoundingMode::Ceil), three);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-a).mul_round(b, RoundingMode::Floor), -three);\n",
+                    "assert_eq!((-a).mul_round(b, RoundingMode::TowardZero), -two);\n",
+                ),
+            ];
+            checked = checked_mul_round, "on overflow" [
+                "let a = Fix::from_num(1.25);\n",
+                "let b = Fix::from_num(0.125);\n",
+                "let three = Fix::from_num(0.1875);\n",
+                "assert_eq!(a.checked_mul_round(b, RoundingMode::Ceil), Some(three));\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(Fix::MAX.checked_mul_round(four, RoundingMode::Floor), None);\n",
+            ];
+            overflowing = overflowing_mul_round [
+                "let half_delta = Fix::from_num(0.5);\n",
+                "assert_eq!(\n",
+                "    Fix::DELTA.overflowing_mul_round(half_delta, RoundingMode::NearestAway),\n",
+                "    (Fix::DELTA, false)\n",
+                ");\n",
+                "let four = Fix::from_num(4);\n",
+                "let wrapped = Fix::MAX.wrapping_mul(four);\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.overflowing_mul_round(four, RoundingMode::Floor),\n",
+                "    (wrapped, true)\n",
+                ");\n",
+            ] {
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    arith::i256::overflowing_mul_round(
+                        self.to_u256(),
+                        rhs.to_u256(),
+                        Self::FRAC_NBITS,
+                        mode,
+                    )
+                } else {
+                    arith::u256::overflowing_mul_round(
+                        self.to_u256(),
+                        rhs.to_u256(),
+                        Self::FRAC_NBITS,
+                        mode,
+                    )
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Division, rounding the quotient using the given rounding mode",
+            "division, rounding the quotient using the given rounding mode", "quotient", "";
+            fn [const] [] [self] (rhs: $Self<Frac>, mode: RoundingMode) -> $Self<Frac>;
+            panics = ["Panics if the divisor is zero."];
+            imports = [RoundingMode];
+            plain = div_round (overflowing_div_round) [
+                "let a = Fix::from_num(2);\n",
+                "let b = Fix::from_num(3);\n",
+                "// the exact quotient is 10.67 × DELTA\n",
+                "let ten = Fix::from_num(0.625);\n",
+                "let eleven = Fix::from_num(0.6875);\n",
+                "assert_eq!(a.div_round(b, RoundingMode::NearestEven), eleven);\n",
+                "assert_eq!(a.div_round(b, RoundingMode::Floor), ten);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-a).div_round(b, RoundingMode::Floor), -eleven);\n",
+                    "assert_eq!((-a).div_round(b, RoundingMode::TowardZero), -ten);\n",
+                ),
+            ];
+            checked = checked_div_round if rhs.is_zero(), "if the divisor is zero or on overflow" [
+                "let a = Fix::from_num(2);\n",
+                "let b = Fix::from_num(3);\n",
+                "let eleven = Fix::from_num(0.6875);\n",
+                "assert_eq!(a.checked_div_round(b, RoundingMode::Ceil), Some(eleven));\n",
+                "assert_eq!(a.checked_div_round(Fix::ZERO, RoundingMode::Ceil), None);\n",
+                "let quarter = Fix::from_num(0.25);\n",
+                "assert_eq!(Fix::MAX.checked_div_round(quarter, RoundingMode::Floor), None);\n",
+            ];
+            overflowing = overflowing_div_round [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(\n",
+                "    Fix::ONE.overflowing_div_round(three, RoundingMode::NearestEven),\n",
+                "    (Fix::from_num(0.3125), false)\n",
+                ");\n",
+                "let quarter = Fix::from_num(0.25);\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4));\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.overflowing_div_round(quarter, RoundingMode::Floor),\n",
+                "    (wrapped, true)\n",
+                ");\n",
+            ] {
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    arith::i256::overflowing_div_round(
+                        self.to_u256(),
+                        rhs.to_u256(),
+                        Self::FRAC_NBITS,
+                        mode,
+                    )
+                } else {
+                    arith::u256::overflowing_div_round(
+                        self.to_u256(),
+                        rhs.to_u256(),
+                        Self::FRAC_NBITS,
+                        mode,
+                    )
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`,
+rounding using the given rounding mode",
+            "reciprocal, rounding using the given rounding mode", "reciprocal", "";
+            fn [const] [] [self] (mode: RoundingMode) -> $Self<Frac>;
+            panics = ["Panics if the fixed-point number is zero."];
+            imports = [RoundingMode];
+            plain = recip_round (overflowing_recip_round) [
+                "let a = Fix::from_num(3);\n",
+                "// the exact reciprocal is 5.33 × DELTA\n",
+                "let five = Fix::from_num(0.3125);\n",
+                "let six = Fix::from_num(0.375);\n",
+                "assert_eq!(a.recip_round(RoundingMode::NearestEven), five);\n",
+                "assert_eq!(a.recip_round(RoundingMode::Ceil), six);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-a).recip_round(RoundingMode::Floor), -six);\n",
+                ),
+            ];
+            checked = checked_recip_round if self.is_zero(), "if `self` is zero or on overflow" [
+                "let six = Fix::from_num(0.375);\n",
+                "assert_eq!(Fix::from_num(3).checked_recip_round(RoundingMode::Ceil), Some(six));\n",
+                "assert_eq!(Fix::ZERO.checked_recip_round(RoundingMode::Ceil), None);\n",
+            ];
+            overflowing = overflowing_recip_round [
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let six = Fix::from_num(0.375);\n",
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(three.overflowing_recip_round(RoundingMode::Ceil), (six, false));\n",
+                "let quarter = Small::from_num(0.25);\n",
+                "let check = (Small::ZERO, true);\n",
+                "assert_eq!(quarter.overflowing_recip_round(RoundingMode::Floor), check);\n",
+            ] {
+                if let Some(one) = Self::TRY_ONE {
+                    return one.overflowing_div_round(self, mode);
+                }
+                if_signed! {
+                    $Signedness;
+                    let (neg, abs) = self.neg_abs();
+                    let uns_abs = $USelf::<Frac>::from_u256(abs);
+                    // round the magnitude so that the negated result is rounded using mode
+                    let uns_mode = if neg { mode.negated() } else { mode };
+                    let (uns_wrapped, overflow1) = uns_abs.overflowing_recip_round(uns_mode);
+                    let wrapped = $Self::<Frac>::from_u256(uns_wrapped.to_u256());
+                    let overflow2 = wrapped.is_negative();
+                    if neg && int256::is_zero_u256(xor(wrapped.to_u256(), MSB)) {
+                        return (wrapped, overflow1);
+                    }
+                    if neg {
+                        // if we do not have overflow yet, we will not overflow now
+                        (wrapped.wrapping_neg(), overflow1 | overflow2)
+                    } else {
+                        (wrapped, overflow1 | overflow2)
+                    }
+                }
+                if_unsigned! {
+                    $Signedness;
+                    // 0 < x < 1: 1/x = 1 + (1 - x) / x, wrapped to (1 - x) / x,
+                    // and 1 does not affect rounding
+                    // x.wrapping_neg() = 1 - x
+
+                    // x = 0: we still get division by zero
+
+                    (self.wrapping_neg().overflowing_div_round(self, mode).0, true)
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Adds `self` to the product `a`&nbsp;×&nbsp;`b`", "addition of `self` to the product `a`&nbsp;×&nbsp;`b`",
+            "result",
+            concat!(
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would overflow on its own,
+but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b` is representable; in
+these cases this method returns the correct result without overflow.
+
+",
+                ),
+                "The `a` and `b` parameters can have a fixed-point type like `self` but with
+a different number of fractional bits.
+
+The [`mul_acc`] method performs the same operation as this method but mutates
+`self` instead of returning the result.
+
+[`mul_acc`]: Self::mul_acc
+
+",
+            );
+            fn [const] [AFrac: LeEqU256, BFrac: LeEqU256] [self] (
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = add_prod (wrapping_add_prod) [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(three.add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow\n",
+                    "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                    "assert_eq!((-Fix::MAX).add_prod(Fix::MAX, Fix::from_num(1.5)), half_max);\n",
+                ),
+            ];
+            checked = checked_add_prod, "on overflow" [
+                "let three = Fix::from_num(3);\n",
+                "let check = Some(Fix::from_num(5));\n",
+                "assert_eq!(three.checked_add_prod(Fix::from_num(4), Fix::from_num(0.5)), check);\n",
+                "assert_eq!(Fix::DELTA.checked_add_prod(Fix::MAX, Fix::ONE), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow\n",
+                    "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                    "let check = Some(half_max);\n",
+                    "assert_eq!((-Fix::MAX).checked_add_prod(Fix::MAX, Fix::from_num(1.5)), check);\n",
+                ),
+            ];
+            saturating = saturating_add_prod => $Self::saturated(
+                Self::IS_SIGNED && is_neg(a.to_u256()) != is_neg(b.to_u256())
+            ); [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(three.saturating_add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);\n",
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(half_max.saturating_add_prod(half_max, Fix::from_num(3)), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(half_max.saturating_add_prod(half_max, Fix::from_num(-5)), Fix::MIN);\n",
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow\n",
+                    "let check = half_max;\n",
+                    "assert_eq!((-Fix::MAX).saturating_add_prod(Fix::MAX, Fix::from_num(1.5)), check);\n",
+                ),
+            ];
+            wrapping = wrapping_add_prod [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(three.wrapping_add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4));\n",
+                "assert_eq!(Fix::MAX.wrapping_add_prod(Fix::MAX, Fix::from_num(3)), wrapped);\n",
+            ];
+            unwrapped = unwrapped_add_prod [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(three.unwrapped_add_prod(Fix::from_num(4), Fix::from_num(0.5)), 5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow\n",
+                    "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                    "assert_eq!((-Fix::MAX).unwrapped_add_prod(Fix::MAX, Fix::from_num(1.5)), half_max);\n",
+                ),
+            ] [
+                "let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);\n",
+            ];
+            overflowing = overflowing_add_prod [
+                "let three = Fix::from_num(3);\n",
+                "assert_eq!(\n",
+                "    three.overflowing_add_prod(Fix::from_num(4), Fix::from_num(0.5)),\n",
+                "    (Fix::from_num(5), false)\n",
+                ");\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4));\n",
+                "assert_eq!(Fix::MAX.overflowing_add_prod(Fix::MAX, Fix::from_num(3)), (wrapped, true));\n",
+            ] {
+                let frac_nbits = AFrac::I32 + BFrac::I32 - Frac::I32;
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    arith::i256::overflowing_mul_add(
+                        a.to_u256(),
+                        b.to_u256(),
+                        self.to_u256(),
+                        frac_nbits,
+                    )
+                } else {
+                    arith::u256::overflowing_mul_add(
+                        a.to_u256(),
+                        b.to_u256(),
+                        self.to_u256(),
+                        frac_nbits,
+                    )
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+
+        comment! {
+            "Multiply and accumulate. Adds (`a` × `b`) to `self`.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+            },
+            "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+The [`add_prod`] method performs the same operation as this method but returns
+the result instead of mutating `self`.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value
+can be returned, but it is not considered a breaking change if in the
+future it panics; if wrapping is required use [`wrapping_mul_acc`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+acc.mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, Fix::MAX / Fix::from_num(2));
+"
+            },
+            "```
+
+[`add_prod`]: Self::add_prod
+[`wrapping_mul_acc`]: Self::wrapping_mul_acc
+";
+            #[inline]
+            #[track_caller]
+            pub const fn mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) {
+                *self = self.add_prod(a, b);
+            }
+        }
+
+        comment! {
+            "Checked multiply and accumulate. Adds (`a` × `b`) to `self`,
+or returns [`None`] on overflow.
+
+Like all other checked methods, this method wraps the successful return value
+in an [`Option`]. Since the unchecked [`mul_acc`] method does not return a
+value, which is equivalent to returning [`()`][unit], this method wraps [`()`][unit]
+into [`Some`]`(())` on success.
+
+When there is an overflow, this method does not modify `self`.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+            },
+            "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+let check = acc.checked_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(check, Some(()));
+assert_eq!(acc, 5);
+
+acc = Fix::DELTA;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::ONE);
+assert_eq!(check, None);
+// acc is unchanged on error
+assert_eq!(acc, Fix::DELTA);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(check, Some(()));
+assert_eq!(acc, Fix::MAX / Fix::from_num(2));
+"
+            },
+            "```
+
+[`mul_acc`]: Self::mul_acc
+";
+            #[inline]
+            #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+            pub const fn checked_mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) -> Option<()> {
+                match self.checked_add_prod(a, b) {
+                    Some(s) => {
+                        *self = s;
+                        Some(())
+                    }
+                    None => None,
+                }
+            }
+        }
+
+        comment! {
+            "Saturating multiply and accumulate. Adds (`a` × `b`) to `self`,
+saturating on overflow.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+            },
+            "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+acc.saturating_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
+
+let half_max = Fix::MAX / Fix::from_num(2);
+acc = half_max;
+acc.saturating_mul_acc(half_max, Fix::from_num(3));
+assert_eq!(acc, Fix::MAX);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.saturating_mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, half_max);
+"
+            },
+            "```
+";
+            #[inline]
+            pub const fn saturating_mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) {
+                *self = self.saturating_add_prod(a, b);
+            }
+        }
+
+        comment! {
+            "Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`,
+wrapping on overflow.
+
+The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+acc.wrapping_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
+
+acc = Fix::MAX;
+acc.wrapping_mul_acc(Fix::MAX, Fix::from_num(3));
+assert_eq!(acc, Fix::MAX.wrapping_mul(Fix::from_num(4)));
+```
+";
+            #[inline]
+            pub const fn wrapping_mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) {
+                *self = self.wrapping_add_prod(a, b);
+            }
+        }
+
+        comment! {
+            "Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`,
+panicking on overflow.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+            },
+            "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+acc.unwrapped_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.unwrapped_mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, Fix::MAX / Fix::from_num(2));
+"
+            },
+            "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::DELTA;
+acc.unwrapped_mul_acc(Fix::MAX, Fix::ONE);
+```
+";
+            #[inline]
+            #[track_caller]
+            pub const fn unwrapped_mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) {
+                *self = self.unwrapped_add_prod(a, b);
+            }
+        }
+
+        comment! {
+            "Overflowing multiply and accumulate. Adds (`a` × `b`) to `self`,
+wrapping and returning [`true`] if overflow occurs.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+            },
+            "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+assert!(!acc.overflowing_mul_acc(Fix::from_num(4), Fix::from_num(0.5)));
+assert_eq!(acc, 5);
+
+acc = Fix::MAX;
+assert!(acc.overflowing_mul_acc(Fix::MAX, Fix::from_num(3)));
+assert_eq!(acc, Fix::MAX.wrapping_mul(Fix::from_num(4)));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+assert!(!acc.overflowing_mul_acc(Fix::MAX, Fix::from_num(1.5)));
+assert_eq!(acc, Fix::MAX / Fix::from_num(2));
+"
+            },
+            "```
+";
+            #[inline]
+            #[must_use = "this returns whether overflow occurs; use `wrapping_mul_acc` if the flag is not needed"]
+            pub const fn overflowing_mul_acc<AFrac: LeEqU256, BFrac: LeEqU256>(
+                &mut self,
+                a: $Self<AFrac>,
+                b: $Self<BFrac>,
+            ) -> bool {
+                let (ans, overflow) = self.overflowing_add_prod(a, b);
+                *self = ans;
+                overflow
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Linear interpolation between `start` and `end`", "linear interpolation between `start` and `end`",
+            "interpolated value",
+            "Returns `start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+";
+            fn [const] [RangeFrac] [self] (start: $Self<RangeFrac>, end: $Self<RangeFrac>) -> $Self<RangeFrac>;
+            panics = [];
+            imports = [];
+            plain = lerp (wrapping_lerp) [
+                "let start = Fix::from_num(2);\n",
+                "let end = Fix::from_num(3.5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);\n",
+                ),
+                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);\n",
+                "assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);\n",
+                "assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);\n",
+                "assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);\n",
+            ];
+            checked = checked_lerp, "on overflow" [
+                "assert_eq!(Fix::from_num(0.5).checked_lerp(Fix::ZERO, Fix::MAX), Some(Fix::MAX / Fix::from_num(2)));\n",
+                "assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);\n",
+            ];
+            saturating = saturating_lerp => {
+                let range_neg = int256::sub_neg_abs_u256(end.to_u256(), start.to_u256(), Self::IS_SIGNED).0;
+                if (Self::IS_SIGNED && is_neg(self.to_u256())) != range_neg {
+                    $Self::MIN
+                } else {
+                    $Self::MAX
+                }
+            }; [
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(Fix::from_num(0.5).saturating_lerp(Fix::ZERO, Fix::MAX), half_max);\n",
+                "assert_eq!(Fix::from_num(1.5).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2.0).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MIN);\n",
+                ),
+                "assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::MIN);\n",
+            ];
+            wrapping = wrapping_lerp [
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(Fix::from_num(0.5).wrapping_lerp(Fix::ZERO, Fix::MAX), half_max);\n",
+                "assert_eq!(\n",
+                "    Fix::from_num(1.5).wrapping_lerp(Fix::ZERO, Fix::MAX),\n",
+                "    Fix::MAX.wrapping_add(half_max)\n",
+                ");\n",
+            ];
+            unwrapped = unwrapped_lerp [
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(Fix::from_num(0.5).unwrapped_lerp(Fix::ZERO, Fix::MAX), half_max);\n",
+            ] [
+                "let _overflow = Fix::from_num(1.5).unwrapped_lerp(Fix::ZERO, Fix::MAX);\n",
+            ];
+            overflowing = overflowing_lerp [
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(\n",
+                "    Fix::from_num(0.5).overflowing_lerp(Fix::ZERO, Fix::MAX),\n",
+                "    (half_max, false)\n",
+                ");\n",
+                "assert_eq!(\n",
+                "    Fix::from_num(1.5).overflowing_lerp(Fix::ZERO, Fix::MAX),\n",
+                "    (Fix::MAX.wrapping_add(half_max), true)\n",
+                ");\n",
+            ] {
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    lerp::i256(self.to_u256(), start.to_u256(), end.to_u256(), Self::FRAC_NBITS)
+                } else {
+                    lerp::u256(self.to_u256(), start.to_u256(), end.to_u256(), Self::FRAC_NBITS)
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Inverse linear interpolation between `start` and `end`",
+            "inverse linear interpolation between `start` and `end`", "computed value",
+            "The computed value can have a fixed-point type like `self` but with a
+different number of fractional bits.
+
+Returns
+(`self`&nbsp;&minus;&nbsp;`start`)&nbsp;/&nbsp;(`end`&nbsp;&minus;&nbsp;`start`).
+This is 0 when `self`&nbsp;=&nbsp;`start`, and 1 when `self`&nbsp;=&nbsp;`end`.
+
+";
+            fn [const] [RetFrac: LeEqU256] [self] (start: $Self<Frac>, end: $Self<Frac>) -> $Self<RetFrac>;
+            panics = ["Panics when `start`&nbsp;=&nbsp;`end`."];
+            imports = [];
+            plain = inv_lerp (wrapping_inv_lerp) [
+                "let start = Fix::from_num(2);\n",
+                "let end = Fix::from_num(3.5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.5).inv_lerp::<U4>(start, end), -1);\n",
+                ),
+                "assert_eq!(Fix::from_num(2).inv_lerp::<U4>(start, end), 0);\n",
+                "assert_eq!(Fix::from_num(2.75).inv_lerp::<U4>(start, end), 0.5);\n",
+                "assert_eq!(Fix::from_num(3.5).inv_lerp::<U4>(start, end), 1);\n",
+                "assert_eq!(Fix::from_num(5).inv_lerp::<U4>(start, end), 2);\n",
+            ];
+            checked = checked_inv_lerp if start.to_u256().lo == end.to_u256().lo
+                && start.to_u256().hi == end.to_u256().hi,
+                "if `start`&nbsp;=&nbsp;`end` or on overflow" [
+                "let two = Fix::from_num(2);\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(Fix::from_num(3).checked_inv_lerp::<U4>(two, four), Some(Fix::from_num(0.5)));\n",
+                "assert_eq!(Fix::from_num(2).checked_inv_lerp::<U4>(two, two), None);\n",
+                "assert_eq!(Fix::MAX.checked_inv_lerp::<U4>(Fix::ZERO, Fix::from_num(0.5)), None);\n",
+            ];
+            saturating = saturating_inv_lerp => {
+                let signed = Self::IS_SIGNED;
+                let diff_neg = int256::sub_neg_abs_u256(self.to_u256(), start.to_u256(), signed).0;
+                let range_neg = int256::sub_neg_abs_u256(end.to_u256(), start.to_u256(), signed).0;
+                $Self::<RetFrac>::saturated(diff_neg != range_neg)
+            }; [
+                "let two = Fix::from_num(2);\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(Fix::from_num(3).saturating_inv_lerp::<U4>(two, four), 0.5);\n",
+                "assert_eq!(Fix::MAX.saturating_inv_lerp::<U4>(Fix::ZERO, Fix::from_num(0.5)), Fix::MAX);\n",
+                "assert_eq!(Fix::MAX.saturating_inv_lerp::<U4>(Fix::from_num(0.5), Fix::ZERO), Fix::MIN);\n",
+            ];
+            wrapping = wrapping_inv_lerp [
+                "let two = Fix::from_num(2);\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(Fix::from_num(3).wrapping_inv_lerp::<U4>(two, four), 0.5);\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.wrapping_inv_lerp::<U4>(Fix::ZERO, Fix::from_num(0.5)),\n",
+                "    Fix::MAX.wrapping_add(Fix::MAX)\n",
+                ");\n",
+            ];
+            unwrapped = unwrapped_inv_lerp [
+                "let two = Fix::from_num(2);\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(Fix::from_num(3).unwrapped_inv_lerp::<U4>(two, four), 0.5);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_inv_lerp::<U4>(Fix::ZERO, Fix::from_num(0.5));\n",
+            ];
+            overflowing = overflowing_inv_lerp [
+                "let two = Fix::from_num(2);\n",
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(\n",
+                "    Fix::from_num(3).overflowing_inv_lerp::<U4>(two, four),\n",
+                "    (Fix::from_num(0.5), false)\n",
+                ");\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.overflowing_inv_lerp::<U4>(Fix::ZERO, Fix::from_num(0.5)),\n",
+                "    (Fix::MAX.wrapping_add(Fix::MAX), true)\n",
+                ");\n",
+            ] {
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    inv_lerp::i256(self.to_u256(), start.to_u256(), end.to_u256(), RetFrac::U32)
+                } else {
+                    inv_lerp::u256(self.to_u256(), start.to_u256(), end.to_u256(), RetFrac::U32)
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to a fixed-point number with the
+same underlying integer type and `NewFrac` fractional bits, rounding using the
+given rounding mode.
+
+`NewFrac` cannot be larger than `Frac`, which is checked at compile time. Since
+any fractional bits that are removed become integer bits, the result always
+fits, even when it is rounded up.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U4};
+use fixed::"#, stringify!($Self), r#";
+use fixed::RoundingMode;
+type Fix = "#, stringify!($Self), r#"<U4>;
+// binary 1.1011 is 1.6875
+let a = Fix::from_num(1.6875);
+// binary 1.11 is 1.75, binary 1.10 is 1.5
+assert_eq!(a.requantize::<U2>(RoundingMode::NearestEven), 1.75);
+assert_eq!(a.requantize::<U2>(RoundingMode::Floor), 1.5);
+// the maximum value does not overflow when rounded up
+let max = Fix::MAX.requantize::<U2>(RoundingMode::Ceil);
+assert!(max > Fix::MAX);
+```
+
+A conversion to more fractional bits does not compile:
+
+```rust,compile_fail
+use fixed::types::extra::{U2, U4};
+use fixed::"#, stringify!($Self), r#";
+use fixed::RoundingMode;
+let _ = "#, stringify!($Self), r#"::<U2>::ONE.requantize::<U4>(RoundingMode::Floor);
+```
+"#;
+            #[inline]
+            #[must_use]
+            pub fn requantize<NewFrac>(self, mode: RoundingMode) -> $Self<NewFrac>
+            where
+                NewFrac: LeEqU256 + IsLessOrEqual<Frac, Output = True>,
+            {
+                let (ans, overflow) = overflowing_convert_round(self, mode);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. A value
+that cannot be represented exactly is rounded up with a probability equal to its
+distance from the representable number below it, and rounded down otherwise, so
+the result is unbiased in expectation. Values that can be represented exactly
+are returned unchanged.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use
+[`overflowing_from_num_stochastic`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// any source of random bits can be used, here a xorshift generator
+let mut state = 1u64;
+let mut rng = || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+};
+
+// src is a quarter of the way from 1 to 1 + DELTA
+let src = I16F16::from_num(1.015625);
+let mut sum = 0.0;
+for _ in 0..1000 {
+    let dst = Fix::from_num_stochastic(src, &mut rng);
+    assert!(dst == 1 || dst == Fix::ONE + Fix::DELTA);
+    sum += dst.to_num::<f64>();
+}
+assert!((sum / 1000.0 - src.to_num::<f64>()).abs() < 0.004);
+
+// exact values are not changed
+assert_eq!(Fix::from_num_stochastic(1.5, &mut rng), 1.5);
+```
+
+[`ToFixed`]: crate::traits::ToFixed
+[`overflowing_from_num_stochastic`]: Self::overflowing_from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                let (wrapped, overflow) =
+                    overflowing_convert_random(src, Self::FRAC_NBITS, false, rng);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`, if it fits, otherwise returns
+[`None`].
+
+See [`from_num_stochastic`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+assert_eq!(Fix::checked_from_num_stochastic(0.1, &mut zero), Some(Fix::from_num(0.125)));
+assert!(Fix::checked_from_num_stochastic(1e80, &mut zero).is_none());
+assert!(Fix::checked_from_num_stochastic(f64::NAN, &mut zero).is_none());
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> Option<$Self<Frac>> {
+                checked_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`, and saturating if it does not
+fit.
+
+See [`from_num_stochastic`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+assert_eq!(Fix::saturating_from_num_stochastic(0.1, &mut zero), Fix::from_num(0.125));
+assert_eq!(Fix::saturating_from_num_stochastic(1e80, &mut zero), Fix::MAX);
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[NaN]: f64::is_nan
+";
+            #[inline]
+            #[track_caller]
+            pub fn saturating_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                saturating_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_stochastic`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+let ans = Fix::overflowing_from_num_stochastic(0.1, &mut zero);
+assert_eq!(ans, (Fix::from_num(0.125), false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let ans = TooFewIntBits::overflowing_from_num_stochastic(Fix::MAX, &mut zero);
+assert_eq!(ans, (wrapped, true));
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+triangular probability density function (TPDF) dither with random bits from
+`rng` before rounding.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. The dither
+is the sum of two independent noises that are uniformly distributed over one
+[`DELTA`], so it is centered on zero and is less than one [`DELTA`] in
+magnitude, and the dithered value is rounded to the nearest. The result is
+unbiased in expectation, and unlike with stochastic rounding the added noise
+does not depend on the value. Values that can be represented exactly are
+dithered too.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_from_num_dither`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// any source of random bits can be used, here a xorshift generator
+let mut state = 1u64;
+let mut rng = || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+};
+
+// src is a quarter of the way from 1 to 1 + DELTA
+let src = I16F16::from_num(1.015625);
+let mut sum = 0.0;
+for _ in 0..1000 {
+    let dst = Fix::from_num_dither(src, &mut rng);
+    assert!(Fix::ONE - Fix::DELTA <= dst && dst <= Fix::ONE + Fix::from_num(0.125));
+    sum += dst.to_num::<f64>();
+}
+assert!((sum / 1000.0 - src.to_num::<f64>()).abs() < 0.005);
+```
+
+[`DELTA`]: Self::DELTA
+[`ToFixed`]: crate::traits::ToFixed
+[`overflowing_from_num_dither`]: Self::overflowing_from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                let (wrapped, overflow) =
+                    overflowing_convert_random(src, Self::FRAC_NBITS, true, rng);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding, if it fits, otherwise
+returns [`None`].
+
+See [`from_num_dither`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// with zero random bits, exact values are unchanged
+assert_eq!(Fix::checked_from_num_dither(Fix::ONE, &mut zero), Some(Fix::ONE));
+assert!(Fix::checked_from_num_dither(1e80, &mut zero).is_none());
+assert!(Fix::checked_from_num_dither(f64::NAN, &mut zero).is_none());
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> Option<$Self<Frac>> {
+                checked_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding, and saturating if it
+does not fit.
+
+See [`from_num_dither`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+assert_eq!(Fix::saturating_from_num_dither(Fix::MAX, &mut zero), Fix::MAX);
+assert_eq!(Fix::saturating_from_num_dither(1e80, &mut zero), Fix::MAX);
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[NaN]: f64::is_nan
+";
+            #[inline]
+            #[track_caller]
+            pub fn saturating_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                saturating_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_dither`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// with zero random bits, exact values are unchanged
+let ans = Fix::overflowing_from_num_dither(Fix::ONE, &mut zero);
+assert_eq!(ans, (Fix::ONE, false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let ans = TooFewIntBits::overflowing_from_num_dither(Fix::MAX, &mut zero);
+assert_eq!(ans, (wrapped, true));
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+    };
+}
@@ -0,0 +1,269 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Generates the variants of an operation from its overflowing variant.
+//
+// The operation is described by its title, the title in lower case, and the
+// name of the result, followed by an extra paragraph for all the variants,
+// which is either empty or ends with a blank line. Then comes the signature,
+// which is written
+//
+//     fn [const] [generics] [self](args) -> Value;
+//
+// where `const`, the generics and `self` can be omitted, leaving the brackets
+// empty. The signature does not include `pub`, and for each variant Value is
+// wrapped as required, for example in an Option for the checked variant. This
+// is followed by the text for the panics section excluding overflow panics, in
+// brackets which can be empty, and by a list of extra items to import from the
+// crate in the examples, also in brackets which can be empty.
+//
+// Then each variant is listed with its name and the lines of its example. The
+// following are given too:
+//
+//   * plain: the name of the wrapping variant, which is referred to in the docs
+//   * checked: an optional condition for returning None without calling the
+//     overflowing variant, and the text describing when None is returned
+//   * saturating: the expression that an overflowing result saturates to
+//   * unwrapped: the lines of an example that panics
+//   * overflowing: the function body
+//
+// Since the body is passed to the macro, `self` is passed as a macro argument
+// too, otherwise it could not be used inside the body.
+macro_rules! fixed256_variants {
+    (
+        $Self:ident;
+        $Title:literal, $title:literal, $result:literal, $desc:expr;
+        fn $qual:tt $gen:tt $slf:tt $args:tt -> $Value:ty;
+        panics = $panics:tt;
+        imports = $imports:tt;
+        $(plain = $plain:ident ($plain_wrapping:ident) [$($plain_ex:expr),* $(,)?];)?
+        $(
+            checked = $checked:ident $(if $checked_if:expr)?, $checked_none:literal
+            [$($checked_ex:expr),* $(,)?];
+        )?
+        $(
+            saturating = $saturating:ident => $saturate:expr;
+            [$($saturating_ex:expr),* $(,)?];
+        )?
+        $(wrapping = $wrapping:ident [$($wrapping_ex:expr),* $(,)?];)?
+        $(
+            unwrapped = $unwrapped:ident
+            [$($unwrapped_ex:expr),* $(,)?] [$($unwrapped_panic:expr),* $(,)?];
+        )?
+        overflowing = $overflowing:ident [$($overflowing_ex:expr),* $(,)?] $body:block
+    ) => {
+        $(
+            fixed256_variants! {
+                @fn
+                $Title, ". Returns the ", $result, ".
+
+", $desc, "# Panics
+
+", fixed256_variants!(@panics_text $panics),
+                "When debug assertions are enabled, this method panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`", stringify!($plain_wrapping), "`] instead.
+
+# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($plain_ex,)* "```
+
+[`", stringify!($plain_wrapping), "`]: Self::", stringify!($plain_wrapping), "
+";
+                $qual $plain $gen $slf $args -> $Value;
+                |args| {
+                    let (ans, overflow) = fixed256_call!(Self::$overflowing $slf $args);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+        )?
+        $(
+            fixed256_variants! {
+                @fn
+                "Checked ", $title, ". Returns the ", $result, ", or [`None`] ",
+                $checked_none, ".
+
+", $desc, "# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($checked_ex,)* "```
+";
+                $qual $checked $gen $slf $args -> Option<$Value>;
+                |args| {
+                    $(
+                        if $checked_if {
+                            return None;
+                        }
+                    )?
+                    match fixed256_call!(Self::$overflowing $slf $args) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+        )?
+        $(
+            fixed256_variants! {
+                @fn
+                "Saturating ", $title, ". Returns the ", $result, ", saturating on overflow.
+
+", $desc, fixed256_variants!(@panics_section $panics), "# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($saturating_ex,)* "```
+";
+                $qual $saturating $gen $slf $args -> $Value;
+                |args| {
+                    match fixed256_call!(Self::$overflowing $slf $args) {
+                        (ans, false) => ans,
+                        (_, true) => $saturate,
+                    }
+                }
+            }
+        )?
+        $(
+            fixed256_variants! {
+                @fn
+                "Wrapping ", $title, ". Returns the ", $result, ", wrapping on overflow.
+
+", $desc, fixed256_variants!(@panics_section $panics), "# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($wrapping_ex,)* "```
+";
+                $qual $wrapping $gen $slf $args -> $Value;
+                |args| {
+                    fixed256_call!(Self::$overflowing $slf $args).0
+                }
+            }
+        )?
+        $(
+            fixed256_variants! {
+                @fn
+                "Unwrapped ", $title, ". Returns the ", $result, ", panicking on overflow.
+
+", $desc, "# Panics
+
+", fixed256_variants!(@panics_text $panics), "Panics if the result does not fit.
+
+# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($unwrapped_ex,)* "```
+
+The following panics because of overflow.
+
+```rust,should_panic
+", fixed256_variants!(@header $Self $imports), $($unwrapped_panic,)* "```
+";
+                $qual $unwrapped $gen $slf $args -> $Value;
+                |args| {
+                    match fixed256_call!(Self::$overflowing $slf $args) {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+        )?
+        fixed256_variants! {
+            @fn
+            "Overflowing ", $title, ".
+
+Returns a [tuple] of the ", $result, " and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+", $desc, fixed256_variants!(@panics_section $panics), "# Examples
+
+```rust
+", fixed256_variants!(@header $Self $imports), $($overflowing_ex,)* "```
+";
+            $qual $overflowing $gen $slf $args -> ($Value, bool);
+            |args| $body
+        }
+    };
+
+    (@header $Self:ident []) => {
+        concat!(
+            "use fixed::types::extra::U4;\nuse fixed::",
+            stringify!($Self),
+            ";\ntype Fix = ",
+            stringify!($Self),
+            "<U4>;\n",
+        )
+    };
+    (@header $Self:ident [$($import:ident),*]) => {
+        concat!(
+            "use fixed::types::extra::U4;\nuse fixed::{",
+            stringify!($Self),
+            $(", ", stringify!($import),)*
+            "};\ntype Fix = ",
+            stringify!($Self),
+            "<U4>;\n",
+        )
+    };
+    (@panics_text []) => {
+        ""
+    };
+    (@panics_text [$panics:expr]) => {
+        concat!($panics, "\n\n")
+    };
+    (@panics_section []) => {
+        ""
+    };
+    (@panics_section [$panics:expr]) => {
+        concat!("# Panics\n\n", $panics, "\n\n")
+    };
+
+    (
+        @fn $($doc:expr),*;
+        [$($qual:ident)?] $name:ident [$($gen:tt)*]
+        [$($slf:ident)?] ($($arg:ident: $T:ty),* $(,)?) -> $Ret:ty;
+        |args| $body:block
+    ) => {
+        comment! {
+            $($doc),*;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub $($qual)? fn $name<$($gen)*>($($slf,)? $($arg: $T),*) -> $Ret $body
+        }
+    };
+}
+
+// Calls a function with the arguments of a signature passed to
+// fixed256_variants!.
+macro_rules! fixed256_call {
+    ($($f:ident)::+ [$($slf:ident)?] ($($arg:ident: $T:ty),* $(,)?)) => {
+        $($f)::+($($slf,)? $($arg),*)
+    };
+}
+
+// Expands to the source text of a bits value for use in examples, for example
+// "I256Bits::from_i128(2)".
+macro_rules! fixed256_int {
+    ($Bits:ident, $from_prim:ident, $val:literal) => {
+        concat!(
+            stringify!($Bits),
+            "::",
+            stringify!($from_prim),
+            "(",
+            $val,
+            ")"
+        )
+    };
+}
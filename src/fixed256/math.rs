@@ -0,0 +1,1006 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Square roots, exponentials, logarithms and powers of the 256-bit fixed-point
+// numbers. This macro is expanded inside the impl<Frac: LeEqU256> block of the
+// fixed256! macro.
+macro_rules! fixed256_math {
+    (
+        {Self, ISelf, USelf} = {$Self:ident, $ISelf:ident, $USelf:ident},
+        Signedness = $Signedness:ident,
+        {Bits, from_prim} = {$Bits:ident, $from_prim:ident},
+    ) => {
+        #[inline]
+        const fn to_float(self) -> float::Float {
+            float::Float::from_bits(self.to_u256(), Self::FRAC_NBITS, Self::IS_SIGNED)
+        }
+
+        // Rounds to the nearest if nearest is true, otherwise toward zero.
+        #[inline]
+        const fn from_float(val: float::Float, nearest: bool) -> ($Self<Frac>, bool) {
+            let (bits, overflow) = val.to_bits(Self::FRAC_NBITS, nearest, Self::IS_SIGNED);
+            ($Self::from_u256(bits), overflow)
+        }
+
+        #[inline]
+        const fn is_non_positive(self) -> bool {
+            self.is_zero() || (Self::IS_SIGNED && is_neg(self.to_u256()))
+        }
+
+        // Returns floor(√(abs × 2^FRAC_NBITS)), which is < 2^256.
+        #[inline]
+        const fn sqrt_abs(self) -> U256 {
+            let abs = U512 {
+                lo: self.neg_abs().1,
+                hi: U256::ZERO,
+            };
+            float::isqrt_u512(false, int256::wrapping_shl_u512(abs, Self::FRAC_NBITS)).lo
+        }
+
+        if_signed! {
+            $Signedness;
+            fixed256_variants! {
+                $Self;
+                "Square root", "square root", "square root",
+                "The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+Overflow can only occur when there are no integer bits and the representable
+range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5. In this case,
+overflow occurs for an input value ≥&nbsp;0.25.
+
+";
+                fn [const] [] [self] () -> $Self<Frac>;
+                panics = ["Panics if the number is negative."];
+                imports = [];
+                plain = sqrt (wrapping_sqrt) [
+                    "assert_eq!(Fix::from_num(6.25).sqrt(), Fix::from_num(2.5));\n",
+                    "// √2 ≈ 1.414 is rounded down\n",
+                    "assert_eq!(Fix::from_num(2).sqrt(), Fix::from_num(1.375));\n",
+                ];
+                checked = checked_sqrt if self.is_negative(),
+                "if the number is negative or on overflow" [
+                    "assert_eq!(Fix::from_num(6.25).checked_sqrt(), Some(Fix::from_num(2.5)));\n",
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);\n",
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);\n",
+                ];
+                saturating = saturating_sqrt => $Self::MAX; [
+                    "assert_eq!(Fix::from_num(6.25).saturating_sqrt(), Fix::from_num(2.5));\n",
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);\n",
+                ];
+                wrapping = wrapping_sqrt [
+                    "assert_eq!(Fix::from_num(6.25).wrapping_sqrt(), Fix::from_num(2.5));\n",
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));\n",
+                ];
+                unwrapped = unwrapped_sqrt [
+                    "assert_eq!(Fix::from_num(6.25).unwrapped_sqrt(), Fix::from_num(2.5));\n",
+                ] [
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "let quarter: AllFrac = Fix::from_num(0.25).to_num();\n",
+                    "let _overflow = quarter.unwrapped_sqrt();\n",
+                ];
+                overflowing = overflowing_sqrt [
+                    "assert_eq!(\n",
+                    "    Fix::from_num(6.25).overflowing_sqrt(),\n",
+                    "    (Fix::from_num(2.5), false)\n",
+                    ");\n",
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "assert_eq!(\n",
+                    "    AllFrac::from_num(0.25).overflowing_sqrt(),\n",
+                    "    (AllFrac::from_num(-0.5), true)\n",
+                    ");\n",
+                ] {
+                    if self.is_negative() {
+                        panic!("square root of negative number");
+                    }
+                    let root = self.sqrt_abs();
+                    ($Self::from_u256(root), is_neg(root))
+                }
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+            fixed256_variants! {
+                $Self;
+                "Square root", "square root", "square root",
+                "The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+Overflow cannot occur for unsigned values.
+
+";
+                fn [const] [] [self] () -> $Self<Frac>;
+                panics = [];
+                imports = [];
+                plain = sqrt (wrapping_sqrt) [
+                    "assert_eq!(Fix::from_num(6.25).sqrt(), Fix::from_num(2.5));\n",
+                    "// √2 ≈ 1.414 is rounded down\n",
+                    "assert_eq!(Fix::from_num(2).sqrt(), Fix::from_num(1.375));\n",
+                ];
+                checked = checked_sqrt, "on overflow" [
+                    "assert_eq!(Fix::from_num(6.25).checked_sqrt(), Some(Fix::from_num(2.5)));\n",
+                    "assert!(Fix::MAX.checked_sqrt().is_some());\n",
+                ];
+                saturating = saturating_sqrt => $Self::MAX; [
+                    "assert_eq!(Fix::from_num(6.25).saturating_sqrt(), Fix::from_num(2.5));\n",
+                ];
+                wrapping = wrapping_sqrt [
+                    "assert_eq!(Fix::from_num(6.25).wrapping_sqrt(), Fix::from_num(2.5));\n",
+                ];
+                overflowing = overflowing_sqrt [
+                    "assert_eq!(\n",
+                    "    Fix::from_num(6.25).overflowing_sqrt(),\n",
+                    "    (Fix::from_num(2.5), false)\n",
+                    ");\n",
+                ] {
+                    ($Self::from_u256(self.sqrt_abs()), false)
+                }
+            }
+
+            comment! {
+                "Unwrapped square root. Returns the square root.
+
+The result is rounded down, and the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+That is, result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+Can never overflow for unsigned types.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(6.25).unwrapped_sqrt(), Fix::from_num(2.5));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn unwrapped_sqrt(self) -> $Self<Frac> {
+                    $Self::from_u256(self.sqrt_abs())
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Reciprocal square root", "reciprocal square root", "reciprocal square root",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. The result is computed directly instead of by
+taking the reciprocal of [`sqrt`][Self::sqrt], so there is only one rounding
+step.
+
+Overflow occurs if `self` is so small that its reciprocal square root is greater
+than [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [if_signed_unsigned!(
+                $Signedness,
+                "Panics if the number is zero or negative.",
+                "Panics if the number is zero.",
+            )];
+            imports = [];
+            plain = rsqrt (wrapping_rsqrt) [
+                "assert_eq!(Fix::from_num(4).rsqrt(), Fix::from_num(0.5));\n",
+                "assert_eq!(Fix::from_num(0.25).rsqrt(), Fix::from_num(2));\n",
+            ];
+            checked = checked_rsqrt if self.is_non_positive(),
+            "if the number is ≤&nbsp;0 or on overflow" [
+                "assert_eq!(Fix::from_num(4).checked_rsqrt(), Some(Fix::from_num(0.5)));\n",
+                "assert_eq!(Fix::ZERO.checked_rsqrt(), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_rsqrt(), None);\n",
+                ),
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).checked_rsqrt(), None);\n",
+            ];
+            saturating = saturating_rsqrt => $Self::MAX; [
+                "assert_eq!(Fix::from_num(4).saturating_rsqrt(), Fix::from_num(0.5));\n",
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).saturating_rsqrt(), Small::MAX);\n",
+            ];
+            wrapping = wrapping_rsqrt [
+                "assert_eq!(Fix::from_num(4).wrapping_rsqrt(), Fix::from_num(0.5));\n",
+                "// only one integer bit, so 2 wraps to 0\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).wrapping_rsqrt(), Small::ZERO);\n",
+            ];
+            unwrapped = unwrapped_rsqrt [
+                "assert_eq!(Fix::from_num(4).unwrapped_rsqrt(), Fix::from_num(0.5));\n",
+            ] [
+                "// only one integer bit\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let quarter: Small = Fix::from_num(0.25).to_num();\n",
+                "let _overflow = quarter.unwrapped_rsqrt();\n",
+            ];
+            overflowing = overflowing_rsqrt [
+                "assert_eq!(Fix::from_num(4).overflowing_rsqrt(), (Fix::from_num(0.5), false));\n",
+                "// only one integer bit, so 2 wraps to 0\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(Small::from_num(0.25).overflowing_rsqrt(), (Small::ZERO, true));\n",
+            ] {
+                if self.is_zero() {
+                    panic!("division by zero");
+                }
+                if Self::IS_SIGNED && is_neg(self.to_u256()) {
+                    panic!("square root of negative number");
+                }
+                Self::from_float(self.to_float().sqrt().recip(), true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Base-2 exponential", "base-2 exponential", "base-2 exponential",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = exp2 (wrapping_exp2) [
+                "assert_eq!(Fix::from_num(2).exp2(), Fix::from_num(4));\n",
+                "// 2^0.5 ≈ 1.414\n",
+                "assert!(Fix::from_num(0.5).exp2().dist(Fix::from_num(1.414)) <= Fix::DELTA);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2).exp2(), Fix::from_num(0.25));\n",
+                ),
+            ];
+            checked = checked_exp2, "on overflow" [
+                "assert_eq!(Fix::from_num(2).checked_exp2(), Some(Fix::from_num(4)));\n",
+                "assert_eq!(Fix::MAX.checked_exp2(), None);\n",
+            ];
+            saturating = saturating_exp2 => $Self::MAX; [
+                "assert_eq!(Fix::from_num(2).saturating_exp2(), Fix::from_num(4));\n",
+                "assert_eq!(Fix::MAX.saturating_exp2(), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_exp2 [
+                "assert_eq!(Fix::from_num(2).wrapping_exp2(), Fix::from_num(4));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// 2^4.5 ≈ 22.627 wraps to 22.627 &minus; 16 = 6.627\n",
+                "let wrapped = Small::from_num(4.5).wrapping_exp2();\n",
+                "assert!(wrapped.dist(Small::from_num(6.627)) < Small::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_exp2 [
+                "assert_eq!(Fix::from_num(2).unwrapped_exp2(), Fix::from_num(4));\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_exp2();\n",
+            ];
+            overflowing = overflowing_exp2 [
+                "assert_eq!(Fix::from_num(2).overflowing_exp2(), (Fix::from_num(4), false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// 2^4.5 ≈ 22.627 wraps to 22.627 &minus; 16 = 6.627\n",
+                "let (wrapped, overflow) = Small::from_num(4.5).overflowing_exp2();\n",
+                "assert!(overflow);\n",
+                "assert!(wrapped.dist(Small::from_num(6.627)) < Small::from_num(0.001));\n",
+            ] {
+                Self::from_float(float::exp2(self.to_float()), true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Exponential", "exponential", "exponential",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = exp (wrapping_exp) [
+                "assert_eq!(Fix::ZERO.exp(), Fix::ONE);\n",
+                "// e ≈ 2.718\n",
+                "assert!(Fix::ONE.exp().dist(Fix::from_num(2.718)) <= Fix::DELTA);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// e^&minus;1 ≈ 0.368\n",
+                    "assert_eq!(Fix::from_num(-1).exp(), Fix::from_num(0.375));\n",
+                ),
+            ];
+            checked = checked_exp, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_exp(), Some(Fix::ONE));\n",
+                "assert_eq!(Fix::MAX.checked_exp(), None);\n",
+            ];
+            saturating = saturating_exp => $Self::MAX; [
+                "assert_eq!(Fix::ZERO.saturating_exp(), Fix::ONE);\n",
+                "assert_eq!(Fix::MAX.saturating_exp(), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_exp [
+                "assert_eq!(Fix::ZERO.wrapping_exp(), Fix::ONE);\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// e^3 ≈ 20.086 wraps to 20.086 &minus; 16 = 4.086\n",
+                "let wrapped = Small::from_num(3).wrapping_exp();\n",
+                "assert!(wrapped.dist(Small::from_num(4.086)) < Small::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_exp [
+                "assert_eq!(Fix::ZERO.unwrapped_exp(), Fix::ONE);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_exp();\n",
+            ];
+            overflowing = overflowing_exp [
+                "assert_eq!(Fix::ZERO.overflowing_exp(), (Fix::ONE, false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// e^3 ≈ 20.086 wraps to 20.086 &minus; 16 = 4.086\n",
+                "let (wrapped, overflow) = Small::from_num(3).overflowing_exp();\n",
+                "assert!(overflow);\n",
+                "assert!(wrapped.dist(Small::from_num(4.086)) < Small::from_num(0.001));\n",
+            ] {
+                Self::from_float(float::exp(self.to_float()), true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Exponential minus one", "exponential minus one", "exponential minus one",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Unlike `self.exp() - 1`, this method does not overflow when
+<i>e</i><sup><i>x</i></sup> is too large for the fixed-point number but
+<i>e</i><sup><i>x</i></sup>&nbsp;&minus;&nbsp;1 is not. Overflow occurs if the
+result is greater than [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = exp_m1 (wrapping_exp_m1) [
+                "assert_eq!(Fix::ZERO.exp_m1(), Fix::ZERO);\n",
+                "// e &minus; 1 ≈ 1.718\n",
+                "assert!(Fix::ONE.exp_m1().dist(Fix::from_num(1.718)) <= Fix::DELTA);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// e^&minus;1 &minus; 1 ≈ &minus;0.632\n",
+                    "assert_eq!(Fix::from_num(-1).exp_m1(), Fix::from_num(-0.625));\n",
+                ),
+            ];
+            checked = checked_exp_m1, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_exp_m1(), Some(Fix::ZERO));\n",
+                "assert_eq!(Fix::MAX.checked_exp_m1(), None);\n",
+            ];
+            saturating = saturating_exp_m1 => $Self::MAX; [
+                "assert_eq!(Fix::ZERO.saturating_exp_m1(), Fix::ZERO);\n",
+                "assert_eq!(Fix::MAX.saturating_exp_m1(), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_exp_m1 [
+                "assert_eq!(Fix::ZERO.wrapping_exp_m1(), Fix::ZERO);\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// e^3 &minus; 1 ≈ 19.086 wraps to 19.086 &minus; 16 = 3.086\n",
+                "let wrapped = Small::from_num(3).wrapping_exp_m1();\n",
+                "assert!(wrapped.dist(Small::from_num(3.086)) < Small::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_exp_m1 [
+                "assert_eq!(Fix::ZERO.unwrapped_exp_m1(), Fix::ZERO);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_exp_m1();\n",
+            ];
+            overflowing = overflowing_exp_m1 [
+                "assert_eq!(Fix::ZERO.overflowing_exp_m1(), (Fix::ZERO, false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// e^3 &minus; 1 ≈ 19.086 wraps to 19.086 &minus; 16 = 3.086\n",
+                "let (wrapped, overflow) = Small::from_num(3).overflowing_exp_m1();\n",
+                "assert!(overflow);\n",
+                "assert!(wrapped.dist(Small::from_num(3.086)) < Small::from_num(0.001));\n",
+            ] {
+                let e = float::exp(self.to_float());
+                Self::from_float(e.sub(float::Float::ONE), true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Integer power", "integer power", "value raised to the integer power `n`",
+            "The power is computed by squaring and multiplying with a wide intermediate
+precision, and the result is rounded to the nearest only at the end. The error
+is &lt;&nbsp;[`DELTA`][Self::DELTA]. If there are no fractional bits and
+`n`&nbsp;≥&nbsp;0, the result is exact.
+
+";
+            fn [const] [] [self] (n: i32) -> $Self<Frac>;
+            panics = ["Panics if `self` is zero and `n` is negative."];
+            imports = [];
+            plain = powi (wrapping_powi) [
+                "assert_eq!(Fix::from_num(1.5).powi(3), Fix::from_num(3.375));\n",
+                "assert_eq!(Fix::from_num(2).powi(-2), Fix::from_num(0.25));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2).powi(3), Fix::from_num(-8));\n",
+                ),
+            ];
+            checked = checked_powi if self.is_zero() && n < 0,
+            "if `self` is zero and `n` is negative or on overflow" [
+                "assert_eq!(Fix::from_num(1.5).checked_powi(3), Some(Fix::from_num(3.375)));\n",
+                "assert_eq!(Fix::ZERO.checked_powi(-1), None);\n",
+                "assert_eq!(Fix::MAX.checked_powi(2), None);\n",
+            ];
+            saturating = saturating_powi => $Self::saturated(
+                Self::IS_SIGNED && is_neg(self.to_u256()) && n % 2 != 0
+            ); [
+                "assert_eq!(Fix::from_num(1.5).saturating_powi(3), Fix::from_num(3.375));\n",
+                "assert_eq!(Fix::MAX.saturating_powi(2), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_powi(3), Fix::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_powi [
+                "assert_eq!(Fix::from_num(1.5).wrapping_powi(3), Fix::from_num(3.375));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// 2^4 = 16 wraps to 0\n",
+                "assert_eq!(Small::from_num(2).wrapping_powi(4), Small::ZERO);\n",
+            ];
+            unwrapped = unwrapped_powi [
+                "assert_eq!(Fix::from_num(1.5).unwrapped_powi(3), Fix::from_num(3.375));\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_powi(2);\n",
+            ];
+            overflowing = overflowing_powi [
+                "assert_eq!(\n",
+                "    Fix::from_num(1.5).overflowing_powi(2),\n",
+                "    (Fix::from_num(2.25), false)\n",
+                ");\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// 2^4 = 16 wraps to 0\n",
+                "assert_eq!(Small::from_num(2).overflowing_powi(4), (Small::ZERO, true));\n",
+            ] {
+                if Self::FRAC_NBITS == 0 && n >= 0 {
+                    let (bits, overflow) = self.to_bits().overflowing_pow(n as u32);
+                    return ($Self::from_bits(bits), overflow);
+                }
+                if self.is_zero() && n < 0 {
+                    panic!("division by zero");
+                }
+                Self::from_float(float::powi(self.to_float(), n), true)
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+            fixed256_variants! {
+                $Self;
+                "Power", "power", "value raised to the fixed-point power `exp`",
+                "The power is computed as
+<i>e</i><sup>`exp`&nbsp;×&nbsp;ln&nbsp;`self`</sup> with a wide intermediate
+precision. The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+";
+                fn [const] [] [self] (exp: $Self<Frac>) -> $Self<Frac>;
+                panics = ["Panics if `self` is negative, or if `self` is zero and `exp` is negative."];
+                imports = [];
+                plain = powf (wrapping_powf) [
+                    "assert_eq!(Fix::from_num(4).powf(Fix::from_num(0.5)), Fix::from_num(2));\n",
+                    "// 2^2.5 ≈ 5.657\n",
+                    "assert_eq!(Fix::from_num(2).powf(Fix::from_num(2.5)), Fix::from_num(5.6875));\n",
+                    "assert_eq!(Fix::from_num(4).powf(Fix::from_num(-0.5)), Fix::from_num(0.5));\n",
+                ];
+                checked = checked_powf if self.is_negative() || (self.is_zero() && exp.is_negative()),
+                "if `self` is negative, if `self` is zero and `exp` is negative, or on overflow" [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).checked_powf(half), Some(Fix::from_num(2)));\n",
+                    "assert_eq!(Fix::MAX.checked_powf(Fix::from_num(2)), None);\n",
+                    "assert_eq!(Fix::from_num(-1).checked_powf(Fix::ONE), None);\n",
+                    "assert_eq!(Fix::ZERO.checked_powf(-half), None);\n",
+                ];
+                saturating = saturating_powf => $Self::MAX; [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).saturating_powf(half), Fix::from_num(2));\n",
+                    "assert_eq!(Fix::MAX.saturating_powf(Fix::from_num(2)), Fix::MAX);\n",
+                ];
+                wrapping = wrapping_powf [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).wrapping_powf(half), Fix::from_num(2));\n",
+                    "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                    "// 4^2 = 16 wraps to 0\n",
+                    "let two = Small::from_num(2);\n",
+                    "assert_eq!(Small::from_num(4).wrapping_powf(two), Small::ZERO);\n",
+                ];
+                unwrapped = unwrapped_powf [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).unwrapped_powf(half), Fix::from_num(2));\n",
+                ] [
+                    "let _overflow = Fix::MAX.unwrapped_powf(Fix::from_num(2));\n",
+                ];
+                overflowing = overflowing_powf [
+                    "assert_eq!(\n",
+                    "    Fix::from_num(4).overflowing_powf(Fix::from_num(0.5)),\n",
+                    "    (Fix::from_num(2), false)\n",
+                    ");\n",
+                    "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                    "// 4^2 = 16 wraps to 0\n",
+                    "let two = Small::from_num(2);\n",
+                    "assert_eq!(Small::from_num(4).overflowing_powf(two), (Small::ZERO, true));\n",
+                ] {
+                    if self.is_negative() {
+                        panic!("negative base");
+                    }
+                    if self.is_zero() {
+                        if exp.is_negative() {
+                            panic!("division by zero");
+                        }
+                        if !exp.is_zero() {
+                            return ($Self::ZERO, false);
+                        }
+                    }
+                    Self::from_float(float::powf(self.to_float(), exp.to_float()), true)
+                }
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+            fixed256_variants! {
+                $Self;
+                "Power", "power", "value raised to the fixed-point power `exp`",
+                "The power is computed as
+<i>e</i><sup>`exp`&nbsp;×&nbsp;ln&nbsp;`self`</sup> with a wide intermediate
+precision. The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+";
+                fn [const] [] [self] (exp: $Self<Frac>) -> $Self<Frac>;
+                panics = [];
+                imports = [];
+                plain = powf (wrapping_powf) [
+                    "assert_eq!(Fix::from_num(4).powf(Fix::from_num(0.5)), Fix::from_num(2));\n",
+                    "// 2^2.5 ≈ 5.657\n",
+                    "assert_eq!(Fix::from_num(2).powf(Fix::from_num(2.5)), Fix::from_num(5.6875));\n",
+                ];
+                checked = checked_powf, "on overflow" [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).checked_powf(half), Some(Fix::from_num(2)));\n",
+                    "assert_eq!(Fix::MAX.checked_powf(Fix::from_num(2)), None);\n",
+                ];
+                saturating = saturating_powf => $Self::MAX; [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).saturating_powf(half), Fix::from_num(2));\n",
+                    "assert_eq!(Fix::MAX.saturating_powf(Fix::from_num(2)), Fix::MAX);\n",
+                ];
+                wrapping = wrapping_powf [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).wrapping_powf(half), Fix::from_num(2));\n",
+                    "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                    "// 4^2 = 16 wraps to 0\n",
+                    "let two = Small::from_num(2);\n",
+                    "assert_eq!(Small::from_num(4).wrapping_powf(two), Small::ZERO);\n",
+                ];
+                unwrapped = unwrapped_powf [
+                    "let half = Fix::from_num(0.5);\n",
+                    "assert_eq!(Fix::from_num(4).unwrapped_powf(half), Fix::from_num(2));\n",
+                ] [
+                    "let _overflow = Fix::MAX.unwrapped_powf(Fix::from_num(2));\n",
+                ];
+                overflowing = overflowing_powf [
+                    "assert_eq!(\n",
+                    "    Fix::from_num(4).overflowing_powf(Fix::from_num(0.5)),\n",
+                    "    (Fix::from_num(2), false)\n",
+                    ");\n",
+                    "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                    "// 4^2 = 16 wraps to 0\n",
+                    "let two = Small::from_num(2);\n",
+                    "assert_eq!(Small::from_num(4).overflowing_powf(two), (Small::ZERO, true));\n",
+                ] {
+                    if self.is_zero() && !exp.is_zero() {
+                        return ($Self::ZERO, false);
+                    }
+                    Self::from_float(float::powf(self.to_float(), exp.to_float()), true)
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Cube root", "cube root", "cube root",
+            concat!(
+                "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].",
+                if_signed_else_empty_str!($Signedness; " The cube root of a negative number is negative."),
+                "
+
+Overflow can only occur when the fixed-point number cannot represent 1, as the
+cube root of a number with a magnitude less than 1 is closer to 1.
+
+",
+            );
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = cbrt (wrapping_cbrt) [
+                "assert_eq!(Fix::from_num(3.375).cbrt(), Fix::from_num(1.5));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.375).cbrt(), Fix::from_num(-1.5));\n",
+                ),
+            ];
+            checked = checked_cbrt, "on overflow" [
+                "assert_eq!(Fix::from_num(3.375).checked_cbrt(), Some(Fix::from_num(1.5)));\n",
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert_eq!(AllFrac::MAX.checked_cbrt(), None);\n",
+            ];
+            saturating = saturating_cbrt => $Self::saturated(
+                Self::IS_SIGNED && is_neg(self.to_u256())
+            ); [
+                "assert_eq!(Fix::from_num(3.375).saturating_cbrt(), Fix::from_num(1.5));\n",
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert_eq!(AllFrac::MAX.saturating_cbrt(), AllFrac::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(AllFrac::MIN.saturating_cbrt(), AllFrac::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_cbrt [
+                "assert_eq!(Fix::from_num(3.375).wrapping_cbrt(), Fix::from_num(1.5));\n",
+                if_unsigned_else_empty_str!(
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "// the cube root of MAX rounds to 1, which wraps to 0\n",
+                    "assert_eq!(AllFrac::MAX.wrapping_cbrt(), AllFrac::ZERO);\n",
+                ),
+            ];
+            unwrapped = unwrapped_cbrt [
+                "assert_eq!(Fix::from_num(3.375).unwrapped_cbrt(), Fix::from_num(1.5));\n",
+            ] [
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "let max: AllFrac = Fix::MAX.saturating_to_num();\n",
+                "let _overflow = max.unwrapped_cbrt();\n",
+            ];
+            overflowing = overflowing_cbrt [
+                "assert_eq!(\n",
+                "    Fix::from_num(3.375).overflowing_cbrt(),\n",
+                "    (Fix::from_num(1.5), false)\n",
+                ");\n",
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert!(AllFrac::MAX.overflowing_cbrt().1);\n",
+            ] {
+                self.overflowing_nth_root(3)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "<i>n</i>th root", "<i>n</i>th root", "<i>n</i>th root",
+            concat!(
+                "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    " If `n` is odd, the root of a negative number is negative.",
+                ),
+                "
+
+Overflow can only occur when the fixed-point number cannot represent 1, as the
+root of a number with a magnitude less than 1 is closer to 1.
+
+",
+            );
+            fn [const] [] [self] (n: u32) -> $Self<Frac>;
+            panics = [if_signed_unsigned!(
+                $Signedness,
+                "Panics if `n` is zero, or if `n` is even and the number is negative.",
+                "Panics if `n` is zero.",
+            )];
+            imports = [];
+            plain = nth_root (wrapping_nth_root) [
+                "assert_eq!(Fix::from_num(5.0625).nth_root(4), Fix::from_num(1.5));\n",
+                "assert_eq!(Fix::from_num(0.25).nth_root(2), Fix::from_num(0.5));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.125).nth_root(3), Fix::from_num(-0.5));\n",
+                ),
+            ];
+            checked = checked_nth_root
+            if n == 0 || (n % 2 == 0 && Self::IS_SIGNED && is_neg(self.to_u256())),
+            "if `n` is zero, if `n` is even and the number is negative, or on overflow" [
+                "let x = Fix::from_num(5.0625);\n",
+                "assert_eq!(x.checked_nth_root(4), Some(Fix::from_num(1.5)));\n",
+                "assert_eq!(x.checked_nth_root(0), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!((-x).checked_nth_root(4), None);\n",
+                ),
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert_eq!(AllFrac::MAX.checked_nth_root(4), None);\n",
+            ];
+            saturating = saturating_nth_root => $Self::saturated(
+                Self::IS_SIGNED && is_neg(self.to_u256())
+            ); [
+                "assert_eq!(Fix::from_num(5.0625).saturating_nth_root(4), Fix::from_num(1.5));\n",
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert_eq!(AllFrac::MAX.saturating_nth_root(4), AllFrac::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(AllFrac::MIN.saturating_nth_root(3), AllFrac::MIN);\n",
+                ),
+            ];
+            wrapping = wrapping_nth_root [
+                "assert_eq!(Fix::from_num(5.0625).wrapping_nth_root(4), Fix::from_num(1.5));\n",
+                if_unsigned_else_empty_str!(
+                    $Signedness;
+                    "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                    "// the root of MAX rounds to 1, which wraps to 0\n",
+                    "assert_eq!(AllFrac::MAX.wrapping_nth_root(4), AllFrac::ZERO);\n",
+                ),
+            ];
+            unwrapped = unwrapped_nth_root [
+                "assert_eq!(Fix::from_num(5.0625).unwrapped_nth_root(4), Fix::from_num(1.5));\n",
+            ] [
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "let max: AllFrac = Fix::MAX.saturating_to_num();\n",
+                "let _overflow = max.unwrapped_nth_root(4);\n",
+            ];
+            overflowing = overflowing_nth_root [
+                "assert_eq!(\n",
+                "    Fix::from_num(0.0625).overflowing_nth_root(2),\n",
+                "    (Fix::from_num(0.25), false)\n",
+                ");\n",
+                "type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;\n",
+                "assert!(AllFrac::MAX.overflowing_nth_root(4).1);\n",
+            ] {
+                if n == 0 {
+                    panic!("zeroth root");
+                }
+                if n % 2 == 0 && Self::IS_SIGNED && is_neg(self.to_u256()) {
+                    panic!("even root of negative number");
+                }
+                if n == 1 || self.is_zero() {
+                    return (self, false);
+                }
+                Self::from_float(float::root(self.to_float(), n), true)
+            }
+        }
+
+        comment! {
+            "Base-2 logarithm.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).log2(), Fix::from_num(2));
+assert_eq!(Fix::ONE.log2(), Fix::ZERO);
+// log₂ 3 ≈ 1.585
+assert_eq!(Fix::from_num(3).log2(), Fix::from_num(1.5625));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(0.25).log2(), Fix::from_num(-2));
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn log2(self) -> $Self<Frac> {
+                if self.is_non_positive() {
+                    panic!("log of non-positive number");
+                }
+                match Self::from_float(float::log2(self.to_float()), true) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Natural logarithm.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.ln(), Fix::ZERO);
+// ln 2 ≈ 0.693
+assert_eq!(Fix::from_num(2).ln(), Fix::from_num(0.6875));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "// ln 0.5 ≈ &minus;0.693
+assert_eq!(Fix::from_num(0.5).ln(), Fix::from_num(-0.6875));
+",
+            },
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn ln(self) -> $Self<Frac> {
+                if self.is_non_positive() {
+                    panic!("log of non-positive number");
+                }
+                match Self::from_float(float::ln(self.to_float()), true) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Base-10 logarithm.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.log10(), Fix::ZERO);
+// log₁₀ 5 ≈ 0.699
+assert_eq!(Fix::from_num(5).log10(), Fix::from_num(0.6875));
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn log10(self) -> $Self<Frac> {
+                if self.is_non_positive() {
+                    panic!("log of non-positive number");
+                }
+                match Self::from_float(float::log10(self.to_float()), true) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Checked base-2 logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`log2`][Self::log2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_log2(), None);
+assert_eq!(Fix::from_num(4).checked_log2(), Some(Fix::from_num(2)));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-4).checked_log2(), None);
+",
+            },
+            "
+type AllFrac = ", stringify!($Self), "<fixed::types::extra::U256>;
+assert_eq!(AllFrac::from_num(0.25).checked_log2(), None);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_log2(self) -> Option<$Self<Frac>> {
+                if self.is_non_positive() {
+                    return None;
+                }
+                match Self::from_float(float::log2(self.to_float()), true) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Checked natural logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`ln`][Self::ln] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_ln(), None);
+assert_eq!(Fix::ONE.checked_ln(), Some(Fix::ZERO));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-1).checked_ln(), None);
+",
+            },
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_ln(self) -> Option<$Self<Frac>> {
+                if self.is_non_positive() {
+                    return None;
+                }
+                match Self::from_float(float::ln(self.to_float()), true) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+
+        comment! {
+            "Checked base-10 logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`log10`][Self::log10] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_log10(), None);
+assert_eq!(Fix::ONE.checked_log10(), Some(Fix::ZERO));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_num(-1).checked_log10(), None);
+",
+            },
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_log10(self) -> Option<$Self<Frac>> {
+                if self.is_non_positive() {
+                    return None;
+                }
+                match Self::from_float(float::log10(self.to_float()), true) {
+                    (ans, false) => Some(ans),
+                    (_, true) => None,
+                }
+            }
+        }
+    };
+}
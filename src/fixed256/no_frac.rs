@@ -0,0 +1,1752 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Methods of the 256-bit fixed-point numbers that are independent of the
+// number of fractional bits. This macro is expanded inside the impl<Frac>
+// block of the fixed256! macro.
+macro_rules! fixed256_no_frac {
+    (
+        {Self, ISelf, USelf} = {$Self:ident, $ISelf:ident, $USelf:ident},
+        Signedness = $Signedness:ident,
+        {Bits, from_prim} = {$Bits:ident, $from_prim:ident},
+    ) => {
+        comment! {
+            "Converts a fixed-point number from big endian to the target’s endianness.
+
+On big endian this is a no-op. On little endian the bytes are swapped.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0x1234_5678"), ");
+if cfg!(target_endian = \"big\") {
+    assert_eq!(Fix::from_be(f), f);
+} else {
+    assert_eq!(Fix::from_be(f), f.swap_bytes());
+}
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn from_be(f: $Self<Frac>) -> $Self<Frac> {
+                $Self::from_bits($Bits::from_be(f.bits))
+            }
+        }
+
+        comment! {
+            "Converts a fixed-point number from little endian to the target’s endianness.
+
+On little endian this is a no-op. On big endian the bytes are swapped.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0x1234_5678"), ");
+if cfg!(target_endian = \"little\") {
+    assert_eq!(Fix::from_le(f), f);
+} else {
+    assert_eq!(Fix::from_le(f), f.swap_bytes());
+}
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn from_le(f: $Self<Frac>) -> $Self<Frac> {
+                $Self::from_bits($Bits::from_le(f.bits))
+            }
+        }
+
+        comment! {
+            "Converts `self` to big endian from the target’s endianness.
+
+On big endian this is a no-op. On little endian the bytes are swapped.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0x1234_5678"), ");
+if cfg!(target_endian = \"big\") {
+    assert_eq!(f.to_be(), f);
+} else {
+    assert_eq!(f.to_be(), f.swap_bytes());
+}
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn to_be(self) -> $Self<Frac> {
+                $Self::from_bits(self.bits.to_be())
+            }
+        }
+
+        comment! {
+            "Converts `self` to little endian from the target’s endianness.
+
+On little endian this is a no-op. On big endian the bytes are swapped.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0x1234_5678"), ");
+if cfg!(target_endian = \"little\") {
+    assert_eq!(f.to_le(), f);
+} else {
+    assert_eq!(f.to_le(), f.swap_bytes());
+}
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn to_le(self) -> $Self<Frac> {
+                $Self::from_bits(self.bits.to_le())
+            }
+        }
+
+        comment! {
+            "Reverses the byte order of the fixed-point number.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0x12"), ");
+let swapped = Fix::from_be_bytes(f.to_le_bytes());
+assert_eq!(f.swap_bytes(), swapped);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn swap_bytes(self) -> $Self<Frac> {
+                $Self::from_bits(self.bits.swap_bytes())
+            }
+        }
+
+        comment! {
+            "Reverses the order of the bits of the fixed-point number.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let msb = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "1"), " << 255);
+assert_eq!(Fix::DELTA.reverse_bits(), msb);
+assert_eq!(Fix::ZERO.reverse_bits(), Fix::ZERO);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn reverse_bits(self) -> $Self<Frac> {
+                $Self::from_bits(self.bits.reverse_bits())
+            }
+        }
+
+        comment! {
+            "Returns the number of leading ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let all_ones = !Fix::ZERO;
+let f = all_ones - Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b10_0000"), ");
+assert_eq!(f.leading_ones(), 256 - 6);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn leading_ones(self) -> u32 {
+                self.bits.leading_ones()
+            }
+        }
+
+        comment! {
+            "Returns the number of trailing ones in the binary representation.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let f = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b101_1111"), ");
+assert_eq!(f.trailing_ones(), 5);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn trailing_ones(self) -> u32 {
+                self.bits.trailing_ones()
+            }
+        }
+
+        comment! {
+            "Shifts to the left by `n` bits, wrapping the truncated bits to the right end.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let bits = ", stringify!($Bits), "::MAX.rotate_left(4);
+let f = Fix::from_bits(bits);
+assert_eq!(f.rotate_left(3), Fix::from_bits(bits.rotate_left(3)));
+assert_eq!(Fix::DELTA.rotate_left(256 + 4), Fix::ONE);
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn rotate_left(self, n: u32) -> $Self<Frac> {
+                $Self::from_bits(self.bits.rotate_left(n))
+            }
+        }
+
+        comment! {
+            "Shifts to the right by `n` bits, wrapping the truncated bits to the left end.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.rotate_right(4), Fix::DELTA);
+assert_eq!(Fix::DELTA.rotate_right(1), Fix::DELTA.rotate_left(255));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn rotate_right(self, n: u32) -> $Self<Frac> {
+                $Self::from_bits(self.bits.rotate_right(n))
+            }
+        }
+
+        comment! {
+            "Bitwise NOT. Usable in constant context.
+
+This is equivalent to the `!` operator and
+<code>[Not]::[not][Not::not]</code>, but can also be used in constant context.
+Unless required in constant context, use the operator or trait instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+const A: Fix = Fix::ZERO;
+const NOT_A: Fix = A.const_not();
+assert_eq!(NOT_A, !A);
+assert_eq!(NOT_A, ", if_signed_unsigned!($Signedness, "-Fix::DELTA", "Fix::MAX"), ");
+```
+
+[Not]: core::ops::Not
+[Not::not]: core::ops::Not::not
+";
+            #[inline]
+            #[must_use]
+            pub const fn const_not(self) -> $Self<Frac> {
+                $Self::from_u256(not(self.to_u256()))
+            }
+        }
+
+        comment! {
+            "Bitwise AND. Usable in constant context.
+
+This is equivalent to the `&` operator and
+<code>[BitAnd]::[bitand][BitAnd::bitand]</code>, but can also be used in
+constant context. Unless required in constant context, use the operator or
+trait instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+const A: Fix = Fix::lit(\"0b1100\");
+const B: Fix = Fix::lit(\"0b1010\");
+const A_AND_B: Fix = A.const_bitand(B);
+assert_eq!(A_AND_B, A & B);
+assert_eq!(A_AND_B, Fix::lit(\"0b1000\"));
+```
+
+[BitAnd]: core::ops::BitAnd
+[BitAnd::bitand]: core::ops::BitAnd::bitand
+";
+            #[inline]
+            #[must_use]
+            pub const fn const_bitand(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                $Self::from_u256(and(self.to_u256(), rhs.to_u256()))
+            }
+        }
+
+        comment! {
+            "Bitwise OR. Usable in constant context.
+
+This is equivalent to the `|` operator and
+<code>[BitOr]::[bitor][BitOr::bitor]</code>, but can also be used in constant
+context. Unless required in constant context, use the operator or trait instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+const A: Fix = Fix::lit(\"0b1100\");
+const B: Fix = Fix::lit(\"0b1010\");
+const A_OR_B: Fix = A.const_bitor(B);
+assert_eq!(A_OR_B, A | B);
+assert_eq!(A_OR_B, Fix::lit(\"0b1110\"));
+```
+
+[BitOr]: core::ops::BitOr
+[BitOr::bitor]: core::ops::BitOr::bitor
+";
+            #[inline]
+            #[must_use]
+            pub const fn const_bitor(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                $Self::from_u256(or(self.to_u256(), rhs.to_u256()))
+            }
+        }
+
+        comment! {
+            "Bitwise XOR. Usable in constant context.
+
+This is equivalent to the `^` operator and
+<code>[BitXor]::[bitxor][BitXor::bitxor]</code>, but can also be used in
+constant context. Unless required in constant context, use the operator or
+trait instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+const A: Fix = Fix::lit(\"0b1100\");
+const B: Fix = Fix::lit(\"0b1010\");
+const A_XOR_B: Fix = A.const_bitxor(B);
+assert_eq!(A_XOR_B, A ^ B);
+assert_eq!(A_XOR_B, Fix::lit(\"0b0110\"));
+```
+
+[BitXor]: core::ops::BitXor
+[BitXor::bitxor]: core::ops::BitXor::bitxor
+";
+            #[inline]
+            #[must_use]
+            pub const fn const_bitxor(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                $Self::from_u256(xor(self.to_u256(), rhs.to_u256()))
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+
+            comment! {
+                "Returns the number of bits required to represent the value.
+
+The number of bits required includes an initial one for negative
+numbers, and an initial zero for non-negative numbers.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(-3).signed_bits(), 7);      // “_101.0000”
+assert_eq!(Fix::from_num(-1).signed_bits(), 5);      // “___1.0000”
+assert_eq!(Fix::from_num(-0.0625).signed_bits(), 1); // “____.___1”
+assert_eq!(Fix::from_num(0).signed_bits(), 1);       // “____.___0”
+assert_eq!(Fix::from_num(0.0625).signed_bits(), 2);  // “____.__01”
+assert_eq!(Fix::from_num(1).signed_bits(), 6);       // “__01.0000”
+assert_eq!(Fix::from_num(3).signed_bits(), 7);       // “_011.0000”
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn signed_bits(self) -> u32 {
+                    let leading = if self.is_negative() {
+                        self.leading_ones()
+                    } else {
+                        self.leading_zeros()
+                    };
+                    256 + 1 - leading
+                }
+            }
+
+            comment! {
+                "Returns the bit pattern of `self` reinterpreted as an
+unsigned fixed-point number of the same size.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+let n = -", stringify!($Self), "::<U4>::DELTA;
+assert_eq!(n.cast_unsigned(), ", stringify!($USelf), "::<U4>::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn cast_unsigned(self) -> $USelf<Frac> {
+                    $USelf::from_u256(self.to_u256())
+                }
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+
+            comment! {
+                "Returns the number of bits required to represent the value.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(0).significant_bits(), 0);      // “____.____”
+assert_eq!(Fix::from_num(0.0625).significant_bits(), 1); // “____.___1”
+assert_eq!(Fix::from_num(1).significant_bits(), 5);      // “___1.0000”
+assert_eq!(Fix::from_num(3).significant_bits(), 6);      // “__11.0000”
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn significant_bits(self) -> u32 {
+                    256 - self.leading_zeros()
+                }
+            }
+
+            comment! {
+                "Returns the highest one in the binary
+representation, or zero if `self` is zero.
+
+If `self`&nbsp;>&nbsp;0, the highest one is equal to the largest power of two
+that is ≤&nbsp;`self`.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(
+    Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b11_0010"), ").highest_one(),
+    Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b10_0000"), ")
+);
+assert_eq!(Fix::from_num(0.3).highest_one(), Fix::from_num(0.25));
+assert_eq!(Fix::from_num(4).highest_one(), Fix::from_num(4));
+assert_eq!(Fix::from_num(6.5).highest_one(), Fix::from_num(4));
+assert_eq!(Fix::ZERO.highest_one(), Fix::ZERO);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn highest_one(self) -> $Self<Frac> {
+                    if self.is_zero() {
+                        self
+                    } else {
+                        let one = U256 { lo: 1, hi: 0 };
+                        $Self::from_u256(int256::wrapping_shl_u256(one, 255 - self.leading_zeros()))
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the bit pattern of `self` reinterpreted as a signed
+fixed-point number of the same size.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($ISelf), ", ", stringify!($Self), "};
+let n = ", stringify!($Self), "::<U4>::MAX;
+assert_eq!(n.cast_signed(), -", stringify!($ISelf), "::<U4>::DELTA);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn cast_signed(self) -> $ISelf<Frac> {
+                    $ISelf::from_u256(self.to_u256())
+                }
+            }
+        }
+
+        comment! {
+            "Unchecked addition. Computes `self`&nbsp;+&nbsp;`rhs`, assuming
+overflow cannot occur.
+
+Calling `x.unchecked_add(y)` is semantically equivalent to calling
+<code>x.[checked\\_add][Self::checked_add](y).[unwrap\\_unchecked][Option::unwrap_unchecked]()</code>.
+
+If you’re just trying to avoid the panic in debug mode, then **do not** use
+this. Instead, you’re looking for [`wrapping_add`][Self::wrapping_add].
+
+# Safety
+
+This results in undefined behavior when
+`self`&nbsp;+&nbsp;`rhs`&nbsp;\\>&nbsp;[`MAX`][Self::MAX] or
+`self`&nbsp;+&nbsp;`rhs`&nbsp;\\<&nbsp;[`MIN`][Self::MIN], that is when
+[`checked_add`][Self::checked_add] would return [`None`].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let two = Fix::from_num(2);
+let three = Fix::from_num(3);
+assert_eq!(unsafe { two.unchecked_add(three) }, 5);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const unsafe fn unchecked_add(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                // SAFETY: the caller guarantees that the addition does not overflow
+                unsafe { self.checked_add(rhs).unwrap_unchecked() }
+            }
+        }
+
+        comment! {
+            "Unchecked subtraction. Computes `self`&nbsp;&minus;&nbsp;`rhs`,
+assuming overflow cannot occur.
+
+Calling `x.unchecked_sub(y)` is semantically equivalent to calling
+<code>x.[checked\\_sub][Self::checked_sub](y).[unwrap\\_unchecked][Option::unwrap_unchecked]()</code>.
+
+If you’re just trying to avoid the panic in debug mode, then **do not** use
+this. Instead, you’re looking for [`wrapping_sub`][Self::wrapping_sub].
+
+# Safety
+
+This results in undefined behavior when
+`self`&nbsp;&minus;&nbsp;`rhs`&nbsp;\\>&nbsp;[`MAX`][Self::MAX] or
+`self`&nbsp;&minus;&nbsp;`rhs`&nbsp;\\<&nbsp;[`MIN`][Self::MIN], that is when
+[`checked_sub`][Self::checked_sub] would return [`None`].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let two = Fix::from_num(2);
+let five = Fix::from_num(5);
+assert_eq!(unsafe { five.unchecked_sub(two) }, 3);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const unsafe fn unchecked_sub(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                // SAFETY: the caller guarantees that the subtraction does not overflow
+                unsafe { self.checked_sub(rhs).unwrap_unchecked() }
+            }
+        }
+
+        comment! {
+            "Unchecked multiplication by an integer. Computes
+`self`&nbsp;×&nbsp;`rhs`, assuming overflow cannot occur.
+
+Calling `x.unchecked_mul_int(y)` is semantically equivalent to calling
+<code>x.[checked\\_mul\\_int][Self::checked_mul_int](y).[unwrap\\_unchecked][Option::unwrap_unchecked]()</code>.
+
+If you’re just trying to avoid the panic in debug mode, then **do not** use
+this. Instead, you’re looking for [`wrapping_mul_int`][Self::wrapping_mul_int].
+
+# Safety
+
+This results in undefined behavior when
+`self`&nbsp;×&nbsp;`rhs`&nbsp;\\>&nbsp;[`MAX`][Self::MAX] or
+`self`&nbsp;×&nbsp;`rhs`&nbsp;\\<&nbsp;[`MIN`][Self::MIN], that is when
+[`checked_mul_int`][Self::checked_mul_int] would return [`None`].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+let two = ", fixed256_int!($Bits, $from_prim, "2"), ";
+assert_eq!(unsafe { Fix::from_num(3).unchecked_mul_int(two) }, 6);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const unsafe fn unchecked_mul_int(self, rhs: $Bits) -> $Self<Frac> {
+                // SAFETY: the caller guarantees that the multiplication does not overflow
+                unsafe { self.checked_mul_int(rhs).unwrap_unchecked() }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Multiplication by an integer", "multiplication by an integer", "product", "";
+            fn [const] [] [self] (rhs: $Bits) -> $Self<Frac>;
+            panics = [];
+            imports = [$Bits];
+            checked = checked_mul_int, "on overflow" [
+                "assert_eq!(Fix::MAX.checked_mul_int(", fixed256_int!($Bits, $from_prim, "1"),
+                "), Some(Fix::MAX));\n",
+                "assert_eq!(Fix::MAX.checked_mul_int(", fixed256_int!($Bits, $from_prim, "2"),
+                "), None);\n",
+            ];
+            saturating = saturating_mul_int => if_signed_unsigned!(
+                $Signedness,
+                if self.is_negative() != rhs.is_negative() {
+                    $Self::MIN
+                } else {
+                    $Self::MAX
+                },
+                $Self::MAX,
+            ); [
+                "assert_eq!(Fix::from_num(3).saturating_mul_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), 6);\n",
+                "assert_eq!(Fix::MAX.saturating_mul_int(", fixed256_int!($Bits, $from_prim, "2"),
+                "), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_mul_int [
+                "assert_eq!(Fix::from_num(3).wrapping_mul_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), 6);\n",
+                "let wrapped = Fix::from_bits(!", fixed256_int!($Bits, $from_prim, "0"), " << 2);\n",
+                "assert_eq!(Fix::MAX.wrapping_mul_int(", fixed256_int!($Bits, $from_prim, "4"),
+                "), wrapped);\n",
+            ];
+            unwrapped = unwrapped_mul_int [
+                "assert_eq!(Fix::from_num(3).unwrapped_mul_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), 6);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_mul_int(",
+                fixed256_int!($Bits, $from_prim, "4"), ");\n",
+            ];
+            overflowing = overflowing_mul_int [
+                "assert_eq!(Fix::from_num(3).overflowing_mul_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), (Fix::from_num(6), false));\n",
+                "let wrapped = Fix::from_bits(!", fixed256_int!($Bits, $from_prim, "0"), " << 2);\n",
+                "assert_eq!(Fix::MAX.overflowing_mul_int(", fixed256_int!($Bits, $from_prim, "4"),
+                "), (wrapped, true));\n",
+            ] {
+                let (ans, overflow) = self.bits.overflowing_mul(rhs);
+                ($Self::from_bits(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Division by an integer", "division by an integer", "quotient",
+            if_signed_unsigned!(
+                $Signedness,
+                "Overflow can only occur when dividing the minimum value by &minus;1.
+
+",
+                "Overflow cannot occur for unsigned values.
+
+",
+            );
+            fn [const] [] [self] (rhs: $Bits) -> $Self<Frac>;
+            panics = ["Panics if the divisor is zero."];
+            imports = [$Bits];
+            checked = checked_div_int if rhs.is_zero(), "if the divisor is zero or on overflow" [
+                "assert_eq!(Fix::MAX.checked_div_int(", fixed256_int!($Bits, $from_prim, "1"),
+                "), Some(Fix::MAX));\n",
+                "assert_eq!(Fix::ONE.checked_div_int(", fixed256_int!($Bits, $from_prim, "0"),
+                "), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_int(", fixed256_int!($Bits, $from_prim, "-1"),
+                    "), None);\n",
+                ),
+            ];
+            saturating = saturating_div_int => $Self::MAX; [
+                "// 1.5 is binary 1.1\n",
+                "let one_point_5 = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b11 << (4 - 1)"),
+                ");\n",
+                "assert_eq!(Fix::from_num(3).saturating_div_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), one_point_5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.saturating_div_int(", fixed256_int!($Bits, $from_prim, "-1"),
+                    "), Fix::MAX);\n",
+                ),
+            ];
+            wrapping = wrapping_div_int [
+                "// 1.5 is binary 1.1\n",
+                "let one_point_5 = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b11 << (4 - 1)"),
+                ");\n",
+                "assert_eq!(Fix::from_num(3).wrapping_div_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), one_point_5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.wrapping_div_int(", fixed256_int!($Bits, $from_prim, "-1"),
+                    "), Fix::MIN);\n",
+                ),
+            ];
+            unwrapped = unwrapped_div_int [
+                "// 1.5 is binary 1.1\n",
+                "let one_point_5 = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b11 << (4 - 1)"),
+                ");\n",
+                "assert_eq!(Fix::from_num(3).unwrapped_div_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), one_point_5);\n",
+            ] [
+                "let _divisor_is_zero = Fix::ONE.unwrapped_div_int(",
+                fixed256_int!($Bits, $from_prim, "0"), ");\n",
+            ];
+            overflowing = overflowing_div_int [
+                "// 1.5 is binary 1.1\n",
+                "let one_point_5 = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b11 << (4 - 1)"),
+                ");\n",
+                "assert_eq!(Fix::from_num(3).overflowing_div_int(",
+                fixed256_int!($Bits, $from_prim, "2"), "), (one_point_5, false));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.overflowing_div_int(", fixed256_int!($Bits, $from_prim, "-1"),
+                    "), (Fix::MIN, true));\n",
+                ),
+            ] {
+                let (ans, overflow) = self.bits.overflowing_div(rhs);
+                ($Self::from_bits(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Shift left", "shift left", "shifted number",
+            "Overflow occurs when `rhs`&nbsp;≥&nbsp;256. On overflow `rhs` is wrapped
+before the shift operation.
+
+";
+            fn [const] [] [self] (rhs: u32) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            checked = checked_shl, "if `rhs`&nbsp;≥&nbsp;256" [
+                "assert_eq!(Fix::from_num(0.5).checked_shl(3), Some(Fix::from_num(4)));\n",
+                "assert_eq!(Fix::from_num(0.5).checked_shl(256), None);\n",
+            ];
+            wrapping = wrapping_shl [
+                "assert_eq!(Fix::from_num(0.5).wrapping_shl(3), Fix::from_num(4));\n",
+                "assert_eq!(Fix::from_num(0.5).wrapping_shl(3 + 256), Fix::from_num(4));\n",
+            ];
+            unwrapped = unwrapped_shl [
+                "assert_eq!(Fix::from_num(0.5).unwrapped_shl(3), Fix::from_num(4));\n",
+            ] [
+                "let _overflow = Fix::ONE.unwrapped_shl(256);\n",
+            ];
+            overflowing = overflowing_shl [
+                "assert_eq!(Fix::from_num(0.5).overflowing_shl(3), (Fix::from_num(4), false));\n",
+                "assert_eq!(Fix::from_num(0.5).overflowing_shl(3 + 256), (Fix::from_num(4), true));\n",
+            ] {
+                let (ans, overflow) = self.bits.overflowing_shl(rhs);
+                ($Self::from_bits(ans), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Shift right", "shift right", "shifted number",
+            "Overflow occurs when `rhs`&nbsp;≥&nbsp;256. On overflow `rhs` is wrapped
+before the shift operation.
+
+";
+            fn [const] [] [self] (rhs: u32) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            checked = checked_shr, "if `rhs`&nbsp;≥&nbsp;256" [
+                "assert_eq!(Fix::from_num(4).checked_shr(3), Some(Fix::from_num(0.5)));\n",
+                "assert_eq!(Fix::from_num(4).checked_shr(256), None);\n",
+            ];
+            wrapping = wrapping_shr [
+                "assert_eq!(Fix::from_num(4).wrapping_shr(3), Fix::from_num(0.5));\n",
+                "assert_eq!(Fix::from_num(4).wrapping_shr(3 + 256), Fix::from_num(0.5));\n",
+            ];
+            unwrapped = unwrapped_shr [
+                "assert_eq!(Fix::from_num(4).unwrapped_shr(3), Fix::from_num(0.5));\n",
+            ] [
+                "let _overflow = Fix::from_num(4).unwrapped_shr(256);\n",
+            ];
+            overflowing = overflowing_shr [
+                "assert_eq!(Fix::from_num(4).overflowing_shr(3), (Fix::from_num(0.5), false));\n",
+                "assert_eq!(Fix::from_num(4).overflowing_shr(3 + 256), (Fix::from_num(0.5), true));\n",
+            ] {
+                let (ans, overflow) = self.bits.overflowing_shr(rhs);
+                ($Self::from_bits(ans), overflow)
+            }
+        }
+
+        comment! {
+            "Unbounded shift left. Computes `self << rhs`, without bounding the value of `rhs`.
+
+If `rhs`&nbsp;≥&nbsp;256, the entire value is shifted out, and [`ZERO`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let num = Fix::from_num(1.5);
+assert_eq!(num.unbounded_shl(5), num << 5);
+assert_eq!(num.unbounded_shl(256), Fix::ZERO);
+```
+
+[`ZERO`]: Self::ZERO
+";
+            #[inline]
+            #[must_use]
+            pub const fn unbounded_shl(self, rhs: u32) -> $Self<Frac> {
+                if rhs >= 256 {
+                    $Self::ZERO
+                } else {
+                    self.wrapping_shl(rhs)
+                }
+            }
+        }
+
+        comment! {
+            "Unbounded shift right. Computes `self >> rhs`, without bounding the value of `rhs`.
+
+If `rhs`&nbsp;≥&nbsp;256, the entire value is shifted out, and ",
+            if_signed_unsigned!(
+                $Signedness,
+                "[`ZERO`] is returned for non-negative numbers and &minus;[`DELTA`] is
+returned for negative numbers.",
+                "[`ZERO`] is returned.",
+            ),
+            "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let num = Fix::from_num(1.5);
+assert_eq!(num.unbounded_shr(5), num >> 5);
+assert_eq!(num.unbounded_shr(256), Fix::ZERO);
+",
+            if_signed_else_empty_str!(
+                $Signedness;
+                "assert_eq!((-num).unbounded_shr(5), (-num) >> 5);
+assert_eq!((-num).unbounded_shr(256), -Fix::DELTA);
+",
+            ),
+            "```
+
+[`ZERO`]: Self::ZERO
+",
+            if_signed_else_empty_str!($Signedness; "[`DELTA`]: Self::DELTA\n");
+            #[inline]
+            #[must_use]
+            pub const fn unbounded_shr(self, rhs: u32) -> $Self<Frac> {
+                if rhs >= 256 {
+                    if Self::IS_SIGNED && is_neg(self.to_u256()) {
+                        $Self::from_u256(U256::MAX)
+                    } else {
+                        $Self::ZERO
+                    }
+                } else {
+                    self.wrapping_shr(rhs)
+                }
+            }
+        }
+
+        comment! {
+            if_signed_unsigned!(
+                $Signedness,
+                "Returns the absolute value of the difference between `self`
+and `other` using an unsigned type without any wrapping or panicking.
+
+This method is the same as [`unsigned_dist`] for signed fixed-point numbers.
+
+[`unsigned_dist`]: Self::unsigned_dist
+",
+                "Returns the absolute value of the difference between `self` and `other`.
+
+This method is the same as [`dist`] for unsigned fixed-point numbers.
+
+[`dist`]: Self::dist
+",
+            );
+            #[inline]
+            #[must_use]
+            pub const fn abs_diff(self, other: $Self<Frac>) -> $USelf<Frac> {
+                let (a, b) = (self.to_u256(), other.to_u256());
+                let diff = match self.bits.cmp_bits(other.bits) {
+                    Ordering::Less => int256::wrapping_sub_u256(b, a),
+                    _ => int256::wrapping_sub_u256(a, b),
+                };
+                $USelf::from_u256(diff)
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+
+            comment! {
+                "Returns the distance from `self` to `other` using an
+unsigned type without any wrapping or panicking.
+
+The distance is the absolute value of the difference.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($USelf), "};
+type Fix = ", stringify!($Self), "<U4>;
+type UFix = ", stringify!($USelf), "<U4>;
+assert_eq!(Fix::NEG_ONE.unsigned_dist(Fix::from_num(2)), UFix::from_num(3));
+assert_eq!(Fix::MIN.unsigned_dist(Fix::MAX), UFix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn unsigned_dist(self, other: $Self<Frac>) -> $USelf<Frac> {
+                    self.abs_diff(other)
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Distance", "distance", "distance from `self` to `other`",
+            "The distance is the absolute value of the difference.
+
+";
+            fn [const] [] [self] (other: $Self<Frac>) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = dist (wrapping_dist) [
+                "assert_eq!(Fix::ONE.dist(Fix::from_num(5)), Fix::from_num(4));\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(Fix::NEG_ONE.dist(Fix::from_num(2)), Fix::from_num(3));\n",
+                ),
+            ];
+            checked = checked_dist, "on overflow" [
+                "assert_eq!(Fix::ONE.checked_dist(Fix::from_num(5)), Some(Fix::from_num(4)));\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.checked_dist(Fix::ZERO), None);\n",
+                    "assert_eq!(Fix::ZERO.checked_dist(Fix::MAX), Some(Fix::MAX));\n",
+                ),
+            ];
+            saturating = saturating_dist => $Self::MAX; [
+                "assert_eq!(Fix::ONE.saturating_dist(Fix::from_num(5)), Fix::from_num(4));\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.saturating_dist(Fix::MAX), Fix::MAX);\n",
+                    "assert_eq!(Fix::ZERO.saturating_dist(Fix::MAX), Fix::MAX);\n",
+                ),
+            ];
+            wrapping = wrapping_dist [
+                "assert_eq!(Fix::ONE.wrapping_dist(Fix::from_num(5)), Fix::from_num(4));\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(Fix::MIN.wrapping_dist(Fix::MAX), -Fix::DELTA);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_dist(Fix::MAX), Fix::MAX);\n",
+                ),
+            ];
+            overflowing = overflowing_dist [
+                "assert_eq!(\n",
+                "    Fix::ONE.overflowing_dist(Fix::from_num(5)),\n",
+                "    (Fix::from_num(4), false)\n",
+                ");\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "assert_eq!(\n    Fix::MIN.overflowing_dist(Fix::MAX),\n    (-Fix::DELTA, true)\n);\n",
+                    "assert_eq!(\n    Fix::ZERO.overflowing_dist(Fix::MAX),\n    (Fix::MAX, false)\n);\n",
+                ),
+            ] {
+                let diff = self.abs_diff(other).to_u256();
+                ($Self::from_u256(diff), Self::IS_SIGNED && is_neg(diff))
+            }
+        }
+
+        comment! {
+            "Unwrapped distance. Returns the distance from `self` to `other`",
+            if_signed_else_empty_str!($Signedness; ", panicking on overflow"),
+            ".
+
+The distance is the absolute value of the difference.
+
+",
+            if_signed_unsigned!(
+                $Signedness,
+                "# Panics
+
+Panics if the result does not fit.",
+                "Can never overflow for unsigned types.",
+            ),
+            "
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.unwrapped_dist(Fix::from_num(5)), Fix::from_num(4));
+",
+            if_unsigned_else_empty_str!(
+                $Signedness;
+                "assert_eq!(Fix::ZERO.unwrapped_dist(Fix::MAX), Fix::MAX);
+",
+            ),
+            "```
+",
+            if_signed_else_empty_str!(
+                $Signedness;
+                "
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MIN.unwrapped_dist(Fix::ZERO);
+```
+",
+            );
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_dist(self, other: $Self<Frac>) -> $Self<Frac> {
+                match self.overflowing_dist(other) {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        comment! {
+            "Returns the mean of `self` and `other`.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).mean(Fix::from_num(4)), Fix::from_num(3.5));
+",
+            if_signed_else_empty_str!(
+                $Signedness;
+                "assert_eq!(Fix::from_num(-3).mean(Fix::from_num(4)), Fix::from_num(0.5));
+",
+            ),
+            "```
+";
+            #[inline]
+            #[must_use]
+            pub const fn mean(self, other: $Self<Frac>) -> $Self<Frac> {
+                // a & b == common bits
+                // a ^ b == different bits
+                // a + b == 2 * (a & b) + (a ^ b)
+                // (a + b) / 2 = (a & b) + (a ^ b) / 2
+                let (a, b) = (self.to_u256(), other.to_u256());
+                let half_diff = $Self::from_u256(xor(a, b)).wrapping_shr(1);
+                $Self::from_u256(and(a, b)).wrapping_add(half_diff)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Hypotenuse", "hypotenuse", "hypotenuse of a right triangle",
+            "The hypotenuse is equal to the square root of the sum of the squares of the
+operands. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√(`self`²&nbsp;+&nbsp;`other`²)&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+";
+            fn [const] [] [self] (other: $Self<Frac>) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = hypot (wrapping_hypot) [
+                "// hypot(3, 4) == 5\n",
+                "assert_eq!(Fix::from_num(3).hypot(Fix::from_num(4)), Fix::from_num(5));\n",
+            ];
+            checked = checked_hypot, "on overflow" [
+                "let (three, four) = (Fix::from_num(3), Fix::from_num(4));\n",
+                "assert_eq!(three.checked_hypot(four), Some(Fix::from_num(5)));\n",
+                "assert_eq!(Fix::MAX.checked_hypot(Fix::MAX), None);\n",
+            ];
+            saturating = saturating_hypot => $Self::MAX; [
+                "let (three, four) = (Fix::from_num(3), Fix::from_num(4));\n",
+                "assert_eq!(three.saturating_hypot(four), Fix::from_num(5));\n",
+                "assert_eq!(Fix::MAX.saturating_hypot(Fix::MAX), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_hypot [
+                "let (three, four) = (Fix::from_num(3), Fix::from_num(4));\n",
+                "assert_eq!(three.wrapping_hypot(four), Fix::from_num(5));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "// hypot(2, 7.875) == 8.125, which wraps to -7.875\n",
+                        "let (a, b) = (Small::from_num(2), Small::from_num(7.875));\n",
+                        "assert_eq!(a.wrapping_hypot(b), Small::from_num(-7.875));\n",
+                    ),
+                    concat!(
+                        "// hypot(8, 15) == 17, which wraps to 1\n",
+                        "let (a, b) = (Small::from_num(8), Small::from_num(15));\n",
+                        "assert_eq!(a.wrapping_hypot(b), Small::ONE);\n",
+                    ),
+                ),
+            ];
+            unwrapped = unwrapped_hypot [
+                "let (three, four) = (Fix::from_num(3), Fix::from_num(4));\n",
+                "assert_eq!(three.unwrapped_hypot(four), Fix::from_num(5));\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_hypot(Fix::MAX);\n",
+            ];
+            overflowing = overflowing_hypot [
+                "let (three, four) = (Fix::from_num(3), Fix::from_num(4));\n",
+                "assert_eq!(three.overflowing_hypot(four), (Fix::from_num(5), false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                if_signed_unsigned!(
+                    $Signedness,
+                    concat!(
+                        "// hypot(2, 7.875) == 8.125, which wraps to -7.875\n",
+                        "let (a, b) = (Small::from_num(2), Small::from_num(7.875));\n",
+                        "assert_eq!(a.overflowing_hypot(b), (Small::from_num(-7.875), true));\n",
+                    ),
+                    concat!(
+                        "// hypot(8, 15) == 17, which wraps to 1\n",
+                        "let (a, b) = (Small::from_num(8), Small::from_num(15));\n",
+                        "assert_eq!(a.overflowing_hypot(b), (Small::ONE, true));\n",
+                    ),
+                ),
+            ] {
+                let (_, a) = self.neg_abs();
+                let (_, b) = other.neg_abs();
+                let sq_a = int256::wide_mul_u256(a, a);
+                let sq_b = int256::wide_mul_u256(b, b);
+                let (sum, carry) = int256::overflowing_add_u512(sq_a, sq_b);
+                let root = float::isqrt_u512(carry, sum);
+                let overflow = !int256::is_zero_u256(root.hi) || (Self::IS_SIGNED && is_neg(root.lo));
+                ($Self::from_u256(root.lo), overflow)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Next multiple of `other`", "next multiple of `other`", "next multiple",
+            concat!(
+                "The next multiple is the smallest multiple of `other` that is ≥&nbsp;`self`",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    " if `other` is positive, and the largest multiple of
+`other` that is ≤&nbsp;`self` if `other` is negative",
+                ),
+                ".
+
+",
+            );
+            fn [const] [] [self] (other: $Self<Frac>) -> $Self<Frac>;
+            panics = ["Panics if `other` is zero."];
+            imports = [];
+            plain = next_multiple_of (wrapping_next_multiple_of) [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).next_multiple_of(Fix::from_num(1.5)),\n",
+                "    Fix::from_num(4.5)\n",
+                ");\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(\n",
+                    "    Fix::from_num(4).next_multiple_of(Fix::from_num(-1.5)),\n",
+                    "    Fix::from_num(3)\n",
+                    ");\n",
+                ),
+            ];
+            checked = checked_next_multiple_of if other.is_zero(),
+            "if `other` is zero or on overflow" [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).checked_next_multiple_of(Fix::from_num(1.5)),\n",
+                "    Some(Fix::from_num(4.5))\n",
+                ");\n",
+                "assert!(Fix::from_num(4).checked_next_multiple_of(Fix::ZERO).is_none());\n",
+                "assert!(Fix::MAX.checked_next_multiple_of(Fix::from_num(2)).is_none());\n",
+            ];
+            saturating = saturating_next_multiple_of => if_signed_unsigned!(
+                $Signedness,
+                if other.is_negative() {
+                    $Self::MIN
+                } else {
+                    $Self::MAX
+                },
+                $Self::MAX,
+            ); [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).saturating_next_multiple_of(Fix::from_num(1.5)),\n",
+                "    Fix::from_num(4.5)\n",
+                ");\n",
+                "assert_eq!(Fix::MAX.saturating_next_multiple_of(Fix::from_num(2)), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_next_multiple_of [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).wrapping_next_multiple_of(Fix::from_num(1.5)),\n",
+                "    Fix::from_num(4.5)\n",
+                ");\n",
+                "let max_minus_delta = Fix::MAX - Fix::DELTA;\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.wrapping_next_multiple_of(max_minus_delta),\n",
+                "    max_minus_delta.wrapping_add(max_minus_delta)\n",
+                ");\n",
+            ];
+            unwrapped = unwrapped_next_multiple_of [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).unwrapped_next_multiple_of(Fix::from_num(1.5)),\n",
+                "    Fix::from_num(4.5)\n",
+                ");\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_next_multiple_of(Fix::from_num(2));\n",
+            ];
+            overflowing = overflowing_next_multiple_of [
+                "assert_eq!(\n",
+                "    Fix::from_num(4).overflowing_next_multiple_of(Fix::from_num(1.5)),\n",
+                "    (Fix::from_num(4.5), false)\n",
+                ");\n",
+                "let max_minus_delta = Fix::MAX - Fix::DELTA;\n",
+                "assert_eq!(\n",
+                "    Fix::MAX.overflowing_next_multiple_of(max_minus_delta),\n",
+                "    (max_minus_delta.wrapping_add(max_minus_delta), true)\n",
+                ");\n",
+            ] {
+                let (slf, other) = (self.bits, other.bits);
+                if_signed! {
+                    $Signedness;
+
+                    // check for overflowing division
+                    if other.is_negative() && other.count_zeros() == 0 {
+                        return (self, false);
+                    }
+
+                    // panics if other == 0
+                    let rem = slf.wrapping_rem(other);
+
+                    let m = if rem.is_negative() != other.is_negative() {
+                        // cannot overflow as they have opposite signs
+                        rem.wrapping_add(other)
+                    } else {
+                        rem
+                    };
+                    if m.is_zero() {
+                        (self, false)
+                    } else {
+                        // other - m cannot overflow because they have the same sign
+                        self.overflowing_add($Self::from_bits(other.wrapping_sub(m)))
+                    }
+                }
+                if_unsigned! {
+                    $Signedness;
+
+                    // panics if other == 0
+                    let rem = slf.wrapping_rem(other);
+
+                    if rem.is_zero() {
+                        (self, false)
+                    } else {
+                        // other - rem cannot overflow because rem is smaller
+                        self.overflowing_add($Self::from_bits(other.wrapping_sub(rem)))
+                    }
+                }
+            }
+        }
+
+        if_signed! {
+            $Signedness;
+
+            fixed256_variants! {
+                $Self;
+                "Addition with an unsigned fixed-point number",
+                "addition with an unsigned fixed-point number", "sum", "";
+                fn [const] [] [self] (rhs: $USelf<Frac>) -> $Self<Frac>;
+                panics = [];
+                imports = [$USelf];
+                plain = add_unsigned (wrapping_add_unsigned) [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(-5).add_unsigned(UFix::from_num(3)), -2);\n",
+                ];
+                checked = checked_add_unsigned, "on overflow" [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(-5).checked_add_unsigned(UFix::from_num(3)),\n",
+                    "    Some(Fix::from_num(-2))\n",
+                    ");\n",
+                    "assert_eq!(Fix::ZERO.checked_add_unsigned(UFix::MAX), None);\n",
+                ];
+                saturating = saturating_add_unsigned => $Self::MAX; [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(-5).saturating_add_unsigned(UFix::from_num(3)), -2);\n",
+                    "assert_eq!(Fix::from_num(-5).saturating_add_unsigned(UFix::MAX), Fix::MAX);\n",
+                ];
+                wrapping = wrapping_add_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(-5).wrapping_add_unsigned(UFix::from_num(3)), -2);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_add_unsigned(UFix::MAX), -Fix::DELTA);\n",
+                ];
+                unwrapped = unwrapped_add_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(-5).unwrapped_add_unsigned(UFix::from_num(3)), -2);\n",
+                ] [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "let _overflow = Fix::ZERO.unwrapped_add_unsigned(UFix::MAX);\n",
+                ];
+                overflowing = overflowing_add_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(-5).overflowing_add_unsigned(UFix::from_num(3)),\n",
+                    "    (Fix::from_num(-2), false)\n",
+                    ");\n",
+                    "assert_eq!(\n",
+                    "    Fix::ZERO.overflowing_add_unsigned(UFix::MAX),\n",
+                    "    (-Fix::DELTA, true)\n",
+                    ");\n",
+                ] {
+                    let rhs = rhs.cast_signed();
+                    let (ans, overflow) = self.overflowing_add(rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+
+            fixed256_variants! {
+                $Self;
+                "Subtraction with an unsigned fixed-point number",
+                "subtraction with an unsigned fixed-point number", "difference", "";
+                fn [const] [] [self] (rhs: $USelf<Frac>) -> $Self<Frac>;
+                panics = [];
+                imports = [$USelf];
+                plain = sub_unsigned (wrapping_sub_unsigned) [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(3).sub_unsigned(UFix::from_num(5)), -2);\n",
+                ];
+                checked = checked_sub_unsigned, "on overflow" [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(3).checked_sub_unsigned(UFix::from_num(5)),\n",
+                    "    Some(Fix::from_num(-2))\n",
+                    ");\n",
+                    "assert_eq!(Fix::ZERO.checked_sub_unsigned(UFix::MAX), None);\n",
+                ];
+                saturating = saturating_sub_unsigned => $Self::MIN; [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(3).saturating_sub_unsigned(UFix::from_num(5)), -2);\n",
+                    "assert_eq!(Fix::from_num(5).saturating_sub_unsigned(UFix::MAX), Fix::MIN);\n",
+                ];
+                wrapping = wrapping_sub_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(3).wrapping_sub_unsigned(UFix::from_num(5)), -2);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_sub_unsigned(UFix::MAX), Fix::DELTA);\n",
+                ];
+                unwrapped = unwrapped_sub_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(3).unwrapped_sub_unsigned(UFix::from_num(5)), -2);\n",
+                ] [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "let _overflow = Fix::MIN.unwrapped_sub_unsigned(UFix::DELTA);\n",
+                ];
+                overflowing = overflowing_sub_unsigned [
+                    "type UFix = ", stringify!($USelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(3).overflowing_sub_unsigned(UFix::from_num(5)),\n",
+                    "    (Fix::from_num(-2), false)\n",
+                    ");\n",
+                    "assert_eq!(\n",
+                    "    Fix::ZERO.overflowing_sub_unsigned(UFix::MAX),\n",
+                    "    (Fix::DELTA, true)\n",
+                    ");\n",
+                ] {
+                    let rhs = rhs.cast_signed();
+                    let (ans, overflow) = self.overflowing_sub(rhs);
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+        }
+
+        if_unsigned! {
+            $Signedness;
+
+            fixed256_variants! {
+                $Self;
+                "Addition with a signed fixed-point number",
+                "addition with a signed fixed-point number", "sum", "";
+                fn [const] [] [self] (rhs: $ISelf<Frac>) -> $Self<Frac>;
+                panics = [];
+                imports = [$ISelf];
+                plain = add_signed (wrapping_add_signed) [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).add_signed(IFix::from_num(-3)), 2);\n",
+                ];
+                checked = checked_add_signed, "on overflow" [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(5).checked_add_signed(IFix::from_num(-3)),\n",
+                    "    Some(Fix::from_num(2))\n",
+                    ");\n",
+                    "assert_eq!(Fix::from_num(2).checked_add_signed(IFix::from_num(-3)), None);\n",
+                ];
+                saturating = saturating_add_signed => if rhs.is_negative() {
+                    $Self::ZERO
+                } else {
+                    $Self::MAX
+                }; [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).saturating_add_signed(IFix::from_num(-3)), 2);\n",
+                    "assert_eq!(Fix::from_num(2).saturating_add_signed(IFix::from_num(-3)), 0);\n",
+                    "assert_eq!(Fix::MAX.saturating_add_signed(IFix::MAX), Fix::MAX);\n",
+                ];
+                wrapping = wrapping_add_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).wrapping_add_signed(IFix::from_num(-3)), 2);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_add_signed(-IFix::DELTA), Fix::MAX);\n",
+                ];
+                unwrapped = unwrapped_add_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).unwrapped_add_signed(IFix::from_num(-3)), 2);\n",
+                ] [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "let _overflow = Fix::from_num(2).unwrapped_add_signed(IFix::from_num(-3));\n",
+                ];
+                overflowing = overflowing_add_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(5).overflowing_add_signed(IFix::from_num(-3)),\n",
+                    "    (Fix::from_num(2), false)\n",
+                    ");\n",
+                    "assert_eq!(\n",
+                    "    Fix::ZERO.overflowing_add_signed(-IFix::DELTA),\n",
+                    "    (Fix::MAX, true)\n",
+                    ");\n",
+                ] {
+                    let (ans, overflow) = self.overflowing_add(rhs.cast_unsigned());
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+
+            fixed256_variants! {
+                $Self;
+                "Subtraction with a signed fixed-point number",
+                "subtraction with a signed fixed-point number", "difference", "";
+                fn [const] [] [self] (rhs: $ISelf<Frac>) -> $Self<Frac>;
+                panics = [];
+                imports = [$ISelf];
+                plain = sub_signed (wrapping_sub_signed) [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).sub_signed(IFix::from_num(-3)), 8);\n",
+                ];
+                checked = checked_sub_signed, "on overflow" [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(5).checked_sub_signed(IFix::from_num(-3)),\n",
+                    "    Some(Fix::from_num(8))\n",
+                    ");\n",
+                    "assert_eq!(Fix::from_num(2).checked_sub_signed(IFix::from_num(3)), None);\n",
+                ];
+                saturating = saturating_sub_signed => if rhs.is_negative() {
+                    $Self::MAX
+                } else {
+                    $Self::ZERO
+                }; [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).saturating_sub_signed(IFix::from_num(-3)), 8);\n",
+                    "assert_eq!(Fix::from_num(2).saturating_sub_signed(IFix::from_num(3)), 0);\n",
+                    "assert_eq!(Fix::MAX.saturating_sub_signed(IFix::MIN), Fix::MAX);\n",
+                ];
+                wrapping = wrapping_sub_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).wrapping_sub_signed(IFix::from_num(-3)), 8);\n",
+                    "assert_eq!(Fix::ZERO.wrapping_sub_signed(IFix::DELTA), Fix::MAX);\n",
+                ];
+                unwrapped = unwrapped_sub_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(Fix::from_num(5).unwrapped_sub_signed(IFix::from_num(-3)), 8);\n",
+                ] [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "let _overflow = Fix::from_num(2).unwrapped_sub_signed(IFix::from_num(3));\n",
+                ];
+                overflowing = overflowing_sub_signed [
+                    "type IFix = ", stringify!($ISelf), "<U4>;\n",
+                    "assert_eq!(\n",
+                    "    Fix::from_num(5).overflowing_sub_signed(IFix::from_num(-3)),\n",
+                    "    (Fix::from_num(8), false)\n",
+                    ");\n",
+                    "assert_eq!(\n",
+                    "    Fix::ZERO.overflowing_sub_signed(IFix::DELTA),\n",
+                    "    (Fix::MAX, true)\n",
+                    ");\n",
+                ] {
+                    let (ans, overflow) = self.overflowing_sub(rhs.cast_unsigned());
+                    (ans, overflow != rhs.is_negative())
+                }
+            }
+
+            comment! {
+                "Returns the smallest power of two that is ≥&nbsp;`self`.
+
+# Panics
+
+When debug assertions are enabled, panics if the next power of two is too large
+to represent. When debug assertions are not enabled, zero can be returned, but
+it is not considered a breaking change if in the future it panics.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b0110"), ");
+// 1/2 is 0.1000
+let half = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b1000"), ");
+assert_eq!(three_eights.next_power_of_two(), half);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn next_power_of_two(self) -> $Self<Frac> {
+                    $Self::from_bits(self.bits.next_power_of_two())
+                }
+            }
+
+            comment! {
+                "Returns the smallest power of two that is ≥&nbsp;`self`, or [`None`] if the
+next power of two is too large to represent.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b0110"), ");
+// 1/2 is 0.1000
+let half = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b1000"), ");
+assert_eq!(three_eights.checked_next_power_of_two(), Some(half));
+assert!(Fix::MAX.checked_next_power_of_two().is_none());
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_next_power_of_two(self) -> Option<$Self<Frac>> {
+                    match self.bits.checked_next_power_of_two() {
+                        Some(bits) => Some($Self::from_bits(bits)),
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the smallest power of two that is ≥&nbsp;`self`, wrapping
+to 0 if the next power of two is too large to represent.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b0110"), ");
+// 1/2 is 0.1000
+let half = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b1000"), ");
+assert_eq!(three_eights.wrapping_next_power_of_two(), half);
+assert_eq!(Fix::MAX.wrapping_next_power_of_two(), 0);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_next_power_of_two(self) -> $Self<Frac> {
+                    match self.checked_next_power_of_two() {
+                        Some(ans) => ans,
+                        None => $Self::ZERO,
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the smallest power of two that is ≥&nbsp;`self`, panicking
+if the next power of two is too large to represent.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ", stringify!($Bits), "};
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.0110
+let three_eights = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b0110"), ");
+// 1/2 is 0.1000
+let half = Fix::from_bits(", fixed256_int!($Bits, $from_prim, "0b1000"), ");
+assert_eq!(three_eights.unwrapped_next_power_of_two(), half);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MAX.unwrapped_next_power_of_two();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_next_power_of_two(self) -> $Self<Frac> {
+                    match self.checked_next_power_of_two() {
+                        Some(ans) => ans,
+                        None => panic!("overflow"),
+                    }
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Multiply and add", "multiply and add", "result",
+            concat!(
+                "Computes `self`&nbsp;×&nbsp;`mul`&nbsp;+&nbsp;`add`.
+
+",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "For some cases, the product `self`&nbsp;×&nbsp;`mul` would overflow on its
+own, but the final result `self`&nbsp;×&nbsp;`mul`&nbsp;+&nbsp;`add` is
+representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                ),
+                "The `mul` parameter can have a fixed-point type like `self` but with a
+different number of fractional bits.
+
+",
+            );
+            fn [const] [MulFrac: LeEqU256] [self] (mul: $Self<MulFrac>, add: $Self<Frac>) -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = mul_add (wrapping_mul_add) [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(four.mul_add(Fix::from_num(0.5), Fix::from_num(3)), 5);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// MAX × 1.5 - MAX = MAX / 2, which does not overflow\n",
+                    "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                    "assert_eq!(Fix::MAX.mul_add(Fix::from_num(1.5), -Fix::MAX), half_max);\n",
+                ),
+            ];
+            checked = checked_mul_add, "on overflow" [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(four.checked_mul_add(Fix::from_num(0.5), Fix::from_num(3)), Some(Fix::from_num(5)));\n",
+                "assert_eq!(Fix::MAX.checked_mul_add(Fix::ONE, Fix::DELTA), None);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "// MAX × 1.5 - MAX = MAX / 2, which does not overflow\n",
+                    "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                    "assert_eq!(Fix::MAX.checked_mul_add(Fix::from_num(1.5), -Fix::MAX), Some(half_max));\n",
+                ),
+            ];
+            saturating = saturating_mul_add => $Self::saturated(
+                Self::IS_SIGNED && is_neg(self.to_u256()) != is_neg(mul.to_u256())
+            ); [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(four.saturating_mul_add(Fix::from_num(0.5), Fix::from_num(3)), 5);\n",
+                "let half_max = Fix::MAX / Fix::from_num(2);\n",
+                "assert_eq!(half_max.saturating_mul_add(Fix::from_num(3), half_max), Fix::MAX);\n",
+                if_signed_else_empty_str!(
+                    $Signedness;
+                    "assert_eq!(half_max.saturating_mul_add(Fix::from_num(-5), half_max), Fix::MIN);\n",
+                    "// MAX × 1.5 - MAX = MAX / 2, which does not overflow\n",
+                    "assert_eq!(Fix::MAX.saturating_mul_add(Fix::from_num(1.5), -Fix::MAX), half_max);\n",
+                ),
+            ];
+            wrapping = wrapping_mul_add [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(four.wrapping_mul_add(Fix::from_num(0.5), Fix::from_num(3)), 5);\n",
+                "assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::from_num(0)), Fix::MAX);\n",
+                "assert_eq!(Fix::MAX.wrapping_mul_add(Fix::ONE, Fix::DELTA), Fix::MIN);\n",
+                "let wrapped = Fix::MAX.wrapping_mul(Fix::from_num(4));\n",
+                "assert_eq!(Fix::MAX.wrapping_mul_add(Fix::from_num(3), Fix::MAX), wrapped);\n",
+            ];
+            unwrapped = unwrapped_mul_add [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(four.unwrapped_mul_add(Fix::from_num(0.5), Fix::from_num(3)), 5);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_mul_add(Fix::ONE, Fix::DELTA);\n",
+            ];
+            overflowing = overflowing_mul_add [
+                "let four = Fix::from_num(4);\n",
+                "assert_eq!(\n",
+                "    four.overflowing_mul_add(Fix::from_num(0.5), Fix::from_num(3)),\n",
+                "    (Fix::from_num(5), false)\n",
+                ");\n",
+                "assert_eq!(Fix::MAX.overflowing_mul_add(Fix::ONE, Fix::DELTA), (Fix::MIN, true));\n",
+            ] {
+                let (ans, overflow) = if Self::IS_SIGNED {
+                    arith::i256::overflowing_mul_add(
+                        self.to_u256(),
+                        mul.to_u256(),
+                        add.to_u256(),
+                        MulFrac::I32,
+                    )
+                } else {
+                    arith::u256::overflowing_mul_add(
+                        self.to_u256(),
+                        mul.to_u256(),
+                        add.to_u256(),
+                        MulFrac::I32,
+                    )
+                };
+                ($Self::from_u256(ans), overflow)
+            }
+        }
+    };
+}
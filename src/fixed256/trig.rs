@@ -0,0 +1,549 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+// Trigonometric and hyperbolic functions of the signed 256-bit fixed-point
+// numbers. This macro is expanded inside the impl<Frac: LeEqU256> block of the
+// fixed256! macro, and uses the helpers of fixed256_math!.
+macro_rules! fixed256_trig {
+    (Self = $Self:ident,) => {
+        fixed256_variants! {
+            $Self;
+            "Sine", "sine", "sine",
+            "The argument is in radians. The result is rounded to the nearest, and the
+error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+
+The sine cannot overflow, since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|.
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = sin (wrapping_sin) [
+                "assert_eq!(Fix::ZERO.sin(), Fix::ZERO);\n",
+                "// sin 1.5625 ≈ 0.99998\n",
+                "assert_eq!(Fix::from_num(1.5625).sin(), Fix::ONE);\n",
+                "assert_eq!(Fix::from_num(-1.5625).sin(), Fix::NEG_ONE);\n",
+                "// sin 0.5 ≈ 0.479\n",
+                "assert!(Fix::from_num(0.5).sin().dist(Fix::from_num(0.479)) <= Fix::DELTA);\n",
+            ];
+            checked = checked_sin, "on overflow" [
+                "assert_eq!(Fix::from_num(1.5625).checked_sin(), Some(Fix::ONE));\n",
+            ];
+            saturating = saturating_sin => $Self::MAX; [
+                "assert_eq!(Fix::from_num(1.5625).saturating_sin(), Fix::ONE);\n",
+            ];
+            wrapping = wrapping_sin [
+                "assert_eq!(Fix::from_num(1.5625).wrapping_sin(), Fix::ONE);\n",
+            ];
+            overflowing = overflowing_sin [
+                "assert_eq!(Fix::from_num(1.5625).overflowing_sin(), (Fix::ONE, false));\n",
+            ] {
+                let (sin, _) = float::sin_cos(self.to_float());
+                Self::from_float(sin, true)
+            }
+        }
+
+        comment! {
+            "Unwrapped sine. Returns the sine, panicking on overflow.
+
+Since the sine cannot overflow, this method is equivalent to
+[`sin`][Self::sin] and never panics.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(1.5625).unwrapped_sin(), Fix::ONE);
+```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn unwrapped_sin(self) -> $Self<Frac> {
+                match self.overflowing_sin() {
+                    (ans, false) => ans,
+                    (_, true) => panic!("overflow"),
+                }
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Cosine", "cosine", "cosine",
+            "The argument is in radians. The result is rounded to the nearest, and the
+error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow can only occur when there are less than two integer bits, in which case
+the result 1 may not be representable. Since the result can only overflow in the
+positive direction, the saturated value is always [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = cos (wrapping_cos) [
+                "assert_eq!(Fix::ZERO.cos(), Fix::ONE);\n",
+                "// cos 3.125 ≈ &minus;0.99987\n",
+                "assert_eq!(Fix::from_num(3.125).cos(), Fix::NEG_ONE);\n",
+                "// cos 1 ≈ 0.540\n",
+                "assert!(Fix::ONE.cos().dist(Fix::from_num(0.540)) <= Fix::DELTA);\n",
+            ];
+            checked = checked_cos, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_cos(), Some(Fix::ONE));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.checked_cos(), None);\n",
+            ];
+            saturating = saturating_cos => $Self::MAX; [
+                "assert_eq!(Fix::ZERO.saturating_cos(), Fix::ONE);\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.saturating_cos(), OneIntBit::MAX);\n",
+            ];
+            wrapping = wrapping_cos [
+                "assert_eq!(Fix::ZERO.wrapping_cos(), Fix::ONE);\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.wrapping_cos(), OneIntBit::NEG_ONE);\n",
+            ];
+            unwrapped = unwrapped_cos [
+                "assert_eq!(Fix::ZERO.unwrapped_cos(), Fix::ONE);\n",
+            ] [
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let zero: OneIntBit = Fix::ZERO.to_num();\n",
+                "let _overflow = zero.unwrapped_cos();\n",
+            ];
+            overflowing = overflowing_cos [
+                "assert_eq!(Fix::ZERO.overflowing_cos(), (Fix::ONE, false));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.overflowing_cos(), (OneIntBit::NEG_ONE, true));\n",
+            ] {
+                let (_, cos) = float::sin_cos(self.to_float());
+                Self::from_float(cos, true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Simultaneous sine and cosine", "simultaneous sine and cosine",
+            "[tuple] (sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>)",
+            "This is more efficient than calling both [`sin`][Self::sin] and
+[`cos`][Self::cos], and the results are identical to the results of those two
+methods. Only the cosine can overflow, and when saturating only the cosine is
+saturated.
+
+";
+            fn [const] [] [self] () -> ($Self<Frac>, $Self<Frac>);
+            panics = [];
+            imports = [];
+            plain = sin_cos (wrapping_sin_cos) [
+                "let x = Fix::from_num(0.75);\n",
+                "assert_eq!(x.sin_cos(), (x.sin(), x.cos()));\n",
+                "assert_eq!(Fix::ZERO.sin_cos(), (Fix::ZERO, Fix::ONE));\n",
+            ];
+            checked = checked_sin_cos, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_sin_cos(), Some((Fix::ZERO, Fix::ONE)));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.checked_sin_cos(), None);\n",
+            ];
+            saturating = saturating_sin_cos => (self.wrapping_sin(), $Self::MAX); [
+                "assert_eq!(Fix::ZERO.saturating_sin_cos(), (Fix::ZERO, Fix::ONE));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(\n",
+                "    OneIntBit::ZERO.saturating_sin_cos(),\n",
+                "    (OneIntBit::ZERO, OneIntBit::MAX)\n",
+                ");\n",
+            ];
+            wrapping = wrapping_sin_cos [
+                "assert_eq!(Fix::ZERO.wrapping_sin_cos(), (Fix::ZERO, Fix::ONE));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(\n",
+                "    OneIntBit::ZERO.wrapping_sin_cos(),\n",
+                "    (OneIntBit::ZERO, OneIntBit::NEG_ONE)\n",
+                ");\n",
+            ];
+            unwrapped = unwrapped_sin_cos [
+                "assert_eq!(Fix::ZERO.unwrapped_sin_cos(), (Fix::ZERO, Fix::ONE));\n",
+            ] [
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let zero: OneIntBit = Fix::ZERO.to_num();\n",
+                "let _overflow = zero.unwrapped_sin_cos();\n",
+            ];
+            overflowing = overflowing_sin_cos [
+                "assert_eq!(Fix::ZERO.overflowing_sin_cos(), ((Fix::ZERO, Fix::ONE), false));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(\n",
+                "    OneIntBit::ZERO.overflowing_sin_cos(),\n",
+                "    ((OneIntBit::ZERO, OneIntBit::NEG_ONE), true)\n",
+                ");\n",
+            ] {
+                let (sin, cos) = float::sin_cos(self.to_float());
+                let (sin, _) = Self::from_float(sin, true);
+                let (cos, overflow) = Self::from_float(cos, true);
+                ((sin, cos), overflow)
+            }
+        }
+
+        comment! {
+            "Arctangent.
+
+The result is an angle in radians in the range
+&minus;π/2&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2. The returned fixed-point
+number has 256 bits and three integer bits, that is
+<code>", stringify!($Self), "\\<[U253][crate::types::extra::U253]></code>,
+which is the type with the most fractional bits that can hold ±π. This is the
+same type as the one returned by [`atan2`][Self::atan2].
+
+The result is rounded toward zero, so that the error is &lt;&nbsp;`DELTA` of the
+returned type, and so that
+atan&nbsp;(&minus;<i>x</i>)&nbsp;=&nbsp;&minus;atan&nbsp;<i>x</i>.
+
+The arctangent cannot overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U253};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+type Angle = ", stringify!($Self), "<U253>;
+assert_eq!(Fix::ZERO.atan(), Angle::ZERO);
+assert_eq!(Fix::NEG_ONE.atan(), -Fix::ONE.atan());
+// atan 1 = π/4 ≈ 0.785
+let x = Fix::ONE.atan();
+assert!(x.dist(Angle::from_num(0.785)) < Angle::from_num(0.001));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn atan(self) -> $Self<U253> {
+                let (bits, _) = float::atan(self.to_float()).to_bits(253, false, true);
+                $Self::from_u256(bits)
+            }
+        }
+
+        comment! {
+            "Four-quadrant arctangent of `self` and `other`.
+
+This is the angle in radians of the point (`other`,&nbsp;`self`), that is `self`
+is the <i>y</i> coordinate and `other` is the <i>x</i> coordinate. The result
+is in the range &minus;π&nbsp;&lt;&nbsp;<i>θ</i>&nbsp;≤&nbsp;π, and if both
+`self` and `other` are zero, the result is zero. The returned fixed-point number
+has 256 bits and three integer bits, that is
+<code>", stringify!($Self), "\\<[U253][crate::types::extra::U253]></code>,
+which is the type with the most fractional bits that can hold ±π.
+
+The result is rounded toward zero, so that the error is &lt;&nbsp;`DELTA` of the
+returned type.
+
+The arctangent cannot overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U253};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+type Angle = ", stringify!($Self), "<U253>;
+let (zero, one, neg_one) = (Fix::ZERO, Fix::ONE, Fix::NEG_ONE);
+assert_eq!(zero.atan2(one), Angle::ZERO);
+assert_eq!(neg_one.atan2(zero), -one.atan2(zero));
+assert_eq!(Fix::from_num(3).atan2(Fix::from_num(-3)), -neg_one.atan2(neg_one));
+// π ≈ 3.142
+let pi = zero.atan2(neg_one);
+assert!(pi.dist(Angle::from_num(3.142)) < Angle::from_num(0.001));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn atan2(self, other: $Self<Frac>) -> $Self<U253> {
+                let angle = float::atan2(self.to_float(), other.to_float());
+                let (bits, _) = angle.to_bits(253, false, true);
+                $Self::from_u256(bits)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Arcsine", "arcsine", "arcsine",
+            "The result is an angle in radians in the range
+&minus;π/2&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2. The result is rounded toward
+zero, so the error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow can only occur when there are less than two integer bits, in which case
+π/2 is not representable. When saturating, [`MAX`][Self::MAX] is returned for
+positive arguments and [`MIN`][Self::MIN] is returned for negative arguments.
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [
+                "Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1."
+            ];
+            imports = [];
+            plain = asin (wrapping_asin) [
+                "assert_eq!(Fix::ZERO.asin(), Fix::ZERO);\n",
+                "// π/2 ≈ 1.571 is rounded toward zero\n",
+                "assert_eq!(Fix::ONE.asin(), Fix::from_num(1.5625));\n",
+                "assert_eq!(Fix::NEG_ONE.asin(), Fix::from_num(-1.5625));\n",
+            ];
+            checked = checked_asin if self.to_float().abs_gt_one(),
+            "if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on overflow" [
+                "assert_eq!(Fix::from_num(2).checked_asin(), None);\n",
+                "assert_eq!(Fix::from_num(-2).checked_asin(), None);\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::NEG_ONE.checked_asin(), None);\n",
+            ];
+            saturating = saturating_asin => $Self::saturated(self.is_negative()); [
+                "assert_eq!(Fix::ONE.saturating_asin(), Fix::from_num(1.5625));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::NEG_ONE.saturating_asin(), OneIntBit::MIN);\n",
+            ];
+            wrapping = wrapping_asin [
+                "assert_eq!(Fix::ONE.wrapping_asin(), Fix::from_num(1.5625));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "// &minus;π/2 wraps to 2 &minus; π/2 ≈ 0.429\n",
+                "let wrapped = OneIntBit::NEG_ONE.wrapping_asin();\n",
+                "assert!(wrapped.dist(OneIntBit::from_num(0.429)) < OneIntBit::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_asin [
+                "assert_eq!(Fix::ONE.unwrapped_asin(), Fix::from_num(1.5625));\n",
+            ] [
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let neg_one: OneIntBit = Fix::NEG_ONE.to_num();\n",
+                "let _overflow = neg_one.unwrapped_asin();\n",
+            ];
+            overflowing = overflowing_asin [
+                "assert_eq!(Fix::ONE.overflowing_asin(), (Fix::from_num(1.5625), false));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let (wrapped, overflow) = OneIntBit::NEG_ONE.overflowing_asin();\n",
+                "assert!(overflow);\n",
+                "// &minus;π/2 wraps to 2 &minus; π/2 ≈ 0.429\n",
+                "assert!(wrapped.dist(OneIntBit::from_num(0.429)) < OneIntBit::from_num(0.001));\n",
+            ] {
+                let x = self.to_float();
+                if x.abs_gt_one() {
+                    panic!("arcsine argument out of range");
+                }
+                let (asin, _) = float::asin_acos(x);
+                Self::from_float(asin, false)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Arccosine", "arccosine", "arccosine",
+            "The result is an angle in radians in the range
+0&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π. The result is rounded toward zero, so the
+error is &lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow can only occur when there are less than three integer bits, in which
+case π is not representable. Since the arccosine is never negative,
+[`MAX`][Self::MAX] is returned when saturating.
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [
+                "Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1."
+            ];
+            imports = [];
+            plain = acos (wrapping_acos) [
+                "assert_eq!(Fix::ONE.acos(), Fix::ZERO);\n",
+                "// π/2 ≈ 1.571 and π ≈ 3.142 are rounded toward zero\n",
+                "assert_eq!(Fix::ZERO.acos(), Fix::from_num(1.5625));\n",
+                "assert_eq!(Fix::NEG_ONE.acos(), Fix::from_num(3.125));\n",
+            ];
+            checked = checked_acos if self.to_float().abs_gt_one(),
+            "if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on overflow" [
+                "assert_eq!(Fix::from_num(2).checked_acos(), None);\n",
+                "assert_eq!(Fix::from_num(-2).checked_acos(), None);\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.checked_acos(), None);\n",
+            ];
+            saturating = saturating_acos => $Self::MAX; [
+                "assert_eq!(Fix::NEG_ONE.saturating_acos(), Fix::from_num(3.125));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "assert_eq!(OneIntBit::ZERO.saturating_acos(), OneIntBit::MAX);\n",
+            ];
+            wrapping = wrapping_acos [
+                "assert_eq!(Fix::NEG_ONE.wrapping_acos(), Fix::from_num(3.125));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "// π/2 wraps to π/2 &minus; 2 ≈ &minus;0.429\n",
+                "let wrapped = OneIntBit::ZERO.wrapping_acos();\n",
+                "assert!(wrapped.dist(OneIntBit::from_num(-0.429)) < OneIntBit::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_acos [
+                "assert_eq!(Fix::NEG_ONE.unwrapped_acos(), Fix::from_num(3.125));\n",
+            ] [
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let zero: OneIntBit = Fix::ZERO.to_num();\n",
+                "let _overflow = zero.unwrapped_acos();\n",
+            ];
+            overflowing = overflowing_acos [
+                "assert_eq!(Fix::NEG_ONE.overflowing_acos(), (Fix::from_num(3.125), false));\n",
+                "type OneIntBit = ", stringify!($Self), "<fixed::types::extra::U255>;\n",
+                "let (wrapped, overflow) = OneIntBit::ZERO.overflowing_acos();\n",
+                "assert!(overflow);\n",
+                "// π/2 wraps to π/2 &minus; 2 ≈ &minus;0.429\n",
+                "assert!(wrapped.dist(OneIntBit::from_num(-0.429)) < OneIntBit::from_num(0.001));\n",
+            ] {
+                let x = self.to_float();
+                if x.abs_gt_one() {
+                    panic!("arccosine argument out of range");
+                }
+                let (_, acos) = float::asin_acos(x);
+                Self::from_float(acos, false)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Hyperbolic sine", "hyperbolic sine", "hyperbolic sine",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX] or less than
+[`MIN`][Self::MIN].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = sinh (wrapping_sinh) [
+                "assert_eq!(Fix::ZERO.sinh(), Fix::ZERO);\n",
+                "// sinh 1 ≈ 1.175\n",
+                "assert!(Fix::ONE.sinh().dist(Fix::from_num(1.175)) <= Fix::DELTA);\n",
+                "assert_eq!(Fix::NEG_ONE.sinh(), -Fix::ONE.sinh());\n",
+            ];
+            checked = checked_sinh, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_sinh(), Some(Fix::ZERO));\n",
+                "assert_eq!(Fix::MAX.checked_sinh(), None);\n",
+                "assert_eq!(Fix::MIN.checked_sinh(), None);\n",
+            ];
+            saturating = saturating_sinh => $Self::saturated(self.is_negative()); [
+                "assert_eq!(Fix::ZERO.saturating_sinh(), Fix::ZERO);\n",
+                "assert_eq!(Fix::MAX.saturating_sinh(), Fix::MAX);\n",
+                "assert_eq!(Fix::MIN.saturating_sinh(), Fix::MIN);\n",
+            ];
+            wrapping = wrapping_sinh [
+                "assert_eq!(Fix::ZERO.wrapping_sinh(), Fix::ZERO);\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// sinh 3 ≈ 10.018 wraps to 10.018 &minus; 16 = &minus;5.982\n",
+                "let wrapped = Small::from_num(3).wrapping_sinh();\n",
+                "assert!(wrapped.dist(Small::from_num(-5.982)) < Small::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_sinh [
+                "assert_eq!(Fix::ZERO.unwrapped_sinh(), Fix::ZERO);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_sinh();\n",
+            ];
+            overflowing = overflowing_sinh [
+                "assert_eq!(Fix::ZERO.overflowing_sinh(), (Fix::ZERO, false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// sinh 3 ≈ 10.018 wraps to 10.018 &minus; 16 = &minus;5.982\n",
+                "let (wrapped, overflow) = Small::from_num(3).overflowing_sinh();\n",
+                "assert!(overflow);\n",
+                "assert!(wrapped.dist(Small::from_num(-5.982)) < Small::from_num(0.001));\n",
+            ] {
+                let (sinh, _, _) = float::hyperbolic(self.to_float());
+                Self::from_float(sinh, true)
+            }
+        }
+
+        fixed256_variants! {
+            $Self;
+            "Hyperbolic cosine", "hyperbolic cosine", "hyperbolic cosine",
+            "The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX].
+
+";
+            fn [const] [] [self] () -> $Self<Frac>;
+            panics = [];
+            imports = [];
+            plain = cosh (wrapping_cosh) [
+                "assert_eq!(Fix::ZERO.cosh(), Fix::ONE);\n",
+                "// cosh 1 ≈ 1.543\n",
+                "assert!(Fix::ONE.cosh().dist(Fix::from_num(1.543)) <= Fix::DELTA);\n",
+                "assert_eq!(Fix::NEG_ONE.cosh(), Fix::ONE.cosh());\n",
+            ];
+            checked = checked_cosh, "on overflow" [
+                "assert_eq!(Fix::ZERO.checked_cosh(), Some(Fix::ONE));\n",
+                "assert_eq!(Fix::MAX.checked_cosh(), None);\n",
+                "assert_eq!(Fix::MIN.checked_cosh(), None);\n",
+            ];
+            saturating = saturating_cosh => $Self::MAX; [
+                "assert_eq!(Fix::ZERO.saturating_cosh(), Fix::ONE);\n",
+                "assert_eq!(Fix::MIN.saturating_cosh(), Fix::MAX);\n",
+            ];
+            wrapping = wrapping_cosh [
+                "assert_eq!(Fix::ZERO.wrapping_cosh(), Fix::ONE);\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// cosh 3 ≈ 10.068 wraps to 10.068 &minus; 16 = &minus;5.932\n",
+                "let wrapped = Small::from_num(3).wrapping_cosh();\n",
+                "assert!(wrapped.dist(Small::from_num(-5.932)) < Small::from_num(0.001));\n",
+            ];
+            unwrapped = unwrapped_cosh [
+                "assert_eq!(Fix::ZERO.unwrapped_cosh(), Fix::ONE);\n",
+            ] [
+                "let _overflow = Fix::MAX.unwrapped_cosh();\n",
+            ];
+            overflowing = overflowing_cosh [
+                "assert_eq!(Fix::ZERO.overflowing_cosh(), (Fix::ONE, false));\n",
+                "type Small = ", stringify!($Self), "<fixed::types::extra::U252>;\n",
+                "// cosh 3 ≈ 10.068 wraps to 10.068 &minus; 16 = &minus;5.932\n",
+                "let (wrapped, overflow) = Small::from_num(3).overflowing_cosh();\n",
+                "assert!(overflow);\n",
+                "assert!(wrapped.dist(Small::from_num(-5.932)) < Small::from_num(0.001));\n",
+            ] {
+                let (_, cosh, _) = float::hyperbolic(self.to_float());
+                Self::from_float(cosh, true)
+            }
+        }
+
+        comment! {
+            "Hyperbolic tangent, tanh&nbsp;<i>x</i>.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+This method cannot overflow, since |tanh&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|
+and |tanh&nbsp;<i>x</i>|&nbsp;<&nbsp;1, so there are no checked, saturating,
+wrapping, unwrapped or overflowing variants.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U256};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.tanh(), Fix::ZERO);
+// tanh 1 ≈ 0.762
+assert!(Fix::ONE.tanh().dist(Fix::from_num(0.762)) <= Fix::DELTA);
+assert!(Fix::MAX.tanh() <= Fix::ONE);
+assert!(Fix::MIN.tanh() >= Fix::NEG_ONE);
+type AllFrac = ", stringify!($Self), "<U256>;
+// tanh &minus;0.5 ≈ &minus;0.462
+assert!(AllFrac::MIN.tanh().dist(AllFrac::from_num(-0.462)) < AllFrac::from_num(0.001));
+```
+";
+            #[inline]
+            #[must_use]
+            pub const fn tanh(self) -> $Self<Frac> {
+                let (_, _, tanh) = float::hyperbolic(self.to_float());
+                Self::from_float(tanh, true).0
+            }
+        }
+    };
+}
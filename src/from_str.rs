@@ -13,11 +13,15 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::RoundingMode;
 use crate::bytes::{DigitsExp, DigitsUnds};
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::int256;
+use crate::int256::U256;
+use crate::ratio;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256,
 };
 use core::error::Error;
 use core::fmt::{Display, Formatter, Result as FmtResult};
@@ -101,9 +105,7 @@ macro_rules! all {
         ) -> Result<$Single, ParseFixedError> {
             match overflowing_from_str_radix(s, radix, frac_nbits) {
                 Ok((val, false)) => Ok(val),
-                Ok((_, true)) => Err(ParseFixedError {
-                    kind: ParseErrorKind::Overflow,
-                }),
+                Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
                 Err(e) => Err(e),
             }
         }
@@ -151,7 +153,7 @@ macro_rules! all {
         ) -> Result<($Single, bool), ParseFixedError> {
             match from_str(s, radix, Sep::Error, frac_nbits) {
                 Ok(val) => Ok(val),
-                Err(kind) => Err(ParseFixedError { kind }),
+                Err(e) => Err(e),
             }
         }
     };
@@ -174,7 +176,7 @@ macro_rules! signed {
                 radix: u32,
                 sep: Sep,
                 frac_nbits: u32,
-            ) -> Result<($Single, bool), ParseErrorKind> {
+            ) -> Result<($Single, bool), ParseFixedError> {
                 let (neg, abs, mut overflow) = match crate::from_str::$Uns::get_int_frac(
                     bytes,
                     radix,
@@ -195,9 +197,7 @@ macro_rules! signed {
 
             pub const fn lit(mut s: &[u8], frac_nbits: u32) -> Result<$Single, ParseFixedError> {
                 if s.is_empty() {
-                    return Err(ParseFixedError {
-                        kind: ParseErrorKind::NoDigits,
-                    });
+                    return Err(ParseFixedError::at(ParseErrorKind::NoDigits, 0, 10));
                 }
                 let neg = if s[0] == b'-' {
                     s = s.split_at(1).1;
@@ -205,15 +205,13 @@ macro_rules! signed {
                 } else {
                     false
                 };
-                let abs = match crate::from_str::$Uns::lit_no_sign(s, frac_nbits) {
-                    Ok(val) => val,
-                    Err(kind) => return Err(ParseFixedError { kind }),
+                let (abs, radix) = match crate::from_str::$Uns::lit_no_sign(s, frac_nbits) {
+                    Ok(o) => o,
+                    Err(e) => return Err(e.shifted(neg as usize)),
                 };
                 let bound = if !neg { $Single::MAX } else { $Single::MIN };
                 if abs > bound.unsigned_abs() {
-                    return Err(ParseFixedError {
-                        kind: ParseErrorKind::Overflow,
-                    });
+                    return Err(ParseFixedError::overflow(radix));
                 }
                 let val = if neg { abs.wrapping_neg() } else { abs } as $Single;
                 Ok(val)
@@ -261,7 +259,7 @@ macro_rules! unsigned {
             radix: u32,
             sep: Sep,
             frac_nbits: u32,
-        ) -> Result<($Uns, bool), ParseErrorKind> {
+        ) -> Result<($Uns, bool), ParseFixedError> {
             let (neg, abs, mut overflow) =
                 match get_int_frac(bytes, radix, sep, $Uns::BITS - frac_nbits, frac_nbits) {
                     Ok((neg, abs, overflow)) => (neg, abs, overflow),
@@ -277,17 +275,18 @@ macro_rules! unsigned {
         #[inline]
         pub const fn lit(s: &[u8], frac_nbits: u32) -> Result<$Uns, ParseFixedError> {
             match lit_no_sign(s, frac_nbits) {
-                Ok(val) => Ok(val),
-                Err(kind) => Err(ParseFixedError { kind }),
+                Ok((val, _)) => Ok(val),
+                Err(e) => Err(e),
             }
         }
 
+        // Returns the value and the radix.
         pub(super) const fn lit_no_sign(
             mut bytes: &[u8],
             frac_nbits: u32,
-        ) -> Result<$Uns, ParseErrorKind> {
+        ) -> Result<($Uns, u32), ParseFixedError> {
             if bytes.is_empty() {
-                return Err(ParseErrorKind::NoDigits);
+                return Err(ParseFixedError::at(ParseErrorKind::NoDigits, 0, 10));
             }
             let radix = if bytes.len() >= 2 && bytes[0] == b'0' {
                 match bytes[1] {
@@ -299,19 +298,22 @@ macro_rules! unsigned {
             } else {
                 10
             };
+            let mut skipped = 0;
             if radix != 10 {
                 bytes = bytes.split_at(2).1;
+                skipped += 2;
                 while let Some((b'_', rest)) = bytes.split_first() {
                     bytes = rest;
+                    skipped += 1;
                 }
             }
             if let Some((b'-' | b'+', _)) = bytes.split_first() {
-                return Err(ParseErrorKind::MisplacedSign);
+                return Err(ParseFixedError::at(ParseErrorKind::MisplacedSign, skipped, radix));
             }
             match from_str(bytes, radix, Sep::Skip, frac_nbits) {
-                Ok((val, false)) => Ok(val),
-                Ok((_, true)) => Err(ParseErrorKind::Overflow),
-                Err(e) => Err(e),
+                Ok((val, false)) => Ok((val, radix)),
+                Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
+                Err(e) => Err(e.shifted(skipped)),
             }
         }
 
@@ -321,7 +323,7 @@ macro_rules! unsigned {
             sep: Sep,
             int_nbits: u32,
             frac_nbits: u32,
-        ) -> Result<(bool, $Uns, bool), ParseErrorKind> {
+        ) -> Result<(bool, $Uns, bool), ParseFixedError> {
             let Parse {
                 neg,
                 int,
@@ -940,6 +942,357 @@ pub mod u128 {
     }
 }
 
+// There is no primitive 256-bit integer, so 256-bit parsing does not use the
+// macros above. Instead of decoding chunks of digits, fractional digits are
+// converted to bits one at a time by repeatedly doubling them.
+//
+//   * Creates modules u256 and i256.
+//   * Defines:
+//       - pub const fn from_str_radix
+//       - pub const fn overflowing_from_str_radix
+//       - pub const fn lit
+//       - pub const fn from_str_radix_nbits (u256 only)
+//       - pub const fn overflowing_from_str_radix_nbits (u256 only)
+//       - pub(super) const fn lit_no_sign (u256 only)
+//       - pub(super) const fn get_int_frac (u256 only)
+pub mod u256 {
+    use crate::RoundingMode;
+    use crate::from_str::{
+        BitExp, DigitsExp, Parse, ParseErrorKind, ParseFixedError, Sep, parse_bounds,
+        unchecked_hex_digit,
+    };
+    use crate::int256;
+    use crate::int256::U256;
+
+    // frac_nbits ≤ 256, and bit_exp.exp ≤ 3, so we need at most 256 + 3 bits
+    // plus the rounding bit
+    const MAX_FRAC_DIGITS: usize = 260;
+
+    #[inline]
+    pub const fn from_str_radix(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+    ) -> Result<U256, ParseFixedError> {
+        match overflowing_from_str_radix(s, radix, frac_nbits) {
+            Ok((val, false)) => Ok(val),
+            Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[inline]
+    pub const fn overflowing_from_str_radix(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+    ) -> Result<(U256, bool), ParseFixedError> {
+        from_str(s, radix, Sep::Error, frac_nbits)
+    }
+
+    const fn from_str(
+        s: &[u8],
+        radix: u32,
+        sep: Sep,
+        frac_nbits: u32,
+    ) -> Result<(U256, bool), ParseFixedError> {
+        let (neg, abs, mut overflow) =
+            match get_int_frac(s, radix, sep, frac_nbits, RoundingMode::NearestEven) {
+                Ok((neg, abs, overflow, _)) => (neg, abs, overflow),
+                Err(e) => return Err(e),
+            };
+        if neg && !int256::is_zero_u256(abs) {
+            overflow = true;
+        }
+        let val = if neg {
+            int256::wrapping_neg_u256(abs)
+        } else {
+            abs
+        };
+        Ok((val, overflow))
+    }
+
+    #[inline]
+    pub const fn lit(s: &[u8], frac_nbits: u32) -> Result<U256, ParseFixedError> {
+        match lit_no_sign(s, frac_nbits) {
+            Ok((val, _)) => Ok(val),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Returns the value and the radix.
+    pub(super) const fn lit_no_sign(
+        mut bytes: &[u8],
+        frac_nbits: u32,
+    ) -> Result<(U256, u32), ParseFixedError> {
+        if bytes.is_empty() {
+            return Err(ParseFixedError::at(ParseErrorKind::NoDigits, 0, 10));
+        }
+        let radix = if bytes.len() >= 2 && bytes[0] == b'0' {
+            match bytes[1] {
+                b'b' => 2,
+                b'o' => 8,
+                b'x' => 16,
+                _ => 10,
+            }
+        } else {
+            10
+        };
+        let mut skipped = 0;
+        if radix != 10 {
+            bytes = bytes.split_at(2).1;
+            skipped += 2;
+            while let Some((b'_', rest)) = bytes.split_first() {
+                bytes = rest;
+                skipped += 1;
+            }
+        }
+        if let Some((b'-' | b'+', _)) = bytes.split_first() {
+            return Err(ParseFixedError::at(
+                ParseErrorKind::MisplacedSign,
+                skipped,
+                radix,
+            ));
+        }
+        match from_str(bytes, radix, Sep::Skip, frac_nbits) {
+            Ok((val, false)) => Ok((val, radix)),
+            Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
+            Err(e) => Err(e.shifted(skipped)),
+        }
+    }
+
+    // For DynFixed, where the number has nbits ≤ 128 bits. The returned bits
+    // are wrapped to nbits bits, but need not be sign-extended.
+    #[inline]
+    pub const fn from_str_radix_nbits(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+        nbits: u32,
+        signed: bool,
+    ) -> Result<u128, ParseFixedError> {
+        match overflowing_from_str_radix_nbits(s, radix, frac_nbits, nbits, signed) {
+            Ok((val, false)) => Ok(val),
+            Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub const fn overflowing_from_str_radix_nbits(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+        nbits: u32,
+        signed: bool,
+    ) -> Result<(u128, bool), ParseFixedError> {
+        let (neg, abs, mut overflow) =
+            match get_int_frac(s, radix, Sep::Error, frac_nbits, RoundingMode::NearestEven) {
+                Ok((neg, abs, overflow, _)) => (neg, abs, overflow),
+                Err(e) => return Err(e),
+            };
+        let used = 256 - int256::leading_zeros_u256(abs);
+        let fits = if signed {
+            used < nbits || (neg && used == nbits && int256::trailing_zeros_u256(abs) == nbits - 1)
+        } else {
+            used <= nbits && (!neg || used == 0)
+        };
+        if !fits {
+            overflow = true;
+        }
+        let val = if neg { abs.lo.wrapping_neg() } else { abs.lo };
+        Ok((val, overflow))
+    }
+
+    // Returns the sign, the rounded magnitude, whether there was an overflow,
+    // and whether the magnitude was inexact before rounding.
+    pub(super) const fn get_int_frac(
+        bytes: &[u8],
+        radix: u32,
+        sep: Sep,
+        frac_nbits: u32,
+        mode: RoundingMode,
+    ) -> Result<(bool, U256, bool, bool), ParseFixedError> {
+        let Parse {
+            neg,
+            int,
+            frac,
+            bit_exp,
+        } = match parse_bounds(bytes, radix, sep) {
+            Ok(o) => o,
+            Err(e) => return Err(e),
+        };
+        // the parsed digits have to be multiplied by 2^exp_nbits
+        let exp_nbits = match bit_exp {
+            Some(BitExp { exp, .. }) => exp.get(),
+            None => 0,
+        };
+        let (mut acc, mut overflow) = get_int(int, radix);
+        let nbits = frac_nbits + exp_nbits;
+
+        // Only the first nbits + 1 fractional digits can affect the first
+        // nbits + 1 fractional bits, as any number with nbits + 1 fractional
+        // bits can be represented exactly using nbits + 1 fractional digits.
+        // Since trailing zeros are trimmed, any digits after that are non-zero.
+        let max_len = nbits as usize + 1;
+        let (frac, mut has_lower_digits) = if frac.len() > max_len {
+            (frac.split_at(max_len).0, true)
+        } else {
+            (frac, false)
+        };
+        let mut buf = [0u8; MAX_FRAC_DIGITS];
+        let mut len = 0;
+        let mut rem_digits = frac;
+        while let Some((digit, rem)) = rem_digits.split_first() {
+            rem_digits = rem;
+            buf[len] = unchecked_hex_digit(digit);
+            len += 1;
+        }
+
+        let mut half = false;
+        let mut i = 0;
+        while i <= nbits {
+            let bit = double_digits(buf.split_at_mut(len).0, radix as u8);
+            while len > 0 && buf[len - 1] == 0 {
+                len -= 1;
+            }
+            if i == nbits {
+                half = bit;
+            } else {
+                overflow |= acc.hi >> 127 != 0;
+                acc = int256::wrapping_shl_u256(acc, 1);
+                acc.lo |= bit as u128;
+            }
+            i += 1;
+        }
+        has_lower_digits |= len > 0;
+
+        let inexact = half || has_lower_digits;
+        if mode
+            .round_abs(neg)
+            .is_up(half, has_lower_digits, acc.lo & 1 != 0)
+        {
+            let (sum, carry) = int256::overflowing_add_u256(acc, U256 { lo: 1, hi: 0 });
+            acc = sum;
+            overflow |= carry;
+        }
+        Ok((neg, acc, overflow, inexact))
+    }
+
+    const fn get_int(int: DigitsExp, radix: u32) -> (U256, bool) {
+        // Since radix is even, digits that are more than 256 places to the
+        // left of the point only contribute multiples of 2^256.
+        let max_len = U256::BITS as usize;
+        let (int, mut overflow) = if int.len() > max_len {
+            (int.split_at(int.len() - max_len).1, true)
+        } else {
+            (int, false)
+        };
+        let mut acc = U256::ZERO;
+        let mut rem_digits = int;
+        while let Some((digit, rem)) = rem_digits.split_first() {
+            rem_digits = rem;
+
+            let (val, digit_overflow) = int256::overflowing_mul_add_u256_u128(
+                acc,
+                radix as u128,
+                unchecked_hex_digit(digit) as u128,
+            );
+            acc = val;
+            overflow |= digit_overflow;
+        }
+        (acc, overflow)
+    }
+
+    // Doubles the fractional digits, and returns the integer bit carried out.
+    const fn double_digits(digits: &mut [u8], radix: u8) -> bool {
+        let mut carry = 0;
+        let mut i = digits.len();
+        while i > 0 {
+            i -= 1;
+            let doubled = digits[i] * 2 + carry;
+            (digits[i], carry) = if doubled >= radix {
+                (doubled - radix, 1)
+            } else {
+                (doubled, 0)
+            };
+        }
+        carry != 0
+    }
+}
+
+pub mod i256 {
+    use crate::RoundingMode;
+    use crate::from_str::{ParseErrorKind, ParseFixedError, Sep};
+    use crate::int256;
+    use crate::int256::U256;
+
+    #[inline]
+    pub const fn from_str_radix(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+    ) -> Result<U256, ParseFixedError> {
+        match overflowing_from_str_radix(s, radix, frac_nbits) {
+            Ok((val, false)) => Ok(val),
+            Ok((_, true)) => Err(ParseFixedError::overflow(radix)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub const fn overflowing_from_str_radix(
+        s: &[u8],
+        radix: u32,
+        frac_nbits: u32,
+    ) -> Result<(U256, bool), ParseFixedError> {
+        let (neg, abs, overflow) = match crate::from_str::u256::get_int_frac(
+            s,
+            radix,
+            Sep::Error,
+            frac_nbits,
+            RoundingMode::NearestEven,
+        ) {
+            Ok((neg, abs, overflow, _)) => (neg, abs, overflow),
+            Err(e) => return Err(e),
+        };
+        let val = if neg {
+            int256::wrapping_neg_u256(abs)
+        } else {
+            abs
+        };
+        Ok((val, overflow || !fits(neg, abs)))
+    }
+
+    pub const fn lit(mut s: &[u8], frac_nbits: u32) -> Result<U256, ParseFixedError> {
+        if s.is_empty() {
+            return Err(ParseFixedError::at(ParseErrorKind::NoDigits, 0, 10));
+        }
+        let neg = if s[0] == b'-' {
+            s = s.split_at(1).1;
+            true
+        } else {
+            false
+        };
+        let (abs, radix) = match crate::from_str::u256::lit_no_sign(s, frac_nbits) {
+            Ok(o) => o,
+            Err(e) => return Err(e.shifted(neg as usize)),
+        };
+        if !fits(neg, abs) {
+            return Err(ParseFixedError::overflow(radix));
+        }
+        let val = if neg {
+            int256::wrapping_neg_u256(abs)
+        } else {
+            abs
+        };
+        Ok(val)
+    }
+
+    // abs must be < 2^255, except for neg where abs can be 2^255
+    const fn fits(neg: bool, abs: U256) -> bool {
+        abs.hi >> 127 == 0 || (neg && abs.hi == 1 << 127 && abs.lo == 0)
+    }
+}
+
 const fn unchecked_hex_digit(byte: u8) -> u8 {
     // We know that byte is a valid hex:
     //   * b'0'..=b'9' (0x30..=0x39) => byte & 0x0f
@@ -1008,27 +1361,163 @@ let error: ParseFixedError = match s.parse::<I16F16>() {
 };
 println!("Parse error: {error}");
 ```
+
+The error records where parsing failed, which can be used to point at the
+offending character.
+
+```rust
+use fixed::types::I16F16;
+use fixed::ParseErrorKind;
+let s = "1.25x";
+let error = s.parse::<I16F16>().unwrap_err();
+assert_eq!(error.kind(), ParseErrorKind::InvalidDigit);
+assert_eq!(error.offset(), Some(4));
+assert_eq!(error.radix(), 10);
+// 1.25x
+//     ^
+let caret = format!("{s}\n{:>1$}", "^", error.offset().unwrap() + 1);
+assert_eq!(caret, "1.25x\n    ^");
+```
 */
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ParseFixedError {
     kind: ParseErrorKind,
+    offset: Option<usize>,
+    radix: u32,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum ParseErrorKind {
+/// The kind of a [`ParseFixedError`].
+///
+/// More kinds may be added in the future, so matches on this enum need a
+/// wildcard arm.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed::ParseErrorKind;
+/// let error = "1.2.3".parse::<I16F16>().unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::TooManyPoints);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A character that is not a valid digit for the radix was found.
     InvalidDigit,
+    /// A sign was found after the start of the number.
     MisplacedSign,
+    /// An underscore separator was found where it is not allowed, for example
+    /// before the first digit.
     MisplacedUnderscore,
+    /// The string has no digits, for example it is empty.
     NoDigits,
+    /// More than one point was found.
     TooManyPoints,
+    /// The value does not fit in the fixed-point number.
     Overflow,
+    /// A character that is not a decimal digit was found in the exponent.
     ExpInvalidDigit,
+    /// The exponent has no digits.
     ExpNoDigits,
+    /// More than one exponent separator was found.
     TooManyExp,
+    /// The exponent is too large.
     ExpOverflow,
+    /// The value cannot be represented exactly, and the
+    /// [`exact`][ParseOptions::exact] option is set.
+    ExcessPrecision,
+    /// A required radix prefix such as “`0x`” is missing.
+    MissingPrefix,
+    /// The denominator of a ratio is zero.
+    ZeroDenominator,
 }
 
 impl ParseFixedError {
+    #[inline]
+    pub(crate) const fn at(kind: ParseErrorKind, offset: usize, radix: u32) -> ParseFixedError {
+        ParseFixedError {
+            kind,
+            offset: Some(offset),
+            radix,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn without_offset(kind: ParseErrorKind, radix: u32) -> ParseFixedError {
+        ParseFixedError {
+            kind,
+            offset: None,
+            radix,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn overflow(radix: u32) -> ParseFixedError {
+        ParseFixedError::without_offset(ParseErrorKind::Overflow, radix)
+    }
+
+    // Adjusts the offset when the parsed bytes did not start at the beginning
+    // of the string.
+    #[inline]
+    pub(crate) const fn shifted(mut self, skipped: usize) -> ParseFixedError {
+        if let Some(offset) = self.offset {
+            self.offset = Some(offset + skipped);
+        }
+        self
+    }
+
+    /// Returns the kind of error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::ParseErrorKind;
+    /// let error = "".parse::<I16F16>().unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::NoDigits);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the string where the error was detected.
+    ///
+    /// For errors caused by a character, this is the offset of that
+    /// character. For errors detected at the end of the string, such as
+    /// [`NoDigits`][ParseErrorKind::NoDigits], this is the length of the
+    /// string. For errors that concern the whole value, such as
+    /// [`Overflow`][ParseErrorKind::Overflow], this is [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// assert_eq!("1.5-".parse::<I8F8>().unwrap_err().offset(), Some(3));
+    /// assert_eq!("1e".parse::<I8F8>().unwrap_err().offset(), Some(2));
+    /// assert_eq!("1000".parse::<I8F8>().unwrap_err().offset(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns the radix that was in effect when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// assert_eq!(I8F8::from_str_hex("1.G").unwrap_err().radix(), 16);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+
     #[inline]
     #[track_caller]
     pub(crate) const fn lit_message(self) -> &'static str {
@@ -1044,6 +1533,9 @@ impl ParseFixedError {
             ExpNoDigits => "invalid literal: exponent has no digits",
             TooManyExp => "invalid literal: more than one exponent found",
             ExpOverflow => "invalid literal: exponent overflow",
+            ExcessPrecision => "invalid literal: excess precision found in string",
+            MissingPrefix => "invalid literal: missing radix prefix",
+            ZeroDenominator => "invalid literal: denominator is zero",
         }
     }
 
@@ -1061,6 +1553,9 @@ impl ParseFixedError {
             ExpNoDigits => "exponent has no digits",
             TooManyExp => "more than one exponent found",
             ExpOverflow => "exponent overflow",
+            ExcessPrecision => "excess precision found in string",
+            MissingPrefix => "missing radix prefix",
+            ZeroDenominator => "denominator is zero",
         }
     }
 }
@@ -1077,15 +1572,413 @@ impl Error for ParseFixedError {
     }
 }
 
+/**
+Options for parsing a fixed-point number from a string.
+
+The options are set using builder methods, and the string is parsed using
+methods such as <code>FixedI32::[from\_str\_with][crate::FixedI32::from_str_with]</code>.
+The default options, returned by [`new`][ParseOptions::new], parse the same
+strings as <code>FixedI32::[from\_str][crate::FixedI32::from_str]</code>:
+
+  * The radix is 10.
+  * Rounding is to the nearest, with ties rounded to even.
+  * Underscore separators are not allowed.
+  * A leading “`+`” sign is allowed.
+  * Surrounding whitespace is not allowed.
+  * Excess precision is rounded rather than returned as an error.
+
+# Examples
+
+```rust
+use fixed::types::I16F16;
+use fixed::{ParseOptions, RoundingMode};
+let strict = ParseOptions::new().exact(true);
+assert_eq!(I16F16::from_str_with("1.25", &strict), Ok(I16F16::from_num(1.25)));
+assert!(I16F16::from_str_with("0.1", &strict).is_err());
+
+let relaxed = ParseOptions::new()
+    .rounding(RoundingMode::Floor)
+    .underscores(true)
+    .whitespace(true);
+let parsed = I16F16::from_str_with(" 1_000.5 ", &relaxed);
+assert_eq!(parsed, Ok(I16F16::from_num(1000.5)));
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    radix: u32,
+    rounding: RoundingMode,
+    underscores: bool,
+    plus_sign: bool,
+    whitespace: bool,
+    exact: bool,
+}
+
+impl ParseOptions {
+    /// Creates the default parsing options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::ParseOptions;
+    /// assert_eq!(ParseOptions::new(), ParseOptions::default());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            radix: 10,
+            rounding: RoundingMode::NearestEven,
+            underscores: false,
+            plus_sign: true,
+            whitespace: false,
+            exact: false,
+        }
+    }
+
+    /// Sets the radix, which can be 2, 8, 10 or 16.
+    ///
+    /// The exponent separators are the same as for the radix-specific methods
+    /// such as <code>FixedI32::[from\_str\_hex][crate::FixedI32::from_str_hex]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not 2, 8, 10 or 16.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::ParseOptions;
+    /// let hex = ParseOptions::new().radix(16);
+    /// assert_eq!(I16F16::from_str_with("1.C", &hex), Ok(I16F16::from_num(1.75)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn radix(mut self, radix: u32) -> ParseOptions {
+        assert!(
+            matches!(radix, 2 | 8 | 10 | 16),
+            "radix must be 2, 8, 10 or 16"
+        );
+        self.radix = radix;
+        self
+    }
+
+    /// Sets how excess precision is rounded.
+    ///
+    /// This has no effect if [`exact`][ParseOptions::exact] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::{ParseOptions, RoundingMode};
+    /// let floor = ParseOptions::new().rounding(RoundingMode::Floor);
+    /// let ceil = ParseOptions::new().rounding(RoundingMode::Ceil);
+    /// assert_eq!(I8F8::from_str_with("-0.001", &floor), Ok(-I8F8::DELTA));
+    /// assert_eq!(I8F8::from_str_with("-0.001", &ceil), Ok(I8F8::ZERO));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rounding(mut self, mode: RoundingMode) -> ParseOptions {
+        self.rounding = mode;
+        self
+    }
+
+    /// Sets whether underscores are allowed as digit separators.
+    ///
+    /// Underscores cannot be leading, but they can follow any digit, including
+    /// in the exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I32F32;
+    /// use fixed::ParseOptions;
+    /// let opts = ParseOptions::new().underscores(true);
+    /// let parsed = I32F32::from_str_with("1_000_000.000_5", &opts);
+    /// assert_eq!(parsed, Ok(I32F32::from_num(1_000_000.0005)));
+    /// assert!(I32F32::from_str_with("_1", &opts).is_err());
+    /// assert!(I32F32::from_str_with("1_000", &ParseOptions::new()).is_err());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn underscores(mut self, allow: bool) -> ParseOptions {
+        self.underscores = allow;
+        self
+    }
+
+    /// Sets whether a leading “`+`” sign is allowed.
+    ///
+    /// This does not affect the sign of an exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::ParseOptions;
+    /// let opts = ParseOptions::new().plus_sign(false);
+    /// assert!(I16F16::from_str_with("+1", &opts).is_err());
+    /// assert_eq!(I16F16::from_str_with("1e+1", &opts), Ok(I16F16::from_num(10)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn plus_sign(mut self, allow: bool) -> ParseOptions {
+        self.plus_sign = allow;
+        self
+    }
+
+    /// Sets whether leading and trailing whitespace is allowed.
+    ///
+    /// Whitespace is defined as for [`str::trim`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::ParseOptions;
+    /// let opts = ParseOptions::new().whitespace(true);
+    /// assert_eq!(I16F16::from_str_with("\t1.5\n", &opts), Ok(I16F16::from_num(1.5)));
+    /// assert!(I16F16::from_str_with("1 .5", &opts).is_err());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn whitespace(mut self, allow: bool) -> ParseOptions {
+        self.whitespace = allow;
+        self
+    }
+
+    /// Sets whether a string that cannot be represented exactly is an error
+    /// instead of being rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::U8F8;
+    /// use fixed::ParseOptions;
+    /// let opts = ParseOptions::new().exact(true);
+    /// assert_eq!(U8F8::from_str_with("0.375", &opts), Ok(U8F8::from_num(0.375)));
+    /// // 0.001 cannot be represented exactly using 8 fractional bits
+    /// assert!(U8F8::from_str_with("0.001", &opts).is_err());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn exact(mut self, exact: bool) -> ParseOptions {
+        self.exact = exact;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+// Returns the bits wrapped to nbits ≤ 256 bits, but not sign-extended.
+pub(crate) fn from_str_with(
+    src: &str,
+    options: &ParseOptions,
+    frac_nbits: u32,
+    nbits: u32,
+    signed: bool,
+) -> Result<U256, ParseFixedError> {
+    let (src, skipped) = if options.whitespace {
+        let start = src.trim_start();
+        (start.trim_end(), src.len() - start.len())
+    } else {
+        (src, 0)
+    };
+    if !options.plus_sign && src.starts_with('+') {
+        return Err(ParseFixedError::at(
+            ParseErrorKind::MisplacedSign,
+            skipped,
+            options.radix,
+        ));
+    }
+    let sep = if options.underscores {
+        Sep::Skip
+    } else {
+        Sep::Error
+    };
+    let (neg, abs, overflow, inexact) = match u256::get_int_frac(
+        src.as_bytes(),
+        options.radix,
+        sep,
+        frac_nbits,
+        options.rounding,
+    ) {
+        Ok(o) => o,
+        Err(e) => return Err(e.shifted(skipped)),
+    };
+    if options.exact && inexact {
+        return Err(ParseFixedError::without_offset(
+            ParseErrorKind::ExcessPrecision,
+            options.radix,
+        ));
+    }
+    match apply_sign(neg, abs, overflow, nbits, signed) {
+        (bits, false) => Ok(bits),
+        (_, true) => Err(ParseFixedError::overflow(options.radix)),
+    }
+}
+
+// Parses a hexadecimal floating-point number such as "-0x1.8p3". Returns the
+// bits wrapped to nbits ≤ 256 bits, but not sign-extended, and whether there
+// was an overflow.
+pub(crate) fn overflowing_from_str_hexfloat(
+    src: &str,
+    frac_nbits: u32,
+    nbits: u32,
+    signed: bool,
+) -> Result<(U256, bool), ParseFixedError> {
+    let bytes = src.as_bytes();
+    let (neg, sign_len) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let body = match &bytes[sign_len..] {
+        [b'0', b'x' | b'X', body @ ..] => body,
+        _ => {
+            return Err(ParseFixedError::at(
+                ParseErrorKind::MissingPrefix,
+                sign_len,
+                16,
+            ));
+        }
+    };
+    let skipped = sign_len + 2;
+    if let Some(b'-' | b'+') = body.first() {
+        return Err(ParseFixedError::at(
+            ParseErrorKind::MisplacedSign,
+            skipped,
+            16,
+        ));
+    }
+    let (_, abs, overflow, _) =
+        match u256::get_int_frac(body, 16, Sep::Error, frac_nbits, RoundingMode::NearestEven) {
+            Ok(o) => o,
+            Err(e) => return Err(e.shifted(skipped)),
+        };
+    Ok(apply_sign(neg, abs, overflow, nbits, signed))
+}
+
+// Parses a ratio such as "-3/7", where the denominator can be omitted. The
+// quotient is rounded to the nearest, with ties rounded to even. Returns the
+// bits wrapped to nbits ≤ 256 bits, but not sign-extended, and whether there
+// was an overflow.
+pub(crate) fn overflowing_from_str_ratio(
+    src: &str,
+    frac_nbits: u32,
+    nbits: u32,
+    signed: bool,
+) -> Result<(U256, bool), ParseFixedError> {
+    let bytes = src.as_bytes();
+    let (neg, sign_len) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let rest = &bytes[sign_len..];
+    let (num_bytes, den_bytes) = match rest.iter().position(|&b| b == b'/') {
+        Some(slash) => (&rest[..slash], Some(&rest[slash + 1..])),
+        None => (rest, None),
+    };
+    let num = parse_ratio_int(num_bytes).map_err(|e| e.shifted(sign_len))?;
+    let den = match den_bytes {
+        Some(den_bytes) => {
+            let den_start = sign_len + num_bytes.len() + 1;
+            let den = parse_ratio_int(den_bytes).map_err(|e| e.shifted(den_start))?;
+            if int256::is_zero_u256(den) {
+                return Err(ParseFixedError::at(
+                    ParseErrorKind::ZeroDenominator,
+                    den_start,
+                    10,
+                ));
+            }
+            den
+        }
+        None => U256::from(1u8),
+    };
+    Ok(ratio::overflowing_div_bits(
+        neg, num, den, frac_nbits, nbits, signed,
+    ))
+}
+
+// Parses decimal digits with no sign, point or exponent.
+fn parse_ratio_int(bytes: &[u8]) -> Result<U256, ParseFixedError> {
+    if bytes.is_empty() {
+        return Err(ParseFixedError::at(ParseErrorKind::NoDigits, 0, 10));
+    }
+    let mut acc = U256::ZERO;
+    for (index, &byte) in bytes.iter().enumerate() {
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'+' | b'-' => {
+                return Err(ParseFixedError::at(
+                    ParseErrorKind::MisplacedSign,
+                    index,
+                    10,
+                ));
+            }
+            _ => {
+                return Err(ParseFixedError::at(ParseErrorKind::InvalidDigit, index, 10));
+            }
+        };
+        let (val, overflow) = int256::overflowing_mul_add_u256_u128(acc, 10, u128::from(digit));
+        if overflow {
+            return Err(ParseFixedError::overflow(10));
+        }
+        acc = val;
+    }
+    Ok(acc)
+}
+
+// Returns the bits wrapped to nbits ≤ 256 bits, but not sign-extended, and
+// whether the value overflows.
+pub(crate) const fn apply_sign(
+    neg: bool,
+    abs: U256,
+    overflow: bool,
+    nbits: u32,
+    signed: bool,
+) -> (U256, bool) {
+    let used = 256 - int256::leading_zeros_u256(abs);
+    let fits = if signed {
+        used < nbits || (neg && used == nbits && int256::trailing_zeros_u256(abs) == nbits - 1)
+    } else {
+        used <= nbits && (!neg || used == 0)
+    };
+    let bits = if neg {
+        int256::wrapping_neg_u256(abs)
+    } else {
+        abs
+    };
+    (bits, overflow || !fits)
+}
+
+// Used by F128, which converts the decimal digits itself.
+pub const fn parse_dec_digits(
+    bytes: &[u8],
+) -> Result<(bool, DigitsExp<'_>, DigitsExp<'_>), ParseFixedError> {
+    match parse_bounds(bytes, 10, Sep::Error) {
+        Ok(Parse { neg, int, frac, .. }) => Ok((neg, int, frac)),
+        Err(e) => Err(e),
+    }
+}
+
 // Zeros at start of int and at end of frac are trimmed.
 // Leading underscores for either int or frac are never accepted, even for Sep::Skip.
-const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, ParseErrorKind> {
+const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, ParseFixedError> {
     let mut sign: Option<bool> = None;
     let mut int_start: Option<usize> = None;
     let mut point: Option<usize> = None;
     let mut frac_end: Option<usize> = None;
     let mut has_int_digit = false;
     let mut has_frac_digit = false;
+    let mut has_nonzero_digit = false;
     let mut exp_sep: Option<usize> = None;
     let mut exp_sign: Option<bool> = None;
     let mut exp: i32 = 0;
@@ -1103,12 +1996,20 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
             (b'+', _) => {
                 if exp_sep.is_none() {
                     if sign.is_some() || has_int_digit || point.is_some() {
-                        return Err(ParseErrorKind::MisplacedSign);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::MisplacedSign,
+                            index,
+                            radix,
+                        ));
                     }
                     sign = Some(false);
                 } else {
                     if exp_sign.is_some() || has_exp_digit {
-                        return Err(ParseErrorKind::MisplacedSign);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::MisplacedSign,
+                            index,
+                            radix,
+                        ));
                     }
                     exp_sign = Some(false);
                 }
@@ -1116,22 +2017,38 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
             (b'-', _) => {
                 if exp_sep.is_none() {
                     if sign.is_some() || has_int_digit || point.is_some() {
-                        return Err(ParseErrorKind::MisplacedSign);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::MisplacedSign,
+                            index,
+                            radix,
+                        ));
                     }
                     sign = Some(true);
                 } else {
                     if exp_sign.is_some() || has_exp_digit {
-                        return Err(ParseErrorKind::MisplacedSign);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::MisplacedSign,
+                            index,
+                            radix,
+                        ));
                     }
                     exp_sign = Some(true);
                 }
             }
             (b'.', _) => {
                 if exp_sep.is_some() {
-                    return Err(ParseErrorKind::ExpInvalidDigit);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::ExpInvalidDigit,
+                        index,
+                        radix,
+                    ));
                 }
                 if point.is_some() {
-                    return Err(ParseErrorKind::TooManyPoints);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::TooManyPoints,
+                        index,
+                        radix,
+                    ));
                 }
                 point = Some(index);
                 frac_end = Some(index + 1);
@@ -1139,26 +2056,46 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
             (b'_', _) => {
                 if matches!(sep, Sep::Error) {
                     if exp_sep.is_some() {
-                        return Err(ParseErrorKind::ExpInvalidDigit);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::ExpInvalidDigit,
+                            index,
+                            radix,
+                        ));
                     }
-                    return Err(ParseErrorKind::InvalidDigit);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::InvalidDigit,
+                        index,
+                        radix,
+                    ));
                 };
                 if (point.is_none() && exp_sep.is_none() && !has_int_digit)
                     || (point.is_some() && exp_sep.is_none() && !has_frac_digit)
                     || (exp_sep.is_some() && !has_exp_digit)
                 {
-                    return Err(ParseErrorKind::MisplacedUnderscore);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::MisplacedUnderscore,
+                        index,
+                        radix,
+                    ));
                 }
             }
             (b'e' | b'E', 2 | 8 | 10) | (b'@', _) => {
                 if exp_sep.is_some() {
-                    return Err(ParseErrorKind::TooManyExp);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::TooManyExp,
+                        index,
+                        radix,
+                    ));
                 }
                 exp_sep = Some(index);
             }
             (b'p' | b'P', 2 | 8 | 16) => {
                 if exp_sep.is_some() {
-                    return Err(ParseErrorKind::TooManyExp);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::TooManyExp,
+                        index,
+                        radix,
+                    ));
                 }
                 exp_sep = Some(index);
                 exp_is_bit = true;
@@ -1169,6 +2106,9 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
             | (b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F', 16)
                 if exp_sep.is_none() =>
             {
+                if byte != b'0' {
+                    has_nonzero_digit = true;
+                }
                 if point.is_none() {
                     has_int_digit = true;
                     if int_start.is_none() && byte != b'0' {
@@ -1182,10 +2122,20 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
                 }
             }
             (b'0'..=b'9', _) if exp_sep.is_some() => {
+                has_exp_digit = true;
+                // zero stays zero whatever the exponent, so a large exponent
+                // must not be reported as an overflow
+                if !has_nonzero_digit {
+                    continue;
+                }
                 exp = match exp.checked_mul(10) {
                     Some(s) => s,
                     None => {
-                        return Err(ParseErrorKind::ExpOverflow);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::ExpOverflow,
+                            index,
+                            radix,
+                        ));
                     }
                 };
                 let add = match exp_sign {
@@ -1195,24 +2145,43 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
                 exp = match exp.checked_add(add) {
                     Some(s) => s,
                     None => {
-                        return Err(ParseErrorKind::ExpOverflow);
+                        return Err(ParseFixedError::at(
+                            ParseErrorKind::ExpOverflow,
+                            index,
+                            radix,
+                        ));
                     }
                 };
-                has_exp_digit = true;
             }
             _ => {
                 if exp_sep.is_some() {
-                    return Err(ParseErrorKind::ExpInvalidDigit);
+                    return Err(ParseFixedError::at(
+                        ParseErrorKind::ExpInvalidDigit,
+                        index,
+                        radix,
+                    ));
                 }
-                return Err(ParseErrorKind::InvalidDigit);
+                return Err(ParseFixedError::at(
+                    ParseErrorKind::InvalidDigit,
+                    index,
+                    radix,
+                ));
             }
         }
     }
     if !has_int_digit && !has_frac_digit {
-        return Err(ParseErrorKind::NoDigits);
+        return Err(ParseFixedError::at(
+            ParseErrorKind::NoDigits,
+            bytes.len(),
+            radix,
+        ));
     }
     if exp_sep.is_some() && !has_exp_digit {
-        return Err(ParseErrorKind::ExpNoDigits);
+        return Err(ParseFixedError::at(
+            ParseErrorKind::ExpNoDigits,
+            bytes.len(),
+            radix,
+        ));
     }
     // workaround for https://github.com/rust-lang/rust-clippy/issues/12568
     #[allow(unknown_lints, clippy::manual_unwrap_or_default)]
@@ -1259,7 +2228,15 @@ const fn parse_bounds(bytes: &[u8], radix: u32, sep: Sep) -> Result<Parse<'_>, P
         0
     };
     let Some((int, frac)) = DigitsExp::new_int_frac(int, frac, exp) else {
-        return Err(ParseErrorKind::ExpOverflow);
+        let offset = match exp_sep {
+            Some(exp_sep) => exp_sep,
+            None => 0,
+        };
+        return Err(ParseFixedError::at(
+            ParseErrorKind::ExpOverflow,
+            offset,
+            radix,
+        ));
     };
     let bit_exp = BitExp::new(bit_exp, frac);
     Ok(Parse {
@@ -1303,6 +2280,8 @@ impl_from_str! { FixedU16, LeEqU16 }
 impl_from_str! { FixedU32, LeEqU32 }
 impl_from_str! { FixedU64, LeEqU64 }
 impl_from_str! { FixedU128, LeEqU128 }
+impl_from_str! { FixedI256, LeEqU256 }
+impl_from_str! { FixedU256, LeEqU256 }
 
 #[cfg(test)]
 mod tests {
@@ -1316,9 +2295,6 @@ mod tests {
 
     #[test]
     fn overflowing() {
-        let overflow = ParseFixedError {
-            kind: ParseErrorKind::Overflow,
-        };
         assert_eq!(
             U4F4::overflowing_from_str("15.5"),
             Ok((U4F4::from_bits(0xF8), false))
@@ -1328,7 +2304,10 @@ mod tests {
             U4F4::overflowing_from_str("31.5"),
             Ok((U4F4::from_bits(0xF8), true))
         );
-        assert_eq!(U4F4::from_str("31.5"), Err(overflow));
+        assert_eq!(
+            U4F4::from_str("31.5").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
         assert_eq!(
             U4F4::overflowing_from_str("271.5"),
             Ok((U4F4::from_bits(0xF8), true))
@@ -1355,7 +2334,10 @@ mod tests {
             U4F4::overflowing_from_str_binary("11111.1000"),
             Ok((U4F4::from_bits(0xF8), true))
         );
-        assert_eq!(U4F4::from_str_binary("11111.1000"), Err(overflow));
+        assert_eq!(
+            U4F4::from_str_binary("11111.1000").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
         assert_eq!(
             U8F0::overflowing_from_str_binary("100001111"),
             Ok((U8F0::from_bits(0x0F), true))
@@ -1370,7 +2352,10 @@ mod tests {
             U4F4::overflowing_from_str_octal("77.7"),
             Ok((U4F4::from_bits(0xFE), true))
         );
-        assert_eq!(U4F4::from_str_octal("77.7"), Err(overflow));
+        assert_eq!(
+            U4F4::from_str_octal("77.7").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
         assert_eq!(
             U4F4::overflowing_from_str_octal("707.7"),
             Ok((U4F4::from_bits(0x7E), true))
@@ -1389,7 +2374,10 @@ mod tests {
             U6F10::overflowing_from_str_hex("FF.8"),
             Ok((U6F10::from_bits(0xFE00), true))
         );
-        assert_eq!(U6F10::from_str_hex("FF.8"), Err(overflow));
+        assert_eq!(
+            U6F10::from_str_hex("FF.8").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
         assert_eq!(
             U6F10::overflowing_from_str_hex("F0F.8"),
             Ok((U6F10::from_bits(0x3E00), true))
@@ -1555,7 +2543,7 @@ mod tests {
     #[track_caller]
     fn check_parse_bounds_err(bytes: &str, radix: u32, sep: Sep, check: ParseErrorKind) {
         let bytes = bytes.as_bytes();
-        let kind = parse_bounds(bytes, radix, sep).unwrap_err();
+        let kind = parse_bounds(bytes, radix, sep).unwrap_err().kind();
         assert_eq!(kind, check);
     }
 
@@ -2818,4 +3806,212 @@ mod tests {
             assert_eq!(I15F17::from_str(&fix_neg_str9).unwrap(), fix_neg);
         }
     }
+
+    #[test]
+    fn from_str_with() {
+        use crate::{ParseOptions, RoundingMode};
+
+        let modes = [
+            RoundingMode::NearestEven,
+            RoundingMode::NearestAway,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+        ];
+        // n / 1000 with 4 fractional bits is n × 16 / 1000 = n × 2 / 125
+        for n in -9000i32..=9000 {
+            let abs = n.unsigned_abs();
+            let sign = if n < 0 { "-" } else { "" };
+            let s = format!("{sign}{}.{:03}", abs / 1000, abs % 1000);
+            let (floor, rem) = ((n * 2).div_euclid(125), (n * 2).rem_euclid(125));
+            for mode in modes {
+                let bits = match mode {
+                    _ if rem == 0 => floor,
+                    RoundingMode::NearestEven if rem * 2 == 125 => floor + (floor & 1),
+                    RoundingMode::NearestAway if rem * 2 == 125 => floor + i32::from(n > 0),
+                    RoundingMode::NearestEven | RoundingMode::NearestAway => {
+                        floor + i32::from(rem * 2 > 125)
+                    }
+                    RoundingMode::Floor => floor,
+                    RoundingMode::Ceil => floor + 1,
+                    RoundingMode::TowardZero => floor + i32::from(n < 0),
+                };
+                let opts = ParseOptions::new().rounding(mode);
+                let expected = i8::try_from(bits).map(I4F4::from_bits);
+                let expected = expected.map_err(|_| ParseFixedError::overflow(10));
+                assert_eq!(I4F4::from_str_with(&s, &opts), expected, "{s} {mode:?}");
+                let expected = u8::try_from(bits).map(U4F4::from_bits);
+                let expected = expected.map_err(|_| ParseFixedError::overflow(10));
+                assert_eq!(U4F4::from_str_with(&s, &opts), expected, "{s} {mode:?}");
+                let expected = I124F4::from_bits(bits.into());
+                assert_eq!(
+                    I124F4::from_str_with(&s, &opts),
+                    Ok(expected),
+                    "{s} {mode:?}"
+                );
+
+                let exact = opts.exact(true);
+                let expected = if rem == 0 {
+                    Ok(expected)
+                } else {
+                    Err(ParseFixedError::without_offset(
+                        ParseErrorKind::ExcessPrecision,
+                        10,
+                    ))
+                };
+                assert_eq!(I124F4::from_str_with(&s, &exact), expected, "{s} {mode:?}");
+            }
+        }
+
+        let opts = ParseOptions::new();
+        for s in [
+            "1.5", "-0.25e1", "+7", "0x1", "1_0", " 1", "", "1e", "1.2.3",
+        ] {
+            assert_eq!(I16F16::from_str_with(s, &opts), I16F16::from_str(s));
+            assert_eq!(U16F16::from_str_with(s, &opts), U16F16::from_str(s));
+            assert_eq!(I8F120::from_str_with(s, &opts), I8F120::from_str(s));
+            assert_eq!(
+                crate::FixedI256::<extra::U200>::from_str_with(s, &opts),
+                s.parse()
+            );
+        }
+        let relaxed = opts.underscores(true).whitespace(true).plus_sign(false);
+        assert_eq!(
+            I16F16::from_str_with(" 1_0.5\t", &relaxed),
+            Ok(I16F16::from_num(10.5))
+        );
+        assert_eq!(
+            I16F16::from_str_with("+1", &relaxed),
+            Err(ParseFixedError::at(ParseErrorKind::MisplacedSign, 0, 10))
+        );
+        assert_eq!(I8F8::from_str_with("-128", &opts), Ok(I8F8::MIN));
+        assert!(I8F8::from_str_with("128", &opts).is_err());
+        assert!(U8F8::from_str_with("-0.001", &opts.rounding(RoundingMode::Floor)).is_err());
+    }
+
+    #[test]
+    fn error_offset_radix() {
+        use crate::ParseOptions;
+
+        #[track_caller]
+        fn check(err: ParseFixedError, kind: ParseErrorKind, offset: Option<usize>, radix: u32) {
+            assert_eq!(
+                (err.kind(), err.offset(), err.radix()),
+                (kind, offset, radix)
+            );
+        }
+
+        check(
+            I16F16::from_str("12.3x4").unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(4),
+            10,
+        );
+        check(
+            I16F16::from_str("-1.5e+1-").unwrap_err(),
+            ParseErrorKind::MisplacedSign,
+            Some(7),
+            10,
+        );
+        check(
+            I16F16::from_str("1.5e").unwrap_err(),
+            ParseErrorKind::ExpNoDigits,
+            Some(4),
+            10,
+        );
+        check(
+            I16F16::from_str("").unwrap_err(),
+            ParseErrorKind::NoDigits,
+            Some(0),
+            10,
+        );
+        check(
+            U16F16::from_str_binary("1.12").unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(3),
+            2,
+        );
+        check(
+            U16F16::from_str_octal("7.7.7").unwrap_err(),
+            ParseErrorKind::TooManyPoints,
+            Some(3),
+            8,
+        );
+        check(
+            I16F16::from_str_hex("F@1@1").unwrap_err(),
+            ParseErrorKind::TooManyExp,
+            Some(3),
+            16,
+        );
+        check(
+            I16F16::from_str_hex("1_0").unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            16,
+        );
+        check(
+            I8F8::from_str("1000").unwrap_err(),
+            ParseErrorKind::Overflow,
+            None,
+            10,
+        );
+        check(
+            I128F0::from_str("1e99999999999").unwrap_err(),
+            ParseErrorKind::ExpOverflow,
+            Some(11),
+            10,
+        );
+        assert_eq!(I128F0::from_str("0.0e99999999999"), Ok(I128F0::ZERO));
+        check(
+            crate::FixedU256::<extra::U8>::from_str_octal("18").unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(1),
+            8,
+        );
+
+        check(
+            from_str::i32::lit(b"-0x1_.g", 16).unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(6),
+            16,
+        );
+        check(
+            from_str::i32::lit(b"0b_+1", 16).unwrap_err(),
+            ParseErrorKind::MisplacedSign,
+            Some(3),
+            2,
+        );
+        check(
+            from_str::i32::lit(b"-", 16).unwrap_err(),
+            ParseErrorKind::NoDigits,
+            Some(1),
+            10,
+        );
+        check(
+            from_str::i16::lit(b"0o400", 8).unwrap_err(),
+            ParseErrorKind::Overflow,
+            None,
+            8,
+        );
+
+        let opts = ParseOptions::new().whitespace(true).plus_sign(false);
+        check(
+            I16F16::from_str_with("  1.5!", &opts).unwrap_err(),
+            ParseErrorKind::InvalidDigit,
+            Some(5),
+            10,
+        );
+        check(
+            I16F16::from_str_with(" +1", &opts).unwrap_err(),
+            ParseErrorKind::MisplacedSign,
+            Some(1),
+            10,
+        );
+        check(
+            I16F16::from_str_with("0.000001", &opts.radix(8).exact(true)).unwrap_err(),
+            ParseErrorKind::ExcessPrecision,
+            None,
+            8,
+        );
+    }
 }
@@ -13,11 +13,12 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::fixed256::{self, FromWide};
 use crate::int_helper;
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256,
 };
 use core::cmp::Ordering;
 
@@ -50,7 +51,7 @@ pub enum FloatKind {
 
 pub struct Private;
 
-pub trait Sealed: Copy {
+pub trait Sealed: Copy + FromWide {
     fn to_fixed_helper(self, _: Private, dst_frac_nbits: u32, dst_int_nbits: u32) -> ToFixedHelper;
     fn to_float_helper(self, _: Private) -> ToFloatHelper;
     fn saturating_from_float_helper(_: Private, src: FromFloatHelper) -> Self;
@@ -157,3 +158,40 @@ impl_sealed! { FixedU16(LeEqU16, Unsigned, u16) }
 impl_sealed! { FixedU32(LeEqU32, Unsigned, u32) }
 impl_sealed! { FixedU64(LeEqU64, Unsigned, u64) }
 impl_sealed! { FixedU128(LeEqU128, Unsigned, u128) }
+
+// The float helpers hold at most 128 bits, so conversions between 256-bit
+// fixed-point numbers and floating-point numbers go through Wide instead; see
+// is_wide.
+macro_rules! impl_sealed_256 {
+    ($Fixed:ident) => {
+        impl<Frac: LeEqU256> Sealed for $Fixed<Frac> {
+            #[inline]
+            fn to_fixed_helper(
+                self,
+                _: Private,
+                dst_frac_nbits: u32,
+                dst_int_nbits: u32,
+            ) -> ToFixedHelper {
+                fixed256::to_fixed_helper(self.finite(), dst_frac_nbits, dst_int_nbits)
+            }
+            fn to_float_helper(self, _: Private) -> ToFloatHelper {
+                unreachable!("256-bit numbers are converted to floats through Wide");
+            }
+            fn saturating_from_float_helper(_: Private, _: FromFloatHelper) -> Self {
+                unreachable!("256-bit numbers are converted from floats through Wide");
+            }
+            fn overflowing_from_float_helper(_: Private, _: FromFloatHelper) -> (Self, bool) {
+                unreachable!("256-bit numbers are converted from floats through Wide");
+            }
+        }
+    };
+}
+
+impl_sealed_256! { FixedI256 }
+impl_sealed_256! { FixedU256 }
+
+// Returns true for fixed-point numbers that cannot use the float helpers.
+#[inline]
+pub const fn is_wide<F: crate::traits::Fixed>() -> bool {
+    F::INT_NBITS + F::FRAC_NBITS > 128
+}
@@ -0,0 +1,334 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::int_helper;
+use crate::int256;
+use crate::int256::U256;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
+use crate::{
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256,
+};
+use core::fmt::{Alignment, Debug, Display, Formatter, LowerHex, Result as FmtResult, UpperHex};
+use core::str;
+
+/// Formats a fixed-point number as a hexadecimal floating-point number.
+///
+/// The output uses the notation of hexadecimal floating-point literals in C,
+/// for example “`0x1.8p+3`” for 12. The significand is normalized so that
+/// its integer digit is 1, trailing zeros are omitted, and the exponent is a
+/// power of two written in decimal. Zero is written as “`0x0p+0`”.
+///
+/// Every fixed-point number is represented exactly, so the output can be
+/// parsed back using
+/// <code>FixedI32::[from\_str\_hexfloat][crate::FixedI32::from_str_hexfloat]</code>
+/// or
+/// <code>FixedU32::[from\_str\_hexfloat][crate::FixedU32::from_str_hexfloat]</code>
+/// to give the same value.
+///
+/// [`Display`] and [`LowerHex`] use lowercase digits, while [`UpperHex`] uses
+/// uppercase digits, “`0X`” and “`P`”. The width, fill, alignment, sign and
+/// zero-padding flags are supported; the precision is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::{I16F16, U8F8};
+/// use fixed::HexFloat;
+/// assert_eq!(HexFloat(I16F16::from_num(12)).to_string(), "0x1.8p+3");
+/// assert_eq!(HexFloat(I16F16::from_num(-0.1875)).to_string(), "-0x1.8p-3");
+/// assert_eq!(format!("{:X}", HexFloat(U8F8::from_num(5.75))), "0X1.7P+2");
+/// assert_eq!(HexFloat(U8F8::ZERO).to_string(), "0x0p+0");
+///
+/// let f = I16F16::from_bits(0x1234_5678);
+/// let s = HexFloat(f).to_string();
+/// assert_eq!(s, "0x1.2345678p+12");
+/// assert_eq!(I16F16::from_str_hexfloat(&s), Ok(f));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct HexFloat<F>(pub F);
+
+// Large enough for "1." + 64 digits + "p-256".
+const BUF_LEN: usize = 72;
+
+fn fmt_hex_float(
+    (neg, abs): (bool, U256),
+    frac_nbits: u32,
+    upper: bool,
+    fmt: &mut Formatter,
+) -> FmtResult {
+    use core::fmt::Write;
+
+    let mut buf = [0u8; BUF_LEN];
+    let mut len = 1;
+    let exp = if int256::is_zero_u256(abs) {
+        buf[0] = b'0';
+        0
+    } else {
+        buf[0] = b'1';
+        let lz = int256::leading_zeros_u256(abs);
+        // shift out the leading one, leaving the remaining bits at the top
+        let mut rest = int256::wrapping_shl_u256(int256::wrapping_shl_u256(abs, lz), 1);
+        if !int256::is_zero_u256(rest) {
+            buf[len] = b'.';
+            len += 1;
+        }
+        while !int256::is_zero_u256(rest) {
+            let digit = (rest.hi >> 124) as u8;
+            buf[len] = if digit < 10 {
+                b'0' + digit
+            } else if upper {
+                b'A' - 10 + digit
+            } else {
+                b'a' - 10 + digit
+            };
+            len += 1;
+            rest = int256::wrapping_shl_u256(rest, 4);
+        }
+        (255 - lz) as i32 - frac_nbits as i32
+    };
+    buf[len] = if upper { b'P' } else { b'p' };
+    buf[len + 1] = if exp < 0 { b'-' } else { b'+' };
+    len += 2;
+    let abs_exp = exp.unsigned_abs();
+    if abs_exp >= 100 {
+        buf[len] = b'0' + (abs_exp / 100) as u8;
+        len += 1;
+    }
+    if abs_exp >= 10 {
+        buf[len] = b'0' + (abs_exp / 10 % 10) as u8;
+        len += 1;
+    }
+    buf[len] = b'0' + (abs_exp % 10) as u8;
+    len += 1;
+
+    let sign = if neg {
+        "-"
+    } else if fmt.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let prefix = if upper { "0X" } else { "0x" };
+    let req_width = sign.len() + prefix.len() + len;
+    let pad = fmt
+        .width()
+        .and_then(|w| w.checked_sub(req_width))
+        .unwrap_or(0);
+    let (pad_left, pad_zeros, pad_right) = if fmt.sign_aware_zero_pad() {
+        (0, pad, 0)
+    } else {
+        match fmt.align() {
+            Some(Alignment::Left) => (0, 0, pad),
+            Some(Alignment::Center) => (pad / 2, 0, pad - pad / 2),
+            None | Some(Alignment::Right) => (pad, 0, 0),
+        }
+    };
+    let fill = fmt.fill();
+
+    for _ in 0..pad_left {
+        fmt.write_char(fill)?;
+    }
+    fmt.write_str(sign)?;
+    fmt.write_str(prefix)?;
+    for _ in 0..pad_zeros {
+        fmt.write_char('0')?;
+    }
+    fmt.write_str(str::from_utf8(&buf[..len]).unwrap())?;
+    for _ in 0..pad_right {
+        fmt.write_char(fill)?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_hex_float {
+    ($Fixed:ident, $LeEqU:ident, $Inner:ident) => {
+        impl_hex_float! {
+            $Fixed,
+            $LeEqU,
+            |x: $Fixed<Frac>| {
+                let (neg, abs) = int_helper::$Inner::neg_abs(x.to_bits());
+                (neg, U256 { lo: u128::from(abs), hi: 0 })
+            }
+        }
+    };
+    ($Fixed:ident, $LeEqU:ident, $neg_abs:expr) => {
+        impl<Frac: $LeEqU> Display for HexFloat<$Fixed<Frac>> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_hex_float($neg_abs(self.0), $Fixed::<Frac>::FRAC_NBITS, false, f)
+            }
+        }
+
+        impl<Frac: $LeEqU> Debug for HexFloat<$Fixed<Frac>> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_hex_float($neg_abs(self.0), $Fixed::<Frac>::FRAC_NBITS, false, f)
+            }
+        }
+
+        impl<Frac: $LeEqU> LowerHex for HexFloat<$Fixed<Frac>> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_hex_float($neg_abs(self.0), $Fixed::<Frac>::FRAC_NBITS, false, f)
+            }
+        }
+
+        impl<Frac: $LeEqU> UpperHex for HexFloat<$Fixed<Frac>> {
+            fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                fmt_hex_float($neg_abs(self.0), $Fixed::<Frac>::FRAC_NBITS, true, f)
+            }
+        }
+    };
+}
+
+impl_hex_float! { FixedI8, LeEqU8, i8 }
+impl_hex_float! { FixedI16, LeEqU16, i16 }
+impl_hex_float! { FixedI32, LeEqU32, i32 }
+impl_hex_float! { FixedI64, LeEqU64, i64 }
+impl_hex_float! { FixedI128, LeEqU128, i128 }
+impl_hex_float! { FixedU8, LeEqU8, u8 }
+impl_hex_float! { FixedU16, LeEqU16, u16 }
+impl_hex_float! { FixedU32, LeEqU32, u32 }
+impl_hex_float! { FixedU64, LeEqU64, u64 }
+impl_hex_float! { FixedU128, LeEqU128, u128 }
+impl_hex_float! { FixedI256, LeEqU256, |x: FixedI256<Frac>| x.neg_abs() }
+impl_hex_float! { FixedU256, LeEqU256, |x: FixedU256<Frac>| x.neg_abs() }
+
+#[cfg(test)]
+mod tests {
+    use crate::from_str::ParseErrorKind;
+    use crate::types::extra::{U0, U8, U128, U255, U256};
+    use crate::types::*;
+    use crate::{FixedI128, FixedI256, FixedU128, FixedU256, HexFloat};
+    use std::format;
+    #[cfg(not(feature = "std"))]
+    use std::string::ToString;
+
+    #[test]
+    fn format() {
+        assert_eq!(HexFloat(I8F8::ZERO).to_string(), "0x0p+0");
+        assert_eq!(HexFloat(I8F8::DELTA).to_string(), "0x1p-8");
+        assert_eq!(HexFloat(I8F8::MIN).to_string(), "-0x1p+7");
+        assert_eq!(HexFloat(I8F8::MAX).to_string(), "0x1.fffcp+6");
+        assert_eq!(HexFloat(U0F8::MAX).to_string(), "0x1.fep-1");
+        assert_eq!(HexFloat(U8F0::MAX).to_string(), "0x1.fep+7");
+        assert_eq!(format!("{:X}", HexFloat(I8F8::MAX)), "0X1.FFFCP+6");
+        assert_eq!(format!("{:x}", HexFloat(I8F8::MAX)), "0x1.fffcp+6");
+        assert_eq!(format!("{:?}", HexFloat(I8F8::MAX)), "0x1.fffcp+6");
+        assert_eq!(HexFloat(FixedU256::<U256>::DELTA).to_string(), "0x1p-256");
+        assert_eq!(HexFloat(FixedI256::<U0>::MIN).to_string(), "-0x1p+255");
+        assert_eq!(
+            HexFloat(FixedU256::<U0>::MAX).to_string(),
+            format!("0x1.{}ep+255", "f".repeat(63))
+        );
+        assert_eq!(HexFloat(FixedU128::<U128>::DELTA).to_string(), "0x1p-128");
+        assert_eq!(HexFloat(FixedI128::<U0>::MIN).to_string(), "-0x1p+127");
+
+        let f = I8F8::from_num(-1.5);
+        assert_eq!(format!("{:12}", HexFloat(f)), "   -0x1.8p+0");
+        assert_eq!(format!("{:<12}", HexFloat(f)), "-0x1.8p+0   ");
+        assert_eq!(format!("{:*^12}", HexFloat(f)), "*-0x1.8p+0**");
+        assert_eq!(format!("{:012}", HexFloat(f)), "-0x0001.8p+0");
+        assert_eq!(format!("{:+}", HexFloat(-f)), "+0x1.8p+0");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            I8F8::from_str_hexfloat("0x1.8p-3"),
+            Ok(I8F8::from_num(0.1875))
+        );
+        assert_eq!(
+            I8F8::from_str_hexfloat("+0X.18P+1"),
+            Ok(I8F8::from_num(0.1875))
+        );
+        assert_eq!(I8F8::from_str_hexfloat("-0x80"), Ok(I8F8::MIN));
+        assert_eq!(I8F8::from_str_hexfloat("0x1p-9"), Ok(I8F8::ZERO));
+        assert_eq!(I8F8::from_str_hexfloat("0x3p-9"), Ok(I8F8::DELTA * 2));
+        assert_eq!(
+            I8F8::from_str_hexfloat("0x0p99999999999999999999"),
+            Ok(I8F8::ZERO)
+        );
+        assert_eq!(
+            I8F8::from_str_hexfloat("-0x0.00p-99999999999999999999"),
+            Ok(I8F8::ZERO)
+        );
+        assert_eq!(
+            I8F8::from_str_hexfloat("0x1p99999999999999999999")
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::ExpOverflow
+        );
+        assert_eq!(
+            I8F8::from_str_hexfloat("0x80").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(
+            I8F8::overflowing_from_str_hexfloat("0x80"),
+            Ok((I8F8::MIN, true))
+        );
+        assert_eq!(U8F8::saturating_from_str_hexfloat("-0x1"), Ok(U8F8::ZERO));
+        assert_eq!(
+            FixedU256::<U255>::saturating_from_str_hexfloat("0x1p1"),
+            Ok(FixedU256::<U255>::MAX)
+        );
+
+        let err = I8F8::from_str_hexfloat("1.8p3").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::MissingPrefix);
+        assert_eq!(err.offset(), Some(0));
+        let err = I8F8::from_str_hexfloat("-1.8p3").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::MissingPrefix);
+        assert_eq!(err.offset(), Some(1));
+        let err = I8F8::from_str_hexfloat("0x-1").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::MisplacedSign);
+        assert_eq!(err.offset(), Some(2));
+        let err = I8F8::from_str_hexfloat("-0x1.8g").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!((err.offset(), err.radix()), (Some(6), 16));
+        let err = I8F8::from_str_hexfloat("0x1p").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::ExpNoDigits);
+        assert_eq!(err.offset(), Some(4));
+        let err = I8F8::from_str_hexfloat("0x").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NoDigits);
+    }
+
+    #[test]
+    fn round_trip() {
+        for bits in i16::MIN..=i16::MAX {
+            let f = I8F8::from_bits(bits);
+            assert_eq!(I8F8::from_str_hexfloat(&HexFloat(f).to_string()), Ok(f));
+            assert_eq!(
+                I8F8::from_str_hexfloat(&format!("{:X}", HexFloat(f))),
+                Ok(f)
+            );
+            let f = U0F16::from_bits(bits as u16);
+            assert_eq!(U0F16::from_str_hexfloat(&HexFloat(f).to_string()), Ok(f));
+        }
+        let mut bits = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210_u128;
+        for _ in 0..128 {
+            let f = FixedI128::<U8>::from_bits(bits as i128);
+            let s = HexFloat(f).to_string();
+            assert_eq!(FixedI128::<U8>::from_str_hexfloat(&s), Ok(f));
+            let f = FixedU256::<U255>::from_be_bytes(
+                [bits.to_be_bytes(), bits.reverse_bits().to_be_bytes()]
+                    .concat()
+                    .try_into()
+                    .unwrap(),
+            );
+            let s = HexFloat(f).to_string();
+            assert_eq!(FixedU256::<U255>::from_str_hexfloat(&s), Ok(f));
+            bits = bits.rotate_left(1) ^ 0x5;
+        }
+    }
+}
@@ -13,12 +13,14 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::int256::U256;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128, Unwrapped, Wrapping,
+    Bounded, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16,
+    FixedU32, FixedU64, FixedU128, FixedU256, I256Bits, U256Bits, Unwrapped, Wrapping,
 };
 use arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured};
+use core::mem;
 
 macro_rules! impl_trait {
     ($Fixed:ident, $LeEqU:ident, $Inner:ident) => {
@@ -57,6 +59,54 @@ macro_rules! impl_trait {
                 <$Inner as Arbitrary<'a>>::size_hint(depth)
             }
         }
+
+        impl_bounded! { $Fixed, $LeEqU, $Inner }
+    };
+}
+
+// There is no primitive 256-bit integer for Unstructured::int_in_range, so the
+// 256-bit bounded numbers reduce arbitrary bits modulo the length of the range.
+macro_rules! impl_bounded {
+    ($Fixed:ident, LeEqU256, $Inner:ident) => {
+        impl<'a, Frac: LeEqU256, const MIN: i128, const MAX: i128> Arbitrary<'a>
+            for Bounded<$Fixed<Frac>, MIN, MAX>
+        {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+                let min = U256Bits(Self::min_bound().to_bits().0);
+                let max = U256Bits(Self::max_bound().to_bits().0);
+                let offset = <U256Bits as Arbitrary<'a>>::arbitrary(u)?;
+                let offset = match max.wrapping_sub(min).checked_add(U256Bits::from_u128(1)) {
+                    Some(len) => offset % len,
+                    None => offset,
+                };
+                let bits = $Inner(min.wrapping_add(offset).0);
+                Ok(Self::new_clamped($Fixed::from_bits(bits)))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <U256Bits as Arbitrary<'a>>::size_hint(depth)
+            }
+        }
+    };
+    ($Fixed:ident, $LeEqU:ident, $Inner:ident) => {
+        impl<'a, Frac: $LeEqU, const MIN: i128, const MAX: i128> Arbitrary<'a>
+            for Bounded<$Fixed<Frac>, MIN, MAX>
+        {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+                let min = Self::min_bound().to_bits();
+                let max = Self::max_bound().to_bits();
+                let bits = u.int_in_range(min..=max)?;
+                Ok(Self::new_clamped($Fixed::from_bits(bits)))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$Inner as Arbitrary<'a>>::size_hint(depth)
+            }
+        }
     };
 }
 
@@ -70,3 +120,68 @@ impl_trait! { FixedU16, LeEqU16, u16 }
 impl_trait! { FixedU32, LeEqU32, u32 }
 impl_trait! { FixedU64, LeEqU64, u64 }
 impl_trait! { FixedU128, LeEqU128, u128 }
+impl_trait! { FixedI256, LeEqU256, I256Bits }
+impl_trait! { FixedU256, LeEqU256, U256Bits }
+
+macro_rules! impl_bits_256 {
+    ($Bits:ident) => {
+        impl<'a> Arbitrary<'a> for $Bits {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+                let lo = <u128 as Arbitrary<'a>>::arbitrary(u)?;
+                let hi = <u128 as Arbitrary<'a>>::arbitrary(u)?;
+                Ok($Bits(U256 { lo, hi }))
+            }
+
+            #[inline]
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                let size = mem::size_of::<$Bits>();
+                (size, Some(size))
+            }
+        }
+    };
+}
+
+impl_bits_256! { I256Bits }
+impl_bits_256! { U256Bits }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::extra::U8;
+    use crate::types::{I8F8, U0F128};
+    use crate::{Bounded, FixedI256, U256Bits};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn bounded_in_range() {
+        type B = Bounded<I8F8, -0x0180, 0x0200>;
+        let mut bytes = [0u8; 1024];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(37).wrapping_add(i as u8 >> 3);
+        }
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let val = B::arbitrary(&mut u).unwrap().get();
+            assert!(B::min_bound() <= val && val <= B::max_bound(), "{val}");
+        }
+        // once the data is exhausted, the minimum is returned
+        assert_eq!(B::arbitrary(&mut u).unwrap().get(), B::min_bound());
+
+        type C = Bounded<U0F128, 1, { i128::MAX }>;
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let val = C::arbitrary(&mut u).unwrap().get();
+            assert!(C::min_bound() <= val && val <= C::max_bound(), "{val}");
+        }
+    }
+
+    #[test]
+    fn bounded_size_hint() {
+        type B = Bounded<I8F8, -0x0180, 0x0200>;
+        type C = Bounded<U0F128, 1, { i128::MAX }>;
+        type D = Bounded<FixedI256<U8>, -1, 1>;
+        assert_eq!(B::size_hint(0), <i16 as Arbitrary>::size_hint(0));
+        assert_eq!(C::size_hint(0), <u128 as Arbitrary>::size_hint(0));
+        assert_eq!(D::size_hint(0), <U256Bits as Arbitrary>::size_hint(0));
+    }
+}
@@ -13,10 +13,10 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128, Unwrapped, Wrapping,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits, Unwrapped, Wrapping,
 };
 use bytemuck::{Contiguous, Pod, TransparentWrapper, Zeroable};
 
@@ -64,3 +64,24 @@ unsafe_impl_traits! { FixedU16, LeEqU16, u16 }
 unsafe_impl_traits! { FixedU32, LeEqU32, u32 }
 unsafe_impl_traits! { FixedU64, LeEqU64, u64 }
 unsafe_impl_traits! { FixedU128, LeEqU128, u128 }
+
+macro_rules! unsafe_impl_bits_256 {
+    ($Bits:ident) => {
+        unsafe impl Zeroable for $Bits {}
+        unsafe impl Pod for $Bits {}
+        unsafe impl Contiguous for $Bits {
+            type Int = $Bits;
+            const MAX_VALUE: $Bits = $Bits::MAX;
+            const MIN_VALUE: $Bits = $Bits::MIN;
+        }
+    };
+}
+
+// SAFETY: the 256-bit bits types are repr(transparent) over U256, which is
+// repr(C) with two u128 fields and no padding, so every bit pattern is valid.
+// They are not primitive integers, but their Ord and arithmetic behave like
+// those of a primitive integer, so they can be used as Contiguous::Int.
+unsafe_impl_bits_256! { I256Bits }
+unsafe_impl_bits_256! { U256Bits }
+unsafe_impl_traits! { FixedI256, LeEqU256, I256Bits }
+unsafe_impl_traits! { FixedU256, LeEqU256, U256Bits }
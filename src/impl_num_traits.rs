@@ -14,27 +14,32 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::consts;
+use crate::int256;
+use crate::int256::U256;
+use crate::rounding::RoundingMode;
 use crate::traits::Fixed;
 use crate::types::extra::{
-    IsLessOrEqual, LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, True, U6, U7, U14, U15, U30, U31,
-    U62, U63, U126, U127,
+    IsLessOrEqual, LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256, True, U0, U6, U7, U14,
+    U15, U30, U31, U62, U63, U126, U127, U254, U255,
 };
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128, ParseFixedError,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, ParseFixedError, U256Bits,
 };
+use az::WrappingCast;
 use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::num::ParseIntError;
 use num_traits::bounds::Bounded;
-use num_traits::cast::{FromPrimitive, ToPrimitive};
+use num_traits::cast::{AsPrimitive, FromPrimitive, NumCast, ToPrimitive};
 use num_traits::float::FloatConst;
 use num_traits::identities::{ConstOne, ConstZero, One, Zero};
 
 use core::error::Error;
-use num_traits::Num;
 use num_traits::ops::bytes::{FromBytes, ToBytes};
 use num_traits::ops::checked::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
 };
+use num_traits::ops::euclid::{CheckedEuclid, Euclid};
 use num_traits::ops::inv::Inv;
 use num_traits::ops::mul_add::{MulAdd, MulAddAssign};
 use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
@@ -43,6 +48,7 @@ use num_traits::ops::wrapping::{
     WrappingAdd, WrappingMul, WrappingNeg, WrappingShl, WrappingShr, WrappingSub,
 };
 use num_traits::sign::{Signed, Unsigned};
+use num_traits::{Num, PrimInt, Saturating};
 
 /// An error which can be returned when parsing a fixed-point number
 /// with a given radix.
@@ -585,3 +591,487 @@ impl_traits! { FixedU16, LeEqU16, U15, Unsigned }
 impl_traits! { FixedU32, LeEqU32, U31, Unsigned }
 impl_traits! { FixedU64, LeEqU64, U63, Unsigned }
 impl_traits! { FixedU128, LeEqU128, U127, Unsigned }
+impl_traits! { FixedI256, LeEqU256, U254, Signed }
+impl_traits! { FixedU256, LeEqU256, U255, Unsigned }
+
+// The bits of the 256-bit fixed-point numbers are converted from and to other
+// numbers through the equivalent fixed-point type with no fractional bits.
+macro_rules! impl_bits_256 {
+    ($Bits:ident, $Fixed:ident) => {
+        impl Bounded for $Bits {
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl Zero for $Bits {
+            #[inline]
+            fn zero() -> Self {
+                $Bits(U256::ZERO)
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                int256::is_zero_u256(self.0)
+            }
+        }
+
+        impl ConstZero for $Bits {
+            const ZERO: Self = $Bits(U256::ZERO);
+        }
+
+        impl One for $Bits {
+            #[inline]
+            fn one() -> Self {
+                $Bits(U256 { lo: 1, hi: 0 })
+            }
+        }
+
+        impl ConstOne for $Bits {
+            const ONE: Self = $Bits(U256 { lo: 1, hi: 0 });
+        }
+
+        impl Num for $Bits {
+            type FromStrRadixErr = ParseIntError;
+
+            #[inline]
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ParseIntError> {
+                Self::from_str_radix(str, radix)
+            }
+        }
+
+        impl NumCast for $Bits {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                if let Some(i) = n.to_i128() {
+                    Self::try_from(i).ok()
+                } else if let Some(u) = n.to_u128() {
+                    Self::try_from(u).ok()
+                } else {
+                    n.to_f64().and_then(Self::from_f64)
+                }
+            }
+        }
+
+        impl Saturating for $Bits {
+            #[inline]
+            fn saturating_add(self, v: Self) -> Self {
+                self.saturating_add(v)
+            }
+            #[inline]
+            fn saturating_sub(self, v: Self) -> Self {
+                self.saturating_sub(v)
+            }
+        }
+
+        impl PrimInt for $Bits {
+            #[inline]
+            fn count_ones(self) -> u32 {
+                self.count_ones()
+            }
+            #[inline]
+            fn count_zeros(self) -> u32 {
+                self.count_zeros()
+            }
+            #[inline]
+            fn leading_ones(self) -> u32 {
+                self.leading_ones()
+            }
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.leading_zeros()
+            }
+            #[inline]
+            fn trailing_ones(self) -> u32 {
+                self.trailing_ones()
+            }
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                self.trailing_zeros()
+            }
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                self.rotate_left(n)
+            }
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                self.rotate_right(n)
+            }
+            #[inline]
+            fn signed_shl(self, n: u32) -> Self {
+                self << n
+            }
+            #[inline]
+            fn signed_shr(self, n: u32) -> Self {
+                $Bits((I256Bits(self.0) >> n).0)
+            }
+            #[inline]
+            fn unsigned_shl(self, n: u32) -> Self {
+                self << n
+            }
+            #[inline]
+            fn unsigned_shr(self, n: u32) -> Self {
+                $Bits((U256Bits(self.0) >> n).0)
+            }
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                self.swap_bytes()
+            }
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                self.reverse_bits()
+            }
+            #[inline]
+            fn from_be(x: Self) -> Self {
+                Self::from_be(x)
+            }
+            #[inline]
+            fn from_le(x: Self) -> Self {
+                Self::from_le(x)
+            }
+            #[inline]
+            fn to_be(self) -> Self {
+                self.to_be()
+            }
+            #[inline]
+            fn to_le(self) -> Self {
+                self.to_le()
+            }
+            #[inline]
+            fn pow(self, exp: u32) -> Self {
+                self.pow(exp)
+            }
+        }
+
+        impl CheckedAdd for $Bits {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                (*self).checked_add(*v)
+            }
+        }
+
+        impl CheckedSub for $Bits {
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                (*self).checked_sub(*v)
+            }
+        }
+
+        impl CheckedNeg for $Bits {
+            #[inline]
+            fn checked_neg(&self) -> Option<Self> {
+                (*self).checked_neg()
+            }
+        }
+
+        impl CheckedMul for $Bits {
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                (*self).checked_mul(*v)
+            }
+        }
+
+        impl CheckedDiv for $Bits {
+            #[inline]
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                (*self).checked_div(*v)
+            }
+        }
+
+        impl CheckedRem for $Bits {
+            #[inline]
+            fn checked_rem(&self, v: &Self) -> Option<Self> {
+                (*self).checked_rem(*v)
+            }
+        }
+
+        impl CheckedShl for $Bits {
+            #[inline]
+            fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                (*self).checked_shl(rhs)
+            }
+        }
+
+        impl CheckedShr for $Bits {
+            #[inline]
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                (*self).checked_shr(rhs)
+            }
+        }
+
+        impl SaturatingAdd for $Bits {
+            #[inline]
+            fn saturating_add(&self, v: &Self) -> Self {
+                (*self).saturating_add(*v)
+            }
+        }
+
+        impl SaturatingSub for $Bits {
+            #[inline]
+            fn saturating_sub(&self, v: &Self) -> Self {
+                (*self).saturating_sub(*v)
+            }
+        }
+
+        impl SaturatingMul for $Bits {
+            #[inline]
+            fn saturating_mul(&self, v: &Self) -> Self {
+                (*self).saturating_mul(*v)
+            }
+        }
+
+        impl WrappingAdd for $Bits {
+            #[inline]
+            fn wrapping_add(&self, v: &Self) -> Self {
+                (*self).wrapping_add(*v)
+            }
+        }
+
+        impl WrappingSub for $Bits {
+            #[inline]
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                (*self).wrapping_sub(*v)
+            }
+        }
+
+        impl WrappingNeg for $Bits {
+            #[inline]
+            fn wrapping_neg(&self) -> Self {
+                (*self).wrapping_neg()
+            }
+        }
+
+        impl WrappingMul for $Bits {
+            #[inline]
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                (*self).wrapping_mul(*v)
+            }
+        }
+
+        impl WrappingShl for $Bits {
+            #[inline]
+            fn wrapping_shl(&self, rhs: u32) -> Self {
+                (*self).wrapping_shl(rhs)
+            }
+        }
+
+        impl WrappingShr for $Bits {
+            #[inline]
+            fn wrapping_shr(&self, rhs: u32) -> Self {
+                (*self).wrapping_shr(rhs)
+            }
+        }
+
+        impl OverflowingAdd for $Bits {
+            #[inline]
+            fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+                (*self).overflowing_add(*v)
+            }
+        }
+
+        impl OverflowingSub for $Bits {
+            #[inline]
+            fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+                (*self).overflowing_sub(*v)
+            }
+        }
+
+        impl OverflowingMul for $Bits {
+            #[inline]
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                (*self).overflowing_mul(*v)
+            }
+        }
+
+        impl Euclid for $Bits {
+            #[inline]
+            fn div_euclid(&self, v: &Self) -> Self {
+                (*self).div_euclid(*v)
+            }
+            #[inline]
+            fn rem_euclid(&self, v: &Self) -> Self {
+                (*self).rem_euclid(*v)
+            }
+        }
+
+        impl CheckedEuclid for $Bits {
+            #[inline]
+            fn checked_div_euclid(&self, v: &Self) -> Option<Self> {
+                (*self).checked_div_euclid(*v)
+            }
+            #[inline]
+            fn checked_rem_euclid(&self, v: &Self) -> Option<Self> {
+                (*self).checked_rem_euclid(*v)
+            }
+        }
+
+        impl MulAdd for $Bits {
+            type Output = $Bits;
+            #[inline]
+            #[track_caller]
+            fn mul_add(self, a: $Bits, b: $Bits) -> $Bits {
+                self * a + b
+            }
+        }
+
+        impl MulAddAssign for $Bits {
+            #[inline]
+            #[track_caller]
+            fn mul_add_assign(&mut self, a: $Bits, b: $Bits) {
+                *self = *self * a + b;
+            }
+        }
+
+        impl ToPrimitive for $Bits {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_isize(&self) -> Option<isize> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_i8(&self) -> Option<i8> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_i16(&self) -> Option<i16> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_i32(&self) -> Option<i32> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_usize(&self) -> Option<usize> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_u8(&self) -> Option<u8> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_u16(&self) -> Option<u16> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_u32(&self) -> Option<u32> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                (*self).try_into().ok()
+            }
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                $Fixed::<U0>::from_bits(*self).checked_to_num()
+            }
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                $Fixed::<U0>::from_bits(*self).checked_to_num()
+            }
+        }
+
+        impl FromPrimitive for $Bits {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_isize(n: isize) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_i8(n: i8) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_i16(n: i16) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_i32(n: i32) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_usize(n: usize) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_u8(n: u8) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_u16(n: u16) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_u32(n: u32) -> Option<Self> {
+                n.try_into().ok()
+            }
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                n.try_into().ok()
+            }
+            // Like the primitive integers, the fractional part is discarded.
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                $Fixed::<U0>::checked_from_num_round(n, RoundingMode::TowardZero)
+                    .map($Fixed::to_bits)
+            }
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                $Fixed::<U0>::checked_from_num_round(n, RoundingMode::TowardZero)
+                    .map($Fixed::to_bits)
+            }
+        }
+
+        impl_bits_256! { @as $Bits, $Fixed; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+        impl AsPrimitive<f32> for $Bits {
+            #[inline]
+            fn as_(self) -> f32 {
+                $Fixed::<U0>::from_bits(self).to_num()
+            }
+        }
+
+        impl AsPrimitive<f64> for $Bits {
+            #[inline]
+            fn as_(self) -> f64 {
+                $Fixed::<U0>::from_bits(self).to_num()
+            }
+        }
+    };
+    (@as $Bits:ident, $Fixed:ident; $($Prim:ident)*) => { $(
+        impl AsPrimitive<$Prim> for $Bits {
+            #[inline]
+            fn as_(self) -> $Prim {
+                self.wrapping_cast()
+            }
+        }
+    )* };
+}
+
+impl_bits_256! { I256Bits, FixedI256 }
+impl_bits_256! { U256Bits, FixedU256 }
@@ -13,10 +13,18 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use az::WrappingCast;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use core::hint;
 use core::num::NonZero;
+use core::ops::{
+    Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr,
+};
 
+// repr(C) so that the public 256-bit bits types, which wrap U256, are Pod.
 #[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct U256 {
     pub lo: u128,
     pub hi: u128,
@@ -28,6 +36,15 @@ pub struct I256 {
     pub hi: i128,
 }
 
+impl U256 {
+    pub const BITS: u32 = 256;
+    pub const ZERO: U256 = U256 { lo: 0, hi: 0 };
+    pub const MAX: U256 = U256 {
+        lo: u128::MAX,
+        hi: u128::MAX,
+    };
+}
+
 #[inline]
 pub const fn u256_wrapping_as_i256(a: U256) -> I256 {
     I256 {
@@ -150,6 +167,21 @@ pub const fn wide_mul_u128(lhs: u128, rhs: u128) -> U256 {
     }
 }
 
+// Returns the upper half of the 512-bit product.
+#[inline]
+pub const fn mul_hi_u256(lhs: U256, rhs: U256) -> U256 {
+    let hh = wide_mul_u128(lhs.hi, rhs.hi);
+    let hl = wide_mul_u128(lhs.hi, rhs.lo);
+    let lh = wide_mul_u128(lhs.lo, rhs.hi);
+    let ll = wide_mul_u128(lhs.lo, rhs.lo);
+
+    let (col128, carry1) = ll.hi.overflowing_add(hl.lo);
+    let (_, carry2) = col128.overflowing_add(lh.lo);
+    let ans = wrapping_add_u256_u128(hh, hl.hi);
+    let ans = wrapping_add_u256_u128(ans, lh.hi);
+    wrapping_add_u256_u128(ans, carry1 as u128 + carry2 as u128)
+}
+
 #[inline]
 pub const fn wide_mul_i128(lhs: i128, rhs: i128) -> I256 {
     let (ll, lh) = i128_lo_hi(lhs);
@@ -339,6 +371,56 @@ pub const fn wrapping_sub_u256(a: U256, b: U256) -> U256 {
     U256 { lo, hi }
 }
 
+// sh must be < 256
+#[inline]
+pub const fn wrapping_shl_u256(a: U256, sh: u32) -> U256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: a.lo << sh,
+            hi: (a.hi << sh) | (a.lo >> (128 - sh)),
+        }
+    } else {
+        U256 {
+            lo: 0,
+            hi: a.lo << (sh - 128),
+        }
+    }
+}
+
+#[inline]
+pub const fn wrapping_add_i256(a: I256, b: I256) -> I256 {
+    let (lo, carry) = a.lo.overflowing_add(b.lo);
+    let hi = a.hi.wrapping_add(b.hi).wrapping_add(carry as i128);
+    I256 { lo, hi }
+}
+
+#[inline]
+pub const fn wrapping_sub_i256(a: I256, b: I256) -> I256 {
+    let (lo, borrow) = a.lo.overflowing_sub(b.lo);
+    let hi = a.hi.wrapping_sub(b.hi).wrapping_sub(borrow as i128);
+    I256 { lo, hi }
+}
+
+// sh must be < 256
+#[inline]
+pub const fn shr_i256(a: I256, sh: u32) -> I256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        I256 {
+            lo: (a.lo >> sh) | ((a.hi as u128) << (128 - sh)),
+            hi: a.hi >> sh,
+        }
+    } else {
+        I256 {
+            lo: (a.hi >> (sh - 128)) as u128,
+            hi: a.hi >> 127,
+        }
+    }
+}
+
 #[inline]
 pub const fn overflowing_shl_u256_into_u128(a: U256, sh: u32) -> (u128, bool) {
     if sh == 128 {
@@ -367,6 +449,546 @@ pub const fn overflowing_shl_i256_into_i128(a: I256, sh: u32) -> (i128, bool) {
     }
 }
 
+#[inline]
+pub const fn overflowing_sub_u256(a: U256, b: U256) -> (U256, bool) {
+    let (lo, borrow0) = a.lo.overflowing_sub(b.lo);
+    let (hi, borrow1_a) = a.hi.overflowing_sub(b.hi);
+    let (hi, borrow1_b) = hi.overflowing_sub(borrow0 as u128);
+    (U256 { lo, hi }, borrow1_a | borrow1_b)
+}
+
+// sh must be < 256
+#[inline]
+pub const fn wrapping_shr_u256(a: U256, sh: u32) -> U256 {
+    if sh == 0 {
+        a
+    } else if sh < 128 {
+        U256 {
+            lo: (a.lo >> sh) | (a.hi << (128 - sh)),
+            hi: a.hi >> sh,
+        }
+    } else {
+        U256 {
+            lo: a.hi >> (sh - 128),
+            hi: 0,
+        }
+    }
+}
+
+#[inline]
+pub const fn is_zero_u256(a: U256) -> bool {
+    a.lo == 0 && a.hi == 0
+}
+
+#[inline]
+pub const fn leading_zeros_u256(a: U256) -> u32 {
+    if a.hi == 0 {
+        128 + a.lo.leading_zeros()
+    } else {
+        a.hi.leading_zeros()
+    }
+}
+
+#[inline]
+pub const fn trailing_zeros_u256(a: U256) -> u32 {
+    if a.lo == 0 {
+        128 + a.hi.trailing_zeros()
+    } else {
+        a.lo.trailing_zeros()
+    }
+}
+
+#[inline]
+pub const fn overflowing_mul_add_u256_u128(a: U256, mul: u128, add: u128) -> (U256, bool) {
+    let lo = wide_mul_u128(a.lo, mul);
+    let hi = wide_mul_u128(a.hi, mul);
+    let (ans_hi, carry0) = lo.hi.overflowing_add(hi.lo);
+    let (ans_lo, carry1) = lo.lo.overflowing_add(add);
+    let (ans_hi, carry2) = ans_hi.overflowing_add(carry1 as u128);
+    (
+        U256 {
+            lo: ans_lo,
+            hi: ans_hi,
+        },
+        hi.hi != 0 || carry0 || carry2,
+    )
+}
+
+impl PartialEq for U256 {
+    #[inline]
+    fn eq(&self, other: &U256) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl Eq for U256 {}
+
+impl PartialOrd for U256 {
+    #[inline]
+    fn partial_cmp(&self, other: &U256) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    #[inline]
+    fn cmp(&self, other: &U256) -> Ordering {
+        self.hi.cmp(&other.hi).then(self.lo.cmp(&other.lo))
+    }
+}
+
+impl Hash for U256 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lo.hash(state);
+        self.hi.hash(state);
+    }
+}
+
+impl From<u8> for U256 {
+    #[inline]
+    fn from(src: u8) -> U256 {
+        U256 {
+            lo: src.into(),
+            hi: 0,
+        }
+    }
+}
+
+// wrapping
+impl Add for U256 {
+    type Output = U256;
+    #[inline]
+    fn add(self, rhs: U256) -> U256 {
+        overflowing_add_u256(self, rhs).0
+    }
+}
+
+// wrapping, rhs must be < 256
+impl Shl<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shl(self, rhs: u32) -> U256 {
+        wrapping_shl_u256(self, rhs)
+    }
+}
+
+// rhs must be < 256
+impl Shr<u32> for U256 {
+    type Output = U256;
+    #[inline]
+    fn shr(self, rhs: u32) -> U256 {
+        wrapping_shr_u256(self, rhs)
+    }
+}
+
+macro_rules! bitwise {
+    (impl $Imp:ident, $ImpAssign:ident { $method:ident, $method_assign:ident }) => {
+        impl $Imp for U256 {
+            type Output = U256;
+            #[inline]
+            fn $method(self, rhs: U256) -> U256 {
+                U256 {
+                    lo: self.lo.$method(rhs.lo),
+                    hi: self.hi.$method(rhs.hi),
+                }
+            }
+        }
+
+        impl $ImpAssign for U256 {
+            #[inline]
+            fn $method_assign(&mut self, rhs: U256) {
+                self.lo.$method_assign(rhs.lo);
+                self.hi.$method_assign(rhs.hi);
+            }
+        }
+    };
+}
+
+bitwise! { impl BitAnd, BitAndAssign { bitand, bitand_assign } }
+bitwise! { impl BitOr, BitOrAssign { bitor, bitor_assign } }
+bitwise! { impl BitXor, BitXorAssign { bitxor, bitxor_assign } }
+
+impl Not for U256 {
+    type Output = U256;
+    #[inline]
+    fn not(self) -> U256 {
+        U256 {
+            lo: !self.lo,
+            hi: !self.hi,
+        }
+    }
+}
+
+impl WrappingCast<u8> for U256 {
+    #[inline]
+    fn wrapping_cast(self) -> u8 {
+        self.lo as u8
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct U512 {
+    pub lo: U256,
+    pub hi: U256,
+}
+
+#[inline]
+pub const fn wide_mul_u256(lhs: U256, rhs: U256) -> U512 {
+    let ll = wide_mul_u128(lhs.lo, rhs.lo);
+    let lh = wide_mul_u128(lhs.lo, rhs.hi);
+    let hl = wide_mul_u128(lhs.hi, rhs.lo);
+    let hh = wide_mul_u128(lhs.hi, rhs.hi);
+
+    let (col128, carry1) = ll.hi.overflowing_add(lh.lo);
+    let (col128, carry2) = col128.overflowing_add(hl.lo);
+    let col256 = U256 {
+        lo: carry1 as u128 + carry2 as u128,
+        hi: 0,
+    };
+    let col256 = wrapping_add_u256_u128(col256, lh.hi);
+    let col256 = wrapping_add_u256_u128(col256, hl.hi);
+    // Since lhs * rhs fits in 512 bits, this sum will never overflow.
+    let (hi, _) = overflowing_add_u256(hh, col256);
+    U512 {
+        lo: U256 {
+            lo: ll.lo,
+            hi: col128,
+        },
+        hi,
+    }
+}
+
+// sh must be < 512
+#[inline]
+pub const fn wrapping_shr_u512(a: U512, sh: u32) -> U512 {
+    if sh == 0 {
+        a
+    } else if sh < 256 {
+        let lo = wrapping_shr_u256(a.lo, sh);
+        let carry = wrapping_shl_u256(a.hi, 256 - sh);
+        U512 {
+            lo: U256 {
+                lo: lo.lo | carry.lo,
+                hi: lo.hi | carry.hi,
+            },
+            hi: wrapping_shr_u256(a.hi, sh),
+        }
+    } else {
+        U512 {
+            lo: wrapping_shr_u256(a.hi, sh - 256),
+            hi: U256::ZERO,
+        }
+    }
+}
+
+// sh must be < 512
+#[inline]
+pub const fn wrapping_shl_u512(a: U512, sh: u32) -> U512 {
+    if sh == 0 {
+        a
+    } else if sh < 256 {
+        let hi = wrapping_shl_u256(a.hi, sh);
+        let carry = wrapping_shr_u256(a.lo, 256 - sh);
+        U512 {
+            lo: wrapping_shl_u256(a.lo, sh),
+            hi: U256 {
+                lo: hi.lo | carry.lo,
+                hi: hi.hi | carry.hi,
+            },
+        }
+    } else {
+        U512 {
+            lo: U256::ZERO,
+            hi: wrapping_shl_u256(a.lo, sh - 256),
+        }
+    }
+}
+
+#[inline]
+pub const fn is_zero_u512(a: U512) -> bool {
+    is_zero_u256(a.lo) && is_zero_u256(a.hi)
+}
+
+#[inline]
+pub const fn eq_u512(a: U512, b: U512) -> bool {
+    a.lo.lo == b.lo.lo && a.lo.hi == b.lo.hi && a.hi.lo == b.hi.lo && a.hi.hi == b.hi.hi
+}
+
+#[inline]
+pub const fn overflowing_add_u512(a: U512, b: U512) -> (U512, bool) {
+    let (lo, carry0) = overflowing_add_u256(a.lo, b.lo);
+    let (hi, carry1_a) = overflowing_add_u256(a.hi, b.hi);
+    let (hi, carry1_b) = overflowing_add_u256(
+        hi,
+        U256 {
+            lo: carry0 as u128,
+            hi: 0,
+        },
+    );
+    (U512 { lo, hi }, carry1_a | carry1_b)
+}
+
+#[inline]
+pub const fn wrapping_neg_u512(a: U512) -> U512 {
+    let lo = wrapping_neg_u256(a.lo);
+    let borrow = !is_zero_u256(a.lo);
+    let hi = wrapping_sub_u256(
+        wrapping_neg_u256(a.hi),
+        U256 {
+            lo: borrow as u128,
+            hi: 0,
+        },
+    );
+    U512 { lo, hi }
+}
+
+#[inline]
+pub const fn inc_u512(a: U512) -> U512 {
+    let (lo, carry) = overflowing_add_u256(a.lo, U256 { lo: 1, hi: 0 });
+    let hi = wrapping_add_u256_u128(a.hi, carry as u128);
+    U512 { lo, hi }
+}
+
+// Returns whether lhs − rhs is negative, and its magnitude; lhs and rhs are
+// two's complement if signed.
+#[inline]
+pub const fn sub_neg_abs_u256(lhs: U256, rhs: U256, signed: bool) -> (bool, U256) {
+    let flip = if signed { 1 << 127 } else { 0 };
+    let (lhs_hi, rhs_hi) = (lhs.hi ^ flip, rhs.hi ^ flip);
+    if lhs_hi < rhs_hi || (lhs_hi == rhs_hi && lhs.lo < rhs.lo) {
+        (true, wrapping_sub_u256(rhs, lhs))
+    } else {
+        (false, wrapping_sub_u256(lhs, rhs))
+    }
+}
+
+// Shifts abs right by sh, or left by −sh if sh is negative, and returns
+// whether any nonzero bits were shifted out on the left. If up is true and
+// any nonzero bits are shifted out on the right, the magnitude is
+// incremented, so that the result is rounded up instead of truncated.
+//
+// −511 <= sh
+#[inline]
+pub const fn shift_abs_u512(abs: U512, sh: i32, up: bool) -> (U512, bool) {
+    if sh < 0 {
+        let sh = sh.unsigned_abs();
+        let ans = wrapping_shl_u512(abs, sh);
+        let back = wrapping_shr_u512(ans, sh);
+        return (ans, !eq_u512(back, abs));
+    }
+    let sh = sh as u32;
+    let (ans, exact) = if sh >= 512 {
+        (
+            U512 {
+                lo: U256::ZERO,
+                hi: U256::ZERO,
+            },
+            is_zero_u512(abs),
+        )
+    } else {
+        let ans = wrapping_shr_u512(abs, sh);
+        (ans, eq_u512(wrapping_shl_u512(ans, sh), abs))
+    };
+    if up && !exact {
+        (inc_u512(ans), false)
+    } else {
+        (ans, false)
+    }
+}
+
+// Returns add ± abs wrapped, and whether it overflowed.
+#[inline]
+pub const fn overflowing_add_neg_abs_u256(neg: bool, abs: U512, add: U256) -> (U256, bool) {
+    let (ans, overflow) = if neg {
+        overflowing_sub_u256(add, abs.lo)
+    } else {
+        overflowing_add_u256(add, abs.lo)
+    };
+    (ans, overflow || !is_zero_u256(abs.hi))
+}
+
+// Returns add ± abs wrapped, and whether it overflowed; add is two's
+// complement.
+#[inline]
+pub const fn overflowing_add_neg_abs_i256(neg: bool, abs: U512, add: U256) -> (U256, bool) {
+    // abs >= 2^511 would not fit as a 512-bit two's complement number, and
+    // the result would overflow 256 bits anyway
+    let too_large = abs.hi.hi >> 127 != 0;
+    let val = if neg { wrapping_neg_u512(abs) } else { abs };
+    let add_ext = if add.hi >> 127 != 0 {
+        U256::MAX
+    } else {
+        U256::ZERO
+    };
+    let (sum, _) = overflowing_add_u512(
+        val,
+        U512 {
+            lo: add,
+            hi: add_ext,
+        },
+    );
+    let fits = if sum.lo.hi >> 127 != 0 {
+        sum.hi.lo == u128::MAX && sum.hi.hi == u128::MAX
+    } else {
+        is_zero_u256(sum.hi)
+    };
+    (sum.lo, too_large || !fits)
+}
+
+const fn u256_to_limbs(a: U256) -> [u64; 4] {
+    let (l0, l1) = u128_lo_hi(a.lo);
+    let (l2, l3) = u128_lo_hi(a.hi);
+    [l0, l1, l2, l3]
+}
+
+const fn limbs_to_u256(l0: u64, l1: u64, l2: u64, l3: u64) -> U256 {
+    U256 {
+        lo: l0 as u128 | ((l1 as u128) << 64),
+        hi: l2 as u128 | ((l3 as u128) << 64),
+    }
+}
+
+// Long division using Knuth's algorithm D on 64-bit limbs; d must not be zero.
+pub const fn div_rem_u512_u256(n: U512, d: U256) -> (U512, U256) {
+    let d = u256_to_limbs(d);
+    let (n_lo, n_hi) = (u256_to_limbs(n.lo), u256_to_limbs(n.hi));
+    let n = [
+        n_lo[0], n_lo[1], n_lo[2], n_lo[3], n_hi[0], n_hi[1], n_hi[2], n_hi[3],
+    ];
+    let mut q = [0u64; 8];
+
+    let mut dlen = 4;
+    while d[dlen - 1] == 0 {
+        dlen -= 1;
+    }
+
+    if dlen == 1 {
+        let d0 = d[0] as u128;
+        let mut r = 0u128;
+        let mut i = 8;
+        while i > 0 {
+            i -= 1;
+            let cur = (r << 64) | n[i] as u128;
+            q[i] = (cur / d0) as u64;
+            r = cur % d0;
+        }
+        let q = U512 {
+            lo: limbs_to_u256(q[0], q[1], q[2], q[3]),
+            hi: limbs_to_u256(q[4], q[5], q[6], q[7]),
+        };
+        return (q, U256 { lo: r, hi: 0 });
+    }
+
+    // normalize so that the most significant limb of the divisor has its msb set
+    let s = d[dlen - 1].leading_zeros();
+    let mut dn = [0u64; 4];
+    let mut un = [0u64; 9];
+    let mut i = 0;
+    while i < 4 {
+        let prev = if i == 0 { 0 } else { d[i - 1] };
+        dn[i] = if s == 0 {
+            d[i]
+        } else {
+            (d[i] << s) | (prev >> (64 - s))
+        };
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 9 {
+        let cur = if i == 8 { 0 } else { n[i] };
+        let prev = if i == 0 { 0 } else { n[i - 1] };
+        un[i] = if s == 0 {
+            cur
+        } else {
+            (cur << s) | (prev >> (64 - s))
+        };
+        i += 1;
+    }
+
+    let dtop = dn[dlen - 1] as u128;
+    let dnext = dn[dlen - 2] as u128;
+    let mut j = 9 - dlen;
+    while j > 0 {
+        j -= 1;
+
+        // estimate quotient digit
+        let num = ((un[j + dlen] as u128) << 64) | un[j + dlen - 1] as u128;
+        let mut qhat = num / dtop;
+        let mut rhat = num % dtop;
+        while qhat >> 64 != 0 || qhat * dnext > ((rhat << 64) | un[j + dlen - 2] as u128) {
+            qhat -= 1;
+            rhat += dtop;
+            if rhat >> 64 != 0 {
+                break;
+            }
+        }
+
+        // multiply and subtract
+        let mut k: i128 = 0;
+        let mut i = 0;
+        while i < dlen {
+            let p = qhat * dn[i] as u128;
+            let t = un[i + j] as i128 - k - (p as u64) as i128;
+            un[i + j] = t as u64;
+            k = (p >> 64) as i128 - (t >> 64);
+            i += 1;
+        }
+        let t = un[j + dlen] as i128 - k;
+        un[j + dlen] = t as u64;
+
+        // add back if we subtracted too much
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u128;
+            let mut i = 0;
+            while i < dlen {
+                let sum = un[i + j] as u128 + dn[i] as u128 + carry;
+                un[i + j] = sum as u64;
+                carry = sum >> 64;
+                i += 1;
+            }
+            un[j + dlen] = un[j + dlen].wrapping_add(carry as u64);
+        }
+        q[j] = qhat as u64;
+    }
+
+    // unnormalize remainder
+    let mut r = [0u64; 4];
+    let mut i = 0;
+    while i < dlen {
+        r[i] = if s == 0 {
+            un[i]
+        } else {
+            (un[i] >> s) | (un[i + 1] << (64 - s))
+        };
+        i += 1;
+    }
+    let q = U512 {
+        lo: limbs_to_u256(q[0], q[1], q[2], q[3]),
+        hi: limbs_to_u256(q[4], q[5], q[6], q[7]),
+    };
+    (q, limbs_to_u256(r[0], r[1], r[2], r[3]))
+}
+
+// d must not be zero.
+#[inline]
+pub const fn div_rem_u256(n: U256, d: U256) -> (U256, U256) {
+    if d.hi == 0 {
+        let Some(d) = NonZero::<u128>::new(d.lo) else {
+            panic!("division by zero");
+        };
+        let (q, r) = div_rem_u256_u128(n, d);
+        return (q, U256 { lo: r, hi: 0 });
+    }
+    let n = U512 {
+        lo: n,
+        hi: U256::ZERO,
+    };
+    let (q, r) = div_rem_u512_u256(n, d);
+    (q.lo, r)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
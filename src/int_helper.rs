@@ -94,7 +94,7 @@ macro_rules! make_helper {
             make_helper_common! { $u }
 
             #[inline]
-            pub fn neg_abs(val: $u) -> (bool, $u) {
+            pub const fn neg_abs(val: $u) -> (bool, $u) {
                 (false, val)
             }
 
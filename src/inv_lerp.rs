@@ -14,7 +14,7 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::int256;
-use crate::int256::U256;
+use crate::int256::{U256, U512};
 use core::num::NonZeroU128;
 
 macro_rules! make_inv_lerp {
@@ -229,6 +229,46 @@ pub const fn u128(v: u128, start: u128, end: u128, frac_bits: u32) -> (u128, boo
     }
 }
 
+// v, start and end are two's complement
+pub const fn i256(v: U256, start: U256, end: U256, frac_bits: u32) -> (U256, bool) {
+    let (diff_neg, diff_abs) = int256::sub_neg_abs_u256(v, start, true);
+    let (range_neg, range_abs) = int256::sub_neg_abs_u256(end, start, true);
+    if int256::is_zero_u256(range_abs) {
+        panic!("empty range");
+    }
+    let neg = diff_neg != range_neg;
+    let quot = div_round_floor(diff_abs, range_abs, frac_bits, neg);
+    int256::overflowing_add_neg_abs_i256(neg, quot, U256::ZERO)
+}
+
+pub const fn u256(v: U256, start: U256, end: U256, frac_bits: u32) -> (U256, bool) {
+    let (diff_neg, diff_abs) = int256::sub_neg_abs_u256(v, start, false);
+    let (range_neg, range_abs) = int256::sub_neg_abs_u256(end, start, false);
+    if int256::is_zero_u256(range_abs) {
+        panic!("empty range");
+    }
+    let neg = diff_neg != range_neg;
+    let quot = div_round_floor(diff_abs, range_abs, frac_bits, neg);
+    int256::overflowing_add_neg_abs_u256(neg, quot, U256::ZERO)
+}
+
+// Returns the magnitude of (diff_abs << frac_bits) / range_abs, where the
+// quotient is rounded towards −∞, that is its magnitude is rounded up if it is
+// negative.
+const fn div_round_floor(diff_abs: U256, range_abs: U256, frac_bits: u32, neg: bool) -> U512 {
+    let wide_diff_abs = U512 {
+        lo: diff_abs,
+        hi: U256::ZERO,
+    };
+    let wide_diff_abs = int256::wrapping_shl_u512(wide_diff_abs, frac_bits);
+    let (quot, rem) = int256::div_rem_u512_u256(wide_diff_abs, range_abs);
+    if neg && !int256::is_zero_u256(rem) {
+        int256::inc_u512(quot)
+    } else {
+        quot
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::inv_lerp;
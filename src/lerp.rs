@@ -14,6 +14,7 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::int256;
+use crate::int256::U256;
 
 macro_rules! make_lerp {
     ($i:ident, $u:ident, $ii:ident, $uu:ident, $uu1:expr) => {
@@ -151,6 +152,25 @@ pub const fn u128(r: u128, start: u128, end: u128, frac_bits: u32) -> (u128, boo
     }
 }
 
+// r, start and end are two's complement
+pub const fn i256(r: U256, start: U256, end: U256, frac_bits: u32) -> (U256, bool) {
+    let (r_neg, r_abs) = int256::sub_neg_abs_u256(r, U256::ZERO, true);
+    let (range_neg, range_abs) = int256::sub_neg_abs_u256(end, start, true);
+    let wide_abs = int256::wide_mul_u256(r_abs, range_abs);
+    let wide_neg = r_neg != range_neg;
+    // a negative product is rounded towards −∞, so its magnitude is rounded up
+    let (shifted, _) = int256::shift_abs_u512(wide_abs, frac_bits as i32, wide_neg);
+    int256::overflowing_add_neg_abs_i256(wide_neg, shifted, start)
+}
+
+pub const fn u256(r: U256, start: U256, end: U256, frac_bits: u32) -> (U256, bool) {
+    let (range_neg, range_abs) = int256::sub_neg_abs_u256(end, start, false);
+    let wide_abs = int256::wide_mul_u256(r, range_abs);
+    // a negative product is rounded towards −∞, so its magnitude is rounded up
+    let (shifted, _) = int256::shift_abs_u512(wide_abs, frac_bits as i32, range_neg);
+    int256::overflowing_add_neg_abs_u256(range_neg, shifted, start)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::lerp;
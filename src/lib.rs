@@ -23,6 +23,11 @@ The [*fixed* crate] provides fixed-point numbers.
   * [`FixedI32`] and [`FixedU32`] are 32-bit fixed-point numbers.
   * [`FixedI64`] and [`FixedU64`] are 64-bit fixed-point numbers.
   * [`FixedI128`] and [`FixedU128`] are 128-bit fixed-point numbers.
+  * [`FixedI256`] and [`FixedU256`] are 256-bit fixed-point numbers. They
+    provide arithmetic, conversions, parsing and formatting, but not all the
+    methods of the narrower fixed-point numbers.
+  * [`DynFixed`] is a fixed-point number of up to 128 bits with a
+    [`DynFormat`] chosen at run time instead of at compile time.
 
 An <i>n</i>-bit fixed-point number has <i>f</i>&nbsp;=&nbsp;`Frac` fractional
 bits where 0&nbsp;≤&nbsp;<i>f</i>&nbsp;≤&nbsp;<i>n</i>, and
@@ -58,12 +63,19 @@ cannot be represented exactly, as it is 1/10<sup>3</sup>. It is binary fractions
 like 1/2<sup>4</sup> (0.0625) that can be represented exactly, provided there
 are enough fractional bits.
 
-This crate does *not* provide general analytic functions.
+This crate provides only a few analytic functions, for example the square root
+`sqrt` and its reciprocal `rsqrt`, the roots `cbrt` and `nth_root`, the powers
+`powi` and `powf`, the exponentials `exp2`, `exp` and `exp_m1`, the logarithms
+`log2`, `ln` and `log10`, and the trigonometric functions `sin`, `cos`,
+`sin_cos`, `asin`, `acos`, `atan` and `atan2` and hyperbolic functions `sinh`,
+`cosh` and `tanh` for signed fixed-point numbers. The arguments of `sin`, `cos`
+and `sin_cos` are reduced by multiples of π/2, using `consts::FRAC_2_PI` and
+`consts::FRAC_PI_2`, not by multiples of π or 2π. It does *not* provide general
+analytic functions.
 
-  * No algebraic functions are provided, for example no `pow`.
-  * No trigonometric functions are provided, for example no `sin` or `cos`.
-  * No other transcendental functions are provided, for example no `log` or
-    `exp`.
+  * No other algebraic functions are provided, for example no reciprocal
+    cube root.
+  * No other transcendental functions are provided, for example no `asinh`.
 
 These functions are not provided because different implementations can have
 different trade-offs, for example trading some correctness for speed.
@@ -356,18 +368,24 @@ mod macros;
 mod arith;
 #[cfg(feature = "borsh")]
 mod borshize;
+mod bounded;
 mod bytes;
 mod cast;
+mod checked;
 mod cmp;
 mod cmp_fixed;
 pub mod consts;
 mod convert;
 mod debug_hex;
 mod display;
+mod dyn_fixed;
+mod exp;
 pub mod f128;
+mod fixed256;
 mod float_helper;
 mod from_str;
 mod helpers;
+mod hex_float;
 mod hypot;
 #[cfg(feature = "arbitrary")]
 mod impl_arbitrary;
@@ -380,24 +398,43 @@ mod inv_lerp;
 mod lerp;
 mod log;
 mod log10;
+mod overflowing;
+mod pow;
 mod prim_traits;
+mod ratio;
+mod rounding;
 mod saturating;
 #[cfg(feature = "serde")]
 mod serdeize;
 mod sqrt;
 pub mod traits;
 mod traits_bits;
+mod trig;
 pub mod types;
 mod unwrapped;
 mod wrapping;
 
+pub use crate::bounded::Bounded;
+pub use crate::checked::Checked;
+pub use crate::dyn_fixed::{DynFixed, DynFormat};
+use crate::exp::{ExpBase, Hyperbolic};
 pub use crate::f128::private::F128;
-pub use crate::from_str::ParseFixedError;
+pub use crate::fixed256::{
+    FixedI256, FixedU256, I256Bits, NonZeroI256Bits, NonZeroU256Bits, U256Bits,
+};
+use crate::fixed256::{
+    checked_convert_random, checked_convert_round, overflowing_convert_random,
+    overflowing_convert_round, saturating_convert_random, saturating_convert_round,
+};
+pub use crate::from_str::{ParseErrorKind, ParseFixedError, ParseOptions};
+pub use crate::hex_float::HexFloat;
 #[cfg(feature = "num-traits")]
 pub use crate::impl_num_traits::RadixParseFixedError;
-use crate::log::Base;
+use crate::log::{Base, RealBase};
+pub use crate::overflowing::{Overflowing, OverflowingSaturating};
+pub use crate::rounding::RoundingMode;
 pub use crate::saturating::Saturating;
-use crate::traits::{FromFixed, ToFixed};
+use crate::traits::{FromFixed, LosslessTryFrom, MulExact, RngLike, ToFixed};
 use crate::types::extra::{
     Diff, IsLessOrEqual, LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, Sum, True, U0, U4, U5, U6,
     U7, U8, U12, U13, U14, U15, U16, U28, U29, U30, U31, U32, U60, U61, U62, U63, U64, U124, U125,
@@ -454,6 +491,8 @@ mod macros_no_frac;
 mod macros_frac;
 #[macro_use]
 mod macros_const;
+#[macro_use]
+mod macros_trig;
 
 macro_rules! fixed {
     (
@@ -607,6 +646,15 @@ assert_eq!(two_point_75.to_string(), \"2.8\");
             [nm4 ..= n] = [$nm4, $nm3, $nm2, $nm1, $n],
             [LeEqUC0 ..= LeEqUC3] = [$LeEqUC0, $LeEqUC1, $LeEqUC2, $LeEqUC3],
         }
+        if_signed! {
+            $Signedness;
+            fixed_trig! {
+                {Self, Inner} = {$Self, $Inner},
+                LeEqU = $LeEqU,
+                {nm4, nm3, nm1, n} = {$nm4, $nm3, $nm1, $n},
+                UAngleFrac = $LeEqUC2,
+            }
+        }
     };
 }
 
@@ -1632,4 +1680,32 @@ mod tests {
         assert_eq!(t.wide_mul(u), u.wide_mul(t));
         assert_eq!(s.wide_mul(v), v.wide_mul(s));
     }
+
+    #[test]
+    fn const_frac() {
+        use crate::types::extra::{Const, U, Unsigned};
+        use crate::types::{FixedI8C, FixedU256C, I8F0, I8F8, U8F8};
+
+        macro_rules! check_all {
+            ($($n:literal)*) => {
+                $(assert_eq!(U::<$n>::U32, $n);)*
+            };
+        }
+        check_all! { 0 1 7 8 31 32 63 64 127 128 255 256 }
+
+        let a: I8F8 = crate::types::FixedI16C::<8>::from_num(-1.5);
+        assert_eq!(a, -1.5);
+        let b: crate::types::FixedU16C<8> = U8F8::MAX;
+        assert_eq!(b, U8F8::MAX);
+        assert_eq!(FixedI8C::<0>::MIN, I8F0::MIN);
+        assert_eq!(FixedU256C::<256>::FRAC_NBITS, 256);
+
+        const {
+            assert!(Const::<8>::is_frac_nbits_of::<I8F8>());
+            assert!(Const::<8>::is_int_nbits_of::<I8F8>());
+        }
+        assert!(Const::<0>::is_frac_nbits_of::<FixedI8C<0>>());
+        assert!(!Const::<7>::is_frac_nbits_of::<U8F8>());
+        assert!(!Const::<9>::is_int_nbits_of::<U8F8>());
+    }
 }
@@ -13,6 +13,11 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::consts;
+use crate::int256;
+use crate::int256::I256;
+use core::num::NonZero;
+
 #[derive(Clone, Copy, Debug)]
 pub struct Base(u32);
 
@@ -140,10 +145,138 @@ pub mod frac_part {
     impl_frac_part! { u128 }
 }
 
+// The base-2 logarithm is computed by normalizing the value into the range
+// 1 ≤ m < 2, which gives the integer part of the logarithm. Then the
+// fractional bits are found one by one by squaring m: if m² ≥ 2, the next bit
+// is 1 and m is set to m²/2, otherwise the next bit is 0 and m is set to m².
+//
+// Since m is truncated after every squaring, the computed bits are never
+// larger than the exact logarithm, but the error is very small as the 127
+// fractional bits of m are much more than the bits required.
+//
+// The natural and base-10 logarithms are found by multiplying the base-2
+// logarithm by ln 2 and log₁₀ 2 respectively.
+
+#[derive(Clone, Copy, Debug)]
+pub enum RealBase {
+    Two,
+    E,
+    Ten,
+}
+
+// Returns log₂ val×2^−frac_nbits with 128 fractional bits, of which only the
+// first iters are computed.
+const fn log2_wide(val: NonZero<u128>, frac_nbits: u32, iters: u32) -> I256 {
+    const ONE: u128 = 1 << 127;
+
+    let lz = val.leading_zeros();
+    let int_part = 127 - lz as i32 - frac_nbits as i32;
+    // 1 ≤ m < 2 with 127 fractional bits
+    let mut m = val.get() << lz;
+    let mut frac = 0u128;
+    let mut i = 0;
+    while i < iters && m != ONE {
+        // 1 ≤ m² < 4 with 254 fractional bits
+        let square = int256::wide_mul_u128(m, m);
+        if square.hi & ONE != 0 {
+            m = square.hi;
+            frac |= ONE >> i;
+        } else {
+            m = (square.hi << 1) | (square.lo >> 127);
+        }
+        i += 1;
+    }
+    I256 {
+        lo: frac,
+        hi: int_part as i128,
+    }
+}
+
+// Returns log×factor, where factor has 128 fractional bits, rounded down.
+const fn mul_factor(log: I256, factor: u128) -> I256 {
+    let int_prod = int256::wide_mul_u128(log.hi.unsigned_abs(), factor);
+    let int_prod = if log.hi < 0 {
+        int256::wrapping_neg_u256(int_prod)
+    } else {
+        int_prod
+    };
+    let frac_prod = int256::wide_mul_u128(log.lo, factor).hi;
+    int256::u256_wrapping_as_i256(int256::wrapping_add_u256_u128(int_prod, frac_prod))
+}
+
+// Returns log val×2^−frac_nbits rounded to frac_nbits fractional bits, together
+// with an overflow flag which is set if the result does not fit in i128.
+const fn real_log(val: NonZero<u128>, frac_nbits: u32, base: RealBase) -> (i128, bool) {
+    // use a few guard bits in case the result needs to be scaled
+    let iters = if frac_nbits < 120 {
+        frac_nbits + 8
+    } else {
+        128
+    };
+    let log2 = log2_wide(val, frac_nbits, iters);
+    let log = match base {
+        RealBase::Two => log2,
+        RealBase::E => mul_factor(log2, consts::LN_2.to_bits()),
+        RealBase::Ten => mul_factor(log2, consts::LOG10_2.to_bits()),
+    };
+    // round to nearest
+    let log = if frac_nbits < 128 {
+        int256::wrapping_add_i256(
+            log,
+            I256 {
+                lo: 1 << (127 - frac_nbits),
+                hi: 0,
+            },
+        )
+    } else {
+        log
+    };
+    int256::overflowing_shl_i256_into_i128(log, 128 - frac_nbits)
+}
+
+macro_rules! impl_real {
+    ($i:ident) => {
+        pub const fn $i(val: $i, frac_nbits: u32, base: RealBase) -> Option<($i, bool)> {
+            let non_zero = match NonZero::<u128>::new(val as u128) {
+                Some(s) if val > 0 => s,
+                _ => return None,
+            };
+            let (wide, overflow) = log::real_log(non_zero, frac_nbits, base);
+            let wrapped = wide as $i;
+            // the comparison with MIN is required for u128
+            let fits = wide >= $i::MIN as i128 && wrapped as i128 == wide;
+            Some((wrapped, overflow || !fits))
+        }
+    };
+}
+
+// Returns Some((log, overflow)), or None if the value is ≤ 0.
+pub mod real {
+    use crate::log;
+    use crate::log::RealBase;
+    use core::num::NonZero;
+
+    impl_real! { i8 }
+    impl_real! { i16 }
+    impl_real! { i32 }
+    impl_real! { i64 }
+    impl_real! { i128 }
+    impl_real! { u8 }
+    impl_real! { u16 }
+    impl_real! { u32 }
+    impl_real! { u64 }
+    impl_real! { u128 }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::consts;
     use crate::log;
     use crate::log::Base;
+    use crate::types::extra;
+    use crate::types::extra::{U0, U4, U7, U8, U12, U13, U15, U16, U28, U31, U32, U40, U61, U127};
+    use crate::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128};
+    use crate::{FixedU8, FixedU16, FixedU32, FixedU64, FixedU128};
     use core::num::NonZero;
 
     // these tests require the maximum table sizes
@@ -175,4 +308,92 @@ mod tests {
             -128
         );
     }
+
+    macro_rules! check_real_f64 {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            let f = val.to_num::<f64>();
+            let delta = Fix::DELTA.to_num::<f64>();
+            let (min, max) = (Fix::MIN.to_num::<f64>(), Fix::MAX.to_num::<f64>());
+            if f <= 0.0 {
+                assert_eq!(val.checked_log2(), None, "{val}");
+                assert_eq!(val.checked_ln(), None, "{val}");
+                assert_eq!(val.checked_log10(), None, "{val}");
+            } else {
+                for (exact, checked) in [
+                    (f.log2(), val.checked_log2()),
+                    (f.ln(), val.checked_ln()),
+                    (f.log10(), val.checked_log10()),
+                ] {
+                    match checked {
+                        Some(log) => {
+                            let err = (log.to_num::<f64>() - exact).abs();
+                            assert!(err < delta, "{val} {exact} {log}");
+                        }
+                        None => assert!(exact < min - delta / 2.0 || exact > max, "{val}"),
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn real_exhaustive_8_16() {
+        for bits in i8::MIN..=i8::MAX {
+            check_real_f64!(FixedI8<U0>, bits);
+            check_real_f64!(FixedI8<U4>, bits);
+            check_real_f64!(FixedI8<U7>, bits);
+            check_real_f64!(FixedI8<U8>, bits);
+            check_real_f64!(FixedU8<U0>, bits as u8);
+            check_real_f64!(FixedU8<U4>, bits as u8);
+            check_real_f64!(FixedU8<U8>, bits as u8);
+        }
+        for bits in i16::MIN..=i16::MAX {
+            check_real_f64!(FixedI16<U0>, bits);
+            check_real_f64!(FixedI16<U8>, bits);
+            check_real_f64!(FixedI16<U13>, bits);
+            check_real_f64!(FixedU16<U12>, bits as u16);
+            check_real_f64!(FixedU16<U15>, bits as u16);
+        }
+    }
+
+    #[test]
+    fn real_sample_32_64() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let bits = bits >> (i & 15);
+            check_real_f64!(FixedI32<U16>, bits);
+            check_real_f64!(FixedI32<U28>, bits);
+            check_real_f64!(FixedU32<U31>, bits as u32);
+            check_real_f64!(FixedI64<U32>, i64::from(bits) << 16);
+            check_real_f64!(FixedU64<U40>, (i64::from(bits) << 24) as u64);
+        }
+    }
+
+    #[test]
+    fn real_consts() {
+        type U126 = FixedU128<extra::U126>;
+        let two = U126::from_num(2);
+        let delta = U126::DELTA;
+        assert!(two.ln().dist(U126::from_num(consts::LN_2)) <= delta);
+        assert!(two.log10().dist(U126::from_num(consts::LOG10_2)) <= delta);
+        assert!(U126::E.log2().dist(U126::from_num(consts::LOG2_E)) <= 2 * delta);
+        assert_eq!(two.log2(), 1);
+        assert_eq!(U126::ONE.ln(), 0);
+
+        type I64 = FixedI64<U61>;
+        let half = I64::from_num(0.5);
+        let delta = I64::DELTA;
+        assert!(half.ln().dist(-I64::from_num(consts::LN_2)) <= delta);
+        assert!(half.log10().dist(-I64::from_num(consts::LOG10_2)) <= delta);
+        assert!(I64::E.log2().dist(I64::from_num(consts::LOG2_E)) <= delta);
+        assert_eq!(I64::from_num(0.125).log2(), -3);
+
+        type I128 = FixedI128<U127>;
+        let err = I128::from_num(0.5).ln() + I128::from_num(consts::LN_2);
+        assert!(err.abs() <= 2 * I128::DELTA);
+        assert_eq!(I128::from_num(0.25).checked_log2(), None);
+        assert_eq!(I128::MIN.checked_log2(), None);
+    }
 }
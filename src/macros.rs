@@ -68,3 +68,61 @@ macro_rules! comment {
         }
     };
 }
+
+// Overrides the FromFixed rounding methods, which by default ignore the
+// rounding mode, with conversions through fixed256.
+macro_rules! from_fixed_round {
+    () => {
+        /// Converts from a fixed-point number, rounding as specified by `mode`.
+        ///
+        /// # Panics
+        ///
+        /// When debug assertions are enabled, panics if the value
+        /// does not fit. When debug assertions are not enabled,
+        /// the wrapped value can be returned, but it is not
+        /// considered a breaking change if in the future it
+        /// panics; if wrapping is required use
+        /// [`wrapping_from_fixed_round`] instead.
+        ///
+        /// [`wrapping_from_fixed_round`]: FromFixed::wrapping_from_fixed_round
+        #[inline]
+        #[track_caller]
+        fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+            let (wrapped, overflow) = $crate::fixed256::overflowing_convert_round(src, mode);
+            debug_assert!(!overflow, "{} overflows", src);
+            wrapped
+        }
+
+        /// Converts from a fixed-point number, rounding as specified by
+        /// `mode`, if it fits, otherwise returns [`None`].
+        #[inline]
+        fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self> {
+            $crate::fixed256::checked_convert_round(src, mode)
+        }
+
+        /// Converts from a fixed-point number, rounding as specified by
+        /// `mode`, and saturating if it does not fit.
+        #[inline]
+        fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+            $crate::fixed256::saturating_convert_round(src, mode)
+        }
+
+        /// Converts from a fixed-point number, rounding as specified by
+        /// `mode`, and wrapping if it does not fit.
+        #[inline]
+        fn wrapping_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self {
+            $crate::fixed256::overflowing_convert_round(src, mode).0
+        }
+
+        /// Converts from a fixed-point number, rounding as specified by
+        /// `mode`.
+        ///
+        /// Returns a [tuple] of the value and a [`bool`] indicating whether
+        /// an overflow has occurred. On overflow, the wrapped value is
+        /// returned.
+        #[inline]
+        fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool) {
+            $crate::fixed256::overflowing_convert_round(src, mode)
+        }
+    };
+}
@@ -178,6 +178,122 @@ assert_eq!(Fix::from_num(0.1875).int_log(5), -2);
                 }
             }
 
+            comment! {
+                "Base-2 logarithm.
+
+The error is &lt;&nbsp;[`DELTA`][Self::DELTA] for fixed-point numbers with up to
+64 bits, and &lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA] for 128-bit
+fixed-point numbers.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).log2(), 2);
+assert_eq!(Fix::ONE.log2(), 0);
+// log₂ 3 ≈ 1.585
+assert_eq!(Fix::from_num(3).log2(), Fix::from_num(1.5625));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(0.25).log2(), -2);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log2(self) -> Self {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::Two) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
+                        None => panic!("log of non-positive number"),
+                    }
+                }
+            }
+
+            comment! {
+                "Natural logarithm.
+
+The error is &lt;&nbsp;[`DELTA`][Self::DELTA] for fixed-point numbers with up to
+64 bits, and &lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA] for 128-bit
+fixed-point numbers.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.ln(), 0);
+// ln 2 ≈ 0.693
+assert_eq!(Fix::from_num(2).ln(), Fix::from_num(0.6875));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// ln 0.5 ≈ &minus;0.693
+assert_eq!(Fix::from_num(0.5).ln(), Fix::from_num(-0.6875));
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn ln(self) -> Self {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::E) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
+                        None => panic!("log of non-positive number"),
+                    }
+                }
+            }
+
+            comment! {
+                "Base-10 logarithm.
+
+The error is &lt;&nbsp;[`DELTA`][Self::DELTA] for fixed-point numbers with up to
+64 bits, and &lt;&nbsp;2&nbsp;×&nbsp;[`DELTA`][Self::DELTA] for 128-bit
+fixed-point numbers.
+
+# Panics
+
+Panics if the fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), ", or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ONE.log10(), 0);
+// log₁₀ 5 ≈ 0.699
+assert_eq!(Fix::from_num(5).log10(), Fix::from_num(0.6875));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn log10(self) -> Self {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::Ten) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
+                        None => panic!("log of non-positive number"),
+                    }
+                }
+            }
+
             comment! {
                 "Checked integer base-2 logarithm, rounded down.
 Returns the logarithm or [`None`] if the fixed-point number is
@@ -301,6 +417,105 @@ assert_eq!(Fix::from_num(0.1875).checked_int_log(5), Some(-2));
                 }
             }
 
+            comment! {
+                "Checked base-2 logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`log2`][Self::log2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $n, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_log2(), None);
+assert_eq!(Fix::from_num(4).checked_log2(), Some(Fix::from_num(2)));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-4).checked_log2(), None);
+",
+                },
+                "
+type AllFrac = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).checked_log2(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_log2(self) -> Option<Self> {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::Two) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked natural logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`ln`][Self::ln] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_ln(), None);
+assert_eq!(Fix::ONE.checked_ln(), Some(Fix::ZERO));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_ln(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_ln(self) -> Option<Self> {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::E) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-10 logarithm. Returns the logarithm, or [`None`] if the
+fixed-point number is ", if_signed_unsigned!($Signedness, "≤&nbsp;0", "zero"), " or if the result does not fit.
+
+See [`log10`][Self::log10] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_log10(), None);
+assert_eq!(Fix::ONE.checked_log10(), Some(Fix::ZERO));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_log10(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_log10(self) -> Option<Self> {
+                    match log::real::$Inner(self.to_bits(), Self::FRAC_NBITS, RealBase::Ten) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
             if_signed! {
                 $Signedness;
                 comment! {
@@ -375,6 +590,141 @@ assert_eq!(Fix::from_num(2).recip(), Fix::from_num(0.5));
                 }
             }
 
+            comment! {
+                "Multiplication, rounding the product using the given rounding mode.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the product
+overflows. When debug assertions are not enabled, the wrapped value can
+be returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_mul_round`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let a = Fix::from_num(1.25);
+let b = Fix::from_num(0.125);
+// the exact product is 2.5 × DELTA
+assert_eq!(a.mul_round(b, RoundingMode::NearestEven), Fix::from_bits(2));
+assert_eq!(a.mul_round(b, RoundingMode::NearestAway), Fix::from_bits(3));
+assert_eq!(a.mul_round(b, RoundingMode::Ceil), Fix::from_bits(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).mul_round(b, RoundingMode::Floor), Fix::from_bits(-3));
+assert_eq!((-a).mul_round(b, RoundingMode::TowardZero), Fix::from_bits(-2));
+",
+                },
+                "```
+
+[`overflowing_mul_round`]: Self::overflowing_mul_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn mul_round(self, rhs: $Self<Frac>, mode: RoundingMode) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_mul_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Division, rounding the quotient using the given rounding mode.
+
+# Panics
+
+Panics if the divisor is zero.
+
+When debug assertions are enabled, this method also panics if the
+division overflows. When debug assertions are not enabled, the wrapped
+value can be returned, but it is not considered a breaking change if in
+the future it panics; if wrapping is required use
+[`overflowing_div_round`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let a = Fix::from_num(2);
+let b = Fix::from_num(3);
+// the exact quotient is 10.67 × DELTA
+assert_eq!(a.div_round(b, RoundingMode::NearestEven), Fix::from_bits(11));
+assert_eq!(a.div_round(b, RoundingMode::Floor), Fix::from_bits(10));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).div_round(b, RoundingMode::Floor), Fix::from_bits(-11));
+assert_eq!((-a).div_round(b, RoundingMode::TowardZero), Fix::from_bits(-10));
+",
+                },
+                "```
+
+[`overflowing_div_round`]: Self::overflowing_div_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn div_round(self, rhs: $Self<Frac>, mode: RoundingMode) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_div_round(rhs, mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Returns the reciprocal (inverse) of the fixed-point number, 1/`self`,
+rounding using the given rounding mode.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+When debug assertions are enabled, this method also panics if the
+reciprocal overflows. When debug assertions are not enabled, the
+wrapped value can be returned, but it is not considered a breaking
+change if in the future it panics; if wrapping is required use
+[`overflowing_recip_round`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let a = Fix::from_num(3);
+// the exact reciprocal is 5.33 × DELTA
+assert_eq!(a.recip_round(RoundingMode::NearestEven), Fix::from_bits(5));
+assert_eq!(a.recip_round(RoundingMode::Ceil), Fix::from_bits(6));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!((-a).recip_round(RoundingMode::Floor), Fix::from_bits(-6));
+",
+                },
+                "```
+
+[`overflowing_recip_round`]: Self::overflowing_recip_round
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn recip_round(self, mode: RoundingMode) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_recip_round(mode);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
             comment! {
                 "Euclidean division.
 
@@ -667,21 +1017,27 @@ assert_eq!(Fix::from_num(2).sqrt(), Fix::SQRT_2);
                 }
             }
 
+
             comment! {
-                "Linear interpolation between `start` and `end`.
+                "Returns the reciprocal square root, 1/√`self`.
 
-Returns
-`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
-is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
-linear interpolation for all other values of `self`. Linear extrapolation is
-performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+The result is rounded to the nearest, so the error is at most half of
+[`DELTA`][Self::DELTA]. The result is computed directly instead of by taking the
+reciprocal of [`sqrt`][Self::sqrt], so there is only one rounding step.
+
+Overflow occurs if `self` is so small that its reciprocal square root is greater
+than [`MAX`][Self::MAX].
 
 # Panics
 
-When debug assertions are enabled, this method panics if the result overflows.
-When debug assertions are not enabled, the wrapped value can be returned, but it
-is not considered a breaking change if in the future it panics; if wrapping is
-required use [`wrapping_lerp`] instead.
+Panics if the number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_rsqrt`] instead.
 
 # Examples
 
@@ -689,83 +1045,39 @@ required use [`wrapping_lerp`] instead.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let start = Fix::from_num(2);
-let end = Fix::from_num(3.5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
-",
-                },
-                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
-assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
-assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
-assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+assert_eq!(Fix::from_num(4).rsqrt(), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(0.25).rsqrt(), Fix::from_num(2));
+assert_eq!(Fix::from_num(2).rsqrt(), Fix::FRAC_1_SQRT_2);
 ```
 
-[`wrapping_lerp`]: Self::wrapping_lerp
+[`wrapping_rsqrt`]: Self::wrapping_rsqrt
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn lerp<RangeFrac>(
-                    self,
-                    start: $Self<RangeFrac>,
-                    end: $Self<RangeFrac>,
-                ) -> $Self<RangeFrac> {
-                    let (ans, overflow) = lerp::$Inner(
-                        self.to_bits(),
-                        start.to_bits(),
-                        end.to_bits(),
-                        Frac::U32,
-                    );
+                pub const fn rsqrt(self) -> Self {
+                    let (val, overflow) = self.overflowing_rsqrt();
                     debug_assert!(!overflow, "overflow");
-                    $Self::from_bits(ans)
+                    val
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Checked signum. Returns a number representing the
-sign of `self`, or [`None`] on overflow.
-
-Overflow can only occur
-  * if the value is positive and the fixed-point number has zero
-    or one integer bits such that it cannot hold the value 1.
-  * if the value is negative and the fixed-point number has zero
-    integer bits, such that it cannot hold the value &minus;1.
+            comment! {
+                "Base-2 exponential, 2<sup><i>x</i></sup>.
 
-# Examples
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
 
-```rust
-use fixed::types::extra::{U4, U", $nm1, ", U", $n, "};
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::ONE));
-assert_eq!(Fix::ZERO.checked_signum(), Some(Fix::ZERO));
-assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::NEG_ONE));
+Overflow occurs if the result is greater than [`MAX`][Self::MAX]. With
+<i>i</i> integer bits, 2<sup><i>x</i></sup> overflows for
+<i>x</i>&nbsp;≥&nbsp;<i>i</i>", if_signed_else_empty_str!($Signedness; "&nbsp;&minus;&nbsp;1"), ".
 
-type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
-type ZeroIntBits = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(OneIntBit::from_num(0.5).checked_signum(), None);
-assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);
-assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);
-```
-";
-                    #[inline]
-                    #[must_use]
-                    pub const fn checked_signum(self) -> Option<$Self<Frac>> {
-                        match self.overflowing_signum() {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
-                }
-            }
+# Panics
 
-            comment! {
-                "Checked multiplication. Returns the product, or [`None`] on overflow.
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp2`] instead.
 
 # Examples
 
@@ -773,23 +1085,44 @@ assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::MAX.checked_mul(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
-```
+assert_eq!(Fix::from_num(2).exp2(), 4);
+assert!(Fix::from_num(0.5).exp2().dist(Fix::SQRT_2) <= Fix::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-2).exp2(), 0.25);
+",
+                },
+                "```
+
+[`wrapping_exp2`]: Self::wrapping_exp2
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_mul(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
-                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
-                        (ans, false) => Some(Self::from_bits(ans)),
-                        (_, true) => None,
-                    }
+                #[track_caller]
+                #[must_use]
+                pub const fn exp2(self) -> Self {
+                    let (ans, overflow) = self.overflowing_exp2();
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                "Checked division. Returns the quotient, or [`None`] if
-the divisor is zero or on overflow.
+                "Exponential, <i>e</i><sup><i>x</i></sup>.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX]. With
+<i>i</i> integer bits, <i>e</i><sup><i>x</i></sup> overflows for
+<i>x</i>&nbsp;≥&nbsp;", if_signed_unsigned!($Signedness, "(<i>i</i>&nbsp;&minus;&nbsp;1)", "<i>i</i>"), "&nbsp;ln&nbsp;2.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp`] instead.
 
 # Examples
 
@@ -797,26 +1130,46 @@ the divisor is zero or on overflow.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
-assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
-```
+assert_eq!(Fix::ZERO.exp(), 1);
+assert!(Fix::ONE.exp().dist(Fix::E) <= Fix::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// e^&minus;1 ≈ 0.368
+assert_eq!(Fix::NEG_ONE.exp(), 0.375);
+",
+                },
+                "```
+
+[`wrapping_exp`]: Self::wrapping_exp
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
-                    if rhs.to_bits() == 0 {
-                        return None;
-                    }
-                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
-                        (ans, false) => Some(Self::from_bits(ans)),
-                        (_, true) => None,
-                    }
+                #[track_caller]
+                #[must_use]
+                pub const fn exp(self) -> Self {
+                    let (ans, overflow) = self.overflowing_exp();
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                "Checked reciprocal. Returns the reciprocal, or
-[`None`] if `self` is zero or on overflow.
+                "Exponential minus one, <i>e</i><sup><i>x</i></sup>&nbsp;&minus;&nbsp;1.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Unlike `self.exp() - 1`, this method does not overflow when
+<i>e</i><sup><i>x</i></sup> is too large for the fixed-point number but
+<i>e</i><sup><i>x</i></sup>&nbsp;&minus;&nbsp;1 is not. Overflow occurs if the
+result is greater than [`MAX`][Self::MAX].
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_exp_m1`] instead.
 
 # Examples
 
@@ -824,27 +1177,45 @@ assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
-assert_eq!(Fix::ZERO.checked_recip(), None);
-```
+assert_eq!(Fix::ZERO.exp_m1(), 0);
+assert!(Fix::ONE.exp_m1().dist(Fix::E - Fix::ONE) <= Fix::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// e^&minus;1 &minus; 1 ≈ &minus;0.632
+assert_eq!(Fix::NEG_ONE.exp_m1(), -0.625);
+",
+                },
+                "```
+
+[`wrapping_exp_m1`]: Self::wrapping_exp_m1
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn checked_recip(self) -> Option<$Self<Frac>> {
-                    if self.to_bits() == 0 {
-                        None
-                    } else {
-                        match self.overflowing_recip() {
-                            (ans, false) => Some(ans),
-                            (_, true) => None,
-                        }
-                    }
+                pub const fn exp_m1(self) -> Self {
+                    let (ans, overflow) = self.overflowing_exp_m1();
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                "Checked Euclidean division. Returns the quotient, or
-[`None`] if the divisor is zero or on overflow.
+                "Returns the value raised to the integer power `n`.
+
+The power is computed by squaring and multiplying with a wide intermediate
+precision, and the result is rounded to the nearest only at the end. The error
+is &lt;&nbsp;[`DELTA`][Self::DELTA]. If there are no fractional bits and
+`n`&nbsp;≥&nbsp;0, the result is exact.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+When debug assertions are enabled, this method also panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_powi`] instead.
 
 # Examples
 
@@ -852,56 +1223,50 @@ assert_eq!(Fix::ZERO.checked_recip(), None);
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(3)));
-assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::ZERO), None);
-assert_eq!(Fix::MAX.checked_div_euclid(Fix::from_num(0.25)), None);
+assert_eq!(Fix::from_num(2.5).powi(2), 6.25);
+assert_eq!(Fix::from_num(1.5).powi(3), 3.375);
+assert_eq!(Fix::from_num(2).powi(-2), 0.25);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(-4)));
+                    "assert_eq!(Fix::from_num(-2).powi(3), -8);
 ",
                 },
                 "```
+
+[`wrapping_powi`]: Self::wrapping_powi
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_euclid(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
-                    let q = match self.checked_div(rhs) {
-                        Some(s) => s.round_to_zero(),
-                        None => return None,
-                    };
-                    if_signed! {
-                        $Signedness;
-                        if self.unwrapped_rem(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return None;
-                            };
-                            return if rhs.is_positive() {
-                                q.checked_add(neg_one)
-                            } else {
-                                q.checked_sub(neg_one)
-                            };
-                        }
-                    }
-                    Some(q)
+                #[track_caller]
+                #[must_use]
+                pub const fn powi(self, n: i32) -> Self {
+                    let (ans, overflow) = self.overflowing_powi(n);
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
+                "Returns the value raised to the fixed-point power `exp`.
+
+The power is computed as
+2<sup>`exp`&nbsp;×&nbsp;log<sub>2</sub>&nbsp;`self`</sup> with a wide
+intermediate precision. The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+# Panics
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
-is representable; in these cases this method returns the correct result without
-overflow.
+                    "Panics if `self` is negative, or if `self` is zero and `exp` is negative.
 
 ",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
+                "When debug assertions are enabled, this method ", if_signed_else_empty_str!($Signedness; "also "), "panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_powf`] instead.
 
 # Examples
 
@@ -909,65 +1274,50 @@ overflow.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(
-    Fix::from_num(3).checked_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    Some(Fix::from_num(5))
-);
-assert_eq!(Fix::DELTA.checked_add_prod(Fix::MAX, Fix::ONE), None);
+assert_eq!(Fix::from_num(4).powf(Fix::from_num(0.5)), 2);
+// 2^2.5 ≈ 5.657
+assert_eq!(Fix::from_num(2).powf(Fix::from_num(2.5)), 5.6875);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
-assert_eq!(
-    (-Fix::MAX).checked_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Some(Fix::MAX / 2)
-);
-"
+                    "assert_eq!(Fix::from_num(4).powf(Fix::from_num(-0.5)), 0.5);
+",
                 },
                 "```
+
+[`wrapping_powf`]: Self::wrapping_powf
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn checked_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) -> Option<$Self<Frac>> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
-                        self.to_bits(),
-                        AFrac::I32 + BFrac::I32 - Frac::I32,
-                    );
-                    if overflow {
-                        return None;
-                    }
-                    Some(Self::from_bits(ans))
+                pub const fn powf(self, exp: $Self<Frac>) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_powf(exp);
+                    debug_assert!(!overflow, "overflow");
+                    ans
                 }
             }
 
             comment! {
-                r#"Checked multiply and accumulate. Adds (`a` × `b`) to `self`,
-or returns [`None`] on overflow.
-
-Like all other checked methods, this method wraps the successful return value in
-an [`Option`]. Since the unchecked [`mul_acc`] method does not return a value,
-which is equivalent to returning [`()`][unit], this method wraps [`()`][unit]
-into <code>[Some]\([()][unit])</code> on success.
+                "Returns the cube root.
 
-When overflow occurs, `self` is not modified and retains its previous value.
-
-"#,
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "For some cases, the product `a` × `b` would overflow on its
-own, but the final result `self` + `a` × `b` is representable; in these cases
-this method saves the correct result without overflow.
-
-",
+                    " The cube root of a negative number
+is negative.",
                 },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
+                "
+
+Overflow can only occur when the fixed-point number cannot represent 1, as the
+cube root of a number with a magnitude less than 1 is closer to 1.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cbrt`] instead.
 
 # Examples
 
@@ -975,51 +1325,1636 @@ this method saves the correct result without overflow.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let mut acc = Fix::from_num(3);
-let check = acc.checked_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(Fix::from_num(3.375).cbrt(), 1.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.375).cbrt(), -1.5);
+",
+                },
+                "```
+
+[`wrapping_cbrt`]: Self::wrapping_cbrt
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cbrt(self) -> Self {
+                    let (ans, overflow) = self.overflowing_cbrt();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Returns the <i>n</i>th root.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " If `n` is odd, the root of a negative
+number is negative.",
+                },
+                "
+
+Overflow can only occur when the fixed-point number cannot represent 1, as the
+root of a number with a magnitude less than 1 is closer to 1.
+
+# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if `n` is even and the number is negative",
+                },
+                ".
+
+When debug assertions are enabled, this method also panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_nth_root`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5.0625).nth_root(4), 1.5);
+assert_eq!(Fix::from_num(0.25).nth_root(2), 0.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.125).nth_root(3), -0.5);
+",
+                },
+                "```
+
+[`wrapping_nth_root`]: Self::wrapping_nth_root
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn nth_root(self, n: u32) -> Self {
+                    let (ans, overflow) = self.overflowing_nth_root(n);
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `start` and `end`.
+
+Returns
+`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
+is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_lerp`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let start = Fix::from_num(2);
+let end = Fix::from_num(3.5);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1.0).lerp(start, end), 0.5);
+",
+                },
+                "assert_eq!(Fix::from_num(0.0).lerp(start, end), 2);
+assert_eq!(Fix::from_num(0.5).lerp(start, end), 2.75);
+assert_eq!(Fix::from_num(1.0).lerp(start, end), 3.5);
+assert_eq!(Fix::from_num(2.0).lerp(start, end), 5);
+```
+
+[`wrapping_lerp`]: Self::wrapping_lerp
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn lerp<RangeFrac>(
+                    self,
+                    start: $Self<RangeFrac>,
+                    end: $Self<RangeFrac>,
+                ) -> $Self<RangeFrac> {
+                    let (ans, overflow) = lerp::$Inner(
+                        self.to_bits(),
+                        start.to_bits(),
+                        end.to_bits(),
+                        Frac::U32,
+                    );
+                    debug_assert!(!overflow, "overflow");
+                    $Self::from_bits(ans)
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Checked signum. Returns a number representing the
+sign of `self`, or [`None`] on overflow.
+
+Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value &minus;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, ", U", $n, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5).checked_signum(), Some(Fix::ONE));
+assert_eq!(Fix::ZERO.checked_signum(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(-5).checked_signum(), Some(Fix::NEG_ONE));
+
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+type ZeroIntBits = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(OneIntBit::from_num(0.5).checked_signum(), None);
+assert_eq!(ZeroIntBits::from_num(0.25).checked_signum(), None);
+assert_eq!(ZeroIntBits::from_num(-0.5).checked_signum(), None);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn checked_signum(self) -> Option<$Self<Frac>> {
+                        match self.overflowing_signum() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication. Returns the product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::MAX.checked_mul(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_mul(Fix::from_num(2)), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division. Returns the quotient, or [`None`] if
+the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::MAX.checked_div(Fix::ONE), Some(Fix::MAX));
+assert_eq!(Fix::MAX.checked_div(Fix::ONE / 2), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Some(Self::from_bits(ans)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked reciprocal. Returns the reciprocal, or
+[`None`] if `self` is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2).checked_recip(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_recip(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_recip(self) -> Option<$Self<Frac>> {
+                    if self.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_recip() {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked multiplication, rounding the product using the given
+rounding mode. Returns the product, or [`None`] on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let half_delta = Fix::from_num(0.5);
+assert_eq!(Fix::DELTA.checked_mul_round(half_delta, RoundingMode::Ceil), Some(Fix::DELTA));
+assert_eq!(Fix::MAX.checked_mul_round(Fix::from_num(2), RoundingMode::Floor), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_mul_round(
+                    self,
+                    rhs: $Self<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Self<Frac>> {
+                    match self.overflowing_mul_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked division, rounding the quotient using the given
+rounding mode. Returns the quotient, or [`None`] if the divisor is zero
+or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let three = Fix::from_num(3);
+assert_eq!(Fix::ONE.checked_div_round(three, RoundingMode::Ceil), Some(Fix::from_bits(6)));
+assert_eq!(Fix::ONE.checked_div_round(Fix::ZERO, RoundingMode::Ceil), None);
+assert_eq!(Fix::MAX.checked_div_round(Fix::ONE / 2, RoundingMode::Floor), None);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_round(
+                    self,
+                    rhs: $Self<Frac>,
+                    mode: RoundingMode,
+                ) -> Option<$Self<Frac>> {
+                    if rhs.to_bits() == 0 {
+                        return None;
+                    }
+                    match self.overflowing_div_round(rhs, mode) {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked reciprocal, rounding using the given rounding mode.
+Returns the reciprocal, or [`None`] if `self` is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let three = Fix::from_num(3);
+assert_eq!(three.checked_recip_round(RoundingMode::Ceil), Some(Fix::from_bits(6)));
+assert_eq!(Fix::ZERO.checked_recip_round(RoundingMode::Ceil), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_recip_round(self, mode: RoundingMode) -> Option<$Self<Frac>> {
+                    if self.to_bits() == 0 {
+                        None
+                    } else {
+                        match self.overflowing_recip_round(mode) {
+                            (ans, false) => Some(ans),
+                            (_, true) => None,
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Checked Euclidean division. Returns the quotient, or
+[`None`] if the divisor is zero or on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(3)));
+assert_eq!(Fix::from_num(7.5).checked_div_euclid(Fix::ZERO), None);
+assert_eq!(Fix::MAX.checked_div_euclid(Fix::from_num(0.25)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_div_euclid(Fix::from_num(2)), Some(Fix::from_num(-4)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_euclid(self, rhs: $Self<Frac>) -> Option<$Self<Frac>> {
+                    let q = match self.checked_div(rhs) {
+                        Some(s) => s.round_to_zero(),
+                        None => return None,
+                    };
+                    if_signed! {
+                        $Signedness;
+                        if self.unwrapped_rem(rhs).is_negative() {
+                            let Some(neg_one) = Self::TRY_NEG_ONE else {
+                                return None;
+                            };
+                            return if rhs.is_positive() {
+                                q.checked_add(neg_one)
+                            } else {
+                                q.checked_sub(neg_one)
+                            };
+                        }
+                    }
+                    Some(q)
+                }
+            }
+
+            comment! {
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, returning [`None`] on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(
+    Fix::from_num(3).checked_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    Some(Fix::from_num(5))
+);
+assert_eq!(Fix::DELTA.checked_add_prod(Fix::MAX, Fix::ONE), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).checked_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Some(Fix::MAX / 2)
+);
+"
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) -> Option<$Self<Frac>> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        AFrac::I32 + BFrac::I32 - Frac::I32,
+                    );
+                    if overflow {
+                        return None;
+                    }
+                    Some(Self::from_bits(ans))
+                }
+            }
+
+            comment! {
+                r#"Checked multiply and accumulate. Adds (`a` × `b`) to `self`,
+or returns [`None`] on overflow.
+
+Like all other checked methods, this method wraps the successful return value in
+an [`Option`]. Since the unchecked [`mul_acc`] method does not return a value,
+which is equivalent to returning [`()`][unit], this method wraps [`()`][unit]
+into <code>[Some]\([()][unit])</code> on success.
+
+When overflow occurs, `self` is not modified and retains its previous value.
+
+"#,
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+let check = acc.checked_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(check, Some(()));
+assert_eq!(acc, 5);
+
+acc = Fix::DELTA;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::ONE);
+assert_eq!(check, None);
+// acc is unchanged on error
+assert_eq!(acc, Fix::DELTA);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+let check = acc.checked_mul_acc(Fix::MAX, Fix::from_num(1.5));
 assert_eq!(check, Some(()));
+assert_eq!(acc, Fix::MAX / 2);
+"
+                },
+                "```
+
+[`mul_acc`]: Self::mul_acc
+";
+                #[inline]
+                #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
+                pub const fn checked_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    &mut self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) -> Option<()> {
+                    match self.checked_add_prod(a, b) {
+                        Some(s) => {
+                            *self = s;
+                            Some(())
+                        }
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked fixed-point remainder for division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3.75).checked_rem_int(2), Some(Fix::from_num(1.75)));
+assert_eq!(Fix::from_num(3.75).checked_rem_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-3.75).checked_rem_int(2), Some(Fix::from_num(-1.75)));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
+                    // Overflow converting rhs to $Self<Frac> means that either
+                    //   * |rhs| > |self|, and so remainder is self, or
+                    //   * self is signed min with at least one integer bit,
+                    //     and the value of rhs is -self, so remainder is 0.
+                    if rhs == 0 {
+                        return None;
+                    }
+                    let can_shift = if_signed_unsigned!(
+                        $Signedness,
+                        if rhs.is_negative() {
+                            rhs.leading_ones() - 1
+                        } else {
+                            rhs.leading_zeros() - 1
+                        },
+                        rhs.leading_zeros(),
+                    );
+                    if Self::FRAC_NBITS <= can_shift {
+                        let fixed_rhs = Self::from_bits(rhs << Self::FRAC_NBITS);
+                        self.checked_rem(fixed_rhs)
+                    } else {
+                        if_signed_unsigned!(
+                            $Signedness,
+                            if self.to_bits() == $Inner::MIN
+                                && (Self::INT_NBITS > 0 && rhs == 1 << (Self::INT_NBITS - 1))
+                            {
+                                Some(Self::ZERO)
+                            } else {
+                                Some(self)
+                            },
+                            Some(self),
+                        )
+                    }
+                }
+            }
+
+            comment! {
+                "Checked Euclidean division by an integer. Returns the
+quotient, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
+assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
+                    let q = match self.checked_div_int(rhs) {
+                        Some(s) => s.round_to_zero(),
+                        None => return None,
+                    };
+                    if_signed! {
+                        $Signedness;
+                        if self.unwrapped_rem_int(rhs).is_negative() {
+                            let Some(neg_one) = Self::TRY_NEG_ONE else {
+                                return None;
+                            };
+                            return if rhs.is_positive() {
+                                q.checked_add(neg_one)
+                            } else {
+                                q.checked_sub(neg_one)
+                            };
+                        }
+                    }
+                    Some(q)
+                }
+            }
+
+            comment! {
+                "Checked remainder for Euclidean division by an integer.
+Returns the remainder, or [`None`] if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the remainder results in overflow",
+                },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
+assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
+// -8 ≤ Fix < 8, so the answer 12.5 overflows
+assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
+                    if_signed! {
+                        $Signedness;
+                        let Some(rem) = self.checked_rem_int(rhs) else {
+                            return None;
+                        };
+                        if !rem.is_negative() {
+                            return Some(rem);
+                        }
+                        // Work in unsigned.
+                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
+                        // INT_NBITS == 0 is a special case, as fraction can be negative.
+                        if Self::INT_NBITS == 0 {
+                            // -0.5 <= rem < 0, so euclidean remainder is in the range
+                            // 0.5 <= answer < 1, which does not fit.
+                            return None;
+                        }
+                        let rhs_abs = rhs.wrapping_abs() as $UInner;
+                        let remb = rem.to_bits();
+                        let remb_abs = remb.wrapping_neg() as $UInner;
+                        let rem_int_abs = remb_abs >> Self::FRAC_NBITS;
+                        let rem_frac = remb & Self::FRAC_MASK;
+                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
+                        let ansb_abs = if ans_int == 0 {
+                            0
+                        } else if Self::FRAC_NBITS <= ans_int.leading_zeros() {
+                            ans_int << Self::FRAC_NBITS
+                        } else {
+                            return None
+                        };
+                        let ansb = ansb_abs as $Inner;
+                        if ansb.is_negative() {
+                            return None;
+                        }
+                        Some(Self::from_bits(ansb | rem_frac))
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        self.checked_rem_int(rhs)
+                    }
+                }
+            }
+
+            comment! {
+                "Checked square root. ",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "Returns [`None`] for negative numbers and on overflow.",
+                    "Always returns the square root for unsigned numbers."
+                ),
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+ [`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
+
+"
+                },
+                "# Examples
+
+```rust
+use fixed::types::extra::",
+                if_signed_unsigned!($Signedness, concat!("{U4, U", $n, "}"), "U4"),
+                ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
+
+type AllFrac = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sqrt(self) -> Option<Self> {
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_sqrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+
+            comment! {
+                "Checked reciprocal square root. Returns the reciprocal square
+root, or [`None`] if `self` is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                " or on overflow.
+
+See [`rsqrt`][Self::rsqrt] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).checked_rsqrt(), Some(Fix::from_num(0.5)));
+assert_eq!(Fix::ZERO.checked_rsqrt(), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_rsqrt(), None);
+",
+                },
+                "// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Small::from_num(0.25).checked_rsqrt(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_rsqrt(self) -> Option<Self> {
+                    if self.to_bits() == 0 {
+                        return None;
+                    }
+                    if_signed! {
+                        $Signedness;
+                        if self.is_negative() {
+                            return None;
+                        }
+                    }
+                    match self.overflowing_rsqrt() {
+                        (val, false) => Some(val),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked base-2 exponential. Returns the base-2 exponential, or [`None`] on
+overflow.
+
+See [`exp2`][Self::exp2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2).checked_exp2(), Some(Fix::from_num(4)));
+assert_eq!(Fix::MAX.checked_exp2(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp2(self) -> Option<Self> {
+                    match self.overflowing_exp2() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked exponential. Returns the exponential, or [`None`] on
+overflow.
+
+See [`exp`][Self::exp] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_exp(), Some(Fix::ONE));
+assert_eq!(Fix::MAX.checked_exp(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp(self) -> Option<Self> {
+                    match self.overflowing_exp() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked exponential minus one. Returns the exponential minus one, or [`None`] on
+overflow.
+
+See [`exp_m1`][Self::exp_m1] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.checked_exp_m1(), Some(Fix::ZERO));
+assert_eq!(Fix::MAX.checked_exp_m1(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_exp_m1(self) -> Option<Self> {
+                    match self.overflowing_exp_m1() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked integer power. Returns the value raised to the integer power `n`, or
+[`None`] if `self` is zero and `n` is negative or on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(1.5).checked_powi(3), Some(Fix::from_num(3.375)));
+assert_eq!(Fix::ZERO.checked_powi(-1), None);
+assert_eq!(Fix::MAX.checked_powi(2), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_powi(self, n: i32) -> Option<Self> {
+                    match pow::$Inner::powi(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked power. Returns the value raised to the fixed-point power `exp`, or
+[`None`] ",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "if `self` is negative, if `self` is zero and `exp` is negative,
+or ",
+                },
+                "on overflow.
+
+See [`powf`][Self::powf] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(
+    Fix::from_num(4).checked_powf(Fix::from_num(0.5)),
+    Some(Fix::from_num(2))
+);
+assert_eq!(Fix::MAX.checked_powf(Fix::from_num(2)), None);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-1).checked_powf(Fix::ONE), None);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_powf(self, exp: $Self<Frac>) -> Option<$Self<Frac>> {
+                    match pow::$Inner::powf(self.to_bits(), exp.to_bits(), Self::FRAC_NBITS) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked cube root. Returns the cube root, or [`None`] on overflow.
+
+See [`cbrt`][Self::cbrt] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(
+    Fix::from_num(0.015625).checked_cbrt(),
+    Some(Fix::from_num(0.25))
+);
+// the cube root of MAX rounds to a value that cannot be represented
+assert_eq!(Fix::MAX.checked_cbrt(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cbrt(self) -> Option<Self> {
+                    self.checked_nth_root(3)
+                }
+            }
+
+            comment! {
+                "Checked <i>n</i>th root. Returns the <i>n</i>th root, or [`None`] if `n` is
+zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", if `n` is even and the number is negative,",
+                },
+                " or on overflow.
+
+See [`nth_root`][Self::nth_root] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(
+    Fix::from_num(0.0625).checked_nth_root(2),
+    Some(Fix::from_num(0.25))
+);
+assert_eq!(Fix::from_num(0.0625).checked_nth_root(0), None);
+// the fourth root of MAX rounds to a value that cannot be represented
+assert_eq!(Fix::MAX.checked_nth_root(4), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_nth_root(self, n: u32) -> Option<Self> {
+                    match pow::$Inner::root(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Some(Self::from_bits(ans)),
+                        _ => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked linear interpolation between `start` and `end`. Returns
+[`None`] on overflow.
+
+The interpolted value is
+`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
+is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(0.5).checked_lerp(Fix::ZERO, Fix::MAX), Some(Fix::MAX / 2));
+assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_lerp<RangeFrac>(
+                    self,
+                    start: $Self<RangeFrac>,
+                    end: $Self<RangeFrac>,
+                ) -> Option<$Self<RangeFrac>> {
+                    match lerp::$Inner(self.to_bits(), start.to_bits(), end.to_bits(), Frac::U32) {
+                        (bits, false) => Some($Self::from_bits(bits)),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            if_signed! {
+                $Signedness;
+                comment! {
+                    "Saturating signum. Returns a number representing
+the sign of `self`, saturating on overflow.
+
+Overflow can only occur
+  * if the value is positive and the fixed-point number has zero
+    or one integer bits such that it cannot hold the value 1.
+  * if the value is negative and the fixed-point number has zero
+    integer bits, such that it cannot hold the value &minus;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, ", U", $n, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(5).saturating_signum(), 1);
+assert_eq!(Fix::ZERO.saturating_signum(), 0);
+assert_eq!(Fix::from_num(-5).saturating_signum(), -1);
+
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+type ZeroIntBits = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(OneIntBit::from_num(0.5).saturating_signum(), OneIntBit::MAX);
+assert_eq!(ZeroIntBits::from_num(0.25).saturating_signum(), ZeroIntBits::MAX);
+assert_eq!(ZeroIntBits::from_num(-0.5).saturating_signum(), ZeroIntBits::MIN);
+```
+";
+                    #[inline]
+                    #[must_use]
+                    pub const fn saturating_signum(self) -> $Self<Frac> {
+                        match self.overflowing_signum() {
+                            (ans, false) => ans,
+                            (_, true) => {
+                                if self.is_negative() {
+                                    $Self::MIN
+                                } else {
+                                    $Self::MAX
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiplication. Returns the product, saturating on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
+assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating division. Returns the quotient, saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one_half = Fix::ONE / 2;
+assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
+assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
+                        (ans, false) => Self::from_bits(ans),
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating reciprocal. Returns the reciprocal,
+saturating on overflow.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+// only one integer bit
+type Fix = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-0.25).saturating_recip(), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_recip(self) -> $Self<Frac> {
+                    match self.overflowing_recip() {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating Euclidean division. Returns the quotient,
+saturating on overflow.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(7.5).saturating_div_euclid(Fix::from_num(2)), Fix::from_num(3));
+assert_eq!(Fix::MAX.saturating_div_euclid(Fix::from_num(0.25)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).saturating_div_euclid(Fix::from_num(2)), Fix::from_num(-4));
+assert_eq!(Fix::MIN.saturating_div_euclid(Fix::from_num(0.25)), Fix::MIN);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_div_euclid(rhs) {
+                        (val, false) => val,
+                        (_, true) => {
+                            if_signed_unsigned!(
+                                $Signedness,
+                                if self.is_negative() != rhs.is_negative() {
+                                    Self::MIN
+                                } else {
+                                    Self::MAX
+                                },
+                                Self::MAX,
+                            )
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating Euclidean division by an integer. Returns the quotient",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", saturating on overflow.
+
+Overflow can only occur when dividing the minimum value by &minus;1.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(7.5).saturating_div_euclid_int(2), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).saturating_div_euclid_int(2), Fix::from_num(-4));
+assert_eq!(Fix::MIN.saturating_div_euclid_int(-1), Fix::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    // dividing by integer can never result in something < MIN
+                    match self.overflowing_div_euclid_int(rhs) {
+                        (val, false) => val,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, saturating on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(
+    Fix::from_num(3).saturating_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    5
+);
+assert_eq!(Fix::ONE.saturating_add_prod(Fix::MAX, Fix::from_num(3)), Fix::MAX);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).saturating_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Fix::MAX / 2
+);
+"
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) -> $Self<Frac> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        AFrac::I32 + BFrac::I32 - Frac::I32,
+                    );
+                    if overflow {
+                        if_signed_unsigned!(
+                            $Signedness,
+                            if a.is_negative() != b.is_negative() {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            },
+                            Self::MAX,
+                        )
+                    } else {
+                        Self::from_bits(ans)
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating multiply and accumulate. Adds (`a` × `b`) to `self`,
+saturating on overflow.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
+
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::from_num(3);
+acc.saturating_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
 assert_eq!(acc, 5);
 
-acc = Fix::DELTA;
-let check = acc.checked_mul_acc(Fix::MAX, Fix::ONE);
-assert_eq!(check, None);
-// acc is unchanged on error
-assert_eq!(acc, Fix::DELTA);
+acc = Fix::MAX / 2;
+acc.saturating_mul_acc(Fix::MAX / 2, Fix::from_num(3));
+assert_eq!(acc, Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
 // MAX × 1.5 - MAX = MAX / 2, which does not overflow
 acc = -Fix::MAX;
-let check = acc.checked_mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(check, Some(()));
+acc.saturating_mul_acc(Fix::MAX, Fix::from_num(1.5));
 assert_eq!(acc, Fix::MAX / 2);
 "
                 },
                 "```
+";
+                #[inline]
+                pub const fn saturating_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    &mut self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) {
+                    *self = self.saturating_add_prod(a, b);
+                }
+            }
 
-[`mul_acc`]: Self::mul_acc
+            comment! {
+                "Saturating remainder for Euclidean division by an integer. Returns the remainder",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", saturating on overflow.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).saturating_rem_euclid_int(2), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(2), Fix::from_num(0.5));
+// -8 ≤ Fix < 8, so the answer 12.5 saturates
+assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(20), Fix::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn saturating_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    match self.overflowing_rem_euclid_int(rhs) {
+                        (val, false) => val,
+                        (_, true) => $Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Returns the square root, saturating on overflow.",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    " Can never overflow for unsigned numbers."
+                },
+                "
+
+This method uses an iterative method, with up to ", $n, " iterations for
+ [`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method returns [`MAX`][Self::MAX] for an input value ≥&nbsp;0.25.
+
+"
+                },
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative.
+
+"
+                },
+                "# Examples
+
+```rust
+use fixed::types::extra::",
+                if_signed_unsigned!($Signedness, concat!("{U4, U", $n, "}"), "U4"),
+                ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2).saturating_sqrt(), Fix::SQRT_2);
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+type AllFrac = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sqrt(self) -> Self {
+                    match self.overflowing_sqrt() {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+
+            comment! {
+                "Saturating reciprocal square root. Returns the reciprocal
+square root, saturating on overflow.
+
+Since the result is positive, overflow can only occur in the positive direction,
+and [`MAX`][Self::MAX] is returned on overflow.
+
+See [`rsqrt`][Self::rsqrt] for details.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).saturating_rsqrt(), Fix::from_num(0.5));
+// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Small::from_num(0.25).saturating_rsqrt(), Small::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_rsqrt(self) -> Self {
+                    match self.overflowing_rsqrt() {
+                        (val, false) => val,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating base-2 exponential. Returns the base-2 exponential, saturating on
+overflow.
+
+Since the result is never less than ", "0", ", overflow can only
+occur in the positive direction, and [`MAX`][Self::MAX] is returned on overflow.
+
+See [`exp2`][Self::exp2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(2).saturating_exp2(), 4);
+assert_eq!(Fix::MAX.saturating_exp2(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_exp2(self) -> Self {
+                    match self.overflowing_exp2() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating exponential. Returns the exponential, saturating on
+overflow.
+
+Since the result is never less than ", "0", ", overflow can only
+occur in the positive direction, and [`MAX`][Self::MAX] is returned on overflow.
+
+See [`exp`][Self::exp] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::ZERO.saturating_exp(), 1);
+assert_eq!(Fix::MAX.saturating_exp(), Fix::MAX);
+```
 ";
                 #[inline]
-                #[must_use = "this `Option` may be a `None` variant indicating overflow, which should be handled"]
-                pub const fn checked_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    &mut self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) -> Option<()> {
-                    match self.checked_add_prod(a, b) {
-                        Some(s) => {
-                            *self = s;
-                            Some(())
-                        }
-                        None => None,
+                #[must_use]
+                pub const fn saturating_exp(self) -> Self {
+                    match self.overflowing_exp() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
                     }
                 }
             }
 
             comment! {
-                "Checked fixed-point remainder for division by an integer.
-Returns the remainder, or [`None`] if the divisor is zero.
+                "Saturating exponential minus one. Returns the exponential minus one, saturating on
+overflow.
+
+Since the result is never less than ", "&minus;1", ", overflow can only
+occur in the positive direction, and [`MAX`][Self::MAX] is returned on overflow.
+
+See [`exp_m1`][Self::exp_m1] for details.
 
 # Examples
 
@@ -1027,62 +2962,29 @@ Returns the remainder, or [`None`] if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(3.75).checked_rem_int(2), Some(Fix::from_num(1.75)));
-assert_eq!(Fix::from_num(3.75).checked_rem_int(0), None);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-3.75).checked_rem_int(2), Some(Fix::from_num(-1.75)));
-",
-                },
-                "```
+assert_eq!(Fix::ZERO.saturating_exp_m1(), 0);
+assert_eq!(Fix::MAX.saturating_exp_m1(), Fix::MAX);
+```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
-                    // Overflow converting rhs to $Self<Frac> means that either
-                    //   * |rhs| > |self|, and so remainder is self, or
-                    //   * self is signed min with at least one integer bit,
-                    //     and the value of rhs is -self, so remainder is 0.
-                    if rhs == 0 {
-                        return None;
-                    }
-                    let can_shift = if_signed_unsigned!(
-                        $Signedness,
-                        if rhs.is_negative() {
-                            rhs.leading_ones() - 1
-                        } else {
-                            rhs.leading_zeros() - 1
-                        },
-                        rhs.leading_zeros(),
-                    );
-                    if Self::FRAC_NBITS <= can_shift {
-                        let fixed_rhs = Self::from_bits(rhs << Self::FRAC_NBITS);
-                        self.checked_rem(fixed_rhs)
-                    } else {
-                        if_signed_unsigned!(
-                            $Signedness,
-                            if self.to_bits() == $Inner::MIN
-                                && (Self::INT_NBITS > 0 && rhs == 1 << (Self::INT_NBITS - 1))
-                            {
-                                Some(Self::ZERO)
-                            } else {
-                                Some(self)
-                            },
-                            Some(self),
-                        )
+                #[must_use]
+                pub const fn saturating_exp_m1(self) -> Self {
+                    match self.overflowing_exp_m1() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
                     }
                 }
             }
 
             comment! {
-                "Checked Euclidean division by an integer. Returns the
-quotient, or [`None`] if the divisor is zero",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    " or if the division results in overflow",
-                },
-                ".
+                "Saturating integer power. Returns the value raised to the integer power `n`,
+saturating on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
 
 # Examples
 
@@ -1090,176 +2992,170 @@ quotient, or [`None`] if the divisor is zero",
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(2), Some(Fix::from_num(3)));
-assert_eq!(Fix::from_num(7.5).checked_div_euclid_int(0), None);
+assert_eq!(Fix::from_num(1.5).saturating_powi(3), 3.375);
+assert_eq!(Fix::MAX.saturating_powi(2), Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::MIN.checked_div_euclid_int(-1), None);
+                    "assert_eq!(Fix::MIN.saturating_powi(3), Fix::MIN);
 ",
                 },
                 "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_div_euclid_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
-                    let q = match self.checked_div_int(rhs) {
-                        Some(s) => s.round_to_zero(),
-                        None => return None,
-                    };
-                    if_signed! {
-                        $Signedness;
-                        if self.unwrapped_rem_int(rhs).is_negative() {
-                            let Some(neg_one) = Self::TRY_NEG_ONE else {
-                                return None;
-                            };
-                            return if rhs.is_positive() {
-                                q.checked_add(neg_one)
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_powi(self, n: i32) -> Self {
+                    match pow::$Inner::powi(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => {
+                            if if_signed_unsigned!(
+                                $Signedness,
+                                self.is_negative() && n % 2 != 0,
+                                false,
+                            ) {
+                                Self::MIN
                             } else {
-                                q.checked_sub(neg_one)
-                            };
+                                Self::MAX
+                            }
                         }
+                        None => panic!("division by zero"),
                     }
-                    Some(q)
                 }
             }
 
             comment! {
-                "Checked remainder for Euclidean division by an integer.
-Returns the remainder, or [`None`] if the divisor is zero",
+                "Saturating power. Returns the value raised to the fixed-point power `exp`,
+saturating on overflow.
+
+See [`powf`][Self::powf] for details.
+",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the remainder results in overflow",
+                    "
+# Panics
+
+Panics if `self` is negative, or if `self` is zero and `exp` is negative.
+",
                 },
-                ".
+                "
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).saturating_powf(Fix::from_num(0.5)), 2);
+assert_eq!(Fix::MAX.saturating_powf(Fix::from_num(2)), Fix::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_powf(self, exp: $Self<Frac>) -> $Self<Frac> {
+                    match pow::$Inner::powf(self.to_bits(), exp.to_bits(), Self::FRAC_NBITS) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => Self::MAX,
+                        None => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() {
+                                panic!("negative base")
+                            } else {
+                                panic!("division by zero")
+                            },
+                            unreachable!(),
+                        ),
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating cube root. Returns the cube root, saturating on overflow.
+
+See [`cbrt`][Self::cbrt] for details.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U", $nm4, ";
+use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(2), Some(Fix::from_num(1.5)));
-assert_eq!(Fix::from_num(7.5).checked_rem_euclid_int(0), None);
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.015625).saturating_cbrt(), 0.25);
+assert_eq!(Fix::MAX.saturating_cbrt(), Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(2), Some(Fix::from_num(0.5)));
-// -8 ≤ Fix < 8, so the answer 12.5 overflows
-assert_eq!(Fix::from_num(-7.5).checked_rem_euclid_int(20), None);
+                    "assert_eq!(Fix::MIN.saturating_cbrt(), Fix::MIN);
 ",
                 },
                 "```
 ";
                 #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn checked_rem_euclid_int(self, rhs: $Inner) -> Option<$Self<Frac>> {
-                    if_signed! {
-                        $Signedness;
-                        let Some(rem) = self.checked_rem_int(rhs) else {
-                            return None;
-                        };
-                        if !rem.is_negative() {
-                            return Some(rem);
-                        }
-                        // Work in unsigned.
-                        // Answer required is |rhs| - |rem|, but rhs is int, rem is fixed.
-                        // INT_NBITS == 0 is a special case, as fraction can be negative.
-                        if Self::INT_NBITS == 0 {
-                            // -0.5 <= rem < 0, so euclidean remainder is in the range
-                            // 0.5 <= answer < 1, which does not fit.
-                            return None;
-                        }
-                        let rhs_abs = rhs.wrapping_abs() as $UInner;
-                        let remb = rem.to_bits();
-                        let remb_abs = remb.wrapping_neg() as $UInner;
-                        let rem_int_abs = remb_abs >> Self::FRAC_NBITS;
-                        let rem_frac = remb & Self::FRAC_MASK;
-                        let ans_int = rhs_abs - rem_int_abs - if rem_frac > 0 { 1 } else { 0 };
-                        let ansb_abs = if ans_int == 0 {
-                            0
-                        } else if Self::FRAC_NBITS <= ans_int.leading_zeros() {
-                            ans_int << Self::FRAC_NBITS
-                        } else {
-                            return None
-                        };
-                        let ansb = ansb_abs as $Inner;
-                        if ansb.is_negative() {
-                            return None;
-                        }
-                        Some(Self::from_bits(ansb | rem_frac))
-                    }
-                    if_unsigned! {
-                        $Signedness;
-                        self.checked_rem_int(rhs)
-                    }
+                #[must_use]
+                pub const fn saturating_cbrt(self) -> Self {
+                    self.saturating_nth_root(3)
                 }
             }
 
             comment! {
-                "Checked square root. ",
-                if_signed_unsigned!(
-                    $Signedness,
-                    "Returns [`None`] for negative numbers and on overflow.",
-                    "Always returns the square root for unsigned numbers."
-                ),
-                "
+                "Saturating <i>n</i>th root. Returns the <i>n</i>th root, saturating on
+overflow.
 
-This method uses an iterative method, with up to ", $n, " iterations for
- [`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+See [`nth_root`][Self::nth_root] for details.
 
-",
+# Panics
+
+Panics if `n` is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "Overflow can only occur when there are no integer bits and
-the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns [`None`] for an input value ≥&nbsp;0.25.
-
-"
+                    ", or if `n` is even and the number is negative",
                 },
-                "# Examples
+                ".
+
+# Examples
 
 ```rust
-use fixed::types::extra::",
-                if_signed_unsigned!($Signedness, concat!("{U4, U", $n, "}"), "U4"),
-                ";
+use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(2).checked_sqrt(), Some(Fix::SQRT_2));
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.0625).saturating_nth_root(2), 0.25);
+assert_eq!(Fix::MAX.saturating_nth_root(4), Fix::MAX);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-1).checked_sqrt(), None);
-
-type AllFrac = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).checked_sqrt(), None);
+                    "assert_eq!(Fix::MIN.saturating_nth_root(3), Fix::MIN);
 ",
                 },
                 "```
 ";
                 #[inline]
+                #[track_caller]
                 #[must_use]
-                pub const fn checked_sqrt(self) -> Option<Self> {
-                    if_signed! {
-                        $Signedness;
-                        if self.is_negative() {
-                            return None;
+                pub const fn saturating_nth_root(self, n: u32) -> Self {
+                    match pow::$Inner::root(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => {
+                            if if_signed_unsigned!($Signedness, self.is_negative(), false) {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                        None => {
+                            if n == 0 {
+                                panic!("zeroth root");
+                            }
+                            panic!("even root of negative number");
                         }
-                    }
-                    match self.overflowing_sqrt() {
-                        (val, false) => Some(val),
-                        (_, true) => None,
                     }
                 }
             }
 
             comment! {
-                "Checked linear interpolation between `start` and `end`. Returns
-[`None`] on overflow.
+                "Linear interpolation between `start` and `end`, saturating on
+overflow.
 
-The interpolted value is
+The interpolated value is
 `start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
 is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
 linear interpolation for all other values of `self`. Linear extrapolation is
@@ -1271,20 +3167,41 @@ performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(0.5).checked_lerp(Fix::ZERO, Fix::MAX), Some(Fix::MAX / 2));
-assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);
-```
+assert_eq!(Fix::from_num(0.5).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MAX / 2);
+assert_eq!(Fix::from_num(1.5).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MAX);
+",
+                if_signed_unsigned! (
+                    $Signedness,
+                    "assert_eq!(Fix::from_num(-2.0).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MIN);
+assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::MIN);
+",
+                    "assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::ZERO);
+",
+                ),
+                "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn checked_lerp<RangeFrac>(
+                pub const fn saturating_lerp<RangeFrac>(
                     self,
                     start: $Self<RangeFrac>,
                     end: $Self<RangeFrac>,
-                ) -> Option<$Self<RangeFrac>> {
+                ) -> $Self<RangeFrac> {
                     match lerp::$Inner(self.to_bits(), start.to_bits(), end.to_bits(), Frac::U32) {
-                        (bits, false) => Some($Self::from_bits(bits)),
-                        (_, true) => None,
+                        (bits, false) => $Self::from_bits(bits),
+                        (_, true) => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() == (end.to_bits() < start.to_bits()) {
+                                $Self::MAX
+                            } else {
+                                $Self::MIN
+                            },
+                            if end.to_bits() < start.to_bits() {
+                                $Self::MIN
+                            } else {
+                                $Self::MAX
+                            },
+                        ),
                     }
                 }
             }
@@ -1292,8 +3209,8 @@ assert_eq!(Fix::from_num(1.5).checked_lerp(Fix::ZERO, Fix::MAX), None);
             if_signed! {
                 $Signedness;
                 comment! {
-                    "Saturating signum. Returns a number representing
-the sign of `self`, saturating on overflow.
+                    "Wrapping signum. Returns a number representing
+the sign of `self`, wrapping on overflow.
 
 Overflow can only occur
   * if the value is positive and the fixed-point number has zero
@@ -1307,36 +3224,27 @@ Overflow can only occur
 use fixed::types::extra::{U4, U", $nm1, ", U", $n, "};
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(5).saturating_signum(), 1);
-assert_eq!(Fix::ZERO.saturating_signum(), 0);
-assert_eq!(Fix::from_num(-5).saturating_signum(), -1);
+assert_eq!(Fix::from_num(5).wrapping_signum(), 1);
+assert_eq!(Fix::ZERO.wrapping_signum(), 0);
+assert_eq!(Fix::from_num(-5).wrapping_signum(), -1);
 
 type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
 type ZeroIntBits = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(OneIntBit::from_num(0.5).saturating_signum(), OneIntBit::MAX);
-assert_eq!(ZeroIntBits::from_num(0.25).saturating_signum(), ZeroIntBits::MAX);
-assert_eq!(ZeroIntBits::from_num(-0.5).saturating_signum(), ZeroIntBits::MIN);
+assert_eq!(OneIntBit::from_num(0.5).wrapping_signum(), -1);
+assert_eq!(ZeroIntBits::from_num(0.25).wrapping_signum(), 0);
+assert_eq!(ZeroIntBits::from_num(-0.5).wrapping_signum(), 0);
 ```
 ";
                     #[inline]
                     #[must_use]
-                    pub const fn saturating_signum(self) -> $Self<Frac> {
-                        match self.overflowing_signum() {
-                            (ans, false) => ans,
-                            (_, true) => {
-                                if self.is_negative() {
-                                    $Self::MIN
-                                } else {
-                                    $Self::MAX
-                                }
-                            }
-                        }
+                    pub const fn wrapping_signum(self) -> $Self<Frac> {
+                        self.overflowing_signum().0
                     }
                 }
             }
 
             comment! {
-                "Saturating multiplication. Returns the product, saturating on overflow.
+                "Wrapping multiplication. Returns the product, wrapping on overflow.
 
 # Examples
 
@@ -1344,32 +3252,22 @@ assert_eq!(ZeroIntBits::from_num(-0.5).saturating_signum(), ZeroIntBits::MIN);
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(3).saturating_mul(Fix::from_num(2)), Fix::from_num(6));
-assert_eq!(Fix::MAX.saturating_mul(Fix::from_num(2)), Fix::MAX);
+assert_eq!(Fix::from_num(3).wrapping_mul(Fix::from_num(2)), Fix::from_num(6));
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.wrapping_mul(Fix::from_num(4)), wrapped);
 ```
 ";
                 #[inline]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32) {
-                        (ans, false) => Self::from_bits(ans),
-                        (_, true) => {
-                            if_signed_unsigned!(
-                                $Signedness,
-                                if self.is_negative() != rhs.is_negative() {
-                                    Self::MIN
-                                } else {
-                                    Self::MAX
-                                },
-                                Self::MAX,
-                            )
-                        }
-                    }
+                pub const fn wrapping_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    let (ans, _) =
+                        arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32);
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Saturating division. Returns the quotient, saturating on overflow.
+                "Wrapping division. Returns the quotient, wrapping on overflow.
 
 # Panics
 
@@ -1381,35 +3279,26 @@ Panics if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let one_half = Fix::ONE / 2;
-assert_eq!(Fix::ONE.saturating_div(Fix::from_num(2)), one_half);
-assert_eq!(Fix::MAX.saturating_div(one_half), Fix::MAX);
+let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_num(3).wrapping_div(Fix::from_num(2)), one_point_5);
+let quarter = Fix::ONE / 4;
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.wrapping_div(quarter), wrapped);
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32) {
-                        (ans, false) => Self::from_bits(ans),
-                        (_, true) => {
-                            if_signed_unsigned!(
-                                $Signedness,
-                                if self.is_negative() != rhs.is_negative() {
-                                    Self::MIN
-                                } else {
-                                    Self::MAX
-                                },
-                                Self::MAX,
-                            )
-                        }
-                    }
+                pub const fn wrapping_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    let (ans, _) =
+                        arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32);
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Saturating reciprocal. Returns the reciprocal,
-saturating on overflow.
+                "Wrapping reciprocal. Returns the reciprocal,
+wrapping on overflow.
 
 # Panics
 
@@ -1422,39 +3311,20 @@ use fixed::types::extra::U", $nm1, ";
 use fixed::", stringify!($Self), ";
 // only one integer bit
 type Fix = ", stringify!($Self), "<U", $nm1, ">;
-assert_eq!(Fix::from_num(0.25).saturating_recip(), Fix::MAX);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-0.25).saturating_recip(), Fix::MIN);
-",
-                },
-                "```
+assert_eq!(Fix::from_num(0.25).wrapping_recip(), Fix::ZERO);
+```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn saturating_recip(self) -> $Self<Frac> {
-                    match self.overflowing_recip() {
-                        (ans, false) => ans,
-                        (_, true) => {
-                            if_signed_unsigned!(
-                                $Signedness,
-                                if self.is_negative() {
-                                    Self::MIN
-                                } else {
-                                    Self::MAX
-                                },
-                                Self::MAX,
-                            )
-                        }
-                    }
+                pub const fn wrapping_recip(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_recip();
+                    ans
                 }
             }
 
             comment! {
-                "Saturating Euclidean division. Returns the quotient,
-saturating on overflow.
+                "Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
 
 # Panics
 
@@ -1466,43 +3336,24 @@ Panics if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).saturating_div_euclid(Fix::from_num(2)), Fix::from_num(3));
-assert_eq!(Fix::MAX.saturating_div_euclid(Fix::from_num(0.25)), Fix::MAX);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).saturating_div_euclid(Fix::from_num(2)), Fix::from_num(-4));
-assert_eq!(Fix::MIN.saturating_div_euclid(Fix::from_num(0.25)), Fix::MIN);
-",
-                },
-                "```
+assert_eq!(Fix::from_num(7.5).wrapping_div_euclid(Fix::from_num(2)), Fix::from_num(3));
+let wrapped = Fix::MAX.wrapping_mul_int(4).round_to_zero();
+assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);
+```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match self.overflowing_div_euclid(rhs) {
-                        (val, false) => val,
-                        (_, true) => {
-                            if_signed_unsigned!(
-                                $Signedness,
-                                if self.is_negative() != rhs.is_negative() {
-                                    Self::MIN
-                                } else {
-                                    Self::MAX
-                                },
-                                Self::MAX,
-                            )
-                        }
-                    }
+                pub const fn wrapping_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_div_euclid(rhs).0
                 }
             }
 
             comment! {
-                "Saturating Euclidean division by an integer. Returns the quotient",
+                "Wrapping Euclidean division by an integer. Returns the quotient",
                 if_signed_unsigned!(
                     $Signedness,
-                    ", saturating on overflow.
+                    ", wrapping on overflow.
 
 Overflow can only occur when dividing the minimum value by &minus;1.",
                     ".
@@ -1521,12 +3372,13 @@ Panics if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).saturating_div_euclid_int(2), Fix::from_num(3));
+assert_eq!(Fix::from_num(7.5).wrapping_div_euclid_int(2), Fix::from_num(3));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).saturating_div_euclid_int(2), Fix::from_num(-4));
-assert_eq!(Fix::MIN.saturating_div_euclid_int(-1), Fix::MAX);
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_div_euclid_int(2), Fix::from_num(-4));
+let wrapped = Fix::MIN.round_to_zero();
+assert_eq!(Fix::MIN.wrapping_div_euclid_int(-1), wrapped);
 ",
                 },
                 "```
@@ -1534,30 +3386,16 @@ assert_eq!(Fix::MIN.saturating_div_euclid_int(-1), Fix::MAX);
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    // dividing by integer can never result in something < MIN
-                    match self.overflowing_div_euclid_int(rhs) {
-                        (val, false) => val,
-                        (_, true) => $Self::MAX,
-                    }
+                pub const fn wrapping_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    self.overflowing_div_euclid_int(rhs).0
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, saturating on overflow.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
-is representable; in these cases this method returns the correct result without
-overflow.
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, wrapping on overflow.
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
+The `a` and `b` parameters can have a fixed-point type like `self` but with a
+different number of fractional bits.
 
 # Examples
 
@@ -1566,65 +3404,37 @@ use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
 assert_eq!(
-    Fix::from_num(3).saturating_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    Fix::from_num(3).wrapping_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
     5
 );
-assert_eq!(Fix::ONE.saturating_add_prod(Fix::MAX, Fix::from_num(3)), Fix::MAX);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
 assert_eq!(
-    (-Fix::MAX).saturating_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Fix::MAX / 2
+    Fix::MAX.wrapping_add_prod(Fix::MAX, Fix::from_num(3)),
+    Fix::MAX.wrapping_mul_int(4)
 );
-"
-                },
-                "```
+```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
+                pub const fn wrapping_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
                     self,
                     a: $Self<AFrac>,
                     b: $Self<BFrac>,
                 ) -> $Self<Frac> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                    let (ans, _) = arith::$Inner::overflowing_mul_add(
                         a.to_bits(),
                         b.to_bits(),
                         self.to_bits(),
                         AFrac::I32 + BFrac::I32 - Frac::I32,
                     );
-                    if overflow {
-                        if_signed_unsigned!(
-                            $Signedness,
-                            if a.is_negative() != b.is_negative() {
-                                Self::MIN
-                            } else {
-                                Self::MAX
-                            },
-                            Self::MAX,
-                        )
-                    } else {
-                        Self::from_bits(ans)
-                    }
-                }
-            }
-
-            comment! {
-                "Saturating multiply and accumulate. Adds (`a` × `b`) to `self`,
-saturating on overflow.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a` × `b` would overflow on its
-own, but the final result `self` + `a` × `b` is representable; in these cases
-this method saves the correct result without overflow.
+                    Self::from_bits(ans)
+                }
+            }
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
+            comment! {
+                "Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`,
+wrapping on overflow.
+
+The `a` and `b` parameters can have a fixed-point type like
 `self` but with a different number of fractional bits.
 
 # Examples
@@ -1634,39 +3444,32 @@ use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
 let mut acc = Fix::from_num(3);
-acc.saturating_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+acc.wrapping_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
 assert_eq!(acc, 5);
 
-acc = Fix::MAX / 2;
-acc.saturating_mul_acc(Fix::MAX / 2, Fix::from_num(3));
-assert_eq!(acc, Fix::MAX);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "
-// MAX × 1.5 - MAX = MAX / 2, which does not overflow
-acc = -Fix::MAX;
-acc.saturating_mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(acc, Fix::MAX / 2);
-"
-                },
-                "```
+acc = Fix::MAX;
+acc.wrapping_mul_acc(Fix::MAX, Fix::from_num(3));
+assert_eq!(acc, Fix::MAX.wrapping_mul_int(4));
+```
 ";
                 #[inline]
-                pub const fn saturating_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
+                pub const fn wrapping_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
                     &mut self,
                     a: $Self<AFrac>,
                     b: $Self<BFrac>,
                 ) {
-                    *self = self.saturating_add_prod(a, b);
+                    *self = self.wrapping_add_prod(a, b);
                 }
             }
 
             comment! {
-                "Saturating remainder for Euclidean division by an integer. Returns the remainder",
+                "Wrapping remainder for Euclidean division by an integer. Returns the remainder",
                 if_signed_unsigned!(
                     $Signedness,
-                    ", saturating on overflow.",
+                    ", wrapping on overflow.
+
+Note that while remainder for Euclidean division cannot be negative,
+the wrapped value can be negative.",
                     ".
 
 Can never overflow for unsigned values.",
@@ -1683,13 +3486,13 @@ Panics if the divisor is zero.
 use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).saturating_rem_euclid_int(2), Fix::from_num(1.5));
+assert_eq!(Fix::from_num(7.5).wrapping_rem_euclid_int(2), Fix::from_num(1.5));
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(2), Fix::from_num(0.5));
-// -8 ≤ Fix < 8, so the answer 12.5 saturates
-assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(20), Fix::MAX);
+                    "assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(2), Fix::from_num(0.5));
+// -8 ≤ Fix < 8, so the answer 12.5 wraps to -3.5
+assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5));
 ",
                 },
                 "```
@@ -1697,16 +3500,13 @@ assert_eq!(Fix::from_num(-7.5).saturating_rem_euclid_int(20), Fix::MAX);
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn saturating_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    match self.overflowing_rem_euclid_int(rhs) {
-                        (val, false) => val,
-                        (_, true) => $Self::MAX,
-                    }
+                pub const fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    self.overflowing_rem_euclid_int(rhs).0
                 }
             }
 
             comment! {
-                "Returns the square root, saturating on overflow.",
+                "Returns the square root, wrapping on overflow.",
                 if_unsigned_else_empty_str! {
                     $Signedness;
                     " Can never overflow for unsigned numbers."
@@ -1723,7 +3523,7 @@ result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
                     $Signedness;
                     "Overflow can only occur when there are no integer bits and
 the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns [`MAX`][Self::MAX] for an input value ≥&nbsp;0.25.
+In this case, the method returns the wrapped answer for an input value ≥&nbsp;0.25.
 
 "
                 },
@@ -1743,87 +3543,301 @@ use fixed::types::extra::",
                 ";
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(2).saturating_sqrt(), Fix::SQRT_2);
+assert_eq!(Fix::from_num(2).wrapping_sqrt(), Fix::SQRT_2);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
 type AllFrac = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).saturating_sqrt(), AllFrac::MAX);
+assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));
 ",
                 },
                 "```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_sqrt(self) -> Self {
-                    match self.overflowing_sqrt() {
-                        (val, false) => val,
-                        (_, true) => Self::MAX,
-                    }
+                pub const fn wrapping_sqrt(self) -> Self {
+                    self.overflowing_sqrt().0
                 }
             }
 
+
             comment! {
-                "Linear interpolation between `start` and `end`, saturating on
-overflow.
+                "Wrapping reciprocal square root. Returns the reciprocal square
+root, wrapping on overflow.
 
-The interpolated value is
-`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
-is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
-linear interpolation for all other values of `self`. Linear extrapolation is
-performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+See [`rsqrt`][Self::rsqrt] for details.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
 
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
+use fixed::types::extra::{U4, U", $nm1, "};
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(0.5).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MAX / 2);
-assert_eq!(Fix::from_num(1.5).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MAX);
+assert_eq!(Fix::from_num(4).wrapping_rsqrt(), Fix::from_num(0.5));
+// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Small::from_num(0.25).wrapping_rsqrt(), Small::ZERO);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_rsqrt(self) -> Self {
+                    self.overflowing_rsqrt().0
+                }
+            }
+
+            comment! {
+                "Wrapping base-2 exponential. Returns the base-2 exponential, wrapping on
+overflow.
+
+See [`exp2`][Self::exp2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(2).wrapping_exp2(), 4);
+assert_eq!(Fix::from_num(4).wrapping_exp2(), 0);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp2(self) -> Self {
+                    let (ans, _) = self.overflowing_exp2();
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping exponential. Returns the exponential, wrapping on
+overflow.
+
+See [`exp`][Self::exp] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_exp(), 1);
+// e^4 ≈ 54.598 wraps to 54.598 &minus; 48 = 6.598
+let wrapped = Fix::from_num(4).wrapping_exp();
+assert!(wrapped.dist(Fix::from_num(6.598)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp(self) -> Self {
+                    let (ans, _) = self.overflowing_exp();
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping exponential minus one. Returns the exponential minus one, wrapping on
+overflow.
+
+See [`exp_m1`][Self::exp_m1] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_exp_m1(), 0);
+// e^4 &minus; 1 ≈ 53.598 wraps to 53.598 &minus; 48 = 5.598
+let wrapped = Fix::from_num(4).wrapping_exp_m1();
+assert!(wrapped.dist(Fix::from_num(5.598)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_exp_m1(self) -> Self {
+                    let (ans, _) = self.overflowing_exp_m1();
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping integer power. Returns the value raised to the integer power `n`,
+wrapping on overflow.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(1.5).wrapping_powi(2), 2.25);
+assert_eq!(Fix::from_num(2).wrapping_powi(4), 0);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_powi(self, n: i32) -> Self {
+                    self.overflowing_powi(n).0
+                }
+            }
+
+            comment! {
+                "Wrapping power. Returns the value raised to the fixed-point power `exp`,
+wrapping on overflow.
+
+See [`powf`][Self::powf] for details.
 ",
-                if_signed_unsigned! (
-                    $Signedness,
-                    "assert_eq!(Fix::from_num(-2.0).saturating_lerp(Fix::ZERO, Fix::MAX), Fix::MIN);
-assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::MIN);
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+# Panics
+
+Panics if `self` is negative, or if `self` is zero and `exp` is negative.
 ",
-                    "assert_eq!(Fix::from_num(3.0).saturating_lerp(Fix::MAX, Fix::ZERO), Fix::ZERO);
+                },
+                "
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(4).wrapping_powf(Fix::from_num(0.5)), 2);
+assert_eq!(Fix::from_num(4).wrapping_powf(Fix::from_num(2)), 0);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_powf(self, exp: $Self<Frac>) -> $Self<Frac> {
+                    self.overflowing_powf(exp).0
+                }
+            }
+
+            comment! {
+                "Wrapping cube root. Returns the cube root, wrapping on overflow.
+
+See [`cbrt`][Self::cbrt] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.015625).wrapping_cbrt(), 0.25);
 ",
-                ),
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// the cube root of MAX rounds to 1, which wraps to 0
+assert_eq!(Fix::MAX.wrapping_cbrt(), 0);
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cbrt(self) -> Self {
+                    self.overflowing_cbrt().0
+                }
+            }
+
+            comment! {
+                "Wrapping <i>n</i>th root. Returns the <i>n</i>th root, wrapping on overflow.
+
+See [`nth_root`][Self::nth_root] for details.
+
+# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if `n` is even and the number is negative",
+                },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.0625).wrapping_nth_root(2), 0.25);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "// the fourth root of MAX rounds to 1, which wraps to 0
+assert_eq!(Fix::MAX.wrapping_nth_root(4), 0);
+",
+                },
                 "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_nth_root(self, n: u32) -> Self {
+                    self.overflowing_nth_root(n).0
+                }
+            }
+
+            comment! {
+                "Linear interpolation between `start` and `end`, wrapping on
+overflow.
+
+The interpolated value is
+`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
+is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
+linear interpolation for all other values of `self`. Linear extrapolation is
+performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(0.5).wrapping_lerp(Fix::ZERO, Fix::MAX), Fix::MAX / 2);
+assert_eq!(
+    Fix::from_num(1.5).wrapping_lerp(Fix::ZERO, Fix::MAX),
+    Fix::MAX.wrapping_add(Fix::MAX / 2)
+);
+```
 ";
                 #[inline]
                 #[must_use]
-                pub const fn saturating_lerp<RangeFrac>(
+                pub const fn wrapping_lerp<RangeFrac>(
                     self,
                     start: $Self<RangeFrac>,
                     end: $Self<RangeFrac>,
                 ) -> $Self<RangeFrac> {
-                    match lerp::$Inner(self.to_bits(), start.to_bits(), end.to_bits(), Frac::U32) {
-                        (bits, false) => $Self::from_bits(bits),
-                        (_, true) => if_signed_unsigned!(
-                            $Signedness,
-                            if self.is_negative() == (end.to_bits() < start.to_bits()) {
-                                $Self::MAX
-                            } else {
-                                $Self::MIN
-                            },
-                            if end.to_bits() < start.to_bits() {
-                                $Self::MIN
-                            } else {
-                                $Self::MAX
-                            },
-                        ),
-                    }
+                    let (bits, _) =
+                        lerp::$Inner(self.to_bits(), start.to_bits(), end.to_bits(), Frac::U32);
+                    $Self::from_bits(bits)
                 }
             }
 
             if_signed! {
                 $Signedness;
                 comment! {
-                    "Wrapping signum. Returns a number representing
-the sign of `self`, wrapping on overflow.
+                    "Unwrapped signum. Returns a number representing
+the sign of `self`, panicking on overflow.
 
 Overflow can only occur
   * if the value is positive and the fixed-point number has zero
@@ -1831,33 +3845,47 @@ Overflow can only occur
   * if the value is negative and the fixed-point number has zero
     integer bits, such that it cannot hold the value &minus;1.
 
+# Panics
+
+Panics if the result does not fit.
+
 # Examples
 
 ```rust
-use fixed::types::extra::{U4, U", $nm1, ", U", $n, "};
+use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(5).wrapping_signum(), 1);
-assert_eq!(Fix::ZERO.wrapping_signum(), 0);
-assert_eq!(Fix::from_num(-5).wrapping_signum(), -1);
+assert_eq!(Fix::from_num(5).unwrapped_signum(), 1);
+assert_eq!(Fix::ZERO.unwrapped_signum(), 0);
+assert_eq!(Fix::from_num(-5).unwrapped_signum(), -1);
+```
 
+The following panics because of overflow.
+
+```should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
 type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
-type ZeroIntBits = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(OneIntBit::from_num(0.5).wrapping_signum(), -1);
-assert_eq!(ZeroIntBits::from_num(0.25).wrapping_signum(), 0);
-assert_eq!(ZeroIntBits::from_num(-0.5).wrapping_signum(), 0);
+let _overflow = OneIntBit::from_num(0.5).unwrapped_signum();
 ```
 ";
                     #[inline]
+                    #[track_caller]
                     #[must_use]
-                    pub const fn wrapping_signum(self) -> $Self<Frac> {
-                        self.overflowing_signum().0
+                    pub const fn unwrapped_signum(self) -> $Self<Frac> {
+                        let (ans, overflow) = self.overflowing_signum();
+                        assert!(!overflow, "overflow");
+                        ans
                     }
                 }
             }
 
             comment! {
-                "Wrapping multiplication. Returns the product, wrapping on overflow.
+                "Unwrapped multiplication. Returns the product, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
 
 # Examples
 
@@ -1865,119 +3893,74 @@ assert_eq!(ZeroIntBits::from_num(-0.5).wrapping_signum(), 0);
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(3).wrapping_mul(Fix::from_num(2)), Fix::from_num(6));
-let wrapped = Fix::from_bits(!0 << 2);
-assert_eq!(Fix::MAX.wrapping_mul(Fix::from_num(4)), wrapped);
+assert_eq!(Fix::from_num(3).unwrapped_mul(Fix::from_num(2)), Fix::from_num(6));
 ```
-";
-                #[inline]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    let (ans, _) =
-                        arith::$Inner::overflowing_mul(self.to_bits(), rhs.to_bits(), Frac::U32);
-                    Self::from_bits(ans)
-                }
-            }
 
-            comment! {
-                "Wrapping division. Returns the quotient, wrapping on overflow.
-
-# Panics
-
-Panics if the divisor is zero.
-
-# Examples
+The following panics because of overflow.
 
-```rust
+```should_panic
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
-assert_eq!(Fix::from_num(3).wrapping_div(Fix::from_num(2)), one_point_5);
-let quarter = Fix::ONE / 4;
-let wrapped = Fix::from_bits(!0 << 2);
-assert_eq!(Fix::MAX.wrapping_div(quarter), wrapped);
+let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    let (ans, _) =
-                        arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32);
-                    Self::from_bits(ans)
+                pub const fn unwrapped_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.checked_mul(rhs) {
+                        Some(s) => s,
+                        None => panic!("overflow"),
+                    }
                 }
             }
 
             comment! {
-                "Wrapping reciprocal. Returns the reciprocal,
-wrapping on overflow.
+                "Unwrapped division. Returns the quotient, panicking on overflow.
 
 # Panics
 
-Panics if the fixed-point number is zero.
+Panics if the divisor is zero or if the division results in overflow.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U", $nm1, ";
+use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
-// only one integer bit
-type Fix = ", stringify!($Self), "<U", $nm1, ">;
-assert_eq!(Fix::from_num(0.25).wrapping_recip(), Fix::ZERO);
+type Fix = ", stringify!($Self), "<U4>;
+let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_num(3).unwrapped_div(Fix::from_num(2)), one_point_5);
 ```
-";
-                #[inline]
-                #[track_caller]
-                #[must_use]
-                pub const fn wrapping_recip(self) -> $Self<Frac> {
-                    let (ans, _) = self.overflowing_recip();
-                    ans
-                }
-            }
 
-            comment! {
-                "Wrapping Euclidean division. Returns the quotient, wrapping on overflow.
-
-# Panics
-
-Panics if the divisor is zero.
-
-# Examples
+The following panics because of overflow.
 
-```rust
+```should_panic
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).wrapping_div_euclid(Fix::from_num(2)), Fix::from_num(3));
-let wrapped = Fix::MAX.wrapping_mul_int(4).round_to_zero();
-assert_eq!(Fix::MAX.wrapping_div_euclid(Fix::from_num(0.25)), wrapped);
+let quarter = Fix::ONE / 4;
+let _overflow = Fix::MAX.unwrapped_div(quarter);
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    self.overflowing_div_euclid(rhs).0
+                pub const fn unwrapped_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_div(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
                 }
             }
 
             comment! {
-                "Wrapping Euclidean division by an integer. Returns the quotient",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", wrapping on overflow.
-
-Overflow can only occur when dividing the minimum value by &minus;1.",
-                    ".
-
-Can never overflow for unsigned values.",
-                ),
-                "
+                "Unwrapped reciprocal. Returns the reciprocal,
+panicking on overflow.
 
 # Panics
 
-Panics if the divisor is zero.
+Panics if the fixed-point number is zero or on overflow.
 
 # Examples
 
@@ -1985,30 +3968,26 @@ Panics if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).wrapping_div_euclid_int(2), Fix::from_num(3));
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).wrapping_div_euclid_int(2), Fix::from_num(-4));
-let wrapped = Fix::MIN.round_to_zero();
-assert_eq!(Fix::MIN.wrapping_div_euclid_int(-1), wrapped);
-",
-                },
-                "```
+assert_eq!(Fix::from_num(0.25).unwrapped_recip(), Fix::from_num(4));
+```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    self.overflowing_div_euclid_int(rhs).0
+                #[must_use]
+                pub const fn unwrapped_recip(self) -> $Self<Frac> {
+                    match self.overflowing_recip() {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, wrapping on overflow.
+                "Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
 
-The `a` and `b` parameters can have a fixed-point type like `self` but with a
-different number of fractional bits.
+# Panics
+
+Panics if the divisor is zero or if the division results in overflow.
 
 # Examples
 
@@ -2016,173 +3995,168 @@ different number of fractional bits.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(
-    Fix::from_num(3).wrapping_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    5
-);
-assert_eq!(
-    Fix::MAX.wrapping_add_prod(Fix::MAX, Fix::from_num(3)),
-    Fix::MAX.wrapping_mul_int(4)
-);
+assert_eq!(Fix::from_num(7.5).unwrapped_div_euclid(Fix::from_num(2)), Fix::from_num(3));
 ```
-";
-                #[inline]
-                #[must_use]
-                pub const fn wrapping_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) -> $Self<Frac> {
-                    let (ans, _) = arith::$Inner::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
-                        self.to_bits(),
-                        AFrac::I32 + BFrac::I32 - Frac::I32,
-                    );
-                    Self::from_bits(ans)
-                }
-            }
-
-            comment! {
-                "Wrapping multiply and accumulate. Adds (`a` × `b`) to `self`,
-wrapping on overflow.
 
-The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
-
-# Examples
+The following panics because of overflow.
 
-```rust
+```should_panic
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let mut acc = Fix::from_num(3);
-acc.wrapping_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
-assert_eq!(acc, 5);
-
-acc = Fix::MAX;
-acc.wrapping_mul_acc(Fix::MAX, Fix::from_num(3));
-assert_eq!(acc, Fix::MAX.wrapping_mul_int(4));
+let _overflow = Fix::MAX.unwrapped_div_euclid(Fix::from_num(0.25));
 ```
 ";
                 #[inline]
-                pub const fn wrapping_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    &mut self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) {
-                    *self = self.wrapping_add_prod(a, b);
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
+                    match self.overflowing_div_euclid(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
+                    }
                 }
             }
 
             comment! {
-                "Wrapping remainder for Euclidean division by an integer. Returns the remainder",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", wrapping on overflow.
+                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, panicking on overflow.
 
-Note that while remainder for Euclidean division cannot be negative,
-the wrapped value can be negative.",
-                    ".
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
+overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
+is representable; in these cases this method returns the correct result without
+overflow.
 
-Can never overflow for unsigned values.",
-                ),
-                "
+",
+                },
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
 
 # Panics
 
-Panics if the divisor is zero.
+Panics if the result does not fit.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U", $nm4, ";
+use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).wrapping_rem_euclid_int(2), Fix::from_num(1.5));
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(
+    Fix::from_num(3).unwrapped_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
+    5
+);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(2), Fix::from_num(0.5));
-// -8 ≤ Fix < 8, so the answer 12.5 wraps to -3.5
-assert_eq!(Fix::from_num(-7.5).wrapping_rem_euclid_int(20), Fix::from_num(-3.5));
-",
+                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
+assert_eq!(
+    (-Fix::MAX).unwrapped_add_prod(Fix::MAX, Fix::from_num(1.5)),
+    Fix::MAX / 2
+);
+"
                 },
                 "```
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);
+```
 ";
                 #[inline]
+                #[must_use]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn wrapping_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    self.overflowing_rem_euclid_int(rhs).0
+                pub const fn unwrapped_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) -> $Self<Frac> {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
+                        a.to_bits(),
+                        b.to_bits(),
+                        self.to_bits(),
+                        AFrac::I32 + BFrac::I32 - Frac::I32,
+                    );
+                    assert!(!overflow, "overflow");
+                    Self::from_bits(ans)
                 }
             }
 
             comment! {
-                "Returns the square root, wrapping on overflow.",
-                if_unsigned_else_empty_str! {
-                    $Signedness;
-                    " Can never overflow for unsigned numbers."
-                },
-                "
-
-This method uses an iterative method, with up to ", $n, " iterations for
- [`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+                "Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`,
+panicking on overflow.
 
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "Overflow can only occur when there are no integer bits and
-the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method returns the wrapped answer for an input value ≥&nbsp;0.25.
+                    "For some cases, the product `a` × `b` would overflow on its
+own, but the final result `self` + `a` × `b` is representable; in these cases
+this method saves the correct result without overflow.
 
-"
+",
                 },
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "# Panics
+                "The `a` and `b` parameters can have a fixed-point type like
+`self` but with a different number of fractional bits.
 
-Panics if the number is negative.
+# Panics
 
-"
-                },
-                "# Examples
+Panics if the result does not fit.
+
+# Examples
 
 ```rust
-use fixed::types::extra::",
-                if_signed_unsigned!($Signedness, concat!("{U4, U", $n, "}"), "U4"),
-                ";
+use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(2).wrapping_sqrt(), Fix::SQRT_2);
+let mut acc = Fix::from_num(3);
+acc.unwrapped_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
+assert_eq!(acc, 5);
 ",
                 if_signed_else_empty_str! {
                     $Signedness;
                     "
-type AllFrac = ", stringify!($Self), "<U", $n, ">;
-assert_eq!(AllFrac::from_num(0.25).wrapping_sqrt(), AllFrac::from_num(-0.5));
-",
+// MAX × 1.5 - MAX = MAX / 2, which does not overflow
+acc = -Fix::MAX;
+acc.unwrapped_mul_acc(Fix::MAX, Fix::from_num(1.5));
+assert_eq!(acc, Fix::MAX / 2);
+"
                 },
                 "```
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut acc = Fix::DELTA;
+acc.unwrapped_mul_acc(Fix::MAX, Fix::ONE);
+```
 ";
                 #[inline]
-                #[must_use]
-                pub const fn wrapping_sqrt(self) -> Self {
-                    self.overflowing_sqrt().0
+                #[track_caller]
+                pub const fn unwrapped_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
+                    &mut self,
+                    a: $Self<AFrac>,
+                    b: $Self<BFrac>,
+                ) {
+                    *self = self.unwrapped_add_prod(a, b);
                 }
             }
 
             comment! {
-                "Linear interpolation between `start` and `end`, wrapping on
-overflow.
+                "Unwrapped fixed-point remainder for division by an integer.
+Returns the remainder, panicking if the divisor is zero.
 
-The interpolated value is
-`start`&nbsp;+&nbsp;`self`&nbsp;×&nbsp;(`end`&nbsp;&minus;&nbsp;`start`). This
-is `start` when `self`&nbsp;=&nbsp;0, `end` when `self`&nbsp;=&nbsp;1, and
-linear interpolation for all other values of `self`. Linear extrapolation is
-performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+# Panics
+
+Panics if the divisor is zero.
 
 # Examples
 
@@ -2190,41 +4164,50 @@ performed if `self` is not in the range 0&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(0.5).wrapping_lerp(Fix::ZERO, Fix::MAX), Fix::MAX / 2);
-assert_eq!(
-    Fix::from_num(1.5).wrapping_lerp(Fix::ZERO, Fix::MAX),
-    Fix::MAX.wrapping_add(Fix::MAX / 2)
-);
+assert_eq!(Fix::from_num(3.75).unwrapped_rem_int(2), Fix::from_num(1.75));
+```
+
+The following panics because the divisor is zero.
+
+```should_panic
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let _divisor_is_zero = Fix::from_num(3.75).unwrapped_rem_int(0);
 ```
 ";
                 #[inline]
-                #[must_use]
-                pub const fn wrapping_lerp<RangeFrac>(
-                    self,
-                    start: $Self<RangeFrac>,
-                    end: $Self<RangeFrac>,
-                ) -> $Self<RangeFrac> {
-                    let (bits, _) =
-                        lerp::$Inner(self.to_bits(), start.to_bits(), end.to_bits(), Frac::U32);
-                    $Self::from_bits(bits)
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_rem_int(self, rhs: $Inner) -> $Self<Frac> {
+                    match self.checked_rem_int(rhs) {
+                        Some(ans) => ans,
+                        None => panic!("division by zero"),
+                    }
                 }
             }
 
-            if_signed! {
-                $Signedness;
-                comment! {
-                    "Unwrapped signum. Returns a number representing
-the sign of `self`, panicking on overflow.
+            comment! {
+                "Unwrapped Euclidean division by an integer. Returns the quotient",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", panicking on overflow.
 
-Overflow can only occur
-  * if the value is positive and the fixed-point number has zero
-    or one integer bits such that it cannot hold the value 1.
-  * if the value is negative and the fixed-point number has zero
-    integer bits, such that it cannot hold the value &minus;1.
+Overflow can only occur when dividing the minimum value by &minus;1.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
 
 # Panics
 
-Panics if the result does not fit.
+Panics if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
 
 # Examples
 
@@ -2232,112 +4215,179 @@ Panics if the result does not fit.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(5).unwrapped_signum(), 1);
-assert_eq!(Fix::ZERO.unwrapped_signum(), 0);
-assert_eq!(Fix::from_num(-5).unwrapped_signum(), -1);
+assert_eq!(Fix::from_num(7.5).unwrapped_div_euclid_int(2), Fix::from_num(3));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).unwrapped_div_euclid_int(2), Fix::from_num(-4));
 ```
 
 The following panics because of overflow.
 
 ```should_panic
-use fixed::types::extra::U", $nm1, ";
+use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
-type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
-let _overflow = OneIntBit::from_num(0.5).unwrapped_signum();
-```
+type Fix = ", stringify!($Self), "<U4>;
+let _overflow = Fix::MIN.unwrapped_div_euclid_int(-1);
+",
+                },
+                "```
 ";
-                    #[inline]
-                    #[track_caller]
-                    #[must_use]
-                    pub const fn unwrapped_signum(self) -> $Self<Frac> {
-                        let (ans, overflow) = self.overflowing_signum();
-                        assert!(!overflow, "overflow");
-                        ans
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn unwrapped_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    match self.overflowing_div_euclid_int(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
                     }
                 }
             }
 
             comment! {
-                "Unwrapped multiplication. Returns the product, panicking on overflow.
+                "Unwrapped remainder for Euclidean division by an integer. Returns the remainder",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", panicking on overflow.
+
+Note that while remainder for Euclidean division cannot be negative,
+the wrapped value can be negative.",
+                    ".
+
+Can never overflow for unsigned values.",
+                ),
+                "
 
 # Panics
 
-Panics if the result does not fit.
+Panics if the divisor is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    " or if the division results in overflow",
+                },
+                ".
 
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(3).unwrapped_mul(Fix::from_num(2)), Fix::from_num(6));
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(7.5).unwrapped_rem_euclid_int(2), Fix::from_num(1.5));
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::from_num(-7.5).unwrapped_rem_euclid_int(2), Fix::from_num(0.5));
 ```
 
 The following panics because of overflow.
 
 ```should_panic
-use fixed::types::extra::U4;
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let _overflow = Fix::MAX.unwrapped_mul(Fix::from_num(4));
-```
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+// -8 ≤ Fix < 8, so the answer 12.5 overflows
+let _overflow = Fix::from_num(-7.5).unwrapped_rem_euclid_int(20);
+",
+                },
+                "```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_mul(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match self.checked_mul(rhs) {
-                        Some(s) => s,
-                        None => panic!("overflow"),
+                pub const fn unwrapped_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
+                    match self.overflowing_rem_euclid_int(rhs) {
+                        (_, true) => panic!("overflow"),
+                        (ans, false) => ans,
                     }
                 }
             }
 
             comment! {
-                "Unwrapped division. Returns the quotient, panicking on overflow.
+                "Returns the square root",
+                if_signed_unsigned!(
+                    $Signedness,
+                    ", panicking for negative numbers and on overflow.",
+                    ". Can never overflow for unsigned numbers.",
+                ),
+                "
 
-# Panics
+This method uses an iterative method, with up to ", $n, " iterations for
+[`", stringify!($Self), "`]. The result is rounded down, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
+result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
 
-Panics if the divisor is zero or if the division results in overflow.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "Overflow can only occur when there are no integer bits and
+the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
+In this case, the method panics for an input value ≥&nbsp;0.25.
 
-# Examples
+"
+                },
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "# Panics
+
+Panics if the number is negative and on overflow.
+
+"
+                },
+                "# Examples
 
 ```rust
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
-assert_eq!(Fix::from_num(3).unwrapped_div(Fix::from_num(2)), one_point_5);
+assert_eq!(Fix::from_num(2).unwrapped_sqrt(), Fix::SQRT_2);
 ```
-
-The following panics because of overflow.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+The following panics because the input value is negative.
 
 ```should_panic
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let quarter = Fix::ONE / 4;
-let _overflow = Fix::MAX.unwrapped_div(quarter);
+let _sqrt_neg = Fix::from_num(-1).unwrapped_sqrt();
 ```
-";
+
+The following panics because of overflow.
+
+```should_panic
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type AllFrac = ", stringify!($Self), "<U", $n, ">;
+let _overflow = AllFrac::from_num(0.25).unwrapped_sqrt();
+```
+",
+                };
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_div(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match self.overflowing_div(rhs) {
+                #[must_use]
+                pub const fn unwrapped_sqrt(self) -> Self {
+                    match self.overflowing_sqrt() {
+                        (val, false) => val,
                         (_, true) => panic!("overflow"),
-                        (ans, false) => ans,
                     }
                 }
             }
 
+
             comment! {
-                "Unwrapped reciprocal. Returns the reciprocal,
-panicking on overflow.
+                "Unwrapped reciprocal square root. Returns the reciprocal
+square root, panicking on overflow.
+
+See [`rsqrt`][Self::rsqrt] for details.
 
 # Panics
 
-Panics if the fixed-point number is zero or on overflow.
+Panics if the number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ", or if the result does not fit.
 
 # Examples
 
@@ -2345,71 +4395,74 @@ Panics if the fixed-point number is zero or on overflow.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(0.25).unwrapped_recip(), Fix::from_num(4));
+assert_eq!(Fix::from_num(4).unwrapped_rsqrt(), Fix::from_num(0.5));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+let _overflow = Small::from_num(0.25).unwrapped_rsqrt();
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn unwrapped_recip(self) -> $Self<Frac> {
-                    match self.overflowing_recip() {
+                pub const fn unwrapped_rsqrt(self) -> Self {
+                    match self.overflowing_rsqrt() {
+                        (val, false) => val,
                         (_, true) => panic!("overflow"),
-                        (ans, false) => ans,
                     }
                 }
             }
 
             comment! {
-                "Unwrapped Euclidean division. Returns the quotient, panicking on overflow.
+                "Unwrapped base-2 exponential. Returns the base-2 exponential, panicking on
+overflow.
+
+See [`exp2`][Self::exp2] for details.
 
 # Panics
 
-Panics if the divisor is zero or if the division results in overflow.
+Panics if the result does not fit.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).unwrapped_div_euclid(Fix::from_num(2)), Fix::from_num(3));
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(2).unwrapped_exp2(), 4);
 ```
 
 The following panics because of overflow.
 
-```should_panic
-use fixed::types::extra::U4;
+```rust,should_panic
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let _overflow = Fix::MAX.unwrapped_div_euclid(Fix::from_num(0.25));
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let _overflow = Fix::from_num(4).unwrapped_exp2();
 ```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_div_euclid(self, rhs: $Self<Frac>) -> $Self<Frac> {
-                    match self.overflowing_div_euclid(rhs) {
-                        (_, true) => panic!("overflow"),
+                #[must_use]
+                pub const fn unwrapped_exp2(self) -> Self {
+                    match self.overflowing_exp2() {
                         (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
                     }
                 }
             }
 
             comment! {
-                "Adds `self` to the product `a`&nbsp;×&nbsp;`b`, panicking on overflow.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a`&nbsp;×&nbsp;`b` would
-overflow on its own, but the final result `self`&nbsp;+&nbsp;`a`&nbsp;×&nbsp;`b`
-is representable; in these cases this method returns the correct result without
+                "Unwrapped exponential. Returns the exponential, panicking on
 overflow.
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
+See [`exp`][Self::exp] for details.
 
 # Panics
 
@@ -2418,68 +4471,37 @@ Panics if the result does not fit.
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(
-    Fix::from_num(3).unwrapped_add_prod(Fix::from_num(4), Fix::from_num(0.5)),
-    5
-);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "// -MAX + MAX × 1.5 = MAX / 2, which does not overflow
-assert_eq!(
-    (-Fix::MAX).unwrapped_add_prod(Fix::MAX, Fix::from_num(1.5)),
-    Fix::MAX / 2
-);
-"
-                },
-                "```
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_exp(), 1);
+```
 
 The following panics because of overflow.
 
-```should_panic
-use fixed::types::extra::U4;
+```rust,should_panic
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let _overflow = Fix::DELTA.unwrapped_add_prod(Fix::MAX, Fix::ONE);
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let _overflow = Fix::from_num(4).unwrapped_exp();
 ```
 ";
                 #[inline]
-                #[must_use]
                 #[track_caller]
-                pub const fn unwrapped_add_prod<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) -> $Self<Frac> {
-                    let (ans, overflow) = arith::$Inner::overflowing_mul_add(
-                        a.to_bits(),
-                        b.to_bits(),
-                        self.to_bits(),
-                        AFrac::I32 + BFrac::I32 - Frac::I32,
-                    );
-                    assert!(!overflow, "overflow");
-                    Self::from_bits(ans)
+                #[must_use]
+                pub const fn unwrapped_exp(self) -> Self {
+                    match self.overflowing_exp() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
                 }
             }
 
             comment! {
-                "Unwrapped multiply and accumulate. Adds (`a` × `b`) to `self`,
-panicking on overflow.
-
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "For some cases, the product `a` × `b` would overflow on its
-own, but the final result `self` + `a` × `b` is representable; in these cases
-this method saves the correct result without overflow.
+                "Unwrapped exponential minus one. Returns the exponential minus one, panicking on
+overflow.
 
-",
-                },
-                "The `a` and `b` parameters can have a fixed-point type like
-`self` but with a different number of fractional bits.
+See [`exp_m1`][Self::exp_m1] for details.
 
 # Panics
 
@@ -2488,52 +4510,41 @@ Panics if the result does not fit.
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let mut acc = Fix::from_num(3);
-acc.unwrapped_mul_acc(Fix::from_num(4), Fix::from_num(0.5));
-assert_eq!(acc, 5);
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "
-// MAX × 1.5 - MAX = MAX / 2, which does not overflow
-acc = -Fix::MAX;
-acc.unwrapped_mul_acc(Fix::MAX, Fix::from_num(1.5));
-assert_eq!(acc, Fix::MAX / 2);
-"
-                },
-                "```
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_exp_m1(), 0);
+```
 
 The following panics because of overflow.
 
-```should_panic
-use fixed::types::extra::U4;
+```rust,should_panic
+use fixed::types::extra::U", $nm4, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let mut acc = Fix::DELTA;
-acc.unwrapped_mul_acc(Fix::MAX, Fix::ONE);
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let _overflow = Fix::from_num(4).unwrapped_exp_m1();
 ```
 ";
                 #[inline]
                 #[track_caller]
-                pub const fn unwrapped_mul_acc<AFrac: $LeEqU, BFrac: $LeEqU>(
-                    &mut self,
-                    a: $Self<AFrac>,
-                    b: $Self<BFrac>,
-                ) {
-                    *self = self.unwrapped_add_prod(a, b);
+                #[must_use]
+                pub const fn unwrapped_exp_m1(self) -> Self {
+                    match self.overflowing_exp_m1() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
                 }
             }
 
             comment! {
-                "Unwrapped fixed-point remainder for division by an integer.
-Returns the remainder, panicking if the divisor is zero.
+                "Unwrapped integer power. Returns the value raised to the integer power `n`,
+panicking on overflow.
+
+See [`powi`][Self::powi] for details.
 
 # Panics
 
-Panics if the divisor is zero.
+Panics if `self` is zero and `n` is negative, or if the result does not fit.
 
 # Examples
 
@@ -2541,50 +4552,45 @@ Panics if the divisor is zero.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(3.75).unwrapped_rem_int(2), Fix::from_num(1.75));
+assert_eq!(Fix::from_num(1.5).unwrapped_powi(3), 3.375);
 ```
 
-The following panics because the divisor is zero.
+The following panics because of overflow.
 
 ```should_panic
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let _divisor_is_zero = Fix::from_num(3.75).unwrapped_rem_int(0);
+let _overflow = Fix::MAX.unwrapped_powi(2);
 ```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_rem_int(self, rhs: $Inner) -> $Self<Frac> {
-                    match self.checked_rem_int(rhs) {
-                        Some(ans) => ans,
+                #[must_use]
+                pub const fn unwrapped_powi(self, n: i32) -> Self {
+                    match pow::$Inner::powi(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
                         None => panic!("division by zero"),
                     }
                 }
             }
 
             comment! {
-                "Unwrapped Euclidean division by an integer. Returns the quotient",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", panicking on overflow.
-
-Overflow can only occur when dividing the minimum value by &minus;1.",
-                    ".
+                "Unwrapped power. Returns the value raised to the fixed-point power `exp`,
+panicking on overflow.
 
-Can never overflow for unsigned values.",
-                ),
-                "
+See [`powf`][Self::powf] for details.
 
 # Panics
 
-Panics if the divisor is zero",
+Panics ",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    " or if the division results in overflow",
+                    "if `self` is negative, if `self` is zero and `exp` is negative,
+or ",
                 },
-                ".
+                "if the result does not fit.
 
 # Examples
 
@@ -2592,11 +4598,7 @@ Panics if the divisor is zero",
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(7.5).unwrapped_div_euclid_int(2), Fix::from_num(3));
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).unwrapped_div_euclid_int(2), Fix::from_num(-4));
+assert_eq!(Fix::from_num(4).unwrapped_powf(Fix::from_num(0.5)), 2);
 ```
 
 The following panics because of overflow.
@@ -2605,131 +4607,86 @@ The following panics because of overflow.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let _overflow = Fix::MIN.unwrapped_div_euclid_int(-1);
-",
-                },
-                "```
+let _overflow = Fix::MAX.unwrapped_powf(Fix::from_num(2));
+```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_div_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    match self.overflowing_div_euclid_int(rhs) {
-                        (_, true) => panic!("overflow"),
-                        (ans, false) => ans,
+                #[must_use]
+                pub const fn unwrapped_powf(self, exp: $Self<Frac>) -> $Self<Frac> {
+                    match pow::$Inner::powf(self.to_bits(), exp.to_bits(), Self::FRAC_NBITS) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
+                        None => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() {
+                                panic!("negative base")
+                            } else {
+                                panic!("division by zero")
+                            },
+                            unreachable!(),
+                        ),
                     }
                 }
             }
 
             comment! {
-                "Unwrapped remainder for Euclidean division by an integer. Returns the remainder",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", panicking on overflow.
-
-Note that while remainder for Euclidean division cannot be negative,
-the wrapped value can be negative.",
-                    ".
+                "Unwrapped cube root. Returns the cube root, panicking on overflow.
 
-Can never overflow for unsigned values.",
-                ),
-                "
+See [`cbrt`][Self::cbrt] for details.
 
 # Panics
 
-Panics if the divisor is zero",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    " or if the division results in overflow",
-                },
-                ".
+Panics if the result does not fit.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U", $nm4, ";
+use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U", $nm4, ">;
-assert_eq!(Fix::from_num(7.5).unwrapped_rem_euclid_int(2), Fix::from_num(1.5));
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "assert_eq!(Fix::from_num(-7.5).unwrapped_rem_euclid_int(2), Fix::from_num(0.5));
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.015625).unwrapped_cbrt(), 0.25);
 ```
 
 The following panics because of overflow.
 
 ```should_panic
-use fixed::types::extra::U", $nm4, ";
+use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U", $nm4, ">;
-// -8 ≤ Fix < 8, so the answer 12.5 overflows
-let _overflow = Fix::from_num(-7.5).unwrapped_rem_euclid_int(20);
-",
-                },
-                "```
+type Fix = ", stringify!($Self), "<U", $n, ">;
+let _overflow = Fix::MAX.unwrapped_cbrt();
+```
 ";
                 #[inline]
                 #[track_caller]
-                #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn unwrapped_rem_euclid_int(self, rhs: $Inner) -> $Self<Frac> {
-                    match self.overflowing_rem_euclid_int(rhs) {
-                        (_, true) => panic!("overflow"),
-                        (ans, false) => ans,
-                    }
+                #[must_use]
+                pub const fn unwrapped_cbrt(self) -> Self {
+                    self.unwrapped_nth_root(3)
                 }
             }
 
             comment! {
-                "Returns the square root",
-                if_signed_unsigned!(
-                    $Signedness,
-                    ", panicking for negative numbers and on overflow.",
-                    ". Can never overflow for unsigned numbers.",
-                ),
-                "
+                "Unwrapped <i>n</i>th root. Returns the <i>n</i>th root, panicking on overflow.
 
-This method uses an iterative method, with up to ", $n, " iterations for
-[`", stringify!($Self), "`]. The result is rounded down, and the error is
-&lt;&nbsp;[`DELTA`][Self::DELTA]. That is,
-result&nbsp;≤&nbsp;√`self`&nbsp;&lt;&nbsp;result&nbsp;+&nbsp;`DELTA`.
+See [`nth_root`][Self::nth_root] for details.
 
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "Overflow can only occur when there are no integer bits and
-the representable range is &minus;0.5&nbsp;≤&nbsp;<i>x</i>&nbsp;&lt;&nbsp;0.5.
-In this case, the method panics for an input value ≥&nbsp;0.25.
+# Panics
 
-"
-                },
+Panics if `n` is zero",
                 if_signed_else_empty_str! {
                     $Signedness;
-                    "# Panics
-
-Panics if the number is negative and on overflow.
-
-"
+                    ", if `n` is even and the number is negative,",
                 },
-                "# Examples
+                " or if the result does not
+fit.
 
-```rust
-use fixed::types::extra::U4;
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-assert_eq!(Fix::from_num(2).unwrapped_sqrt(), Fix::SQRT_2);
-```
-",
-                if_signed_else_empty_str! {
-                    $Signedness;
-                    "
-The following panics because the input value is negative.
+# Examples
 
-```should_panic
-use fixed::types::extra::U4;
+```rust
+use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), "<U4>;
-let _sqrt_neg = Fix::from_num(-1).unwrapped_sqrt();
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(Fix::from_num(0.0625).unwrapped_nth_root(2), 0.25);
 ```
 
 The following panics because of overflow.
@@ -2737,18 +4694,23 @@ The following panics because of overflow.
 ```should_panic
 use fixed::types::extra::U", $n, ";
 use fixed::", stringify!($Self), ";
-type AllFrac = ", stringify!($Self), "<U", $n, ">;
-let _overflow = AllFrac::from_num(0.25).unwrapped_sqrt();
+type Fix = ", stringify!($Self), "<U", $n, ">;
+let _overflow = Fix::MAX.unwrapped_nth_root(4);
 ```
-",
-                };
+";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn unwrapped_sqrt(self) -> Self {
-                    match self.overflowing_sqrt() {
-                        (val, false) => val,
-                        (_, true) => panic!("overflow"),
+                pub const fn unwrapped_nth_root(self, n: u32) -> Self {
+                    match pow::$Inner::root(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, false)) => Self::from_bits(ans),
+                        Some((_, true)) => panic!("overflow"),
+                        None => {
+                            if n == 0 {
+                                panic!("zeroth root");
+                            }
+                            panic!("even root of negative number");
+                        }
                     }
                 }
             }
@@ -2874,7 +4836,142 @@ assert_eq!(Fix::MAX.overflowing_mul(Fix::from_num(4)), (wrapped, true));
             }
 
             comment! {
-                "Overflowing division.
+                "Overflowing division.
+
+Returns a [tuple] of the quotient and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if the divisor is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
+assert_eq!(Fix::from_num(3).overflowing_div(Fix::from_num(2)), (one_point_5, false));
+let quarter = Fix::ONE / 4;
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(Fix::MAX.overflowing_div(quarter), (wrapped, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_div(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) =
+                        arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing reciprocal.
+
+Returns a [tuple] of the reciprocal and a [`bool`] indicating whether
+an overflow has occurred. On overflow, the wrapped value is returned.
+
+# Panics
+
+Panics if the fixed-point number is zero.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Fix::from_num(0.25).overflowing_recip(), (Fix::from_num(4), false));
+assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_recip(self) -> ($Self<Frac>, bool) {
+                    if let Some(one) = Self::TRY_ONE {
+                        return one.overflowing_div(self);
+                    }
+                    if_signed! {
+                        $Signedness;
+                        let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
+                        let uns_abs = $USelf::<Frac>::from_bits(abs);
+                        let (uns_wrapped, overflow1) = uns_abs.overflowing_recip();
+                        let wrapped = $Self::<Frac>::from_bits(uns_wrapped.to_bits() as $Inner);
+                        let overflow2 = wrapped.is_negative();
+                        if wrapped.to_bits() == $Inner::MIN && neg {
+                            return (wrapped, overflow1);
+                        }
+                        if neg {
+                            // if we do not have overflow yet, we will not overflow now
+                            (wrapped.wrapping_neg(), overflow1 | overflow2)
+                        } else {
+                            (wrapped, overflow1 | overflow2)
+                        }
+                    }
+                    if_unsigned! {
+                        $Signedness;
+                        // 0 < x < 1: 1/x = 1 + (1 - x) / x, wrapped to (1 - x) / x
+                        // x.wrapping_neg() = 1 - x
+
+                        // x = 0: we still get division by zero
+
+                        (self.wrapping_neg().wrapping_div(self), true)
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing multiplication, rounding the product using the
+given rounding mode.
+
+Returns a [tuple] of the product and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::RoundingMode;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let half_delta = Fix::from_num(0.5);
+assert_eq!(
+    Fix::DELTA.overflowing_mul_round(half_delta, RoundingMode::NearestAway),
+    (Fix::DELTA, false)
+);
+let wrapped = Fix::from_bits(!0 << 2);
+assert_eq!(
+    Fix::MAX.overflowing_mul_round(Fix::from_num(4), RoundingMode::Floor),
+    (wrapped, true)
+);
+```
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub const fn overflowing_mul_round(
+                    self,
+                    rhs: $Self<Frac>,
+                    mode: RoundingMode,
+                ) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_mul_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        Frac::U32,
+                        mode,
+                    );
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing division, rounding the quotient using the given
+rounding mode.
 
 Returns a [tuple] of the quotient and a [`bool`] indicating whether an
 overflow has occurred. On overflow, the wrapped value is returned.
@@ -2887,59 +4984,83 @@ Panics if the divisor is zero.
 
 ```rust
 use fixed::types::extra::U4;
+use fixed::RoundingMode;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
-let one_point_5 = Fix::from_bits(0b11 << (4 - 1));
-assert_eq!(Fix::from_num(3).overflowing_div(Fix::from_num(2)), (one_point_5, false));
-let quarter = Fix::ONE / 4;
+let three = Fix::from_num(3);
+assert_eq!(
+    Fix::ONE.overflowing_div_round(three, RoundingMode::NearestEven),
+    (Fix::from_bits(5), false)
+);
 let wrapped = Fix::from_bits(!0 << 2);
-assert_eq!(Fix::MAX.overflowing_div(quarter), (wrapped, true));
+assert_eq!(
+    Fix::MAX.overflowing_div_round(Fix::ONE / 4, RoundingMode::Floor),
+    (wrapped, true)
+);
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use = "this returns the result of the operation, without modifying the original"]
-                pub const fn overflowing_div(self, rhs: $Self<Frac>) -> ($Self<Frac>, bool) {
-                    let (ans, overflow) =
-                        arith::$Inner::overflowing_div(self.to_bits(), rhs.to_bits(), Frac::U32);
+                pub const fn overflowing_div_round(
+                    self,
+                    rhs: $Self<Frac>,
+                    mode: RoundingMode,
+                ) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) = arith::$Inner::overflowing_div_round(
+                        self.to_bits(),
+                        rhs.to_bits(),
+                        Frac::U32,
+                        mode,
+                    );
                     (Self::from_bits(ans), overflow)
                 }
             }
 
             comment! {
-                "Overflowing reciprocal.
+                "Overflowing reciprocal, rounding using the given rounding mode.
 
 Returns a [tuple] of the reciprocal and a [`bool`] indicating whether
 an overflow has occurred. On overflow, the wrapped value is returned.
 
 # Panics
 
-Panics if the fixed-point number is zero.
+Panics if `self` is zero.
 
 # Examples
 
 ```rust
 use fixed::types::extra::{U4, U", $nm1, "};
+use fixed::RoundingMode;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), "<U4>;
 // only one integer bit
 type Small = ", stringify!($Self), "<U", $nm1, ">;
-assert_eq!(Fix::from_num(0.25).overflowing_recip(), (Fix::from_num(4), false));
-assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));
+let three = Fix::from_num(3);
+assert_eq!(
+    three.overflowing_recip_round(RoundingMode::Ceil),
+    (Fix::from_bits(6), false)
+);
+assert_eq!(
+    Small::from_num(0.25).overflowing_recip_round(RoundingMode::Floor),
+    (Small::from_num(0), true)
+);
 ```
 ";
                 #[inline]
                 #[track_caller]
                 #[must_use]
-                pub const fn overflowing_recip(self) -> ($Self<Frac>, bool) {
+                pub const fn overflowing_recip_round(self, mode: RoundingMode) -> ($Self<Frac>, bool) {
                     if let Some(one) = Self::TRY_ONE {
-                        return one.overflowing_div(self);
+                        return one.overflowing_div_round(self, mode);
                     }
                     if_signed! {
                         $Signedness;
                         let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
                         let uns_abs = $USelf::<Frac>::from_bits(abs);
-                        let (uns_wrapped, overflow1) = uns_abs.overflowing_recip();
+                        // round the magnitude so that the negated result is rounded using mode
+                        let uns_mode = if neg { mode.negated() } else { mode };
+                        let (uns_wrapped, overflow1) = uns_abs.overflowing_recip_round(uns_mode);
                         let wrapped = $Self::<Frac>::from_bits(uns_wrapped.to_bits() as $Inner);
                         let overflow2 = wrapped.is_negative();
                         if wrapped.to_bits() == $Inner::MIN && neg {
@@ -2954,12 +5075,13 @@ assert_eq!(Small::from_num(0.25).overflowing_recip(), (Small::ZERO, true));
                     }
                     if_unsigned! {
                         $Signedness;
-                        // 0 < x < 1: 1/x = 1 + (1 - x) / x, wrapped to (1 - x) / x
+                        // 0 < x < 1: 1/x = 1 + (1 - x) / x, wrapped to (1 - x) / x,
+                        // and 1 does not affect rounding
                         // x.wrapping_neg() = 1 - x
 
                         // x = 0: we still get division by zero
 
-                        (self.wrapping_neg().wrapping_div(self), true)
+                        (self.wrapping_neg().overflowing_div_round(self, mode).0, true)
                     }
                 }
             }
@@ -3350,6 +5472,320 @@ assert_eq!(
                 }
             }
 
+
+            comment! {
+                "Overflowing reciprocal square root.
+
+Returns a [tuple] of the reciprocal square root and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`rsqrt`][Self::rsqrt] for details.
+
+# Panics
+
+Panics if the number is zero",
+                if_signed_else_empty_str! { $Signedness; " or negative" },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::from_num(4).overflowing_rsqrt(), (Fix::from_num(0.5), false));
+// only one integer bit
+type Small = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(Small::from_num(0.25).overflowing_rsqrt(), (Small::ZERO, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_rsqrt(self) -> (Self, bool) {
+                    if_signed_unsigned!(
+                        $Signedness,
+                        {
+                            if self.is_negative() {
+                                panic!("square root of negative number");
+                            }
+                            let u = $USelf::<Frac>::from_bits(self.to_bits() as $UInner);
+                            let (r, overflow) = u.overflowing_rsqrt();
+                            let s = $Self::from_bits(r.to_bits() as $Inner);
+                            (s, overflow || s.is_negative())
+                        },
+                        {
+                            let Some(nz) = NonZero::<$UInner>::new(self.to_bits()) else {
+                                panic!("division by zero");
+                            };
+                            let (ans, overflow) = sqrt::rsqrt::$UInner(nz, Self::FRAC_NBITS);
+                            (Self::from_bits(ans), overflow)
+                        }
+                    )
+                }
+            }
+
+            comment! {
+                "Overflowing base-2 exponential.
+
+Returns a [tuple] of the base-2 exponential and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`exp2`][Self::exp2] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(2).overflowing_exp2(), (Fix::from_num(4), false));
+assert_eq!(Fix::from_num(4).overflowing_exp2(), (Fix::ZERO, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp2(self) -> (Self, bool) {
+                    let (ans, overflow) =
+                        exp::real::$Inner(self.to_bits(), Self::FRAC_NBITS, ExpBase::Two, false);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing exponential.
+
+Returns a [tuple] of the exponential and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`exp`][Self::exp] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_exp(), (Fix::ONE, false));
+// e^4 ≈ 54.598 wraps to 54.598 &minus; 48 = 6.598
+let (wrapped, overflow) = Fix::from_num(4).overflowing_exp();
+assert!(overflow);
+assert!(wrapped.dist(Fix::from_num(6.598)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp(self) -> (Self, bool) {
+                    let (ans, overflow) =
+                        exp::real::$Inner(self.to_bits(), Self::FRAC_NBITS, ExpBase::E, false);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing exponential minus one.
+
+Returns a [tuple] of the exponential minus one and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`exp_m1`][Self::exp_m1] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_exp_m1(), (Fix::ZERO, false));
+// e^4 &minus; 1 ≈ 53.598 wraps to 53.598 &minus; 48 = 5.598
+let (wrapped, overflow) = Fix::from_num(4).overflowing_exp_m1();
+assert!(overflow);
+assert!(wrapped.dist(Fix::from_num(5.598)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_exp_m1(self) -> (Self, bool) {
+                    let (ans, overflow) =
+                        exp::real::$Inner(self.to_bits(), Self::FRAC_NBITS, ExpBase::E, true);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Overflowing integer power.
+
+Returns a [tuple] of the value raised to the integer power `n` and a [`bool`]
+indicating whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`powi`][Self::powi] for details.
+
+# Panics
+
+Panics if `self` is zero and `n` is negative.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(
+    Fix::from_num(1.5).overflowing_powi(2),
+    (Fix::from_num(2.25), false)
+);
+assert_eq!(Fix::from_num(2).overflowing_powi(4), (Fix::ZERO, true));
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_powi(self, n: i32) -> (Self, bool) {
+                    match pow::$Inner::powi(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, overflow)) => (Self::from_bits(ans), overflow),
+                        None => panic!("division by zero"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing power.
+
+Returns a [tuple] of the value raised to the fixed-point power `exp` and a
+[`bool`] indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+See [`powf`][Self::powf] for details.
+",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    "
+# Panics
+
+Panics if `self` is negative, or if `self` is zero and `exp` is negative.
+",
+                },
+                "
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(
+    Fix::from_num(4).overflowing_powf(Fix::from_num(0.5)),
+    (Fix::from_num(2), false)
+);
+assert_eq!(
+    Fix::from_num(4).overflowing_powf(Fix::from_num(2)),
+    (Fix::ZERO, true)
+);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_powf(self, exp: $Self<Frac>) -> ($Self<Frac>, bool) {
+                    match pow::$Inner::powf(self.to_bits(), exp.to_bits(), Self::FRAC_NBITS) {
+                        Some((ans, overflow)) => (Self::from_bits(ans), overflow),
+                        None => if_signed_unsigned!(
+                            $Signedness,
+                            if self.is_negative() {
+                                panic!("negative base")
+                            } else {
+                                panic!("division by zero")
+                            },
+                            unreachable!(),
+                        ),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing cube root.
+
+Returns a [tuple] of the cube root and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`cbrt`][Self::cbrt] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(
+    Fix::from_num(0.015625).overflowing_cbrt(),
+    (Fix::from_num(0.25), false)
+);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.overflowing_cbrt(), (Fix::ZERO, true));
+",
+                },
+                "```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cbrt(self) -> (Self, bool) {
+                    self.overflowing_nth_root(3)
+                }
+            }
+
+            comment! {
+                "Overflowing <i>n</i>th root.
+
+Returns a [tuple] of the <i>n</i>th root and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`nth_root`][Self::nth_root] for details.
+
+# Panics
+
+Panics if `n` is zero",
+                if_signed_else_empty_str! {
+                    $Signedness;
+                    ", or if `n` is even and the number is negative",
+                },
+                ".
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $n, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $n, ">;
+assert_eq!(
+    Fix::from_num(0.0625).overflowing_nth_root(2),
+    (Fix::from_num(0.25), false)
+);
+",
+                if_unsigned_else_empty_str! {
+                    $Signedness;
+                    "assert_eq!(Fix::MAX.overflowing_nth_root(4), (Fix::ZERO, true));
+",
+                },
+                "```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_nth_root(self, n: u32) -> (Self, bool) {
+                    match pow::$Inner::root(self.to_bits(), Self::FRAC_NBITS, n) {
+                        Some((ans, overflow)) => (Self::from_bits(ans), overflow),
+                        None => {
+                            if n == 0 {
+                                panic!("zeroth root");
+                            }
+                            panic!("even root of negative number");
+                        }
+                    }
+                }
+            }
+
             comment! {
                 "Overflowing linear interpolation between `start` and `end`.
 
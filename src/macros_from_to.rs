@@ -803,6 +803,901 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
             }
         }
 
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. Unlike
+[`from_num`], which discards the extra fractional bits of fixed-point numbers
+and rounds floating-point numbers to the nearest, this method rounds any value
+that cannot be represented exactly using `mode`.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_from_num_round`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+
+// 1.03125 is 1.00001 in binary, exactly between 1 and 1.0625
+let src = I16F16::from_bits(0b100001 << (16 - 5));
+assert_eq!(Fix::from_num_round(src, RoundingMode::NearestEven), 1);
+assert_eq!(Fix::from_num_round(src, RoundingMode::NearestAway), 1.0625);
+assert_eq!(Fix::from_num_round(src, RoundingMode::Floor), 1);
+
+// 0.1 × 16 = 1.6
+assert_eq!(Fix::from_num_round(0.1f32, RoundingMode::TowardZero), Fix::from_bits(1));
+assert_eq!(Fix::from_num_round(0.1f32, RoundingMode::Ceil), Fix::from_bits(2));
+```
+
+[`from_num`]: Self::from_num
+[`wrapping_from_num_round`]: Self::wrapping_from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> $Self<Frac> {
+                let (wrapped, overflow) = overflowing_convert_round(src, mode);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`FromFixed`] is implemented. Unlike
+[`to_num`], which discards the extra fractional bits when converting to
+fixed-point numbers and integers and rounds to the nearest when converting to
+floating-point numbers, this method rounds any value that cannot be represented
+exactly using `mode`.
+
+# Panics
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`wrapping_to_num_round`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I30F2;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestEven), 2);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::NearestAway), 3);
+assert_eq!(two_point_5.to_num_round::<i32>(RoundingMode::Ceil), 3);
+
+// 0.0625 is 0.0001 in binary
+let small = Fix::from_bits(1);
+assert_eq!(small.to_num_round::<I30F2>(RoundingMode::Floor), 0);
+assert_eq!(small.to_num_round::<I30F2>(RoundingMode::Ceil), 0.25);
+```
+
+[`to_num`]: Self::to_num
+[`wrapping_to_num_round`]: Self::wrapping_to_num_round
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, if it fits, otherwise returns [`None`].
+
+See [`from_num_round`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::checked_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Some(Fix::from_bits(2)));
+assert!(Fix::checked_from_num_round(1e80, RoundingMode::Floor).is_none());
+assert!(Fix::checked_from_num_round(f64::NAN, RoundingMode::Floor).is_none());
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> Option<$Self<Frac>> {
+                checked_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, if it fits, otherwise returns [`None`].
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.checked_to_num_round::<u8>(RoundingMode::Ceil), Some(3));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+assert!(Fix::MAX.checked_to_num_round::<TooFewIntBits>(RoundingMode::Floor).is_none());
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst> {
+                Dst::checked_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, and saturating if it does not fit.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::saturating_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Fix::from_bits(2));
+assert_eq!(Fix::saturating_from_num_round(1e80, RoundingMode::Floor), Fix::MAX);
+assert_eq!(Fix::saturating_from_num_round(f64::NEG_INFINITY, RoundingMode::Ceil), Fix::MIN);
+```
+
+[`from_num_round`]: Self::from_num_round
+[NaN]: f64::is_nan
+";
+            #[inline]
+            #[track_caller]
+            pub fn saturating_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> $Self<Frac> {
+                saturating_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, and saturating if it does not fit.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.saturating_to_num_round::<u8>(RoundingMode::Ceil), 3);
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let saturated = Fix::MAX.saturating_to_num_round::<TooFewIntBits>(RoundingMode::Floor);
+assert_eq!(saturated, TooFewIntBits::MAX);
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::saturating_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode, and wrapping the value on overflow.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U5};
+use fixed::{"#, stringify!($Self), ", FixedI256, RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::wrapping_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, Fix::from_bits(2));
+// MAX + 1/32 rounds down to MAX, or up to MAX + 1/16 which wraps to MIN
+let src = FixedI256::<U5>::from_num(Fix::MAX) + FixedI256::<U5>::DELTA;
+assert_eq!(Fix::wrapping_from_num_round(src, RoundingMode::Floor), Fix::MAX);
+assert_eq!(Fix::wrapping_from_num_round(src, RoundingMode::Ceil), Fix::MIN);
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn wrapping_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> $Self<Frac> {
+                overflowing_convert_round(src, mode).0
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode, and wrapping the value on overflow.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+assert_eq!(two_point_5.wrapping_to_num_round::<u8>(RoundingMode::Ceil), 3);
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+assert_eq!(Fix::MAX.wrapping_to_num_round::<TooFewIntBits>(RoundingMode::Floor), wrapped);
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst {
+                Dst::wrapping_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, rounding
+using the given rounding mode.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_round`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U5};
+use fixed::{"#, stringify!($Self), ", FixedI256, RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+let ceil = Fix::overflowing_from_num_round(0.1f32, RoundingMode::Ceil);
+assert_eq!(ceil, (Fix::from_bits(2), false));
+// MAX + 1/32 rounds down to MAX, or up to MAX + 1/16 which wraps to MIN
+let src = FixedI256::<U5>::from_num(Fix::MAX) + FixedI256::<U5>::DELTA;
+let floor = Fix::overflowing_from_num_round(src, RoundingMode::Floor);
+assert_eq!(floor, (Fix::MAX, false));
+let ceil = Fix::overflowing_from_num_round(src, RoundingMode::Ceil);
+assert_eq!(ceil, (Fix::MIN, true));
+```
+
+[`from_num_round`]: Self::from_num_round
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_round<Src: ToFixed>(
+                src: Src,
+                mode: RoundingMode,
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_round(src, mode)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to another number, rounding
+using the given rounding mode.
+
+Returns a [tuple] of the number and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`to_num_round`] for details on the conversion.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::{"#, stringify!($Self), ", RoundingMode};
+type Fix = ", stringify!($Self), "<U4>;
+// 2.5 is 10.1 in binary
+let two_point_5 = Fix::from_bits(0b101 << (4 - 1));
+let ceil = two_point_5.overflowing_to_num_round::<u8>(RoundingMode::Ceil);
+assert_eq!(ceil, (3, false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let floor = Fix::MAX.overflowing_to_num_round::<TooFewIntBits>(RoundingMode::Floor);
+assert_eq!(floor, (wrapped, true));
+```
+
+[`to_num_round`]: Self::to_num_round
+";
+            #[inline]
+            #[must_use]
+            pub fn overflowing_to_num_round<Dst: FromFixed>(
+                self,
+                mode: RoundingMode,
+            ) -> (Dst, bool) {
+                Dst::overflowing_from_fixed_round(self, mode)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. A value
+that cannot be represented exactly is rounded up with a probability equal to its
+distance from the representable number below it, and rounded down otherwise, so
+the result is unbiased in expectation. Values that can be represented exactly
+are returned unchanged.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use
+[`overflowing_from_num_stochastic`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// any source of random bits can be used, here a xorshift generator
+let mut state = 1u64;
+let mut rng = || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+};
+
+// src is a quarter of the way from 1 to 1 + DELTA
+let src = I16F16::ONE + I16F16::from_num(Fix::DELTA) / 4;
+let mut sum = 0.0;
+for _ in 0..1000 {
+    let dst = Fix::from_num_stochastic(src, &mut rng);
+    assert!(dst == 1 || dst == Fix::ONE + Fix::DELTA);
+    sum += dst.to_num::<f64>();
+}
+assert!((sum / 1000.0 - src.to_num::<f64>()).abs() < 0.004);
+
+// exact values are not changed
+assert_eq!(Fix::from_num_stochastic(1.5, &mut rng), 1.5);
+```
+
+[`ToFixed`]: crate::traits::ToFixed
+[`overflowing_from_num_stochastic`]: Self::overflowing_from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                let (wrapped, overflow) =
+                    overflowing_convert_random(src, Self::FRAC_NBITS, false, rng);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`, if it fits, otherwise returns
+[`None`].
+
+See [`from_num_stochastic`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+assert_eq!(Fix::checked_from_num_stochastic(0.1, &mut zero), Some(Fix::from_bits(2)));
+assert!(Fix::checked_from_num_stochastic(1e80, &mut zero).is_none());
+assert!(Fix::checked_from_num_stochastic(f64::NAN, &mut zero).is_none());
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> Option<$Self<Frac>> {
+                checked_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`, and saturating if it does not
+fit.
+
+See [`from_num_stochastic`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+assert_eq!(Fix::saturating_from_num_stochastic(0.1, &mut zero), Fix::from_bits(2));
+assert_eq!(Fix::saturating_from_num_stochastic(1e80, &mut zero), Fix::MAX);
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[NaN]: f64::is_nan
+";
+            #[inline]
+            #[track_caller]
+            pub fn saturating_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                saturating_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number using
+stochastic rounding, with random bits from `rng`.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_stochastic`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// 0.1 × 16 = 1.6, and zero random bits always round up
+let ans = Fix::overflowing_from_num_stochastic(0.1, &mut zero);
+assert_eq!(ans, (Fix::from_bits(2), false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let ans = TooFewIntBits::overflowing_from_num_stochastic(Fix::MAX, &mut zero);
+assert_eq!(ans, (wrapped, true));
+```
+
+[`from_num_stochastic`]: Self::from_num_stochastic
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_stochastic<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_random(src, Self::FRAC_NBITS, false, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+triangular probability density function (TPDF) dither with random bits from
+`rng` before rounding.
+
+The other number can be a fixed-point number, an integer, a floating-point
+number, or any other number for which [`ToFixed`] is implemented. The dither
+is the sum of two independent noises that are uniformly distributed over one
+[`DELTA`], so it is centered on zero and is less than one [`DELTA`] in
+magnitude, and the dithered value is rounded to the nearest. The result is
+unbiased in expectation, and unlike with stochastic rounding the added noise
+does not depend on the value. Values that can be represented exactly are
+dithered too.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+When debug assertions are enabled, panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future
+it panics; if wrapping is required use [`overflowing_from_num_dither`]
+instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::I16F16;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// any source of random bits can be used, here a xorshift generator
+let mut state = 1u64;
+let mut rng = || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+};
+
+// src is a quarter of the way from 1 to 1 + DELTA
+let src = I16F16::ONE + I16F16::from_num(Fix::DELTA) / 4;
+let mut sum = 0.0;
+for _ in 0..1000 {
+    let dst = Fix::from_num_dither(src, &mut rng);
+    assert!(Fix::ONE - Fix::DELTA <= dst && dst <= Fix::ONE + Fix::DELTA * 2);
+    sum += dst.to_num::<f64>();
+}
+assert!((sum / 1000.0 - src.to_num::<f64>()).abs() < 0.005);
+```
+
+[`DELTA`]: Self::DELTA
+[`ToFixed`]: crate::traits::ToFixed
+[`overflowing_from_num_dither`]: Self::overflowing_from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                let (wrapped, overflow) =
+                    overflowing_convert_random(src, Self::FRAC_NBITS, true, rng);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding, if it fits, otherwise
+returns [`None`].
+
+See [`from_num_dither`] for details on the conversion. For floating-point
+numbers that are not [finite], [`None`] is returned.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// with zero random bits, exact values are unchanged
+assert_eq!(Fix::checked_from_num_dither(Fix::ONE, &mut zero), Some(Fix::ONE));
+assert!(Fix::checked_from_num_dither(1e80, &mut zero).is_none());
+assert!(Fix::checked_from_num_dither(f64::NAN, &mut zero).is_none());
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            pub fn checked_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> Option<$Self<Frac>> {
+                checked_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding, and saturating if it
+does not fit.
+
+See [`from_num_dither`] for details on the conversion.
+
+# Panics
+
+This method panics if the value is a floating-point [NaN].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+assert_eq!(Fix::saturating_from_num_dither(Fix::MAX, &mut zero), Fix::MAX);
+assert_eq!(Fix::saturating_from_num_dither(1e80, &mut zero), Fix::MAX);
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[NaN]: f64::is_nan
+";
+            #[inline]
+            #[track_caller]
+            pub fn saturating_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> $Self<Frac> {
+                saturating_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+        comment! {
+            r#"Creates a fixed-point number from another number, adding
+TPDF dither with random bits from `rng` before rounding.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+See [`from_num_dither`] for details on the conversion.
+
+# Panics
+
+For floating-point numbers, panics if the value is not [finite].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U4, U6};
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+let mut zero = || 0u64;
+// with zero random bits, exact values are unchanged
+let ans = Fix::overflowing_from_num_dither(Fix::ONE, &mut zero);
+assert_eq!(ans, (Fix::ONE, false));
+type TooFewIntBits = ", stringify!($Self), "<U6>;
+let wrapped = TooFewIntBits::from_bits(Fix::MAX.to_bits() << 2);
+let ans = TooFewIntBits::overflowing_from_num_dither(Fix::MAX, &mut zero);
+assert_eq!(ans, (wrapped, true));
+```
+
+[`from_num_dither`]: Self::from_num_dither
+[finite]: f64::is_finite
+";
+            #[inline]
+            #[track_caller]
+            pub fn overflowing_from_num_dither<Src: ToFixed>(
+                src: Src,
+                rng: &mut (impl RngLike + ?Sized),
+            ) -> ($Self<Frac>, bool) {
+                overflowing_convert_random(src, Self::FRAC_NBITS, true, rng)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to a wider fixed-point number.
+
+The destination must have at least as many integer bits and at least as many
+fractional bits as `self`, so the conversion never overflows and never loses
+precision. This is checked at compile time using the bounds of the [`From`]
+implementations, so a conversion that could lose bits does not compile.
+
+# Examples
+
+```rust
+"#,
+            if_signed_unsigned!(
+                $Signedness,
+                "use fixed::types::{I8F8, I16F16, I32F32};
+let a = I8F8::from_num(-1.5);
+let b: I16F16 = a.widen();
+assert_eq!(b, -1.5);
+assert_eq!(b.widen::<I32F32>(), -1.5);
+```
+
+A conversion to a type with fewer integer bits does not compile:
+
+```rust,compile_fail
+use fixed::types::{I4F12, I8F8};
+let _ = I8F8::ONE.widen::<I4F12>();
+```
+",
+                "use fixed::types::{U8F8, U16F16, U32F32};
+let a = U8F8::from_num(1.5);
+let b: U16F16 = a.widen();
+assert_eq!(b, 1.5);
+assert_eq!(b.widen::<U32F32>(), 1.5);
+```
+
+A conversion to a type with fewer integer bits does not compile:
+
+```rust,compile_fail
+use fixed::types::{U4F12, U8F8};
+let _ = U8F8::ONE.widen::<U4F12>();
+```
+",
+            ),
+            "
+[`From`]: core::convert::From
+";
+            #[inline]
+            #[must_use]
+            pub fn widen<Dst: From<$Self<Frac>>>(self) -> Dst {
+                Dst::from(self)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to a fixed-point number that may
+have fewer integer bits, if it fits, otherwise returns [`None`].
+
+The destination must have at least as many fractional bits as `self`, so the
+conversion never loses precision, though it can overflow. This is checked at
+compile time using the bounds of the [`LosslessTryFrom`] implementations, so a
+conversion that could lose fractional bits does not compile; for such a
+conversion, use [`requantize`] first to choose how to round.
+
+# Examples
+
+```rust
+"#,
+            if_signed_unsigned!(
+                $Signedness,
+                "use fixed::types::extra::U8;
+use fixed::types::{I8F8, I32F32, I56F8};
+use fixed::RoundingMode;
+let a = I32F32::from_num(-1.75);
+// I32F32 to I8F8 loses fractional bits, so first round to 8 fractional bits
+let b = a.requantize::<U8>(RoundingMode::NearestEven);
+assert_eq!(b.narrow::<I8F8>(), Some(I8F8::from_num(-1.75)));
+// I56F8 to I8F8 can overflow
+assert_eq!(I56F8::MAX.narrow::<I8F8>(), None);
+```
+
+A conversion to a type with fewer fractional bits does not compile:
+
+```rust,compile_fail
+use fixed::types::{I8F8, I16F16};
+let _ = I16F16::ONE.narrow::<I8F8>();
+```
+",
+                "use fixed::types::extra::U8;
+use fixed::types::{U8F8, U32F32, U56F8};
+use fixed::RoundingMode;
+let a = U32F32::from_num(1.75);
+// U32F32 to U8F8 loses fractional bits, so first round to 8 fractional bits
+let b = a.requantize::<U8>(RoundingMode::NearestEven);
+assert_eq!(b.narrow::<U8F8>(), Some(U8F8::from_num(1.75)));
+// U56F8 to U8F8 can overflow
+assert_eq!(U56F8::MAX.narrow::<U8F8>(), None);
+```
+
+A conversion to a type with fewer fractional bits does not compile:
+
+```rust,compile_fail
+use fixed::types::{U8F8, U16F16};
+let _ = U16F16::ONE.narrow::<U8F8>();
+```
+",
+            ),
+            "
+[`LosslessTryFrom`]: crate::traits::LosslessTryFrom
+[`requantize`]: Self::requantize
+";
+            #[inline]
+            #[must_use]
+            pub fn narrow<Dst: LosslessTryFrom<$Self<Frac>>>(self) -> Option<Dst> {
+                Dst::lossless_try_from(self)
+            }
+        }
+
+        comment! {
+            r#"Converts a fixed-point number to a fixed-point number with the
+same underlying integer type and `NewFrac` fractional bits, rounding using the
+given rounding mode.
+
+`NewFrac` cannot be larger than `Frac`, which is checked at compile time. Since
+any fractional bits that are removed become integer bits, the result always
+fits, even when it is rounded up.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U4};
+use fixed::"#, stringify!($Self), r#";
+use fixed::RoundingMode;
+type Fix = "#, stringify!($Self), r#"<U4>;
+// binary 1.1011 is 1.6875
+let a = Fix::from_bits(0b1_1011);
+// binary 1.11 is 1.75, binary 1.10 is 1.5
+assert_eq!(a.requantize::<U2>(RoundingMode::NearestEven), 1.75);
+assert_eq!(a.requantize::<U2>(RoundingMode::Floor), 1.5);
+// the maximum value does not overflow when rounded up
+let max = Fix::MAX.requantize::<U2>(RoundingMode::Ceil);
+assert!(max > Fix::MAX);
+```
+
+A conversion to more fractional bits does not compile:
+
+```rust,compile_fail
+use fixed::types::extra::{U2, U4};
+use fixed::"#, stringify!($Self), r#";
+use fixed::RoundingMode;
+let _ = "#, stringify!($Self), r#"::<U2>::ONE.requantize::<U4>(RoundingMode::Floor);
+```
+"#;
+            #[inline]
+            #[must_use]
+            pub fn requantize<NewFrac>(self, mode: RoundingMode) -> $Self<NewFrac>
+            where
+                NewFrac: $LeEqU + IsLessOrEqual<Frac, Output = True>,
+            {
+                let (ans, overflow) = overflowing_convert_round(self, mode);
+                debug_assert!(!overflow, "overflow");
+                ans
+            }
+        }
+
         /// Creates a fixed-point number from a fixed-point number with the same
         /// underlying integer type. Usable in constant context.
         ///
@@ -866,246 +1761,828 @@ assert_eq!(one_point_625.overflowing_to_num::<f32>(), (1.625f32, false));
                 if (shifted >> shift_left) != src_bits {
                     panic!("overflow");
                 }
-                shifted
-            };
-            Self::from_bits(bits)
+                shifted
+            };
+            Self::from_bits(bits)
+        }
+
+        /// Creates a fixed-point number from the underlying integer type
+        #[doc = concat!("[`", stringify!($Inner), "`].")]
+        /// Usable in constant context.
+        ///
+        /// This is equivalent to the [`unwrapped_from_num`] method with
+        #[doc = concat!("[`", stringify!($Inner), "`]")]
+        /// as its generic parameter, but can also be used in constant context.
+        /// Unless required in constant context, use [`unwrapped_from_num`] or
+        /// [`from_num`] instead.
+        ///
+        /// # Planned deprecation
+        ///
+        /// This method will be deprecated when the [`unwrapped_from_num`]
+        /// method is usable in constant context.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value does not fit.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use fixed::types::extra::U4;
+        #[doc = concat!("use fixed::", stringify!($Self), ";")]
+        #[doc = concat!("type Fix = ", stringify!($Self), "<U4>;")]
+        /// const FIVE: Fix = Fix::const_from_int(5);
+        /// assert_eq!(FIVE, 5);
+        /// ```
+        ///
+        /// The following would fail to compile because of overflow.
+        ///
+        /// ```rust,compile_fail
+        /// use fixed::types::extra::U4;
+        #[doc = concat!("use fixed::", stringify!($Self), ";")]
+        #[doc = concat!(
+            "const _OVERFLOW: ", stringify!($Self), "<U4> = ",
+            stringify!($Self), "::const_from_int(",
+            stringify!($Inner), "::MAX);"
+        )]
+        /// ```
+        ///
+        /// [`from_num`]: Self::from_num
+        /// [`unwrapped_from_num`]: Self::unwrapped_from_num
+        #[inline]
+        #[track_caller]
+        #[must_use]
+        pub const fn const_from_int(src: $Inner) -> $Self<Frac> {
+            Self::const_from_fixed($Self::<U0>::from_bits(src))
+        }
+
+        comment! {
+            "Parses a fixed-point literal.
+
+Rounding is to the nearest, with ties rounded to even.
+
+This is similar to [`from_str`][Self::from_str] but accepts a prefix for setting
+the radix, and ignores underscores, such that the parsing is more similar to
+numeric literals in Rust code.
+
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "The string can start with “`-`” for a negative number.
+
+",
+            },
+            r#"Strings starting with “`0b`” are parsed as binary, strings
+starting with “`0o`” are parsed as octal, and strings starting with “`0x`” are
+parsed as hexadecimal.
+
+Exponents are supported as well. For decimal, binary and octal numbers, the
+separator “`e`” or “`E`” can be used to start an exponent, which is then
+followed by an optional sign “`+`” or “`-`”, and then by a decimal integer which
+is the exponent. For hexadecimal numbers, since “`e`” and “`E`” are hexadecimal
+digits, the separator “`@`” has to be used instead. The separator “`@`” is
+accepted for all radices. The parsed value is scaled by the radix to the power
+of the exponent.
+
+For binary, octal and hexadecimal, base-2 exponents are supported too, using the
+separator “`p`” or “`P`”. The parsed value is scaled by 2 to the power of the
+exponent. For example, for hexadecimal “`P8`” means ×2⁸, and is equivalent to
+“`@2`” which means ×16².
+
+# Constants
+
+The `lit` method is useful to write constant fixed-point literals, and can be
+evaluated in constant context.
+
+**Warning:** Normal calls to the `lit` method are *not* evaluated at compile
+time. To ensure that the call is evaluated at compile time, `lit` must be used
+in an inline constant expression, or used to initialize a constant.
+
+For example, here `lit` would be evaluated at compile time:
+
+```rust
+# use fixed::types::extra::U4;
+# use fixed::FixedU32;
+# type Fix = FixedU32<U4>;
+const ONE_AND_HALF: Fix = Fix::lit("1.5");
+# assert_eq!(ONE_AND_HALF, 1.5);
+```
+
+However, here `lit` would be evaluated at run time:
+
+```rust
+# use fixed::types::extra::U4;
+# use fixed::FixedU32;
+# type Fix = FixedU32<U4>;
+// Evaluated at run time.
+let one_and_half = Fix::lit("1.5");
+# assert_eq!(one_and_half, 1.5);
+```
+
+To evaluate at compile time without introducing a constant into the scope:
+
+```rust
+# use fixed::types::extra::U4;
+# use fixed::FixedU32;
+# type Fix = FixedU32<U4>;
+let one_and_half = const { Fix::lit("1.5") };
+# assert_eq!(one_and_half, 1.5);
+```
+
+# Panics
+
+Panics if the number is not valid or overflows.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+
+assert_eq!(Fix::lit("1.75"), 1.75);
+assert_eq!(Fix::lit("1_.7_5_"), 1.75);
+assert_eq!(Fix::lit("17.5e-1"), 1.75);
+assert_eq!(Fix::lit("0_.017_5_e+0_2"), 1.75);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::lit("-01.75"), -1.75);
+"#,
+            },
+            r#"
+assert_eq!(Fix::lit("0b1.11"), 1.75);
+assert_eq!(Fix::lit("0b_111e-2"), 1.75);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::lit("-0b1.11"), -1.75);
+"#,
+            },
+            r#"
+assert_eq!(Fix::lit("0o1.6"), 1.75);
+assert_eq!(Fix::lit("0o.16E1"), 1.75);
+assert_eq!(Fix::lit("0o7p-2"), 1.75);
+
+assert_eq!(Fix::lit("0x1.C"), 1.75);
+assert_eq!(Fix::lit("0x0.1C@1"), 1.75);
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::lit("-0x1.C"), -1.75);
+"#,
+            },
+            r#"```
+
+This method is useful to write constant fixed-point literals.
+
+```rust
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+const ONE_AND_HALF: Fix = Fix::lit("1.5");
+assert_eq!(ONE_AND_HALF, 1.5);
+```
+"#;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn lit(src: &str) -> $Self<Frac> {
+                match from_str::$Inner::lit(src.as_bytes(), Self::FRAC_NBITS) {
+                    Ok(s) => $Self::from_bits(s),
+                    Err(e) => panic!("{}", e.lit_message()),
+                }
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing decimal digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
+can be used to start an exponent, which is then followed by an optional sign
+“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
+value is scaled by 10 to the power of the exponent.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+assert_eq!(Fix::from_str("1.75"), Ok(Fix::from_num(1.75)));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::from_str("-1.75"), Ok(Fix::from_num(-1.75)));
+"#,
+            },
+            r#"assert_eq!(Fix::from_str("0.00625E+3"), Ok(Fix::from_num(6.25)));
+assert_eq!(Fix::from_str("1.25e-1"), Ok(Fix::from_num(0.125)));
+```
+"#;
+            #[inline]
+            pub const fn from_str(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::from_ascii(src.as_bytes())
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing binary digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
+can be used to start an exponent, which is then followed by an optional sign
+“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
+value is scaled by the radix (2 for binary) to the power of the exponent.
+
+Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
+parsed value is scaled by 2 to the power of the exponent. For binary, since the
+radix is 2, base-2 exponents are equivalent to the other form of exponent.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+// 1.11 in binary is 1.75
+assert_eq!(Fix::from_str_binary("1.11"), Ok(Fix::from_num(1.75)));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::from_str_binary("-1.11"), Ok(Fix::from_num(-1.75)));
+"#,
+            },
+            r#"
+// 111.0101 in binary is 7.3125
+assert_eq!(Fix::from_str_binary("1.110101e2"), Ok(Fix::from_num(7.3125)));
+assert_eq!(Fix::from_str_binary("11101.01e-2"), Ok(Fix::from_num(7.3125)));
+```
+"#;
+            #[inline]
+            pub const fn from_str_binary(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::from_ascii_binary(src.as_bytes())
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing octal digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
+can be used to start an exponent, which is then followed by an optional sign
+“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
+value is scaled by 8 to the power of the exponent.
+
+Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
+parsed value is scaled by 2 to the power of the exponent. For example, for octal
+“`P6`” means ×2⁶, and is equivalent to “`E2`” which means ×8².
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+// 1.75 is 1.11 in binary, 1.6 in octal
+let f = Fix::from_str_octal("1.6");
+let check = Fix::from_bits(0b111 << (4 - 2));
+assert_eq!(f, Ok(check));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"let neg = Fix::from_str_octal("-1.6");
+assert_eq!(neg, Ok(-check));
+"#,
+            },
+            r#"assert_eq!(Fix::from_str_octal("160e-2"), Ok(check));
+```
+"#;
+            #[inline]
+            pub const fn from_str_octal(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::from_ascii_octal(src.as_bytes())
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+
+Rounding is to the nearest, with ties rounded to even.
+
+The number can have an optional exponent. Since “`e`” and “`E`” are valid
+hexadecimal digits, they cannot be used as a separator to start an exponent, so
+“`@`” is used instead. This is then followed by an optional sign “`+`” or “`-`”,
+and then by a decimal integer which is the exponent. The parsed value is scaled
+by 16 to the power of the exponent.
+
+Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
+parsed value is scaled by 2 to the power of the exponent. For example, for
+hexadecimal “`P8`” means ×2⁸, and is equivalent to “`@2`” which means ×16².
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+// 1.C in hexadecimal is 1.75
+assert_eq!(Fix::from_str_hex("1.C"), Ok(Fix::from_num(1.75)));
+"#,
+            if_signed_else_empty_str! {
+                $Signedness;
+                r#"assert_eq!(Fix::from_str_hex("-1.C"), Ok(Fix::from_num(-1.75)));
+"#,
+            },
+            r#"assert_eq!(Fix::from_str_hex("1C@-1"), Ok(Fix::from_num(1.75)));
+assert_eq!(Fix::from_str_hex(".01C@+2"), Ok(Fix::from_num(1.75)));
+```
+"#;
+            #[inline]
+            pub const fn from_str_hex(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                Self::from_ascii_hex(src.as_bytes())
+            }
         }
 
-        /// Creates a fixed-point number from the underlying integer type
-        #[doc = concat!("[`", stringify!($Inner), "`].")]
-        /// Usable in constant context.
-        ///
-        /// This is equivalent to the [`unwrapped_from_num`] method with
-        #[doc = concat!("[`", stringify!($Inner), "`]")]
-        /// as its generic parameter, but can also be used in constant context.
-        /// Unless required in constant context, use [`unwrapped_from_num`] or
-        /// [`from_num`] instead.
-        ///
-        /// # Planned deprecation
-        ///
-        /// This method will be deprecated when the [`unwrapped_from_num`]
-        /// method is usable in constant context.
-        ///
-        /// # Panics
-        ///
-        /// Panics if the value does not fit.
-        ///
-        /// # Examples
-        ///
-        /// ```rust
-        /// use fixed::types::extra::U4;
-        #[doc = concat!("use fixed::", stringify!($Self), ";")]
-        #[doc = concat!("type Fix = ", stringify!($Self), "<U4>;")]
-        /// const FIVE: Fix = Fix::const_from_int(5);
-        /// assert_eq!(FIVE, 5);
-        /// ```
-        ///
-        /// The following would fail to compile because of overflow.
-        ///
-        /// ```rust,compile_fail
-        /// use fixed::types::extra::U4;
-        #[doc = concat!("use fixed::", stringify!($Self), ";")]
-        #[doc = concat!(
-            "const _OVERFLOW: ", stringify!($Self), "<U4> = ",
-            stringify!($Self), "::const_from_int(",
-            stringify!($Inner), "::MAX);"
-        )]
-        /// ```
-        ///
-        /// [`from_num`]: Self::from_num
-        /// [`unwrapped_from_num`]: Self::unwrapped_from_num
-        #[inline]
-        #[track_caller]
-        #[must_use]
-        pub const fn const_from_int(src: $Inner) -> $Self<Frac> {
-            Self::const_from_fixed($Self::<U0>::from_bits(src))
+        comment! {
+            "Parses a string slice to return a fixed-point number, using the
+given [`ParseOptions`].
+
+The options specify the radix, the rounding mode, whether excess precision
+is an error, and which separators, signs and whitespace are accepted. With
+the default options, this is equivalent to [`from_str`][Self::from_str].
+
+# Errors
+
+Returns an error if the string is not a valid number with the given
+options, if the value overflows, or if [`exact`][ParseOptions::exact] is set
+and the value cannot be represented exactly.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", ParseOptions, RoundingMode};
+type Fix = ", stringify!($Self), r#"<U4>;
+// 1.3 lies between 1.25 and 1.3125
+let floor = ParseOptions::new().rounding(RoundingMode::Floor);
+assert_eq!(Fix::from_str_with("1.3", &floor), Ok(Fix::from_num(1.25)));
+let exact = ParseOptions::new().exact(true);
+assert!(Fix::from_str_with("1.3", &exact).is_err());
+assert_eq!(Fix::from_str_with("1.3125", &exact), Ok(Fix::from_num(1.3125)));
+let hex = ParseOptions::new().radix(16).underscores(true);
+assert_eq!(Fix::from_str_with("1.4_0", &hex), Ok(Fix::from_num(1.25)));
+```
+"#;
+            #[inline]
+            pub fn from_str_with(
+                src: &str,
+                options: &ParseOptions,
+            ) -> Result<$Self<Frac>, ParseFixedError> {
+                match from_str::from_str_with(
+                    src,
+                    options,
+                    Self::FRAC_NBITS,
+                    $Inner::BITS,
+                    Self::IS_SIGNED,
+                ) {
+                    Ok(bits) => Ok($Self::from_bits(bits.lo as $Inner)),
+                    Err(e) => Err(e),
+                }
+            }
         }
 
         comment! {
-            "Parses a fixed-point literal.
+            "Parses a string slice containing a hexadecimal floating-point number
+to return a fixed-point number.
+
+The syntax is the one used for hexadecimal floating-point literals in C:
+an optional sign, the prefix “`0x`” or “`0X`”, hexadecimal digits with an
+optional point, and an optional base-2 exponent introduced by “`p`” or
+“`P`”. For example “`0x1.8p-3`” is 1.5 × 2<sup>−3</sup>. The output of
+[`HexFloat`] can be parsed back to give exactly the same value.
 
 Rounding is to the nearest, with ties rounded to even.
 
-This is similar to [`from_str`][Self::from_str] but accepts a prefix for setting
-the radix, and ignores underscores, such that the parsing is more similar to
-numeric literals in Rust code.
+# Errors
 
-",
+Returns an error if the string is not a valid hexadecimal floating-point
+number or if the value overflows.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::{", stringify!($Self), ", HexFloat};
+type Fix = ", stringify!($Self), r#"<U4>;
+// 1.5 × 2^-3 = 0.1875
+assert_eq!(Fix::from_str_hexfloat("0x1.8p-3"), Ok(Fix::from_num(0.1875)));
+"#,
             if_signed_else_empty_str! {
                 $Signedness;
-                "The string can start with “`-`” for a negative number.
+                r#"assert_eq!(Fix::from_str_hexfloat("-0X1.8P-3"), Ok(Fix::from_num(-0.1875)));
+"#,
+            },
+            r#"let f = Fix::from_num(5.75);
+let s = HexFloat(f).to_string();
+assert_eq!(s, "0x1.7p+2");
+assert_eq!(Fix::from_str_hexfloat(&s), Ok(f));
+```
+"#;
+            #[inline]
+            pub fn from_str_hexfloat(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_hexfloat(src) {
+                    Ok((val, false)) => Ok(val),
+                    Ok((_, true)) => Err(ParseFixedError::overflow(16)),
+                    Err(e) => Err(e),
+                }
+            }
+        }
 
+        comment! {
+            "Parses a string slice containing a hexadecimal floating-point number
+to return a fixed-point number, saturating on overflow.
+
+The syntax is the same as for
+[`from_str_hexfloat`][Self::from_str_hexfloat]. Rounding is to the nearest,
+with ties rounded to even.
+
+# Examples
+
+```rust
 ",
-            },
-            r#"Strings starting with “`0b`” are parsed as binary, strings
-starting with “`0o`” are parsed as octal, and strings starting with “`0x`” are
-parsed as hexadecimal.
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_hexfloat("0x1p10"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str_hexfloat("-0x1p10"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_hexfloat("0x1p10"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str_hexfloat("-0x1p0"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str_hexfloat(
+                src: &str,
+            ) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_hexfloat(src) {
+                    Ok((val, false)) => Ok(val),
+                    Ok((_, true)) => {
+                        if src.starts_with('-') {
+                            Ok(Self::MIN)
+                        } else {
+                            Ok(Self::MAX)
+                        }
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }
 
-Exponents are supported as well. For decimal, binary and octal numbers, the
-separator “`e`” or “`E`” can be used to start an exponent, which is then
-followed by an optional sign “`+`” or “`-`”, and then by a decimal integer which
-is the exponent. For hexadecimal numbers, since “`e`” and “`E`” are hexadecimal
-digits, the separator “`@`” has to be used instead. The separator “`@`” is
-accepted for all radices. The parsed value is scaled by the radix to the power
-of the exponent.
+        comment! {
+            "Parses a string slice containing a hexadecimal floating-point number
+to return a fixed-point number, wrapping on overflow.
 
-For binary, octal and hexadecimal, base-2 exponents are supported too, using the
-separator “`p`” or “`P`”. The parsed value is scaled by 2 to the power of the
-exponent. For example, for hexadecimal “`P8`” means ×2⁸, and is equivalent to
-“`@2`” which means ×16².
+The syntax is the same as for
+[`from_str_hexfloat`][Self::from_str_hexfloat]. Rounding is to the nearest,
+with ties rounded to even.
 
-# Constants
+# Examples
 
-The `lit` method is useful to write constant fixed-point literals, and can be
-evaluated in constant context.
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+// 0x1.4p8 = 320, which wraps to 64
+assert_eq!(I8F8::wrapping_from_str_hexfloat("0x1.4p8"), Ok(I8F8::from_num(64)));
+assert_eq!(I8F8::wrapping_from_str_hexfloat("-0x1.4p8"), Ok(I8F8::from_num(-64)));
+"#,
+                r#"use fixed::types::U8F8;
+// 0x1.4p8 = 320, which wraps to 64
+assert_eq!(U8F8::wrapping_from_str_hexfloat("0x1.4p8"), Ok(U8F8::from_num(64)));
+assert_eq!(U8F8::wrapping_from_str_hexfloat("-0x1p6"), Ok(U8F8::from_num(192)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn wrapping_from_str_hexfloat(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_hexfloat(src) {
+                    Ok((val, _)) => Ok(val),
+                    Err(e) => Err(e),
+                }
+            }
+        }
 
-**Warning:** Normal calls to the `lit` method are *not* evaluated at compile
-time. To ensure that the call is evaluated at compile time, `lit` must be used
-in an inline constant expression, or used to initialize a constant.
+        comment! {
+            "Parses a string slice containing a hexadecimal floating-point number
+to return a fixed-point number, panicking on overflow.
 
-For example, here `lit` would be evaluated at compile time:
+The syntax is the same as for
+[`from_str_hexfloat`][Self::from_str_hexfloat]. Rounding is to the nearest,
+with ties rounded to even.
+
+# Panics
+
+Panics if the value does not fit or if there is a parsing error.
+
+# Examples
 
 ```rust
-# use fixed::types::extra::U4;
-# use fixed::FixedU32;
-# type Fix = FixedU32<U4>;
-const ONE_AND_HALF: Fix = Fix::lit("1.5");
-# assert_eq!(ONE_AND_HALF, 1.5);
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let f = Fix::unwrapped_from_str_hexfloat("0x1.cp0");
+assert_eq!(f, Fix::from_num(1.75));
 ```
 
-However, here `lit` would be evaluated at run time:
+The following panics because of a parsing error, as the prefix is missing.
+
+```rust,should_panic
+use fixed::types::extra::U4;
+use fixed::"#, stringify!($Self), ";
+type Fix = ", stringify!($Self), r#"<U4>;
+let _error = Fix::unwrapped_from_str_hexfloat("1.cp0");
+```
+"#;
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub fn unwrapped_from_str_hexfloat(src: &str) -> $Self<Frac> {
+                match Self::from_str_hexfloat(src) {
+                    Ok(o) => o,
+                    Err(e) => panic!("{}", e.message()),
+                }
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing a hexadecimal floating-point number
+to return a fixed-point number.
+
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
+
+The syntax is the same as for
+[`from_str_hexfloat`][Self::from_str_hexfloat]. Rounding is to the nearest,
+with ties rounded to even.
+
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_num(64);
+assert_eq!(I8F8::overflowing_from_str_hexfloat("0x1p6"), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_hexfloat("0x1.4p8"), Ok((check, true)));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_num(64);
+assert_eq!(U8F8::overflowing_from_str_hexfloat("0x1p6"), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_hexfloat("0x1.4p8"), Ok((check, true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str_hexfloat(
+                src: &str,
+            ) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                match from_str::overflowing_from_str_hexfloat(
+                    src,
+                    Self::FRAC_NBITS,
+                    $Inner::BITS,
+                    Self::IS_SIGNED,
+                ) {
+                    Ok((bits, overflow)) => Ok(($Self::from_bits(bits.lo as $Inner), overflow)),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from the ratio of two integers.
+
+The quotient `num`&nbsp;/&nbsp;`den` is rounded to the nearest, with ties
+rounded to even. The division uses a wide intermediate, so the result is
+correctly rounded for all `num` and `den`.
+
+# Panics
+
+Panics if `den` is zero.
+
+When debug assertions are enabled, also panics if the value does not fit.
+When debug assertions are not enabled, the wrapped value can be returned,
+but it is not considered a breaking change if in the future it panics; if
+wrapping is required use [`wrapping_from_ratio`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+// 3/8 is 0.011 in binary
+assert_eq!(Fix::from_ratio(3, 8), Fix::from_num(0.375));
+// 1/3 × 16 = 5.33…, which rounds to 5
+assert_eq!(Fix::from_ratio(1, 3), Fix::from_bits(5));
+",
+            if_signed_else_empty_str! {
+                $Signedness;
+                "assert_eq!(Fix::from_ratio(1, -3), Fix::from_bits(-5));
+",
+            },
+            "```
+
+[`wrapping_from_ratio`]: Self::wrapping_from_ratio
+";
+            #[inline]
+            #[track_caller]
+            pub const fn from_ratio(num: $Inner, den: $Inner) -> $Self<Frac> {
+                let (wrapped, overflow) = Self::overflowing_from_ratio(num, den);
+                debug_assert!(!overflow, "overflow");
+                wrapped
+            }
+        }
+
+        comment! {
+            "Creates a fixed-point number from the ratio of two integers if it
+fits and `den` is not zero, otherwise returns [`None`].
+
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Examples
 
 ```rust
-# use fixed::types::extra::U4;
-# use fixed::FixedU32;
-# type Fix = FixedU32<U4>;
-// Evaluated at run time.
-let one_and_half = Fix::lit("1.5");
-# assert_eq!(one_and_half, 1.5);
+use fixed::types::extra::U4;
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::checked_from_ratio(1, 3), Some(Fix::from_bits(5)));
+assert_eq!(Fix::checked_from_ratio(1, 0), None);
+assert_eq!(Fix::checked_from_ratio(Fix::MAX.to_bits(), 1), None);
 ```
+";
+            #[inline]
+            #[must_use]
+            pub const fn checked_from_ratio(num: $Inner, den: $Inner) -> Option<$Self<Frac>> {
+                if den == 0 {
+                    return None;
+                }
+                match Self::overflowing_from_ratio(num, den) {
+                    (val, false) => Some(val),
+                    (_, true) => None,
+                }
+            }
+        }
 
-To evaluate at compile time without introducing a constant into the scope:
+        comment! {
+            "Creates a fixed-point number from the ratio of two integers,
+saturating on overflow.
 
-```rust
-# use fixed::types::extra::U4;
-# use fixed::FixedU32;
-# type Fix = FixedU32<U4>;
-let one_and_half = const { Fix::lit("1.5") };
-# assert_eq!(one_and_half, 1.5);
-```
+The quotient is rounded to the nearest, with ties rounded to even.
 
 # Panics
 
-Panics if the number is not valid or overflows.
+Panics if `den` is zero.
 
 # Examples
 
 ```rust
 use fixed::types::extra::U4;
-use fixed::"#, stringify!($Self), ";
-type Fix = ", stringify!($Self), r#"<U4>;
-
-assert_eq!(Fix::lit("1.75"), 1.75);
-assert_eq!(Fix::lit("1_.7_5_"), 1.75);
-assert_eq!(Fix::lit("17.5e-1"), 1.75);
-assert_eq!(Fix::lit("0_.017_5_e+0_2"), 1.75);
-"#,
-            if_signed_else_empty_str! {
-                $Signedness;
-                r#"assert_eq!(Fix::lit("-01.75"), -1.75);
-"#,
-            },
-            r#"
-assert_eq!(Fix::lit("0b1.11"), 1.75);
-assert_eq!(Fix::lit("0b_111e-2"), 1.75);
-"#,
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::saturating_from_ratio(1, 3), Fix::from_bits(5));
+assert_eq!(Fix::saturating_from_ratio(Fix::MAX.to_bits(), 1), Fix::MAX);
+",
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"assert_eq!(Fix::lit("-0b1.11"), -1.75);
-"#,
+                "assert_eq!(Fix::saturating_from_ratio(Fix::MAX.to_bits(), -1), Fix::MIN);
+",
             },
-            r#"
-assert_eq!(Fix::lit("0o1.6"), 1.75);
-assert_eq!(Fix::lit("0o.16E1"), 1.75);
-assert_eq!(Fix::lit("0o7p-2"), 1.75);
+            "```
+";
+            #[inline]
+            #[track_caller]
+            #[must_use]
+            pub const fn saturating_from_ratio(num: $Inner, den: $Inner) -> $Self<Frac> {
+                match Self::overflowing_from_ratio(num, den) {
+                    (val, false) => val,
+                    (_, true) => {
+                        let (num_neg, _) = int_helper::$Inner::neg_abs(num);
+                        let (den_neg, _) = int_helper::$Inner::neg_abs(den);
+                        if num_neg != den_neg {
+                            Self::MIN
+                        } else {
+                            Self::MAX
+                        }
+                    }
+                }
+            }
+        }
 
-assert_eq!(Fix::lit("0x1.C"), 1.75);
-assert_eq!(Fix::lit("0x0.1C@1"), 1.75);
-"#,
-            if_signed_else_empty_str! {
-                $Signedness;
-                r#"assert_eq!(Fix::lit("-0x1.C"), -1.75);
-"#,
-            },
-            r#"```
+        comment! {
+            "Creates a fixed-point number from the ratio of two integers,
+wrapping on overflow.
 
-This method is useful to write constant fixed-point literals.
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `den` is zero.
+
+# Examples
 
 ```rust
 use fixed::types::extra::U4;
-use fixed::"#, stringify!($Self), ";
-type Fix = ", stringify!($Self), r#"<U4>;
-const ONE_AND_HALF: Fix = Fix::lit("1.5");
-assert_eq!(ONE_AND_HALF, 1.5);
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::wrapping_from_ratio(1, 3), Fix::from_bits(5));
+let wrapped = Fix::from_bits(Fix::MAX.to_bits() << 4);
+assert_eq!(Fix::wrapping_from_ratio(Fix::MAX.to_bits(), 1), wrapped);
 ```
-"#;
+";
             #[inline]
             #[track_caller]
             #[must_use]
-            pub const fn lit(src: &str) -> $Self<Frac> {
-                match from_str::$Inner::lit(src.as_bytes(), Self::FRAC_NBITS) {
-                    Ok(s) => $Self::from_bits(s),
-                    Err(e) => panic!("{}", e.lit_message()),
-                }
+            pub const fn wrapping_from_ratio(num: $Inner, den: $Inner) -> $Self<Frac> {
+                Self::overflowing_from_ratio(num, den).0
             }
         }
 
         comment! {
-            "Parses a string slice containing decimal digits to return a fixed-point number.
+            "Creates a fixed-point number from the ratio of two integers.
 
-Rounding is to the nearest, with ties rounded to even.
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
 
-The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
-can be used to start an exponent, which is then followed by an optional sign
-“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
-value is scaled by 10 to the power of the exponent.
+The quotient is rounded to the nearest, with ties rounded to even.
+
+# Panics
+
+Panics if `den` is zero.
 
 # Examples
 
 ```rust
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), r#"<U4>;
-assert_eq!(Fix::from_str("1.75"), Ok(Fix::from_num(1.75)));
-"#,
-            if_signed_else_empty_str! {
-                $Signedness;
-                r#"assert_eq!(Fix::from_str("-1.75"), Ok(Fix::from_num(-1.75)));
-"#,
-            },
-            r#"assert_eq!(Fix::from_str("0.00625E+3"), Ok(Fix::from_num(6.25)));
-assert_eq!(Fix::from_str("1.25e-1"), Ok(Fix::from_num(0.125)));
+type Fix = ", stringify!($Self), "<U4>;
+assert_eq!(Fix::overflowing_from_ratio(1, 3), (Fix::from_bits(5), false));
+let wrapped = Fix::from_bits(Fix::MAX.to_bits() << 4);
+assert_eq!(Fix::overflowing_from_ratio(Fix::MAX.to_bits(), 1), (wrapped, true));
 ```
-"#;
+";
             #[inline]
-            pub const fn from_str(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
-                Self::from_ascii(src.as_bytes())
+            #[track_caller]
+            #[must_use]
+            pub const fn overflowing_from_ratio(num: $Inner, den: $Inner) -> ($Self<Frac>, bool) {
+                let (num_neg, num_abs) = int_helper::$Inner::neg_abs(num);
+                let (den_neg, den_abs) = int_helper::$Inner::neg_abs(den);
+                if den_abs == 0 {
+                    panic!("division by zero");
+                }
+                let (bits, overflow) = ratio::overflowing_div_bits(
+                    num_neg != den_neg,
+                    int256::U256 {
+                        lo: num_abs as u128,
+                        hi: 0,
+                    },
+                    int256::U256 {
+                        lo: den_abs as u128,
+                        hi: 0,
+                    },
+                    Self::FRAC_NBITS,
+                    $Inner::BITS,
+                    Self::IS_SIGNED,
+                );
+                ($Self::from_bits(bits.lo as $Inner), overflow)
             }
         }
 
         comment! {
-            "Parses a string slice containing binary digits to return a fixed-point number.
+            "Parses a string slice containing a ratio of two decimal integers,
+such as “`-3/7`”, to return a fixed-point number.
 
-Rounding is to the nearest, with ties rounded to even.
+The numerator can have a sign, while the denominator cannot. The denominator
+can be omitted, in which case it is 1. The quotient is rounded to the
+nearest, with ties rounded to even, as for
+[`from_ratio`][Self::from_ratio].
 
-The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
-can be used to start an exponent, which is then followed by an optional sign
-“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
-value is scaled by the radix (2 for binary) to the power of the exponent.
+# Errors
 
-Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
-parsed value is scaled by 2 to the power of the exponent. For binary, since the
-radix is 2, base-2 exponents are equivalent to the other form of exponent.
+Returns an error if the string is not a valid ratio, if the denominator is
+zero, or if the value overflows.
 
 # Examples
 
@@ -1113,102 +2590,214 @@ radix is 2, base-2 exponents are equivalent to the other form of exponent.
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
 type Fix = ", stringify!($Self), r#"<U4>;
-// 1.11 in binary is 1.75
-assert_eq!(Fix::from_str_binary("1.11"), Ok(Fix::from_num(1.75)));
+assert_eq!(Fix::from_str_ratio("3/8"), Ok(Fix::from_num(0.375)));
+assert_eq!(Fix::from_str_ratio("1/3"), Ok(Fix::from_bits(5)));
 "#,
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"assert_eq!(Fix::from_str_binary("-1.11"), Ok(Fix::from_num(-1.75)));
+                r#"assert_eq!(Fix::from_str_ratio("-1/3"), Ok(Fix::from_bits(-5)));
 "#,
             },
-            r#"
-// 111.0101 in binary is 7.3125
-assert_eq!(Fix::from_str_binary("1.110101e2"), Ok(Fix::from_num(7.3125)));
-assert_eq!(Fix::from_str_binary("11101.01e-2"), Ok(Fix::from_num(7.3125)));
+            r#"assert_eq!(Fix::from_str_ratio("5"), Ok(Fix::from_num(5)));
+assert!(Fix::from_str_ratio("1/0").is_err());
 ```
 "#;
             #[inline]
-            pub const fn from_str_binary(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
-                Self::from_ascii_binary(src.as_bytes())
+            pub fn from_str_ratio(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_ratio(src) {
+                    Ok((val, false)) => Ok(val),
+                    Ok((_, true)) => Err(ParseFixedError::overflow(10)),
+                    Err(e) => Err(e),
+                }
             }
         }
 
         comment! {
-            "Parses a string slice containing octal digits to return a fixed-point number.
+            "Parses a string slice containing a ratio of two decimal integers,
+such as “`-3/7`”, to return a fixed-point number, saturating on overflow.
 
-Rounding is to the nearest, with ties rounded to even.
+The syntax is the same as for [`from_str_ratio`][Self::from_str_ratio].
+The quotient is rounded to the nearest, with ties rounded to even.
 
-The number can have an optional exponent. The separator “`e`”, “`E`” or “`@`”
-can be used to start an exponent, which is then followed by an optional sign
-“`+`” or “`-`”, and then by a decimal integer which is the exponent. The parsed
-value is scaled by 8 to the power of the exponent.
+# Examples
 
-Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
-parsed value is scaled by 2 to the power of the exponent. For example, for octal
-“`P6`” means ×2⁶, and is equivalent to “`E2`” which means ×8².
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+assert_eq!(I8F8::saturating_from_str_ratio("1000/3"), Ok(I8F8::MAX));
+assert_eq!(I8F8::saturating_from_str_ratio("-1000/3"), Ok(I8F8::MIN));
+"#,
+                r#"use fixed::types::U8F8;
+assert_eq!(U8F8::saturating_from_str_ratio("1000/3"), Ok(U8F8::MAX));
+assert_eq!(U8F8::saturating_from_str_ratio("-1/3"), Ok(U8F8::ZERO));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn saturating_from_str_ratio(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_ratio(src) {
+                    Ok((val, false)) => Ok(val),
+                    Ok((_, true)) => {
+                        if src.starts_with('-') {
+                            Ok(Self::MIN)
+                        } else {
+                            Ok(Self::MAX)
+                        }
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }
+
+        comment! {
+            "Parses a string slice containing a ratio of two decimal integers,
+such as “`-3/7`”, to return a fixed-point number, wrapping on overflow.
+
+The syntax is the same as for [`from_str_ratio`][Self::from_str_ratio].
+The quotient is rounded to the nearest, with ties rounded to even.
 
 # Examples
 
 ```rust
-use fixed::types::extra::U4;
-use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), r#"<U4>;
-// 1.75 is 1.11 in binary, 1.6 in octal
-let f = Fix::from_str_octal("1.6");
-let check = Fix::from_bits(0b111 << (4 - 2));
-assert_eq!(f, Ok(check));
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+// 640/2 = 320, which wraps to 64
+assert_eq!(I8F8::wrapping_from_str_ratio("640/2"), Ok(I8F8::from_num(64)));
+assert_eq!(I8F8::wrapping_from_str_ratio("-640/2"), Ok(I8F8::from_num(-64)));
 "#,
-            if_signed_else_empty_str! {
-                $Signedness;
-                r#"let neg = Fix::from_str_octal("-1.6");
-assert_eq!(neg, Ok(-check));
+                r#"use fixed::types::U8F8;
+// 640/2 = 320, which wraps to 64
+assert_eq!(U8F8::wrapping_from_str_ratio("640/2"), Ok(U8F8::from_num(64)));
+assert_eq!(U8F8::wrapping_from_str_ratio("-128/2"), Ok(U8F8::from_num(192)));
 "#,
-            },
-            r#"assert_eq!(Fix::from_str_octal("160e-2"), Ok(check));
-```
-"#;
+            ),
+            "```
+";
             #[inline]
-            pub const fn from_str_octal(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
-                Self::from_ascii_octal(src.as_bytes())
+            pub fn wrapping_from_str_ratio(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
+                match Self::overflowing_from_str_ratio(src) {
+                    Ok((val, _)) => Ok(val),
+                    Err(e) => Err(e),
+                }
             }
         }
 
         comment! {
-            "Parses a string slice containing hexadecimal digits to return a fixed-point number.
+            "Parses a string slice containing a ratio of two decimal integers,
+such as “`-3/7`”, to return a fixed-point number.
 
-Rounding is to the nearest, with ties rounded to even.
+Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+whether an overflow has occurred. On overflow, the wrapped value is
+returned.
 
-The number can have an optional exponent. Since “`e`” and “`E`” are valid
-hexadecimal digits, they cannot be used as a separator to start an exponent, so
-“`@`” is used instead. This is then followed by an optional sign “`+`” or “`-`”,
-and then by a decimal integer which is the exponent. The parsed value is scaled
-by 16 to the power of the exponent.
+The syntax is the same as for [`from_str_ratio`][Self::from_str_ratio].
+The quotient is rounded to the nearest, with ties rounded to even.
 
-Base-2 exponents are supported too, using the separator “`p`” or “`P`”. The
-parsed value is scaled by 2 to the power of the exponent. For example, for
-hexadecimal “`P8`” means ×2⁸, and is equivalent to “`@2`” which means ×16².
+# Examples
+
+```rust
+",
+            if_signed_unsigned!(
+                $Signedness,
+                r#"use fixed::types::I8F8;
+let check = I8F8::from_num(64);
+assert_eq!(I8F8::overflowing_from_str_ratio("128/2"), Ok((check, false)));
+assert_eq!(I8F8::overflowing_from_str_ratio("640/2"), Ok((check, true)));
+"#,
+                r#"use fixed::types::U8F8;
+let check = U8F8::from_num(64);
+assert_eq!(U8F8::overflowing_from_str_ratio("128/2"), Ok((check, false)));
+assert_eq!(U8F8::overflowing_from_str_ratio("640/2"), Ok((check, true)));
+"#,
+            ),
+            "```
+";
+            #[inline]
+            pub fn overflowing_from_str_ratio(
+                src: &str,
+            ) -> Result<($Self<Frac>, bool), ParseFixedError> {
+                match from_str::overflowing_from_str_ratio(
+                    src,
+                    Self::FRAC_NBITS,
+                    $Inner::BITS,
+                    Self::IS_SIGNED,
+                ) {
+                    Ok((bits, overflow)) => Ok(($Self::from_bits(bits.lo as $Inner), overflow)),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+
+        comment! {
+            "Returns the ratio of two integers that is closest to the value, with a
+denominator no larger than `max_den`.
+
+The ratio is returned as a [tuple] `(num, den)` in lowest terms, with
+1&nbsp;≤&nbsp;`den`&nbsp;≤&nbsp;`max_den`. It is found using continued
+fractions, and the numerator is also kept within the range of [`",
+            stringify!($Inner),
+            "`], so that the ratio can be passed back to
+[`from_ratio`][Self::from_ratio]. If two ratios are equally close, the one
+with the smaller denominator is returned, and if the denominators are equal,
+the one with the smaller numerator magnitude is returned.
+
+# Panics
+
+Panics if `max_den` is ",
+            if_signed_unsigned!($Signedness, "not positive", "zero"),
+            ".
 
 # Examples
 
 ```rust
 use fixed::types::extra::U4;
 use fixed::", stringify!($Self), ";
-type Fix = ", stringify!($Self), r#"<U4>;
-// 1.C in hexadecimal is 1.75
-assert_eq!(Fix::from_str_hex("1.C"), Ok(Fix::from_num(1.75)));
-"#,
+type Fix = ", stringify!($Self), "<U4>;
+// 1.3125 is exactly 21/16
+assert_eq!(Fix::from_num(1.3125).to_ratio(16), (21, 16));
+// 4/3 = 1.333… is the closest ratio with a denominator up to 4
+assert_eq!(Fix::from_num(1.3125).to_ratio(4), (4, 3));
+",
             if_signed_else_empty_str! {
                 $Signedness;
-                r#"assert_eq!(Fix::from_str_hex("-1.C"), Ok(Fix::from_num(-1.75)));
-"#,
+                "assert_eq!(Fix::from_num(-1.3125).to_ratio(4), (-4, 3));
+",
             },
-            r#"assert_eq!(Fix::from_str_hex("1C@-1"), Ok(Fix::from_num(1.75)));
-assert_eq!(Fix::from_str_hex(".01C@+2"), Ok(Fix::from_num(1.75)));
-```
-"#;
+            "```
+";
             #[inline]
-            pub const fn from_str_hex(src: &str) -> Result<$Self<Frac>, ParseFixedError> {
-                Self::from_ascii_hex(src.as_bytes())
+            #[track_caller]
+            #[must_use]
+            pub const fn to_ratio(self, max_den: $Inner) -> ($Inner, $Inner) {
+                #[allow(unused_comparisons)]
+                if max_den <= 0 {
+                    panic!("non-positive maximum denominator");
+                }
+                let (neg, abs) = int_helper::$Inner::neg_abs(self.to_bits());
+                // the magnitude of a negative numerator can be one more than MAX
+                let max_num = $Inner::MAX as u128 + neg as u128;
+                let (num, den) = ratio::best_ratio(
+                    int256::U256 {
+                        lo: abs as u128,
+                        hi: 0,
+                    },
+                    Self::FRAC_NBITS,
+                    int256::U256 {
+                        lo: max_num,
+                        hi: 0,
+                    },
+                    int256::U256 {
+                        lo: max_den as u128,
+                        hi: 0,
+                    },
+                );
+                let num = num.lo as $Inner;
+                (if neg { num.wrapping_neg() } else { num }, den.lo as $Inner)
             }
         }
 
@@ -834,6 +834,62 @@ assert!(half.is_power_of_two());
                 }
             }
 
+            comment! {
+                "Multiplies two fixed-point numbers exactly, returning a type
+that is computed from the types of the operands.
+
+`rhs` can be any ",
+                if_signed_unsigned!($Signedness, "signed", "unsigned"),
+                " fixed-point number. If `self` has ", $n, " bits and <i>f</i>
+fractional bits, and `rhs` has <i>n</i> bits and <i>g</i> fractional bits, then
+the product is the narrowest fixed-point number with at least ", $n,
+                "&nbsp;+&nbsp;<i>n</i> bits, and it has
+<code>[Sum]&lt;Frac, RhsFrac></code>, that is <i>f</i>&nbsp;+&nbsp;<i>g</i>,
+fractional bits. The product cannot overflow and does not lose precision.
+
+This method is provided for convenience; see the [`MulExact`] trait for
+details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U2, U4};
+use fixed::", stringify!($Self), ";
+",
+                if_signed_unsigned!(
+                    $Signedness,
+                    "// decimal: -1.25 × 1.0625 = -1.328_125
+// binary: -1.01 × 1.0001 = -1.010101
+let a = ",
+                    "// decimal: 1.25 × 1.0625 = 1.328_125
+// binary: 1.01 × 1.0001 = 1.010101
+let a = ",
+                ),
+                stringify!($Self), "::<U2>::from_num(",
+                if_signed_unsigned!($Signedness, "-1.25", "1.25"),
+                ");
+let b = ", stringify!($Self), "::<U4>::from_num(1.0625);
+assert_eq!(a.mul_exact(b), ",
+                if_signed_unsigned!($Signedness, "-1.328_125", "1.328_125"),
+                ");
+// the product of the maximum values does not overflow
+let max = ", stringify!($Self), "::<U4>::MAX;
+assert!(max.mul_exact(max) > max);
+```
+
+[Sum]: crate::types::extra::Sum
+[`MulExact`]: crate::traits::MulExact
+";
+                #[inline]
+                #[must_use = "this returns the result of the operation, without modifying the original"]
+                pub fn mul_exact<Rhs>(self, rhs: Rhs) -> <Self as MulExact<Rhs>>::Output
+                where
+                    Self: MulExact<Rhs>,
+                {
+                    <Self as MulExact<Rhs>>::mul_exact(self, rhs)
+                }
+            }
+
             $(
                 comment! {
                     "Multiplies two fixed-point numbers and returns a
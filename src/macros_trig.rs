@@ -0,0 +1,1516 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+macro_rules! fixed_trig {
+    (
+        {Self, Inner} = {$Self:ident, $Inner:ident},
+        LeEqU = $LeEqU:ident,
+        {nm4, nm3, nm1, n} = {$nm4:literal, $nm3:literal, $nm1:literal, $n:literal},
+        UAngleFrac = $UAngleFrac:ident,
+    ) => {
+        /// This block contains trigonometric functions, which are only
+        /// available for signed fixed-point numbers.
+        impl<Frac: $LeEqU> $Self<Frac> {
+            comment! {
+                "Sine.
+
+The argument is in radians. The sine is computed using the CORDIC algorithm,
+and the error is &lt;&nbsp;[`DELTA`][Self::DELTA] for fixed-point numbers with
+up to 64 bits, and &lt;&nbsp;2&nbsp;×&nbsp;`DELTA` for 128-bit fixed-point
+numbers.
+
+The sine cannot overflow, since |sin&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.sin(), Fix::ZERO);
+assert_eq!(Fix::FRAC_PI_2.sin(), Fix::ONE);
+assert_eq!((-Fix::FRAC_PI_2).sin(), Fix::NEG_ONE);
+let half = Fix::FRAC_PI_6.sin();
+assert!((half - Fix::from_num(0.5)).abs() <= Fix::DELTA);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sin(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_sin();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Cosine.
+
+The argument is in radians. The cosine is computed using the CORDIC algorithm,
+and the error is &lt;&nbsp;[`DELTA`][Self::DELTA] for fixed-point numbers with
+up to 64 bits, and &lt;&nbsp;2&nbsp;×&nbsp;`DELTA` for 128-bit fixed-point
+numbers.
+
+Overflow can only occur when there are less than two integer bits, in which case
+the result 1 may not be representable.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cos`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.cos(), Fix::ONE);
+assert_eq!(Fix::PI.cos(), Fix::NEG_ONE);
+let half = Fix::FRAC_PI_3.cos();
+assert!((half - Fix::from_num(0.5)).abs() <= Fix::DELTA);
+```
+
+[`wrapping_cos`]: Self::wrapping_cos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cos(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_cos();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Simultaneously computes the sine and cosine.
+
+Returns a [tuple] (sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>). This is more
+efficient than calling both [`sin`][Self::sin] and [`cos`][Self::cos], and the
+results are identical to the results of those two methods.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the cosine overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sin_cos`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let x = Fix::from_num(0.75);
+assert_eq!(x.sin_cos(), (x.sin(), x.cos()));
+assert_eq!(Fix::ZERO.sin_cos(), (Fix::ZERO, Fix::ONE));
+```
+
+[`wrapping_sin_cos`]: Self::wrapping_sin_cos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sin_cos(self) -> ($Self<Frac>, $Self<Frac>) {
+                    let (ans, overflow) = self.overflowing_sin_cos();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked sine. Returns the sine, or [`None`] on overflow.
+
+Since the sine cannot overflow, this method always returns [`Some`]. See
+[`sin`][Self::sin] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::FRAC_PI_2.checked_sin(), Some(Fix::ONE));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin(self) -> Option<$Self<Frac>> {
+                    match self.overflowing_sin() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked cosine. Returns the cosine, or [`None`] on overflow.
+
+See [`cos`][Self::cos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.checked_cos(), Some(Fix::ONE));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.checked_cos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cos(self) -> Option<$Self<Frac>> {
+                    match self.overflowing_cos() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Checked simultaneous sine and cosine. Returns a [tuple]
+(sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>), or [`None`] on overflow.
+
+See [`sin_cos`][Self::sin_cos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.checked_sin_cos(), Some((Fix::ZERO, Fix::ONE)));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.checked_sin_cos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sin_cos(self) -> Option<($Self<Frac>, $Self<Frac>)> {
+                    match self.overflowing_sin_cos() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating sine. Returns the sine, saturating on overflow.
+
+Since the sine cannot overflow, this method is equivalent to
+[`sin`][Self::sin].
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::FRAC_PI_2.saturating_sin(), Fix::ONE);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sin(self) -> $Self<Frac> {
+                    match self.overflowing_sin() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating cosine. Returns the cosine, saturating on overflow.
+
+Since the cosine can only overflow when the result 1 is not representable, the
+saturated value is always [`MAX`][Self::MAX].
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.saturating_cos(), Fix::ONE);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.saturating_cos(), OneIntBit::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cos(self) -> $Self<Frac> {
+                    match self.overflowing_cos() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating simultaneous sine and cosine. Returns a [tuple]
+(sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>), saturating on overflow.
+
+See [`saturating_cos`][Self::saturating_cos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.saturating_sin_cos(), (Fix::ZERO, Fix::ONE));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(
+    OneIntBit::ZERO.saturating_sin_cos(),
+    (OneIntBit::ZERO, OneIntBit::MAX)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sin_cos(self) -> ($Self<Frac>, $Self<Frac>) {
+                    let ((sin, _), (cos, cos_overflow)) = self.overflowing_sin_cos_separate();
+                    (sin, if cos_overflow { Self::MAX } else { cos })
+                }
+            }
+
+            comment! {
+                "Wrapping sine. Returns the sine, wrapping on overflow.
+
+Since the sine cannot overflow, this method is equivalent to
+[`sin`][Self::sin].
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::FRAC_PI_2.wrapping_sin(), Fix::ONE);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_sin();
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping cosine. Returns the cosine, wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_cos(), Fix::ONE);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.wrapping_cos(), OneIntBit::NEG_ONE);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cos(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_cos();
+                    ans
+                }
+            }
+
+            comment! {
+                "Wrapping simultaneous sine and cosine. Returns a [tuple]
+(sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>), wrapping on overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_sin_cos(), (Fix::ZERO, Fix::ONE));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(
+    OneIntBit::ZERO.wrapping_sin_cos(),
+    (OneIntBit::ZERO, OneIntBit::NEG_ONE)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sin_cos(self) -> ($Self<Frac>, $Self<Frac>) {
+                    let (ans, _) = self.overflowing_sin_cos();
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped sine. Returns the sine, panicking on overflow.
+
+Since the sine cannot overflow, this method is equivalent to
+[`sin`][Self::sin] and never panics.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::FRAC_PI_2.unwrapped_sin(), Fix::ONE);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sin(self) -> $Self<Frac> {
+                    match self.overflowing_sin() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped cosine. Returns the cosine, panicking on overflow.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_cos(), Fix::ONE);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let _overflow = OneIntBit::ZERO.unwrapped_cos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_cos(self) -> $Self<Frac> {
+                    match self.overflowing_cos() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Unwrapped simultaneous sine and cosine. Returns a [tuple]
+(sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>), panicking on overflow.
+
+# Panics
+
+Panics if the cosine does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_sin_cos(), (Fix::ZERO, Fix::ONE));
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let _overflow = OneIntBit::ZERO.unwrapped_sin_cos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sin_cos(self) -> ($Self<Frac>, $Self<Frac>) {
+                    match self.overflowing_sin_cos() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing sine.
+
+Returns a [tuple] of the sine and [`false`], since the sine cannot overflow.
+See [`sin`][Self::sin] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::FRAC_PI_2.overflowing_sin(), (Fix::ONE, false));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sin(self) -> ($Self<Frac>, bool) {
+                    let (sin, _) = self.overflowing_sin_cos_separate();
+                    sin
+                }
+            }
+
+            comment! {
+                "Overflowing cosine.
+
+Returns a [tuple] of the cosine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`cos`][Self::cos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_cos(), (Fix::ONE, false));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.overflowing_cos(), (OneIntBit::NEG_ONE, true));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cos(self) -> ($Self<Frac>, bool) {
+                    let (_, cos) = self.overflowing_sin_cos_separate();
+                    cos
+                }
+            }
+
+            comment! {
+                "Overflowing simultaneous sine and cosine.
+
+Returns a [tuple] of (sin&nbsp;<i>x</i>,&nbsp;cos&nbsp;<i>x</i>) and a
+[`bool`] indicating whether an overflow has occurred. On overflow, the wrapped
+value is returned.
+
+See [`sin_cos`][Self::sin_cos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_sin_cos(), ((Fix::ZERO, Fix::ONE), false));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(
+    OneIntBit::ZERO.overflowing_sin_cos(),
+    ((OneIntBit::ZERO, OneIntBit::NEG_ONE), true)
+);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sin_cos(self) -> (($Self<Frac>, $Self<Frac>), bool) {
+                    let ((sin, sin_overflow), (cos, cos_overflow)) =
+                        self.overflowing_sin_cos_separate();
+                    ((sin, cos), sin_overflow || cos_overflow)
+                }
+            }
+
+            #[inline]
+            const fn overflowing_sin_cos_separate(
+                self,
+            ) -> (($Self<Frac>, bool), ($Self<Frac>, bool)) {
+                let ((sin, sin_overflow), (cos, cos_overflow)) =
+                    trig::sin_cos::$Inner(self.to_bits(), Self::FRAC_NBITS);
+                (
+                    (Self::from_bits(sin), sin_overflow),
+                    (Self::from_bits(cos), cos_overflow),
+                )
+            }
+
+            comment! {
+                "Arctangent.
+
+The result is an angle in radians in the range
+&minus;π/2&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2. The returned fixed-point
+number has the same number of bits as `self` and three integer bits, that is
+<code>", stringify!($Self), "\\<[U", $nm3, "][crate::types::extra::U", $nm3, "]></code>,
+which is the type with the most fractional bits that can hold ±π. This is the
+same type as the one returned by [`atan2`][Self::atan2].
+
+The arctangent is computed using the CORDIC algorithm with extra precision, and
+the result is rounded toward zero, so that the error is &lt;&nbsp;`DELTA` of the
+returned type, and so that
+atan&nbsp;(&minus;<i>x</i>)&nbsp;=&nbsp;&minus;atan&nbsp;<i>x</i>. The result is
+exact for zero and ±1, where it is 0 and ±π/4 rounded toward zero, matching the
+returned type’s constants.
+
+The arctangent cannot overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm3, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+type Angle = ", stringify!($Self), "<U", $nm3, ">;
+assert_eq!(Fix::ZERO.atan(), Angle::ZERO);
+assert_eq!(Fix::ONE.atan(), Angle::FRAC_PI_4);
+assert_eq!(Fix::NEG_ONE.atan(), -Angle::FRAC_PI_4);
+// atan 2 = 1.107…
+let x = Fix::from_num(2).atan();
+assert!((x - Angle::from_num(1.107)).abs() < 0.01);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn atan(self) -> $Self<$UAngleFrac> {
+                    $Self::from_bits(trig::atan::$Inner(self.to_bits(), Self::FRAC_NBITS))
+                }
+            }
+
+            comment! {
+                "Four-quadrant arctangent of `self` and `other`.
+
+This is the angle in radians of the point (`other`,&nbsp;`self`), that is `self`
+is the <i>y</i> coordinate and `other` is the <i>x</i> coordinate. The result
+is in the range &minus;π&nbsp;&lt;&nbsp;<i>θ</i>&nbsp;≤&nbsp;π, and if both
+`self` and `other` are zero, the result is zero. The returned fixed-point number
+has the same number of bits as `self` and three integer bits, that is
+<code>", stringify!($Self), "\\<[U", $nm3, "][crate::types::extra::U", $nm3, "]></code>,
+which is the type with the most fractional bits that can hold ±π.
+
+The arctangent is computed using the CORDIC algorithm with extra precision, and
+the result is rounded toward zero, so that the error is &lt;&nbsp;`DELTA` of the
+returned type. The results are exact on the axes and on the diagonals, where
+they are multiples of π/4 rounded toward zero; for example they match the
+returned type’s constants [`FRAC_PI_4`][Self::FRAC_PI_4],
+[`FRAC_PI_2`][Self::FRAC_PI_2] and [`PI`][Self::PI].
+
+The arctangent cannot overflow.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm3, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+type Angle = ", stringify!($Self), "<U", $nm3, ">;
+let one = Fix::ONE;
+assert_eq!(Fix::ZERO.atan2(one), Angle::ZERO);
+assert_eq!(one.atan2(one), Angle::FRAC_PI_4);
+assert_eq!(one.atan2(Fix::ZERO), Angle::FRAC_PI_2);
+assert_eq!((-one).atan2(Fix::ZERO), -Angle::FRAC_PI_2);
+assert_eq!(Fix::ZERO.atan2(-one), Angle::PI);
+assert_eq!(Fix::from_num(3).atan2(Fix::from_num(-3)), -(-one).atan2(-one));
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn atan2(self, other: $Self<Frac>) -> $Self<$UAngleFrac> {
+                    $Self::from_bits(trig::atan2::$Inner(self.to_bits(), other.to_bits()))
+                }
+            }
+
+            comment! {
+                "Arcsine.
+
+The result is an angle in radians in the range
+&minus;π/2&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π/2.
+
+The result is rounded toward zero, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow can only occur when there are less than two integer bits, in which case
+π/2 is not representable.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_asin`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.asin(), Fix::ZERO);
+assert_eq!(Fix::ONE.asin(), Fix::FRAC_PI_2);
+assert_eq!(Fix::NEG_ONE.asin(), -Fix::FRAC_PI_2);
+let x = Fix::from_num(0.5).asin();
+assert!((x - Fix::FRAC_PI_6).abs() <= Fix::DELTA);
+```
+
+[`wrapping_asin`]: Self::wrapping_asin
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn asin(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_asin();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked arcsine. Returns the arcsine, or [`None`] if `self` is
+outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on overflow.
+
+See [`asin`][Self::asin] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(2).checked_asin(), None);
+assert_eq!(Fix::from_num(-2).checked_asin(), None);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::NEG_ONE.checked_asin(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_asin(self) -> Option<$Self<Frac>> {
+                    match trig::asin_acos::$Inner(self.to_bits(), Self::FRAC_NBITS) {
+                        Some(both) => match both.0 {
+                            (ans, false) => Some(Self::from_bits(ans)),
+                            (_, true) => None,
+                        },
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating arcsine. Returns the arcsine, saturating on overflow.
+
+Overflow can only occur when there are less than two integer bits. In that
+case, [`MAX`][Self::MAX] is returned for positive arguments and
+[`MIN`][Self::MIN] is returned for negative arguments.
+
+See [`asin`][Self::asin] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ONE.saturating_asin(), Fix::FRAC_PI_2);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::NEG_ONE.saturating_asin(), OneIntBit::MIN);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_asin(self) -> $Self<Frac> {
+                    match self.overflowing_asin() {
+                        (ans, false) => ans,
+                        (_, true) => {
+                            if self.is_negative() {
+                                Self::MIN
+                            } else {
+                                Self::MAX
+                            }
+                        }
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping arcsine. Returns the arcsine, wrapping on overflow.
+
+See [`asin`][Self::asin] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ONE.wrapping_asin(), Fix::FRAC_PI_2);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+// −π/2 wraps to 2 − π/2
+let wrapped = OneIntBit::NEG_ONE.wrapping_asin();
+assert!((wrapped - OneIntBit::from_num(0.429)).abs() < 0.001);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_asin(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_asin();
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped arcsine. Returns the arcsine, panicking on overflow.
+
+See [`asin`][Self::asin] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ONE.unwrapped_asin(), Fix::FRAC_PI_2);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let _overflow = OneIntBit::NEG_ONE.unwrapped_asin();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_asin(self) -> $Self<Frac> {
+                    match self.overflowing_asin() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing arcsine.
+
+Returns a [tuple] of the arcsine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`asin`][Self::asin] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ONE.overflowing_asin(), (Fix::FRAC_PI_2, false));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let (wrapped, overflow) = OneIntBit::NEG_ONE.overflowing_asin();
+assert!(overflow);
+// −π/2 wraps to 2 − π/2
+assert!((wrapped - OneIntBit::from_num(0.429)).abs() < 0.001);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_asin(self) -> ($Self<Frac>, bool) {
+                    match trig::asin_acos::$Inner(self.to_bits(), Self::FRAC_NBITS) {
+                        Some(both) => {
+                            let (ans, overflow) = both.0;
+                            (Self::from_bits(ans), overflow)
+                        }
+                        None => panic!("arcsine argument out of range"),
+                    }
+                }
+            }
+
+            comment! {
+                "Arccosine.
+
+The result is an angle in radians in the range
+0&nbsp;≤&nbsp;<i>θ</i>&nbsp;≤&nbsp;π.
+
+The result is rounded toward zero, so the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow can only occur when there are less than three integer bits, in which
+case π is not representable.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+When debug assertions are enabled, this method also panics if the result
+overflows. When debug assertions are not enabled, the wrapped value can be
+returned, but it is not considered a breaking change if in the future it panics;
+if wrapping is required use [`wrapping_acos`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ONE.acos(), Fix::ZERO);
+assert_eq!(Fix::ZERO.acos(), Fix::FRAC_PI_2);
+assert_eq!(Fix::NEG_ONE.acos(), Fix::PI);
+let x = Fix::from_num(0.5).acos();
+assert!((x - Fix::FRAC_PI_3).abs() <= Fix::DELTA);
+```
+
+[`wrapping_acos`]: Self::wrapping_acos
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn acos(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_acos();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked arccosine. Returns the arccosine, or [`None`] if `self` is
+outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on overflow.
+
+See [`acos`][Self::acos] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::from_num(2).checked_acos(), None);
+assert_eq!(Fix::from_num(-2).checked_acos(), None);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.checked_acos(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_acos(self) -> Option<$Self<Frac>> {
+                    match trig::asin_acos::$Inner(self.to_bits(), Self::FRAC_NBITS) {
+                        Some(both) => match both.1 {
+                            (ans, false) => Some(Self::from_bits(ans)),
+                            (_, true) => None,
+                        },
+                        None => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating arccosine. Returns the arccosine, saturating on overflow.
+
+Overflow can only occur when there are less than three integer bits. Since the
+arccosine is never negative, [`MAX`][Self::MAX] is returned on overflow.
+
+See [`acos`][Self::acos] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::NEG_ONE.saturating_acos(), Fix::PI);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+assert_eq!(OneIntBit::ZERO.saturating_acos(), OneIntBit::MAX);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn saturating_acos(self) -> $Self<Frac> {
+                    match self.overflowing_acos() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping arccosine. Returns the arccosine, wrapping on overflow.
+
+See [`acos`][Self::acos] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::NEG_ONE.wrapping_acos(), Fix::PI);
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+// π/2 wraps to π/2 − 2
+let wrapped = OneIntBit::ZERO.wrapping_acos();
+assert!((wrapped + OneIntBit::from_num(0.429)).abs() < 0.001);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn wrapping_acos(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_acos();
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped arccosine. Returns the arccosine, panicking on overflow.
+
+See [`acos`][Self::acos] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+or if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::NEG_ONE.unwrapped_acos(), Fix::PI);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm1, ";
+use fixed::", stringify!($Self), ";
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let _overflow = OneIntBit::ZERO.unwrapped_acos();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_acos(self) -> $Self<Frac> {
+                    match self.overflowing_acos() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing arccosine.
+
+Returns a [tuple] of the arccosine and a [`bool`] indicating whether an overflow
+has occurred. On overflow, the wrapped value is returned.
+
+See [`acos`][Self::acos] for details.
+
+# Panics
+
+Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $nm1, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::NEG_ONE.overflowing_acos(), (Fix::PI, false));
+type OneIntBit = ", stringify!($Self), "<U", $nm1, ">;
+let (wrapped, overflow) = OneIntBit::ZERO.overflowing_acos();
+assert!(overflow);
+// π/2 wraps to π/2 − 2
+assert!((wrapped + OneIntBit::from_num(0.429)).abs() < 0.001);
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn overflowing_acos(self) -> ($Self<Frac>, bool) {
+                    match trig::asin_acos::$Inner(self.to_bits(), Self::FRAC_NBITS) {
+                        Some(both) => {
+                            let (ans, overflow) = both.1;
+                            (Self::from_bits(ans), overflow)
+                        }
+                        None => panic!("arccosine argument out of range"),
+                    }
+                }
+            }
+
+            comment! {
+                "Hyperbolic sine, sinh&nbsp;<i>x</i>.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Since the result is computed from <i>e</i><sup><i>x</i></sup> and
+<i>e</i><sup>&minus;<i>x</i></sup> using a wide intermediate precision, there is no
+loss of accuracy for small <i>x</i>. Overflow occurs if the result is greater
+than [`MAX`][Self::MAX] or less than [`MIN`][Self::MIN].
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_sinh`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.sinh(), Fix::ZERO);
+// sinh 1 ≈ 1.175
+assert!(Fix::ONE.sinh().dist(Fix::from_num(1.175)) < 0.001);
+assert_eq!(Fix::NEG_ONE.sinh(), -Fix::ONE.sinh());
+```
+
+[`wrapping_sinh`]: Self::wrapping_sinh
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn sinh(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_sinh();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic sine. Returns the hyperbolic sine, or [`None`] on
+overflow.
+
+See [`sinh`][Self::sinh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.checked_sinh(), Some(Fix::ZERO));
+assert_eq!(Fix::from_num(3).checked_sinh(), None);
+assert_eq!(Fix::from_num(-3).checked_sinh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_sinh(self) -> Option<$Self<Frac>> {
+                    match self.overflowing_sinh() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating hyperbolic sine. Returns the hyperbolic sine, saturating on
+overflow.
+
+Overflow can occur in both directions, so [`MIN`][Self::MIN] is returned if
+`self` is negative and [`MAX`][Self::MAX] is returned otherwise.
+
+See [`sinh`][Self::sinh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.saturating_sinh(), Fix::ZERO);
+assert_eq!(Fix::from_num(3).saturating_sinh(), Fix::MAX);
+assert_eq!(Fix::from_num(-3).saturating_sinh(), Fix::MIN);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_sinh(self) -> $Self<Frac> {
+                    match self.overflowing_sinh() {
+                        (ans, false) => ans,
+                        (_, true) => if self.is_negative() { Self::MIN } else { Self::MAX },
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping hyperbolic sine. Returns the hyperbolic sine, wrapping on
+overflow.
+
+See [`sinh`][Self::sinh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_sinh(), Fix::ZERO);
+// sinh 3 ≈ 10.018 wraps to 10.018 &minus; 16 = &minus;5.982
+let wrapped = Fix::from_num(3).wrapping_sinh();
+assert!(wrapped.dist(Fix::from_num(-5.982)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_sinh(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_sinh();
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped hyperbolic sine. Returns the hyperbolic sine, panicking on
+overflow.
+
+See [`sinh`][Self::sinh] for details.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_sinh(), Fix::ZERO);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let _overflow = Fix::from_num(3).unwrapped_sinh();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_sinh(self) -> $Self<Frac> {
+                    match self.overflowing_sinh() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing hyperbolic sine.
+
+Returns a [tuple] of the hyperbolic sine and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`sinh`][Self::sinh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_sinh(), (Fix::ZERO, false));
+// sinh 3 ≈ 10.018 wraps to 10.018 &minus; 16 = &minus;5.982
+let (wrapped, overflow) = Fix::from_num(3).overflowing_sinh();
+assert!(overflow);
+assert!(wrapped.dist(Fix::from_num(-5.982)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_sinh(self) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) =
+                        exp::hyperbolic::$Inner(self.to_bits(), Self::FRAC_NBITS, Hyperbolic::Sinh);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Hyperbolic cosine, cosh&nbsp;<i>x</i>.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+Overflow occurs if the result is greater than [`MAX`][Self::MAX], which always
+happens when there are no integer bits other than the sign bit, since
+cosh&nbsp;<i>x</i>&nbsp;≥&nbsp;1.
+
+# Panics
+
+When debug assertions are enabled, this method panics if the result overflows.
+When debug assertions are not enabled, the wrapped value can be returned, but it
+is not considered a breaking change if in the future it panics; if wrapping is
+required use [`wrapping_cosh`] instead.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.cosh(), Fix::ONE);
+// cosh 1 ≈ 1.543
+assert!(Fix::ONE.cosh().dist(Fix::from_num(1.543)) < 0.001);
+assert_eq!(Fix::NEG_ONE.cosh(), Fix::ONE.cosh());
+```
+
+[`wrapping_cosh`]: Self::wrapping_cosh
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn cosh(self) -> $Self<Frac> {
+                    let (ans, overflow) = self.overflowing_cosh();
+                    debug_assert!(!overflow, "overflow");
+                    ans
+                }
+            }
+
+            comment! {
+                "Checked hyperbolic cosine. Returns the hyperbolic cosine, or [`None`] on
+overflow.
+
+See [`cosh`][Self::cosh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.checked_cosh(), Some(Fix::ONE));
+assert_eq!(Fix::from_num(3).checked_cosh(), None);
+assert_eq!(Fix::from_num(-3).checked_cosh(), None);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn checked_cosh(self) -> Option<$Self<Frac>> {
+                    match self.overflowing_cosh() {
+                        (ans, false) => Some(ans),
+                        (_, true) => None,
+                    }
+                }
+            }
+
+            comment! {
+                "Saturating hyperbolic cosine. Returns the hyperbolic cosine, saturating on
+overflow.
+
+Since the result is never less than one, overflow can only occur in the
+positive direction, and [`MAX`][Self::MAX] is returned on overflow.
+
+See [`cosh`][Self::cosh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.saturating_cosh(), Fix::ONE);
+assert_eq!(Fix::from_num(3).saturating_cosh(), Fix::MAX);
+assert_eq!(Fix::from_num(-3).saturating_cosh(), Fix::MAX);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn saturating_cosh(self) -> $Self<Frac> {
+                    match self.overflowing_cosh() {
+                        (ans, false) => ans,
+                        (_, true) => Self::MAX,
+                    }
+                }
+            }
+
+            comment! {
+                "Wrapping hyperbolic cosine. Returns the hyperbolic cosine, wrapping on
+overflow.
+
+See [`cosh`][Self::cosh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.wrapping_cosh(), Fix::ONE);
+// cosh 3 ≈ 10.068 wraps to 10.068 &minus; 16 = &minus;5.932
+let wrapped = Fix::from_num(3).wrapping_cosh();
+assert!(wrapped.dist(Fix::from_num(-5.932)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn wrapping_cosh(self) -> $Self<Frac> {
+                    let (ans, _) = self.overflowing_cosh();
+                    ans
+                }
+            }
+
+            comment! {
+                "Unwrapped hyperbolic cosine. Returns the hyperbolic cosine, panicking on
+overflow.
+
+See [`cosh`][Self::cosh] for details.
+
+# Panics
+
+Panics if the result does not fit.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.unwrapped_cosh(), Fix::ONE);
+```
+
+The following panics because of overflow.
+
+```rust,should_panic
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+let _overflow = Fix::from_num(3).unwrapped_cosh();
+```
+";
+                #[inline]
+                #[track_caller]
+                #[must_use]
+                pub const fn unwrapped_cosh(self) -> $Self<Frac> {
+                    match self.overflowing_cosh() {
+                        (ans, false) => ans,
+                        (_, true) => panic!("overflow"),
+                    }
+                }
+            }
+
+            comment! {
+                "Overflowing hyperbolic cosine.
+
+Returns a [tuple] of the hyperbolic cosine and a [`bool`] indicating whether an
+overflow has occurred. On overflow, the wrapped value is returned.
+
+See [`cosh`][Self::cosh] for details.
+
+# Examples
+
+```rust
+use fixed::types::extra::U", $nm4, ";
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.overflowing_cosh(), (Fix::ONE, false));
+// cosh 3 ≈ 10.068 wraps to 10.068 &minus; 16 = &minus;5.932
+let (wrapped, overflow) = Fix::from_num(3).overflowing_cosh();
+assert!(overflow);
+assert!(wrapped.dist(Fix::from_num(-5.932)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn overflowing_cosh(self) -> ($Self<Frac>, bool) {
+                    let (ans, overflow) =
+                        exp::hyperbolic::$Inner(self.to_bits(), Self::FRAC_NBITS, Hyperbolic::Cosh);
+                    (Self::from_bits(ans), overflow)
+                }
+            }
+
+            comment! {
+                "Hyperbolic tangent, tanh&nbsp;<i>x</i>.
+
+The result is rounded to the nearest, and the error is
+&lt;&nbsp;[`DELTA`][Self::DELTA].
+
+This method cannot overflow, since |tanh&nbsp;<i>x</i>|&nbsp;≤&nbsp;|<i>x</i>|
+and |tanh&nbsp;<i>x</i>|&nbsp;<&nbsp;1, so there are no checked, saturating,
+wrapping, unwrapped or overflowing variants.
+
+# Examples
+
+```rust
+use fixed::types::extra::{U", $nm4, ", U", $n, "};
+use fixed::", stringify!($Self), ";
+type Fix = ", stringify!($Self), "<U", $nm4, ">;
+assert_eq!(Fix::ZERO.tanh(), Fix::ZERO);
+// tanh 1 ≈ 0.762
+assert!(Fix::ONE.tanh().dist(Fix::from_num(0.762)) < 0.001);
+assert!(Fix::MAX.tanh() <= Fix::ONE);
+assert!(Fix::MIN.tanh() >= Fix::NEG_ONE);
+type AllFrac = ", stringify!($Self), "<U", $n, ">;
+// tanh &minus;0.5 ≈ &minus;0.462
+assert!(AllFrac::MIN.tanh().dist(AllFrac::from_num(-0.462)) < 0.001);
+```
+";
+                #[inline]
+                #[must_use]
+                pub const fn tanh(self) -> $Self<Frac> {
+                    let (ans, _) =
+                        exp::hyperbolic::$Inner(self.to_bits(), Self::FRAC_NBITS, Hyperbolic::Tanh);
+                    Self::from_bits(ans)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,2897 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::fixed256;
+use crate::from_str::ParseFixedError;
+use crate::traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
+use crate::{
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
+};
+use core::fmt::{
+    Binary, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp, UpperHex,
+};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Provides arithmetic operations that wrap on overflow and record the
+/// overflow in a sticky flag.
+///
+/// Every operation returns the wrapped value together with an overflow flag
+/// which is the OR of the overflow flags of its operands and of the operation
+/// itself. Like the status register of a processor, once set the flag stays
+/// set until it is cleared explicitly with
+/// [`clear_overflow`][Overflowing::clear_overflow], so that a block of
+/// computations can be checked for overflow just once at the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed::Overflowing;
+/// let mut acc = Overflowing::new(I16F16::MAX);
+/// // the sum wraps
+/// acc += I16F16::DELTA;
+/// assert_eq!(acc.value, I16F16::MIN);
+/// // subtracting wraps back, but the flag is sticky
+/// acc -= I16F16::DELTA;
+/// assert_eq!(acc.value, I16F16::MAX);
+/// assert!(acc.overflow);
+///
+/// // clear the flag for the next block
+/// assert!(acc.clear_overflow());
+/// assert!(!acc.overflow);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Overflowing<F> {
+    /// The wrapped value.
+    pub value: F,
+    /// [`true`] if any operation leading to the value has overflowed.
+    pub overflow: bool,
+}
+
+impl<F: Fixed> Overflowing<F> {
+    /// Zero.
+    ///
+    /// See also <code>FixedI32::[ZERO][FixedI32::ZERO]</code> and
+    /// <code>FixedU32::[ZERO][FixedU32::ZERO]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO, Overflowing::new(I16F16::ZERO));
+    /// ```
+    pub const ZERO: Overflowing<F> = Overflowing::new(F::ZERO);
+
+    /// The difference between any two successive representable numbers, <i>Δ</i>.
+    ///
+    /// See also <code>FixedI32::[DELTA][FixedI32::DELTA]</code> and
+    /// <code>FixedU32::[DELTA][FixedU32::DELTA]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::DELTA, Overflowing::new(I16F16::DELTA));
+    /// ```
+    pub const DELTA: Overflowing<F> = Overflowing::new(F::DELTA);
+
+    /// The smallest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MIN][FixedI32::MIN]</code> and
+    /// <code>FixedU32::[MIN][FixedU32::MIN]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::MIN, Overflowing::new(I16F16::MIN));
+    /// ```
+    pub const MIN: Overflowing<F> = Overflowing::new(F::MIN);
+
+    /// The largest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MAX][FixedI32::MAX]</code> and
+    /// <code>FixedU32::[MAX][FixedU32::MAX]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::MAX, Overflowing::new(I16F16::MAX));
+    /// ```
+    pub const MAX: Overflowing<F> = Overflowing::new(F::MAX);
+
+    /// [`true`] if the type is signed.
+    ///
+    /// See also <code>FixedI32::[IS\_SIGNED][FixedI32::IS_SIGNED]</code> and
+    /// <code>FixedU32::[IS\_SIGNED][FixedU32::IS_SIGNED]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Overflowing;
+    /// assert!(Overflowing::<I16F16>::IS_SIGNED);
+    /// assert!(!Overflowing::<U16F16>::IS_SIGNED);
+    /// ```
+    pub const IS_SIGNED: bool = F::IS_SIGNED;
+
+    /// The number of integer bits.
+    ///
+    /// See also <code>FixedI32::[INT\_NBITS][FixedI32::INT_NBITS]</code> and
+    /// <code>FixedU32::[INT\_NBITS][FixedU32::INT_NBITS]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::INT_NBITS, I16F16::INT_NBITS);
+    /// ```
+    pub const INT_NBITS: u32 = F::INT_NBITS;
+
+    /// The number of fractional bits.
+    ///
+    /// See also <code>FixedI32::[FRAC\_NBITS][FixedI32::FRAC_NBITS]</code> and
+    /// <code>FixedU32::[FRAC\_NBITS][FixedU32::FRAC_NBITS]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::FRAC_NBITS, I16F16::FRAC_NBITS);
+    /// ```
+    pub const FRAC_NBITS: u32 = F::FRAC_NBITS;
+
+    /// Wraps a fixed-point number with the overflow flag cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let o = Overflowing::new(I16F16::from_num(1.5));
+    /// assert_eq!(o.value, 1.5);
+    /// assert!(!o.overflow);
+    /// ```
+    #[inline]
+    pub const fn new(value: F) -> Overflowing<F> {
+        Overflowing {
+            value,
+            overflow: false,
+        }
+    }
+
+    /// Clears the overflow flag, returning its previous state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let mut o = Overflowing::new(I16F16::MAX) + Overflowing::DELTA;
+    /// assert!(o.clear_overflow());
+    /// assert!(!o.overflow);
+    /// assert!(!o.clear_overflow());
+    /// ```
+    #[inline]
+    pub fn clear_overflow(&mut self) -> bool {
+        let overflow = self.overflow;
+        self.overflow = false;
+        overflow
+    }
+
+    /// Creates a fixed-point number that has a bitwise representation
+    /// identical to the given integer.
+    ///
+    /// The overflow flag is cleared.
+    ///
+    /// See also <code>FixedI32::[from\_bits][FixedI32::from_bits]</code> and
+    /// <code>FixedU32::[from\_bits][FixedU32::from_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let o = Overflowing::<I16F16>::from_bits(0x1C);
+    /// assert_eq!(o, Overflowing::new(I16F16::from_bits(0x1C)));
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: F::Bits) -> Overflowing<F> {
+        Overflowing::new(F::from_bits(bits))
+    }
+
+    /// Creates an integer that has a bitwise representation identical
+    /// to the given fixed-point number.
+    ///
+    /// See also <code>FixedI32::[to\_bits][FixedI32::to_bits]</code> and
+    /// <code>FixedU32::[to\_bits][FixedU32::to_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let o = Overflowing::new(I16F16::from_bits(0x1C));
+    /// assert_eq!(o.to_bits(), 0x1C);
+    /// ```
+    #[inline]
+    pub fn to_bits(self) -> F::Bits {
+        self.value.to_bits()
+    }
+
+    /// Creates a fixed-point number from another number.
+    ///
+    /// The value wraps if it does not fit, in which case the overflow flag is
+    /// set.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`].
+    ///   * A floating-point number of type
+    ///     <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other number `src` for which [`ToFixed`] is implemented.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num][FixedI32::overflowing_from_num]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num][FixedU32::overflowing_from_num]</code>.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is not [finite].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I4F4, I16F16};
+    /// use fixed::Overflowing;
+    /// let src = I16F16::from_num(1.75);
+    /// assert_eq!(Overflowing::<I4F4>::from_num(src), Overflowing::new(I4F4::from_num(1.75)));
+    ///
+    /// let too_large = I16F16::from_bits(0x1234_5678);
+    /// let o = Overflowing::<I4F4>::from_num(too_large);
+    /// assert_eq!(o.value, I4F4::from_bits(0x45));
+    /// assert!(o.overflow);
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    pub fn from_num<Src: ToFixed>(src: Src) -> Overflowing<F> {
+        let (value, overflow) = src.overflowing_to_fixed();
+        Overflowing { value, overflow }
+    }
+
+    /// Converts a fixed-point number to another number.
+    ///
+    /// Returns a [tuple] of the wrapped value and the overflow flag, which
+    /// is set if either `self` had its overflow flag set or the conversion
+    /// overflowed.
+    ///
+    /// The other number can be:
+    ///
+    ///   * Another fixed-point number. Any extra fractional bits are
+    ///     discarded, which rounds towards &minus;∞.
+    ///   * An integer of type [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+    ///     [`isize`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], or
+    ///     [`usize`]. Any fractional bits are discarded, which rounds
+    ///     towards &minus;∞.
+    ///   * A floating-point number of type
+    ///     <code>[half]::[f16][half::f16]</code>,
+    ///     <code>[half]::[bf16][half::bf16]</code>, [`f32`], [`f64`] or
+    ///     [`F128`]. For this conversion, the method rounds to the nearest,
+    ///     with ties rounding to even.
+    ///   * Any other type `Dst` for which [`FromFixed`] is implemented.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_to\_num][FixedI32::overflowing_to_num]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_to\_num][FixedU32::overflowing_to_num]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, I2F6, I4F4};
+    /// use fixed::Overflowing;
+    /// let src = Overflowing::new(I4F4::from_num(1.75));
+    /// assert_eq!(src.to_num::<I16F16>(), (I16F16::from_num(1.75), false));
+    ///
+    /// let max = Overflowing::new(I4F4::MAX);
+    /// assert_eq!(max.to_num::<I2F6>(), (I2F6::from_bits(-4), true));
+    /// ```
+    ///
+    /// [`F128`]: crate::F128
+    #[inline]
+    pub fn to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+        let (dst, overflow) = Dst::overflowing_from_fixed(self.value);
+        (dst, self.overflow | overflow)
+    }
+
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. The value wraps
+    /// if it does not fit, in which case the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str][FixedI32::overflowing_from_str]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str][FixedU32::overflowing_from_str]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Overflowing;
+    /// let check = I8F8::from_bits((16 << 8) + (3 << 8) / 4);
+    /// assert_eq!(Overflowing::<I8F8>::from_str_dec("16.75"), Ok(Overflowing::new(check)));
+    /// assert!(Overflowing::<I8F8>::from_str_dec("1000").unwrap().overflow);
+    /// ```
+    #[inline]
+    pub fn from_str_dec(src: &str) -> Result<Overflowing<F>, ParseFixedError> {
+        F::overflowing_from_str(src).map(|(value, overflow)| Overflowing { value, overflow })
+    }
+
+    /// Parses a string slice containing binary digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. The value wraps
+    /// if it does not fit, in which case the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_binary][FixedI32::overflowing_from_str_binary]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_binary][FixedU32::overflowing_from_str_binary]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Overflowing;
+    /// let check = I8F8::from_bits(0b1110001 << (8 - 1));
+    /// assert_eq!(Overflowing::<I8F8>::from_str_binary("111000.1"), Ok(Overflowing::new(check)));
+    /// ```
+    #[inline]
+    pub fn from_str_binary(src: &str) -> Result<Overflowing<F>, ParseFixedError> {
+        F::overflowing_from_str_binary(src).map(|(value, overflow)| Overflowing { value, overflow })
+    }
+
+    /// Parses a string slice containing octal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. The value wraps
+    /// if it does not fit, in which case the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_octal][FixedI32::overflowing_from_str_octal]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_octal][FixedU32::overflowing_from_str_octal]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Overflowing;
+    /// let check = I8F8::from_bits(0o1654 << (8 - 3));
+    /// assert_eq!(Overflowing::<I8F8>::from_str_octal("165.4"), Ok(Overflowing::new(check)));
+    /// ```
+    #[inline]
+    pub fn from_str_octal(src: &str) -> Result<Overflowing<F>, ParseFixedError> {
+        F::overflowing_from_str_octal(src).map(|(value, overflow)| Overflowing { value, overflow })
+    }
+
+    /// Parses a string slice containing hexadecimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. The value wraps
+    /// if it does not fit, in which case the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_hex][FixedI32::overflowing_from_str_hex]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_hex][FixedU32::overflowing_from_str_hex]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I8F8;
+    /// use fixed::Overflowing;
+    /// let check = I8F8::from_bits(0xFFE);
+    /// assert_eq!(Overflowing::<I8F8>::from_str_hex("F.FE"), Ok(Overflowing::new(check)));
+    /// ```
+    #[inline]
+    pub fn from_str_hex(src: &str) -> Result<Overflowing<F>, ParseFixedError> {
+        F::overflowing_from_str_hex(src).map(|(value, overflow)| Overflowing { value, overflow })
+    }
+
+    /// Returns the integer part.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operands.
+    ///
+    /// See also <code>FixedI32::[int][FixedI32::int]</code> and
+    /// <code>FixedU32::[int][FixedU32::int]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.25));
+    /// assert_eq!(x.int(), Overflowing::new(I16F16::from_num(12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn int(self) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.int(),
+            overflow: self.overflow,
+        }
+    }
+
+    /// Returns the fractional part.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operands.
+    ///
+    /// See also <code>FixedI32::[frac][FixedI32::frac]</code> and
+    /// <code>FixedU32::[frac][FixedU32::frac]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.25));
+    /// assert_eq!(x.frac(), Overflowing::new(I16F16::from_num(0.25)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn frac(self) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.frac(),
+            overflow: self.overflow,
+        }
+    }
+
+    /// Rounds to the next integer towards 0.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operand.
+    ///
+    /// See also
+    /// <code>FixedI32::[round\_to\_zero][FixedI32::round_to_zero]</code> and
+    /// <code>FixedU32::[round\_to\_zero][FixedU32::round_to_zero]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(-12.75));
+    /// assert_eq!(x.round_to_zero(), Overflowing::new(I16F16::from_num(-12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_to_zero(self) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.round_to_zero(),
+            overflow: self.overflow,
+        }
+    }
+
+    /// Rounds to the next integer towards +∞.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_ceil][FixedI32::overflowing_ceil]</code> and
+    /// <code>FixedU32::[overflowing\_ceil][FixedU32::overflowing_ceil]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.25));
+    /// assert_eq!(x.ceil(), Overflowing::new(I16F16::from_num(13)));
+    /// assert!(Overflowing::new(I16F16::MAX).ceil().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ceil(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_ceil())
+    }
+
+    /// Rounds to the next integer towards −∞.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_floor][FixedI32::overflowing_floor]</code> and
+    /// <code>FixedU32::[overflowing\_floor][FixedU32::overflowing_floor]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.75));
+    /// assert_eq!(x.floor(), Overflowing::new(I16F16::from_num(12)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn floor(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_floor())
+    }
+
+    /// Rounds to the nearest integer, with ties rounded away from zero.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_round][FixedI32::overflowing_round]</code> and
+    /// <code>FixedU32::[overflowing\_round][FixedU32::overflowing_round]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.5));
+    /// assert_eq!(x.round(), Overflowing::new(I16F16::from_num(13)));
+    /// assert!(Overflowing::new(I16F16::MAX).round().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_round())
+    }
+
+    /// Rounds to the nearest integer, with ties rounded to even.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_round\_ties\_even][FixedI32::overflowing_round_ties_even]</code> and
+    /// <code>FixedU32::[overflowing\_round\_ties\_even][FixedU32::overflowing_round_ties_even]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(12.5));
+    /// assert_eq!(x.round_ties_even(), Overflowing::new(I16F16::from_num(12)));
+    /// let x = Overflowing::new(I16F16::from_num(13.5));
+    /// assert_eq!(x.round_ties_even(), Overflowing::new(I16F16::from_num(14)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_ties_even(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_round_ties_even())
+    }
+
+    /// Returns the square root.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sqrt][FixedI32::overflowing_sqrt]</code> and
+    /// <code>FixedU32::[overflowing\_sqrt][FixedU32::overflowing_sqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(6.25));
+    /// assert_eq!(x.sqrt(), Overflowing::new(I16F16::from_num(2.5)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn sqrt(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_sqrt())
+    }
+
+    /// Returns the reciprocal of the square root.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rsqrt][FixedI32::overflowing_rsqrt]</code> and
+    /// <code>FixedU32::[overflowing\_rsqrt][FixedU32::overflowing_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I4F12::from_num(4));
+    /// assert_eq!(x.rsqrt(), Overflowing::new(I4F12::from_num(0.5)));
+    /// // 1/√Δ = 64 does not fit
+    /// assert!(Overflowing::new(I4F12::DELTA).rsqrt().overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rsqrt(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_rsqrt())
+    }
+
+    /// Returns the reciprocal (inverse) of the fixed-point number, 1/`self`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_recip][FixedI32::overflowing_recip]</code> and
+    /// <code>FixedU32::[overflowing\_recip][FixedU32::overflowing_recip]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(4));
+    /// assert_eq!(x.recip(), Overflowing::new(I16F16::from_num(0.25)));
+    /// assert!(Overflowing::new(I16F16::DELTA).recip().overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn recip(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_recip())
+    }
+
+    /// Returns 2 raised to the power of the number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp2][FixedI32::overflowing_exp2]</code> and
+    /// <code>FixedU32::[overflowing\_exp2][FixedU32::overflowing_exp2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// assert_eq!(x.exp2(), Overflowing::new(I16F16::from_num(8)));
+    /// assert!(Overflowing::new(I16F16::from_num(20)).exp2().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_exp2())
+    }
+
+    /// Returns <i>e</i> raised to the power of the number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp][FixedI32::overflowing_exp]</code> and
+    /// <code>FixedU32::[overflowing\_exp][FixedU32::overflowing_exp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::new(I16F16::from_num(0)).exp(), Overflowing::new(I16F16::ONE));
+    /// assert!(Overflowing::new(I16F16::from_num(20)).exp().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_exp())
+    }
+
+    /// Returns <i>e</i> raised to the power of the number, minus one.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp\_m1][FixedI32::overflowing_exp_m1]</code> and
+    /// <code>FixedU32::[overflowing\_exp\_m1][FixedU32::overflowing_exp_m1]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO.exp_m1(), Overflowing::ZERO);
+    /// assert!(Overflowing::new(I16F16::from_num(20)).exp_m1().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn exp_m1(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_exp_m1())
+    }
+
+    /// Raises the number to an integer power.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powi][FixedI32::overflowing_powi]</code> and
+    /// <code>FixedU32::[overflowing\_powi][FixedU32::overflowing_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if raising zero to a negative power.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3), Overflowing::new(I16F16::from_num(3.375)));
+    /// assert!(Overflowing::new(I16F16::MAX).powi(2).overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn powi(self, n: i32) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_powi(n))
+    }
+
+    /// Raises the number to a fixed-point power.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powf][FixedI32::overflowing_powf]</code> and
+    /// <code>FixedU32::[overflowing\_powf][FixedU32::overflowing_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `y` is
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(4));
+    /// let half = Overflowing::new(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(half), Overflowing::new(I16F16::from_num(2)));
+    /// let two = Overflowing::new(I16F16::from_num(2));
+    /// assert!(Overflowing::new(I16F16::MAX).powf(two).overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn powf(self, y: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | y.overflow,
+            self.value.overflowing_powf(y.value),
+        )
+    }
+
+    /// Returns the cube root.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cbrt][FixedI32::overflowing_cbrt]</code> and
+    /// <code>FixedU32::[overflowing\_cbrt][FixedU32::overflowing_cbrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(-3.375));
+    /// assert_eq!(x.cbrt(), Overflowing::new(I16F16::from_num(-1.5)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cbrt(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_cbrt())
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_nth\_root][FixedI32::overflowing_nth_root]</code> and
+    /// <code>FixedU32::[overflowing\_nth\_root][FixedU32::overflowing_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(5.0625));
+    /// assert_eq!(x.nth_root(4), Overflowing::new(I16F16::from_num(1.5)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn nth_root(self, n: u32) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_nth_root(n))
+    }
+
+    /// Returns the distance from `self` to `other`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_dist][FixedI32::overflowing_dist]</code> and
+    /// <code>FixedU32::[overflowing\_dist][FixedU32::overflowing_dist]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// let other = Overflowing::new(I16F16::from_num(-2));
+    /// assert_eq!(x.dist(other), Overflowing::new(I16F16::from_num(5)));
+    /// let min = Overflowing::new(I16F16::MIN);
+    /// assert!(min.dist(Overflowing::new(I16F16::MAX)).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn dist(self, other: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | other.overflow,
+            self.value.overflowing_dist(other.value),
+        )
+    }
+
+    /// Returns the mean of `self` and `other`.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operands.
+    ///
+    /// See also <code>FixedI32::[mean][FixedI32::mean]</code> and
+    /// <code>FixedU32::[mean][FixedU32::mean]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// let other = Overflowing::new(I16F16::from_num(4));
+    /// assert_eq!(x.mean(other), Overflowing::new(I16F16::from_num(3.5)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mean(self, other: Overflowing<F>) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.mean(other.value),
+            overflow: self.overflow | other.overflow,
+        }
+    }
+
+    /// Compute the hypotenuse of a right triangle.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_hypot][FixedI32::overflowing_hypot]</code> and
+    /// <code>FixedU32::[overflowing\_hypot][FixedU32::overflowing_hypot]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// let other = Overflowing::new(I16F16::from_num(4));
+    /// assert_eq!(x.hypot(other), Overflowing::new(I16F16::from_num(5)));
+    /// let max = Overflowing::new(I16F16::MAX);
+    /// assert!(max.hypot(max).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hypot(self, other: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | other.overflow,
+            self.value.overflowing_hypot(other.value),
+        )
+    }
+
+    /// Returns the next multiple of `other`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_next\_multiple\_of][FixedI32::overflowing_next_multiple_of]</code> and
+    /// <code>FixedU32::[overflowing\_next\_multiple\_of][FixedU32::overflowing_next_multiple_of]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(5));
+    /// let other = Overflowing::new(I16F16::from_num(1.5));
+    /// assert_eq!(x.next_multiple_of(other), Overflowing::new(I16F16::from_num(6)));
+    /// assert!(Overflowing::<I16F16>::MAX.next_multiple_of(other).overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn next_multiple_of(self, other: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | other.overflow,
+            self.value.overflowing_next_multiple_of(other.value),
+        )
+    }
+
+    /// Multiply and add. Returns `self` × `mul` + `add`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_add][FixedI32::overflowing_mul_add]</code> and
+    /// <code>FixedU32::[overflowing\_mul\_add][FixedU32::overflowing_mul_add]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// let mul = Overflowing::new(I16F16::from_num(2));
+    /// let add = Overflowing::new(I16F16::from_num(0.5));
+    /// assert_eq!(x.mul_add(mul, add), Overflowing::new(I16F16::from_num(6.5)));
+    /// let max = Overflowing::new(I16F16::MAX);
+    /// assert!(max.mul_add(Overflowing::new(I16F16::from_num(2)), Overflowing::ZERO).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, mul: Overflowing<F>, add: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | mul.overflow | add.overflow,
+            self.value.overflowing_mul_add(mul.value, add.value),
+        )
+    }
+
+    /// Adds `self` to the product `a`&nbsp;×&nbsp;`b`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_add\_prod][FixedI32::overflowing_add_prod]</code> and
+    /// <code>FixedU32::[overflowing\_add\_prod][FixedU32::overflowing_add_prod]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(0.5));
+    /// let a = Overflowing::new(I16F16::from_num(3));
+    /// let b = Overflowing::new(I16F16::from_num(2));
+    /// assert_eq!(x.add_prod(a, b), Overflowing::new(I16F16::from_num(6.5)));
+    /// let max = Overflowing::new(I16F16::MAX);
+    /// assert!(max.add_prod(Overflowing::new(I16F16::from_num(1)), Overflowing::DELTA).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_prod(self, a: Overflowing<F>, b: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | a.overflow | b.overflow,
+            self.value.overflowing_add_prod(a.value, b.value),
+        )
+    }
+
+    /// Euclidean division.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_div\_euclid][FixedI32::overflowing_div_euclid]</code> and
+    /// <code>FixedU32::[overflowing\_div\_euclid][FixedU32::overflowing_div_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(7.5));
+    /// let rhs = Overflowing::new(I16F16::from_num(2));
+    /// assert_eq!(x.div_euclid(rhs), Overflowing::new(I16F16::from_num(3)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn div_euclid(self, rhs: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | rhs.overflow,
+            self.value.overflowing_div_euclid(rhs.value),
+        )
+    }
+
+    /// Remainder for Euclidean division.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operands.
+    ///
+    /// See also <code>FixedI32::[rem\_euclid][FixedI32::rem_euclid]</code> and
+    /// <code>FixedU32::[rem\_euclid][FixedU32::rem_euclid]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(7.5));
+    /// let rhs = Overflowing::new(I16F16::from_num(2));
+    /// assert_eq!(x.rem_euclid(rhs), Overflowing::new(I16F16::from_num(1.5)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Overflowing<F>) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.rem_euclid(rhs.value),
+            overflow: self.overflow | rhs.overflow,
+        }
+    }
+
+    /// Euclidean division by an integer.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_div\_euclid\_int][FixedI32::overflowing_div_euclid_int]</code> and
+    /// <code>FixedU32::[overflowing\_div\_euclid\_int][FixedU32::overflowing_div_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(7.5));
+    /// assert_eq!(x.div_euclid_int(2), Overflowing::new(I16F16::from_num(3)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn div_euclid_int(self, rhs: F::Bits) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_div_euclid_int(rhs))
+    }
+
+    /// Remainder for Euclidean division by an integer.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rem\_euclid\_int][FixedI32::overflowing_rem_euclid_int]</code> and
+    /// <code>FixedU32::[overflowing\_rem\_euclid\_int][FixedU32::overflowing_rem_euclid_int]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(7.5));
+    /// assert_eq!(x.rem_euclid_int(2), Overflowing::new(I16F16::from_num(1.5)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rem_euclid_int(self, rhs: F::Bits) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_rem_euclid_int(rhs))
+    }
+
+    /// Linear interpolation between `start` and `end`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_lerp][FixedI32::overflowing_lerp]</code> and
+    /// <code>FixedU32::[overflowing\_lerp][FixedU32::overflowing_lerp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(0.5));
+    /// let start = Overflowing::new(I16F16::from_num(2));
+    /// let end = Overflowing::new(I16F16::from_num(4));
+    /// assert_eq!(x.lerp(start, end), Overflowing::new(I16F16::from_num(3)));
+    /// let max = Overflowing::new(I16F16::MAX);
+    /// assert!(Overflowing::new(I16F16::from_num(2)).lerp(Overflowing::ZERO, max).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, start: Overflowing<F>, end: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | start.overflow | end.overflow,
+            self.value.overflowing_lerp(start.value, end.value),
+        )
+    }
+
+    /// Inverse linear interpolation between `start` and `end`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_inv\_lerp][FixedI32::overflowing_inv_lerp]</code> and
+    /// <code>FixedU32::[overflowing\_inv\_lerp][FixedU32::overflowing_inv_lerp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `start`&nbsp;=&nbsp;`end`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// let start = Overflowing::new(I16F16::from_num(2));
+    /// let end = Overflowing::new(I16F16::from_num(4));
+    /// assert_eq!(x.inv_lerp(start, end), Overflowing::new(I16F16::from_num(0.5)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn inv_lerp(self, start: Overflowing<F>, end: Overflowing<F>) -> Overflowing<F> {
+        sticky(
+            self.overflow | start.overflow | end.overflow,
+            self.value.overflowing_inv_lerp(start.value, end.value),
+        )
+    }
+
+    /// Multiply and accumulate. Adds (`a` × `b`) to `self`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_acc][FixedI32::overflowing_mul_acc]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_acc][FixedU32::overflowing_mul_acc]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let mut acc = Overflowing::new(I16F16::from_num(0.5));
+    /// let a = Overflowing::new(I16F16::from_num(3));
+    /// let b = Overflowing::new(I16F16::from_num(2));
+    /// acc.mul_acc(a, b);
+    /// assert_eq!(acc, Overflowing::new(I16F16::from_num(6.5)));
+    ///
+    /// acc = Overflowing::MAX;
+    /// acc.mul_acc(Overflowing::MAX, a);
+    /// assert!(acc.overflow);
+    /// ```
+    #[inline]
+    pub fn mul_acc(&mut self, a: Overflowing<F>, b: Overflowing<F>) {
+        let overflow = self.value.overflowing_mul_acc(a.value, b.value);
+        self.overflow |= a.overflow | b.overflow | overflow;
+    }
+}
+
+impl<F: FixedSigned> Overflowing<F> {
+    /// Returns the absolute value.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_abs][FixedI32::overflowing_abs]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(-5));
+    /// assert_eq!(x.abs(), Overflowing::new(I16F16::from_num(5)));
+    /// assert!(Overflowing::new(I16F16::MIN).abs().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_abs())
+    }
+
+    /// Returns a number representing the sign of `self`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_signum][FixedI32::overflowing_signum]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I0F16, I16F16};
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(-5));
+    /// assert_eq!(x.signum(), Overflowing::new(I16F16::NEG_ONE));
+    /// assert!(Overflowing::new(I0F16::from_num(0.25)).signum().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn signum(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_signum())
+    }
+
+    /// Addition with an unsigned fixed-point number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_add\_unsigned][FixedI32::overflowing_add_unsigned]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(-3));
+    /// assert_eq!(x.add_unsigned(U16F16::from_num(5)), Overflowing::new(I16F16::from_num(2)));
+    /// assert!(Overflowing::new(I16F16::MAX).add_unsigned(U16F16::DELTA).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_unsigned(self, rhs: F::Unsigned) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_add_unsigned(rhs))
+    }
+
+    /// Subtraction with an unsigned fixed-point number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sub\_unsigned][FixedI32::overflowing_sub_unsigned]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(I16F16::from_num(3));
+    /// assert_eq!(x.sub_unsigned(U16F16::from_num(5)), Overflowing::new(I16F16::from_num(-2)));
+    /// assert!(Overflowing::new(I16F16::MIN).sub_unsigned(U16F16::DELTA).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sub_unsigned(self, rhs: F::Unsigned) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_sub_unsigned(rhs))
+    }
+
+    /// Computes the sine of `self`, where `self` is in radians.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin][FixedI32::overflowing_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO.sin(), Overflowing::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_sin())
+    }
+
+    /// Computes the cosine of `self`, where `self` is in radians.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cos][FixedI32::overflowing_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO.cos(), Overflowing::new(I16F16::ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_cos())
+    }
+
+    /// Computes the inverse sine of `self`, with the result in radians.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_asin][FixedI32::overflowing_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO.asin(), Overflowing::ZERO);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn asin(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_asin())
+    }
+
+    /// Computes the inverse cosine of `self`, with the result in radians.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_acos][FixedI32::overflowing_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::new(I16F16::ONE).acos(), Overflowing::ZERO);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn acos(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_acos())
+    }
+
+    /// Computes the hyperbolic sine of `self`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sinh][FixedI32::overflowing_sinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I4F12>::ZERO.sinh(), Overflowing::ZERO);
+    /// assert!(Overflowing::new(I4F12::from_num(3)).sinh().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sinh(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_sinh())
+    }
+
+    /// Computes the hyperbolic cosine of `self`.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cosh][FixedI32::overflowing_cosh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I4F12>::ZERO.cosh(), Overflowing::new(I4F12::ONE));
+    /// assert!(Overflowing::new(I4F12::from_num(3)).cosh().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cosh(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_cosh())
+    }
+
+    /// Computes the hyperbolic tangent of `self`.
+    ///
+    /// This never overflows; the overflow flag is propagated from the
+    /// operand.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// assert_eq!(Overflowing::<I16F16>::ZERO.tanh(), Overflowing::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tanh(self) -> Overflowing<F> {
+        Overflowing {
+            value: self.value.tanh(),
+            overflow: self.overflow,
+        }
+    }
+
+    /// Computes the sine and cosine of `self`, where `self` is in radians.
+    ///
+    /// The results wrap on overflow, and the overflow flag is set in both.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin\_cos][FixedI32::overflowing_sin_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Overflowing;
+    /// let (sin, cos) = Overflowing::<I16F16>::ZERO.sin_cos();
+    /// assert_eq!(sin, Overflowing::ZERO);
+    /// assert_eq!(cos, Overflowing::new(I16F16::ONE));
+    /// ```
+    #[inline]
+    pub fn sin_cos(self) -> (Overflowing<F>, Overflowing<F>) {
+        let ((sin, cos), overflow) = self.value.overflowing_sin_cos();
+        let overflow = self.overflow | overflow;
+        (
+            Overflowing {
+                value: sin,
+                overflow,
+            },
+            Overflowing {
+                value: cos,
+                overflow,
+            },
+        )
+    }
+}
+
+impl<F: FixedUnsigned> Overflowing<F> {
+    /// Returns the smallest power of two that is ≥&nbsp;`self`.
+    ///
+    /// If the next power of two is too large to fit, zero is returned and the
+    /// overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedU32::[wrapping\_next\_power\_of\_two][FixedU32::wrapping_next_power_of_two]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::U16F16;
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(U16F16::from_num(5));
+    /// assert_eq!(x.next_power_of_two(), Overflowing::new(U16F16::from_num(8)));
+    /// let max = Overflowing::<U16F16>::MAX.next_power_of_two();
+    /// assert_eq!(max.value, 0);
+    /// assert!(max.overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn next_power_of_two(self) -> Overflowing<F> {
+        match self.value.checked_next_power_of_two() {
+            Some(value) => Overflowing {
+                value,
+                overflow: self.overflow,
+            },
+            None => Overflowing {
+                value: self.value.wrapping_next_power_of_two(),
+                overflow: true,
+            },
+        }
+    }
+
+    /// Addition with a signed fixed-point number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedU32::[overflowing\_add\_signed][FixedU32::overflowing_add_signed]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(U16F16::from_num(5));
+    /// assert_eq!(x.add_signed(I16F16::from_num(-3)), Overflowing::new(U16F16::from_num(2)));
+    /// assert!(Overflowing::new(U16F16::from_num(2)).add_signed(I16F16::from_num(-3)).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_signed(self, rhs: F::Signed) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_add_signed(rhs))
+    }
+
+    /// Subtraction with a signed fixed-point number.
+    ///
+    /// The result wraps on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedU32::[overflowing\_sub\_signed][FixedU32::overflowing_sub_signed]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I16F16, U16F16};
+    /// use fixed::Overflowing;
+    /// let x = Overflowing::new(U16F16::from_num(5));
+    /// assert_eq!(x.sub_signed(I16F16::from_num(3)), Overflowing::new(U16F16::from_num(2)));
+    /// assert!(Overflowing::new(U16F16::from_num(2)).sub_signed(I16F16::from_num(3)).overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sub_signed(self, rhs: F::Signed) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_sub_signed(rhs))
+    }
+}
+
+#[inline]
+fn sticky<F>(overflow: bool, (value, o): (F, bool)) -> Overflowing<F> {
+    Overflowing {
+        value,
+        overflow: overflow | o,
+    }
+}
+
+impl<F: Fixed> Display for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> Binary for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Binary::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> Octal for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Octal::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> LowerHex for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        LowerHex::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> UpperHex for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        UpperHex::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> LowerExp for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        LowerExp::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> UpperExp for Overflowing<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        UpperExp::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> From<F> for Overflowing<F> {
+    /// Wraps a fixed-point number with the overflow flag cleared.
+    #[inline]
+    fn from(src: F) -> Overflowing<F> {
+        Overflowing::new(src)
+    }
+}
+
+impl<F: Fixed> FromStr for Overflowing<F> {
+    type Err = ParseFixedError;
+    /// Parses a string slice containing decimal digits to return a fixed-point number.
+    ///
+    /// Rounding is to the nearest, with ties rounded to even. The value wraps
+    /// if it does not fit, in which case the overflow flag is set.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Overflowing::from_str_dec(s)
+    }
+}
+
+macro_rules! op {
+    ($overflowing:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Overflowing<F>> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: Overflowing<F>) -> Overflowing<F> {
+                sticky(
+                    self.overflow || other.overflow,
+                    (self.value).$overflowing(other.value),
+                )
+            }
+        }
+        impl<F: Fixed> $Op<Overflowing<F>> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: Overflowing<F>) -> Overflowing<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&Overflowing<F>> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &Overflowing<F>) -> Overflowing<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&Overflowing<F>> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &Overflowing<F>) -> Overflowing<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<Overflowing<F>> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: Overflowing<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&Overflowing<F>> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &Overflowing<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<F> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: F) {
+                *self = sticky(self.overflow, (self.value).$overflowing(other));
+            }
+        }
+        impl<F: Fixed> $OpAssign<&F> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &F) {
+                *self = sticky(self.overflow, (self.value).$overflowing(*other));
+            }
+        }
+    };
+}
+
+macro_rules! op_no_overflow {
+    ($Op:ident $op:ident, $OpAssign:ident $op_assign:ident) => {
+        impl<F: Fixed> $Op<Overflowing<F>> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: Overflowing<F>) -> Overflowing<F> {
+                Overflowing {
+                    value: (self.value).$op(other.value),
+                    overflow: self.overflow || other.overflow,
+                }
+            }
+        }
+        impl<F: Fixed> $Op<Overflowing<F>> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: Overflowing<F>) -> Overflowing<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&Overflowing<F>> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &Overflowing<F>) -> Overflowing<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&Overflowing<F>> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &Overflowing<F>) -> Overflowing<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<Overflowing<F>> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: Overflowing<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&Overflowing<F>> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &Overflowing<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<F> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: F) {
+                (self.value).$op_assign(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&F> for Overflowing<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &F) {
+                (self.value).$op_assign(*other);
+            }
+        }
+    };
+}
+
+macro_rules! op_shift {
+    (
+        $overflowing:ident, $Op:ident $op:ident, $OpAssign:ident $op_assign:ident;
+        $($Rhs:ident),*
+    ) => { $(
+        impl<F: Fixed> $Op<$Rhs> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Overflowing<F> {
+                // Negative amounts and amounts that do not fit in u32 overflow.
+                // Since the number of bits divides 2^32, truncating to u32
+                // keeps the amount modulo the number of bits.
+                let out_of_range = u32::try_from(other).is_err();
+                let (value, overflow) = (self.value).$overflowing(other as u32);
+                Overflowing {
+                    value,
+                    overflow: self.overflow | overflow | out_of_range,
+                }
+            }
+        }
+        impl<F: Fixed> $Op<$Rhs> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            fn $op(self, other: $Rhs) -> Overflowing<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&$Rhs> for Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Overflowing<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&$Rhs> for &Overflowing<F> {
+            type Output = Overflowing<F>;
+            #[inline]
+            fn $op(self, other: &$Rhs) -> Overflowing<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<$Rhs> for Overflowing<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: $Rhs) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&$Rhs> for Overflowing<F> {
+            #[inline]
+            fn $op_assign(&mut self, other: &$Rhs) {
+                *self = (*self).$op(*other);
+            }
+        }
+    )* };
+}
+
+impl<F: Fixed> Neg for Overflowing<F> {
+    type Output = Overflowing<F>;
+    #[inline]
+    fn neg(self) -> Overflowing<F> {
+        sticky(self.overflow, self.value.overflowing_neg())
+    }
+}
+
+impl<F: Fixed> Neg for &Overflowing<F> {
+    type Output = Overflowing<F>;
+    #[inline]
+    fn neg(self) -> Overflowing<F> {
+        -*self
+    }
+}
+op! { overflowing_add, Add add, AddAssign add_assign }
+op! { overflowing_sub, Sub sub, SubAssign sub_assign }
+op! { overflowing_mul, Mul mul, MulAssign mul_assign }
+op! { overflowing_div, Div div, DivAssign div_assign }
+op_no_overflow! { Rem rem, RemAssign rem_assign }
+
+impl<F: Fixed> Not for Overflowing<F> {
+    type Output = Overflowing<F>;
+    #[inline]
+    fn not(self) -> Overflowing<F> {
+        Overflowing {
+            value: !self.value,
+            overflow: self.overflow,
+        }
+    }
+}
+impl<F: Fixed> Not for &Overflowing<F> {
+    type Output = Overflowing<F>;
+    #[inline]
+    fn not(self) -> Overflowing<F> {
+        !*self
+    }
+}
+op_no_overflow! { BitAnd bitand, BitAndAssign bitand_assign }
+op_no_overflow! { BitOr bitor, BitOrAssign bitor_assign }
+op_no_overflow! { BitXor bitxor, BitXorAssign bitxor_assign }
+
+op_shift! {
+    overflowing_shl, Shl shl, ShlAssign shl_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+op_shift! {
+    overflowing_shr, Shr shr, ShrAssign shr_assign;
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+}
+
+impl<F: Fixed> Sum<Overflowing<F>> for Overflowing<F> {
+    fn sum<I>(iter: I) -> Overflowing<F>
+    where
+        I: Iterator<Item = Overflowing<F>>,
+    {
+        iter.fold(Overflowing::ZERO, Add::add)
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a Overflowing<F>> for Overflowing<F> {
+    fn sum<I>(iter: I) -> Overflowing<F>
+    where
+        I: Iterator<Item = &'a Overflowing<F>>,
+    {
+        iter.fold(Overflowing::ZERO, Add::add)
+    }
+}
+
+impl<F: Fixed> Product<Overflowing<F>> for Overflowing<F> {
+    fn product<I>(mut iter: I) -> Overflowing<F>
+    where
+        I: Iterator<Item = Overflowing<F>>,
+    {
+        match iter.next() {
+            None => Overflowing::from_num(1),
+            Some(first) => iter.fold(first, Mul::mul),
+        }
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a Overflowing<F>> for Overflowing<F> {
+    fn product<I>(mut iter: I) -> Overflowing<F>
+    where
+        I: Iterator<Item = &'a Overflowing<F>>,
+    {
+        match iter.next() {
+            None => Overflowing::from_num(1),
+            Some(first) => iter.fold(*first, Mul::mul),
+        }
+    }
+}
+
+// The following cannot be implemented for Overflowing<F> where F: Fixed,
+// otherwise there will be a conflicting implementation error. For
+// example we cannot implement both these without triggering E0119:
+//
+//     impl<F: Fixed> Op<F::Bits> for Overflowing<F> { /* ... */ }
+//     impl<F: Fixed> Op<&F::Bits> for Overflowing<F> { /* ... */ }
+//
+// To work around this, we provide implementations like this:
+//
+//     impl<Frac> Op<i8> for Overflowing<FixedI8<Frac>> { /* ... */ }
+//     impl<Frac> Op<&i8> for Overflowing<FixedI8<Frac>> { /* ... */ }
+//     impl<Frac> Op<i16> for Overflowing<FixedI16<Frac>> { /* ... */ }
+//     impl<Frac> Op<&i16> for Overflowing<FixedI16<Frac>> { /* ... */ }
+//     ...
+
+// The remainder never overflows.
+#[inline]
+#[track_caller]
+fn rem_int<F: Rem<B, Output = F>, B>(lhs: F, rhs: B) -> (F, bool) {
+    (lhs % rhs, false)
+}
+
+macro_rules! op_bits {
+    (
+        $Fixed:ident($Bits:ident $(, $LeEqU:ident)*), $overflowing:path,
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<Frac $(: $LeEqU)*> $Op<$Bits> for Overflowing<$Fixed<Frac>> {
+            type Output = Overflowing<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: $Bits) -> Overflowing<$Fixed<Frac>> {
+                sticky(self.overflow, $overflowing(self.value, other))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<$Bits> for &Overflowing<$Fixed<Frac>> {
+            type Output = Overflowing<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: $Bits) -> Overflowing<$Fixed<Frac>> {
+                sticky(self.overflow, $overflowing(self.value, other))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<&$Bits> for Overflowing<$Fixed<Frac>> {
+            type Output = Overflowing<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &$Bits) -> Overflowing<$Fixed<Frac>> {
+                sticky(self.overflow, $overflowing(self.value, *other))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $Op<&$Bits> for &Overflowing<$Fixed<Frac>> {
+            type Output = Overflowing<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &$Bits) -> Overflowing<$Fixed<Frac>> {
+                sticky(self.overflow, $overflowing(self.value, *other))
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $OpAssign<$Bits> for Overflowing<$Fixed<Frac>> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: $Bits) {
+                *self = sticky(self.overflow, $overflowing(self.value, other));
+            }
+        }
+        impl<Frac $(: $LeEqU)*> $OpAssign<&$Bits> for Overflowing<$Fixed<Frac>> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &$Bits) {
+                *self = sticky(self.overflow, $overflowing(self.value, *other));
+            }
+        }
+    };
+}
+
+macro_rules! ops {
+    ($Fixed:ident($Bits:ident, $LeEqU:ident)) => {
+        op_bits! { $Fixed($Bits), $Fixed::overflowing_mul_int, Mul mul, MulAssign mul_assign }
+        op_bits! { $Fixed($Bits), $Fixed::overflowing_div_int, Div div, DivAssign div_assign }
+        op_bits! { $Fixed($Bits, $LeEqU), rem_int, Rem rem, RemAssign rem_assign }
+    };
+}
+ops! { FixedI8(i8, LeEqU8) }
+ops! { FixedI16(i16, LeEqU16) }
+ops! { FixedI32(i32, LeEqU32) }
+ops! { FixedI64(i64, LeEqU64) }
+ops! { FixedI128(i128, LeEqU128) }
+ops! { FixedU8(u8, LeEqU8) }
+ops! { FixedU16(u16, LeEqU16) }
+ops! { FixedU32(u32, LeEqU32) }
+ops! { FixedU64(u64, LeEqU64) }
+ops! { FixedU128(u128, LeEqU128) }
+ops! { FixedI256(I256Bits, LeEqU256) }
+ops! { FixedU256(U256Bits, LeEqU256) }
+
+/// Provides arithmetic operations that saturate on overflow and record the
+/// overflow in a sticky flag.
+///
+/// This is the saturating counterpart of [`Overflowing`]: when an operation
+/// overflows, the value saturates to the minimum or maximum instead of
+/// wrapping. The overflow flag behaves exactly as in [`Overflowing`]; it is
+/// the OR of the overflow flags of the operands and of the operation itself,
+/// and stays set until it is cleared explicitly with
+/// [`clear_overflow`][OverflowingSaturating::clear_overflow].
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::I16F16;
+/// use fixed::OverflowingSaturating;
+/// let mut acc = OverflowingSaturating::new(I16F16::MAX);
+/// // the sum saturates
+/// acc += I16F16::DELTA;
+/// assert_eq!(acc.value, I16F16::MAX);
+/// // subtracting does not restore the lost delta, and the flag is sticky
+/// acc -= I16F16::DELTA;
+/// assert_eq!(acc.value, I16F16::MAX - I16F16::DELTA);
+/// assert!(acc.overflow);
+///
+/// // clear the flag for the next block
+/// assert!(acc.clear_overflow());
+/// assert!(!acc.overflow);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct OverflowingSaturating<F> {
+    /// The saturated value.
+    pub value: F,
+    /// [`true`] if any operation leading to the value has overflowed.
+    pub overflow: bool,
+}
+
+impl<F: Fixed> OverflowingSaturating<F> {
+    /// Zero.
+    ///
+    /// See also <code>FixedI32::[ZERO][FixedI32::ZERO]</code> and
+    /// <code>FixedU32::[ZERO][FixedU32::ZERO]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// assert_eq!(
+    ///     OverflowingSaturating::<I16F16>::ZERO,
+    ///     OverflowingSaturating::new(I16F16::ZERO)
+    /// );
+    /// ```
+    pub const ZERO: OverflowingSaturating<F> = OverflowingSaturating::new(F::ZERO);
+
+    /// The difference between any two successive representable numbers, <i>Δ</i>.
+    ///
+    /// See also <code>FixedI32::[DELTA][FixedI32::DELTA]</code> and
+    /// <code>FixedU32::[DELTA][FixedU32::DELTA]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// assert_eq!(
+    ///     OverflowingSaturating::<I16F16>::DELTA,
+    ///     OverflowingSaturating::new(I16F16::DELTA)
+    /// );
+    /// ```
+    pub const DELTA: OverflowingSaturating<F> = OverflowingSaturating::new(F::DELTA);
+
+    /// The smallest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MIN][FixedI32::MIN]</code> and
+    /// <code>FixedU32::[MIN][FixedU32::MIN]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// assert_eq!(
+    ///     OverflowingSaturating::<I16F16>::MIN,
+    ///     OverflowingSaturating::new(I16F16::MIN)
+    /// );
+    /// ```
+    pub const MIN: OverflowingSaturating<F> = OverflowingSaturating::new(F::MIN);
+
+    /// The largest value that can be represented.
+    ///
+    /// See also <code>FixedI32::[MAX][FixedI32::MAX]</code> and
+    /// <code>FixedU32::[MAX][FixedU32::MAX]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// assert_eq!(
+    ///     OverflowingSaturating::<I16F16>::MAX,
+    ///     OverflowingSaturating::new(I16F16::MAX)
+    /// );
+    /// ```
+    pub const MAX: OverflowingSaturating<F> = OverflowingSaturating::new(F::MAX);
+
+    /// Wraps a fixed-point number with the overflow flag cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// let o = OverflowingSaturating::new(I16F16::from_num(1.5));
+    /// assert_eq!(o.value, 1.5);
+    /// assert!(!o.overflow);
+    /// ```
+    #[inline]
+    pub const fn new(value: F) -> OverflowingSaturating<F> {
+        OverflowingSaturating {
+            value,
+            overflow: false,
+        }
+    }
+
+    /// Clears the overflow flag, returning its previous state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// let mut o = OverflowingSaturating::new(I16F16::MAX) + OverflowingSaturating::DELTA;
+    /// assert!(o.clear_overflow());
+    /// assert!(!o.overflow);
+    /// assert!(!o.clear_overflow());
+    /// ```
+    #[inline]
+    pub fn clear_overflow(&mut self) -> bool {
+        let overflow = self.overflow;
+        self.overflow = false;
+        overflow
+    }
+
+    /// Creates a fixed-point number that has a bitwise representation
+    /// identical to the given integer.
+    ///
+    /// The overflow flag is cleared.
+    ///
+    /// See also <code>FixedI32::[from\_bits][FixedI32::from_bits]</code> and
+    /// <code>FixedU32::[from\_bits][FixedU32::from_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// let o = OverflowingSaturating::<I16F16>::from_bits(0x1C);
+    /// assert_eq!(o, OverflowingSaturating::new(I16F16::from_bits(0x1C)));
+    /// ```
+    #[inline]
+    pub fn from_bits(bits: F::Bits) -> OverflowingSaturating<F> {
+        OverflowingSaturating::new(F::from_bits(bits))
+    }
+
+    /// Creates an integer that has a bitwise representation identical
+    /// to the given fixed-point number.
+    ///
+    /// See also <code>FixedI32::[to\_bits][FixedI32::to_bits]</code> and
+    /// <code>FixedU32::[to\_bits][FixedU32::to_bits]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// let o = OverflowingSaturating::new(I16F16::from_bits(0x1C));
+    /// assert_eq!(o.to_bits(), 0x1C);
+    /// ```
+    #[inline]
+    pub fn to_bits(self) -> F::Bits {
+        self.value.to_bits()
+    }
+
+    /// Creates a fixed-point number from another number.
+    ///
+    /// The value saturates if it does not fit, in which case the overflow
+    /// flag is set.
+    ///
+    /// The other number can be any number for which [`ToFixed`] is
+    /// implemented; see
+    /// <code>[Overflowing]::[from\_num][Overflowing::from_num]</code> for
+    /// details.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_num][FixedI32::saturating_from_num]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_num][FixedU32::saturating_from_num]</code>.
+    ///
+    /// # Panics
+    ///
+    /// For floating-point numbers, panics if the value is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I4F4, I16F16};
+    /// use fixed::OverflowingSaturating;
+    /// let src = I16F16::from_num(1.75);
+    /// assert_eq!(
+    ///     OverflowingSaturating::<I4F4>::from_num(src),
+    ///     OverflowingSaturating::new(I4F4::from_num(1.75))
+    /// );
+    ///
+    /// let o = OverflowingSaturating::<I4F4>::from_num(-1000);
+    /// assert_eq!(o.value, I4F4::MIN);
+    /// assert!(o.overflow);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_num<Src: ToFixed>(src: Src) -> OverflowingSaturating<F> {
+        let (value, overflow) = fixed256::overflowing_saturating_convert(src);
+        OverflowingSaturating { value, overflow }
+    }
+
+    /// Converts a fixed-point number to another number.
+    ///
+    /// Returns a [tuple] of the saturated value and the overflow flag, which
+    /// is set if either `self` had its overflow flag set or the conversion
+    /// overflowed.
+    ///
+    /// The other number can be any type for which [`FromFixed`] is
+    /// implemented; see
+    /// <code>[Overflowing]::[to\_num][Overflowing::to_num]</code> for details.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_to\_num][FixedI32::saturating_to_num]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_to\_num][FixedU32::saturating_to_num]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I4F4, I16F16};
+    /// use fixed::OverflowingSaturating;
+    /// let src = OverflowingSaturating::new(I16F16::from_num(1.75));
+    /// assert_eq!(src.to_num::<I4F4>(), (I4F4::from_num(1.75), false));
+    /// let large = OverflowingSaturating::new(I16F16::from_num(100));
+    /// assert_eq!(large.to_num::<I4F4>(), (I4F4::MAX, true));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+        let overflow = Dst::checked_from_fixed(self.value).is_none();
+        (
+            Dst::saturating_from_fixed(self.value),
+            self.overflow | overflow,
+        )
+    }
+}
+
+impl<F: FixedSigned> OverflowingSaturating<F> {
+    /// Returns the absolute value.
+    ///
+    /// The result saturates on overflow, and the overflow flag is set.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_abs][FixedI32::saturating_abs]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::OverflowingSaturating;
+    /// let x = OverflowingSaturating::new(I16F16::from_num(-5));
+    /// assert_eq!(x.abs(), OverflowingSaturating::new(I16F16::from_num(5)));
+    /// let min = OverflowingSaturating::new(I16F16::MIN);
+    /// assert_eq!(min.abs().value, I16F16::MAX);
+    /// assert!(min.abs().overflow);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> OverflowingSaturating<F> {
+        sticky_saturating(self.overflow, self.value.overflowing_abs(), || {
+            self.value.saturating_abs()
+        })
+    }
+}
+
+// Like sticky, but on overflow the wrapped value is replaced by the saturated
+// value.
+#[inline]
+fn sticky_saturating<F>(
+    overflow: bool,
+    (value, o): (F, bool),
+    saturated: impl FnOnce() -> F,
+) -> OverflowingSaturating<F> {
+    OverflowingSaturating {
+        value: if o { saturated() } else { value },
+        overflow: overflow | o,
+    }
+}
+
+impl<F: Fixed> Display for OverflowingSaturating<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<F: Fixed> From<F> for OverflowingSaturating<F> {
+    /// Wraps a fixed-point number with the overflow flag cleared.
+    #[inline]
+    fn from(src: F) -> OverflowingSaturating<F> {
+        OverflowingSaturating::new(src)
+    }
+}
+
+macro_rules! op_saturating {
+    (
+        $overflowing:path, $saturating:path,
+        $Op:ident $op:ident, $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<F: Fixed> $Op<OverflowingSaturating<F>> for OverflowingSaturating<F> {
+            type Output = OverflowingSaturating<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: OverflowingSaturating<F>) -> OverflowingSaturating<F> {
+                sticky_saturating(
+                    self.overflow || other.overflow,
+                    $overflowing(self.value, other.value),
+                    || $saturating(self.value, other.value),
+                )
+            }
+        }
+        impl<F: Fixed> $Op<OverflowingSaturating<F>> for &OverflowingSaturating<F> {
+            type Output = OverflowingSaturating<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: OverflowingSaturating<F>) -> OverflowingSaturating<F> {
+                (*self).$op(other)
+            }
+        }
+        impl<F: Fixed> $Op<&OverflowingSaturating<F>> for OverflowingSaturating<F> {
+            type Output = OverflowingSaturating<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &OverflowingSaturating<F>) -> OverflowingSaturating<F> {
+                self.$op(*other)
+            }
+        }
+        impl<F: Fixed> $Op<&OverflowingSaturating<F>> for &OverflowingSaturating<F> {
+            type Output = OverflowingSaturating<F>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &OverflowingSaturating<F>) -> OverflowingSaturating<F> {
+                (*self).$op(*other)
+            }
+        }
+        impl<F: Fixed> $OpAssign<OverflowingSaturating<F>> for OverflowingSaturating<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: OverflowingSaturating<F>) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<&OverflowingSaturating<F>> for OverflowingSaturating<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &OverflowingSaturating<F>) {
+                *self = (*self).$op(*other);
+            }
+        }
+        impl<F: Fixed> $OpAssign<F> for OverflowingSaturating<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: F) {
+                *self = (*self).$op(OverflowingSaturating::new(other));
+            }
+        }
+        impl<F: Fixed> $OpAssign<&F> for OverflowingSaturating<F> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &F) {
+                *self = (*self).$op(OverflowingSaturating::new(*other));
+            }
+        }
+    };
+}
+
+impl<F: Fixed> Neg for OverflowingSaturating<F> {
+    type Output = OverflowingSaturating<F>;
+    #[inline]
+    fn neg(self) -> OverflowingSaturating<F> {
+        sticky_saturating(self.overflow, self.value.overflowing_neg(), || {
+            self.value.saturating_neg()
+        })
+    }
+}
+
+impl<F: Fixed> Neg for &OverflowingSaturating<F> {
+    type Output = OverflowingSaturating<F>;
+    #[inline]
+    fn neg(self) -> OverflowingSaturating<F> {
+        -*self
+    }
+}
+op_saturating! { Fixed::overflowing_add, Fixed::saturating_add, Add add, AddAssign add_assign }
+op_saturating! { Fixed::overflowing_sub, Fixed::saturating_sub, Sub sub, SubAssign sub_assign }
+op_saturating! { Fixed::overflowing_mul, Fixed::saturating_mul, Mul mul, MulAssign mul_assign }
+op_saturating! { Fixed::overflowing_div, Fixed::saturating_div, Div div, DivAssign div_assign }
+op_saturating! { rem_int, Rem::rem, Rem rem, RemAssign rem_assign }
+
+impl<F: Fixed> Sum<OverflowingSaturating<F>> for OverflowingSaturating<F> {
+    fn sum<I>(iter: I) -> OverflowingSaturating<F>
+    where
+        I: Iterator<Item = OverflowingSaturating<F>>,
+    {
+        iter.fold(OverflowingSaturating::ZERO, Add::add)
+    }
+}
+
+impl<'a, F: 'a + Fixed> Sum<&'a OverflowingSaturating<F>> for OverflowingSaturating<F> {
+    fn sum<I>(iter: I) -> OverflowingSaturating<F>
+    where
+        I: Iterator<Item = &'a OverflowingSaturating<F>>,
+    {
+        iter.fold(OverflowingSaturating::ZERO, Add::add)
+    }
+}
+
+impl<F: Fixed> Product<OverflowingSaturating<F>> for OverflowingSaturating<F> {
+    fn product<I>(mut iter: I) -> OverflowingSaturating<F>
+    where
+        I: Iterator<Item = OverflowingSaturating<F>>,
+    {
+        match iter.next() {
+            None => OverflowingSaturating::from_num(1),
+            Some(first) => iter.fold(first, Mul::mul),
+        }
+    }
+}
+
+impl<'a, F: 'a + Fixed> Product<&'a OverflowingSaturating<F>> for OverflowingSaturating<F> {
+    fn product<I>(mut iter: I) -> OverflowingSaturating<F>
+    where
+        I: Iterator<Item = &'a OverflowingSaturating<F>>,
+    {
+        match iter.next() {
+            None => OverflowingSaturating::from_num(1),
+            Some(first) => iter.fold(*first, Mul::mul),
+        }
+    }
+}
+
+// As for Overflowing, the integer operands are implemented for each
+// fixed-point type separately.
+macro_rules! op_bits_saturating {
+    (
+        $Fixed:ident($Bits:ident), $overflowing:path, $saturating:path,
+        $Op:ident $op:ident,
+        $OpAssign:ident $op_assign:ident
+    ) => {
+        impl<Frac> $Op<$Bits> for OverflowingSaturating<$Fixed<Frac>> {
+            type Output = OverflowingSaturating<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: $Bits) -> OverflowingSaturating<$Fixed<Frac>> {
+                sticky_saturating(self.overflow, $overflowing(self.value, other), || {
+                    $saturating(self.value, other)
+                })
+            }
+        }
+        impl<Frac> $Op<$Bits> for &OverflowingSaturating<$Fixed<Frac>> {
+            type Output = OverflowingSaturating<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: $Bits) -> OverflowingSaturating<$Fixed<Frac>> {
+                (*self).$op(other)
+            }
+        }
+        impl<Frac> $Op<&$Bits> for OverflowingSaturating<$Fixed<Frac>> {
+            type Output = OverflowingSaturating<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &$Bits) -> OverflowingSaturating<$Fixed<Frac>> {
+                self.$op(*other)
+            }
+        }
+        impl<Frac> $Op<&$Bits> for &OverflowingSaturating<$Fixed<Frac>> {
+            type Output = OverflowingSaturating<$Fixed<Frac>>;
+            #[inline]
+            #[track_caller]
+            fn $op(self, other: &$Bits) -> OverflowingSaturating<$Fixed<Frac>> {
+                (*self).$op(*other)
+            }
+        }
+        impl<Frac> $OpAssign<$Bits> for OverflowingSaturating<$Fixed<Frac>> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: $Bits) {
+                *self = (*self).$op(other);
+            }
+        }
+        impl<Frac> $OpAssign<&$Bits> for OverflowingSaturating<$Fixed<Frac>> {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, other: &$Bits) {
+                *self = (*self).$op(*other);
+            }
+        }
+    };
+}
+
+macro_rules! ops_saturating {
+    ($Fixed:ident($Bits:ident)) => {
+        op_bits_saturating! {
+            $Fixed($Bits), $Fixed::overflowing_mul_int, $Fixed::saturating_mul_int,
+            Mul mul, MulAssign mul_assign
+        }
+        op_bits_saturating! {
+            $Fixed($Bits), $Fixed::overflowing_div_int, $Fixed::saturating_div_int,
+            Div div, DivAssign div_assign
+        }
+    };
+}
+ops_saturating! { FixedI8(i8) }
+ops_saturating! { FixedI16(i16) }
+ops_saturating! { FixedI32(i32) }
+ops_saturating! { FixedI64(i64) }
+ops_saturating! { FixedI128(i128) }
+ops_saturating! { FixedU8(u8) }
+ops_saturating! { FixedU16(u16) }
+ops_saturating! { FixedU32(u32) }
+ops_saturating! { FixedU64(u64) }
+ops_saturating! { FixedU128(u128) }
+ops_saturating! { FixedI256(I256Bits) }
+ops_saturating! { FixedU256(U256Bits) }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{I4F4, I4F12, I8F8, U4F4, U8F8};
+    use crate::{Overflowing, OverflowingSaturating};
+
+    #[test]
+    fn arith() {
+        for a in i8::MIN..=i8::MAX {
+            let fa = I4F4::from_bits(a);
+            let oa = Overflowing::new(fa);
+            let neg = Overflowing {
+                value: fa.wrapping_neg(),
+                overflow: a == i8::MIN,
+            };
+            assert_eq!(-oa, neg);
+            for b in i8::MIN..=i8::MAX {
+                let fb = I4F4::from_bits(b);
+                let ob = Overflowing::new(fb);
+                let expected = |(value, overflow)| Overflowing { value, overflow };
+                assert_eq!(oa + ob, expected(fa.overflowing_add(fb)));
+                assert_eq!(oa - ob, expected(fa.overflowing_sub(fb)));
+                assert_eq!(oa * ob, expected(fa.overflowing_mul(fb)));
+                if b != 0 {
+                    assert_eq!(oa / ob, expected(fa.overflowing_div(fb)));
+                    assert_eq!(oa * b, expected(fa.overflowing_mul_int(b)));
+                    assert_eq!(oa / b, expected(fa.overflowing_div_int(b)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sticky() {
+        let max = Overflowing::new(I8F8::MAX);
+        let one = Overflowing::new(I8F8::ONE);
+        let wrapped = max + Overflowing::new(I8F8::DELTA);
+        assert_eq!(wrapped.value, I8F8::MIN);
+        assert!(wrapped.overflow);
+        // operations that do not overflow keep the flag of either operand
+        assert!((wrapped - one).overflow);
+        assert!((one - wrapped).overflow);
+        assert!((wrapped & one).overflow);
+        assert!((wrapped % one).overflow);
+        assert!((!wrapped).overflow);
+        assert!(wrapped.abs().overflow);
+        assert!(wrapped.mean(one).overflow);
+        assert!(
+            [one, wrapped, one]
+                .iter()
+                .sum::<Overflowing<I8F8>>()
+                .overflow
+        );
+        assert!(
+            [wrapped, one]
+                .into_iter()
+                .product::<Overflowing<I8F8>>()
+                .overflow
+        );
+
+        let mut acc = Overflowing::new(I8F8::from_num(100));
+        acc += I8F8::from_num(100);
+        assert!(acc.overflow);
+        acc -= I8F8::from_num(100);
+        assert_eq!(acc.value, 100);
+        assert!(acc.overflow);
+    }
+
+    #[test]
+    fn shifts() {
+        let x = Overflowing::new(U8F8::from_bits(0x0181));
+        assert_eq!(x << 1u32, Overflowing::new(U8F8::from_bits(0x0302)));
+        assert_eq!(x >> 1i8, Overflowing::new(U8F8::from_bits(0x00C0)));
+        assert_eq!(x << 15usize, Overflowing::new(U8F8::from_bits(0x8000)));
+
+        // the amount is reduced modulo the number of bits, and overflows
+        for amount in [16u32, 17, 33, u32::MAX] {
+            let reduced = amount % 16;
+            assert_eq!((x << amount).value, x.value << reduced);
+            assert_eq!((x >> amount).value, x.value >> reduced);
+            assert!((x << amount).overflow);
+            assert!((x >> amount).overflow);
+        }
+        for amount in [1u64 << 32, (1 << 32) + 1, u64::MAX] {
+            assert!((x << amount).overflow);
+            assert!((x >> amount).overflow);
+        }
+        for amount in [-1i32, -16, -17, i32::MIN] {
+            let reduced = amount.rem_euclid(16) as u32;
+            assert_eq!((x << amount).value, x.value << reduced);
+            assert_eq!((x >> amount).value, x.value >> reduced);
+            assert!((x << amount).overflow);
+            assert!((x >> amount).overflow);
+        }
+        for amount in [-1i128, i128::MIN, 1 << 64] {
+            assert!((x << amount).overflow);
+            assert!((x >> amount).overflow);
+        }
+
+        let mut y = x;
+        y <<= 16;
+        assert!(y.overflow);
+        let mut y = x;
+        y >>= -1;
+        assert!(y.overflow);
+        let mut y = x;
+        y >>= 3;
+        assert!(!y.overflow);
+    }
+
+    #[test]
+    fn clear_overflow() {
+        let mut x = Overflowing::new(I8F8::MIN);
+        assert!(!x.clear_overflow());
+        x = -x;
+        assert_eq!(x.value, I8F8::MIN);
+        assert!(x.clear_overflow());
+        assert_eq!(x, Overflowing::new(I8F8::MIN));
+        assert!(!x.clear_overflow());
+        // after clearing, a new block starts with a clean flag
+        x += I8F8::ONE;
+        assert!(!x.overflow);
+        x <<= 20u8;
+        assert!(x.clear_overflow());
+        assert!(!x.overflow);
+    }
+
+    #[test]
+    fn math() {
+        let tiny = Overflowing::new(I8F8::DELTA);
+        let wrapped = Overflowing::new(I8F8::MAX) + tiny;
+        assert!(Overflowing::new(I4F12::DELTA).rsqrt().overflow);
+        assert!(!tiny.rsqrt().overflow);
+        assert!(!Overflowing::new(I8F8::from_num(4)).rsqrt().overflow);
+        assert!(Overflowing::new(I8F8::from_num(10)).exp_m1().overflow);
+        assert!(
+            Overflowing::new(I8F8::from_num(100))
+                .powf(Overflowing::new(I8F8::from_num(2)))
+                .overflow
+        );
+        assert_eq!(
+            Overflowing::new(I8F8::from_num(8)).cbrt(),
+            Overflowing::new(I8F8::from_num(2))
+        );
+        assert_eq!(
+            Overflowing::new(I8F8::from_num(16)).nth_root(4),
+            Overflowing::new(I8F8::from_num(2))
+        );
+        assert_eq!(
+            Overflowing::new(I8F8::ONE).asin(),
+            Overflowing::new(I8F8::FRAC_PI_2)
+        );
+        assert_eq!(
+            Overflowing::new(I8F8::NEG_ONE).acos(),
+            Overflowing::new(I8F8::PI)
+        );
+        assert_eq!(
+            Overflowing::new(I8F8::from_num(-1.75)).round_to_zero(),
+            Overflowing::new(I8F8::from_num(-1))
+        );
+        assert!(Overflowing::new(U8F8::MAX).next_power_of_two().overflow);
+        assert!(
+            !Overflowing::new(U8F8::from_num(3))
+                .next_power_of_two()
+                .overflow
+        );
+
+        // methods that cannot overflow still propagate the flag
+        assert!(wrapped.tanh().overflow);
+        assert!(wrapped.round_to_zero().overflow);
+        assert!(wrapped.cbrt().overflow);
+        assert!(wrapped.rem_euclid(Overflowing::new(I8F8::ONE)).overflow);
+        assert!(Overflowing::new(I8F8::ONE).powf(wrapped).overflow);
+    }
+
+    #[test]
+    fn saturating_arith() {
+        for a in i8::MIN..=i8::MAX {
+            let fa = I4F4::from_bits(a);
+            let sa = OverflowingSaturating::new(fa);
+            let expected = |value, (_, overflow)| OverflowingSaturating { value, overflow };
+            assert_eq!(-sa, expected(fa.saturating_neg(), fa.overflowing_neg()));
+            assert_eq!(
+                sa.abs(),
+                expected(fa.saturating_abs(), fa.overflowing_abs())
+            );
+            for b in i8::MIN..=i8::MAX {
+                let fb = I4F4::from_bits(b);
+                let sb = OverflowingSaturating::new(fb);
+                assert_eq!(
+                    sa + sb,
+                    expected(fa.saturating_add(fb), fa.overflowing_add(fb))
+                );
+                assert_eq!(
+                    sa - sb,
+                    expected(fa.saturating_sub(fb), fa.overflowing_sub(fb))
+                );
+                assert_eq!(
+                    sa * sb,
+                    expected(fa.saturating_mul(fb), fa.overflowing_mul(fb))
+                );
+                if b != 0 {
+                    assert_eq!(
+                        sa / sb,
+                        expected(fa.saturating_div(fb), fa.overflowing_div(fb))
+                    );
+                    assert_eq!(sa % sb, OverflowingSaturating::new(fa % fb));
+                    assert_eq!(
+                        sa * b,
+                        expected(fa.saturating_mul_int(b), fa.overflowing_mul_int(b))
+                    );
+                    assert_eq!(
+                        sa / b,
+                        expected(fa.saturating_div_int(b), fa.overflowing_div_int(b))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn saturating_sticky() {
+        let max = OverflowingSaturating::new(I8F8::MAX);
+        let one = OverflowingSaturating::new(I8F8::ONE);
+        let saturated = max + one;
+        assert_eq!(saturated.value, I8F8::MAX);
+        assert!(saturated.overflow);
+        // operations that do not overflow keep the flag of either operand
+        assert!((saturated - one).overflow);
+        assert!((one - saturated).overflow);
+        assert!((saturated % one).overflow);
+        assert!((saturated * 1).overflow);
+        assert!(
+            [one, saturated, one]
+                .iter()
+                .sum::<OverflowingSaturating<I8F8>>()
+                .overflow
+        );
+        assert!(
+            [saturated, one]
+                .into_iter()
+                .product::<OverflowingSaturating<I8F8>>()
+                .overflow
+        );
+        assert_eq!(saturated.to_num::<i32>(), (127, true));
+        assert_eq!(saturated.to_num::<i8>(), (127, true));
+        assert_eq!(one.to_num::<u8>(), (1, false));
+        assert_eq!((-one).to_num::<u8>(), (0, true));
+
+        // the flag is the same as for Overflowing, only the value differs
+        let mut acc = OverflowingSaturating::new(I8F8::from_num(100));
+        let mut wrapping = Overflowing::new(I8F8::from_num(100));
+        acc += I8F8::from_num(100);
+        wrapping += I8F8::from_num(100);
+        assert_eq!(acc.value, I8F8::MAX);
+        assert_eq!(acc.overflow, wrapping.overflow);
+        acc -= I8F8::from_num(100);
+        wrapping -= I8F8::from_num(100);
+        assert_eq!(acc.value, I8F8::MAX - I8F8::from_num(100));
+        assert_eq!(wrapping.value, 100);
+        assert!(acc.overflow && wrapping.overflow);
+        assert!(acc.clear_overflow());
+        acc /= I8F8::from_num(2);
+        assert!(!acc.overflow);
+    }
+
+    #[test]
+    fn saturating_from_num() {
+        for src in [
+            0.0,
+            1.5,
+            -8.0,
+            -8.5,
+            7.9375,
+            7.96875,
+            1e10,
+            -1e10,
+            f64::INFINITY,
+        ] {
+            let s = OverflowingSaturating::<I4F4>::from_num(src);
+            assert_eq!(s.value, I4F4::saturating_from_num(src));
+            assert_eq!(s.overflow, I4F4::checked_from_num(src).is_none());
+        }
+        for bits in i16::MIN..=i16::MAX {
+            let src = I8F8::from_bits(bits);
+            let s = OverflowingSaturating::<U4F4>::from_num(src);
+            assert_eq!(s.value, U4F4::saturating_from_num(src));
+            assert_eq!(s.overflow, U4F4::checked_from_num(src).is_none());
+        }
+        assert_eq!(
+            OverflowingSaturating::<U8F8>::from_num(-1),
+            OverflowingSaturating {
+                value: U8F8::ZERO,
+                overflow: true
+            }
+        );
+    }
+}
@@ -0,0 +1,595 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::exp;
+use crate::int256;
+use crate::int256::U256;
+use core::num::NonZero;
+
+// The powers are computed on a wide floating-point representation with a
+// 256-bit mantissa, and the result is rounded to the nearest only at the end.
+//
+// For the integer power x^n, the magnitude of x is raised to the power |n| by
+// squaring and multiplying. For n < 0, the reciprocal of x is found first using
+// long division. When there are no fractional bits and n ≥ 0, the exact integer
+// power is used instead so that the wrapped value is exact too.
+//
+// For the real power x^y and the root x^(1/n), log₂ |x| is computed with 248
+// fractional bits by repeated squaring of the mantissa, then it is multiplied
+// by y or divided by n, and finally 2 is raised to the power of the result
+// using the exponential table.
+//
+// The exponent is clamped so that it cannot overflow; a clamped exponent always
+// leads to a result that overflows or rounds to zero.
+
+const LOG_FRAC_NBITS: u32 = 248;
+const MAX_EXP: i32 = 1 << 24;
+
+// mant × 2^(exp − 255), where 1 ≤ mant × 2^−255 < 2
+#[derive(Clone, Copy)]
+struct Wide {
+    mant: U256,
+    exp: i32,
+}
+
+const MANT_ONE: U256 = U256 {
+    lo: 0,
+    hi: 1 << 127,
+};
+
+const fn clamp_exp(exp: i32) -> i32 {
+    if exp > MAX_EXP {
+        MAX_EXP
+    } else if exp < -MAX_EXP {
+        -MAX_EXP
+    } else {
+        exp
+    }
+}
+
+const fn is_zero(a: U256) -> bool {
+    a.lo == 0 && a.hi == 0
+}
+
+// Returns abs × 2^−frac_nbits.
+const fn from_abs(abs: NonZero<u128>, frac_nbits: u32) -> Wide {
+    let lz = abs.leading_zeros();
+    Wide {
+        mant: U256 {
+            lo: 0,
+            hi: abs.get() << lz,
+        },
+        exp: 127 - lz as i32 - frac_nbits as i32,
+    }
+}
+
+// Returns 1/abs × 2^frac_nbits.
+const fn from_abs_recip(abs: NonZero<u128>, frac_nbits: u32) -> Wide {
+    let w = from_abs(abs, frac_nbits);
+    if w.mant.hi == MANT_ONE.hi {
+        return Wide {
+            mant: MANT_ONE,
+            exp: -w.exp,
+        };
+    }
+    // SAFETY: w.mant.hi has its most significant bit set
+    let d = unsafe { NonZero::<u128>::new_unchecked(w.mant.hi) };
+    // 2^255 < 2^383 / d < 2^256, since 2^127 < d < 2^128, and the division is
+    // split into two steps as 2^383 does not fit
+    let (hi, rem) = int256::div_rem_u256_u128(MANT_ONE, d);
+    let (lo, _) = int256::div_rem_u256_u128(U256 { lo: 0, hi: rem }, d);
+    Wide {
+        mant: U256 {
+            lo: lo.lo,
+            hi: hi.lo,
+        },
+        exp: -w.exp - 1,
+    }
+}
+
+const fn mul(a: Wide, b: Wide) -> Wide {
+    // 1 ≤ prod < 4 with 254 fractional bits
+    let prod = int256::mul_hi_u256(a.mant, b.mant);
+    let exp = a.exp + b.exp;
+    if prod.hi >> 127 != 0 {
+        Wide {
+            mant: prod,
+            exp: clamp_exp(exp + 1),
+        }
+    } else {
+        Wide {
+            mant: int256::wrapping_shl_u256(prod, 1),
+            exp: clamp_exp(exp),
+        }
+    }
+}
+
+// Returns w rounded to frac_nbits fractional bits, negated if neg is true. The
+// overflow flag is set if the magnitude is ≥ 2^129, in which case only the
+// lower 128 bits of the result are returned.
+const fn finish(neg: bool, w: Wide, frac_nbits: u32) -> (U256, bool) {
+    let (ans, overflow) = exp::scale(w.mant, w.exp + frac_nbits as i32);
+    if neg {
+        (int256::wrapping_neg_u256(ans), overflow)
+    } else {
+        (ans, overflow)
+    }
+}
+
+// Returns 2^frac_nbits.
+const fn one(frac_nbits: u32) -> (U256, bool) {
+    (
+        int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, frac_nbits),
+        false,
+    )
+}
+
+// Returns (±abs × 2^−frac_nbits)^n × 2^frac_nbits, or None if abs is zero and n
+// is negative.
+const fn powi(neg: bool, abs: u128, frac_nbits: u32, n: i32) -> Option<(U256, bool)> {
+    if n == 0 {
+        return Some(one(frac_nbits));
+    }
+    let Some(abs) = NonZero::<u128>::new(abs) else {
+        if n < 0 {
+            return None;
+        }
+        return Some((U256 { lo: 0, hi: 0 }, false));
+    };
+    let mut base = if n < 0 {
+        from_abs_recip(abs, frac_nbits)
+    } else {
+        from_abs(abs, frac_nbits)
+    };
+    let mut rem = n.unsigned_abs();
+    let mut acc: Option<Wide> = None;
+    loop {
+        if rem & 1 != 0 {
+            acc = match acc {
+                Some(acc) => Some(mul(acc, base)),
+                None => Some(base),
+            };
+        }
+        rem >>= 1;
+        if rem == 0 {
+            break;
+        }
+        base = mul(base, base);
+    }
+    let Some(acc) = acc else {
+        unreachable!();
+    };
+    let neg = neg && n & 1 != 0;
+    Some(finish(neg, acc, frac_nbits))
+}
+
+// Returns log₂ of w as a sign and a magnitude with LOG_FRAC_NBITS fractional
+// bits.
+const fn log2(w: Wide) -> (bool, U256) {
+    let mut m = w.mant;
+    // fractional part with 256 fractional bits, of which the lowest
+    // 256 − LOG_FRAC_NBITS are not computed
+    let mut frac = U256 { lo: 0, hi: 0 };
+    let mut i = 0;
+    while i < LOG_FRAC_NBITS && !(m.lo == 0 && m.hi == MANT_ONE.hi) {
+        // 1 ≤ m² < 4 with 254 fractional bits
+        let square = int256::mul_hi_u256(m, m);
+        if square.hi >> 127 != 0 {
+            m = square;
+            if i < 128 {
+                frac.hi |= 1 << (127 - i);
+            } else {
+                frac.lo |= 1 << (255 - i);
+            }
+        } else {
+            m = int256::wrapping_shl_u256(square, 1);
+        }
+        i += 1;
+    }
+    let shift = 256 - LOG_FRAC_NBITS;
+    let frac = U256 {
+        lo: (frac.lo >> shift) | (frac.hi << (128 - shift)),
+        hi: frac.hi >> shift,
+    };
+    let int = w.exp.unsigned_abs() as u128;
+    if w.exp >= 0 {
+        (
+            false,
+            U256 {
+                lo: frac.lo,
+                hi: frac.hi | (int << (LOG_FRAC_NBITS - 128)),
+            },
+        )
+    } else {
+        let int = U256 {
+            lo: 0,
+            hi: int << (LOG_FRAC_NBITS - 128),
+        };
+        (true, int256::wrapping_sub_u256(int, frac))
+    }
+}
+
+// Returns 2^(±(int + frac)), where frac has 256 fractional bits.
+const fn exp2(neg: bool, int: u32, frac: U256) -> Wide {
+    let int = if int > MAX_EXP as u32 {
+        MAX_EXP
+    } else {
+        int as i32
+    };
+    let (int, frac) = if !neg {
+        (int, frac)
+    } else if is_zero(frac) {
+        (-int, frac)
+    } else {
+        (-int - 1, int256::wrapping_neg_u256(frac))
+    };
+    let m = exp::wide_exp2_frac(frac);
+    Wide {
+        mant: U256 {
+            lo: (m.lo >> 1) | (m.hi << 127),
+            hi: (1 << 127) | (m.hi >> 1),
+        },
+        exp: int,
+    }
+}
+
+// Returns the 128 bits of w starting from bit k, where w is a 384-bit number
+// stored as three words starting from the least significant one.
+const fn bits_at(w: [u128; 3], k: i32) -> u128 {
+    if k < 0 {
+        return w[0] << -k;
+    }
+    let index = (k / 128) as usize;
+    let rem = k % 128;
+    if index >= 3 {
+        0
+    } else if rem == 0 {
+        w[index]
+    } else if index == 2 {
+        w[2] >> rem
+    } else {
+        (w[index] >> rem) | (w[index + 1] << (128 - rem))
+    }
+}
+
+// Returns (abs × 2^−frac_nbits)^(±y_abs × 2^−frac_nbits) × 2^frac_nbits, or
+// None if abs is zero and y is negative.
+const fn powf(abs: u128, y_neg: bool, y_abs: u128, frac_nbits: u32) -> Option<(U256, bool)> {
+    if y_abs == 0 {
+        return Some(one(frac_nbits));
+    }
+    let Some(abs) = NonZero::<u128>::new(abs) else {
+        if y_neg {
+            return None;
+        }
+        return Some((U256 { lo: 0, hi: 0 }, false));
+    };
+    let (log_neg, log) = log2(from_abs(abs, frac_nbits));
+    // 384-bit product with LOG_FRAC_NBITS + frac_nbits fractional bits
+    let lo = int256::wide_mul_u128(y_abs, log.lo);
+    let hi = int256::wide_mul_u128(y_abs, log.hi);
+    let (mid, carry) = lo.hi.overflowing_add(hi.lo);
+    let prod = [lo.lo, mid, hi.hi + carry as u128];
+    let point = (LOG_FRAC_NBITS + frac_nbits) as i32;
+    let int = bits_at(prod, point);
+    let int = if bits_at(prod, point + 128) != 0 || int > MAX_EXP as u128 {
+        MAX_EXP as u32
+    } else {
+        int as u32
+    };
+    let frac = U256 {
+        lo: bits_at(prod, point - 256),
+        hi: bits_at(prod, point - 128),
+    };
+    let w = exp2(log_neg != y_neg, int, frac);
+    Some(finish(false, w, frac_nbits))
+}
+
+// Returns (±abs × 2^−frac_nbits)^(1/n) × 2^frac_nbits, or None if n is zero or
+// if n is even and the number is negative.
+const fn root(neg: bool, abs: u128, frac_nbits: u32, n: u32) -> Option<(U256, bool)> {
+    let Some(n) = NonZero::<u128>::new(n as u128) else {
+        return None;
+    };
+    if neg && n.get() & 1 == 0 {
+        return None;
+    }
+    let Some(abs) = NonZero::<u128>::new(abs) else {
+        return Some((U256 { lo: 0, hi: 0 }, false));
+    };
+    let (log_neg, log) = log2(from_abs(abs, frac_nbits));
+    let (q, _) = int256::div_rem_u256_u128(log, n);
+    let int = (q.hi >> (LOG_FRAC_NBITS - 128)) as u32;
+    let shift = 256 - LOG_FRAC_NBITS;
+    let frac = U256 {
+        lo: q.lo << shift,
+        hi: (q.hi << shift) | (q.lo >> (128 - shift)),
+    };
+    let w = exp2(log_neg, int, frac);
+    Some(finish(neg, w, frac_nbits))
+}
+
+macro_rules! narrow {
+    ($u:ident; $ans:expr) => {{
+        let (ans, overflow): (U256, bool) = $ans;
+        let wrapped = ans.lo as $u;
+        (
+            wrapped,
+            overflow || ans.hi != 0 || wrapped as u128 != ans.lo,
+        )
+    }};
+    ($i:ident, $u:ident; $ans:expr) => {{
+        let (ans, overflow): (U256, bool) = $ans;
+        let wrapped = ans.lo as $i;
+        let sign_ext = (wrapped as i128 >> 127) as u128;
+        (
+            wrapped,
+            overflow || ans.hi != sign_ext || wrapped as i128 as u128 != ans.lo,
+        )
+    }};
+}
+
+macro_rules! impl_pow {
+    ($u:ident) => {
+        pub mod $u {
+            use crate::int256::U256;
+            use crate::pow;
+
+            // Returns None if val is zero and n is negative.
+            pub const fn powi(val: $u, frac_nbits: u32, n: i32) -> Option<($u, bool)> {
+                if frac_nbits == 0 && n >= 0 {
+                    return Some(val.overflowing_pow(n as u32));
+                }
+                match pow::powi(false, val as u128, frac_nbits, n) {
+                    Some(ans) => Some(narrow!($u; ans)),
+                    None => None,
+                }
+            }
+
+            // Returns None if val is zero and y is negative.
+            pub const fn powf(val: $u, y: $u, frac_nbits: u32) -> Option<($u, bool)> {
+                match pow::powf(val as u128, false, y as u128, frac_nbits) {
+                    Some(ans) => Some(narrow!($u; ans)),
+                    None => None,
+                }
+            }
+
+            // Returns None if n is zero.
+            pub const fn root(val: $u, frac_nbits: u32, n: u32) -> Option<($u, bool)> {
+                match pow::root(false, val as u128, frac_nbits, n) {
+                    Some(ans) => Some(narrow!($u; ans)),
+                    None => None,
+                }
+            }
+        }
+    };
+    ($i:ident, $u:ident) => {
+        impl_pow! { $u }
+
+        pub mod $i {
+            use crate::int256::U256;
+            use crate::pow;
+
+            // Returns None if val is zero and n is negative.
+            pub const fn powi(val: $i, frac_nbits: u32, n: i32) -> Option<($i, bool)> {
+                if frac_nbits == 0 && n >= 0 {
+                    return Some(val.overflowing_pow(n as u32));
+                }
+                let neg = val < 0;
+                match pow::powi(neg, val.unsigned_abs() as u128, frac_nbits, n) {
+                    Some(ans) => Some(narrow!($i, $u; ans)),
+                    None => None,
+                }
+            }
+
+            // Returns None if val is negative, or if val is zero and y is
+            // negative.
+            pub const fn powf(val: $i, y: $i, frac_nbits: u32) -> Option<($i, bool)> {
+                if val < 0 {
+                    return None;
+                }
+                let y_neg = y < 0;
+                match pow::powf(val as u128, y_neg, y.unsigned_abs() as u128, frac_nbits) {
+                    Some(ans) => Some(narrow!($i, $u; ans)),
+                    None => None,
+                }
+            }
+
+            // Returns None if n is zero, or if n is even and val is negative.
+            pub const fn root(val: $i, frac_nbits: u32, n: u32) -> Option<($i, bool)> {
+                let neg = val < 0;
+                match pow::root(neg, val.unsigned_abs() as u128, frac_nbits, n) {
+                    Some(ans) => Some(narrow!($i, $u; ans)),
+                    None => None,
+                }
+            }
+        }
+    };
+}
+
+impl_pow! { i8, u8 }
+impl_pow! { i16, u16 }
+impl_pow! { i32, u32 }
+impl_pow! { i64, u64 }
+impl_pow! { i128, u128 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::extra::{U0, U4, U7, U8, U12, U16, U24, U28, U32, U60, U64, U100, U127};
+    use crate::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128};
+    use crate::{FixedU8, FixedU16, FixedU32, FixedU64, FixedU128};
+
+    // Checks powi for -4 ≤ n ≤ 4, and the roots for 1 ≤ n ≤ 5.
+    macro_rules! check_f64 {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            let f = val.to_num::<f64>();
+            let delta = Fix::DELTA.to_num::<f64>();
+            let max = Fix::MAX.to_num::<f64>();
+            let min = Fix::MIN.to_num::<f64>();
+            let check = |exact: f64, res: Option<(Fix, bool)>| {
+                let Some((ans, overflow)) = res else {
+                    return;
+                };
+                if overflow {
+                    assert!(exact > max - delta / 2.0 || exact < min - delta / 2.0);
+                } else {
+                    let err = (ans.to_num::<f64>() - exact).abs();
+                    assert!(
+                        err <= delta / 2.0 + exact.abs() * 1e-14,
+                        "{val} {exact} {ans}"
+                    );
+                }
+            };
+            for n in -4..=4 {
+                if val == 0 && n < 0 {
+                    assert!(val.checked_powi(n).is_none());
+                    continue;
+                }
+                check(f.powi(n), Some(val.overflowing_powi(n)));
+            }
+            for n in 1..=5 {
+                let exact = if n == 3 {
+                    f.cbrt()
+                } else if f < 0.0 {
+                    -(-f).powf(1.0 / f64::from(n))
+                } else {
+                    f.powf(1.0 / f64::from(n))
+                };
+                if f < 0.0 && n % 2 == 0 {
+                    assert!(val.checked_nth_root(n).is_none());
+                    continue;
+                }
+                check(exact, Some(val.overflowing_nth_root(n)));
+            }
+            assert_eq!(val.checked_nth_root(0), None);
+            if f > 0.0 {
+                for ybits in [-0x7654_3210_i128, -0x65, 0x17, 0x20_1000_4321] {
+                    let y = Fix::from_bits(ybits as _);
+                    check(f.powf(y.to_num::<f64>()), Some(val.overflowing_powf(y)));
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn exhaustive_8_16() {
+        for bits in i8::MIN..=i8::MAX {
+            check_f64!(FixedI8<U0>, bits);
+            check_f64!(FixedI8<U4>, bits);
+            check_f64!(FixedI8<U7>, bits);
+            check_f64!(FixedI8<U8>, bits);
+            check_f64!(FixedU8<U0>, bits as u8);
+            check_f64!(FixedU8<U4>, bits as u8);
+            check_f64!(FixedU8<U8>, bits as u8);
+        }
+        for bits in (i16::MIN..=i16::MAX).step_by(31) {
+            check_f64!(FixedI16<U0>, bits);
+            check_f64!(FixedI16<U8>, bits);
+            check_f64!(FixedI16<U12>, bits);
+            check_f64!(FixedU16<U12>, bits as u16);
+            check_f64!(FixedU16<U16>, bits as u16);
+        }
+    }
+
+    #[test]
+    fn sample_32_64_128() {
+        for i in -500i32..=500 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let bits = bits >> (i & 15);
+            check_f64!(FixedI32<U24>, bits);
+            check_f64!(FixedI32<U28>, bits);
+            check_f64!(FixedU32<U32>, bits as u32);
+            check_f64!(FixedI64<U32>, i64::from(bits) << 8);
+            check_f64!(FixedU64<U60>, (i64::from(bits) << 28) as u64);
+            check_f64!(FixedI128<U64>, i128::from(bits) << 32);
+            check_f64!(FixedI128<U100>, i128::from(bits) << 72);
+        }
+    }
+
+    #[test]
+    fn powf_all_8() {
+        for bits in 1..=u8::MAX {
+            let val = FixedU8::<U4>::from_bits(bits);
+            for ybits in i8::MIN..=i8::MAX {
+                let y = FixedI8::<U4>::from_bits(ybits);
+                let exact = val.to_num::<f64>().powf(y.to_num::<f64>());
+                let (ans, overflow) = FixedI16::<U4>::from_num(val).overflowing_powf(y.into());
+                if overflow {
+                    assert!(exact >= 2048.0 - 1.0 / 32.0);
+                } else {
+                    assert!((ans.to_num::<f64>() - exact).abs() <= 1.0 / 32.0 + exact * 1e-14);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exact() {
+        // without fractional bits, powi is the same as integer pow
+        for bits in i32::MIN..=i32::MIN + 1000 {
+            let val = FixedI32::<U0>::from_bits(bits);
+            assert_eq!(val.wrapping_powi(3).to_bits(), bits.wrapping_pow(3));
+        }
+        assert_eq!(
+            FixedU64::<U0>::from_num(3).overflowing_powi(41),
+            (FixedU64::from_bits(3u64.wrapping_pow(41)), true)
+        );
+        assert_eq!(FixedI128::<U0>::from_num(-3).powi(79), (-3i128).pow(79));
+
+        assert_eq!(FixedI8::<U4>::ZERO.powi(0), 1);
+        assert_eq!(
+            FixedU8::<U8>::ZERO.overflowing_powi(0),
+            (FixedU8::ZERO, true)
+        );
+        assert_eq!(FixedI8::<U4>::ZERO.checked_powi(-1), None);
+        assert_eq!(FixedI32::<U16>::from_num(0.5).powi(-14), 16384);
+        assert_eq!(
+            FixedI32::<U16>::from_num(0.5).powi(16),
+            FixedI32::<U16>::DELTA
+        );
+        assert_eq!(FixedI32::<U16>::from_num(0.5).powi(18), 0);
+        assert_eq!(FixedI32::<U16>::from_num(-0.5).powi(-3), -8);
+        assert_eq!(FixedI64::<U32>::from_num(1.5).powi(i32::MIN), 0);
+        assert_eq!(FixedI64::<U32>::from_num(-1).powi(i32::MAX), -1);
+        assert_eq!(FixedI64::<U32>::from_num(1.5).checked_powi(i32::MAX), None);
+        assert_eq!(
+            FixedI64::<U32>::from_num(-1.5).saturating_powi(i32::MAX),
+            FixedI64::<U32>::MIN
+        );
+
+        type I1F127 = FixedI128<U127>;
+        type U1F127 = FixedU128<U127>;
+        assert_eq!(I1F127::MIN.checked_powi(2), None);
+        assert_eq!(I1F127::MIN.powi(3), I1F127::MIN);
+        assert_eq!(I1F127::MIN.nth_root(5), I1F127::MIN);
+        assert_eq!(U1F127::from_num(0.25).nth_root(2), 0.5);
+        assert_eq!(U1F127::from_num(0.25).powf(U1F127::from_num(1.5)), 0.125);
+        assert_eq!(U1F127::from_num(0.125).cbrt(), 0.5);
+        assert_eq!(FixedU128::<U0>::MAX.nth_root(1), FixedU128::<U0>::MAX);
+        assert_eq!(FixedU128::<U0>::MAX.cbrt(), 6_981_463_658_332u128);
+        assert_eq!(FixedI64::<U28>::from_num(27).cbrt(), 3);
+        assert_eq!(FixedI64::<U28>::from_num(-27).cbrt(), -3);
+        type U32F32 = FixedU64<U32>;
+        type I32F32 = FixedI64<U32>;
+        let half = U32F32::from_num(0.5);
+        assert_eq!(U32F32::from_num(9).powf(half), 3);
+        assert_eq!(U32F32::ZERO.powf(U32F32::ZERO), 1);
+        assert_eq!(U32F32::ZERO.powf(half), 0);
+        assert_eq!(I32F32::ZERO.checked_powf(I32F32::from_num(-0.5)), None);
+        assert_eq!(I32F32::NEG_ONE.checked_powf(I32F32::ONE), None);
+    }
+}
@@ -15,9 +15,14 @@
 
 #![allow(deprecated)]
 
+use crate::fixed256::{
+    Finite, Wide, checked_convert_round, overflowing_convert_round, saturating_convert_round,
+};
 use crate::float_helper;
-use crate::helpers::{FloatKind, FromFloatHelper, Private};
+use crate::helpers::{self, FloatKind, FromFloatHelper, Private};
 use crate::int_helper::IntFixed;
+use crate::int256::U256;
+use crate::rounding::RoundingMode;
 use crate::traits::{Fixed, FixedEquiv, FromFixed, ToFixed};
 use crate::types::extra::U0;
 use crate::{
@@ -85,6 +90,11 @@ impl ToFixed for bool {
     fn unwrapped_to_fixed<F: Fixed>(self) -> F {
         ToFixed::unwrapped_to_fixed(u8::from(self))
     }
+
+    #[inline]
+    fn to_wide(self, _: Private) -> Wide {
+        u8::from(self).to_wide(Private)
+    }
 }
 
 macro_rules! impl_int {
@@ -160,6 +170,8 @@ macro_rules! impl_int {
             fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
                 IntFixed::<$Int>::int(FromFixed::unwrapped_from_fixed(src))
             }
+
+            from_fixed_round! {}
         }
 
         impl ToFixed for $Int {
@@ -220,6 +232,11 @@ macro_rules! impl_int {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 ToFixed::unwrapped_to_fixed(IntFixed(self).fixed())
             }
+
+            #[inline]
+            fn to_wide(self, _: Private) -> Wide {
+                IntFixed(self).fixed().to_wide(Private)
+            }
         }
 
         $(
@@ -293,6 +310,9 @@ macro_rules! impl_float {
             #[inline]
             #[track_caller]
             fn from_fixed<F: Fixed>(src: F) -> Self {
+                if helpers::is_wide::<F>() {
+                    return overflowing_convert_round(src, RoundingMode::NearestEven).0;
+                }
                 let helper = src.to_float_helper(Private);
                 float_helper::$Float::from_to_float_helper(helper, F::FRAC_NBITS, F::INT_NBITS)
             }
@@ -350,6 +370,8 @@ macro_rules! impl_float {
             fn unwrapped_from_fixed<F: Fixed>(src: F) -> Self {
                 FromFixed::from_fixed(src)
             }
+
+            from_fixed_round! {}
         }
 
         impl ToFixed for $Float {
@@ -385,6 +407,9 @@ it panics; if wrapping is required use [`wrapping_to_fixed`] instead.
             /// Rounding is to the nearest, with ties rounded to even.
             #[inline]
             fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                if helpers::is_wide::<F>() {
+                    return checked_convert_round(self, RoundingMode::NearestEven);
+                }
                 let kind = float_helper::$Float::to_float_kind(self, F::FRAC_NBITS, F::INT_NBITS);
                 match kind {
                     FloatKind::Finite { .. } => {
@@ -413,6 +438,9 @@ Panics if `self` is [NaN].
                 #[inline]
                 #[track_caller]
                 fn saturating_to_fixed<F: Fixed>(self) -> F {
+                    if helpers::is_wide::<F>() {
+                        return saturating_convert_round(self, RoundingMode::NearestEven);
+                    }
                     let kind =
                         float_helper::$Float::to_float_kind(self, F::FRAC_NBITS, F::INT_NBITS);
                     let helper = FromFloatHelper { kind };
@@ -458,6 +486,9 @@ Panics if `self` is not [finite].
                 #[inline]
                 #[track_caller]
                 fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                    if helpers::is_wide::<F>() {
+                        return overflowing_convert_round(self, RoundingMode::NearestEven);
+                    }
                     let kind =
                         float_helper::$Float::to_float_kind(self, F::FRAC_NBITS, F::INT_NBITS);
                     let helper = FromFloatHelper { kind };
@@ -487,6 +518,27 @@ when debug assertions are not enabled.
                     }
                 }
             }
+
+            #[inline]
+            fn to_wide(self, _: Private) -> Wide {
+                match float_helper::$Float::kind(self) {
+                    float_helper::$Float::Kind::NaN => Wide::NaN,
+                    float_helper::$Float::Kind::Infinite { neg } => Wide::Infinite { neg },
+                    float_helper::$Float::Kind::Finite {
+                        neg,
+                        abs,
+                        frac_bits,
+                    } => Wide::Finite(Finite {
+                        neg,
+                        abs: U256 {
+                            lo: abs as u128,
+                            hi: 0,
+                        },
+                        frac_nbits: frac_bits,
+                        round: RoundingMode::NearestEven,
+                    }),
+                }
+            }
         }
     };
 }
@@ -0,0 +1,448 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::from_str;
+use crate::int256;
+use crate::int256::{U256, U512};
+
+// Returns num / den with frac_nbits ≤ 256 fractional bits, rounded to the
+// nearest with ties rounded to even. The bits are wrapped to nbits ≤ 256 bits,
+// but not sign-extended, and the bool indicates whether the value overflows.
+//
+// den must not be zero.
+pub(crate) const fn overflowing_div_bits(
+    neg: bool,
+    num: U256,
+    den: U256,
+    frac_nbits: u32,
+    nbits: u32,
+    signed: bool,
+) -> (U256, bool) {
+    let wide = U512 {
+        lo: num,
+        hi: U256::ZERO,
+    };
+    let (q, r) = int256::div_rem_u512_u256(int256::wrapping_shl_u512(wide, frac_nbits), den);
+    // Since r < den, den - r cannot overflow, and r > den - r means that the
+    // remainder is more than half.
+    let rest = int256::wrapping_sub_u256(den, r);
+    let round_up = if r.hi != rest.hi {
+        r.hi > rest.hi
+    } else if r.lo != rest.lo {
+        r.lo > rest.lo
+    } else {
+        q.lo.lo & 1 != 0
+    };
+    let mut abs = q.lo;
+    let mut overflow = !int256::is_zero_u256(q.hi);
+    if round_up {
+        let (sum, carry) = int256::overflowing_add_u256(abs, U256 { lo: 1, hi: 0 });
+        abs = sum;
+        overflow |= carry;
+    }
+    from_str::apply_sign(neg, abs, overflow, nbits, signed)
+}
+
+// Returns a < b.
+const fn lt_u512(a: U512, b: U512) -> bool {
+    if a.hi.hi != b.hi.hi {
+        a.hi.hi < b.hi.hi
+    } else if a.hi.lo != b.hi.lo {
+        a.hi.lo < b.hi.lo
+    } else if a.lo.hi != b.lo.hi {
+        a.lo.hi < b.lo.hi
+    } else {
+        a.lo.lo < b.lo.lo
+    }
+}
+
+// Returns a < b.
+const fn lt_u256(a: U256, b: U256) -> bool {
+    if a.hi != b.hi {
+        a.hi < b.hi
+    } else {
+        a.lo < b.lo
+    }
+}
+
+const fn eq_u256(a: U256, b: U256) -> bool {
+    a.hi == b.hi && a.lo == b.lo
+}
+
+// Returns a / b, where b is not zero.
+const fn div_u256(a: U256, b: U256) -> U256 {
+    int256::div_rem_u256(a, b).0
+}
+
+// Returns t × a + b, which the callers ensure does not overflow.
+const fn mul_add_u256(t: U256, a: U256, b: U256) -> U256 {
+    int256::overflowing_add_u256(int256::wide_mul_u256(t, a).lo, b).0
+}
+
+// Checks whether the semiconvergent (t h1 + h2) / (t k1 + k2) is strictly
+// closer than the convergent h1 / k1, where a + r / d is the complete quotient
+// and t < a.
+//
+// With determinant ±1, the semiconvergent is closer if and only if
+// (a + r / d - t) k1 < t k1 + k2, that is a - t + r / d < t + k2 / k1.
+const fn semiconvergent_is_closer(a: U512, r: U256, d: U256, t: U256, k1: U256, k2: U256) -> bool {
+    if int256::is_zero_u256(k1) {
+        // h1 / k1 is infinite
+        return true;
+    }
+    // t ≤ a, and a can be 2^256 or more when t is close to U256::MAX
+    let lhs_int = int256::overflowing_add_u512(
+        a,
+        int256::wrapping_neg_u512(U512 {
+            lo: t,
+            hi: U256::ZERO,
+        }),
+    )
+    .0;
+    // k2 ≤ k1, and if k2 = k1 then k2 / k1 = 1 is moved to the integer part;
+    // then k1 = 1, so t ≤ max_den − 1 and t + 1 cannot overflow
+    let (rhs_int, k2) = if eq_u256(k2, k1) {
+        (int256::wrapping_add_u256_u128(t, 1), U256::ZERO)
+    } else {
+        (t, k2)
+    };
+    let rhs_int = U512 {
+        lo: rhs_int,
+        hi: U256::ZERO,
+    };
+    if !int256::eq_u512(lhs_int, rhs_int) {
+        return lt_u512(lhs_int, rhs_int);
+    }
+    // compare r / d < k2 / k1
+    lt_u512(int256::wide_mul_u256(r, k1), int256::wide_mul_u256(d, k2))
+}
+
+// Returns the best rational approximation p / q of abs / 2^frac_nbits with
+// p ≤ max_num and 1 ≤ q ≤ max_den, where frac_nbits ≤ 256 and max_den ≥ 1.
+// If two approximations are equally close, the one with the smaller
+// denominator is returned, and for equal denominators, which can only be 1,
+// the one with the smaller numerator is returned.
+pub(crate) const fn best_ratio(
+    abs: U256,
+    frac_nbits: u32,
+    max_num: U256,
+    max_den: U256,
+) -> (U256, U256) {
+    const ZERO: U256 = U256::ZERO;
+    const ONE: U256 = U256 { lo: 1, hi: 0 };
+    // Convergents are h1 / k1, and h2 / k2 is the previous convergent. The
+    // continued fraction of n / d has still to be expanded.
+    let (mut h1, mut k1, mut h2, mut k2) = (ONE, ZERO, ZERO, ONE);
+    let (mut n, mut d) = if frac_nbits < 256 {
+        (
+            U512 {
+                lo: abs,
+                hi: U256::ZERO,
+            },
+            int256::wrapping_shl_u256(ONE, frac_nbits),
+        )
+    } else {
+        // abs / 2^256 < 1, so the first term is 0
+        if int256::is_zero_u256(abs) {
+            return (ZERO, ONE);
+        }
+        (h1, k1, h2, k2) = (ZERO, ONE, ONE, ZERO);
+        (
+            U512 {
+                lo: U256::ZERO,
+                hi: ONE,
+            },
+            abs,
+        )
+    };
+    loop {
+        let (a, r) = int256::div_rem_u512_u256(n, d);
+        let a_fits = int256::is_zero_u256(a.hi);
+        let mut t = if a_fits { a.lo } else { U256::MAX };
+        if !int256::is_zero_u256(h1) {
+            let bound = div_u256(int256::wrapping_sub_u256(max_num, h2), h1);
+            if lt_u256(bound, t) {
+                t = bound;
+            }
+        }
+        if !int256::is_zero_u256(k1) {
+            let bound = div_u256(int256::wrapping_sub_u256(max_den, k2), k1);
+            if lt_u256(bound, t) {
+                t = bound;
+            }
+        }
+        if !a_fits || lt_u256(t, a.lo) {
+            return if semiconvergent_is_closer(a, r, d, t, k1, k2) {
+                (mul_add_u256(t, h1, h2), mul_add_u256(t, k1, k2))
+            } else {
+                (h1, k1)
+            };
+        }
+        (h1, h2) = (mul_add_u256(t, h1, h2), h1);
+        (k1, k2) = (mul_add_u256(t, k1, k2), k1);
+        if int256::is_zero_u256(r) {
+            return (h1, k1);
+        }
+        n = U512 {
+            lo: d,
+            hi: U256::ZERO,
+        };
+        d = r;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::from_str::ParseErrorKind;
+    use crate::int256::U256;
+    use crate::types::extra::{U0, U4, U128, U256 as Frac256};
+    use crate::types::*;
+    use crate::{FixedI128, FixedI256, FixedU128, FixedU256, I256Bits, U256Bits};
+    use std::format;
+    #[cfg(not(feature = "std"))]
+    use std::vec::Vec;
+
+    // Rounds num × 16 / den to the nearest, with ties rounded to even.
+    fn expected_bits(num: i32, den: i32) -> i32 {
+        let (n, d) = if den < 0 {
+            (-num * 16, -den)
+        } else {
+            (num * 16, den)
+        };
+        let q = n.div_euclid(d);
+        let twice_r = 2 * n.rem_euclid(d);
+        if twice_r > d || (twice_r == d && q & 1 != 0) {
+            q + 1
+        } else {
+            q
+        }
+    }
+
+    #[test]
+    fn from_ratio_exhaustive() {
+        for num in i8::MIN..=i8::MAX {
+            for den in i8::MIN..=i8::MAX {
+                if den == 0 {
+                    assert_eq!(I4F4::checked_from_ratio(num, den), None);
+                    continue;
+                }
+                let bits = expected_bits(num.into(), den.into());
+                let fits = i32::from(i8::MIN) <= bits && bits <= i32::from(i8::MAX);
+                assert_eq!(
+                    I4F4::overflowing_from_ratio(num, den),
+                    (I4F4::from_bits(bits as i8), !fits)
+                );
+            }
+        }
+        for num in u8::MIN..=u8::MAX {
+            for den in 1..=u8::MAX {
+                let bits = expected_bits(num.into(), den.into());
+                let fits = bits <= i32::from(u8::MAX);
+                assert_eq!(
+                    U4F4::overflowing_from_ratio(num, den),
+                    (U4F4::from_bits(bits as u8), !fits)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_ratio_wide() {
+        let thirds = u128::MAX / 3;
+        assert_eq!(
+            FixedU128::<U0>::from_ratio(u128::MAX, 3),
+            FixedU128::<U0>::from_bits(thirds)
+        );
+        // 2^128 / 3 rounds down, 2^129 / 3 rounds up
+        assert_eq!(
+            FixedU128::<U128>::from_ratio(1, 3),
+            FixedU128::<U128>::from_bits(thirds)
+        );
+        assert_eq!(
+            FixedU128::<U128>::from_ratio(2, 3),
+            FixedU128::<U128>::from_bits(thirds * 2 + 1)
+        );
+        assert_eq!(
+            FixedI128::<U4>::from_ratio(i128::MIN, i128::MIN),
+            FixedI128::<U4>::ONE
+        );
+        assert_eq!(
+            FixedI128::<U0>::overflowing_from_ratio(i128::MIN, -1),
+            (FixedI128::<U0>::MIN, true)
+        );
+        assert_eq!(
+            FixedI128::<U0>::saturating_from_ratio(i128::MIN, -1),
+            FixedI128::<U0>::MAX
+        );
+        assert_eq!(
+            FixedI128::<U4>::saturating_from_ratio(i128::MAX, -1),
+            FixedI128::<U4>::MIN
+        );
+    }
+
+    #[test]
+    fn from_str_ratio() {
+        assert_eq!(I8F8::from_str_ratio("+3/4"), Ok(I8F8::from_num(0.75)));
+        assert_eq!(I8F8::from_str_ratio("-0/4"), Ok(I8F8::ZERO));
+        assert_eq!(I8F8::from_str_ratio("-128"), Ok(I8F8::MIN));
+        assert_eq!(
+            I8F8::from_str_ratio("128").unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
+        let big = format!("{}/{}", "9".repeat(75), "3".repeat(75));
+        assert_eq!(I8F8::from_str_ratio(&big), Ok(I8F8::from_num(3)));
+        assert_eq!(
+            FixedI256::<U4>::from_str_ratio(&big),
+            Ok(FixedI256::<U4>::from_num(3))
+        );
+        let too_big = format!("{}/1", "9".repeat(80));
+        assert_eq!(
+            I8F8::from_str_ratio(&too_big).unwrap_err().kind(),
+            ParseErrorKind::Overflow
+        );
+
+        let err = I8F8::from_str_ratio("3/0").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::ZeroDenominator, Some(2))
+        );
+        let err = I8F8::from_str_ratio("3/-4").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::MisplacedSign, Some(2))
+        );
+        let err = I8F8::from_str_ratio("-/4").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::NoDigits, Some(1))
+        );
+        let err = I8F8::from_str_ratio("3/").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::NoDigits, Some(2))
+        );
+        let err = I8F8::from_str_ratio("3/4/5").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::InvalidDigit, Some(3))
+        );
+        let err = I8F8::from_str_ratio("1.5/2").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.offset()),
+            (ParseErrorKind::InvalidDigit, Some(1))
+        );
+    }
+
+    // Finds the best p / q for bits / 16 with q ≤ max_den by searching all
+    // denominators, with ties resolved in favor of smaller q and then smaller
+    // |p|. Returns the results for all max_den from 1 to max_q.
+    fn expected_ratios(bits: i32, min_p: i32, max_p: i32, max_q: i32) -> Vec<(i32, i32)> {
+        let mut ret = Vec::new();
+        let mut best: (i32, i32, i32) = (0, 0, i32::MAX);
+        for q in 1..=max_q {
+            let floor = (bits * q).div_euclid(16);
+            for p in [floor, floor + 1] {
+                let p = p.clamp(min_p, max_p);
+                let err = (bits * q - 16 * p).abs();
+                // err / q < best_err / best_q
+                let closer = i64::from(err) * i64::from(best.1) < i64::from(best.2) * i64::from(q);
+                let tie = i64::from(err) * i64::from(best.1) == i64::from(best.2) * i64::from(q);
+                if best.1 == 0 || closer || (tie && q == best.1 && p.abs() < best.0.abs()) {
+                    best = (p, q, err);
+                }
+            }
+            ret.push((best.0, best.1));
+        }
+        ret
+    }
+
+    #[test]
+    fn to_ratio_exhaustive() {
+        for bits in i8::MIN..=i8::MAX {
+            let expected = expected_ratios(bits.into(), -128, 127, 127);
+            for (max_den, &(p, q)) in (1..=i8::MAX).zip(expected.iter()) {
+                let (num, den) = I4F4::from_bits(bits).to_ratio(max_den);
+                assert_eq!((i32::from(num), i32::from(den)), (p, q));
+            }
+        }
+        for bits in u8::MIN..=u8::MAX {
+            let expected = expected_ratios(bits.into(), 0, 255, 255);
+            for (max_den, &(p, q)) in (1..=u8::MAX).zip(expected.iter()) {
+                let (num, den) = U4F4::from_bits(bits).to_ratio(max_den);
+                assert_eq!((i32::from(num), i32::from(den)), (p, q));
+            }
+        }
+    }
+
+    #[test]
+    fn to_ratio_wide() {
+        let pi = I16F16::PI;
+        assert_eq!(pi.to_ratio(7), (22, 7));
+        assert_eq!(pi.to_ratio(112), (333, 106));
+        assert_eq!(pi.to_ratio(113), (355, 113));
+        assert_eq!(I16F16::from_ratio(355, 113), pi);
+        assert_eq!((-pi).to_ratio(113), (-355, 113));
+
+        assert_eq!(I8F0::MIN.to_ratio(1), (-128, 1));
+        assert_eq!(I8F0::MAX.to_ratio(i8::MAX), (127, 1));
+        // 63.5 has no ratio with a numerator that fits
+        assert_eq!(I7F1::MAX.to_ratio(2), (127, 2));
+        assert_eq!(I7F1::MAX.to_ratio(3), (127, 2));
+        assert_eq!(I1F7::MIN.to_ratio(3), (-1, 1));
+
+        let delta = FixedU128::<U128>::DELTA;
+        assert_eq!(delta.to_ratio(u128::MAX), (1, u128::MAX));
+        assert_eq!(delta.to_ratio(u128::MAX / 2), (0, 1));
+        assert_eq!(FixedU128::<U0>::MAX.to_ratio(1), (u128::MAX, 1));
+        assert_eq!(FixedI128::<U0>::MIN.to_ratio(1), (i128::MIN, 1));
+
+        let half = FixedU256::<Frac256>::from_u256(U256 {
+            lo: 0,
+            hi: 1 << 127,
+        });
+        let u = U256Bits::from_u128;
+        let i = I256Bits::from_i128;
+        assert_eq!(half.to_ratio(U256Bits::MAX), (u(1), u(2)));
+        assert_eq!(
+            FixedU256::<Frac256>::DELTA.to_ratio(U256Bits::MAX),
+            (u(1), U256Bits::MAX)
+        );
+        assert_eq!(
+            FixedU256::<Frac256>::DELTA.to_ratio(U256Bits::MAX >> 1),
+            (u(0), u(1))
+        );
+        assert_eq!(
+            FixedU256::<Frac256>::MAX.to_ratio(U256Bits::MAX),
+            (U256Bits::MAX - u(1), U256Bits::MAX)
+        );
+        assert_eq!(FixedU256::<Frac256>::MAX.to_ratio(u(1)), (u(1), u(1)));
+        assert_eq!(FixedU256::<U0>::MAX.to_ratio(u(1)), (U256Bits::MAX, u(1)));
+        assert_eq!(FixedI256::<U0>::MIN.to_ratio(i(1)), (I256Bits::MIN, i(1)));
+        assert_eq!(
+            FixedI256::<U0>::MAX.to_ratio(I256Bits::MAX),
+            (I256Bits::MAX, i(1))
+        );
+        assert_eq!(
+            FixedI256::<U4>::from_num(-1.3125).to_ratio(i(4)),
+            (i(-4), i(3))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "non-positive maximum denominator")]
+    fn to_ratio_zero_max_den() {
+        let _ = I8F8::ONE.to_ratio(0);
+    }
+}
@@ -0,0 +1,531 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+/// The rounding mode used when a conversion loses precision.
+///
+/// This is used by methods such as [`FixedI32::from_num_round`] and
+/// [`FixedI32::to_num_round`] to choose how a value that cannot be represented
+/// exactly in the destination is rounded.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::{I8F8, I16F16};
+/// use fixed::RoundingMode;
+/// // 1.5 is exactly between 1 and 2
+/// let src = I16F16::from_num(-1.5);
+/// assert_eq!(src.to_num_round::<i32>(RoundingMode::NearestEven), -2);
+/// assert_eq!(src.to_num_round::<i32>(RoundingMode::NearestAway), -2);
+/// assert_eq!(src.to_num_round::<i32>(RoundingMode::Floor), -2);
+/// assert_eq!(src.to_num_round::<i32>(RoundingMode::Ceil), -1);
+/// assert_eq!(src.to_num_round::<i32>(RoundingMode::TowardZero), -1);
+///
+/// // 0x1.0080 is exactly between 0x1.00 and 0x1.01
+/// let src = I16F16::from_bits(0x1_0080);
+/// assert_eq!(I8F8::from_num_round(src, RoundingMode::NearestEven), I8F8::from_bits(0x100));
+/// assert_eq!(I8F8::from_num_round(src, RoundingMode::NearestAway), I8F8::from_bits(0x101));
+/// ```
+///
+/// [`FixedI32::from_num_round`]: crate::FixedI32::from_num_round
+/// [`FixedI32::to_num_round`]: crate::FixedI32::to_num_round
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest, with ties rounded to even.
+    ///
+    /// This is the rounding used by default when converting from or to
+    /// floating-point numbers.
+    #[default]
+    NearestEven,
+    /// Round to the nearest, with ties rounded away from zero.
+    NearestAway,
+    /// Round towards &minus;∞.
+    ///
+    /// This is the rounding used by default when converting from a fixed-point
+    /// number to another fixed-point number or to an integer.
+    Floor,
+    /// Round towards +∞.
+    Ceil,
+    /// Round towards zero.
+    TowardZero,
+}
+
+impl RoundingMode {
+    // Returns how the magnitude of a number with the given sign is rounded.
+    #[inline]
+    pub(crate) const fn round_abs(self, neg: bool) -> RoundAbs {
+        match self {
+            RoundingMode::NearestEven => RoundAbs::HalfEven,
+            RoundingMode::NearestAway => RoundAbs::HalfUp,
+            // for negative numbers, rounding towards −∞ increases the magnitude
+            RoundingMode::Floor if neg => RoundAbs::Up,
+            RoundingMode::Floor => RoundAbs::Down,
+            RoundingMode::Ceil if neg => RoundAbs::Down,
+            RoundingMode::Ceil => RoundAbs::Up,
+            RoundingMode::TowardZero => RoundAbs::Down,
+        }
+    }
+
+    // Returns the mode m such that rounding −x using self gives −(x rounded
+    // using m).
+    #[inline]
+    pub(crate) const fn negated(self) -> RoundingMode {
+        match self {
+            RoundingMode::Floor => RoundingMode::Ceil,
+            RoundingMode::Ceil => RoundingMode::Floor,
+            other => other,
+        }
+    }
+}
+
+// How to round a magnitude.
+#[derive(Clone, Copy)]
+pub(crate) enum RoundAbs {
+    Down,
+    Up,
+    HalfUp,
+    HalfEven,
+}
+
+impl RoundAbs {
+    // Returns whether a truncated magnitude q should be incremented. half is
+    // whether the discarded part is at least one half, lower is whether the
+    // discarded part is not exactly zero or one half, and odd is whether q is
+    // odd.
+    #[inline]
+    pub(crate) const fn is_up(self, half: bool, lower: bool, odd: bool) -> bool {
+        match self {
+            RoundAbs::Down => false,
+            RoundAbs::Up => half || lower,
+            RoundAbs::HalfUp => half,
+            RoundAbs::HalfEven => half && (lower || odd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::extra::{U32, U100};
+    use crate::types::{
+        I0F8, I0F128, I1F7, I4F4, I8F8, I16F16, I32F0, I32F32, U0F8, U0F128, U4F4, U8F8,
+    };
+    use crate::*;
+    use half::f16;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestEven,
+        RoundingMode::NearestAway,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::TowardZero,
+    ];
+
+    // Divides num by 2^shift, rounding using mode.
+    fn div_pow2(num: i64, shift: u32, mode: RoundingMode) -> i64 {
+        let floor = num >> shift;
+        let rem = num - (floor << shift);
+        if rem == 0 {
+            return floor;
+        }
+        let half = 1 << (shift - 1);
+        let up = match mode {
+            RoundingMode::NearestEven => rem > half || (rem == half && floor & 1 != 0),
+            RoundingMode::NearestAway => rem > half || (rem == half && num > 0),
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::TowardZero => num < 0,
+        };
+        floor + i64::from(up)
+    }
+
+    // Divides num by den, rounding using mode.
+    fn div_ref(mut num: i128, mut den: i128, mode: RoundingMode) -> i128 {
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        let floor = num.div_euclid(den);
+        let rem = num - floor * den;
+        if rem == 0 {
+            return floor;
+        }
+        let up = match mode {
+            RoundingMode::NearestEven => 2 * rem > den || (2 * rem == den && floor & 1 != 0),
+            RoundingMode::NearestAway => 2 * rem > den || (2 * rem == den && num > 0),
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::TowardZero => num < 0,
+        };
+        floor + i128::from(up)
+    }
+
+    #[test]
+    fn mul_div_narrow() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                let (fa, fb) = (I4F4::from_bits(a), I4F4::from_bits(b));
+                let (ua, ub) = (U4F4::from_bits(a as u8), U4F4::from_bits(b as u8));
+                let (a, b) = (i128::from(a), i128::from(b));
+                let (ua_bits, ub_bits) = (i128::from(ua.to_bits()), i128::from(ub.to_bits()));
+                for mode in MODES {
+                    let expected = div_ref(a * b, 16, mode);
+                    let (wrapped, overflow) = fa.overflowing_mul_round(fb, mode);
+                    assert_eq!(wrapped.to_bits(), expected as i8);
+                    assert_eq!(overflow, i8::try_from(expected).is_err());
+                    let expected = div_ref(ua_bits * ub_bits, 16, mode);
+                    let (wrapped, overflow) = ua.overflowing_mul_round(ub, mode);
+                    assert_eq!(wrapped.to_bits(), expected as u8);
+                    assert_eq!(overflow, u8::try_from(expected).is_err());
+                    if b != 0 {
+                        let expected = div_ref(a << 4, b, mode);
+                        let (wrapped, overflow) = fa.overflowing_div_round(fb, mode);
+                        assert_eq!(wrapped.to_bits(), expected as i8);
+                        assert_eq!(overflow, i8::try_from(expected).is_err());
+                        let expected = div_ref(ua_bits << 4, ub_bits, mode);
+                        let (wrapped, overflow) = ua.overflowing_div_round(ub, mode);
+                        assert_eq!(wrapped.to_bits(), expected as u8);
+                        assert_eq!(overflow, u8::try_from(expected).is_err());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn recip_narrow() {
+        for bits in i8::MIN..=i8::MAX {
+            if bits == 0 {
+                continue;
+            }
+            let b = i128::from(bits);
+            let ub = i128::from(bits as u8);
+            for mode in MODES {
+                let expected = div_ref(1 << 8, b, mode);
+                let (wrapped, overflow) = I4F4::from_bits(bits).overflowing_recip_round(mode);
+                assert_eq!(wrapped.to_bits(), expected as i8);
+                assert_eq!(overflow, i8::try_from(expected).is_err());
+                let expected = div_ref(1 << 14, b, mode);
+                let (wrapped, overflow) = I1F7::from_bits(bits).overflowing_recip_round(mode);
+                assert_eq!(wrapped.to_bits(), expected as i8);
+                assert_eq!(overflow, i8::try_from(expected).is_err());
+                let expected = div_ref(1 << 16, b, mode);
+                let (wrapped, overflow) = I0F8::from_bits(bits).overflowing_recip_round(mode);
+                assert_eq!(wrapped.to_bits(), expected as i8);
+                assert_eq!(overflow, i8::try_from(expected).is_err());
+                let expected = div_ref(1 << 16, ub, mode);
+                let (wrapped, overflow) = U0F8::from_bits(bits as u8).overflowing_recip_round(mode);
+                assert_eq!(wrapped.to_bits(), expected as u8);
+                assert_eq!(overflow, u8::try_from(expected).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_wide() {
+        let mut state = 1u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as i64
+        };
+        for _ in 0..10_000 {
+            let (a, b) = (next(), next());
+            let (a, b) = (i128::from(a), i128::from(b >> 16));
+            for mode in MODES {
+                // (a << 64) × b with 100 fractional bits is a × b >> 36
+                let fa = FixedI128::<U100>::from_bits(a << 64);
+                let fb = FixedI128::<U100>::from_bits(b);
+                let expected = div_ref(a * b, 1 << 36, mode);
+                assert_eq!(fa.mul_round(fb, mode).to_bits(), expected);
+                let fa = FixedU128::<U100>::from_bits(a.unsigned_abs() << 64);
+                let fb = FixedU128::<U100>::from_bits(b.unsigned_abs());
+                let expected = div_ref(a.abs() * b.abs(), 1 << 36, mode);
+                assert_eq!(fa.mul_round(fb, mode).to_bits(), expected as u128);
+                if b != 0 {
+                    let fa = FixedI128::<U32>::from_bits(a);
+                    let fb = FixedI128::<U32>::from_bits(b);
+                    let expected = div_ref(a << 32, b, mode);
+                    assert_eq!(fa.div_round(fb, mode).to_bits(), expected);
+                    let fa = FixedU128::<U32>::from_bits(a.unsigned_abs());
+                    let fb = FixedU128::<U32>::from_bits(b.unsigned_abs());
+                    let expected = div_ref(a.abs() << 32, b.abs(), mode);
+                    assert_eq!(fa.div_round(fb, mode).to_bits(), expected as u128);
+                }
+            }
+
+            // Floor matches mul, and TowardZero matches div
+            let a = I32F32::from_bits(a as i64);
+            let b = I32F32::from_bits(b as i64);
+            assert_eq!(
+                a.overflowing_mul_round(b, RoundingMode::Floor),
+                a.overflowing_mul(b)
+            );
+            if b != 0 {
+                assert_eq!(
+                    a.overflowing_div_round(b, RoundingMode::TowardZero),
+                    a.overflowing_div(b)
+                );
+            }
+        }
+
+        // 2/3 = 0.1010...
+        let two_thirds = U0F128::from_bits(u128::MAX / 3 * 2);
+        let a = U0F128::from_bits(1 << 127);
+        let b = U0F128::from_bits(3 << 126);
+        assert_eq!(a.div_round(b, RoundingMode::Floor), two_thirds);
+        assert_eq!(
+            a.div_round(b, RoundingMode::NearestEven),
+            two_thirds + U0F128::DELTA
+        );
+        let (wrapped, overflow) =
+            I0F128::MIN.overflowing_div_round(-I0F128::DELTA, RoundingMode::Ceil);
+        assert_eq!((wrapped, overflow), (I0F128::ZERO, true));
+        assert_eq!(
+            I0F128::MIN.overflowing_mul_round(I0F128::MIN, RoundingMode::Ceil),
+            (I0F128::from_bits(1 << 126), false)
+        );
+    }
+
+    #[test]
+    fn stochastic() {
+        // With r = j × 2^56 for j in 0..256, the source fraction of 8 bits is
+        // rounded up exactly as often as it should be, so the sum is exact.
+        for bits in i32::from(i16::MIN)..=i32::from(i16::MAX) {
+            let src = I16F16::from_bits(bits);
+            let mut sum = 0;
+            for j in 0..256 {
+                let mut rng = || j << 56;
+                let dst = I8F8::overflowing_from_num_stochastic(src, &mut rng);
+                sum += i32::from(dst.0.to_bits());
+            }
+            assert_eq!(sum, bits);
+        }
+        for num in -0x1_0000..=0x1_0000 {
+            // num / 2^12 is exact in f32
+            let src = num as f32 / 4096.0;
+            let mut sum = 0;
+            for j in 0..256 {
+                let mut rng = || j << 56;
+                sum += i32::from(I8F8::from_num_stochastic(src, &mut rng).to_bits());
+            }
+            assert_eq!(sum, num * 16);
+        }
+
+        // the fraction is far below the first 64 random bits
+        let tiny = f64::from_bits(1);
+        let mut zero = || 0u64;
+        let mut one = || 1u64;
+        assert_eq!(I8F8::from_num_stochastic(tiny, &mut zero), I8F8::DELTA);
+        assert_eq!(I8F8::from_num_stochastic(tiny, &mut one), 0);
+        assert_eq!(I8F8::from_num_stochastic(-tiny, &mut zero), -I8F8::DELTA);
+        assert_eq!(U8F8::checked_from_num_stochastic(-tiny, &mut zero), None);
+        assert_eq!(
+            U8F8::saturating_from_num_stochastic(-tiny, &mut zero),
+            U8F8::ZERO
+        );
+        assert_eq!(U8F8::from_num_stochastic(-tiny, &mut one), 0);
+    }
+
+    #[test]
+    fn dither() {
+        // The first random number, which is added to the value, takes the
+        // midpoints of 256 equal intervals, so its mean is exactly one half.
+        // The second random number, which is used for stochastic rounding,
+        // takes 512 equally spaced values, which is fine enough to round the
+        // resulting fractions without bias. So the sum is exact.
+        let mid = |a: u64| (a << 56) | (1 << 55);
+        for bits in (-1000..=1000).step_by(37).chain([-256, -1, 0, 1, 256]) {
+            let src = I16F16::from_bits(bits);
+            let mut sum = 0;
+            for a in 0..256 {
+                for b in 0..512 {
+                    let mut draws = [mid(a), b << 55].into_iter();
+                    let mut rng = || draws.next().unwrap();
+                    let dst = I8F8::from_num_dither(src, &mut rng);
+                    let floor = bits >> 8;
+                    assert!(floor - 1 <= i32::from(dst.to_bits()));
+                    assert!(i32::from(dst.to_bits()) <= floor + 2);
+                    sum += i32::from(dst.to_bits());
+                }
+            }
+            assert_eq!(sum, 512 * bits);
+        }
+
+        // the dither can make zero negative
+        let mut draws = [0, !0].into_iter().cycle();
+        let mut rng = || draws.next().unwrap();
+        assert_eq!(I8F8::from_num_dither(0, &mut rng), -I8F8::DELTA);
+        assert_eq!(U8F8::checked_from_num_dither(0, &mut rng), None);
+        assert_eq!(U8F8::saturating_from_num_dither(0, &mut rng), 0);
+    }
+
+    #[test]
+    fn stochastic_third_party_source() {
+        use crate::traits::{Fixed, ToFixed};
+        use crate::types::I30F2;
+
+        // a number of quarters, converted through I30F2
+        #[derive(Clone, Copy)]
+        struct Quarters(i32);
+        impl ToFixed for Quarters {
+            fn to_fixed<F: Fixed>(self) -> F {
+                I30F2::from_bits(self.0).to_num()
+            }
+            fn checked_to_fixed<F: Fixed>(self) -> Option<F> {
+                I30F2::from_bits(self.0).checked_to_num()
+            }
+            fn saturating_to_fixed<F: Fixed>(self) -> F {
+                I30F2::from_bits(self.0).saturating_to_num()
+            }
+            fn wrapping_to_fixed<F: Fixed>(self) -> F {
+                I30F2::from_bits(self.0).wrapping_to_num()
+            }
+            fn overflowing_to_fixed<F: Fixed>(self) -> (F, bool) {
+                I30F2::from_bits(self.0).overflowing_to_num()
+            }
+        }
+
+        let mut sum = 0;
+        for j in 0..4 {
+            let mut rng = || j << 62;
+            let dst = Quarters(-7).to_fixed_stochastic::<I32F0>(&mut rng);
+            sum += dst.to_bits();
+        }
+        assert_eq!(sum, -7);
+        let mut zero = || 0u64;
+        assert_eq!(Quarters(6).to_fixed_dither::<I8F8>(&mut zero), 1.5);
+        assert_eq!(I32F0::from_num_round(Quarters(5), RoundingMode::Floor), 1);
+        assert_eq!(I32F0::from_num_round(Quarters(5), RoundingMode::Ceil), 2);
+        assert_eq!(
+            I1F7::checked_from_num_stochastic(Quarters(4), &mut zero),
+            None
+        );
+        assert_eq!(
+            I1F7::saturating_from_num_stochastic(Quarters(-40), &mut zero),
+            I1F7::MIN
+        );
+    }
+
+    #[test]
+    fn fixed_to_fixed() {
+        for bits in i32::from(i16::MIN)..=i32::from(i16::MAX) {
+            let src = I16F16::from_bits(bits << 4);
+            for mode in MODES {
+                let expected = div_pow2(i64::from(bits) << 4, 8, mode);
+                let (wrapped, overflow) = I8F8::overflowing_from_num_round(src, mode);
+                assert_eq!(wrapped, I8F8::from_bits(expected as i16));
+                assert_eq!(overflow, i16::try_from(expected).is_err());
+                let (wrapped, overflow) = src.overflowing_to_num_round::<U8F8>(mode);
+                assert_eq!(wrapped, U8F8::from_bits(expected as u16));
+                assert_eq!(overflow, u16::try_from(expected).is_err());
+                let int = div_pow2(i64::from(bits) << 4, 16, mode);
+                assert_eq!(src.to_num_round::<i32>(mode), int as i32);
+                assert_eq!(src.checked_to_num_round::<u8>(mode), u8::try_from(int).ok());
+            }
+        }
+    }
+
+    #[test]
+    fn requantize() {
+        use crate::types::I24F8;
+        use crate::types::extra::{U0, U3};
+        for bits in i16::MIN..=i16::MAX {
+            let src = I8F8::from_bits(bits);
+            for mode in MODES {
+                let expected = div_pow2(i64::from(bits), 5, mode);
+                assert_eq!(src.requantize::<U3>(mode).to_bits(), expected as i16);
+                let expected = div_pow2(i64::from(bits), 8, mode);
+                assert_eq!(src.requantize::<U0>(mode).to_bits(), expected as i16);
+            }
+            let wide: I24F8 = src.widen();
+            assert_eq!(wide, src);
+            assert_eq!(wide.narrow::<I8F8>(), Some(src));
+            assert_eq!(wide.narrow::<I0F8>(), I0F8::checked_from_num(src));
+        }
+    }
+
+    #[test]
+    fn float_to_fixed() {
+        for num in -0x1_0000..=0x1_0000 {
+            // num / 2^12 is exact in f32
+            let src = num as f32 / 4096.0;
+            for mode in MODES {
+                let expected = I8F8::from_bits(div_pow2(num, 4, mode) as i16);
+                assert_eq!(I8F8::from_num_round(src, mode), expected);
+                assert_eq!(I8F8::from_num_round(f64::from(src), mode), expected);
+            }
+        }
+        assert_eq!(
+            I8F8::checked_from_num_round(f32::INFINITY, RoundingMode::Floor),
+            None
+        );
+        assert_eq!(
+            I8F8::saturating_from_num_round(-1e30f64, RoundingMode::Ceil),
+            I8F8::MIN
+        );
+    }
+
+    #[test]
+    fn fixed_to_float() {
+        let mut state = 1u64;
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let bits = state as i32;
+            let src = I32F0::from_bits(bits);
+            let exact = f64::from(bits);
+            let floor = src.to_num_round::<f32>(RoundingMode::Floor);
+            let ceil = src.to_num_round::<f32>(RoundingMode::Ceil);
+            assert!(f64::from(floor) <= exact && exact <= f64::from(ceil));
+            if f64::from(floor) == exact {
+                assert_eq!(floor, ceil);
+            } else {
+                // floor and ceil are consecutive
+                let up = if floor < 0.0 {
+                    f32::from_bits(floor.to_bits() - 1)
+                } else {
+                    f32::from_bits(floor.to_bits() + 1)
+                };
+                assert_eq!(up, ceil);
+            }
+            let zero = src.to_num_round::<f32>(RoundingMode::TowardZero);
+            assert_eq!(zero, if bits < 0 { ceil } else { floor });
+            let nearest = src.to_num_round::<f32>(RoundingMode::NearestEven);
+            assert_eq!(nearest, src.to_num::<f32>());
+        }
+
+        // f16::MAX is 65504, and the next representable magnitude is 65536
+        let big = I32F0::from_num(70_000);
+        assert_eq!(big.to_num_round::<f16>(RoundingMode::TowardZero), f16::MAX);
+        assert_eq!(big.to_num_round::<f16>(RoundingMode::Ceil), f16::INFINITY);
+        assert_eq!((-big).to_num_round::<f16>(RoundingMode::Ceil), f16::MIN);
+        assert_eq!(
+            (-big).to_num_round::<f16>(RoundingMode::Floor),
+            f16::NEG_INFINITY
+        );
+        // 65519 is less than halfway between 65504 and 65536
+        let below_half = I32F0::from_num(65_519);
+        assert_eq!(
+            below_half.to_num_round::<f16>(RoundingMode::NearestAway),
+            f16::MAX
+        );
+        let half = I32F0::from_num(65_520);
+        assert_eq!(
+            half.to_num_round::<f16>(RoundingMode::NearestAway),
+            f16::INFINITY
+        );
+    }
+}
@@ -15,10 +15,10 @@
 
 use crate::from_str::ParseFixedError;
 use crate::traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed};
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
 };
 use core::fmt::{
     Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp,
@@ -1003,6 +1003,194 @@ impl<F: Fixed> Saturating<F> {
         Saturating(self.0.saturating_sqrt())
     }
 
+    /// Returns the reciprocal square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_rsqrt][FixedI32::saturating_rsqrt]</code> and
+    /// <code>FixedU32::[saturating\_rsqrt][FixedU32::saturating_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F15, I16F16};
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I16F16::from_num(4)).rsqrt().0, 0.5);
+    ///
+    /// let small = Saturating(I1F15::from_num(0.25));
+    /// assert_eq!(small.rsqrt().0, I1F15::MAX);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        Saturating(self.0.saturating_rsqrt())
+    }
+
+    /// Base-2 exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp2][FixedI32::saturating_exp2]</code> and
+    /// <code>FixedU32::[saturating\_exp2][FixedU32::saturating_exp2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(3));
+    /// assert_eq!(x.exp2().0, I16F16::from_num(8));
+    /// let y = Saturating(I16F16::from_num(-2));
+    /// assert_eq!(y.exp2().0, I16F16::from_num(0.25));
+    ///
+    /// let big = Saturating(I16F16::from_num(20));
+    /// assert_eq!(big.exp2().0, I16F16::MAX);
+    /// ```
+    #[inline]
+    pub fn exp2(self) -> Self {
+        Saturating(self.0.saturating_exp2())
+    }
+
+    /// Exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp][FixedI32::saturating_exp]</code> and
+    /// <code>FixedU32::[saturating\_exp][FixedU32::saturating_exp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(0));
+    /// assert_eq!(x.exp().0, I16F16::ONE);
+    /// ```
+    #[inline]
+    pub fn exp(self) -> Self {
+        Saturating(self.0.saturating_exp())
+    }
+
+    /// Exponential minus one.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp\_m1][FixedI32::saturating_exp_m1]</code> and
+    /// <code>FixedU32::[saturating\_exp\_m1][FixedU32::saturating_exp_m1]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(0));
+    /// assert_eq!(x.exp_m1().0, I16F16::ZERO);
+    /// ```
+    #[inline]
+    pub fn exp_m1(self) -> Self {
+        Saturating(self.0.saturating_exp_m1())
+    }
+
+    /// Returns the value raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powi][FixedI32::saturating_powi]</code> and
+    /// <code>FixedU32::[saturating\_powi][FixedU32::saturating_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3).0, I16F16::from_num(3.375));
+    /// let two = Saturating(I16F16::from_num(2));
+    /// assert_eq!(two.powi(-2).0, I16F16::from_num(0.25));
+    ///
+    /// assert_eq!(Saturating(I16F16::MAX).powi(2).0, I16F16::MAX);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powi(self, n: i32) -> Saturating<F> {
+        Saturating(self.0.saturating_powi(n))
+    }
+
+    /// Returns the value raised to the fixed-point power `exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powf][FixedI32::saturating_powf]</code> and
+    /// <code>FixedU32::[saturating\_powf][FixedU32::saturating_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(4));
+    /// let half = Saturating(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(half).0, I16F16::from_num(2));
+    ///
+    /// assert_eq!(Saturating(I16F16::MAX).powf(Saturating(I16F16::from_num(2))).0, I16F16::MAX);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powf(self, exp: Saturating<F>) -> Saturating<F> {
+        Saturating(self.0.saturating_powf(exp.0))
+    }
+
+    /// Returns the cube root.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cbrt][FixedI32::saturating_cbrt]</code> and
+    /// <code>FixedU32::[saturating\_cbrt][FixedU32::saturating_cbrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(-3.375));
+    /// assert_eq!(x.cbrt().0, I16F16::from_num(-1.5));
+    /// ```
+    #[inline]
+    pub fn cbrt(self) -> Saturating<F> {
+        Saturating(self.0.saturating_cbrt())
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_nth\_root][FixedI32::saturating_nth_root]</code> and
+    /// <code>FixedU32::[saturating\_nth\_root][FixedU32::saturating_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// let x = Saturating(I16F16::from_num(5.0625));
+    /// assert_eq!(x.nth_root(4).0, I16F16::from_num(1.5));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn nth_root(self, n: u32) -> Saturating<F> {
+        Saturating(self.0.saturating_nth_root(n))
+    }
+
     /// Integer base-2 logarithm, rounded down.
     ///
     /// See also <code>FixedI32::[int\_log2][FixedI32::int_log2]</code> and
@@ -1734,6 +1922,174 @@ impl<F: FixedSigned> Saturating<F> {
     pub fn sub_unsigned(self, rhs: F::Unsigned) -> Saturating<F> {
         Saturating(self.0.saturating_sub_unsigned(rhs))
     }
+
+    /// Sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin][FixedI32::saturating_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I16F16::FRAC_PI_2).sin(), Saturating(I16F16::ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Saturating<F> {
+        Saturating(self.0.saturating_sin())
+    }
+
+    /// Cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cos][FixedI32::saturating_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I16F16::ZERO).cos(), Saturating(I16F16::ONE));
+    /// assert_eq!(Saturating(I1F31::ZERO).cos(), Saturating(I1F31::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Saturating<F> {
+        Saturating(self.0.saturating_cos())
+    }
+
+    /// Simultaneously computes the sine and cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin\_cos][FixedI32::saturating_sin_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Saturating;
+    /// assert_eq!(
+    ///     Saturating(I16F16::ZERO).sin_cos(),
+    ///     (Saturating(I16F16::ZERO), Saturating(I16F16::ONE))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin_cos(self) -> (Saturating<F>, Saturating<F>) {
+        let (sin, cos) = self.0.saturating_sin_cos();
+        (Saturating(sin), Saturating(cos))
+    }
+
+    /// Arcsine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_asin][FixedI32::saturating_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I16F16::ONE).asin(), Saturating(I16F16::FRAC_PI_2));
+    /// assert_eq!(Saturating(I1F31::NEG_ONE).asin(), Saturating(I1F31::MIN));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn asin(self) -> Saturating<F> {
+        Saturating(self.0.saturating_asin())
+    }
+
+    /// Arccosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_acos][FixedI32::saturating_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I16F16::NEG_ONE).acos(), Saturating(I16F16::PI));
+    /// assert_eq!(Saturating(I1F31::ZERO).acos(), Saturating(I1F31::MAX));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn acos(self) -> Saturating<F> {
+        Saturating(self.0.saturating_acos())
+    }
+
+    /// Hyperbolic sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sinh][FixedI32::saturating_sinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I4F12::ZERO).sinh(), Saturating(I4F12::ZERO));
+    /// assert_eq!(Saturating(I4F12::from_num(3)).sinh(), Saturating(I4F12::MAX));
+    /// assert_eq!(Saturating(I4F12::from_num(-3)).sinh(), Saturating(I4F12::MIN));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sinh(self) -> Saturating<F> {
+        Saturating(self.0.saturating_sinh())
+    }
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cosh][FixedI32::saturating_cosh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I4F12::ZERO).cosh(), Saturating(I4F12::ONE));
+    /// assert_eq!(Saturating(I4F12::from_num(3)).cosh(), Saturating(I4F12::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cosh(self) -> Saturating<F> {
+        Saturating(self.0.saturating_cosh())
+    }
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method cannot overflow.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Saturating;
+    /// assert_eq!(Saturating(I4F12::ZERO).tanh(), Saturating(I4F12::ZERO));
+    /// // tanh 1 ≈ 0.762
+    /// let x = Saturating(I4F12::ONE).tanh().0;
+    /// assert!(x.dist(I4F12::from_num(0.762)) < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tanh(self) -> Saturating<F> {
+        Saturating(self.0.tanh())
+    }
 }
 
 impl<F: FixedUnsigned> Saturating<F> {
@@ -2255,3 +2611,5 @@ ops! { FixedU16(u16, LeEqU16) }
 ops! { FixedU32(u32, LeEqU32) }
 ops! { FixedU64(u64, LeEqU64) }
 ops! { FixedU128(u128, LeEqU128) }
+ops! { FixedI256(I256Bits, LeEqU256) }
+ops! { FixedU256(U256Bits, LeEqU256) }
@@ -13,17 +13,18 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::int256::U256;
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128, Unwrapped, Wrapping,
+    Bounded, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16,
+    FixedU32, FixedU64, FixedU128, FixedU256, I256Bits, U256Bits, Unwrapped, Wrapping,
 };
-use serde::de::{Deserialize, Deserializer, Error as DeError};
+use core::fmt::{Formatter, Result as FmtResult};
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
 use serde::ser::{Serialize, Serializer};
 #[cfg(not(feature = "serde-str"))]
 use {
-    core::fmt::{Formatter, Result as FmtResult},
-    serde::de::{MapAccess, SeqAccess, Visitor},
+    serde::de::{MapAccess, SeqAccess},
     serde::ser::SerializeStruct,
 };
 
@@ -60,6 +61,14 @@ macro_rules! serde_fixed {
             }
         }
 
+        impl<Frac: $LeEqU, const MIN: i128, const MAX: i128> Serialize
+            for Bounded<$Fixed<Frac>, MIN, MAX>
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
         impl<'de, Frac: $LeEqU> Deserialize<'de> for $Fixed<Frac> {
             #[cfg(not(feature = "serde-str"))]
             fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -124,6 +133,21 @@ macro_rules! serde_fixed {
                 $Fixed::deserialize(deserializer).map(Unwrapped)
             }
         }
+
+        impl<'de, Frac: $LeEqU, const MIN: i128, const MAX: i128> Deserialize<'de>
+            for Bounded<$Fixed<Frac>, MIN, MAX>
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let val = $Fixed::deserialize(deserializer)?;
+                Bounded::new(val).ok_or_else(|| {
+                    DeError::custom(format_args!(
+                        "{val} is out of bounds {}..={}",
+                        Self::min_bound(),
+                        Self::max_bound()
+                    ))
+                })
+            }
+        }
     };
 }
 
@@ -137,6 +161,56 @@ serde_fixed! { FixedU16(LeEqU16) is u16 name "FixedU16" }
 serde_fixed! { FixedU32(LeEqU32) is u32 name "FixedU32" }
 serde_fixed! { FixedU64(LeEqU64) is u64 name "FixedU64" }
 serde_fixed! { FixedU128(LeEqU128) is u128 name "FixedU128" }
+serde_fixed! { FixedI256(LeEqU256) is I256Bits name "FixedI256" }
+serde_fixed! { FixedU256(LeEqU256) is U256Bits name "FixedU256" }
+
+// There is no primitive 256-bit integer, so the bits are serialized as a
+// decimal string for human-readable formats, and as a (lo, hi) tuple of the
+// two's-complement halves otherwise.
+macro_rules! serde_bits_256 {
+    ($Bits:ident name $Name:expr) => {
+        impl Serialize for $Bits {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.collect_str(self)
+                } else {
+                    (self.0.lo, self.0.hi).serialize(serializer)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Bits {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct BitsVisitor;
+
+                impl Visitor<'_> for BitsVisitor {
+                    type Value = $Bits;
+
+                    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                        formatter.write_str("a string containing a ")?;
+                        formatter.write_str($Name)
+                    }
+
+                    fn visit_str<E: DeError>(self, value: &str) -> Result<$Bits, E> {
+                        value
+                            .parse()
+                            .map_err(|e| DeError::custom(format_args!("parse error: {e}")))
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str(BitsVisitor)
+                } else {
+                    let (lo, hi) = <(u128, u128)>::deserialize(deserializer)?;
+                    Ok($Bits(U256 { lo, hi }))
+                }
+            }
+        }
+    };
+}
+
+serde_bits_256! { I256Bits name "I256Bits" }
+serde_bits_256! { U256Bits name "U256Bits" }
 
 #[cfg(not(feature = "serde-str"))]
 const FIELDS: &[&str] = &["bits"];
@@ -169,3 +243,37 @@ impl<'de> Deserialize<'de> for Field {
         deserializer.deserialize_identifier(FieldVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Bounded;
+    use crate::types::I8F8;
+    use serde::de::Deserialize;
+    use serde::de::value::Error as ValueError;
+
+    type B = Bounded<I8F8, -0x0180, 0x0200>;
+
+    #[cfg(not(feature = "serde-str"))]
+    fn deserialize_bits(bits: i16) -> Result<B, ValueError> {
+        use serde::de::value::MapDeserializer;
+        let map = MapDeserializer::<_, ValueError>::new(core::iter::once(("bits", bits)));
+        B::deserialize(map)
+    }
+
+    #[cfg(feature = "serde-str")]
+    fn deserialize_bits(bits: i16) -> Result<B, ValueError> {
+        use serde::de::IntoDeserializer;
+        use std::string::ToString;
+        let s = I8F8::from_bits(bits).to_string();
+        B::deserialize(IntoDeserializer::<ValueError>::into_deserializer(s))
+    }
+
+    #[test]
+    fn bounded_deserialize() {
+        assert_eq!(deserialize_bits(-0x0180).unwrap().get(), -1.5);
+        assert_eq!(deserialize_bits(0x0200).unwrap().get(), 2);
+        assert!(deserialize_bits(-0x0181).is_err());
+        assert!(deserialize_bits(0x0201).is_err());
+        assert!(deserialize_bits(i16::MAX).is_err());
+    }
+}
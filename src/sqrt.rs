@@ -13,6 +13,8 @@
 // <https://www.apache.org/licenses/LICENSE-2.0> and
 // <https://opensource.org/licenses/MIT>.
 
+use crate::int256;
+use crate::int256::U256;
 use core::num::NonZero;
 
 // The mathematics below is based on the comments from FreeBSD's
@@ -240,11 +242,113 @@ impl_sqrt! { u32 }
 impl_sqrt! { u64 }
 impl_sqrt! { u128 }
 
+// The reciprocal square root of x = val × 2^−f is rounded to the nearest and
+// has bits r = round(√(2^3f / val)). First 2^3f / val is split into its integer
+// part q, which can have up to 385 bits, and a remainder rem. Then we find m =
+// ⌊√q⌋ = ⌊√(2^3f / val)⌋ and the remainder y = q − m², using the sqrt functions
+// above when q fits in a u128, and the same bit-by-bit algorithm otherwise.
+//
+// The result is rounded up if √(2^3f / val) ≥ m + 1/2, that is, if
+// y + rem / val ≥ m + 1/4. Since y and m are integers, this is true if y > m,
+// or if y = m and 4 rem ≥ val.
+//
+// The returned value can have more than 128 bits, in which case the fixed-point
+// number overflows and its wrapped value is the lower bits.
+const fn rsqrt_bits(val: NonZero<u128>, frac_nbits: u32) -> U256 {
+    let k = 3 * frac_nbits;
+    // q[i] contains bits 128i to 128i + 127
+    let mut q = [0u128; 4];
+    let mut rem = 0u128;
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        let lo = if k / 128 == i as u32 {
+            1 << (k % 128)
+        } else {
+            0
+        };
+        let (quo, r) = int256::div_rem_u256_u128(U256 { lo, hi: rem }, val);
+        debug_assert!(quo.hi == 0);
+        q[i] = quo.lo;
+        rem = r;
+    }
+
+    let (m, y) = if q[1] == 0 && q[2] == 0 && q[3] == 0 {
+        match NonZero::<u128>::new(q[0]) {
+            None => (U256 { lo: 0, hi: 0 }, U256 { lo: 0, hi: 0 }),
+            Some(nz) => {
+                let m = u128(nz, 0);
+                let y = q[0] - m * m;
+                (U256 { lo: m, hi: 0 }, U256 { lo: y, hi: 0 })
+            }
+        }
+    } else {
+        // m ≤ √(2^384) = 2^192, and y ≤ 2m, so both fit in a U256
+        let mut m = U256 { lo: 0, hi: 0 };
+        let mut y = U256 { lo: 0, hi: 0 };
+        let mut pair = if q[3] != 0 {
+            3 * 64 + (127 - q[3].leading_zeros()) / 2
+        } else if q[2] != 0 {
+            2 * 64 + (127 - q[2].leading_zeros()) / 2
+        } else {
+            64 + (127 - q[1].leading_zeros()) / 2
+        } + 1;
+        while pair > 0 {
+            pair -= 1;
+            let bits = (q[(pair / 64) as usize] >> (2 * (pair % 64))) & 3;
+            y = int256::wrapping_shl_u256(y, 2);
+            y.lo |= bits;
+            let mut trial = int256::wrapping_shl_u256(m, 2);
+            trial.lo |= 1;
+            m = int256::wrapping_shl_u256(m, 1);
+            if ge_u256(y, trial) {
+                y = int256::wrapping_sub_u256(y, trial);
+                m.lo |= 1;
+            }
+        }
+        (m, y)
+    };
+
+    let round_up = ge_u256(y, m) && (!ge_u256(m, y) || rem >= val.get().div_ceil(4));
+    if round_up {
+        int256::wrapping_add_u256_u128(m, 1)
+    } else {
+        m
+    }
+}
+
+const fn ge_u256(a: U256, b: U256) -> bool {
+    a.hi > b.hi || (a.hi == b.hi && a.lo >= b.lo)
+}
+
+macro_rules! impl_rsqrt {
+    ($u:ident) => {
+        // Returns (ans, overflow).
+        pub const fn $u(val: NonZero<$u>, frac_nbits: u32) -> ($u, bool) {
+            let val = NonZero::<u128>::new(val.get() as u128).unwrap();
+            let ans = sqrt::rsqrt_bits(val, frac_nbits);
+            let wrapped = ans.lo as $u;
+            (wrapped, ans.hi != 0 || wrapped as u128 != ans.lo)
+        }
+    };
+}
+
+pub mod rsqrt {
+    use crate::sqrt;
+    use core::num::NonZero;
+
+    impl_rsqrt! { u8 }
+    impl_rsqrt! { u16 }
+    impl_rsqrt! { u32 }
+    impl_rsqrt! { u64 }
+    impl_rsqrt! { u128 }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::extra::{
         U0, U1, U3, U4, U5, U6, U7, U8, U9, U13, U14, U15, U16, U17, U29, U30, U31, U32, U33, U61,
-        U62, U63, U64, U65, U125, U126, U127, U128,
+        U62, U63, U64, U65, U96, U125, U126, U127, U128,
     };
     use crate::{
         FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
@@ -518,4 +622,113 @@ mod tests {
                 || FixedI128::<U126>::MAX.sqrt() == FixedI128::<U126>::SQRT_2
         );
     }
+    macro_rules! check_rsqrt {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            if val > 0 {
+                let (ans, overflow) = val.overflowing_rsqrt();
+                let x = val.to_bits() as u128;
+                // 4 × 2^3f / x lies in [(2r − 1)², (2r + 1)²)
+                let four_n = 1u128 << (3 * Fix::FRAC_NBITS + 2);
+                if overflow {
+                    let max = Fix::MAX.to_bits() as u128;
+                    assert!(four_n >= (2 * max + 1) * (2 * max + 1) * x, "{val}");
+                } else {
+                    let r = ans.to_bits() as u128;
+                    assert!(four_n < (2 * r + 1) * (2 * r + 1) * x, "{val}");
+                    if r > 0 {
+                        assert!(four_n >= (2 * r - 1) * (2 * r - 1) * x, "{val}");
+                    }
+                }
+            }
+        }};
+    }
+
+    macro_rules! check_rsqrt_f64 {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            if val > 0 {
+                let exact = 1.0 / val.to_num::<f64>().sqrt();
+                let delta = Fix::DELTA.to_num::<f64>();
+                match val.checked_rsqrt() {
+                    Some(ans) => {
+                        let err = (ans.to_num::<f64>() - exact).abs();
+                        assert!(err <= delta / 2.0 + exact * 1e-15, "{val}");
+                    }
+                    None => assert!(exact > Fix::MAX.to_num::<f64>(), "{val}"),
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn rsqrt_exhaustive_8_16() {
+        for bits in u8::MIN..=u8::MAX {
+            check_rsqrt!(FixedU8<U0>, bits);
+            check_rsqrt!(FixedU8<U4>, bits);
+            check_rsqrt!(FixedU8<U7>, bits);
+            check_rsqrt!(FixedU8<U8>, bits);
+            check_rsqrt!(FixedI8<U0>, bits as i8);
+            check_rsqrt!(FixedI8<U5>, bits as i8);
+            check_rsqrt!(FixedI8<U8>, bits as i8);
+        }
+        for bits in u16::MIN..=u16::MAX {
+            check_rsqrt!(FixedU16<U0>, bits);
+            check_rsqrt!(FixedU16<U8>, bits);
+            check_rsqrt!(FixedU16<U9>, bits);
+            check_rsqrt!(FixedU16<U15>, bits);
+            check_rsqrt!(FixedI16<U13>, bits as i16);
+        }
+    }
+
+    #[test]
+    fn rsqrt_sample_32_64_128() {
+        for i in 0u32..=20_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let bits = bits >> (i & 31);
+            check_rsqrt!(FixedU32<U0>, bits);
+            check_rsqrt!(FixedU32<U16>, bits);
+            check_rsqrt!(FixedU32<U31>, bits);
+            check_rsqrt!(FixedI32<U17>, bits as i32);
+            check_rsqrt_f64!(FixedU64<U32>, u64::from(bits) << (i % 32));
+            check_rsqrt_f64!(FixedI64<U61>, i64::from(bits) << (i % 32));
+            check_rsqrt_f64!(FixedU128<U64>, u128::from(bits) << (i % 96));
+            check_rsqrt_f64!(FixedU128<U127>, u128::from(bits) << (i % 96));
+            check_rsqrt_f64!(FixedI128<U125>, i128::from(bits) << (i % 96));
+        }
+    }
+
+    #[test]
+    fn rsqrt_exact() {
+        assert_eq!(FixedU8::<U0>::ONE.rsqrt(), 1);
+        assert_eq!(
+            FixedU8::<U8>::DELTA.overflowing_rsqrt(),
+            (FixedU8::ZERO, true)
+        );
+        assert_eq!(FixedU32::<U16>::from_num(16).rsqrt(), 0.25);
+        assert_eq!(FixedI64::<U32>::from_num(0.25).rsqrt(), 2);
+        assert_eq!(FixedU128::<U64>::from_num(4).rsqrt(), 0.5);
+        assert_eq!(
+            FixedU128::<U64>::MAX.rsqrt(),
+            FixedU128::<U64>::from_bits(1 << 32)
+        );
+        // the constant is rounded down, but 1/√2 rounds up to 126 fractional bits
+        assert_eq!(
+            FixedU128::<U126>::from_num(2).rsqrt(),
+            FixedU128::<U126>::FRAC_1_SQRT_2 + FixedU128::<U126>::DELTA
+        );
+        // 1/√DELTA = 2^64 overflows, and the wrapped value is zero
+        assert_eq!(
+            FixedU128::<U128>::DELTA.overflowing_rsqrt(),
+            (FixedU128::ZERO, true)
+        );
+        assert_eq!(
+            FixedI128::<U96>::DELTA.overflowing_rsqrt(),
+            (FixedI128::ZERO, true)
+        );
+        assert!(FixedI8::<U4>::ZERO.checked_rsqrt().is_none());
+        assert!(FixedI8::<U4>::NEG_ONE.checked_rsqrt().is_none());
+    }
 }
@@ -19,15 +19,23 @@ Traits for conversions and for generic use of fixed-point numbers.
 
 #![allow(deprecated)]
 
+use crate::fixed256::{self, Finite, Wide};
 use crate::helpers::{Private, Sealed, Widest};
+use crate::int_helper;
+use crate::int256::U256;
+use crate::rounding::RoundingMode;
 pub use crate::traits_bits::{
     FixedBits, FixedBitsCast, FixedBitsOptionalArbitrary, FixedBitsOptionalBorsh,
     FixedBitsOptionalNum, FixedBitsOptionalSerde,
 };
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, Unsigned};
+use crate::types::extra::{
+    LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256, U5, U13, U29, U61, U64, U125, U253,
+    Unsigned,
+};
 use crate::{
-    F128, F128Bits, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32,
-    FixedU64, FixedU128, ParseFixedError,
+    F128, F128Bits, FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16,
+    FixedU32, FixedU64, FixedU128, FixedU256, I256Bits, NonZeroI256Bits, NonZeroU256Bits,
+    ParseFixedError, ParseOptions, U256Bits,
 };
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
@@ -299,8 +307,9 @@ where
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
-/// [`FixedI64`], [`FixedI128`], [`FixedU8`], [`FixedU16`],
-/// [`FixedU32`], [`FixedU64`], and [`FixedU128`].
+/// [`FixedI64`], [`FixedI128`], [`FixedI256`], [`FixedU8`],
+/// [`FixedU16`], [`FixedU32`], [`FixedU64`], [`FixedU128`], and
+/// [`FixedU256`].
 ///
 /// # Examples
 ///
@@ -742,6 +751,23 @@ where
 
     /// The number of fractional bits.
     ///
+    /// This is the `FRAC` parameter of the const-generic aliases such as
+    /// <code>[FixedI32C][crate::types::FixedI32C]&lt;FRAC></code>. In
+    /// generic code, it can be compared with a const generic parameter at
+    /// compile time using
+    /// <code>[Const][crate::types::extra::Const]::[is\_frac\_nbits\_of][crate::types::extra::Const::is_frac_nbits_of]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::Fixed;
+    /// use fixed::types::FixedI32C;
+    /// fn frac_nbits<F: Fixed>() -> u32 {
+    ///     F::FRAC_NBITS
+    /// }
+    /// assert_eq!(frac_nbits::<FixedI32C<12>>(), 12);
+    /// ```
+    ///
     /// See also <code>FixedI32::[FRAC\_NBITS][FixedI32::FRAC_NBITS]</code> and
     /// <code>FixedU32::[FRAC\_NBITS][FixedU32::FRAC_NBITS]</code>.
     const FRAC_NBITS: u32;
@@ -981,6 +1007,205 @@ where
     /// <code>FixedU32::[overflowing\_to\_num][FixedU32::overflowing_to_num]</code>.
     fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool);
 
+    /// Creates a fixed-point number from another number, rounding using
+    /// the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_round][FixedI32::from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_round][FixedU32::from_num_round]</code>.
+    fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, rounding using the
+    /// given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[to\_num\_round][FixedI32::to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[to\_num\_round][FixedU32::to_num_round]</code>.
+    fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number, rounding using
+    /// the given rounding mode, if it fits, otherwise returns [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_round][FixedI32::checked_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_round][FixedU32::checked_from_num_round]</code>.
+    fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self>;
+
+    /// Converts a fixed-point number to another number, rounding using the
+    /// given rounding mode, if it fits, otherwise returns [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_to\_num\_round][FixedI32::checked_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_to\_num\_round][FixedU32::checked_to_num_round]</code>.
+    fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst>;
+
+    /// Creates a fixed-point number from another number, rounding using
+    /// the given rounding mode, and saturating if it does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_num\_round][FixedI32::saturating_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_num\_round][FixedU32::saturating_from_num_round]</code>.
+    fn saturating_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, rounding using the
+    /// given rounding mode, and saturating if it does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_to\_num\_round][FixedI32::saturating_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_to\_num\_round][FixedU32::saturating_to_num_round]</code>.
+    fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number, rounding using
+    /// the given rounding mode, and wrapping the value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_num\_round][FixedI32::wrapping_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_num\_round][FixedU32::wrapping_from_num_round]</code>.
+    fn wrapping_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self;
+
+    /// Converts a fixed-point number to another number, rounding using the
+    /// given rounding mode, and wrapping the value on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_to\_num\_round][FixedI32::wrapping_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_to\_num\_round][FixedU32::wrapping_to_num_round]</code>.
+    fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst;
+
+    /// Creates a fixed-point number from another number, rounding using
+    /// the given rounding mode.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num\_round][FixedI32::overflowing_from_num_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num\_round][FixedU32::overflowing_from_num_round]</code>.
+    fn overflowing_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> (Self, bool);
+
+    /// Converts a fixed-point number to another number, rounding using the
+    /// given rounding mode.
+    ///
+    /// Returns a [tuple] of the number and a [`bool`] indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_to\_num\_round][FixedI32::overflowing_to_num_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_to\_num\_round][FixedU32::overflowing_to_num_round]</code>.
+    fn overflowing_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> (Dst, bool);
+
+    /// Creates a fixed-point number from another number using stochastic
+    /// rounding, with random bits from `rng`.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_stochastic][FixedI32::from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_stochastic][FixedU32::from_num_stochastic]</code>.
+    fn from_num_stochastic<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self;
+
+    /// Creates a fixed-point number from another number using stochastic
+    /// rounding, with random bits from `rng`, if it fits, otherwise returns
+    /// [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_stochastic][FixedI32::checked_from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_stochastic][FixedU32::checked_from_num_stochastic]</code>.
+    fn checked_from_num_stochastic<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> Option<Self>;
+
+    /// Creates a fixed-point number from another number using stochastic
+    /// rounding, with random bits from `rng`, and saturating if it does not
+    /// fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_num\_stochastic][FixedI32::saturating_from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_num\_stochastic][FixedU32::saturating_from_num_stochastic]</code>.
+    fn saturating_from_num_stochastic<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> Self;
+
+    /// Creates a fixed-point number from another number using stochastic
+    /// rounding, with random bits from `rng`.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num\_stochastic][FixedI32::overflowing_from_num_stochastic]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num\_stochastic][FixedU32::overflowing_from_num_stochastic]</code>.
+    fn overflowing_from_num_stochastic<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> (Self, bool);
+
+    /// Creates a fixed-point number from another number, adding TPDF dither
+    /// with random bits from `rng` before rounding.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_num\_dither][FixedI32::from_num_dither]</code>
+    /// and
+    /// <code>FixedU32::[from\_num\_dither][FixedU32::from_num_dither]</code>.
+    fn from_num_dither<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self;
+
+    /// Creates a fixed-point number from another number, adding TPDF dither
+    /// with random bits from `rng` before rounding, if it fits, otherwise
+    /// returns [`None`].
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_from\_num\_dither][FixedI32::checked_from_num_dither]</code>
+    /// and
+    /// <code>FixedU32::[checked\_from\_num\_dither][FixedU32::checked_from_num_dither]</code>.
+    fn checked_from_num_dither<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> Option<Self>;
+
+    /// Creates a fixed-point number from another number, adding TPDF dither
+    /// with random bits from `rng` before rounding, and saturating if it
+    /// does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_num\_dither][FixedI32::saturating_from_num_dither]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_num\_dither][FixedU32::saturating_from_num_dither]</code>.
+    fn saturating_from_num_dither<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> Self;
+
+    /// Creates a fixed-point number from another number, adding TPDF dither
+    /// with random bits from `rng` before rounding.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_num\_dither][FixedI32::overflowing_from_num_dither]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_num\_dither][FixedU32::overflowing_from_num_dither]</code>.
+    fn overflowing_from_num_dither<Src: ToFixed>(
+        src: Src,
+        rng: &mut (impl RngLike + ?Sized),
+    ) -> (Self, bool);
+
     /// Parses a string slice containing binary digits to return a fixed-point number.
     ///
     /// Rounding is to the nearest, with ties rounded to even.
@@ -1469,6 +1694,183 @@ where
     /// <code>FixedU32::[overflowing\_from\_ascii\_hex][FixedU32::overflowing_from_ascii_hex]</code>.
     fn overflowing_from_ascii_hex(src: &[u8]) -> Result<(Self, bool), ParseFixedError>;
 
+    /// Parses a string slice to return a fixed-point number, using the given
+    /// [`ParseOptions`].
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_with][FixedI32::from_str_with]</code> and
+    /// <code>FixedU32::[from\_str\_with][FixedU32::from_str_with]</code>.
+    fn from_str_with(src: &str, options: &ParseOptions) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a hexadecimal floating-point number to
+    /// return a fixed-point number.
+    ///
+    /// See also
+    /// <code>FixedI32::[from\_str\_hexfloat][FixedI32::from_str_hexfloat]</code> and
+    /// <code>FixedU32::[from\_str\_hexfloat][FixedU32::from_str_hexfloat]</code>.
+    fn from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a hexadecimal floating-point number to
+    /// return a fixed-point number, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_hexfloat][FixedI32::saturating_from_str_hexfloat]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_hexfloat][FixedU32::saturating_from_str_hexfloat]</code>.
+    fn saturating_from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a hexadecimal floating-point number to
+    /// return a fixed-point number, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_hexfloat][FixedI32::wrapping_from_str_hexfloat]</code>
+    /// and
+    /// <code>FixedU32::[wrapping\_from\_str\_hexfloat][FixedU32::wrapping_from_str_hexfloat]</code>.
+    fn wrapping_from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a hexadecimal floating-point number to
+    /// return a fixed-point number, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_from\_str\_hexfloat][FixedI32::unwrapped_from_str_hexfloat]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_from\_str\_hexfloat][FixedU32::unwrapped_from_str_hexfloat]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit or if there is a parsing error.
+    #[track_caller]
+    fn unwrapped_from_str_hexfloat(src: &str) -> Self;
+
+    /// Parses a string slice containing a hexadecimal floating-point number to
+    /// return a fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_hexfloat][FixedI32::overflowing_from_str_hexfloat]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_hexfloat][FixedU32::overflowing_from_str_hexfloat]</code>.
+    fn overflowing_from_str_hexfloat(src: &str) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Creates a fixed-point number from the ratio of two integers.
+    ///
+    /// See also <code>FixedI32::[from\_ratio][FixedI32::from_ratio]</code> and
+    /// <code>FixedU32::[from\_ratio][FixedU32::from_ratio]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    ///
+    /// When debug assertions are enabled, also panics if the value does not fit.
+    /// When debug assertions are not enabled, the wrapped value can be returned,
+    /// but it is not considered a breaking change if in the future it panics;
+    /// if wrapping is required use [`wrapping_from_ratio`] instead.
+    ///
+    /// [`wrapping_from_ratio`]: Fixed::wrapping_from_ratio
+    #[track_caller]
+    fn from_ratio(num: Self::Bits, den: Self::Bits) -> Self;
+
+    /// Creates a fixed-point number from the ratio of two integers if it fits
+    /// and `den` is not zero, otherwise returns [`None`].
+    ///
+    /// See also <code>FixedI32::[checked\_from\_ratio][FixedI32::checked_from_ratio]</code> and
+    /// <code>FixedU32::[checked\_from\_ratio][FixedU32::checked_from_ratio]</code>.
+    fn checked_from_ratio(num: Self::Bits, den: Self::Bits) -> Option<Self>;
+
+    /// Creates a fixed-point number from the ratio of two integers, saturating
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_ratio][FixedI32::saturating_from_ratio]</code> and
+    /// <code>FixedU32::[saturating\_from\_ratio][FixedU32::saturating_from_ratio]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    fn saturating_from_ratio(num: Self::Bits, den: Self::Bits) -> Self;
+
+    /// Creates a fixed-point number from the ratio of two integers, wrapping
+    /// on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_from\_ratio][FixedI32::wrapping_from_ratio]</code> and
+    /// <code>FixedU32::[wrapping\_from\_ratio][FixedU32::wrapping_from_ratio]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    fn wrapping_from_ratio(num: Self::Bits, den: Self::Bits) -> Self;
+
+    /// Creates a fixed-point number from the ratio of two integers.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_ratio][FixedI32::overflowing_from_ratio]</code> and
+    /// <code>FixedU32::[overflowing\_from\_ratio][FixedU32::overflowing_from_ratio]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    #[track_caller]
+    fn overflowing_from_ratio(num: Self::Bits, den: Self::Bits) -> (Self, bool);
+
+    /// Parses a string slice containing a ratio of two decimal integers, such
+    /// as “`-3/7`”, to return a fixed-point number.
+    ///
+    /// See also <code>FixedI32::[from\_str\_ratio][FixedI32::from_str_ratio]</code> and
+    /// <code>FixedU32::[from\_str\_ratio][FixedU32::from_str_ratio]</code>.
+    fn from_str_ratio(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a ratio of two decimal integers to
+    /// return a fixed-point number, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_from\_str\_ratio][FixedI32::saturating_from_str_ratio]</code>
+    /// and
+    /// <code>FixedU32::[saturating\_from\_str\_ratio][FixedU32::saturating_from_str_ratio]</code>.
+    fn saturating_from_str_ratio(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a ratio of two decimal integers to
+    /// return a fixed-point number, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_from\_str\_ratio][FixedI32::wrapping_from_str_ratio]</code> and
+    /// <code>FixedU32::[wrapping\_from\_str\_ratio][FixedU32::wrapping_from_str_ratio]</code>.
+    fn wrapping_from_str_ratio(src: &str) -> Result<Self, ParseFixedError>;
+
+    /// Parses a string slice containing a ratio of two decimal integers to
+    /// return a fixed-point number.
+    ///
+    /// Returns a [tuple] of the fixed-point number and a [`bool`] indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_from\_str\_ratio][FixedI32::overflowing_from_str_ratio]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_from\_str\_ratio][FixedU32::overflowing_from_str_ratio]</code>.
+    fn overflowing_from_str_ratio(src: &str) -> Result<(Self, bool), ParseFixedError>;
+
+    /// Returns the ratio of two integers that is closest to the value, with a
+    /// denominator no larger than `max_den`.
+    ///
+    /// See also <code>FixedI32::[to\_ratio][FixedI32::to_ratio]</code> and
+    /// <code>FixedU32::[to\_ratio][FixedU32::to_ratio]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_den` is not positive.
+    #[track_caller]
+    #[must_use]
+    fn to_ratio(self, max_den: Self::Bits) -> (Self::Bits, Self::Bits);
+
     /// Returns the integer part.
     ///
     /// See also <code>FixedI32::[int][FixedI32::int]</code> and
@@ -1809,6 +2211,45 @@ where
     #[doc(alias("ilog"))]
     fn int_log(self, base: u32) -> i32;
 
+    /// Base-2 logarithm.
+    ///
+    /// See also <code>FixedI32::[log2][FixedI32::log2]</code> and
+    /// <code>FixedU32::[log2][FixedU32::log2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0, or if the result does
+    /// not fit.
+    #[track_caller]
+    #[must_use]
+    fn log2(self) -> Self;
+
+    /// Natural logarithm.
+    ///
+    /// See also <code>FixedI32::[ln][FixedI32::ln]</code> and
+    /// <code>FixedU32::[ln][FixedU32::ln]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0, or if the result does
+    /// not fit.
+    #[track_caller]
+    #[must_use]
+    fn ln(self) -> Self;
+
+    /// Base-10 logarithm.
+    ///
+    /// See also <code>FixedI32::[log10][FixedI32::log10]</code> and
+    /// <code>FixedU32::[log10][FixedU32::log10]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fixed-point number is ≤&nbsp;0, or if the result does
+    /// not fit.
+    #[track_caller]
+    #[must_use]
+    fn log10(self) -> Self;
+
     /// Checked integer base-2 logarithm, rounded down. Returns the
     /// logarithm or [`None`] if the fixed-point number is ≤&nbsp;0.
     ///
@@ -1840,6 +2281,33 @@ where
     #[doc(alias("checked_ilog"))]
     fn checked_int_log(self, base: u32) -> Option<i32>;
 
+    /// Checked base-2 logarithm. Returns the logarithm, or [`None`] if the
+    /// fixed-point number is ≤&nbsp;0 or if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log2][FixedI32::checked_log2]</code>
+    /// and
+    /// <code>FixedU32::[checked\_log2][FixedU32::checked_log2]</code>.
+    fn checked_log2(self) -> Option<Self>;
+
+    /// Checked natural logarithm. Returns the logarithm, or [`None`] if the
+    /// fixed-point number is ≤&nbsp;0 or if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_ln][FixedI32::checked_ln]</code>
+    /// and
+    /// <code>FixedU32::[checked\_ln][FixedU32::checked_ln]</code>.
+    fn checked_ln(self) -> Option<Self>;
+
+    /// Checked base-10 logarithm. Returns the logarithm, or [`None`] if the
+    /// fixed-point number is ≤&nbsp;0 or if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_log10][FixedI32::checked_log10]</code>
+    /// and
+    /// <code>FixedU32::[checked\_log10][FixedU32::checked_log10]</code>.
+    fn checked_log10(self) -> Option<Self>;
+
     /// Reverses the order of the bits of the fixed-point number.
     ///
     /// See also <code>FixedI32::[reverse\_bits][FixedI32::reverse_bits]</code>
@@ -1907,6 +2375,38 @@ where
     #[must_use]
     fn recip(self) -> Self;
 
+    /// Multiplication, rounding the product using the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[mul\_round][FixedI32::mul_round]</code> and
+    /// <code>FixedU32::[mul\_round][FixedU32::mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Division, rounding the quotient using the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[div\_round][FixedI32::div_round]</code> and
+    /// <code>FixedU32::[div\_round][FixedU32::div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn div_round(self, rhs: Self, mode: RoundingMode) -> Self;
+
+    /// Returns the reciprocal, rounding using the given rounding mode.
+    ///
+    /// See also
+    /// <code>FixedI32::[recip\_round][FixedI32::recip_round]</code> and
+    /// <code>FixedU32::[recip\_round][FixedU32::recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[must_use]
+    fn recip_round(self, mode: RoundingMode) -> Self;
+
     /// Returns the next multiple of `other`.
     ///
     /// See also
@@ -2033,21 +2533,175 @@ where
     /// Panics if the number is negative.
     fn sqrt(self) -> Self;
 
-    /// Linear interpolation between `start` and `end`.
+    /// Returns the reciprocal square root.
     ///
-    /// See also <code>FixedI32::[lerp][FixedI32::lerp]</code> and
-    /// <code>FixedU32::[lerp][FixedU32::lerp]</code>.
+    /// See also
+    /// <code>FixedI32::[rsqrt][FixedI32::rsqrt]</code> and
+    /// <code>FixedU32::[rsqrt][FixedU32::rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics; if wrapping is required use [`wrapping_rsqrt`]
+    /// instead.
+    ///
+    /// [`wrapping_rsqrt`]: Fixed::wrapping_rsqrt
+    #[track_caller]
     #[must_use]
-    fn lerp(self, start: Self, end: Self) -> Self;
+    fn rsqrt(self) -> Self;
 
-    /// Inverse linear interpolation between `start` and `end`.
+    /// Base-2 exponential.
     ///
-    /// See also <code>FixedI32::[inv\_lerp][FixedI32::inv_lerp]</code> and
-    /// <code>FixedU32::[inv\_lerp][FixedU32::inv_lerp]</code>.
+    /// See also
+    /// <code>FixedI32::[exp2][FixedI32::exp2]</code> and
+    /// <code>FixedU32::[exp2][FixedU32::exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_exp2`] instead.
+    ///
+    /// [`wrapping_exp2`]: Fixed::wrapping_exp2
+    #[track_caller]
     #[must_use]
-    fn inv_lerp(self, start: Self, end: Self) -> Self;
+    fn exp2(self) -> Self;
 
-    /// Checked negation. Returns the negated value, or [`None`] on overflow.
+    /// Exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[exp][FixedI32::exp]</code> and
+    /// <code>FixedU32::[exp][FixedU32::exp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_exp`] instead.
+    ///
+    /// [`wrapping_exp`]: Fixed::wrapping_exp
+    #[track_caller]
+    #[must_use]
+    fn exp(self) -> Self;
+
+    /// Exponential minus one.
+    ///
+    /// See also
+    /// <code>FixedI32::[exp\_m1][FixedI32::exp_m1]</code> and
+    /// <code>FixedU32::[exp\_m1][FixedU32::exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_exp_m1`] instead.
+    ///
+    /// [`wrapping_exp_m1`]: Fixed::wrapping_exp_m1
+    #[track_caller]
+    #[must_use]
+    fn exp_m1(self) -> Self;
+
+    /// Returns the value raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[powi][FixedI32::powi]</code> and
+    /// <code>FixedU32::[powi][FixedU32::powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_powi`] instead.
+    ///
+    /// [`wrapping_powi`]: Fixed::wrapping_powi
+    #[track_caller]
+    #[must_use]
+    fn powi(self, n: i32) -> Self;
+
+    /// Returns the value raised to the fixed-point power `exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[powf][FixedI32::powf]</code> and
+    /// <code>FixedU32::[powf][FixedU32::powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_powf`] instead.
+    ///
+    /// [`wrapping_powf`]: Fixed::wrapping_powf
+    #[track_caller]
+    #[must_use]
+    fn powf(self, exp: Self) -> Self;
+
+    /// Returns the cube root.
+    ///
+    /// See also
+    /// <code>FixedI32::[cbrt][FixedI32::cbrt]</code> and
+    /// <code>FixedU32::[cbrt][FixedU32::cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_cbrt`] instead.
+    ///
+    /// [`wrapping_cbrt`]: Fixed::wrapping_cbrt
+    #[track_caller]
+    #[must_use]
+    fn cbrt(self) -> Self;
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[nth\_root][FixedI32::nth_root]</code> and
+    /// <code>FixedU32::[nth\_root][FixedU32::nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    ///
+    /// When debug assertions are enabled, this method also panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_nth_root`] instead.
+    ///
+    /// [`wrapping_nth_root`]: Fixed::wrapping_nth_root
+    #[track_caller]
+    #[must_use]
+    fn nth_root(self, n: u32) -> Self;
+
+    /// Linear interpolation between `start` and `end`.
+    ///
+    /// See also <code>FixedI32::[lerp][FixedI32::lerp]</code> and
+    /// <code>FixedU32::[lerp][FixedU32::lerp]</code>.
+    #[must_use]
+    fn lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`.
+    ///
+    /// See also <code>FixedI32::[inv\_lerp][FixedI32::inv_lerp]</code> and
+    /// <code>FixedU32::[inv\_lerp][FixedU32::inv_lerp]</code>.
+    #[must_use]
+    fn inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Checked negation. Returns the negated value, or [`None`] on overflow.
     ///
     /// See also <code>FixedI32::[checked\_neg][FixedI32::checked_neg]</code>
     /// and <code>FixedU32::[checked\_neg][FixedU32::checked_neg]</code>.
@@ -2098,6 +2752,34 @@ where
     /// <code>FixedU32::[checked\_recip][FixedU32::checked_recip]</code>.
     fn checked_recip(self) -> Option<Self>;
 
+    /// Checked multiplication, rounding the product using the given
+    /// rounding mode. Returns the product, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_mul\_round][FixedI32::checked_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_mul\_round][FixedU32::checked_mul_round]</code>.
+    fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked division, rounding the quotient using the given rounding
+    /// mode. Returns the quotient, or [`None`] if the divisor is zero or
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_div\_round][FixedI32::checked_div_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_div\_round][FixedU32::checked_div_round]</code>.
+    fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Checked reciprocal, rounding using the given rounding mode. Returns
+    /// the reciprocal, or [`None`] if `self` is zero or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_recip\_round][FixedI32::checked_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[checked\_recip\_round][FixedU32::checked_recip_round]</code>.
+    fn checked_recip_round(self, mode: RoundingMode) -> Option<Self>;
+
     /// Checked next multiple of `other`. Returns the next multiple, or [`None`]
     /// if `other` is zero or on overflow.
     ///
@@ -2250,6 +2932,70 @@ where
     /// and <code>FixedU32::[checked\_sqrt][FixedU32::checked_sqrt]</code>.
     fn checked_sqrt(self) -> Option<Self>;
 
+    /// Checked reciprocal square root. Returns [`None`] for zero or negative
+    /// numbers or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_rsqrt][FixedI32::checked_rsqrt]</code> and
+    /// <code>FixedU32::[checked\_rsqrt][FixedU32::checked_rsqrt]</code>.
+    fn checked_rsqrt(self) -> Option<Self>;
+
+    /// Checked base-2 exponential. Returns the base-2 exponential, or [`None`]
+    /// on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp2][FixedI32::checked_exp2]</code> and
+    /// <code>FixedU32::[checked\_exp2][FixedU32::checked_exp2]</code>.
+    fn checked_exp2(self) -> Option<Self>;
+
+    /// Checked exponential. Returns the exponential, or [`None`] on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp][FixedI32::checked_exp]</code> and
+    /// <code>FixedU32::[checked\_exp][FixedU32::checked_exp]</code>.
+    fn checked_exp(self) -> Option<Self>;
+
+    /// Checked exponential minus one. Returns the exponential minus one, or
+    /// [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_exp\_m1][FixedI32::checked_exp_m1]</code> and
+    /// <code>FixedU32::[checked\_exp\_m1][FixedU32::checked_exp_m1]</code>.
+    fn checked_exp_m1(self) -> Option<Self>;
+
+    /// Checked integer power. Returns the value raised to the integer power
+    /// `n`, or [`None`] if `self` is zero and `n` is negative or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powi][FixedI32::checked_powi]</code> and
+    /// <code>FixedU32::[checked\_powi][FixedU32::checked_powi]</code>.
+    fn checked_powi(self, n: i32) -> Option<Self>;
+
+    /// Checked power. Returns the value raised to the fixed-point power `exp`,
+    /// or [`None`] if `self` is negative, if `self` is zero and `exp` is
+    /// negative, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_powf][FixedI32::checked_powf]</code> and
+    /// <code>FixedU32::[checked\_powf][FixedU32::checked_powf]</code>.
+    fn checked_powf(self, exp: Self) -> Option<Self>;
+
+    /// Checked cube root. Returns the cube root, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_cbrt][FixedI32::checked_cbrt]</code> and
+    /// <code>FixedU32::[checked\_cbrt][FixedU32::checked_cbrt]</code>.
+    fn checked_cbrt(self) -> Option<Self>;
+
+    /// Checked <i>n</i>th root. Returns the <i>n</i>th root, or [`None`] if `n`
+    /// is zero, if `n` is even and the number is negative, or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_nth\_root][FixedI32::checked_nth_root]</code> and
+    /// <code>FixedU32::[checked\_nth\_root][FixedU32::checked_nth_root]</code>.
+    fn checked_nth_root(self, n: u32) -> Option<Self>;
+
     /// Checked linear interpolation between `start` and `end`. Returns [`None`]
     /// on overflow.
     ///
@@ -2456,6 +3202,98 @@ where
     /// Panics if the number is negative.
     fn saturating_sqrt(self) -> Self;
 
+    /// Returns the reciprocal square root, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_rsqrt][FixedI32::saturating_rsqrt]</code> and
+    /// <code>FixedU32::[saturating\_rsqrt][FixedU32::saturating_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_rsqrt(self) -> Self;
+
+    /// Saturating base-2 exponential. Returns the base-2 exponential,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp2][FixedI32::saturating_exp2]</code> and
+    /// <code>FixedU32::[saturating\_exp2][FixedU32::saturating_exp2]</code>.
+    #[must_use]
+    fn saturating_exp2(self) -> Self;
+
+    /// Saturating exponential. Returns the exponential, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp][FixedI32::saturating_exp]</code> and
+    /// <code>FixedU32::[saturating\_exp][FixedU32::saturating_exp]</code>.
+    #[must_use]
+    fn saturating_exp(self) -> Self;
+
+    /// Saturating exponential minus one. Returns the exponential minus one,
+    /// saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_exp\_m1][FixedI32::saturating_exp_m1]</code> and
+    /// <code>FixedU32::[saturating\_exp\_m1][FixedU32::saturating_exp_m1]</code>.
+    #[must_use]
+    fn saturating_exp_m1(self) -> Self;
+
+    /// Saturating integer power. Returns the value raised to the integer power
+    /// `n`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powi][FixedI32::saturating_powi]</code> and
+    /// <code>FixedU32::[saturating\_powi][FixedU32::saturating_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_powi(self, n: i32) -> Self;
+
+    /// Saturating power. Returns the value raised to the fixed-point power
+    /// `exp`, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_powf][FixedI32::saturating_powf]</code> and
+    /// <code>FixedU32::[saturating\_powf][FixedU32::saturating_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_powf(self, exp: Self) -> Self;
+
+    /// Saturating cube root. Returns the cube root, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cbrt][FixedI32::saturating_cbrt]</code> and
+    /// <code>FixedU32::[saturating\_cbrt][FixedU32::saturating_cbrt]</code>.
+    #[track_caller]
+    #[must_use]
+    fn saturating_cbrt(self) -> Self;
+
+    /// Saturating <i>n</i>th root. Returns the <i>n</i>th root, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_nth\_root][FixedI32::saturating_nth_root]</code> and
+    /// <code>FixedU32::[saturating\_nth\_root][FixedU32::saturating_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    #[track_caller]
+    #[must_use]
+    fn saturating_nth_root(self, n: u32) -> Self;
+
     /// Linear interpolation between `start` and `end`, saturating on overflow.
     ///
     /// See also
@@ -2674,6 +3512,98 @@ where
     /// Panics if the number is negative.
     fn wrapping_sqrt(self) -> Self;
 
+    /// Returns the reciprocal square root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_rsqrt][FixedI32::wrapping_rsqrt]</code> and
+    /// <code>FixedU32::[wrapping\_rsqrt][FixedU32::wrapping_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_rsqrt(self) -> Self;
+
+    /// Wrapping base-2 exponential. Returns the base-2 exponential, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp2][FixedI32::wrapping_exp2]</code> and
+    /// <code>FixedU32::[wrapping\_exp2][FixedU32::wrapping_exp2]</code>.
+    #[must_use]
+    fn wrapping_exp2(self) -> Self;
+
+    /// Wrapping exponential. Returns the exponential, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp][FixedI32::wrapping_exp]</code> and
+    /// <code>FixedU32::[wrapping\_exp][FixedU32::wrapping_exp]</code>.
+    #[must_use]
+    fn wrapping_exp(self) -> Self;
+
+    /// Wrapping exponential minus one. Returns the exponential minus one,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp\_m1][FixedI32::wrapping_exp_m1]</code> and
+    /// <code>FixedU32::[wrapping\_exp\_m1][FixedU32::wrapping_exp_m1]</code>.
+    #[must_use]
+    fn wrapping_exp_m1(self) -> Self;
+
+    /// Wrapping integer power. Returns the value raised to the integer power
+    /// `n`, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_powi(self, n: i32) -> Self;
+
+    /// Wrapping power. Returns the value raised to the fixed-point power `exp`,
+    /// wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powf][FixedI32::wrapping_powf]</code> and
+    /// <code>FixedU32::[wrapping\_powf][FixedU32::wrapping_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_powf(self, exp: Self) -> Self;
+
+    /// Wrapping cube root. Returns the cube root, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cbrt][FixedI32::wrapping_cbrt]</code> and
+    /// <code>FixedU32::[wrapping\_cbrt][FixedU32::wrapping_cbrt]</code>.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_cbrt(self) -> Self;
+
+    /// Wrapping <i>n</i>th root. Returns the <i>n</i>th root, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_nth\_root][FixedI32::wrapping_nth_root]</code> and
+    /// <code>FixedU32::[wrapping\_nth\_root][FixedU32::wrapping_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_nth_root(self, n: u32) -> Self;
+
     /// Linear interpolation between `start` and `end`, wrapping on overflow.
     ///
     /// See also
@@ -3009,38 +3939,152 @@ where
     /// Panics if the number is negative or on overflow.
     fn unwrapped_sqrt(self) -> Self;
 
-    /// Linear interpolation between `start` and `end`, panicking on overflow.
+    /// Returns the reciprocal square root, panicking if the number is zero or
+    /// negative or on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rsqrt][FixedI32::unwrapped_rsqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_rsqrt][FixedU32::unwrapped_rsqrt]</code>.
     ///
     /// # Panics
     ///
-    /// Panics if the result does not fit.
+    /// Panics if the number is zero or negative or on overflow.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_rsqrt(self) -> Self;
+
+    /// Unwrapped base-2 exponential. Returns the base-2 exponential, panicking
+    /// on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
-    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
+    /// <code>FixedI32::[unwrapped\_exp2][FixedI32::unwrapped_exp2]</code> and
+    /// <code>FixedU32::[unwrapped\_exp2][FixedU32::unwrapped_exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
     #[track_caller]
     #[must_use]
-    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
+    fn unwrapped_exp2(self) -> Self;
 
-    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
+    /// Unwrapped exponential. Returns the exponential, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp][FixedI32::unwrapped_exp]</code> and
+    /// <code>FixedU32::[unwrapped\_exp][FixedU32::unwrapped_exp]</code>.
     ///
     /// # Panics
     ///
-    /// Panics when `start`&nbsp;=&nbsp;`end` or when the results overflows.
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_exp(self) -> Self;
+
+    /// Unwrapped exponential minus one. Returns the exponential minus one,
+    /// panicking on overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
-    /// and
-    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
+    /// <code>FixedI32::[unwrapped\_exp\_m1][FixedI32::unwrapped_exp_m1]</code> and
+    /// <code>FixedU32::[unwrapped\_exp\_m1][FixedU32::unwrapped_exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
     #[track_caller]
     #[must_use]
-    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
+    fn unwrapped_exp_m1(self) -> Self;
 
-    /// Overflowing negation.
+    /// Unwrapped integer power. Returns the value raised to the integer power
+    /// `n`, panicking on overflow.
     ///
-    /// Returns a [tuple] of the negated value and a [`bool`],
-    /// indicating whether an overflow has occurred. On overflow, the
-    /// wrapped value is returned.
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result does not
+    /// fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_powi(self, n: i32) -> Self;
+
+    /// Unwrapped power. Returns the value raised to the fixed-point power
+    /// `exp`, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powf][FixedI32::unwrapped_powf]</code> and
+    /// <code>FixedU32::[unwrapped\_powf][FixedU32::unwrapped_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative, or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_powf(self, exp: Self) -> Self;
+
+    /// Unwrapped cube root. Returns the cube root, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cbrt][FixedI32::unwrapped_cbrt]</code> and
+    /// <code>FixedU32::[unwrapped\_cbrt][FixedU32::unwrapped_cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_cbrt(self) -> Self;
+
+    /// Unwrapped <i>n</i>th root. Returns the <i>n</i>th root, panicking on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_nth\_root][FixedI32::unwrapped_nth_root]</code> and
+    /// <code>FixedU32::[unwrapped\_nth\_root][FixedU32::unwrapped_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative, or
+    /// if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_nth_root(self, n: u32) -> Self;
+
+    /// Linear interpolation between `start` and `end`, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_lerp][FixedI32::unwrapped_lerp]</code> and
+    /// <code>FixedU32::[unwrapped\_lerp][FixedU32::unwrapped_lerp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Inverse linear interpolation between `start` and `end`, panicking on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `start`&nbsp;=&nbsp;`end` or when the results overflows.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_inv\_lerp][FixedI32::unwrapped_inv_lerp]</code>
+    /// and
+    /// <code>FixedU32::[unwrapped\_inv\_lerp][FixedU32::unwrapped_inv_lerp]</code>.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self;
+
+    /// Overflowing negation.
+    ///
+    /// Returns a [tuple] of the negated value and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
     ///
     /// See also
     /// <code>FixedI32::[overflowing\_neg][FixedI32::overflowing_neg]</code> and
@@ -3115,6 +4159,52 @@ where
     /// Panics if `self` is zero.
     fn overflowing_recip(self) -> (Self, bool);
 
+    /// Overflowing multiplication, rounding the product using the given
+    /// rounding mode.
+    ///
+    /// Returns a [tuple] of the product and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_mul\_round][FixedI32::overflowing_mul_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_mul\_round][FixedU32::overflowing_mul_round]</code>.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing division, rounding the quotient using the given rounding
+    /// mode.
+    ///
+    /// Returns a [tuple] of the quotient and a [`bool`], indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_div\_round][FixedI32::overflowing_div_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_div\_round][FixedU32::overflowing_div_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool);
+
+    /// Overflowing reciprocal, rounding using the given rounding mode.
+    ///
+    /// Returns a [tuple] of the reciprocal of `self` and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_recip\_round][FixedI32::overflowing_recip_round]</code>
+    /// and
+    /// <code>FixedU32::[overflowing\_recip\_round][FixedU32::overflowing_recip_round]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn overflowing_recip_round(self, mode: RoundingMode) -> (Self, bool);
+
     /// Overflowing next multiple of `other`.
     ///
     /// Returns a [tuple] of the next multiple and a [`bool`], indicating
@@ -3311,6 +4401,117 @@ where
     /// Panics if the number is negative.
     fn overflowing_sqrt(self) -> (Self, bool);
 
+    /// Overflowing reciprocal square root.
+    ///
+    /// Returns a [tuple] of the reciprocal square root and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_rsqrt][FixedI32::overflowing_rsqrt]</code> and
+    /// <code>FixedU32::[overflowing\_rsqrt][FixedU32::overflowing_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    #[track_caller]
+    #[must_use]
+    fn overflowing_rsqrt(self) -> (Self, bool);
+
+    /// Overflowing base-2 exponential.
+    ///
+    /// Returns a [tuple] of the base-2 exponential and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp2][FixedI32::overflowing_exp2]</code> and
+    /// <code>FixedU32::[overflowing\_exp2][FixedU32::overflowing_exp2]</code>.
+    fn overflowing_exp2(self) -> (Self, bool);
+
+    /// Overflowing exponential.
+    ///
+    /// Returns a [tuple] of the exponential and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp][FixedI32::overflowing_exp]</code> and
+    /// <code>FixedU32::[overflowing\_exp][FixedU32::overflowing_exp]</code>.
+    fn overflowing_exp(self) -> (Self, bool);
+
+    /// Overflowing exponential minus one.
+    ///
+    /// Returns a [tuple] of the exponential minus one and a [`bool`],
+    /// indicating whether an overflow has occurred. On overflow, the wrapped
+    /// value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_exp\_m1][FixedI32::overflowing_exp_m1]</code> and
+    /// <code>FixedU32::[overflowing\_exp\_m1][FixedU32::overflowing_exp_m1]</code>.
+    fn overflowing_exp_m1(self) -> (Self, bool);
+
+    /// Overflowing integer power.
+    ///
+    /// Returns a [tuple] of the value raised to the integer power `n` and a
+    /// [`bool`], indicating whether an overflow has occurred. On overflow, the
+    /// wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powi][FixedI32::overflowing_powi]</code> and
+    /// <code>FixedU32::[overflowing\_powi][FixedU32::overflowing_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    #[track_caller]
+    fn overflowing_powi(self, n: i32) -> (Self, bool);
+
+    /// Overflowing power.
+    ///
+    /// Returns a [tuple] of the value raised to the fixed-point power `exp` and
+    /// a [`bool`], indicating whether an overflow has occurred. On overflow,
+    /// the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_powf][FixedI32::overflowing_powf]</code> and
+    /// <code>FixedU32::[overflowing\_powf][FixedU32::overflowing_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    #[track_caller]
+    fn overflowing_powf(self, exp: Self) -> (Self, bool);
+
+    /// Overflowing cube root.
+    ///
+    /// Returns a [tuple] of the cube root and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cbrt][FixedI32::overflowing_cbrt]</code> and
+    /// <code>FixedU32::[overflowing\_cbrt][FixedU32::overflowing_cbrt]</code>.
+    #[track_caller]
+    fn overflowing_cbrt(self) -> (Self, bool);
+
+    /// Overflowing <i>n</i>th root.
+    ///
+    /// Returns a [tuple] of the <i>n</i>th root and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_nth\_root][FixedI32::overflowing_nth_root]</code> and
+    /// <code>FixedU32::[overflowing\_nth\_root][FixedU32::overflowing_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    #[track_caller]
+    fn overflowing_nth_root(self, n: u32) -> (Self, bool);
+
     /// Overflowing linear interpolation between `start` and `end`.
     ///
     /// Returns a [tuple] of the interpolated value and a [`bool`], indicating
@@ -3440,11 +4641,29 @@ where
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedI8`], [`FixedI16`], [`FixedI32`],
-/// [`FixedI64`], and [`FixedI128`].
+/// [`FixedI64`], [`FixedI128`], and [`FixedI256`].
 pub trait FixedSigned: Fixed
 where
     Self: Neg<Output = Self>,
 {
+    /// A signed fixed-point number type with the same number of bits as `Self`
+    /// and three integer bits, which is used for angles.
+    ///
+    /// This is the type with the most fractional bits that can hold ±π, and is
+    /// the type returned by [`atan`][FixedSigned::atan] and
+    /// [`atan2`][FixedSigned::atan2].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::FixedSigned;
+    /// use fixed::types::{I3F29, I16F16};
+    /// // I16F16::Angle is I3F29
+    /// assert_eq!(<I16F16 as FixedSigned>::Angle::FRAC_NBITS, I3F29::FRAC_NBITS);
+    /// assert_eq!(<I16F16 as FixedSigned>::Angle::INT_NBITS, I3F29::INT_NBITS);
+    /// ```
+    type Angle: FixedSigned;
+
     /// Negative one if the fixed-point number can represent it, otherwise
     /// [`None`].
     const TRY_NEG_ONE: Option<Self>;
@@ -3515,6 +4734,140 @@ where
     #[must_use]
     fn sub_unsigned(self, rhs: Self::Unsigned) -> Self;
 
+    /// Sine.
+    ///
+    /// See also <code>FixedI32::[sin][FixedI32::sin]</code>.
+    #[track_caller]
+    #[must_use]
+    fn sin(self) -> Self;
+
+    /// Cosine.
+    ///
+    /// See also <code>FixedI32::[cos][FixedI32::cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_cos`] instead.
+    ///
+    /// [`wrapping_cos`]: FixedSigned::wrapping_cos
+    #[track_caller]
+    #[must_use]
+    fn cos(self) -> Self;
+
+    /// Simultaneously computes the sine and cosine.
+    ///
+    /// See also <code>FixedI32::[sin\_cos][FixedI32::sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the cosine
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_sin_cos`] instead.
+    ///
+    /// [`wrapping_sin_cos`]: FixedSigned::wrapping_sin_cos
+    #[track_caller]
+    #[must_use]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Arctangent.
+    ///
+    /// The result is an angle of type [`Angle`][FixedSigned::Angle].
+    ///
+    /// See also <code>FixedI32::[atan][FixedI32::atan]</code>.
+    #[must_use]
+    fn atan(self) -> Self::Angle;
+
+    /// Four-quadrant arctangent of `self` and `other`, where `self` is the
+    /// <i>y</i> coordinate and `other` is the <i>x</i> coordinate.
+    ///
+    /// The result is an angle of type [`Angle`][FixedSigned::Angle].
+    ///
+    /// See also <code>FixedI32::[atan2][FixedI32::atan2]</code>.
+    #[must_use]
+    fn atan2(self, other: Self) -> Self::Angle;
+
+    /// Arcsine.
+    ///
+    /// See also <code>FixedI32::[asin][FixedI32::asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics; if wrapping is required use [`wrapping_asin`]
+    /// instead.
+    ///
+    /// [`wrapping_asin`]: FixedSigned::wrapping_asin
+    #[track_caller]
+    #[must_use]
+    fn asin(self) -> Self;
+
+    /// Arccosine.
+    ///
+    /// See also <code>FixedI32::[acos][FixedI32::acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// When debug assertions are enabled, this method also panics if the
+    /// result overflows. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking change if in
+    /// the future it panics; if wrapping is required use [`wrapping_acos`]
+    /// instead.
+    ///
+    /// [`wrapping_acos`]: FixedSigned::wrapping_acos
+    #[track_caller]
+    #[must_use]
+    fn acos(self) -> Self;
+
+    /// Hyperbolic sine.
+    ///
+    /// See also <code>FixedI32::[sinh][FixedI32::sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_sinh`] instead.
+    ///
+    /// [`wrapping_sinh`]: FixedSigned::wrapping_sinh
+    #[track_caller]
+    #[must_use]
+    fn sinh(self) -> Self;
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also <code>FixedI32::[cosh][FixedI32::cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, this method panics if the result
+    /// overflows. When debug assertions are not enabled, the wrapped value can
+    /// be returned, but it is not considered a breaking change if in the future
+    /// it panics; if wrapping is required use [`wrapping_cosh`] instead.
+    ///
+    /// [`wrapping_cosh`]: FixedSigned::wrapping_cosh
+    #[track_caller]
+    #[must_use]
+    fn cosh(self) -> Self;
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method cannot overflow.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    #[must_use]
+    fn tanh(self) -> Self;
+
     /// Checked absolute value. Returns the absolute value, or [`None`] on overflow.
     ///
     /// Overflow can only occur when trying to find the absolute value of the minimum value.
@@ -3550,6 +4903,48 @@ where
     #[must_use]
     fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self>;
 
+    /// Checked sine. Returns the sine, or [`None`] on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_sin][FixedI32::checked_sin]</code>.
+    fn checked_sin(self) -> Option<Self>;
+
+    /// Checked cosine. Returns the cosine, or [`None`] on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_cos][FixedI32::checked_cos]</code>.
+    fn checked_cos(self) -> Option<Self>;
+
+    /// Checked simultaneous sine and cosine. Returns a [tuple] of the sine and
+    /// cosine, or [`None`] on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[checked\_sin\_cos][FixedI32::checked_sin_cos]</code>.
+    fn checked_sin_cos(self) -> Option<(Self, Self)>;
+
+    /// Checked arcsine. Returns the arcsine, or [`None`] if `self` is outside
+    /// the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_asin][FixedI32::checked_asin]</code>.
+    fn checked_asin(self) -> Option<Self>;
+
+    /// Checked arccosine. Returns the arccosine, or [`None`] if `self` is
+    /// outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1 or on
+    /// overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_acos][FixedI32::checked_acos]</code>.
+    fn checked_acos(self) -> Option<Self>;
+
+    /// Checked hyperbolic sine. Returns the hyperbolic sine, or [`None`] on
+    /// overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_sinh][FixedI32::checked_sinh]</code>.
+    fn checked_sinh(self) -> Option<Self>;
+
+    /// Checked hyperbolic cosine. Returns the hyperbolic cosine, or [`None`] on
+    /// overflow.
+    ///
+    /// See also <code>FixedI32::[checked\_cosh][FixedI32::checked_cosh]</code>.
+    fn checked_cosh(self) -> Option<Self>;
+
     /// Saturating absolute value. Returns the absolute value, saturating on overflow.
     ///
     /// Overflow can only occur when trying to find the absolute value of the minimum value.
@@ -3589,6 +4984,68 @@ where
     #[must_use]
     fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
 
+    /// Saturating sine. Returns the sine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin][FixedI32::saturating_sin]</code>.
+    #[must_use]
+    fn saturating_sin(self) -> Self;
+
+    /// Saturating cosine. Returns the cosine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cos][FixedI32::saturating_cos]</code>.
+    #[must_use]
+    fn saturating_cos(self) -> Self;
+
+    /// Saturating simultaneous sine and cosine. Returns a [tuple] of the sine
+    /// and cosine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sin\_cos][FixedI32::saturating_sin_cos]</code>.
+    #[must_use]
+    fn saturating_sin_cos(self) -> (Self, Self);
+
+    /// Saturating arcsine. Returns the arcsine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_asin][FixedI32::saturating_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    #[must_use]
+    fn saturating_asin(self) -> Self;
+
+    /// Saturating arccosine. Returns the arccosine, saturating on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_acos][FixedI32::saturating_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    #[must_use]
+    fn saturating_acos(self) -> Self;
+
+    /// Saturating hyperbolic sine. Returns the hyperbolic sine, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_sinh][FixedI32::saturating_sinh]</code>.
+    #[must_use]
+    fn saturating_sinh(self) -> Self;
+
+    /// Saturating hyperbolic cosine. Returns the hyperbolic cosine, saturating on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[saturating\_cosh][FixedI32::saturating_cosh]</code>.
+    #[must_use]
+    fn saturating_cosh(self) -> Self;
+
     /// Wrapping absolute value. Returns the absolute value, wrapping on overflow.
     ///
     /// Overflow can only occur when trying to find the absolute value of the minimum value.
@@ -3627,6 +5084,66 @@ where
     #[must_use]
     fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
 
+    /// Wrapping sine. Returns the sine, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_sin][FixedI32::wrapping_sin]</code>.
+    #[must_use]
+    fn wrapping_sin(self) -> Self;
+
+    /// Wrapping cosine. Returns the cosine, wrapping on overflow.
+    ///
+    /// See also <code>FixedI32::[wrapping\_cos][FixedI32::wrapping_cos]</code>.
+    #[must_use]
+    fn wrapping_cos(self) -> Self;
+
+    /// Wrapping simultaneous sine and cosine. Returns a [tuple] of the sine and
+    /// cosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_cos][FixedI32::wrapping_sin_cos]</code>.
+    #[must_use]
+    fn wrapping_sin_cos(self) -> (Self, Self);
+
+    /// Wrapping arcsine. Returns the arcsine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_asin][FixedI32::wrapping_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_asin(self) -> Self;
+
+    /// Wrapping arccosine. Returns the arccosine, wrapping on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_acos][FixedI32::wrapping_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    #[must_use]
+    fn wrapping_acos(self) -> Self;
+
+    /// Wrapping hyperbolic sine. Returns the hyperbolic sine, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sinh][FixedI32::wrapping_sinh]</code>.
+    #[must_use]
+    fn wrapping_sinh(self) -> Self;
+
+    /// Wrapping hyperbolic cosine. Returns the hyperbolic cosine, wrapping on
+    /// overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cosh][FixedI32::wrapping_cosh]</code>.
+    #[must_use]
+    fn wrapping_cosh(self) -> Self;
+
     /// Unwrapped absolute value. Returns the absolute value, panicking on overflow.
     ///
     /// Overflow can only occur when trying to find the absolute value of the minimum value.
@@ -3660,31 +5177,120 @@ where
     #[must_use]
     fn unwrapped_signum(self) -> Self;
 
-    /// Unwrapped addition with an unsigned fixed-point number. Returns the sum,
-    /// panicking on overflow.
+    /// Unwrapped addition with an unsigned fixed-point number. Returns the sum,
+    /// panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_add\_unsigned][FixedI32::unwrapped_add_unsigned]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Unwrapped subtraction with an unsigned fixed-point number. Returns the
+    /// difference, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sub\_unsigned][FixedI32::unwrapped_sub_unsigned]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+
+    /// Unwrapped sine. Returns the sine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin][FixedI32::unwrapped_sin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sin(self) -> Self;
+
+    /// Unwrapped cosine. Returns the cosine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cos][FixedI32::unwrapped_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_cos(self) -> Self;
+
+    /// Unwrapped simultaneous sine and cosine. Returns a [tuple] of the sine
+    /// and cosine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin\_cos][FixedI32::unwrapped_sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_sin_cos(self) -> (Self, Self);
+
+    /// Unwrapped arcsine. Returns the arcsine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_asin][FixedI32::unwrapped_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+    /// or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_asin(self) -> Self;
+
+    /// Unwrapped arccosine. Returns the arccosine, panicking on overflow.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_acos][FixedI32::unwrapped_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+    /// or if the result does not fit.
+    #[track_caller]
+    #[must_use]
+    fn unwrapped_acos(self) -> Self;
+
+    /// Unwrapped hyperbolic sine. Returns the hyperbolic sine, panicking on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_add\_unsigned][FixedI32::unwrapped_add_unsigned]</code>.
+    /// <code>FixedI32::[unwrapped\_sinh][FixedI32::unwrapped_sinh]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the result does not fit.
     #[track_caller]
     #[must_use]
-    fn unwrapped_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+    fn unwrapped_sinh(self) -> Self;
 
-    /// Unwrapped subtraction with an unsigned fixed-point number. Returns the
-    /// difference, panicking on overflow.
+    /// Unwrapped hyperbolic cosine. Returns the hyperbolic cosine, panicking on
+    /// overflow.
     ///
     /// See also
-    /// <code>FixedI32::[unwrapped\_sub\_unsigned][FixedI32::unwrapped_sub_unsigned]</code>.
+    /// <code>FixedI32::[unwrapped\_cosh][FixedI32::unwrapped_cosh]</code>.
     ///
     /// # Panics
     ///
     /// Panics if the result does not fit.
     #[track_caller]
     #[must_use]
-    fn unwrapped_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+    fn unwrapped_cosh(self) -> Self;
 
     /// Overflowing absolute value.
     ///
@@ -3731,6 +5337,82 @@ where
     /// <code>FixedI32::[overflowing\_sub\_unsigned][FixedI32::overflowing_sub_unsigned]</code>.
     #[must_use]
     fn overflowing_sub_unsigned(self, rhs: Self::Unsigned) -> (Self, bool);
+
+    /// Overflowing sine.
+    ///
+    /// Returns a [tuple] of the sine and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin][FixedI32::overflowing_sin]</code>.
+    fn overflowing_sin(self) -> (Self, bool);
+
+    /// Overflowing cosine.
+    ///
+    /// Returns a [tuple] of the cosine and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cos][FixedI32::overflowing_cos]</code>.
+    fn overflowing_cos(self) -> (Self, bool);
+
+    /// Overflowing simultaneous sine and cosine.
+    ///
+    /// Returns a [tuple] of the sine and cosine and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped values are
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sin\_cos][FixedI32::overflowing_sin_cos]</code>.
+    fn overflowing_sin_cos(self) -> ((Self, Self), bool);
+
+    /// Overflowing arcsine.
+    ///
+    /// Returns a [tuple] of the arcsine and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_asin][FixedI32::overflowing_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    fn overflowing_asin(self) -> (Self, bool);
+
+    /// Overflowing arccosine.
+    ///
+    /// Returns a [tuple] of the arccosine and a [`bool`], indicating whether an
+    /// overflow has occurred. On overflow, the wrapped value is returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_acos][FixedI32::overflowing_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    #[track_caller]
+    fn overflowing_acos(self) -> (Self, bool);
+
+    /// Overflowing hyperbolic sine.
+    ///
+    /// Returns a [tuple] of the hyperbolic sine and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_sinh][FixedI32::overflowing_sinh]</code>.
+    fn overflowing_sinh(self) -> (Self, bool);
+
+    /// Overflowing hyperbolic cosine.
+    ///
+    /// Returns a [tuple] of the hyperbolic cosine and a [`bool`], indicating
+    /// whether an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See also
+    /// <code>FixedI32::[overflowing\_cosh][FixedI32::overflowing_cosh]</code>.
+    fn overflowing_cosh(self) -> (Self, bool);
 }
 
 /// This trait provides methods common to all unsigned fixed-point numbers.
@@ -3740,7 +5422,7 @@ where
 ///
 /// This trait is sealed and cannot be implemented for more types; it
 /// is implemented for [`FixedU8`], [`FixedU16`], [`FixedU32`],
-/// [`FixedU64`], and [`FixedU128`].
+/// [`FixedU64`], [`FixedU128`], and [`FixedU256`].
 pub trait FixedUnsigned: Fixed
 where
     Self: Div<<Self as Fixed>::NonZeroBits, Output = Self>,
@@ -4018,6 +5700,50 @@ where
     }
 }
 
+/// This trait provides exact multiplication, where the type of the product is
+/// computed from the types of the operands.
+///
+/// This trait is implemented for multiplication of two signed fixed-point
+/// numbers and for multiplication of two unsigned fixed-point numbers. If one
+/// operand has <i>m</i> bits of which <i>f</i> are fractional bits, and the
+/// other has <i>n</i> bits of which <i>g</i> are fractional bits, the product
+/// is the narrowest fixed-point number with at least <i>m</i>&nbsp;+&nbsp;<i>n</i>
+/// bits, and it has <i>f</i>&nbsp;+&nbsp;<i>g</i> fractional bits, that is
+/// <code>[Sum]&lt;FracLhs, FracRhs></code>. So the product never overflows and
+/// never loses precision, and chains of multiplications only lose bits when an
+/// explicit narrowing conversion is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::traits::MulExact;
+/// use fixed::types::extra::{Sum, U8, U16};
+/// use fixed::types::{I8F8, I16F16};
+/// use fixed::FixedI64;
+/// let a = I16F16::MAX;
+/// let b = I8F8::MIN;
+/// // the product has 32 + 16 bits, and 16 + 8 fractional bits
+/// let prod: FixedI64<Sum<U16, U8>> = a.mul_exact(b);
+/// assert_eq!(prod, a.to_num::<f64>() * b.to_num::<f64>());
+///
+/// // the trait can be used in generic code
+/// fn square<F: MulExact + Copy>(x: F) -> F::Output {
+///     x.mul_exact(x)
+/// }
+/// assert_eq!(square(I8F8::MIN), 16384);
+/// ```
+///
+/// [Sum]: crate::types::extra::Sum
+pub trait MulExact<Rhs = Self> {
+    /// The type of the product.
+    type Output;
+
+    /// Multiplies two fixed-point numbers without any loss of range or
+    /// precision.
+    #[must_use]
+    fn mul_exact(self, rhs: Rhs) -> Self::Output;
+}
+
 /// This trait provides checked conversions from fixed-point numbers.
 ///
 /// This trait is implemented for conversions between integer
@@ -4103,6 +5829,108 @@ pub trait FromFixed {
             (_, true) => panic!("overflow"),
         }
     }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`.
+    ///
+    /// The default implementation ignores `mode` and returns
+    /// <code>Self::[from\_fixed][FromFixed::from_fixed]\(src)</code>, which
+    /// is only correct for types that can represent every fixed-point number
+    /// exactly. Implementations for other types should override the
+    /// `*_from_fixed_round` methods; the implementations in this crate do.
+    ///
+    /// # Panics
+    ///
+    /// When debug assertions are enabled, panics if the value does
+    /// not fit. When debug assertions are not enabled, the wrapped
+    /// value can be returned, but it is not considered a breaking
+    /// change if in the future it panics; if wrapping is required use
+    /// [`wrapping_from_fixed_round`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::FromFixed;
+    /// use fixed::types::I8F8;
+    /// use fixed::RoundingMode;
+    /// let f = I8F8::from_num(-2.5);
+    /// assert_eq!(i32::from_fixed_round(f, RoundingMode::Floor), -3);
+    /// assert_eq!(i32::from_fixed_round(f, RoundingMode::NearestEven), -2);
+    /// assert_eq!(i32::from_fixed_round(f, RoundingMode::NearestAway), -3);
+    /// ```
+    ///
+    /// [`wrapping_from_fixed_round`]: FromFixed::wrapping_from_fixed_round
+    #[inline]
+    #[track_caller]
+    fn from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::from_fixed(src)
+    }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`,
+    /// if it fits, otherwise returns [`None`].
+    ///
+    /// See [`from_fixed_round`] for the default implementation.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn checked_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::checked_from_fixed(src)
+    }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`,
+    /// and saturating if it does not fit.
+    ///
+    /// See [`from_fixed_round`] for the default implementation.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn saturating_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::saturating_from_fixed(src)
+    }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`,
+    /// and wrapping if it does not fit.
+    ///
+    /// See [`from_fixed_round`] for the default implementation.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn wrapping_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::wrapping_from_fixed(src)
+    }
+
+    /// Converts from a fixed-point number, rounding as specified by `mode`.
+    ///
+    /// Returns a [tuple] of the value and a [`bool`] indicating whether
+    /// an overflow has occurred. On overflow, the wrapped value is
+    /// returned.
+    ///
+    /// See [`from_fixed_round`] for the default implementation.
+    ///
+    /// [`from_fixed_round`]: FromFixed::from_fixed_round
+    #[inline]
+    fn overflowing_from_fixed_round<F: Fixed>(src: F, mode: RoundingMode) -> (Self, bool)
+    where
+        Self: Sized,
+    {
+        let _ = mode;
+        Self::overflowing_from_fixed(src)
+    }
 }
 
 /// This trait provides checked conversions to fixed-point numbers.
@@ -4211,6 +6039,98 @@ pub trait ToFixed {
             (_, true) => panic!("overflow"),
         }
     }
+
+    // Converts to the Wide form used by the conversions that round or that
+    // involve the 256-bit fixed-point numbers. The implementations in this
+    // crate are exact. Other implementations cannot name Wide, so they get this
+    // default, which goes through FixedI256<U64>: fractional bits after the
+    // first 64 are discarded, and values that saturate it are infinite.
+    #[doc(hidden)]
+    #[inline]
+    fn to_wide(self, _: Private) -> Wide
+    where
+        Self: Sized,
+    {
+        let f = self.saturating_to_fixed::<FixedI256<U64>>();
+        if f == FixedI256::<U64>::MAX || f == FixedI256::<U64>::MIN {
+            Wide::Infinite { neg: f < 0 }
+        } else {
+            f.to_wide(Private)
+        }
+    }
+
+    /// Converts to a fixed-point number using stochastic rounding, with random
+    /// bits from `rng`.
+    ///
+    /// See [`FixedI32::from_num_stochastic`] for details on the conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::ToFixed;
+    /// use fixed::types::{I8F8, I16F16};
+    /// let mut zero = || 0u64;
+    /// // 0x1.0040 is a quarter of the way from 0x1.00 to 0x1.01
+    /// let src = I16F16::from_bits(0x1_0040);
+    /// let dst = src.to_fixed_stochastic::<I8F8>(&mut zero);
+    /// assert_eq!(dst, I8F8::from_bits(0x101));
+    /// ```
+    ///
+    /// [`FixedI32::from_num_stochastic`]: crate::FixedI32::from_num_stochastic
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    fn to_fixed_stochastic<F: Fixed>(self, rng: &mut (impl RngLike + ?Sized)) -> F
+    where
+        Self: Sized,
+    {
+        F::from_num_stochastic(self, rng)
+    }
+
+    /// Converts to a fixed-point number, adding TPDF dither with random bits
+    /// from `rng` before rounding.
+    ///
+    /// See [`FixedI32::from_num_dither`] for details on the conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is a floating-point number that is not [finite].
+    ///
+    /// When debug assertions are enabled, also panics if the value
+    /// does not fit. When debug assertions are not enabled, the
+    /// wrapped value can be returned, but it is not considered a
+    /// breaking change if in the future it panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::ToFixed;
+    /// use fixed::types::I8F8;
+    /// let mut zero = || 0u64;
+    /// // with zero random bits, exact values are unchanged
+    /// let dst = 1.5f32.to_fixed_dither::<I8F8>(&mut zero);
+    /// assert_eq!(dst, 1.5);
+    /// ```
+    ///
+    /// [`FixedI32::from_num_dither`]: crate::FixedI32::from_num_dither
+    /// [finite]: f64::is_finite
+    #[inline]
+    #[track_caller]
+    fn to_fixed_dither<F: Fixed>(self, rng: &mut (impl RngLike + ?Sized)) -> F
+    where
+        Self: Sized,
+    {
+        F::from_num_dither(self, rng)
+    }
 }
 
 /// This trait provides a way to convert a number to/from an equivalent
@@ -4220,7 +6140,10 @@ pub trait ToFixed {
 /// [`i16`], [`i32`], [`i64`] and [`i128`], which have equivalent fixed-point
 /// types [`I8F0`], [`I16F0`], [`I32F0`], [`I64F0`] and [`I128F0`]. Similar
 /// implementations are provided for the unsigned integer primitives [`u8`],
-/// [`u16`], [`u32`], [`u64`] and [`u128`].
+/// [`u16`], [`u32`], [`u64`] and [`u128`]. The 256-bit integers [`I256Bits`]
+/// and [`U256Bits`] have the equivalent fixed-point types
+/// <code>[FixedI256]&lt;[U0][crate::types::extra::U0]></code> and
+/// <code>[FixedU256]&lt;[U0][crate::types::extra::U0]></code>.
 ///
 /// # Examples
 ///
@@ -4287,6 +6210,56 @@ pub trait FixedEquiv {
     fn mut_from_fixed_equiv(f: &mut Self::Equiv) -> &mut Self;
 }
 
+/// A source of random bits.
+///
+/// This is used by methods such as [`FixedI32::from_num_stochastic`] and
+/// [`FixedI32::from_num_dither`], which need random numbers but should not tie
+/// the library to a particular random number generator. It is implemented for
+/// closures returning [`u64`], so a generator from another crate can be used
+/// by wrapping it in a closure.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::traits::RngLike;
+/// use fixed::types::{I8F8, I16F16};
+///
+/// // A xorshift generator
+/// struct XorShift(u64);
+/// impl RngLike for XorShift {
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 ^= self.0 << 13;
+///         self.0 ^= self.0 >> 7;
+///         self.0 ^= self.0 << 17;
+///         self.0
+///     }
+/// }
+///
+/// let mut rng = XorShift(1);
+/// // 0x1.0040 is a quarter of the way from 0x1.00 to 0x1.01
+/// let src = I16F16::from_bits(0x1_0040);
+/// let dst = I8F8::from_num_stochastic(src, &mut rng);
+/// assert!(dst == I8F8::from_bits(0x100) || dst == I8F8::from_bits(0x101));
+///
+/// // A closure can be used too
+/// let mut zero = || 0u64;
+/// assert_eq!(I8F8::from_num_stochastic(src, &mut zero), I8F8::from_bits(0x101));
+/// ```
+///
+/// [`FixedI32::from_num_dither`]: crate::FixedI32::from_num_dither
+/// [`FixedI32::from_num_stochastic`]: crate::FixedI32::from_num_stochastic
+pub trait RngLike {
+    /// Returns 64 uniformly distributed random bits.
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> RngLike for F {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self()
+    }
+}
+
 macro_rules! trait_delegate {
     (fn $method:ident($($param:ident: $Param:ty),*$(,)?) -> $Ret:ty) => {
         #[inline]
@@ -4326,9 +6299,88 @@ macro_rules! trait_delegate {
     };
 }
 
+macro_rules! to_wide_body {
+    ($self:ident, LeEqU256, $Bits:ident) => {
+        Wide::Finite($self.finite())
+    };
+    ($self:ident, $LeEqU:ident, $Bits:ident) => {{
+        let (neg, abs) = int_helper::$Bits::neg_abs($self.to_bits());
+        Wide::Finite(Finite {
+            neg,
+            abs: U256 {
+                lo: abs as u128,
+                hi: 0,
+            },
+            frac_nbits: Self::FRAC_NBITS as i32,
+            round: RoundingMode::Floor,
+        })
+    }};
+}
+
+// The 256-bit numbers cannot use the 128-bit helpers, so they convert from
+// other fixed-point numbers through Wide.
+macro_rules! from_fixed_body {
+    (saturating, $src:ident, LeEqU256, $Bits:ident, $Signedness:ident) => {
+        fixed256::saturating_convert_round($src, RoundingMode::Floor)
+    };
+    (overflowing, $src:ident, LeEqU256, $Bits:ident, $Signedness:ident) => {
+        fixed256::overflowing_convert_round($src, RoundingMode::Floor)
+    };
+    (saturating, $src:ident, $LeEqU:ident, $Bits:ident, $Signedness:ident) => {{
+        let conv = $src.to_fixed_helper(Private, Self::FRAC_NBITS, Self::INT_NBITS);
+        if conv.overflow {
+            return if $src < 0 { Self::MIN } else { Self::MAX };
+        }
+        let bits = if_signed_unsigned!(
+            $Signedness,
+            match conv.bits {
+                Widest::Unsigned(bits) => {
+                    if (bits as $Bits) < 0 {
+                        return Self::MAX;
+                    }
+                    bits as $Bits
+                }
+                Widest::Negative(bits) => bits as $Bits,
+            },
+            match conv.bits {
+                Widest::Unsigned(bits) => bits as $Bits,
+                Widest::Negative(_) => {
+                    return Self::MIN;
+                }
+            },
+        );
+        Self::from_bits(bits)
+    }};
+    (overflowing, $src:ident, $LeEqU:ident, $Bits:ident, $Signedness:ident) => {{
+        let conv = $src.to_fixed_helper(Private, Self::FRAC_NBITS, Self::INT_NBITS);
+        let mut new_overflow = false;
+        let bits = if_signed_unsigned!(
+            $Signedness,
+            match conv.bits {
+                Widest::Unsigned(bits) => {
+                    if (bits as $Bits) < 0 {
+                        new_overflow = true;
+                    }
+                    bits as $Bits
+                }
+                Widest::Negative(bits) => bits as $Bits,
+            },
+            match conv.bits {
+                Widest::Unsigned(bits) => bits as $Bits,
+                Widest::Negative(bits) => {
+                    new_overflow = true;
+                    bits as $Bits
+                }
+            },
+        );
+        (Self::from_bits(bits), conv.overflow || new_overflow)
+    }};
+}
+
 macro_rules! impl_fixed {
     (
-        $Fixed:ident, $IFixed:ident, $UFixed:ident, $LeEqU:ident, $Bits:ident, $Signedness:ident
+        $Fixed:ident, $IFixed:ident, $UFixed:ident, $LeEqU:ident, $Bits:ident,
+        $NonZeroBits:ty, $Signedness:ident, $UAngleFrac:ident
     ) => {
         impl<Frac: $LeEqU> FixedOptionalArbitrary for $Fixed<Frac> {}
         impl<Frac: $LeEqU> FixedOptionalBorsh for $Fixed<Frac> {}
@@ -4339,7 +6391,7 @@ macro_rules! impl_fixed {
 
         impl<Frac: $LeEqU> Fixed for $Fixed<Frac> {
             type Bits = $Bits;
-            type NonZeroBits = NonZero<$Bits>;
+            type NonZeroBits = $NonZeroBits;
             type Bytes = [u8; size_of::<$Bits>()];
             type Frac = Frac;
             type Signed = $IFixed<Frac>;
@@ -4365,18 +6417,74 @@ macro_rules! impl_fixed {
             trait_delegate! { fn to_be_bytes(self) -> Self::Bytes }
             trait_delegate! { fn to_le_bytes(self) -> Self::Bytes }
             trait_delegate! { fn to_ne_bytes(self) -> Self::Bytes }
-            trait_delegate! { fn from_num<Src: ToFixed>(src: Src) -> Self }
-            trait_delegate! { fn to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> }
-            trait_delegate! { fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> }
-            trait_delegate! { fn saturating_from_num<Src: ToFixed>(val: Src) -> Self }
-            trait_delegate! { fn saturating_to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self }
-            trait_delegate! { fn wrapping_to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn unwrapped_from_num<Src: ToFixed>(val: Src) -> Self }
-            trait_delegate! { fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst }
-            trait_delegate! { fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) }
-            trait_delegate! { fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) }
+            #[inline]
+            fn from_num<Src: ToFixed>(src: Src) -> Self {
+                src.to_fixed()
+            }
+            #[inline]
+            fn to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::from_fixed(self)
+            }
+            #[inline]
+            fn checked_from_num<Src: ToFixed>(val: Src) -> Option<Self> {
+                val.checked_to_fixed()
+            }
+            #[inline]
+            fn checked_to_num<Dst: FromFixed>(self) -> Option<Dst> {
+                Dst::checked_from_fixed(self)
+            }
+            #[inline]
+            fn saturating_from_num<Src: ToFixed>(val: Src) -> Self {
+                val.saturating_to_fixed()
+            }
+            #[inline]
+            fn saturating_to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::saturating_from_fixed(self)
+            }
+            #[inline]
+            fn wrapping_from_num<Src: ToFixed>(val: Src) -> Self {
+                val.wrapping_to_fixed()
+            }
+            #[inline]
+            fn wrapping_to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::wrapping_from_fixed(self)
+            }
+            #[inline]
+            #[track_caller]
+            fn unwrapped_from_num<Src: ToFixed>(val: Src) -> Self {
+                val.unwrapped_to_fixed()
+            }
+            #[inline]
+            #[track_caller]
+            fn unwrapped_to_num<Dst: FromFixed>(self) -> Dst {
+                Dst::unwrapped_from_fixed(self)
+            }
+            #[inline]
+            fn overflowing_from_num<Src: ToFixed>(val: Src) -> (Self, bool) {
+                val.overflowing_to_fixed()
+            }
+            #[inline]
+            fn overflowing_to_num<Dst: FromFixed>(self) -> (Dst, bool) {
+                Dst::overflowing_from_fixed(self)
+            }
+            trait_delegate! { fn from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self }
+            trait_delegate! { fn to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! { fn checked_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Option<Dst> }
+            trait_delegate! { fn saturating_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self }
+            trait_delegate! { fn saturating_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! { fn wrapping_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> Self }
+            trait_delegate! { fn wrapping_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> Dst }
+            trait_delegate! { fn overflowing_from_num_round<Src: ToFixed>(src: Src, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_to_num_round<Dst: FromFixed>(self, mode: RoundingMode) -> (Dst, bool) }
+            trait_delegate! { fn from_num_stochastic<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self }
+            trait_delegate! { fn checked_from_num_stochastic<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Option<Self> }
+            trait_delegate! { fn saturating_from_num_stochastic<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self }
+            trait_delegate! { fn overflowing_from_num_stochastic<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> (Self, bool) }
+            trait_delegate! { fn from_num_dither<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self }
+            trait_delegate! { fn checked_from_num_dither<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Option<Self> }
+            trait_delegate! { fn saturating_from_num_dither<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> Self }
+            trait_delegate! { fn overflowing_from_num_dither<Src: ToFixed>(src: Src, rng: &mut (impl RngLike + ?Sized)) -> (Self, bool) }
             trait_delegate! { fn from_str_binary(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_octal(src: &str) -> Result<Self, ParseFixedError> }
             trait_delegate! { fn from_str_hex(src: &str) -> Result<Self, ParseFixedError> }
@@ -4466,6 +6574,38 @@ macro_rules! impl_fixed {
             trait_delegate! {
                 fn overflowing_from_ascii_hex(src: &[u8]) -> Result<(Self, bool), ParseFixedError>
             }
+            trait_delegate! {
+                fn from_str_with(src: &str, options: &ParseOptions) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! { fn from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! {
+                fn saturating_from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! { fn wrapping_from_str_hexfloat(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! { fn unwrapped_from_str_hexfloat(src: &str) -> Self }
+            trait_delegate! {
+                fn overflowing_from_str_hexfloat(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! { fn from_ratio(num: Self::Bits, den: Self::Bits) -> Self }
+            trait_delegate! {
+                fn checked_from_ratio(num: Self::Bits, den: Self::Bits) -> Option<Self>
+            }
+            trait_delegate! { fn saturating_from_ratio(num: Self::Bits, den: Self::Bits) -> Self }
+            trait_delegate! { fn wrapping_from_ratio(num: Self::Bits, den: Self::Bits) -> Self }
+            trait_delegate! {
+                fn overflowing_from_ratio(num: Self::Bits, den: Self::Bits) -> (Self, bool)
+            }
+            trait_delegate! { fn from_str_ratio(src: &str) -> Result<Self, ParseFixedError> }
+            trait_delegate! {
+                fn saturating_from_str_ratio(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn wrapping_from_str_ratio(src: &str) -> Result<Self, ParseFixedError>
+            }
+            trait_delegate! {
+                fn overflowing_from_str_ratio(src: &str) -> Result<(Self, bool), ParseFixedError>
+            }
+            trait_delegate! { fn to_ratio(self, max_den: Self::Bits) -> (Self::Bits, Self::Bits) }
             trait_delegate! { fn int(self) -> Self }
             trait_delegate! { fn frac(self) -> Self }
             trait_delegate! { fn ceil(self) -> Self }
@@ -4502,9 +6642,15 @@ macro_rules! impl_fixed {
             trait_delegate! { fn int_log2(self) -> i32 }
             trait_delegate! { fn int_log10(self) -> i32 }
             trait_delegate! { fn int_log(self, base: u32) -> i32 }
+            trait_delegate! { fn log2(self) -> Self }
+            trait_delegate! { fn ln(self) -> Self }
+            trait_delegate! { fn log10(self) -> Self }
             trait_delegate! { fn checked_int_log2(self) -> Option<i32> }
             trait_delegate! { fn checked_int_log10(self) -> Option<i32> }
             trait_delegate! { fn checked_int_log(self, base: u32) -> Option<i32> }
+            trait_delegate! { fn checked_log2(self) -> Option<Self> }
+            trait_delegate! { fn checked_ln(self) -> Option<Self> }
+            trait_delegate! { fn checked_log10(self) -> Option<Self> }
             trait_delegate! { fn reverse_bits(self) -> Self }
             trait_delegate! { fn rotate_left(self, n: u32) -> Self }
             trait_delegate! { fn rotate_right(self, n: u32) -> Self }
@@ -4514,6 +6660,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn mean(self, other: Self) -> Self }
             trait_delegate! { fn hypot(self, other: Self) -> Self }
             trait_delegate! { fn recip(self) -> Self }
+            trait_delegate! { fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn div_round(self, rhs: Self, mode: RoundingMode) -> Self }
+            trait_delegate! { fn recip_round(self, mode: RoundingMode) -> Self }
             trait_delegate! { fn next_multiple_of(self, other: Self) -> Self }
             trait_delegate! { fn mul_add(self, mul: Self, add: Self) -> Self }
             trait_delegate! { fn add_prod(self, a: Self, b: Self) -> Self }
@@ -4525,6 +6674,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unbounded_shl(self, rhs: u32) -> Self }
             trait_delegate! { fn unbounded_shr(self, rhs: u32) -> Self }
             trait_delegate! { fn sqrt(self) -> Self }
+            trait_delegate! { fn rsqrt(self) -> Self }
+            trait_delegate! { fn exp2(self) -> Self }
+            trait_delegate! { fn exp(self) -> Self }
+            trait_delegate! { fn exp_m1(self) -> Self }
+            trait_delegate! { fn powi(self, n: i32) -> Self }
+            trait_delegate! { fn powf(self, exp: Self) -> Self }
+            trait_delegate! { fn cbrt(self) -> Self }
+            trait_delegate! { fn nth_root(self, n: u32) -> Self }
             trait_delegate! { fn lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn checked_neg(self) -> Option<Self> }
@@ -4534,6 +6691,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_div(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_rem(self, rhs: Self) -> Option<Self> }
             trait_delegate! { fn checked_recip(self) -> Option<Self> }
+            trait_delegate! { fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> }
+            trait_delegate! { fn checked_recip_round(self, mode: RoundingMode) -> Option<Self> }
             trait_delegate! { fn checked_next_multiple_of(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_mul_add(self, mul: Self, add: Self) -> Option<Self> }
             trait_delegate! { fn checked_add_prod(self, a: Self, b: Self) -> Option<Self> }
@@ -4550,6 +6710,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn checked_dist(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_hypot(self, other: Self) -> Option<Self> }
             trait_delegate! { fn checked_sqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_rsqrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_exp2(self) -> Option<Self> }
+            trait_delegate! { fn checked_exp(self) -> Option<Self> }
+            trait_delegate! { fn checked_exp_m1(self) -> Option<Self> }
+            trait_delegate! { fn checked_powi(self, n: i32) -> Option<Self> }
+            trait_delegate! { fn checked_powf(self, exp: Self) -> Option<Self> }
+            trait_delegate! { fn checked_cbrt(self) -> Option<Self> }
+            trait_delegate! { fn checked_nth_root(self, n: u32) -> Option<Self> }
             trait_delegate! { fn checked_lerp(self, start: Self, end: Self) -> Option<Self> }
             trait_delegate! { fn checked_inv_lerp(self, start: Self, end: Self) -> Option<Self> }
             trait_delegate! { fn saturating_neg(self) -> Self }
@@ -4570,6 +6738,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn saturating_dist(self, other: Self) -> Self }
             trait_delegate! { fn saturating_hypot(self, other: Self) -> Self }
             trait_delegate! { fn saturating_sqrt(self) -> Self }
+            trait_delegate! { fn saturating_rsqrt(self) -> Self }
+            trait_delegate! { fn saturating_exp2(self) -> Self }
+            trait_delegate! { fn saturating_exp(self) -> Self }
+            trait_delegate! { fn saturating_exp_m1(self) -> Self }
+            trait_delegate! { fn saturating_powi(self, n: i32) -> Self }
+            trait_delegate! { fn saturating_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn saturating_cbrt(self) -> Self }
+            trait_delegate! { fn saturating_nth_root(self, n: u32) -> Self }
             trait_delegate! { fn saturating_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn saturating_inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn wrapping_neg(self) -> Self }
@@ -4592,6 +6768,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn wrapping_dist(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_hypot(self, other: Self) -> Self }
             trait_delegate! { fn wrapping_sqrt(self) -> Self }
+            trait_delegate! { fn wrapping_rsqrt(self) -> Self }
+            trait_delegate! { fn wrapping_exp2(self) -> Self }
+            trait_delegate! { fn wrapping_exp(self) -> Self }
+            trait_delegate! { fn wrapping_exp_m1(self) -> Self }
+            trait_delegate! { fn wrapping_powi(self, n: i32) -> Self }
+            trait_delegate! { fn wrapping_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn wrapping_cbrt(self) -> Self }
+            trait_delegate! { fn wrapping_nth_root(self, n: u32) -> Self }
             trait_delegate! { fn wrapping_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn wrapping_inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn unwrapped_neg(self) -> Self }
@@ -4617,6 +6801,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn unwrapped_dist(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_hypot(self, other: Self) -> Self }
             trait_delegate! { fn unwrapped_sqrt(self) -> Self }
+            trait_delegate! { fn unwrapped_rsqrt(self) -> Self }
+            trait_delegate! { fn unwrapped_exp2(self) -> Self }
+            trait_delegate! { fn unwrapped_exp(self) -> Self }
+            trait_delegate! { fn unwrapped_exp_m1(self) -> Self }
+            trait_delegate! { fn unwrapped_powi(self, n: i32) -> Self }
+            trait_delegate! { fn unwrapped_powf(self, exp: Self) -> Self }
+            trait_delegate! { fn unwrapped_cbrt(self) -> Self }
+            trait_delegate! { fn unwrapped_nth_root(self, n: u32) -> Self }
             trait_delegate! { fn unwrapped_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn unwrapped_inv_lerp(self, start: Self, end: Self) -> Self }
             trait_delegate! { fn overflowing_neg(self) -> (Self, bool) }
@@ -4625,6 +6817,9 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_mul(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_div(self, rhs: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_recip(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_div_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) }
+            trait_delegate! { fn overflowing_recip_round(self, mode: RoundingMode) -> (Self, bool) }
             trait_delegate! { fn overflowing_next_multiple_of(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_mul_add(self, mul: Self, add: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_add_prod(self, a: Self, b: Self) -> (Self, bool) }
@@ -4639,6 +6834,14 @@ macro_rules! impl_fixed {
             trait_delegate! { fn overflowing_dist(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_hypot(self, other: Self) -> (Self, bool) }
             trait_delegate! { fn overflowing_sqrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_rsqrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_exp2(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_exp(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_exp_m1(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_powi(self, n: i32) -> (Self, bool) }
+            trait_delegate! { fn overflowing_powf(self, exp: Self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_cbrt(self) -> (Self, bool) }
+            trait_delegate! { fn overflowing_nth_root(self, n: u32) -> (Self, bool) }
             trait_delegate! { fn overflowing_lerp(self, start: Self, end: Self) -> (Self, bool) }
             trait_delegate! {
                 fn overflowing_inv_lerp(self, start: Self, end: Self) -> (Self, bool)
@@ -4686,29 +6889,7 @@ macro_rules! impl_fixed {
             /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
             #[inline]
             fn saturating_from_fixed<F: Fixed>(src: F) -> Self {
-                let conv = src.to_fixed_helper(Private, Self::FRAC_NBITS, Self::INT_NBITS);
-                if conv.overflow {
-                    return if src < 0 { Self::MIN } else { Self::MAX };
-                }
-                let bits = if_signed_unsigned!(
-                    $Signedness,
-                    match conv.bits {
-                        Widest::Unsigned(bits) => {
-                            if (bits as $Bits) < 0 {
-                                return Self::MAX;
-                            }
-                            bits as $Bits
-                        }
-                        Widest::Negative(bits) => bits as $Bits,
-                    },
-                    match conv.bits {
-                        Widest::Unsigned(bits) => bits as $Bits,
-                        Widest::Negative(_) => {
-                            return Self::MIN;
-                        }
-                    },
-                );
-                Self::from_bits(bits)
+                from_fixed_body! { saturating, src, $LeEqU, $Bits, $Signedness }
             }
 
             /// Converts a fixed-point number, wrapping if it does not fit.
@@ -4729,28 +6910,7 @@ macro_rules! impl_fixed {
             /// Any extra fractional bits are discarded, which rounds towards &minus;∞.
             #[inline]
             fn overflowing_from_fixed<F: Fixed>(src: F) -> (Self, bool) {
-                let conv = src.to_fixed_helper(Private, Self::FRAC_NBITS, Self::INT_NBITS);
-                let mut new_overflow = false;
-                let bits = if_signed_unsigned!(
-                    $Signedness,
-                    match conv.bits {
-                        Widest::Unsigned(bits) => {
-                            if (bits as $Bits) < 0 {
-                                new_overflow = true;
-                            }
-                            bits as $Bits
-                        }
-                        Widest::Negative(bits) => bits as $Bits,
-                    },
-                    match conv.bits {
-                        Widest::Unsigned(bits) => bits as $Bits,
-                        Widest::Negative(bits) => {
-                            new_overflow = true;
-                            bits as $Bits
-                        }
-                    },
-                );
-                (Self::from_bits(bits), conv.overflow || new_overflow)
+                from_fixed_body! { overflowing, src, $LeEqU, $Bits, $Signedness }
             }
 
             /// Converts a fixed-point number, panicking if it does not fit.
@@ -4768,6 +6928,8 @@ macro_rules! impl_fixed {
                     (_, true) => panic!("overflow"),
                 }
             }
+
+            from_fixed_round! {}
         }
 
         impl<Frac: $LeEqU> ToFixed for $Fixed<Frac> {
@@ -4837,11 +6999,17 @@ macro_rules! impl_fixed {
             fn unwrapped_to_fixed<F: Fixed>(self) -> F {
                 FromFixed::unwrapped_from_fixed(self)
             }
+
+            #[inline]
+            fn to_wide(self, _: Private) -> Wide {
+                to_wide_body! { self, $LeEqU, $Bits }
+            }
         }
 
         if_signed! {
             $Signedness;
             impl<Frac: $LeEqU> FixedSigned for $Fixed<Frac> {
+                type Angle = $Fixed<$UAngleFrac>;
                 const TRY_NEG_ONE: Option<Self> = Self::TRY_NEG_ONE;
                 trait_delegate! { fn signed_bits(self) -> u32 }
                 trait_delegate! { fn is_positive(self) -> bool }
@@ -4852,6 +7020,16 @@ macro_rules! impl_fixed {
                 trait_delegate! { fn signum(self) -> Self }
                 trait_delegate! { fn add_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn sin(self) -> Self }
+                trait_delegate! { fn cos(self) -> Self }
+                trait_delegate! { fn sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn atan(self) -> Self::Angle }
+                trait_delegate! { fn atan2(self, other: Self) -> Self::Angle }
+                trait_delegate! { fn asin(self) -> Self }
+                trait_delegate! { fn acos(self) -> Self }
+                trait_delegate! { fn sinh(self) -> Self }
+                trait_delegate! { fn cosh(self) -> Self }
+                trait_delegate! { fn tanh(self) -> Self }
                 trait_delegate! { fn checked_abs(self) -> Option<Self> }
                 trait_delegate! { fn checked_signum(self) -> Option<Self> }
                 trait_delegate! {
@@ -4860,18 +7038,46 @@ macro_rules! impl_fixed {
                 trait_delegate! {
                     fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self>
                 }
+                trait_delegate! { fn checked_sin(self) -> Option<Self> }
+                trait_delegate! { fn checked_cos(self) -> Option<Self> }
+                trait_delegate! { fn checked_sin_cos(self) -> Option<(Self, Self)> }
+                trait_delegate! { fn checked_asin(self) -> Option<Self> }
+                trait_delegate! { fn checked_acos(self) -> Option<Self> }
+                trait_delegate! { fn checked_sinh(self) -> Option<Self> }
+                trait_delegate! { fn checked_cosh(self) -> Option<Self> }
                 trait_delegate! { fn saturating_abs(self) -> Self }
                 trait_delegate! { fn saturating_signum(self) -> Self }
                 trait_delegate! { fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn saturating_sin(self) -> Self }
+                trait_delegate! { fn saturating_cos(self) -> Self }
+                trait_delegate! { fn saturating_sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn saturating_asin(self) -> Self }
+                trait_delegate! { fn saturating_acos(self) -> Self }
+                trait_delegate! { fn saturating_sinh(self) -> Self }
+                trait_delegate! { fn saturating_cosh(self) -> Self }
                 trait_delegate! { fn wrapping_abs(self) -> Self }
                 trait_delegate! { fn wrapping_signum(self) -> Self }
                 trait_delegate! { fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn wrapping_sin(self) -> Self }
+                trait_delegate! { fn wrapping_cos(self) -> Self }
+                trait_delegate! { fn wrapping_sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn wrapping_asin(self) -> Self }
+                trait_delegate! { fn wrapping_acos(self) -> Self }
+                trait_delegate! { fn wrapping_sinh(self) -> Self }
+                trait_delegate! { fn wrapping_cosh(self) -> Self }
                 trait_delegate! { fn unwrapped_abs(self) -> Self }
                 trait_delegate! { fn unwrapped_signum(self) -> Self }
                 trait_delegate! { fn unwrapped_add_unsigned(self, rhs: Self::Unsigned) -> Self }
                 trait_delegate! { fn unwrapped_sub_unsigned(self, rhs: Self::Unsigned) -> Self }
+                trait_delegate! { fn unwrapped_sin(self) -> Self }
+                trait_delegate! { fn unwrapped_cos(self) -> Self }
+                trait_delegate! { fn unwrapped_sin_cos(self) -> (Self, Self) }
+                trait_delegate! { fn unwrapped_asin(self) -> Self }
+                trait_delegate! { fn unwrapped_acos(self) -> Self }
+                trait_delegate! { fn unwrapped_sinh(self) -> Self }
+                trait_delegate! { fn unwrapped_cosh(self) -> Self }
                 trait_delegate! { fn overflowing_abs(self) -> (Self, bool) }
                 trait_delegate! { fn overflowing_signum(self) -> (Self, bool) }
                 trait_delegate! {
@@ -4880,6 +7086,13 @@ macro_rules! impl_fixed {
                 trait_delegate! {
                     fn overflowing_sub_unsigned(self, rhs: Self::Unsigned) -> (Self, bool)
                 }
+                trait_delegate! { fn overflowing_sin(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_cos(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_sin_cos(self) -> ((Self, Self), bool) }
+                trait_delegate! { fn overflowing_asin(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_acos(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_sinh(self) -> (Self, bool) }
+                trait_delegate! { fn overflowing_cosh(self) -> (Self, bool) }
             }
         }
 
@@ -4914,13 +7127,17 @@ macro_rules! impl_fixed {
     };
 }
 
-impl_fixed! { FixedI8, FixedI8, FixedU8, LeEqU8, i8, Signed }
-impl_fixed! { FixedI16, FixedI16, FixedU16, LeEqU16, i16, Signed }
-impl_fixed! { FixedI32, FixedI32, FixedU32, LeEqU32, i32, Signed }
-impl_fixed! { FixedI64, FixedI64, FixedU64, LeEqU64, i64, Signed }
-impl_fixed! { FixedI128, FixedI128, FixedU128, LeEqU128, i128, Signed }
-impl_fixed! { FixedU8, FixedI8, FixedU8, LeEqU8, u8, Unsigned }
-impl_fixed! { FixedU16, FixedI16, FixedU16, LeEqU16, u16, Unsigned }
-impl_fixed! { FixedU32, FixedI32, FixedU32, LeEqU32, u32, Unsigned }
-impl_fixed! { FixedU64, FixedI64, FixedU64, LeEqU64, u64, Unsigned }
-impl_fixed! { FixedU128, FixedI128, FixedU128, LeEqU128, u128, Unsigned }
+impl_fixed! { FixedI8, FixedI8, FixedU8, LeEqU8, i8, NonZero<i8>, Signed, U5 }
+impl_fixed! { FixedI16, FixedI16, FixedU16, LeEqU16, i16, NonZero<i16>, Signed, U13 }
+impl_fixed! { FixedI32, FixedI32, FixedU32, LeEqU32, i32, NonZero<i32>, Signed, U29 }
+impl_fixed! { FixedI64, FixedI64, FixedU64, LeEqU64, i64, NonZero<i64>, Signed, U61 }
+impl_fixed! { FixedI128, FixedI128, FixedU128, LeEqU128, i128, NonZero<i128>, Signed, U125 }
+impl_fixed! { FixedU8, FixedI8, FixedU8, LeEqU8, u8, NonZero<u8>, Unsigned, U5 }
+impl_fixed! { FixedU16, FixedI16, FixedU16, LeEqU16, u16, NonZero<u16>, Unsigned, U13 }
+impl_fixed! { FixedU32, FixedI32, FixedU32, LeEqU32, u32, NonZero<u32>, Unsigned, U29 }
+impl_fixed! { FixedU64, FixedI64, FixedU64, LeEqU64, u64, NonZero<u64>, Unsigned, U61 }
+impl_fixed! { FixedU128, FixedI128, FixedU128, LeEqU128, u128, NonZero<u128>, Unsigned, U125 }
+impl_fixed! { FixedI256, FixedI256, FixedU256, LeEqU256, I256Bits, NonZeroI256Bits, Signed, U253 }
+impl_fixed! {
+    FixedU256, FixedI256, FixedU256, LeEqU256, U256Bits, NonZeroU256Bits, Unsigned, U253
+}
@@ -14,6 +14,7 @@
 // <https://opensource.org/licenses/MIT>.
 
 use crate::traits::FixedEquiv;
+use crate::{I256Bits, U256Bits};
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
 use az::{
@@ -52,10 +53,13 @@ use serde::{de::Deserialize, ser::Serialize};
 
 macro_rules! impl_bits {
     ($Bits:ident) => {
+        impl_bits! { $Bits, $Bits::MIN != 0 }
+    };
+    ($Bits:ident, $IS_SIGNED:expr) => {
         impl FixedBits for $Bits {
             const MIN: $Bits = $Bits::MIN;
             const MAX: $Bits = $Bits::MAX;
-            const IS_SIGNED: bool = $Bits::MIN != 0;
+            const IS_SIGNED: bool = $IS_SIGNED;
             const BITS: u32 = $Bits::BITS;
         }
         impl Sealed for $Bits {}
@@ -84,8 +88,9 @@ macro_rules! impl_bits {
 /// This provides some facilities to manipulate bits in generic functions.
 ///
 /// This trait is sealed and cannot be implemented for more types; it is
-/// implemented for [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`u8`],
-/// [`u16`], [`u32`], [`u64`], and [`u128`].
+/// implemented for [`i8`], [`i16`], [`i32`], [`i64`], [`i128`],
+/// [`I256Bits`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], and
+/// [`U256Bits`].
 ///
 /// # Examples
 ///
@@ -176,6 +181,8 @@ impl_bits! { u16 }
 impl_bits! { u32 }
 impl_bits! { u64 }
 impl_bits! { u128 }
+impl_bits! { I256Bits, true }
+impl_bits! { U256Bits, false }
 
 #[cfg(not(feature = "arbitrary"))]
 /// This trait is used to provide supertrait to the [`FixedBits`] trait
@@ -0,0 +1,1447 @@
+// Copyright © 2018–2025 Trevor Spiteri
+
+// This library is free software: you can redistribute it and/or
+// modify it under the terms of either
+//
+//   * the Apache License, Version 2.0 or
+//   * the MIT License
+//
+// at your option.
+//
+// You should have recieved copies of the Apache License and the MIT
+// License along with the library. If not, see
+// <https://www.apache.org/licenses/LICENSE-2.0> and
+// <https://opensource.org/licenses/MIT>.
+
+use crate::consts;
+use crate::fixed256::float;
+use crate::int256;
+use crate::int256::{I256, U256, U512};
+use crate::sqrt;
+use core::num::NonZero;
+
+// The trigonometric functions are computed using the CORDIC algorithm.
+//
+// Values are stored with two integer bits (including the sign bit) and the
+// remaining bits are fractional bits. The wide functions, which are used for
+// 128-bit fixed-point numbers, have 254 fractional bits inside an I256. The
+// narrow functions, which are used for all other fixed-point numbers, have 126
+// fractional bits inside an i128, which is more than enough as those
+// fixed-point numbers have at most 64 fractional bits.
+//
+// The argument is first reduced by multiples of π/2, not by multiples of
+// consts::PI or consts::TAU, to the range −π/4 ≤ r ≤ π/4 such that
+// x = r + q × π/2. Since there is no need for the reduced argument to be
+// exactly within range (CORDIC converges for |r| < 1.74), q is found using
+// consts::FRAC_2_PI and the remainder is found using consts::FRAC_PI_2. For
+// 128-bit fixed-point numbers with a large integer part, the 127 fractional
+// bits of consts::FRAC_PI_2 limit the accuracy of r, which is why the error
+// bound is larger for 128-bit fixed-point numbers.
+//
+// After reduction, CORDIC is performed with frac_nbits + 4 iterations. After
+// iteration i, the remaining angle is ≤ atan(2^-i), so the remaining angle is
+// < 2^-(frac_nbits + 3), which is less than one eighth of the result’s DELTA.
+
+const WIDE_FRAC_NBITS: u32 = 254;
+const NARROW_FRAC_NBITS: u32 = 126;
+const EXTRA_ITERS: u32 = 4;
+
+/*
+```rust
+use rug::{Assign, Float, Integer};
+
+fn u256(val: Float) -> String {
+    let int = (val << 254u32).to_integer().unwrap();
+    let lo = Integer::from(&int & ((Integer::from(1) << 128u32) - 1u32));
+    let hi = Integer::from(&int >> 128u32);
+    let hex = |i: Integer| {
+        let mut s = format!("0x{i:032X}");
+        for i in 0..7 {
+            s.insert(6 + 5 * i, '_');
+        }
+        s
+    };
+    format!("U256 {{\n    lo: {},\n    hi: {},\n}}", hex(lo), hex(hi))
+}
+
+fn float<T>(t: T) -> Float
+where
+    Float: Assign<T>,
+{
+    Float::with_val(1000, t)
+}
+
+fn main() {
+    for i in 0..85 {
+        println!("{},", u256((float(1) >> i).atan()));
+    }
+    let mut k = float(1);
+    for i in 0..500 {
+        k /= (float(1) + (float(1) >> (2 * i))).sqrt();
+    }
+    println!("{}", u256(k));
+}
+```
+*/
+
+// ATAN_TABLE[i] = atan(2^-i) with 254 fractional bits, rounded to the nearest.
+// For i ≥ 85, atan(2^-i) rounded to the nearest is 2^-i.
+const ATAN_TABLE: [U256; 85] = [
+    U256 {
+        lo: 0x4A40_9382_2299_F31D_0082_EFA9_8EC4_E6C9,
+        hi: 0x3243_F6A8_885A_308D_3131_98A2_E037_0734,
+    },
+    U256 {
+        lo: 0x6DC2_82B0_E4C3_9BE0_1C59_E2DC_DD2C_48E1,
+        hi: 0x1DAC_6705_61BB_4F68_ADFC_88BD_9787_51A0,
+    },
+    U256 {
+        lo: 0x5AA7_FA90_388B_3836_B7A3_A767_C944_9A76,
+        hi: 0x0FAD_BAFC_9640_6EB1_56DC_79EF_5F7A_217E,
+    },
+    U256 {
+        lo: 0xBC59_CABE_CAEC_D6C9_1764_9ABB_EFDB_60BB,
+        hi: 0x07F5_6EA6_AB0B_DB71_9644_BCC4_F9F4_4477,
+    },
+    U256 {
+        lo: 0x44C6_704B_3D67_52CB_70E1_EA7E_00F1_2E2C,
+        hi: 0x03FE_AB76_E59F_BD38_DB2C_9E4B_7038_B835,
+    },
+    U256 {
+        lo: 0x2700_DA05_2981_B710_2C8E_02FB_689A_C20C,
+        hi: 0x01FF_D55B_BA97_624A_84EF_3AEE_DBB5_18C4,
+    },
+    U256 {
+        lo: 0x4820_EF65_C10D_EEF4_6069_5F80_3275_61CC,
+        hi: 0x00FF_FAAA_DDDB_94D5_BBE7_8C56_4015_F760,
+    },
+    U256 {
+        lo: 0xA3B4_2B50_0B8D_1780_0D41_E8AB_B3F7_6728,
+        hi: 0x007F_FF55_56EE_EA5C_B403_11A8_FDDF_3057,
+    },
+    U256 {
+        lo: 0x859F_062E_BAE6_F255_E248_922B_49A0_AFBE,
+        hi: 0x003F_FFEA_AAB7_776E_52EC_4ABE_DADB_53DF,
+    },
+    U256 {
+        lo: 0x3A3C_9027_FB88_E202_FB19_DE0E_8B60_FB8A,
+        hi: 0x001F_FFFD_5555_BBBB_A972_9AB7_AAC0_8947,
+    },
+    U256 {
+        lo: 0x97CD_36F0_EAB2_84C4_4F3A_0D64_5758_3B9A,
+        hi: 0x000F_FFFF_AAAA_ADDD_DDB9_4B96_8067_EF3A,
+    },
+    U256 {
+        lo: 0xA09E_70D6_5314_85A5_EA15_620C_615B_2116,
+        hi: 0x0007_FFFF_F555_556E_EEEE_A5CA_5D89_5892,
+    },
+    U256 {
+        lo: 0x6EF9_DE29_D445_FE4A_3046_2955_3C21_FBD2,
+        hi: 0x0003_FFFF_FEAA_AAAB_7777_76E5_2E53_56F5,
+    },
+    U256 {
+        lo: 0x0D00_C46A_1818_549C_8F11_E57B_8D1A_DF58,
+        hi: 0x0001_FFFF_FFD5_5555_5BBB_BBBA_9729_72D0,
+    },
+    U256 {
+        lo: 0xB12B_12AF_B6B5_99F8_F277_E09B_955C_4D8D,
+        hi: 0x0000_FFFF_FFFA_AAAA_AADD_DDDD_DB94_B94B,
+    },
+    U256 {
+        lo: 0xCA6A_DEAD_EAB0_2247_F691_462B_A669_DAFB,
+        hi: 0x0000_7FFF_FFFF_5555_5556_EEEE_EEEA_5CA5,
+    },
+    U256 {
+        lo: 0xE52E_5A01_A019_FBCE_A5A4_8DC0_7EF0_A4F5,
+        hi: 0x0000_3FFF_FFFF_EAAA_AAAA_B777_7777_6E52,
+    },
+    U256 {
+        lo: 0x7297_2976_2562_5624_A827_99BF_94CD_A9F2,
+        hi: 0x0000_1FFF_FFFF_FD55_5555_55BB_BBBB_BBA9,
+    },
+    U256 {
+        lo: 0xB94B_94B9_4D5B_D5BD_5BBE_778A_6161_7FA4,
+        hi: 0x0000_0FFF_FFFF_FFAA_AAAA_AAAD_DDDD_DDDD,
+    },
+    U256 {
+        lo: 0xEEA5_CA5C_A5CB_4034_0340_311A_8605_77A7,
+        hi: 0x0000_07FF_FFFF_FFF5_5555_5555_6EEE_EEEE,
+    },
+    U256 {
+        lo: 0x7776_E52E_52E5_2EC4_AC4A_C4AB_EDAD_6679,
+        hi: 0x0000_03FF_FFFF_FFFE_AAAA_AAAA_AB77_7777,
+    },
+    U256 {
+        lo: 0xBBBB_BA97_2972_9729_AB7A_B7AB_7AAC_0892,
+        hi: 0x0000_01FF_FFFF_FFFF_D555_5555_555B_BBBB,
+    },
+    U256 {
+        lo: 0xDDDD_DDDB_94B9_4B94_B968_0680_6806_7EF4,
+        hi: 0x0000_00FF_FFFF_FFFF_FAAA_AAAA_AAAA_DDDD,
+    },
+    U256 {
+        lo: 0xEEEE_EEEE_EA5C_A5CA_5CA5_D895_8958_9589,
+        hi: 0x0000_007F_FFFF_FFFF_FF55_5555_5555_56EE,
+    },
+    U256 {
+        lo: 0x7777_7777_776E_52E5_2E52_E535_6F56_F56F,
+        hi: 0x0000_003F_FFFF_FFFF_FFEA_AAAA_AAAA_AAB7,
+    },
+    U256 {
+        lo: 0xBBBB_BBBB_BBBB_A972_9729_7297_2D00_D00D,
+        hi: 0x0000_001F_FFFF_FFFF_FFFD_5555_5555_5555,
+    },
+    U256 {
+        lo: 0xADDD_DDDD_DDDD_DDB9_4B94_B94B_94BB_12B1,
+        hi: 0x0000_000F_FFFF_FFFF_FFFF_AAAA_AAAA_AAAA,
+    },
+    U256 {
+        lo: 0x556E_EEEE_EEEE_EEEE_A5CA_5CA5_CA5C_A6AE,
+        hi: 0x0000_0007_FFFF_FFFF_FFFF_F555_5555_5555,
+    },
+    U256 {
+        lo: 0xAAAB_7777_7777_7777_76E5_2E52_E52E_52E6,
+        hi: 0x0000_0003_FFFF_FFFF_FFFF_FEAA_AAAA_AAAA,
+    },
+    U256 {
+        lo: 0x5555_5BBB_BBBB_BBBB_BBBA_9729_7297_2973,
+        hi: 0x0000_0001_FFFF_FFFF_FFFF_FFD5_5555_5555,
+    },
+    U256 {
+        lo: 0xAAAA_AADD_DDDD_DDDD_DDDD_DB94_B94B_94B9,
+        hi: 0x0000_0000_FFFF_FFFF_FFFF_FFFA_AAAA_AAAA,
+    },
+    U256 {
+        lo: 0x5555_5556_EEEE_EEEE_EEEE_EEEA_5CA5_CA5D,
+        hi: 0x0000_0000_7FFF_FFFF_FFFF_FFFF_5555_5555,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_B777_7777_7777_7777_6E52_E52E,
+        hi: 0x0000_0000_3FFF_FFFF_FFFF_FFFF_EAAA_AAAA,
+    },
+    U256 {
+        lo: 0x5555_5555_55BB_BBBB_BBBB_BBBB_BBA9_7297,
+        hi: 0x0000_0000_1FFF_FFFF_FFFF_FFFF_FD55_5555,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_AAAD_DDDD_DDDD_DDDD_DDDD_B94C,
+        hi: 0x0000_0000_0FFF_FFFF_FFFF_FFFF_FFAA_AAAA,
+    },
+    U256 {
+        lo: 0x5555_5555_5555_6EEE_EEEE_EEEE_EEEE_EEA6,
+        hi: 0x0000_0000_07FF_FFFF_FFFF_FFFF_FFF5_5555,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_AAAA_AB77_7777_7777_7777_7777,
+        hi: 0x0000_0000_03FF_FFFF_FFFF_FFFF_FFFE_AAAA,
+    },
+    U256 {
+        lo: 0x5555_5555_5555_555B_BBBB_BBBB_BBBB_BBBC,
+        hi: 0x0000_0000_01FF_FFFF_FFFF_FFFF_FFFF_D555,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_AAAA_AAAA_DDDD_DDDD_DDDD_DDDE,
+        hi: 0x0000_0000_00FF_FFFF_FFFF_FFFF_FFFF_FAAA,
+    },
+    U256 {
+        lo: 0x5555_5555_5555_5555_56EE_EEEE_EEEE_EEEF,
+        hi: 0x0000_0000_007F_FFFF_FFFF_FFFF_FFFF_FF55,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_AAAA_AAAA_AAB7_7777_7777_7777,
+        hi: 0x0000_0000_003F_FFFF_FFFF_FFFF_FFFF_FFEA,
+    },
+    U256 {
+        lo: 0x5555_5555_5555_5555_5555_BBBB_BBBB_BBBC,
+        hi: 0x0000_0000_001F_FFFF_FFFF_FFFF_FFFF_FFFD,
+    },
+    U256 {
+        lo: 0xAAAA_AAAA_AAAA_AAAA_AAAA_ADDD_DDDD_DDDE,
+        hi: 0x0000_0000_000F_FFFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xF555_5555_5555_5555_5555_556E_EEEE_EEEF,
+        hi: 0x0000_0000_0007_FFFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFEAA_AAAA_AAAA_AAAA_AAAA_AAAB_7777_7777,
+        hi: 0x0000_0000_0003_FFFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFD5_5555_5555_5555_5555_5555_5BBB_BBBC,
+        hi: 0x0000_0000_0001_FFFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFA_AAAA_AAAA_AAAA_AAAA_AAAA_AADD_DDDE,
+        hi: 0x0000_0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_5555_5555_5555_5555_5555_5556_EEEF,
+        hi: 0x0000_0000_0000_7FFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_EAAA_AAAA_AAAA_AAAA_AAAA_AAAA_B777,
+        hi: 0x0000_0000_0000_3FFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FD55_5555_5555_5555_5555_5555_55BC,
+        hi: 0x0000_0000_0000_1FFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFAA_AAAA_AAAA_AAAA_AAAA_AAAA_AAAE,
+        hi: 0x0000_0000_0000_0FFF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFF5_5555_5555_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_07FF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFE_AAAA_AAAA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_03FF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_D555_5555_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_01FF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FAAA_AAAA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_00FF_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FF55_5555_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_007F_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFEA_AAAA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_003F_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFD_5555_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_001F_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_AAAA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_000F_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_F555_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_0007_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FEAA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0003_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFD5_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_0001_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFA_AAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_FFFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_5555_5555_5555_5555,
+        hi: 0x0000_0000_0000_0000_7FFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_EAAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_3FFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FD55_5555_5555_5555,
+        hi: 0x0000_0000_0000_0000_1FFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFAA_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_0FFF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFF5_5555_5555_5555,
+        hi: 0x0000_0000_0000_0000_07FF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFE_AAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_03FF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_D555_5555_5555,
+        hi: 0x0000_0000_0000_0000_01FF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FAAA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_00FF_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FF55_5555_5555,
+        hi: 0x0000_0000_0000_0000_007F_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFEA_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_003F_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFD_5555_5555,
+        hi: 0x0000_0000_0000_0000_001F_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_AAAA_AAAB,
+        hi: 0x0000_0000_0000_0000_000F_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_F555_5555,
+        hi: 0x0000_0000_0000_0000_0007_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FEAA_AAAB,
+        hi: 0x0000_0000_0000_0000_0003_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFD5_5555,
+        hi: 0x0000_0000_0000_0000_0001_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFA_AAAB,
+        hi: 0x0000_0000_0000_0000_0000_FFFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_5555,
+        hi: 0x0000_0000_0000_0000_0000_7FFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_EAAB,
+        hi: 0x0000_0000_0000_0000_0000_3FFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FD55,
+        hi: 0x0000_0000_0000_0000_0000_1FFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFAB,
+        hi: 0x0000_0000_0000_0000_0000_0FFF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFF5,
+        hi: 0x0000_0000_0000_0000_0000_07FF_FFFF_FFFF,
+    },
+    U256 {
+        lo: 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF,
+        hi: 0x0000_0000_0000_0000_0000_03FF_FFFF_FFFF,
+    },
+];
+
+// K = ∏ 1/√(1 + 2^-2i) with 254 fractional bits, rounded to the nearest. This
+// is the reciprocal of the CORDIC gain.
+const K: U256 = U256 {
+    lo: 0x70A2_3146_8EF6_7D12_6D20_0135_AB8E_AFD8,
+    hi: 0x26DD_3B6A_10D7_9699_FD7E_424A_F5FF_503A,
+};
+
+// Since WIDE_FRAC_NBITS = 128 + NARROW_FRAC_NBITS, the narrow values are the
+// high parts of the wide values.
+
+const fn narrow_atan(i: u32) -> i128 {
+    if i < 85 {
+        ATAN_TABLE[i as usize].hi as i128
+    } else {
+        1 << (NARROW_FRAC_NBITS - i)
+    }
+}
+
+const fn wide_atan(i: u32) -> I256 {
+    if i < 85 {
+        int256::u256_wrapping_as_i256(ATAN_TABLE[i as usize])
+    } else {
+        let one = U256 { lo: 1, hi: 0 };
+        int256::u256_wrapping_as_i256(int256::wrapping_shl_u256(one, WIDE_FRAC_NBITS - i))
+    }
+}
+
+// Returns r and q % 4 such that abs × 2^-frac_nbits = r + q × π/2 and
+// |r| is approximately ≤ π/4. r has WIDE_FRAC_NBITS fractional bits.
+const fn reduce(abs: u128, frac_nbits: u32) -> (I256, u32) {
+    // q = round(abs × 2^-frac_nbits × 2/π)
+    let prod = int256::wide_mul_u128(abs, consts::FRAC_2_PI.to_bits());
+    // prod has 128 + frac_nbits fractional bits; 2/π < 1, so prod < 2^255
+    let q_shift = 128 + frac_nbits;
+    let half = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, q_shift - 1);
+    let (rounded, carry) = int256::overflowing_add_u256(prod, half);
+    let q = if q_shift == 256 {
+        carry as u128
+    } else {
+        rounded.hi >> (q_shift - 128)
+    };
+
+    // |r| < 1, so we can work modulo 2^256.
+    // FRAC_PI_2 has 127 fractional bits, so q × FRAC_PI_2 must be shifted left
+    // by WIDE_FRAC_NBITS - 127.
+    let abs = U256 { lo: abs, hi: 0 };
+    let abs = int256::wrapping_shl_u256(abs, WIDE_FRAC_NBITS - frac_nbits);
+    let q_frac_pi_2 = int256::wide_mul_u128(q, consts::FRAC_PI_2.to_bits());
+    let q_frac_pi_2 = int256::wrapping_shl_u256(q_frac_pi_2, WIDE_FRAC_NBITS - 127);
+    let r = int256::wrapping_sub_u256(abs, q_frac_pi_2);
+    (int256::u256_wrapping_as_i256(r), (q % 4) as u32)
+}
+
+// Returns (cos, sin) rotating by angle z.
+const fn narrow_rotate(mut z: i128, iters: u32) -> (i128, i128) {
+    let mut x = K.hi as i128;
+    let mut y = 0;
+    let mut i = 0;
+    while i < iters {
+        let dx = y >> i;
+        let dy = x >> i;
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= narrow_atan(i);
+        } else {
+            x += dx;
+            y -= dy;
+            z += narrow_atan(i);
+        }
+        i += 1;
+    }
+    (x, y)
+}
+
+// Returns (cos, sin) rotating by angle z.
+const fn wide_rotate(mut z: I256, iters: u32) -> (I256, I256) {
+    let mut x = int256::u256_wrapping_as_i256(K);
+    let mut y = I256 { lo: 0, hi: 0 };
+    let mut i = 0;
+    while i < iters {
+        let dx = int256::shr_i256(y, i);
+        let dy = int256::shr_i256(x, i);
+        if z.hi >= 0 {
+            x = int256::wrapping_sub_i256(x, dx);
+            y = int256::wrapping_add_i256(y, dy);
+            z = int256::wrapping_sub_i256(z, wide_atan(i));
+        } else {
+            x = int256::wrapping_add_i256(x, dx);
+            y = int256::wrapping_sub_i256(y, dy);
+            z = int256::wrapping_add_i256(z, wide_atan(i));
+        }
+        i += 1;
+    }
+    (x, y)
+}
+
+// Applies quadrant q % 4 to (sin, cos) of reduced argument, then negates sin if
+// the original argument was negative.
+const fn narrow_quadrant(sin: i128, cos: i128, q: u32, neg: bool) -> (i128, i128) {
+    let (sin, cos) = match q {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+    (if neg { -sin } else { sin }, cos)
+}
+
+const fn wide_neg(a: I256) -> I256 {
+    int256::wrapping_sub_i256(I256 { lo: 0, hi: 0 }, a)
+}
+
+const fn wide_quadrant(sin: I256, cos: I256, q: u32, neg: bool) -> (I256, I256) {
+    let (sin, cos) = match q {
+        0 => (sin, cos),
+        1 => (cos, wide_neg(sin)),
+        2 => (wide_neg(sin), wide_neg(cos)),
+        _ => (wide_neg(cos), sin),
+    };
+    (if neg { wide_neg(sin) } else { sin }, cos)
+}
+
+// Rounds to frac_nbits ≤ 64 fractional bits.
+const fn narrow_round(val: i128, frac_nbits: u32) -> i128 {
+    let shift = NARROW_FRAC_NBITS - frac_nbits;
+    (val + (1 << (shift - 1))) >> shift
+}
+
+// Rounds to frac_nbits ≤ 128 fractional bits.
+const fn wide_round(val: I256, frac_nbits: u32) -> (i128, bool) {
+    let shift = WIDE_FRAC_NBITS - frac_nbits;
+    let half = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, shift - 1);
+    let rounded = int256::wrapping_add_i256(val, int256::u256_wrapping_as_i256(half));
+    let shifted = int256::shr_i256(rounded, shift);
+    let ret = shifted.lo as i128;
+    (ret, shifted.hi != ret >> 127)
+}
+
+// Returns (sin, cos) with frac_nbits ≤ 64 fractional bits.
+const fn narrow_sin_cos(val: i128, frac_nbits: u32) -> (i128, i128) {
+    let (r, q) = reduce(val.unsigned_abs(), frac_nbits);
+    let (cos, sin) = narrow_rotate(r.hi, frac_nbits + EXTRA_ITERS);
+    let (sin, cos) = narrow_quadrant(sin, cos, q, val < 0);
+    (narrow_round(sin, frac_nbits), narrow_round(cos, frac_nbits))
+}
+
+macro_rules! impl_sin_cos {
+    ($i:ident) => {
+        pub const fn $i(val: $i, frac_nbits: u32) -> (($i, bool), ($i, bool)) {
+            let (sin, cos) = trig::narrow_sin_cos(val as i128, frac_nbits);
+            let (wrapped_sin, wrapped_cos) = (sin as $i, cos as $i);
+            (
+                (wrapped_sin, wrapped_sin as i128 != sin),
+                (wrapped_cos, wrapped_cos as i128 != cos),
+            )
+        }
+    };
+}
+
+pub mod sin_cos {
+    use crate::int256::I256;
+    use crate::trig;
+
+    impl_sin_cos! { i8 }
+    impl_sin_cos! { i16 }
+    impl_sin_cos! { i32 }
+    impl_sin_cos! { i64 }
+
+    pub const fn i128(val: i128, frac_nbits: u32) -> ((i128, bool), (i128, bool)) {
+        let (r, q): (I256, u32) = trig::reduce(val.unsigned_abs(), frac_nbits);
+        let (cos, sin) = trig::wide_rotate(r, frac_nbits + trig::EXTRA_ITERS);
+        let (sin, cos) = trig::wide_quadrant(sin, cos, q, val < 0);
+        (
+            trig::wide_round(sin, frac_nbits),
+            trig::wide_round(cos, frac_nbits),
+        )
+    }
+}
+
+// The arctangent is computed using CORDIC in vectoring mode on the absolute
+// values of the arguments, which are first normalized so that the larger one
+// has its most significant bit at bit NARROW_FRAC_NBITS − 3 or
+// WIDE_FRAC_NBITS − 3, so that the CORDIC gain cannot overflow. The angle in
+// the first quadrant is then adjusted for the quadrant and rounded toward zero,
+// which keeps the functions odd and makes the results at the axis and diagonal
+// cases equal to the constants such as FRAC_PI_4.
+//
+// The result is rounded toward zero from an approximation that has an error of
+// less than 2^-(frac_nbits + ATAN_EXTRA_ITERS - 1), so that a wrong rounding is
+// only possible when the exact result is that close to a representable value.
+// Since atan of a rational number is irrational (except for atan 0), the axis
+// and diagonal cases are handled exactly before CORDIC.
+
+const ATAN_EXTRA_ITERS: u32 = 48;
+
+// Returns |atan2(y, x)| for the axis and diagonal cases, with
+// NARROW_FRAC_NBITS fractional bits, rounded down.
+const fn special_atan2_abs(y_zero: bool, x_zero: bool, equal: bool, x_neg: bool) -> Option<u128> {
+    let pi = consts::PI.to_bits();
+    let frac_pi_2 = consts::FRAC_PI_2.to_bits() >> 1;
+    let frac_pi_4 = consts::FRAC_PI_4.to_bits() >> 2;
+    if y_zero {
+        Some(if x_neg { pi } else { 0 })
+    } else if x_zero {
+        Some(frac_pi_2)
+    } else if equal {
+        // 3π/4 = π − π/4; rounding down π − π/4 with NARROW_FRAC_NBITS
+        // fractional bits can give a result one ULP too small, but the
+        // fixed-point angle types have at most NARROW_FRAC_NBITS − 1
+        // fractional bits, and 3π/4 is not that close to a representable value.
+        Some(if x_neg { pi - frac_pi_4 } else { frac_pi_4 })
+    } else {
+        None
+    }
+}
+
+// Returns |atan2(y, x)| with frac_nbits ≤ 64 fractional bits, rounded toward
+// zero. y and x are absolute values.
+const fn narrow_atan2_abs(y: u128, x: u128, x_neg: bool, frac_nbits: u32) -> u128 {
+    if let Some(special) = special_atan2_abs(y == 0, x == 0, y == x, x_neg) {
+        return special >> (NARROW_FRAC_NBITS - frac_nbits);
+    }
+    let max = if y > x { y } else { x };
+    let lz = max.leading_zeros();
+    let (mut y, mut x) = if lz >= 4 {
+        ((y << (lz - 4)) as i128, (x << (lz - 4)) as i128)
+    } else {
+        ((y >> (4 - lz)) as i128, (x >> (4 - lz)) as i128)
+    };
+    let mut z = 0i128;
+    let iters = frac_nbits + ATAN_EXTRA_ITERS;
+    let mut i = 0;
+    while i < iters {
+        let dx = y >> i;
+        let dy = x >> i;
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += narrow_atan(i);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= narrow_atan(i);
+        }
+        i += 1;
+    }
+    let z = if z < 0 { 0 } else { z as u128 };
+    let angle = if x_neg { consts::PI.to_bits() - z } else { z };
+    angle >> (NARROW_FRAC_NBITS - frac_nbits)
+}
+
+const fn wide_leading_zeros(a: U256) -> u32 {
+    if a.hi == 0 {
+        128 + a.lo.leading_zeros()
+    } else {
+        a.hi.leading_zeros()
+    }
+}
+
+const fn wide_eq(a: U256, b: U256) -> bool {
+    a.lo == b.lo && a.hi == b.hi
+}
+
+const fn wide_is_zero(a: U256) -> bool {
+    a.lo == 0 && a.hi == 0
+}
+
+// Returns |atan2(y, x)| with frac_nbits ≤ 128 fractional bits, rounded toward
+// zero, and whether the result overflows u128. y and x are absolute values
+// < 2^252.
+const fn wide_atan2_abs(y: U256, x: U256, x_neg: bool, frac_nbits: u32) -> (u128, bool) {
+    let special = special_atan2_abs(wide_is_zero(y), wide_is_zero(x), wide_eq(y, x), x_neg);
+    if let Some(special) = special {
+        let special = U256 { lo: special, hi: 0 };
+        let shifted = if frac_nbits <= NARROW_FRAC_NBITS {
+            int256::shl_u256_max_128(special, NARROW_FRAC_NBITS - frac_nbits)
+        } else {
+            int256::wrapping_shl_u256(special, frac_nbits - NARROW_FRAC_NBITS)
+        };
+        return (shifted.lo, shifted.hi != 0);
+    }
+    let max = if y.hi > x.hi || (y.hi == x.hi && y.lo > x.lo) {
+        y
+    } else {
+        x
+    };
+    let shift = wide_leading_zeros(max) - 4;
+    let mut y = int256::u256_wrapping_as_i256(int256::wrapping_shl_u256(y, shift));
+    let mut x = int256::u256_wrapping_as_i256(int256::wrapping_shl_u256(x, shift));
+    let mut z = I256 { lo: 0, hi: 0 };
+    let iters = frac_nbits + ATAN_EXTRA_ITERS;
+    let mut i = 0;
+    while i < iters {
+        let dx = int256::shr_i256(y, i);
+        let dy = int256::shr_i256(x, i);
+        if y.hi > 0 || (y.hi == 0 && y.lo > 0) {
+            x = int256::wrapping_add_i256(x, dx);
+            y = int256::wrapping_sub_i256(y, dy);
+            z = int256::wrapping_add_i256(z, wide_atan(i));
+        } else {
+            x = int256::wrapping_sub_i256(x, dx);
+            y = int256::wrapping_add_i256(y, dy);
+            z = int256::wrapping_sub_i256(z, wide_atan(i));
+        }
+        i += 1;
+    }
+    let z = if z.hi < 0 {
+        U256 { lo: 0, hi: 0 }
+    } else {
+        U256 {
+            lo: z.lo,
+            hi: z.hi as u128,
+        }
+    };
+    let angle = if x_neg {
+        // π = 4 × atan 1, with an error < 2^-(WIDE_FRAC_NBITS - 1)
+        let pi = int256::wrapping_shl_u256(ATAN_TABLE[0], 2);
+        int256::wrapping_sub_u256(pi, z)
+    } else {
+        z
+    };
+    let shift = WIDE_FRAC_NBITS - frac_nbits;
+    if shift >= 128 {
+        (angle.hi >> (shift - 128), false)
+    } else {
+        let shifted = int256::shl_u256_max_128(angle, shift);
+        (shifted.lo, shifted.hi != 0)
+    }
+}
+
+macro_rules! impl_atan2 {
+    ($i:ident) => {
+        pub const fn $i(y: $i, x: $i) -> $i {
+            let abs = trig::narrow_atan2_abs(
+                y.unsigned_abs() as u128,
+                x.unsigned_abs() as u128,
+                x < 0,
+                $i::BITS - 3,
+            ) as $i;
+            if y < 0 { -abs } else { abs }
+        }
+    };
+}
+
+// Returns atan2(y, x) with Inner::BITS − 3 fractional bits.
+pub mod atan2 {
+    use crate::int256::U256;
+    use crate::trig;
+
+    impl_atan2! { i8 }
+    impl_atan2! { i16 }
+    impl_atan2! { i32 }
+    impl_atan2! { i64 }
+
+    pub const fn i128(y: i128, x: i128) -> i128 {
+        let y_abs = U256 {
+            lo: y.unsigned_abs(),
+            hi: 0,
+        };
+        let x_abs = U256 {
+            lo: x.unsigned_abs(),
+            hi: 0,
+        };
+        let (abs, _) = trig::wide_atan2_abs(y_abs, x_abs, x < 0, i128::BITS - 3);
+        let abs = abs as i128;
+        if y < 0 { -abs } else { abs }
+    }
+}
+
+macro_rules! impl_atan {
+    ($i:ident) => {
+        pub const fn $i(val: $i, frac_nbits: u32) -> $i {
+            let abs = trig::narrow_atan2_abs(
+                val.unsigned_abs() as u128,
+                1 << frac_nbits,
+                false,
+                $i::BITS - 3,
+            ) as $i;
+            if val < 0 { -abs } else { abs }
+        }
+    };
+}
+
+// Returns atan(val) with Inner::BITS − 3 fractional bits.
+pub mod atan {
+    use crate::int256;
+    use crate::int256::U256;
+    use crate::trig;
+
+    impl_atan! { i8 }
+    impl_atan! { i16 }
+    impl_atan! { i32 }
+    impl_atan! { i64 }
+
+    pub const fn i128(val: i128, frac_nbits: u32) -> i128 {
+        let val_abs = U256 {
+            lo: val.unsigned_abs(),
+            hi: 0,
+        };
+        // frac_nbits can be 128, so 1 may not fit in 128 bits
+        let one = int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, frac_nbits);
+        let (abs, _) = trig::wide_atan2_abs(val_abs, one, false, i128::BITS - 3);
+        let abs = abs as i128;
+        if val < 0 { -abs } else { abs }
+    }
+}
+
+// The arcsine and arccosine are computed from the arctangent using
+// asin x = atan2(x, √(1 − x²)) and acos x = atan2(√(1 − x²), x). Since x has
+// frac_nbits fractional bits, 1 − x² is computed exactly with 2 × frac_nbits
+// fractional bits, and then its square root is computed with 64 guard bits
+// using crate::sqrt, or for 128-bit numbers, with 251 − frac_nbits guard bits
+// using isqrt_u512. Since atan2 does not depend on the scale of its arguments,
+// the returned x is scaled to match the square root.
+//
+// The error of atan2 is much smaller than DELTA, but it can still push a result
+// that is just above a representable value below it. This happens near the
+// origin for asin, where asin x − x ≈ x³/6, and near 1 for acos, where
+// acos x − √(2(1 − x)) ≈ (2(1 − x))^(3/2)/24. Since asin |x| ≥ |x| and
+// acos x ≥ √(2(1 − x)) for 0 ≤ x ≤ 1, the results are raised to at least those
+// bounds rounded down. For frac_nbits ≤ 64, acos x − √(2(1 − x)) is always
+// larger than the error, so only the wide acos needs the second bound.
+
+// Returns (x, √(1 − x²)) scaled for atan2. abs ≤ 1 << frac_nbits.
+const fn narrow_asin_parts(abs: u128, frac_nbits: u32) -> (u128, u128) {
+    // for x = 0, 1 − x² = 1 would wrap to 0 when frac_nbits == 64
+    if abs == 0 {
+        return (0, 1);
+    }
+    // 1 − x², where 1 wraps to 0 when frac_nbits == 64 and |x| < 1
+    let one = if frac_nbits == 64 {
+        0
+    } else {
+        1u128 << (2 * frac_nbits)
+    };
+    let Some(v) = NonZero::new(one.wrapping_sub(abs * abs)) else {
+        return (abs, 0);
+    };
+    // √(v × 2^128) = √(1 − x²) × 2^(frac_nbits + 64)
+    (abs << 64, sqrt::u128(v, 128))
+}
+
+// Returns (x, √(1 − x²)) scaled for atan2. abs ≤ 1 << frac_nbits.
+const fn wide_asin_parts(abs: u128, frac_nbits: u32) -> (U256, U256) {
+    // for x = 0, 1 − x² = 1 would wrap to 0 when frac_nbits == 128
+    if abs == 0 {
+        return (U256::ZERO, U256 { lo: 1, hi: 0 });
+    }
+    let abs256 = U256 { lo: abs, hi: 0 };
+    // 1 − x², where 1 wraps to 0 when frac_nbits == 128 and |x| < 1
+    let one = if frac_nbits == 128 {
+        U256::ZERO
+    } else {
+        int256::wrapping_shl_u256(U256 { lo: 1, hi: 0 }, 2 * frac_nbits)
+    };
+    let v = U512 {
+        lo: int256::wrapping_sub_u256(one, int256::wide_mul_u128(abs, abs)),
+        hi: U256::ZERO,
+    };
+    // √(v × 2^(2 × shift)) = √(1 − x²) × 2^251, and x × 2^shift ≤ 2^251, so
+    // both parts are < 2^252 as required by wide_atan2_abs
+    let shift = 251 - frac_nbits;
+    let sqrt = float::isqrt_u512(false, int256::wrapping_shl_u512(v, 2 * shift)).lo;
+    (int256::wrapping_shl_u256(abs256, shift), sqrt)
+}
+
+// Returns floor(√(2(1 − x))), which is a lower bound for acos x. 0 ≤ abs ≤ 1 << frac_nbits.
+const fn wide_acos_bound(abs: u128, frac_nbits: u32) -> U256 {
+    // 2(1 − x) ≤ 2 with frac_nbits fractional bits
+    let two = int256::wrapping_shl_u256(U256 { lo: 2, hi: 0 }, frac_nbits);
+    let abs256 = U256 { lo: abs, hi: 0 };
+    let v = int256::wrapping_sub_u256(int256::wrapping_sub_u256(two, abs256), abs256);
+    // √(v × 2^frac_nbits) = √(2(1 − x)) × 2^frac_nbits
+    let v = U512 {
+        lo: v,
+        hi: U256::ZERO,
+    };
+    float::isqrt_u512(false, int256::wrapping_shl_u512(v, frac_nbits)).lo
+}
+
+// Raises the magnitude and overflow flag returned by wide_atan2_abs to at least bound.
+const fn wide_at_least((abs, overflow): (u128, bool), bound: U256) -> (u128, bool) {
+    if overflow || (bound.hi == 0 && bound.lo <= abs) {
+        (abs, overflow)
+    } else {
+        (bound.lo, bound.hi != 0)
+    }
+}
+
+// Converts the magnitude and overflow flag returned by wide_atan2_abs to a signed
+// number and overflow flag.
+const fn wide_signed((abs, overflow): (u128, bool), neg: bool) -> (i128, bool) {
+    if neg {
+        (
+            (abs as i128).wrapping_neg(),
+            overflow || abs > i128::MIN.unsigned_abs(),
+        )
+    } else {
+        (abs as i128, overflow || abs > i128::MAX as u128)
+    }
+}
+
+macro_rules! impl_asin_acos {
+    ($i:ident) => {
+        pub const fn $i(val: $i, frac_nbits: u32) -> Option<(($i, bool), ($i, bool))> {
+            let abs = val.unsigned_abs() as u128;
+            if frac_nbits < $i::BITS - 1 && abs > 1 << frac_nbits {
+                return None;
+            }
+            let (x, sqrt) = trig::narrow_asin_parts(abs, frac_nbits);
+            // the magnitudes are < 2^66, so they fit in i128
+            let asin = trig::narrow_atan2_abs(x, sqrt, false, frac_nbits);
+            // asin |x| ≥ |x|
+            let asin = if asin < abs { abs } else { asin } as i128;
+            let asin = if val < 0 { -asin } else { asin };
+            let acos = trig::narrow_atan2_abs(sqrt, x, val < 0, frac_nbits) as i128;
+            let (wrapped_asin, wrapped_acos) = (asin as $i, acos as $i);
+            Some((
+                (wrapped_asin, wrapped_asin as i128 != asin),
+                (wrapped_acos, wrapped_acos as i128 != acos),
+            ))
+        }
+    };
+}
+
+// Returns Some(((asin, overflow), (acos, overflow))), or None if the argument is
+// outside the range −1 ≤ x ≤ 1.
+pub mod asin_acos {
+    use crate::int256::U256;
+    use crate::trig;
+
+    impl_asin_acos! { i8 }
+    impl_asin_acos! { i16 }
+    impl_asin_acos! { i32 }
+    impl_asin_acos! { i64 }
+
+    pub const fn i128(val: i128, frac_nbits: u32) -> Option<((i128, bool), (i128, bool))> {
+        let abs = val.unsigned_abs();
+        if frac_nbits < i128::BITS - 1 && abs > 1 << frac_nbits {
+            return None;
+        }
+        let (x, sqrt) = trig::wide_asin_parts(abs, frac_nbits);
+        let asin = trig::wide_atan2_abs(x, sqrt, false, frac_nbits);
+        // asin |x| ≥ |x|
+        let asin = trig::wide_at_least(asin, U256 { lo: abs, hi: 0 });
+        let acos = trig::wide_atan2_abs(sqrt, x, val < 0, frac_nbits);
+        let acos = if val < 0 {
+            acos
+        } else {
+            trig::wide_at_least(acos, trig::wide_acos_bound(abs, frac_nbits))
+        };
+        Some((
+            trig::wide_signed(asin, val < 0),
+            trig::wide_signed(acos, false),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consts;
+    use crate::types::extra::{
+        U0, U4, U5, U6, U7, U8, U12, U13, U15, U16, U20, U28, U29, U30, U31, U32, U48, U61, U62,
+        U64, U112, U124, U125, U126, U127, U128, U252,
+    };
+    use crate::{FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU128};
+
+    macro_rules! check_f64 {
+        ($val:expr, $max_err_deltas:expr) => {{
+            let val = $val;
+            let f = val.to_num::<f64>();
+            let delta = val.wrapping_neg().wrapping_sub(!val).to_num::<f64>();
+            let max_err = delta * $max_err_deltas;
+            let (sin, sin_overflow) = val.overflowing_sin();
+            let (cos, cos_overflow) = val.overflowing_cos();
+            assert!(!sin_overflow);
+            assert_eq!(val.overflowing_sin_cos(), ((sin, cos), cos_overflow));
+            assert!((sin.to_num::<f64>() - f.sin()).abs() < max_err, "sin {val}");
+            if !cos_overflow {
+                assert!((cos.to_num::<f64>() - f.cos()).abs() < max_err, "cos {val}");
+            }
+        }};
+    }
+
+    #[test]
+    fn exhaustive_8() {
+        for bits in i8::MIN..=i8::MAX {
+            check_f64!(FixedI8::<U0>::from_bits(bits), 1.0);
+            check_f64!(FixedI8::<U4>::from_bits(bits), 1.0);
+            check_f64!(FixedI8::<U7>::from_bits(bits), 1.0);
+            check_f64!(FixedI8::<U8>::from_bits(bits), 1.0);
+        }
+    }
+
+    #[test]
+    fn exhaustive_16() {
+        for bits in i16::MIN..=i16::MAX {
+            check_f64!(FixedI16::<U0>::from_bits(bits), 1.0);
+            check_f64!(FixedI16::<U8>::from_bits(bits), 1.0);
+            check_f64!(FixedI16::<U12>::from_bits(bits), 1.0);
+            check_f64!(FixedI16::<U15>::from_bits(bits), 1.0);
+            check_f64!(FixedI16::<U16>::from_bits(bits), 1.0);
+        }
+    }
+
+    #[test]
+    fn sample_32_64() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            check_f64!(FixedI32::<U16>::from_bits(bits), 1.0);
+            check_f64!(FixedI32::<U28>::from_bits(bits), 1.0);
+            check_f64!(FixedI32::<U30>::from_bits(bits), 1.0);
+            check_f64!(FixedI64::<U20>::from_bits(i64::from(bits) << 20), 1.0);
+            check_f64!(FixedI64::<U28>::from_bits(i64::from(bits) << 8), 1.0);
+        }
+    }
+
+    #[test]
+    fn sample_128() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            check_f64!(FixedI128::<U16>::from_bits(i128::from(bits)), 2.0);
+            check_f64!(FixedI128::<U20>::from_bits(i128::from(bits) << 80), 2.0);
+            check_f64!(FixedI128::<U28>::from_bits(i128::from(bits)), 2.0);
+        }
+    }
+
+    #[test]
+    fn narrow_and_wide_agree() {
+        for i in -10_000i64..=10_000 {
+            let bits = i.wrapping_mul(6_364_136_223_846_793_005) >> 8;
+            let narrow = FixedI64::<U48>::from_bits(bits);
+            let wide = FixedI128::<U48>::from_num(narrow);
+            let (narrow_sin, narrow_cos) = narrow.sin_cos();
+            let (wide_sin, wide_cos) = wide.sin_cos();
+            let diff_sin = (FixedI128::<U48>::from_num(narrow_sin) - wide_sin).abs();
+            let diff_cos = (FixedI128::<U48>::from_num(narrow_cos) - wide_cos).abs();
+            assert!(diff_sin <= FixedI128::<U48>::DELTA);
+            assert!(diff_cos <= FixedI128::<U48>::DELTA);
+        }
+    }
+
+    #[test]
+    fn high_precision_and_overflow() {
+        type I2F62 = FixedI64<U62>;
+        type I64F64 = FixedI128<U64>;
+        let one = I64F64::ONE;
+        for i in -1000..=1000 {
+            let narrow = I2F62::from_bits(i64::from(i) << 52);
+            let (sin, cos) = narrow.sin_cos();
+            let sum = sin.wide_mul(sin) + cos.wide_mul(cos);
+            let err = (sum - FixedI128::<U124>::ONE).abs();
+            assert!(err < I2F62::DELTA * 4);
+
+            let wide = I64F64::from_num(i) / 3;
+            let (sin, cos) = wide.sin_cos();
+            let sum = (sin * sin + cos * cos - one).abs();
+            assert!(sum <= I64F64::DELTA * 4);
+        }
+
+        // cos overflows to -1 for 1 integer bit, to 0 for no integer bits
+        assert_eq!(FixedI32::<U30>::ZERO.checked_cos(), Some(FixedI32::ONE));
+        assert_eq!(
+            FixedI32::<U31>::ZERO.overflowing_cos(),
+            (FixedI32::NEG_ONE, true)
+        );
+        assert_eq!(
+            FixedI32::<U32>::ZERO.overflowing_cos(),
+            (FixedI32::ZERO, true)
+        );
+        for val in [FixedI128::<U0>::MIN, FixedI128::<U0>::MAX] {
+            let (sin, cos) = val.sin_cos();
+            assert!(sin.abs() <= 1 && cos.abs() <= 1);
+        }
+        let sin = FixedI128::<U128>::MIN.sin();
+        let err = (sin - FixedI128::<U128>::from_num(-0.479_425_538_604_203)).abs();
+        assert!(err < 1e-15);
+    }
+
+    macro_rules! check_atan_f64 {
+        ($angle:expr, $exact:expr, $delta:expr) => {{
+            let angle = $angle.to_num::<f64>();
+            let exact: f64 = $exact;
+            // rounded toward zero
+            assert!(angle.abs() <= exact.abs() + 1e-12, "{angle} {exact}");
+            assert!(
+                exact.abs() - angle.abs() < $delta + 1e-12,
+                "{angle} {exact}"
+            );
+            assert!(angle == 0.0 || angle.is_sign_negative() == exact.is_sign_negative());
+        }};
+    }
+
+    #[test]
+    fn atan2_exhaustive_8() {
+        type Angle = FixedI8<U5>;
+        let delta = Angle::DELTA.to_num::<f64>();
+        for y in i8::MIN..=i8::MAX {
+            for x in i8::MIN..=i8::MAX {
+                let fy = FixedI8::<U4>::from_bits(y);
+                let fx = FixedI8::<U4>::from_bits(x);
+                let exact = f64::from(y).atan2(f64::from(x));
+                check_atan_f64!(fy.atan2(fx), exact, delta);
+            }
+            check_atan_f64!(
+                FixedI8::<U0>::from_bits(y).atan(),
+                f64::from(y).atan(),
+                delta
+            );
+            let val = FixedI8::<U8>::from_bits(y);
+            check_atan_f64!(val.atan(), val.to_num::<f64>().atan(), delta);
+        }
+    }
+
+    #[test]
+    fn atan_exhaustive_16() {
+        type Angle = FixedI16<U13>;
+        let delta = Angle::DELTA.to_num::<f64>();
+        for bits in i16::MIN..=i16::MAX {
+            let val = FixedI16::<U0>::from_bits(bits);
+            check_atan_f64!(val.atan(), val.to_num::<f64>().atan(), delta);
+            let val = FixedI16::<U8>::from_bits(bits);
+            check_atan_f64!(val.atan(), val.to_num::<f64>().atan(), delta);
+            let val = FixedI16::<U16>::from_bits(bits);
+            check_atan_f64!(val.atan(), val.to_num::<f64>().atan(), delta);
+            let y = FixedI16::<U8>::from_bits(bits);
+            let x = FixedI16::<U8>::from_bits(bits.rotate_left(5) ^ 0x5A5A);
+            let exact = y.to_num::<f64>().atan2(x.to_num::<f64>());
+            check_atan_f64!(y.atan2(x), exact, delta);
+        }
+    }
+
+    #[test]
+    fn atan_sample_32() {
+        type Angle = FixedI32<U29>;
+        let delta = Angle::DELTA.to_num::<f64>();
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            let val = FixedI32::<U16>::from_bits(bits);
+            check_atan_f64!(val.atan(), val.to_num::<f64>().atan(), delta);
+            let x = FixedI32::<U16>::from_bits(bits.rotate_left(7));
+            let exact = val.to_num::<f64>().atan2(x.to_num::<f64>());
+            check_atan_f64!(val.atan2(x), exact, delta);
+        }
+    }
+
+    #[test]
+    fn atan_narrow_and_wide_agree() {
+        for i in -10_000i64..=10_000 {
+            let y = i.wrapping_mul(6_364_136_223_846_793_005);
+            let x = y.rotate_left(17) >> (i & 31);
+            let narrow = FixedI64::<U48>::from_bits(y).atan2(FixedI64::from_bits(x));
+            let wide = FixedI128::<U48>::from_bits(i128::from(y))
+                .atan2(FixedI128::from_bits(i128::from(x)));
+            let wide_bits = wide.to_bits();
+            let truncated = wide_bits.unsigned_abs() >> 64;
+            let truncated = if wide_bits < 0 {
+                -(truncated as i128)
+            } else {
+                truncated as i128
+            };
+            assert_eq!(i128::from(narrow.to_bits()), truncated);
+
+            let narrow = FixedI64::<U48>::from_bits(y).atan();
+            let wide = FixedI128::<U112>::from_bits(i128::from(y) << 64).atan();
+            let wide_bits = wide.to_bits();
+            let truncated = wide_bits.unsigned_abs() >> 64;
+            let truncated = if wide_bits < 0 {
+                -(truncated as i128)
+            } else {
+                truncated as i128
+            };
+            assert_eq!(i128::from(narrow.to_bits()), truncated);
+        }
+    }
+
+    macro_rules! check_atan_exact {
+        ($Fix:ty, $Angle:ty) => {{
+            type Fix = $Fix;
+            type Angle = $Angle;
+            let zero = Fix::ZERO;
+            let frac_pi_4 = Angle::FRAC_PI_4;
+            let frac_pi_2 = Angle::FRAC_PI_2;
+            let pi = Angle::PI;
+            // 3π/4 rounded down
+            let bits = consts::PI.to_bits() - (consts::FRAC_PI_4.to_bits() >> 2);
+            let frac_3pi_4 = Angle::from_num(FixedU128::<U126>::from_bits(bits));
+            for v in [Fix::DELTA, Fix::ONE, Fix::MAX] {
+                assert_eq!(v.atan2(v), frac_pi_4);
+                assert_eq!((-v).atan2(v), -frac_pi_4);
+                assert_eq!(v.atan2(-v), frac_3pi_4);
+                assert_eq!((-v).atan2(-v), -frac_3pi_4);
+                assert_eq!(zero.atan2(v), Angle::ZERO);
+                assert_eq!(zero.atan2(-v), pi);
+                assert_eq!(v.atan2(zero), frac_pi_2);
+                assert_eq!((-v).atan2(zero), -frac_pi_2);
+            }
+            assert_eq!(Fix::MIN.atan2(Fix::MIN), -frac_3pi_4);
+            assert_eq!(zero.atan2(Fix::MIN), pi);
+            assert_eq!(Fix::MIN.atan2(zero), -frac_pi_2);
+            assert_eq!(zero.atan2(zero), Angle::ZERO);
+            assert_eq!(zero.atan(), Angle::ZERO);
+            assert_eq!(Fix::ONE.atan(), frac_pi_4);
+            assert_eq!(Fix::NEG_ONE.atan(), -frac_pi_4);
+        }};
+    }
+
+    #[test]
+    fn atan_exact() {
+        type I8 = FixedI8<U4>;
+        type I16 = FixedI16<U12>;
+        type I32 = FixedI32<U28>;
+        type I64 = FixedI64<U62>;
+        type I128 = FixedI128<U124>;
+        check_atan_exact!(I8, FixedI8<U5>);
+        check_atan_exact!(I16, FixedI16<U13>);
+        check_atan_exact!(I32, FixedI32<U29>);
+        check_atan_exact!(I64, FixedI64<U61>);
+        check_atan_exact!(I128, FixedI128<U125>);
+        let min = FixedI128::<U128>::MIN;
+        assert_eq!(min.atan2(min), FixedI128::<U0>::MIN.atan2(FixedI128::MIN));
+        let err = min.atan() + FixedI128::<U125>::from_num(0.5f64.atan());
+        assert!(err.abs() < 1e-15);
+    }
+
+    macro_rules! check_asin_acos_f64 {
+        ($Fix:ty, $bits:expr) => {{
+            type Fix = $Fix;
+            let val = Fix::from_bits($bits);
+            let f = val.to_num::<f64>();
+            let delta = Fix::DELTA.to_num::<f64>();
+            let (min, max) = (Fix::MIN.to_num::<f64>(), Fix::MAX.to_num::<f64>());
+            if f.abs() > 1.0 {
+                assert_eq!(val.checked_asin(), None, "{val}");
+                assert_eq!(val.checked_acos(), None, "{val}");
+            } else {
+                for (exact, (ans, overflow), checked) in [
+                    (f.asin(), val.overflowing_asin(), val.checked_asin()),
+                    (f.acos(), val.overflowing_acos(), val.checked_acos()),
+                ] {
+                    if overflow {
+                        assert!(exact < min || exact > max, "{val}");
+                        assert_eq!(checked, None, "{val}");
+                    } else {
+                        check_atan_f64!(ans, exact, delta);
+                        assert_eq!(checked, Some(ans), "{val}");
+                    }
+                }
+            }
+        }};
+    }
+
+    #[test]
+    fn asin_acos_exhaustive_8() {
+        for bits in i8::MIN..=i8::MAX {
+            check_asin_acos_f64!(FixedI8<U0>, bits);
+            check_asin_acos_f64!(FixedI8<U4>, bits);
+            check_asin_acos_f64!(FixedI8<U5>, bits);
+            check_asin_acos_f64!(FixedI8<U6>, bits);
+            check_asin_acos_f64!(FixedI8<U7>, bits);
+            check_asin_acos_f64!(FixedI8<U8>, bits);
+        }
+    }
+
+    #[test]
+    fn asin_acos_exhaustive_16() {
+        for bits in i16::MIN..=i16::MAX {
+            check_asin_acos_f64!(FixedI16<U8>, bits);
+            check_asin_acos_f64!(FixedI16<U13>, bits);
+            check_asin_acos_f64!(FixedI16<U15>, bits);
+            check_asin_acos_f64!(FixedI16<U16>, bits);
+        }
+    }
+
+    #[test]
+    fn asin_acos_sample_32_64() {
+        for i in -10_000i32..=10_000 {
+            let bits = i.wrapping_mul(214_013).wrapping_add(2_531_011) ^ i.wrapping_shl(20);
+            check_asin_acos_f64!(FixedI32<U29>, bits >> 2);
+            check_asin_acos_f64!(FixedI32<U30>, bits >> 1);
+            check_asin_acos_f64!(FixedI32<U31>, bits);
+            check_asin_acos_f64!(FixedI64<U48>, i64::from(bits) << 16);
+            check_asin_acos_f64!(FixedI64<U62>, i64::from(bits) << 30);
+        }
+    }
+
+    #[test]
+    fn asin_acos_narrow_and_wide_agree() {
+        for i in -10_000i64..=10_000 {
+            let bits = i.wrapping_mul(6_364_136_223_846_793_005) >> 2;
+            let narrow = FixedI64::<U61>::from_bits(bits);
+            let wide = FixedI128::<U125>::from_bits(i128::from(bits) << 64);
+            for (narrow, wide) in [(narrow.asin(), wide.asin()), (narrow.acos(), wide.acos())] {
+                let wide_bits = wide.to_bits();
+                let truncated = wide_bits.unsigned_abs() >> 64;
+                let truncated = if wide_bits < 0 {
+                    -(truncated as i128)
+                } else {
+                    truncated as i128
+                };
+                assert_eq!(i128::from(narrow.to_bits()), truncated);
+            }
+        }
+    }
+
+    macro_rules! check_asin_acos_exact {
+        ($Fix:ty) => {{
+            type Fix = $Fix;
+            assert_eq!(Fix::ZERO.asin(), Fix::ZERO);
+            assert_eq!(Fix::ONE.asin(), Fix::FRAC_PI_2);
+            assert_eq!(Fix::NEG_ONE.asin(), -Fix::FRAC_PI_2);
+            assert_eq!(Fix::ZERO.acos(), Fix::FRAC_PI_2);
+            assert_eq!(Fix::ONE.acos(), Fix::ZERO);
+            assert_eq!(Fix::NEG_ONE.acos(), Fix::PI);
+            assert_eq!((Fix::ONE + Fix::DELTA).checked_asin(), None);
+            assert_eq!((Fix::NEG_ONE - Fix::DELTA).checked_acos(), None);
+            assert_eq!(Fix::MAX.checked_asin(), None);
+            assert_eq!(Fix::MIN.checked_acos(), None);
+        }};
+    }
+
+    #[test]
+    fn asin_acos_exact() {
+        check_asin_acos_exact!(FixedI8<U5>);
+        check_asin_acos_exact!(FixedI16<U13>);
+        check_asin_acos_exact!(FixedI32<U29>);
+        check_asin_acos_exact!(FixedI64<U61>);
+        check_asin_acos_exact!(FixedI128<U125>);
+
+        // with one integer bit, acos overflows for arguments < cos 1
+        type OneIntBit = FixedI128<U127>;
+        let err = OneIntBit::from_num(0.75).acos() - OneIntBit::from_num(0.75f64.acos());
+        assert!(err.abs() < 1e-15);
+        assert_eq!(OneIntBit::ZERO.checked_acos(), None);
+        assert_eq!(OneIntBit::NEG_ONE.checked_asin(), None);
+        assert_eq!(OneIntBit::NEG_ONE.saturating_asin(), OneIntBit::MIN);
+        assert_eq!(OneIntBit::ZERO.saturating_acos(), OneIntBit::MAX);
+        let err = OneIntBit::from_num(0.5).asin() - OneIntBit::from_num(0.5f64.asin());
+        assert!(err.abs() < 1e-15);
+
+        // with no integer bits, acos never fits
+        type NoIntBits = FixedI128<U128>;
+        assert_eq!(NoIntBits::ZERO.checked_asin(), Some(NoIntBits::ZERO));
+        assert_eq!(NoIntBits::MIN.checked_asin(), None);
+        assert_eq!(NoIntBits::MAX.checked_acos(), None);
+        assert_eq!(NoIntBits::ZERO.checked_acos(), None);
+        assert!(NoIntBits::ZERO.overflowing_acos().1);
+        type NoIntBits64 = FixedI64<U64>;
+        assert_eq!(NoIntBits64::ZERO.checked_acos(), None);
+        assert!(NoIntBits64::ZERO.overflowing_acos().1);
+        let err = NoIntBits::from_num(-0.25).asin() + NoIntBits::from_num(0.25f64.asin());
+        assert!(err.abs() < 1e-15);
+    }
+
+    // Compares with FixedI256<U252>, whose arcsine and arccosine are computed
+    // with a 512-bit mantissa and rounded toward zero.
+    macro_rules! check_asin_acos_reference {
+        ($Fix:ty, $x:expr) => {{
+            type Fix = $Fix;
+            let x = Fix::from_bits($x);
+            let reference = FixedI256::<U252>::from_num(x);
+            let (asin, acos) = (reference.asin(), reference.acos());
+            // x ≥ 0, so both are non-negative and rounding down is rounding
+            // toward zero
+            assert_eq!(x.checked_asin(), Fix::checked_from_num(asin), "asin {x:?}");
+            assert_eq!(x.checked_acos(), Fix::checked_from_num(acos), "acos {x:?}");
+            if let Some(neg) = x.checked_neg() {
+                let acos = (-reference).acos();
+                assert_eq!(neg.checked_asin(), x.checked_asin().map(|a| -a));
+                assert_eq!(
+                    neg.checked_acos(),
+                    Fix::checked_from_num(acos),
+                    "acos {neg:?}"
+                );
+            }
+        }};
+    }
+
+    #[test]
+    fn asin_acos_reference_wide() {
+        let mut state = 0x1234_5678_9abc_def0_u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state
+        };
+        for i in 0..62 {
+            for j in 0..4 {
+                let x = (1 << i) + j;
+                check_asin_acos_reference!(FixedI64<U64>, x);
+                check_asin_acos_reference!(FixedI128<U64>, i128::from(x));
+                check_asin_acos_reference!(FixedI128<U64>, (1 << 64) - (i128::from(x) << 2));
+            }
+            let x = i64::try_from(next() >> 1).unwrap() >> i;
+            check_asin_acos_reference!(FixedI64<U64>, x);
+            check_asin_acos_reference!(FixedI128<U64>, i128::from(x));
+        }
+        for i in 0..126 {
+            for j in 0..4 {
+                let x = (1 << i) + j;
+                check_asin_acos_reference!(FixedI128<U128>, x);
+                // near 1, acos is just above √(2(1 − x)), which can be exact
+                check_asin_acos_reference!(FixedI128<U127>, i128::MAX - ((1 << i) - 1) - j);
+                check_asin_acos_reference!(FixedI128<U126>, (1 << 126) - (1 << i) - j);
+            }
+            let x = (i128::from(next()) << 64 | i128::from(next())) & i128::MAX;
+            check_asin_acos_reference!(FixedI128<U128>, (x >> 1) >> i);
+        }
+        check_asin_acos_reference!(FixedI64<U64>, 0x3039);
+        check_asin_acos_reference!(FixedI128<U64>, 0x3039);
+        check_asin_acos_reference!(FixedI128<U128>, 0x3039);
+        check_asin_acos_reference!(FixedI128<U128>, 0x4000_0000_0000_0000_0000_0000_0000_0007);
+    }
+}
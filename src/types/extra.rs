@@ -21,6 +21,7 @@ These types are mostly reexports from the [*typenum* crate].
 [*typenum* crate]: https://crates.io/crates/typenum
 */
 
+use crate::traits::Fixed;
 pub use typenum::{
     Diff, IsLessOrEqual, Sum, True, U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13,
     U14, U15, U16, U17, U18, U19, U20, U21, U22, U23, U24, U25, U26, U27, U28, U29, U30, U31, U32,
@@ -29,7 +30,15 @@ pub use typenum::{
     U71, U72, U73, U74, U75, U76, U77, U78, U79, U80, U81, U82, U83, U84, U85, U86, U87, U88, U89,
     U90, U91, U92, U93, U94, U95, U96, U97, U98, U99, U100, U101, U102, U103, U104, U105, U106,
     U107, U108, U109, U110, U111, U112, U113, U114, U115, U116, U117, U118, U119, U120, U121, U122,
-    U123, U124, U125, U126, U127, U128, Unsigned,
+    U123, U124, U125, U126, U127, U128, U129, U130, U131, U132, U133, U134, U135, U136, U137, U138,
+    U139, U140, U141, U142, U143, U144, U145, U146, U147, U148, U149, U150, U151, U152, U153, U154,
+    U155, U156, U157, U158, U159, U160, U161, U162, U163, U164, U165, U166, U167, U168, U169, U170,
+    U171, U172, U173, U174, U175, U176, U177, U178, U179, U180, U181, U182, U183, U184, U185, U186,
+    U187, U188, U189, U190, U191, U192, U193, U194, U195, U196, U197, U198, U199, U200, U201, U202,
+    U203, U204, U205, U206, U207, U208, U209, U210, U211, U212, U213, U214, U215, U216, U217, U218,
+    U219, U220, U221, U222, U223, U224, U225, U226, U227, U228, U229, U230, U231, U232, U233, U234,
+    U235, U236, U237, U238, U239, U240, U241, U242, U243, U244, U245, U246, U247, U248, U249, U250,
+    U251, U252, U253, U254, U255, U256, Unsigned,
 };
 
 /// Implemented for all [`Unsigned`] integers ≤ 8.
@@ -47,3 +56,202 @@ impl<T: Unsigned + IsLessOrEqual<U64, Output = True>> LeEqU64 for T {}
 /// Implemented for all [`Unsigned`] integers ≤ 128.
 pub trait LeEqU128: Unsigned + IsLessOrEqual<U128, Output = True> {}
 impl<T: Unsigned + IsLessOrEqual<U128, Output = True>> LeEqU128 for T {}
+/// Implemented for all [`Unsigned`] integers ≤ 256.
+pub trait LeEqU256: Unsigned + IsLessOrEqual<U256, Output = True> {}
+impl<T: Unsigned + IsLessOrEqual<U256, Output = True>> LeEqU256 for T {}
+
+/// A number of fractional bits given as a [`u32`] const generic parameter.
+///
+/// This is used together with [`ToUnsigned`] to convert the number to the
+/// corresponding [`Unsigned`] type, which is available through the [`U`]
+/// alias.
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::extra::{U, U12};
+/// use fixed::types::I20F12;
+/// use fixed::FixedI32;
+/// let a = FixedI32::<U<12>>::from_num(1.5);
+/// let b: FixedI32<U12> = a;
+/// let c: I20F12 = b;
+/// assert_eq!(c, 1.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Const<const N: u32>;
+
+impl<const N: u32> Const<N> {
+    /// Returns [`true`] if the fixed-point number type `F` has `N` fractional
+    /// bits.
+    ///
+    /// Since this is a `const fn`, it can be used in constant expressions in
+    /// generic code, for example to check at compile time that a [`Fixed`]
+    /// type parameter agrees with a const generic parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::traits::Fixed;
+    /// use fixed::types::extra::Const;
+    /// use fixed::types::{FixedI32C, I20F12};
+    ///
+    /// fn frac_bits<F: Fixed, const FRAC: u32>(x: F) -> F::Bits {
+    ///     const {
+    ///         assert!(Const::<FRAC>::is_frac_nbits_of::<F>());
+    ///     }
+    ///     x.to_bits()
+    /// }
+    /// assert_eq!(frac_bits::<I20F12, 12>(I20F12::ONE), 1 << 12);
+    ///
+    /// const CHECK: bool = Const::<12>::is_frac_nbits_of::<FixedI32C<12>>();
+    /// assert!(CHECK);
+    /// assert!(!Const::<11>::is_frac_nbits_of::<I20F12>());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_frac_nbits_of<F: Fixed>() -> bool {
+        F::FRAC_NBITS == N
+    }
+
+    /// Returns [`true`] if the fixed-point number type `F` has `N` integer
+    /// bits.
+    ///
+    /// Since this is a `const fn`, it can be used in constant expressions in
+    /// generic code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::extra::Const;
+    /// use fixed::types::{FixedI32C, I20F12};
+    /// const CHECK: bool = Const::<20>::is_int_nbits_of::<FixedI32C<12>>();
+    /// assert!(CHECK);
+    /// assert!(!Const::<12>::is_int_nbits_of::<I20F12>());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_int_nbits_of<F: Fixed>() -> bool {
+        F::INT_NBITS == N
+    }
+}
+
+/// Converts a [`Const`] to the corresponding [`Unsigned`] type.
+///
+/// This trait is implemented for <code>[Const]&lt;N></code> for all `N` ≤ 256.
+pub trait ToUnsigned {
+    /// The [`Unsigned`] type.
+    type Output: Unsigned;
+}
+
+/// The [`Unsigned`] type corresponding to the [`u32`] constant `N`.
+///
+/// This is the same type as <code>U<i>N</i></code>, for example `U<12>` is the
+/// same type as [`U12`], so it can be used anywhere the *typenum* types are
+/// used.
+pub type U<const N: u32> = <Const<N> as ToUnsigned>::Output;
+
+macro_rules! const_to_unsigned {
+    ($($n:literal => $U:ident,)*) => {
+        $(
+            impl ToUnsigned for Const<$n> {
+                type Output = $U;
+            }
+        )*
+    };
+}
+
+const_to_unsigned! {
+    0 => U0, 1 => U1, 2 => U2, 3 => U3, 4 => U4, 5 => U5, 6 => U6, 7 => U7, 8 => U8, 9 => U9,
+    10 => U10, 11 => U11, 12 => U12, 13 => U13, 14 => U14, 15 => U15, 16 => U16, 17 => U17,
+    18 => U18, 19 => U19, 20 => U20, 21 => U21, 22 => U22, 23 => U23, 24 => U24, 25 => U25,
+    26 => U26, 27 => U27, 28 => U28, 29 => U29, 30 => U30, 31 => U31, 32 => U32, 33 => U33,
+    34 => U34, 35 => U35, 36 => U36, 37 => U37, 38 => U38, 39 => U39, 40 => U40, 41 => U41,
+    42 => U42, 43 => U43, 44 => U44, 45 => U45, 46 => U46, 47 => U47, 48 => U48, 49 => U49,
+    50 => U50, 51 => U51, 52 => U52, 53 => U53, 54 => U54, 55 => U55, 56 => U56, 57 => U57,
+    58 => U58, 59 => U59, 60 => U60, 61 => U61, 62 => U62, 63 => U63, 64 => U64, 65 => U65,
+    66 => U66, 67 => U67, 68 => U68, 69 => U69, 70 => U70, 71 => U71, 72 => U72, 73 => U73,
+    74 => U74, 75 => U75, 76 => U76, 77 => U77, 78 => U78, 79 => U79, 80 => U80, 81 => U81,
+    82 => U82, 83 => U83, 84 => U84, 85 => U85, 86 => U86, 87 => U87, 88 => U88, 89 => U89,
+    90 => U90, 91 => U91, 92 => U92, 93 => U93, 94 => U94, 95 => U95, 96 => U96, 97 => U97,
+    98 => U98, 99 => U99, 100 => U100, 101 => U101, 102 => U102, 103 => U103, 104 => U104,
+    105 => U105, 106 => U106, 107 => U107, 108 => U108, 109 => U109, 110 => U110, 111 => U111,
+    112 => U112, 113 => U113, 114 => U114, 115 => U115, 116 => U116, 117 => U117, 118 => U118,
+    119 => U119, 120 => U120, 121 => U121, 122 => U122, 123 => U123, 124 => U124, 125 => U125,
+    126 => U126, 127 => U127, 128 => U128, 129 => U129, 130 => U130, 131 => U131, 132 => U132,
+    133 => U133, 134 => U134, 135 => U135, 136 => U136, 137 => U137, 138 => U138, 139 => U139,
+    140 => U140, 141 => U141, 142 => U142, 143 => U143, 144 => U144, 145 => U145, 146 => U146,
+    147 => U147, 148 => U148, 149 => U149, 150 => U150, 151 => U151, 152 => U152, 153 => U153,
+    154 => U154, 155 => U155, 156 => U156, 157 => U157, 158 => U158, 159 => U159, 160 => U160,
+    161 => U161, 162 => U162, 163 => U163, 164 => U164, 165 => U165, 166 => U166, 167 => U167,
+    168 => U168, 169 => U169, 170 => U170, 171 => U171, 172 => U172, 173 => U173, 174 => U174,
+    175 => U175, 176 => U176, 177 => U177, 178 => U178, 179 => U179, 180 => U180, 181 => U181,
+    182 => U182, 183 => U183, 184 => U184, 185 => U185, 186 => U186, 187 => U187, 188 => U188,
+    189 => U189, 190 => U190, 191 => U191, 192 => U192, 193 => U193, 194 => U194, 195 => U195,
+    196 => U196, 197 => U197, 198 => U198, 199 => U199, 200 => U200, 201 => U201, 202 => U202,
+    203 => U203, 204 => U204, 205 => U205, 206 => U206, 207 => U207, 208 => U208, 209 => U209,
+    210 => U210, 211 => U211, 212 => U212, 213 => U213, 214 => U214, 215 => U215, 216 => U216,
+    217 => U217, 218 => U218, 219 => U219, 220 => U220, 221 => U221, 222 => U222, 223 => U223,
+    224 => U224, 225 => U225, 226 => U226, 227 => U227, 228 => U228, 229 => U229, 230 => U230,
+    231 => U231, 232 => U232, 233 => U233, 234 => U234, 235 => U235, 236 => U236, 237 => U237,
+    238 => U238, 239 => U239, 240 => U240, 241 => U241, 242 => U242, 243 => U243, 244 => U244,
+    245 => U245, 246 => U246, 247 => U247, 248 => U248, 249 => U249, 250 => U250, 251 => U251,
+    252 => U252, 253 => U253, 254 => U254, 255 => U255, 256 => U256,
+}
+
+/// Implemented for all [`Const`] numbers ≤ 8.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU8</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU8`].
+pub trait ConstLeEqU8: ToUnsigned<Output: LeEqU8> {}
+impl<T: ToUnsigned<Output: LeEqU8>> ConstLeEqU8 for T {}
+/// Implemented for all [`Const`] numbers ≤ 16.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU16</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU16`].
+pub trait ConstLeEqU16: ToUnsigned<Output: LeEqU16> {}
+impl<T: ToUnsigned<Output: LeEqU16>> ConstLeEqU16 for T {}
+/// Implemented for all [`Const`] numbers ≤ 32.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU32</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU32`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fixed::types::extra::{Const, ConstLeEqU32};
+/// use fixed::types::FixedI32C;
+/// fn halve<const FRAC: u32>(x: FixedI32C<FRAC>) -> FixedI32C<FRAC>
+/// where
+///     Const<FRAC>: ConstLeEqU32,
+/// {
+///     x / 2
+/// }
+/// // FRAC cannot be inferred from the argument, so it must be specified
+/// assert_eq!(halve::<16>(FixedI32C::<16>::from_num(3)), 1.5);
+/// ```
+pub trait ConstLeEqU32: ToUnsigned<Output: LeEqU32> {}
+impl<T: ToUnsigned<Output: LeEqU32>> ConstLeEqU32 for T {}
+/// Implemented for all [`Const`] numbers ≤ 64.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU64</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU64`].
+pub trait ConstLeEqU64: ToUnsigned<Output: LeEqU64> {}
+impl<T: ToUnsigned<Output: LeEqU64>> ConstLeEqU64 for T {}
+/// Implemented for all [`Const`] numbers ≤ 128.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU128</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU128`].
+pub trait ConstLeEqU128: ToUnsigned<Output: LeEqU128> {}
+impl<T: ToUnsigned<Output: LeEqU128>> ConstLeEqU128 for T {}
+/// Implemented for all [`Const`] numbers ≤ 256.
+///
+/// This can be used as a bound in generic code using const generics, such as
+/// <code>[Const]&lt;FRAC>: ConstLeEqU256</code>, which implies that
+/// <code>[U]&lt;FRAC></code> implements [`LeEqU256`].
+pub trait ConstLeEqU256: ToUnsigned<Output: LeEqU256> {}
+impl<T: ToUnsigned<Output: LeEqU256>> ConstLeEqU256 for T {}
@@ -18,12 +18,58 @@ Type aliases for all supported fixed-point numbers.
 */
 
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256,
 };
 
 pub mod extra;
 
+macro_rules! const_alias {
+    ($($Alias:ident = $Fixed:ident,)*) => {
+        $(
+            comment! {
+                "[`", stringify!($Fixed), "`] with the number of fractional bits
+given as a const generic parameter.
+
+<code>", stringify!($Alias), "&lt;FRAC></code> is the same type as
+<code>", stringify!($Fixed), "&lt;[U][extra::U]&lt;FRAC>></code>, so values
+can be used with the *typenum* API without any conversion. The number of
+fractional bits is available as the constant
+[`FRAC_NBITS`][", stringify!($Fixed), "::FRAC_NBITS].
+
+# Examples
+
+```rust
+use fixed::types::extra::U4;
+use fixed::types::", stringify!($Alias), ";
+use fixed::", stringify!($Fixed), ";
+let a = ", stringify!($Alias), "::<4>::from_num(1.5);
+let b: ", stringify!($Fixed), "<U4> = a;
+assert_eq!(b, 1.5);
+assert_eq!(", stringify!($Alias), "::<4>::FRAC_NBITS, 4);
+```
+";
+                pub type $Alias<const FRAC: u32> = $Fixed<extra::U<FRAC>>;
+            }
+        )*
+    };
+}
+
+const_alias! {
+    FixedI8C = FixedI8,
+    FixedI16C = FixedI16,
+    FixedI32C = FixedI32,
+    FixedI64C = FixedI64,
+    FixedI128C = FixedI128,
+    FixedI256C = FixedI256,
+    FixedU8C = FixedU8,
+    FixedU16C = FixedU16,
+    FixedU32C = FixedU32,
+    FixedU64C = FixedU64,
+    FixedU128C = FixedU128,
+    FixedU256C = FixedU256,
+}
+
 /*
 ```rust
 fn num(n: i32, noun: &str) -> String {
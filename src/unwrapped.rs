@@ -15,10 +15,10 @@
 
 use crate::from_str::ParseFixedError;
 use crate::traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed};
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
 };
 use core::fmt::{
     Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp,
@@ -1229,6 +1229,257 @@ impl<F: Fixed> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_sqrt())
     }
 
+    /// Returns the reciprocal square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_rsqrt][FixedI32::unwrapped_rsqrt]</code> and
+    /// <code>FixedU32::[unwrapped\_rsqrt][FixedU32::unwrapped_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative or on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I16F16::from_num(4)).rsqrt().0, 0.5);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I1F15;
+    /// use fixed::Unwrapped;
+    /// let small = Unwrapped(I1F15::from_num(0.25));
+    /// let _overflow = small.rsqrt();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        Unwrapped(self.0.unwrapped_rsqrt())
+    }
+
+    /// Base-2 exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp2][FixedI32::unwrapped_exp2]</code> and
+    /// <code>FixedU32::[unwrapped\_exp2][FixedU32::unwrapped_exp2]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(3));
+    /// assert_eq!(x.exp2().0, I16F16::from_num(8));
+    /// let y = Unwrapped(I16F16::from_num(-2));
+    /// assert_eq!(y.exp2().0, I16F16::from_num(0.25));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let big = Unwrapped(I16F16::from_num(20));
+    /// let _overflow = big.exp2();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp2(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp2())
+    }
+
+    /// Exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp][FixedI32::unwrapped_exp]</code> and
+    /// <code>FixedU32::[unwrapped\_exp][FixedU32::unwrapped_exp]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(0));
+    /// assert_eq!(x.exp().0, I16F16::ONE);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let big = Unwrapped(I16F16::from_num(20));
+    /// let _overflow = big.exp();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp())
+    }
+
+    /// Exponential minus one.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_exp\_m1][FixedI32::unwrapped_exp_m1]</code> and
+    /// <code>FixedU32::[unwrapped\_exp\_m1][FixedU32::unwrapped_exp_m1]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(0));
+    /// assert_eq!(x.exp_m1().0, I16F16::ZERO);
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let big = Unwrapped(I16F16::from_num(20));
+    /// let _overflow = big.exp_m1();
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn exp_m1(self) -> Self {
+        Unwrapped(self.0.unwrapped_exp_m1())
+    }
+
+    /// Returns the value raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powi][FixedI32::unwrapped_powi]</code> and
+    /// <code>FixedU32::[unwrapped\_powi][FixedU32::unwrapped_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3).0, I16F16::from_num(3.375));
+    /// let two = Unwrapped(I16F16::from_num(2));
+    /// assert_eq!(two.powi(-2).0, I16F16::from_num(0.25));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I16F16::MAX).powi(2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powi(self, n: i32) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_powi(n))
+    }
+
+    /// Returns the value raised to the fixed-point power `exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_powf][FixedI32::unwrapped_powf]</code> and
+    /// <code>FixedU32::[unwrapped\_powf][FixedU32::unwrapped_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, if `self` is zero and `exp` is
+    /// negative, or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(4));
+    /// let half = Unwrapped(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(half).0, I16F16::from_num(2));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I16F16::MAX).powf(Unwrapped(I16F16::from_num(2)));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powf(self, exp: Unwrapped<F>) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_powf(exp.0))
+    }
+
+    /// Returns the cube root.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cbrt][FixedI32::unwrapped_cbrt]</code> and
+    /// <code>FixedU32::[unwrapped\_cbrt][FixedU32::unwrapped_cbrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(-3.375));
+    /// assert_eq!(x.cbrt().0, I16F16::from_num(-1.5));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn cbrt(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_cbrt())
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_nth\_root][FixedI32::unwrapped_nth_root]</code> and
+    /// <code>FixedU32::[unwrapped\_nth\_root][FixedU32::unwrapped_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, if `n` is even and the number is negative, or
+    /// if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// let x = Unwrapped(I16F16::from_num(5.0625));
+    /// assert_eq!(x.nth_root(4).0, I16F16::from_num(1.5));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn nth_root(self, n: u32) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_nth_root(n))
+    }
+
     /// Integer base-2 logarithm, rounded down.
     ///
     /// See also <code>FixedI32::[int\_log2][FixedI32::int_log2]</code> and
@@ -2122,6 +2373,230 @@ impl<F: FixedSigned> Unwrapped<F> {
     pub fn sub_unsigned(self, rhs: F::Unsigned) -> Unwrapped<F> {
         Unwrapped(self.0.unwrapped_sub_unsigned(rhs))
     }
+
+    /// Sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin][FixedI32::unwrapped_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I16F16::FRAC_PI_2).sin(), Unwrapped(I16F16::ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_sin())
+    }
+
+    /// Cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cos][FixedI32::unwrapped_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I16F16::ZERO).cos(), Unwrapped(I16F16::ONE));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I1F31;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I1F31::ZERO).cos();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn cos(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_cos())
+    }
+
+    /// Simultaneously computes the sine and cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sin\_cos][FixedI32::unwrapped_sin_cos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(
+    ///     Unwrapped(I16F16::ZERO).sin_cos(),
+    ///     (Unwrapped(I16F16::ZERO), Unwrapped(I16F16::ONE))
+    /// );
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn sin_cos(self) -> (Unwrapped<F>, Unwrapped<F>) {
+        let (sin, cos) = self.0.unwrapped_sin_cos();
+        (Unwrapped(sin), Unwrapped(cos))
+    }
+
+    /// Arcsine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_asin][FixedI32::unwrapped_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+    /// or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I16F16::ONE).asin(), Unwrapped(I16F16::FRAC_PI_2));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I1F31;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I1F31::NEG_ONE).asin();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn asin(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_asin())
+    }
+
+    /// Arccosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_acos][FixedI32::unwrapped_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1,
+    /// or if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I16F16::NEG_ONE).acos(), Unwrapped(I16F16::PI));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I1F31;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I1F31::ZERO).acos();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn acos(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_acos())
+    }
+
+    /// Hyperbolic sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_sinh][FixedI32::unwrapped_sinh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I4F12::ZERO).sinh(), Unwrapped(I4F12::ZERO));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I4F12;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I4F12::from_num(3)).sinh();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn sinh(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_sinh())
+    }
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[unwrapped\_cosh][FixedI32::unwrapped_cosh]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I4F12::ZERO).cosh(), Unwrapped(I4F12::ONE));
+    /// ```
+    ///
+    /// The following panics because of overflow.
+    ///
+    /// ```should_panic
+    /// use fixed::types::I4F12;
+    /// use fixed::Unwrapped;
+    /// let _overflow = Unwrapped(I4F12::from_num(3)).cosh();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn cosh(self) -> Unwrapped<F> {
+        Unwrapped(self.0.unwrapped_cosh())
+    }
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method cannot overflow.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Unwrapped;
+    /// assert_eq!(Unwrapped(I4F12::ZERO).tanh(), Unwrapped(I4F12::ZERO));
+    /// // tanh 1 ≈ 0.762
+    /// let x = Unwrapped(I4F12::ONE).tanh().0;
+    /// assert!(x.dist(I4F12::from_num(0.762)) < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tanh(self) -> Unwrapped<F> {
+        Unwrapped(self.0.tanh())
+    }
 }
 
 impl<F: FixedUnsigned> Unwrapped<F> {
@@ -2831,3 +3306,5 @@ ops! { FixedU16(u16, LeEqU16) }
 ops! { FixedU32(u32, LeEqU32) }
 ops! { FixedU64(u64, LeEqU64) }
 ops! { FixedU128(u128, LeEqU128) }
+ops! { FixedI256(I256Bits, LeEqU256) }
+ops! { FixedU256(U256Bits, LeEqU256) }
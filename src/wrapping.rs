@@ -15,10 +15,10 @@
 
 use crate::from_str::ParseFixedError;
 use crate::traits::{Fixed, FixedSigned, FixedUnsigned, FromFixed, ToFixed};
-use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128};
+use crate::types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64, LeEqU128, LeEqU256};
 use crate::{
-    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedU8, FixedU16, FixedU32, FixedU64,
-    FixedU128,
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedI128, FixedI256, FixedU8, FixedU16, FixedU32,
+    FixedU64, FixedU128, FixedU256, I256Bits, U256Bits,
 };
 use core::fmt::{
     Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp,
@@ -1008,6 +1008,191 @@ impl<F: Fixed> Wrapping<F> {
         Wrapping(self.0.wrapping_sqrt())
     }
 
+    /// Returns the reciprocal square root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_rsqrt][FixedI32::wrapping_rsqrt]</code> and
+    /// <code>FixedU32::[wrapping\_rsqrt][FixedU32::wrapping_rsqrt]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is zero or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F15, I16F16};
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I16F16::from_num(4)).rsqrt().0, 0.5);
+    ///
+    /// // 1/√0.25 = 2 wraps to 0
+    /// let small = Wrapping(I1F15::from_num(0.25));
+    /// assert_eq!(small.rsqrt().0, 0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        Wrapping(self.0.wrapping_rsqrt())
+    }
+
+    /// Base-2 exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp2][FixedI32::wrapping_exp2]</code> and
+    /// <code>FixedU32::[wrapping\_exp2][FixedU32::wrapping_exp2]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(3));
+    /// assert_eq!(x.exp2().0, I16F16::from_num(8));
+    /// let y = Wrapping(I16F16::from_num(-2));
+    /// assert_eq!(y.exp2().0, I16F16::from_num(0.25));
+    ///
+    /// let big = Wrapping(I16F16::from_num(20));
+    /// assert_eq!(big.exp2().0, I16F16::ZERO);
+    /// ```
+    #[inline]
+    pub fn exp2(self) -> Self {
+        Wrapping(self.0.wrapping_exp2())
+    }
+
+    /// Exponential.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp][FixedI32::wrapping_exp]</code> and
+    /// <code>FixedU32::[wrapping\_exp][FixedU32::wrapping_exp]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(0));
+    /// assert_eq!(x.exp().0, I16F16::ONE);
+    /// ```
+    #[inline]
+    pub fn exp(self) -> Self {
+        Wrapping(self.0.wrapping_exp())
+    }
+
+    /// Exponential minus one.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_exp\_m1][FixedI32::wrapping_exp_m1]</code> and
+    /// <code>FixedU32::[wrapping\_exp\_m1][FixedU32::wrapping_exp_m1]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(0));
+    /// assert_eq!(x.exp_m1().0, I16F16::ZERO);
+    /// ```
+    #[inline]
+    pub fn exp_m1(self) -> Self {
+        Wrapping(self.0.wrapping_exp_m1())
+    }
+
+    /// Returns the value raised to the integer power `n`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powi][FixedI32::wrapping_powi]</code> and
+    /// <code>FixedU32::[wrapping\_powi][FixedU32::wrapping_powi]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `n` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(1.5));
+    /// assert_eq!(x.powi(3).0, I16F16::from_num(3.375));
+    /// let two = Wrapping(I16F16::from_num(2));
+    /// assert_eq!(two.powi(-2).0, I16F16::from_num(0.25));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powi(self, n: i32) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_powi(n))
+    }
+
+    /// Returns the value raised to the fixed-point power `exp`.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_powf][FixedI32::wrapping_powf]</code> and
+    /// <code>FixedU32::[wrapping\_powf][FixedU32::wrapping_powf]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative, or if `self` is zero and `exp` is
+    /// negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(4));
+    /// let half = Wrapping(I16F16::from_num(0.5));
+    /// assert_eq!(x.powf(half).0, I16F16::from_num(2));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn powf(self, exp: Wrapping<F>) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_powf(exp.0))
+    }
+
+    /// Returns the cube root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cbrt][FixedI32::wrapping_cbrt]</code> and
+    /// <code>FixedU32::[wrapping\_cbrt][FixedU32::wrapping_cbrt]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(-3.375));
+    /// assert_eq!(x.cbrt().0, I16F16::from_num(-1.5));
+    /// ```
+    #[inline]
+    pub fn cbrt(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_cbrt())
+    }
+
+    /// Returns the <i>n</i>th root.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_nth\_root][FixedI32::wrapping_nth_root]</code> and
+    /// <code>FixedU32::[wrapping\_nth\_root][FixedU32::wrapping_nth_root]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if `n` is even and the number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// let x = Wrapping(I16F16::from_num(5.0625));
+    /// assert_eq!(x.nth_root(4).0, I16F16::from_num(1.5));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn nth_root(self, n: u32) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_nth_root(n))
+    }
+
     /// Integer base-2 logarithm, rounded down.
     ///
     /// See also <code>FixedI32::[int\_log2][FixedI32::int_log2]</code> and
@@ -1737,6 +1922,181 @@ impl<F: FixedSigned> Wrapping<F> {
     pub fn sub_unsigned(self, rhs: F::Unsigned) -> Wrapping<F> {
         Wrapping(self.0.wrapping_sub_unsigned(rhs))
     }
+
+    /// Sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin][FixedI32::wrapping_sin]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I16F16::FRAC_PI_2).sin(), Wrapping(I16F16::ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_sin())
+    }
+
+    /// Cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cos][FixedI32::wrapping_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I16F16::ZERO).cos(), Wrapping(I16F16::ONE));
+    /// assert_eq!(Wrapping(I1F31::ZERO).cos(), Wrapping(I1F31::NEG_ONE));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_cos())
+    }
+
+    /// Simultaneously computes the sine and cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sin\_cos][FixedI32::wrapping_sin_cos]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I16F16;
+    /// use fixed::Wrapping;
+    /// assert_eq!(
+    ///     Wrapping(I16F16::ZERO).sin_cos(),
+    ///     (Wrapping(I16F16::ZERO), Wrapping(I16F16::ONE))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sin_cos(self) -> (Wrapping<F>, Wrapping<F>) {
+        let (sin, cos) = self.0.wrapping_sin_cos();
+        (Wrapping(sin), Wrapping(cos))
+    }
+
+    /// Arcsine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_asin][FixedI32::wrapping_asin]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I16F16::ONE).asin(), Wrapping(I16F16::FRAC_PI_2));
+    /// // −π/2 wraps to 2 − π/2
+    /// let wrapped = Wrapping(I1F31::NEG_ONE).asin().0;
+    /// assert!((wrapped - I1F31::from_num(0.429)).abs() < 0.001);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn asin(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_asin())
+    }
+
+    /// Arccosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_acos][FixedI32::wrapping_acos]</code>.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is outside the range &minus;1&nbsp;≤&nbsp;<i>x</i>&nbsp;≤&nbsp;1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::{I1F31, I16F16};
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I16F16::NEG_ONE).acos(), Wrapping(I16F16::PI));
+    /// // π/2 wraps to π/2 − 2
+    /// let wrapped = Wrapping(I1F31::ZERO).acos().0;
+    /// assert!((wrapped + I1F31::from_num(0.429)).abs() < 0.001);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn acos(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_acos())
+    }
+
+    /// Hyperbolic sine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_sinh][FixedI32::wrapping_sinh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I4F12::ZERO).sinh(), Wrapping(I4F12::ZERO));
+    /// // sinh 3 ≈ 10.018 wraps to 10.018 − 16 = −5.982
+    /// let wrapped = Wrapping(I4F12::from_num(3)).sinh().0;
+    /// assert!(wrapped.dist(I4F12::from_num(-5.982)) < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sinh(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_sinh())
+    }
+
+    /// Hyperbolic cosine.
+    ///
+    /// See also
+    /// <code>FixedI32::[wrapping\_cosh][FixedI32::wrapping_cosh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I4F12::ZERO).cosh(), Wrapping(I4F12::ONE));
+    /// // cosh 3 ≈ 10.068 wraps to 10.068 − 16 = −5.932
+    /// let wrapped = Wrapping(I4F12::from_num(3)).cosh().0;
+    /// assert!(wrapped.dist(I4F12::from_num(-5.932)) < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cosh(self) -> Wrapping<F> {
+        Wrapping(self.0.wrapping_cosh())
+    }
+
+    /// Hyperbolic tangent.
+    ///
+    /// This method cannot overflow.
+    ///
+    /// See also <code>FixedI32::[tanh][FixedI32::tanh]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed::types::I4F12;
+    /// use fixed::Wrapping;
+    /// assert_eq!(Wrapping(I4F12::ZERO).tanh(), Wrapping(I4F12::ZERO));
+    /// // tanh 1 ≈ 0.762
+    /// let x = Wrapping(I4F12::ONE).tanh().0;
+    /// assert!(x.dist(I4F12::from_num(0.762)) < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn tanh(self) -> Wrapping<F> {
+        Wrapping(self.0.tanh())
+    }
 }
 
 impl<F: FixedUnsigned> Wrapping<F> {
@@ -2365,3 +2725,5 @@ ops! { FixedU16(u16, LeEqU16) }
 ops! { FixedU32(u32, LeEqU32) }
 ops! { FixedU64(u64, LeEqU64) }
 ops! { FixedU128(u128, LeEqU128) }
+ops! { FixedI256(I256Bits, LeEqU256) }
+ops! { FixedU256(U256Bits, LeEqU256) }